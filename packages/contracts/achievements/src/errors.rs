@@ -0,0 +1,19 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum AchievementsError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// No badge type with the given id is in the catalog.
+    BadgeTypeNotFound = 3,
+    /// A badge type's threshold is not positive.
+    InvalidThreshold = 4,
+    /// The catalog already holds `MAX_BADGE_TYPES` badge types.
+    CatalogFull = 5,
+    /// No badge with the given token id has been minted.
+    BadgeNotFound = 6,
+}
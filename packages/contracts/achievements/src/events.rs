@@ -0,0 +1,25 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::BadgeMetric;
+
+/// Emitted when the admin adds a badge type to the catalog
+pub fn emit_badge_type_added(env: &Env, badge_type: u32, metric: BadgeMetric, threshold: i128) {
+    env.events().publish(
+        ("achievements", "badge_type_added"),
+        (badge_type, metric, threshold),
+    );
+}
+
+/// Emitted when the admin retires or reinstates a badge type
+pub fn emit_badge_type_status(env: &Env, badge_type: u32, active: bool) {
+    env.events()
+        .publish(("achievements", "badge_type_status"), (badge_type, active));
+}
+
+/// Emitted when a badge is minted to its holder
+pub fn emit_badge_minted(env: &Env, token_id: u64, badge_type: u32, owner: &Address) {
+    env.events().publish(
+        ("achievements", "badge_minted"),
+        (token_id, badge_type, owner.clone()),
+    );
+}
@@ -0,0 +1,245 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Achievement badges for BACKit creators and stakers.
+//!
+//! The admin keeps an on-chain catalog of badge types, each earned when one
+//! statistic of the reputation contract (positions won, best win streak,
+//! stake volume, …) reaches a threshold. `mint_if_eligible` reads an
+//! address's track record and mints every active badge it has earned but
+//! does not hold yet. Badges are non-fungible and soulbound: each has its
+//! own token id, an address holds at most one of each type, and badges
+//! cannot be transferred.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, Address, Env, IntoVal, String, Symbol, Vec,
+};
+
+use errors::AchievementsError;
+use events::*;
+use storage::*;
+
+pub use storage::{Badge, BadgeMetric, BadgeType};
+
+/// Most badge types the catalog holds.
+pub const MAX_BADGE_TYPES: u32 = 50;
+
+#[contract]
+pub struct AchievementsContract;
+
+// ─── Cross-contract helpers ────────────────────────────────────────────────────
+
+/// Mirror of the reputation contract's `Reputation`.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Reputation {
+    pub calls_created: u32,
+    pub calls_resolved: u32,
+    pub calls_correct: u32,
+    pub positions_settled: u32,
+    pub positions_won: u32,
+    pub current_win_streak: u32,
+    pub best_win_streak: u32,
+    pub volume: i128,
+}
+
+/// Call `get_reputation(address)` on the reputation contract.
+fn reputation_of(env: &Env, reputation: &Address, address: &Address) -> Reputation {
+    let args = (address.clone(),).into_val(env);
+    env.invoke_contract(reputation, &Symbol::new(env, "get_reputation"), args)
+}
+
+/// Value of `metric` in `reputation`.
+fn metric_value(reputation: &Reputation, metric: BadgeMetric) -> i128 {
+    match metric {
+        BadgeMetric::CallsCreated => reputation.calls_created.into(),
+        BadgeMetric::CallsCorrect => reputation.calls_correct.into(),
+        BadgeMetric::PositionsWon => reputation.positions_won.into(),
+        BadgeMetric::BestWinStreak => reputation.best_win_streak.into(),
+        BadgeMetric::Volume => reputation.volume,
+    }
+}
+
+#[contractimpl]
+impl AchievementsContract {
+    /// Initialise the contract with an admin who manages the catalog and the
+    /// reputation contract milestones are read from.
+    /// # Errors
+    /// * [`AchievementsError::AlreadyInitialized`] – called more than once.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        reputation: Address,
+    ) -> Result<(), AchievementsError> {
+        if get_admin(&env).is_some() {
+            return Err(AchievementsError::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_reputation_contract(&env, &reputation);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Add a badge type earned when `metric` reaches `threshold` (admin
+    /// only), e.g. `PositionsWon ≥ 1` for a first win. Returns its id.
+    /// # Errors
+    /// * [`AchievementsError::NotInitialized`]   – contract not initialised.
+    /// * [`AchievementsError::InvalidThreshold`] – `threshold` ≤ 0.
+    /// * [`AchievementsError::CatalogFull`]      – [`MAX_BADGE_TYPES`] reached.
+    pub fn add_badge_type(
+        env: Env,
+        name: String,
+        metric: BadgeMetric,
+        threshold: i128,
+    ) -> Result<u32, AchievementsError> {
+        let admin = get_admin(&env).ok_or(AchievementsError::NotInitialized)?;
+        admin.require_auth();
+        if threshold <= 0 {
+            return Err(AchievementsError::InvalidThreshold);
+        }
+        if get_badge_type_count(&env) >= MAX_BADGE_TYPES {
+            return Err(AchievementsError::CatalogFull);
+        }
+
+        let id = next_badge_type_id(&env);
+        set_badge_type(
+            &env,
+            &BadgeType {
+                id,
+                name,
+                metric,
+                threshold,
+                active: true,
+            },
+        );
+        extend_instance_ttl(&env);
+
+        emit_badge_type_added(&env, id, metric, threshold);
+
+        Ok(id)
+    }
+
+    /// Retire (`active = false`) or reinstate a badge type (admin only).
+    /// Retired types are no longer minted; badges already minted remain.
+    /// # Errors
+    /// * [`AchievementsError::NotInitialized`]    – contract not initialised.
+    /// * [`AchievementsError::BadgeTypeNotFound`] – no badge type `badge_type`.
+    pub fn set_badge_type_active(
+        env: Env,
+        badge_type: u32,
+        active: bool,
+    ) -> Result<(), AchievementsError> {
+        let admin = get_admin(&env).ok_or(AchievementsError::NotInitialized)?;
+        admin.require_auth();
+
+        let mut entry =
+            get_badge_type(&env, badge_type).ok_or(AchievementsError::BadgeTypeNotFound)?;
+        entry.active = active;
+        set_badge_type(&env, &entry);
+        extend_instance_ttl(&env);
+
+        emit_badge_type_status(&env, badge_type, active);
+
+        Ok(())
+    }
+
+    /// Mint to `holder` every active badge whose milestone their reputation
+    /// has reached and which they do not hold yet. Anyone may call it.
+    /// Returns the token ids minted, possibly none.
+    /// # Errors
+    /// * [`AchievementsError::NotInitialized`] – contract not initialised.
+    pub fn mint_if_eligible(env: Env, holder: Address) -> Result<Vec<u64>, AchievementsError> {
+        let reputation_contract =
+            get_reputation_contract(&env).ok_or(AchievementsError::NotInitialized)?;
+        let reputation = reputation_of(&env, &reputation_contract, &holder);
+
+        let mut minted = Vec::new(&env);
+        for id in 1..=get_badge_type_count(&env) {
+            let Some(badge_type) = get_badge_type(&env, id) else {
+                continue;
+            };
+            if !badge_type.active
+                || get_held_badge(&env, &holder, id).is_some()
+                || metric_value(&reputation, badge_type.metric) < badge_type.threshold
+            {
+                continue;
+            }
+
+            let badge = Badge {
+                token_id: next_badge_id(&env),
+                badge_type: id,
+                owner: holder.clone(),
+                minted_at: env.ledger().timestamp(),
+            };
+            add_badge(&env, &badge);
+            minted.push_back(badge.token_id);
+
+            emit_badge_minted(&env, badge.token_id, id, &holder);
+        }
+        extend_instance_ttl(&env);
+
+        Ok(minted)
+    }
+
+    /// Get a badge type from the catalog.
+    /// # Errors
+    /// * [`AchievementsError::BadgeTypeNotFound`] – no badge type `badge_type`.
+    pub fn get_badge_type(env: Env, badge_type: u32) -> Result<BadgeType, AchievementsError> {
+        get_badge_type(&env, badge_type).ok_or(AchievementsError::BadgeTypeNotFound)
+    }
+
+    /// Get the whole catalog, retired types included, by id.
+    pub fn list_badge_types(env: Env) -> Vec<BadgeType> {
+        let mut badge_types = Vec::new(&env);
+        for id in 1..=get_badge_type_count(&env) {
+            if let Some(badge_type) = get_badge_type(&env, id) {
+                badge_types.push_back(badge_type);
+            }
+        }
+        badge_types
+    }
+
+    /// Get a minted badge.
+    /// # Errors
+    /// * [`AchievementsError::BadgeNotFound`] – no badge `token_id`.
+    pub fn get_badge(env: Env, token_id: u64) -> Result<Badge, AchievementsError> {
+        get_badge(&env, token_id).ok_or(AchievementsError::BadgeNotFound)
+    }
+
+    /// Get the holder of a badge.
+    /// # Errors
+    /// * [`AchievementsError::BadgeNotFound`] – no badge `token_id`.
+    pub fn owner_of(env: Env, token_id: u64) -> Result<Address, AchievementsError> {
+        get_badge(&env, token_id)
+            .map(|badge| badge.owner)
+            .ok_or(AchievementsError::BadgeNotFound)
+    }
+
+    /// Number of badges `owner` holds.
+    pub fn balance(env: Env, owner: Address) -> u32 {
+        get_owner_badges(&env, &owner).len()
+    }
+
+    /// Get the token ids of the badges `owner` holds, oldest first.
+    pub fn get_badges(env: Env, owner: Address) -> Vec<u64> {
+        get_owner_badges(&env, &owner)
+    }
+
+    /// Whether `owner` holds a badge of `badge_type`.
+    pub fn has_badge(env: Env, owner: Address, badge_type: u32) -> bool {
+        get_held_badge(&env, &owner, badge_type).is_some()
+    }
+
+    /// Number of badges minted so far; token ids run from 1 to this.
+    pub fn total_supply(env: Env) -> u64 {
+        get_badge_count(&env)
+    }
+}
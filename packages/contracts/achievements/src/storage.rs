@@ -0,0 +1,178 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    ReputationContract,
+    BadgeTypeCounter,
+    BadgeType(u32),
+    BadgeCounter,
+    Badge(u64),
+    /// Token ids of the badges an address holds, oldest first
+    OwnerBadges(Address),
+    /// Token id of the badge of a type an address holds: (owner, badge_type)
+    Held(Address, u32),
+}
+
+/// Reputation statistic a badge type is earned on.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BadgeMetric {
+    CallsCreated,
+    /// Resolved calls whose outcome the creator had backed
+    CallsCorrect,
+    PositionsWon,
+    /// Longest run of consecutive winning positions
+    BestWinStreak,
+    /// Stake across settled positions
+    Volume,
+}
+
+/// A badge type in the catalog.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadgeType {
+    pub id: u32,
+    pub name: String,
+    pub metric: BadgeMetric,
+    /// Value of `metric` at which the badge is earned
+    pub threshold: i128,
+    /// Retired badge types are no longer minted; minted badges remain
+    pub active: bool,
+}
+
+/// A minted badge.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Badge {
+    pub token_id: u64,
+    pub badge_type: u32,
+    pub owner: Address,
+    pub minted_at: u64,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the reputation contract milestones are read from, if initialised
+pub fn get_reputation_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ReputationContract)
+}
+
+/// Store the reputation contract milestones are read from
+pub fn set_reputation_contract(env: &Env, contract: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReputationContract, contract);
+}
+
+/// Number of badge types in the catalog; ids run from 1 to this
+pub fn get_badge_type_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BadgeTypeCounter)
+        .unwrap_or(0)
+}
+
+/// Allocate the next badge type id
+pub fn next_badge_type_id(env: &Env) -> u32 {
+    let id = get_badge_type_count(env) + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::BadgeTypeCounter, &id);
+    id
+}
+
+/// Get a badge type
+pub fn get_badge_type(env: &Env, id: u32) -> Option<BadgeType> {
+    env.storage().instance().get(&DataKey::BadgeType(id))
+}
+
+/// Store a badge type
+pub fn set_badge_type(env: &Env, badge_type: &BadgeType) {
+    env.storage()
+        .instance()
+        .set(&DataKey::BadgeType(badge_type.id), badge_type);
+}
+
+/// Number of badges minted; token ids run from 1 to this
+pub fn get_badge_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BadgeCounter)
+        .unwrap_or(0)
+}
+
+/// Allocate the next badge token id
+pub fn next_badge_id(env: &Env) -> u64 {
+    let id = get_badge_count(env) + 1;
+    env.storage().instance().set(&DataKey::BadgeCounter, &id);
+    id
+}
+
+/// Get a minted badge
+pub fn get_badge(env: &Env, token_id: u64) -> Option<Badge> {
+    env.storage().persistent().get(&DataKey::Badge(token_id))
+}
+
+/// Store a minted badge and index it by owner and type
+pub fn add_badge(env: &Env, badge: &Badge) {
+    let key = DataKey::Badge(badge.token_id);
+    env.storage().persistent().set(&key, badge);
+    bump_persistent(env, &key);
+
+    let key = DataKey::OwnerBadges(badge.owner.clone());
+    let mut ids = get_owner_badges(env, &badge.owner);
+    ids.push_back(badge.token_id);
+    env.storage().persistent().set(&key, &ids);
+    bump_persistent(env, &key);
+
+    let key = DataKey::Held(badge.owner.clone(), badge.badge_type);
+    env.storage().persistent().set(&key, &badge.token_id);
+    bump_persistent(env, &key);
+}
+
+/// Get the token ids of an address's badges, oldest first
+pub fn get_owner_badges(env: &Env, owner: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerBadges(owner.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get the token id of the badge of `badge_type` an address holds, if any
+pub fn get_held_badge(env: &Env, owner: &Address, badge_type: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Held(owner.clone(), badge_type))
+}
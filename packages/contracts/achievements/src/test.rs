@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec, Address, Env, String};
+
+use crate::errors::AchievementsError;
+use crate::{
+    AchievementsContract, AchievementsContractClient, BadgeMetric, Reputation, MAX_BADGE_TYPES,
+};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+/// Reputation contract stand-in serving records set by the test.
+#[contract]
+pub struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn set_reputation(env: Env, address: Address, reputation: Reputation) {
+        env.storage().instance().set(&address, &reputation);
+    }
+
+    pub fn get_reputation(env: Env, address: Address) -> Reputation {
+        env.storage().instance().get(&address).unwrap_or_default()
+    }
+}
+
+struct Setup {
+    env: Env,
+    client: AchievementsContractClient<'static>,
+    reputation: MockReputationClient<'static>,
+}
+
+/// Contract with the first-win, 10-win-streak and 1M-volume badges.
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let reputation = MockReputationClient::new(&env, &env.register(MockReputation, ()));
+    let client = AchievementsContractClient::new(&env, &env.register(AchievementsContract, ()));
+    client.initialize(&Address::generate(&env), &reputation.address);
+
+    for (name, metric, threshold) in [
+        ("First Win", BadgeMetric::PositionsWon, 1),
+        ("Hot Streak", BadgeMetric::BestWinStreak, 10),
+        ("Whale", BadgeMetric::Volume, 1_000_000),
+    ] {
+        client.add_badge_type(&String::from_str(&env, name), &metric, &threshold);
+    }
+
+    Setup {
+        env,
+        client,
+        reputation,
+    }
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_badges_mint_once_as_milestones_are_crossed() {
+    let s = setup();
+    let staker = Address::generate(&s.env);
+    assert_eq!(s.client.mint_if_eligible(&staker), vec![&s.env]);
+
+    let mut record = Reputation {
+        positions_won: 3,
+        best_win_streak: 3,
+        volume: 400_000,
+        ..Default::default()
+    };
+    s.reputation.set_reputation(&staker, &record);
+    assert_eq!(s.client.mint_if_eligible(&staker), vec![&s.env, 1u64]);
+    assert_eq!(s.client.mint_if_eligible(&staker), vec![&s.env]);
+
+    record.best_win_streak = 10;
+    record.volume = 1_000_000;
+    s.reputation.set_reputation(&staker, &record);
+    assert_eq!(s.client.mint_if_eligible(&staker), vec![&s.env, 2u64, 3]);
+
+    assert_eq!(s.client.balance(&staker), 3);
+    assert_eq!(s.client.get_badges(&staker), vec![&s.env, 1u64, 2, 3]);
+    assert!(s.client.has_badge(&staker, &2));
+    assert_eq!(s.client.owner_of(&3), staker);
+    assert_eq!(s.client.get_badge(&3).badge_type, 3);
+    assert_eq!(s.client.total_supply(), 3);
+}
+
+#[test]
+fn test_retired_badge_types_are_not_minted() {
+    let s = setup();
+    let staker = Address::generate(&s.env);
+    s.reputation.set_reputation(
+        &staker,
+        &Reputation {
+            positions_won: 1,
+            ..Default::default()
+        },
+    );
+
+    s.client.set_badge_type_active(&1, &false);
+    assert_eq!(s.client.mint_if_eligible(&staker), vec![&s.env]);
+    assert!(!s.client.get_badge_type(&1).active);
+
+    s.client.set_badge_type_active(&1, &true);
+    assert_eq!(s.client.mint_if_eligible(&staker), vec![&s.env, 1u64]);
+
+    let result = s.client.try_set_badge_type_active(&4, &false);
+    assert_eq!(result, Err(Ok(AchievementsError::BadgeTypeNotFound)));
+    let result = s.client.try_owner_of(&2);
+    assert_eq!(result, Err(Ok(AchievementsError::BadgeNotFound)));
+}
+
+#[test]
+fn test_catalog_rules() {
+    let s = setup();
+    let name = String::from_str(&s.env, "Pundit");
+
+    let result = s
+        .client
+        .try_add_badge_type(&name, &BadgeMetric::CallsCorrect, &0);
+    assert_eq!(result, Err(Ok(AchievementsError::InvalidThreshold)));
+
+    for _ in s.client.list_badge_types().len()..MAX_BADGE_TYPES {
+        s.client
+            .add_badge_type(&name, &BadgeMetric::CallsCorrect, &5);
+    }
+    assert_eq!(s.client.list_badge_types().len(), MAX_BADGE_TYPES);
+    let result = s
+        .client
+        .try_add_badge_type(&name, &BadgeMetric::CallsCreated, &1);
+    assert_eq!(result, Err(Ok(AchievementsError::CatalogFull)));
+}
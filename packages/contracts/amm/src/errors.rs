@@ -0,0 +1,46 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum AmmError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// A market already exists for this call.
+    MarketExists = 3,
+    /// No market exists for the supplied `call_id`.
+    MarketNotFound = 4,
+    /// `outcome_count` is outside `[2, MAX_OUTCOMES]`.
+    InvalidOutcomeCount = 5,
+    /// The liquidity parameter `b` is ≤ 0.
+    InvalidLiquidity = 6,
+    /// `close_ts` is not strictly in the future.
+    InvalidCloseTime = 7,
+    /// `outcome` is not in `[1, outcome_count]`, or the finalized outcome is
+    /// not valid for the market.
+    InvalidOutcome = 8,
+    /// The share amount is ≤ 0.
+    InvalidShares = 9,
+    /// The market is past `close_ts` or already resolved.
+    TradingClosed = 10,
+    /// The trade's cost exceeds `max_cost`, or its proceeds fall below `min_return`.
+    SlippageExceeded = 11,
+    /// The seller holds fewer shares than they are selling.
+    InsufficientShares = 12,
+    /// The OutcomeManager has not finalized an outcome for the call yet.
+    OutcomeNotFinal = 13,
+    /// The market has not been resolved yet.
+    MarketNotResolved = 14,
+    /// The caller is not the market's creator.
+    Unauthorized = 15,
+    /// The holder has no shares that pay out.
+    NothingToRedeem = 16,
+    /// The creator has already withdrawn the market's leftover funds.
+    SubsidyWithdrawn = 17,
+    /// A price, cost or balance computation overflowed.
+    Overflow = 18,
+    /// The registry has no call with this `call_id`.
+    CallNotFound = 19,
+}
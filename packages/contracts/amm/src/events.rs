@@ -0,0 +1,67 @@
+use soroban_sdk::{Address, Env};
+
+/// Emitted when a market maker opens a market on a call
+pub fn emit_market_created(
+    env: &Env,
+    call_id: u64,
+    creator: &Address,
+    liquidity: i128,
+    subsidy: i128,
+) {
+    env.events().publish(
+        ("amm", "market_created"),
+        (call_id, creator.clone(), liquidity, subsidy),
+    );
+}
+
+/// Emitted when a trader buys outcome shares
+pub fn emit_shares_bought(
+    env: &Env,
+    call_id: u64,
+    buyer: &Address,
+    outcome: u32,
+    shares: i128,
+    cost: i128,
+) {
+    env.events().publish(
+        ("amm", "shares_bought"),
+        (call_id, buyer.clone(), outcome, shares, cost),
+    );
+}
+
+/// Emitted when a trader sells outcome shares back to the market
+pub fn emit_shares_sold(
+    env: &Env,
+    call_id: u64,
+    seller: &Address,
+    outcome: u32,
+    shares: i128,
+    proceeds: i128,
+) {
+    env.events().publish(
+        ("amm", "shares_sold"),
+        (call_id, seller.clone(), outcome, shares, proceeds),
+    );
+}
+
+/// Emitted when a market adopts the OutcomeManager's finalized outcome
+pub fn emit_market_resolved(env: &Env, call_id: u64, outcome: u32) {
+    env.events()
+        .publish(("amm", "market_resolved"), (call_id, outcome));
+}
+
+/// Emitted when a holder redeems shares after resolution
+pub fn emit_shares_redeemed(env: &Env, call_id: u64, holder: &Address, payout: i128) {
+    env.events().publish(
+        ("amm", "shares_redeemed"),
+        (call_id, holder.clone(), payout),
+    );
+}
+
+/// Emitted when the market maker withdraws what is left after winners are covered
+pub fn emit_subsidy_withdrawn(env: &Env, call_id: u64, creator: &Address, amount: i128) {
+    env.events().publish(
+        ("amm", "subsidy_withdrawn"),
+        (call_id, creator.clone(), amount),
+    );
+}
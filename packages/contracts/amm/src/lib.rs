@@ -0,0 +1,560 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! LMSR automated market maker for BACKit calls.
+//!
+//! Traders buy and sell outcome shares of a registry call at prices set by a
+//! logarithmic market scoring rule, instead of joining a parimutuel pool. Once
+//! the OutcomeManager finalizes the call, each winning share redeems for one
+//! unit of the market's token (a DRAW splits every share across the outcomes).
+//! A VOID unwinds the market instead: each trader is refunded what they paid
+//! in net of sale proceeds, pro rata if the market holds less than that.
+
+mod errors;
+mod events;
+mod lmsr;
+mod math;
+mod storage;
+mod test;
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, token, Address, Env, IntoVal, Map, Symbol,
+};
+
+use backit_shared::{OUTCOME_DRAW, OUTCOME_VOID};
+use errors::AmmError;
+use events::*;
+use math::{add, mul_div, sub};
+use storage::*;
+
+pub use lmsr::SCALE;
+pub use storage::Market;
+
+/// Maximum number of outcomes a market may have.
+pub const MAX_OUTCOMES: u32 = 8;
+
+/// Mirror of the OutcomeManager's finalized `Outcome` record.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub registry: Address,
+    pub call_id: u64,
+    pub outcome: u32,
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[contract]
+pub struct Amm;
+
+/// Read the finalized outcome of `call_id` on `registry` from the
+/// OutcomeManager.
+fn finalized_outcome(
+    env: &Env,
+    outcome_manager: &Address,
+    registry: &Address,
+    call_id: u64,
+) -> Option<Outcome> {
+    let args = (registry.clone(), call_id).into_val(env);
+    env.try_invoke_contract::<Outcome, soroban_sdk::Error>(
+        outcome_manager,
+        &Symbol::new(env, "get_outcome"),
+        args,
+    )
+    .ok()
+    .and_then(|result| result.ok())
+}
+
+/// The `(outcome_count, end_ts)` of `call_id` on the registry, or `None` if
+/// the registry has no such call.
+fn registry_call(env: &Env, registry: &Address, call_id: u64) -> Option<(u32, u64)> {
+    let outcome_count = env
+        .try_invoke_contract::<u32, soroban_sdk::Error>(
+            registry,
+            &Symbol::new(env, "get_call_outcome_count"),
+            (call_id,).into_val(env),
+        )
+        .ok()?
+        .ok()?;
+    let end_ts: u64 = env.invoke_contract(
+        registry,
+        &Symbol::new(env, "get_call_end_ts"),
+        (call_id,).into_val(env),
+    );
+    Some((outcome_count, end_ts))
+}
+
+/// Load an open market and validate `outcome` and `shares` for a trade.
+fn open_market_for_trade(
+    env: &Env,
+    call_id: u64,
+    outcome: u32,
+    shares: i128,
+) -> Result<Market, AmmError> {
+    let market = get_market(env, call_id).ok_or(AmmError::MarketNotFound)?;
+    if outcome < 1 || outcome > market.outcome_count {
+        return Err(AmmError::InvalidOutcome);
+    }
+    if shares <= 0 {
+        return Err(AmmError::InvalidShares);
+    }
+    if market.resolved_outcome != 0 || env.ledger().timestamp() >= market.close_ts {
+        return Err(AmmError::TradingClosed);
+    }
+    Ok(market)
+}
+
+/// Cost of buying `shares` of `outcome`, rounded up in the market's favour.
+fn buy_cost(env: &Env, market: &Market, outcome: u32, shares: i128) -> i128 {
+    let before = lmsr::cost(
+        env,
+        &market.quantities,
+        market.outcome_count,
+        market.liquidity,
+    );
+    let mut after = market.quantities.clone();
+    after.set(outcome, add(env, after.get(outcome).unwrap_or(0), shares));
+    let after = lmsr::cost(env, &after, market.outcome_count, market.liquidity);
+    add(env, sub(env, after, before), 1)
+}
+
+/// Proceeds of selling `shares` of `outcome`, rounded down in the market's favour.
+fn sell_proceeds(env: &Env, market: &Market, outcome: u32, shares: i128) -> i128 {
+    let before = lmsr::cost(
+        env,
+        &market.quantities,
+        market.outcome_count,
+        market.liquidity,
+    );
+    let mut after = market.quantities.clone();
+    after.set(outcome, sub(env, after.get(outcome).unwrap_or(0), shares));
+    let after = lmsr::cost(env, &after, market.outcome_count, market.liquidity);
+    sub(env, sub(env, before, after), 1).max(0)
+}
+
+/// Move `trader`'s cost basis by `delta`, keeping the market's total of
+/// positive bases in step.
+fn adjust_cost_basis(env: &Env, market: &mut Market, trader: &Address, delta: i128) {
+    let basis = get_cost_basis(env, market.call_id, trader);
+    let updated = add(env, basis, delta);
+    market.total_cost_basis = add(
+        env,
+        sub(env, market.total_cost_basis, basis.max(0)),
+        updated.max(0),
+    );
+    set_cost_basis(env, market.call_id, trader, updated);
+}
+
+/// Tokens still owed to holders of a resolved market's outstanding shares,
+/// or to traders not yet refunded from a voided one.
+fn outstanding_liability(env: &Env, market: &Market) -> i128 {
+    if market.resolved_outcome == OUTCOME_VOID {
+        mul_div(env, market.total_cost_basis, market.refund_rate, SCALE)
+    } else if market.resolved_outcome == OUTCOME_DRAW {
+        let n = market.outcome_count as i128;
+        let total = market
+            .quantities
+            .values()
+            .iter()
+            .fold(0, |total, quantity| add(env, total, quantity));
+        add(env, total, n - 1) / n
+    } else {
+        market.quantities.get(market.resolved_outcome).unwrap_or(0)
+    }
+}
+
+#[contractimpl]
+impl Amm {
+    /// Initialise the contract with an admin, the CallRegistry whose calls
+    /// markets are opened on, and the OutcomeManager whose finalized
+    /// outcomes settle them.
+    /// # Errors
+    /// * [`AmmError::AlreadyInitialized`] – called more than once.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        outcome_manager: Address,
+        registry: Address,
+    ) -> Result<(), AmmError> {
+        if get_admin(&env).is_some() {
+            return Err(AmmError::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_outcome_manager(&env, &outcome_manager);
+        set_registry(&env, &registry);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Replace the OutcomeManager (admin only).
+    /// # Errors
+    /// * [`AmmError::NotInitialized`] – contract not initialised.
+    pub fn set_outcome_manager(env: Env, outcome_manager: Address) -> Result<(), AmmError> {
+        let admin = get_admin(&env).ok_or(AmmError::NotInitialized)?;
+        admin.require_auth();
+
+        set_outcome_manager(&env, &outcome_manager);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Open a market on registry call `call_id`. The creator deposits the
+    /// maker subsidy, `b · ln(outcome_count)`, which bounds the market
+    /// maker's loss.
+    /// # Errors
+    /// * [`AmmError::NotInitialized`]      – contract not initialised.
+    /// * [`AmmError::MarketExists`]        – the call already has a market.
+    /// * [`AmmError::CallNotFound`]        – the registry has no such call.
+    /// * [`AmmError::InvalidOutcomeCount`] – `outcome_count` ∉ [2, `MAX_OUTCOMES`],
+    ///   or differs from the call's.
+    /// * [`AmmError::InvalidLiquidity`]    – `liquidity` ≤ 0.
+    /// * [`AmmError::InvalidCloseTime`]    – `close_ts` is not in the future, or
+    ///   is after the call's `end_ts`.
+    pub fn create_market(
+        env: Env,
+        creator: Address,
+        call_id: u64,
+        token: Address,
+        outcome_count: u32,
+        liquidity: i128,
+        close_ts: u64,
+    ) -> Result<Market, AmmError> {
+        creator.require_auth();
+
+        let registry = get_registry(&env).ok_or(AmmError::NotInitialized)?;
+        if get_market(&env, call_id).is_some() {
+            return Err(AmmError::MarketExists);
+        }
+        let (call_outcome_count, call_end_ts) =
+            registry_call(&env, &registry, call_id).ok_or(AmmError::CallNotFound)?;
+        if !(2..=MAX_OUTCOMES).contains(&outcome_count) || outcome_count != call_outcome_count {
+            return Err(AmmError::InvalidOutcomeCount);
+        }
+        if liquidity <= 0 {
+            return Err(AmmError::InvalidLiquidity);
+        }
+        if close_ts <= env.ledger().timestamp() || close_ts > call_end_ts {
+            return Err(AmmError::InvalidCloseTime);
+        }
+
+        let subsidy = lmsr::subsidy(&env, outcome_count, liquidity);
+        token::Client::new(&env, &token).transfer(
+            &creator,
+            env.current_contract_address(),
+            &subsidy,
+        );
+
+        let market = Market {
+            call_id,
+            creator: creator.clone(),
+            token,
+            outcome_count,
+            liquidity,
+            quantities: Map::new(&env),
+            balance: subsidy,
+            close_ts,
+            resolved_outcome: 0,
+            subsidy_withdrawn: false,
+            total_cost_basis: 0,
+            refund_rate: 0,
+        };
+        set_market(&env, &market);
+        extend_instance_ttl(&env);
+
+        emit_market_created(&env, call_id, &creator, liquidity, subsidy);
+
+        Ok(market)
+    }
+
+    /// Get a market by call ID.
+    /// # Errors
+    /// * [`AmmError::MarketNotFound`] – no market for `call_id`.
+    pub fn get_market(env: Env, call_id: u64) -> Result<Market, AmmError> {
+        get_market(&env, call_id).ok_or(AmmError::MarketNotFound)
+    }
+
+    /// Current price of `outcome`, scaled by [`SCALE`]. Prices across all
+    /// outcomes sum to `SCALE` and read as implied probabilities.
+    /// # Errors
+    /// * [`AmmError::MarketNotFound`] – no market for `call_id`.
+    /// * [`AmmError::InvalidOutcome`] – `outcome` ∉ [1, outcome_count].
+    pub fn get_price(env: Env, call_id: u64, outcome: u32) -> Result<i128, AmmError> {
+        let market = get_market(&env, call_id).ok_or(AmmError::MarketNotFound)?;
+        if outcome < 1 || outcome > market.outcome_count {
+            return Err(AmmError::InvalidOutcome);
+        }
+        Ok(lmsr::price(
+            &env,
+            &market.quantities,
+            market.outcome_count,
+            market.liquidity,
+            outcome,
+        ))
+    }
+
+    /// Cost of buying `shares` of `outcome` at the current state.
+    /// # Errors
+    /// See [`buy`](Self::buy).
+    pub fn quote_buy(env: Env, call_id: u64, outcome: u32, shares: i128) -> Result<i128, AmmError> {
+        let market = open_market_for_trade(&env, call_id, outcome, shares)?;
+        Ok(buy_cost(&env, &market, outcome, shares))
+    }
+
+    /// Proceeds of selling `shares` of `outcome` at the current state.
+    /// # Errors
+    /// See [`sell`](Self::sell).
+    pub fn quote_sell(
+        env: Env,
+        call_id: u64,
+        outcome: u32,
+        shares: i128,
+    ) -> Result<i128, AmmError> {
+        let market = open_market_for_trade(&env, call_id, outcome, shares)?;
+        Ok(sell_proceeds(&env, &market, outcome, shares))
+    }
+
+    /// Buy `shares` of `outcome`, paying at most `max_cost`. Returns the cost.
+    /// # Errors
+    /// * [`AmmError::MarketNotFound`]   – no market for `call_id`.
+    /// * [`AmmError::InvalidOutcome`]   – `outcome` ∉ [1, outcome_count].
+    /// * [`AmmError::InvalidShares`]    – `shares` ≤ 0.
+    /// * [`AmmError::TradingClosed`]    – past `close_ts` or resolved.
+    /// * [`AmmError::SlippageExceeded`] – cost > `max_cost`.
+    pub fn buy(
+        env: Env,
+        buyer: Address,
+        call_id: u64,
+        outcome: u32,
+        shares: i128,
+        max_cost: i128,
+    ) -> Result<i128, AmmError> {
+        buyer.require_auth();
+
+        let mut market = open_market_for_trade(&env, call_id, outcome, shares)?;
+        let cost = buy_cost(&env, &market, outcome, shares);
+        if cost > max_cost {
+            return Err(AmmError::SlippageExceeded);
+        }
+
+        token::Client::new(&env, &market.token).transfer(
+            &buyer,
+            env.current_contract_address(),
+            &cost,
+        );
+
+        let outstanding = market.quantities.get(outcome).unwrap_or(0);
+        market
+            .quantities
+            .set(outcome, add(&env, outstanding, shares));
+        market.balance = add(&env, market.balance, cost);
+        adjust_cost_basis(&env, &mut market, &buyer, cost);
+        set_market(&env, &market);
+
+        let held = get_shares(&env, call_id, outcome, &buyer);
+        set_shares(&env, call_id, outcome, &buyer, add(&env, held, shares));
+
+        emit_shares_bought(&env, call_id, &buyer, outcome, shares, cost);
+
+        Ok(cost)
+    }
+
+    /// Sell `shares` of `outcome` back to the market, receiving at least
+    /// `min_return`. Returns the proceeds.
+    /// # Errors
+    /// * [`AmmError::InsufficientShares`] – the seller holds fewer than `shares`.
+    /// * [`AmmError::SlippageExceeded`]   – proceeds < `min_return`.
+    /// * Any other error listed for [`buy`](Self::buy).
+    pub fn sell(
+        env: Env,
+        seller: Address,
+        call_id: u64,
+        outcome: u32,
+        shares: i128,
+        min_return: i128,
+    ) -> Result<i128, AmmError> {
+        seller.require_auth();
+
+        let mut market = open_market_for_trade(&env, call_id, outcome, shares)?;
+        let held = get_shares(&env, call_id, outcome, &seller);
+        if held < shares {
+            return Err(AmmError::InsufficientShares);
+        }
+        let proceeds = sell_proceeds(&env, &market, outcome, shares);
+        if proceeds < min_return {
+            return Err(AmmError::SlippageExceeded);
+        }
+
+        let outstanding = market.quantities.get(outcome).unwrap_or(0);
+        market
+            .quantities
+            .set(outcome, sub(&env, outstanding, shares));
+        market.balance = sub(&env, market.balance, proceeds);
+        adjust_cost_basis(&env, &mut market, &seller, -proceeds);
+        set_market(&env, &market);
+        set_shares(&env, call_id, outcome, &seller, held - shares);
+
+        if proceeds > 0 {
+            token::Client::new(&env, &market.token).transfer(
+                &env.current_contract_address(),
+                &seller,
+                &proceeds,
+            );
+        }
+
+        emit_shares_sold(&env, call_id, &seller, outcome, shares, proceeds);
+
+        Ok(proceeds)
+    }
+
+    /// Get a holder's shares of one outcome.
+    pub fn get_shares(env: Env, call_id: u64, outcome: u32, holder: Address) -> i128 {
+        get_shares(&env, call_id, outcome, &holder)
+    }
+
+    /// Settle the market on the OutcomeManager's finalized outcome for the
+    /// call. Callable by anyone. Returns the outcome. A VOID fixes the share
+    /// of their cost basis traders are refunded: all of it, or as much as
+    /// the market's balance covers pro rata.
+    /// # Errors
+    /// * [`AmmError::NotInitialized`]  – contract not initialised.
+    /// * [`AmmError::MarketNotFound`]  – no market for `call_id`.
+    /// * [`AmmError::TradingClosed`]   – already resolved.
+    /// * [`AmmError::OutcomeNotFinal`] – the OutcomeManager has no final outcome.
+    /// * [`AmmError::InvalidOutcome`]  – the outcome is out of range for the market.
+    pub fn resolve_market(env: Env, call_id: u64) -> Result<u32, AmmError> {
+        let outcome_manager = get_outcome_manager(&env).ok_or(AmmError::NotInitialized)?;
+        let registry = get_registry(&env).ok_or(AmmError::NotInitialized)?;
+        let mut market = get_market(&env, call_id).ok_or(AmmError::MarketNotFound)?;
+        if market.resolved_outcome != 0 {
+            return Err(AmmError::TradingClosed);
+        }
+
+        let outcome = finalized_outcome(&env, &outcome_manager, &registry, call_id)
+            .ok_or(AmmError::OutcomeNotFinal)?
+            .outcome;
+        let is_draw = market.outcome_count == 2 && outcome == OUTCOME_DRAW;
+        if outcome == OUTCOME_VOID {
+            market.refund_rate = if market.balance >= market.total_cost_basis {
+                SCALE
+            } else {
+                mul_div(&env, market.balance, SCALE, market.total_cost_basis)
+            };
+        } else if !is_draw && (outcome < 1 || outcome > market.outcome_count) {
+            return Err(AmmError::InvalidOutcome);
+        }
+
+        market.resolved_outcome = outcome;
+        set_market(&env, &market);
+
+        emit_market_resolved(&env, call_id, outcome);
+
+        Ok(outcome)
+    }
+
+    /// Redeem all of `holder`'s shares in a resolved market: one token per
+    /// winning share, or every share divided across the outcomes on a DRAW.
+    /// Losing shares are burned. On a VOID every share is burned and the
+    /// holder is refunded their cost basis at the market's refund rate.
+    /// Returns the payout.
+    /// # Errors
+    /// * [`AmmError::MarketNotFound`]    – no market for `call_id`.
+    /// * [`AmmError::MarketNotResolved`] – the market is unresolved.
+    /// * [`AmmError::NothingToRedeem`]   – the holder has no shares, nor on a
+    ///   VOID any cost basis.
+    pub fn redeem(env: Env, holder: Address, call_id: u64) -> Result<i128, AmmError> {
+        holder.require_auth();
+
+        let mut market = get_market(&env, call_id).ok_or(AmmError::MarketNotFound)?;
+        if market.resolved_outcome == 0 {
+            return Err(AmmError::MarketNotResolved);
+        }
+
+        let mut held_total = 0;
+        let mut winning = 0;
+        for outcome in 1..=market.outcome_count {
+            let held = get_shares(&env, call_id, outcome, &holder);
+            if held == 0 {
+                continue;
+            }
+            held_total = add(&env, held_total, held);
+            if outcome == market.resolved_outcome {
+                winning = held;
+            }
+            let outstanding = market.quantities.get(outcome).unwrap_or(0);
+            market.quantities.set(outcome, sub(&env, outstanding, held));
+            set_shares(&env, call_id, outcome, &holder, 0);
+        }
+        let basis = get_cost_basis(&env, call_id, &holder).max(0);
+        let voided = market.resolved_outcome == OUTCOME_VOID;
+        if held_total == 0 && !(voided && basis > 0) {
+            return Err(AmmError::NothingToRedeem);
+        }
+
+        let payout = if voided {
+            market.total_cost_basis = sub(&env, market.total_cost_basis, basis);
+            set_cost_basis(&env, call_id, &holder, 0);
+            mul_div(&env, basis, market.refund_rate, SCALE)
+        } else if market.resolved_outcome == OUTCOME_DRAW {
+            held_total / market.outcome_count as i128
+        } else {
+            winning
+        };
+        market.balance = sub(&env, market.balance, payout);
+        set_market(&env, &market);
+
+        if payout > 0 {
+            token::Client::new(&env, &market.token).transfer(
+                &env.current_contract_address(),
+                &holder,
+                &payout,
+            );
+        }
+
+        emit_shares_redeemed(&env, call_id, &holder, payout);
+
+        Ok(payout)
+    }
+
+    /// Withdraw what the market holds beyond the payouts still owed to
+    /// outstanding shares (creator only, once). Returns the amount withdrawn.
+    /// # Errors
+    /// * [`AmmError::MarketNotFound`]    – no market for `call_id`.
+    /// * [`AmmError::Unauthorized`]      – `creator` did not open the market.
+    /// * [`AmmError::MarketNotResolved`] – the market is unresolved.
+    /// * [`AmmError::SubsidyWithdrawn`]  – already withdrawn.
+    pub fn withdraw_subsidy(env: Env, creator: Address, call_id: u64) -> Result<i128, AmmError> {
+        creator.require_auth();
+
+        let mut market = get_market(&env, call_id).ok_or(AmmError::MarketNotFound)?;
+        if market.creator != creator {
+            return Err(AmmError::Unauthorized);
+        }
+        if market.resolved_outcome == 0 {
+            return Err(AmmError::MarketNotResolved);
+        }
+        if market.subsidy_withdrawn {
+            return Err(AmmError::SubsidyWithdrawn);
+        }
+
+        let amount = sub(&env, market.balance, outstanding_liability(&env, &market)).max(0);
+        market.balance = sub(&env, market.balance, amount);
+        market.subsidy_withdrawn = true;
+        set_market(&env, &market);
+
+        if amount > 0 {
+            token::Client::new(&env, &market.token).transfer(
+                &env.current_contract_address(),
+                &creator,
+                &amount,
+            );
+        }
+
+        emit_subsidy_withdrawn(&env, call_id, &creator, amount);
+
+        Ok(amount)
+    }
+}
@@ -0,0 +1,126 @@
+//! Fixed-point logarithmic market scoring rule.
+//!
+//! Soroban contracts cannot use floating point, so `exp` and `ln` are
+//! evaluated on `i128` values scaled by [`SCALE`]. All arithmetic is
+//! checked, products of unbounded inputs are taken in [`I256`], and a result
+//! that does not fit `i128` fails with [`crate::errors::AmmError::Overflow`].
+
+use soroban_sdk::{Env, Map, I256};
+
+use crate::math::{add, mul, mul_div, overflow, sub};
+
+/// Fixed-point scale: `SCALE` represents 1.0.
+pub const SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// ln 2 scaled by [`SCALE`].
+const LN2: i128 = 693_147_180_559_945_309;
+
+/// e^x underflows [`SCALE`] for x below −42.
+const EXP_CUTOFF: i128 = 42 * SCALE;
+
+/// e^x for fixed-point `x ≤ 0`, computed as e^r · 2^−k with r ∈ (−ln 2, 0].
+pub fn exp_neg(env: &Env, x: i128) -> i128 {
+    if x >= 0 {
+        return SCALE;
+    }
+    if x <= -EXP_CUTOFF {
+        return 0;
+    }
+
+    let k = -x / LN2;
+    let r = add(env, x, mul(env, k, LN2));
+
+    let mut term = SCALE;
+    let mut sum = SCALE;
+    let mut i: i128 = 1;
+    while term != 0 {
+        term = mul(env, term, r) / (i * SCALE);
+        sum = add(env, sum, term);
+        i += 1;
+    }
+    sum >> k
+}
+
+/// ln y for fixed-point `y ≥ 1`, computed as k · ln 2 + 2 · atanh((m − 1)/(m + 1))
+/// with y = m · 2^k and m ∈ [1, 2).
+pub fn ln(env: &Env, y: i128) -> i128 {
+    let mut m = y;
+    let mut k: i128 = 0;
+    while m >= 2 * SCALE {
+        m /= 2;
+        k += 1;
+    }
+
+    let z = mul(env, sub(env, m, SCALE), SCALE) / add(env, m, SCALE);
+    let z2 = mul(env, z, z) / SCALE;
+    let mut term = z;
+    let mut sum = z;
+    let mut n = 1;
+    while term != 0 {
+        term = mul(env, term, z2) / SCALE;
+        n += 2;
+        sum = add(env, sum, term / n);
+    }
+    add(env, mul(env, 2, sum), mul(env, k, LN2))
+}
+
+/// e^((q − max)/b) for a quantity `q ≤ max`. Exponents too negative for
+/// `i128` underflow to 0 like any other below the cutoff.
+fn shifted_exp(env: &Env, q: i128, max: i128, b: i128) -> i128 {
+    let exponent = I256::from_i128(env, q)
+        .sub(&I256::from_i128(env, max))
+        .mul(&I256::from_i128(env, SCALE))
+        .div(&I256::from_i128(env, b));
+    if exponent <= I256::from_i128(env, -EXP_CUTOFF) {
+        return 0;
+    }
+    exp_neg(env, exponent.to_i128().unwrap_or_else(|| overflow(env)))
+}
+
+/// Quantity of the most-held outcome and Σ e^((q_i − max)/b), shifting every
+/// exponent to ≤ 0 so the sum lies in [1, n].
+fn shifted_exp_sum(
+    env: &Env,
+    quantities: &Map<u32, i128>,
+    outcome_count: u32,
+    b: i128,
+) -> (i128, i128) {
+    let max = (1..=outcome_count)
+        .map(|i| quantities.get(i).unwrap_or(0))
+        .max()
+        .unwrap_or(0);
+    let sum = (1..=outcome_count).fold(0, |sum, i| {
+        add(
+            env,
+            sum,
+            shifted_exp(env, quantities.get(i).unwrap_or(0), max, b),
+        )
+    });
+    (max, sum)
+}
+
+/// Cost function C(q) = b · ln Σ e^(q_i/b), rounded down.
+pub fn cost(env: &Env, quantities: &Map<u32, i128>, outcome_count: u32, b: i128) -> i128 {
+    let (max, sum) = shifted_exp_sum(env, quantities, outcome_count, b);
+    add(env, max, mul_div(env, b, ln(env, sum), SCALE))
+}
+
+/// Instantaneous price of `outcome`, e^(q_i/b) / Σ e^(q_j/b), scaled by [`SCALE`].
+pub fn price(
+    env: &Env,
+    quantities: &Map<u32, i128>,
+    outcome_count: u32,
+    b: i128,
+    outcome: u32,
+) -> i128 {
+    let (max, sum) = shifted_exp_sum(env, quantities, outcome_count, b);
+    let weight = shifted_exp(env, quantities.get(outcome).unwrap_or(0), max, b);
+    mul_div(env, weight, SCALE, sum)
+}
+
+/// Funds the market maker must deposit to cover its worst-case loss,
+/// b · ln n, rounded up.
+pub fn subsidy(env: &Env, outcome_count: u32, b: i128) -> i128 {
+    let ln_n = ln(env, outcome_count as i128 * SCALE);
+    add(env, mul_div(env, b, ln_n, SCALE), 1)
+}
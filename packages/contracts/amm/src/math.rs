@@ -0,0 +1,40 @@
+use soroban_sdk::{panic_with_error, Env, I256};
+
+use crate::errors::AmmError;
+
+/// Fail with [`AmmError::Overflow`].
+pub fn overflow(env: &Env) -> ! {
+    panic_with_error!(env, AmmError::Overflow)
+}
+
+/// `a + b` for share quantities and token amounts.
+/// # Panics
+/// * [`AmmError::Overflow`] – the sum does not fit in `i128`.
+pub fn add(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b).unwrap_or_else(|| overflow(env))
+}
+
+/// `a - b` for share quantities and token amounts.
+/// # Panics
+/// * [`AmmError::Overflow`] – the difference does not fit in `i128`.
+pub fn sub(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_sub(b).unwrap_or_else(|| overflow(env))
+}
+
+/// `a * b` for fixed-point terms whose product is known to be bounded.
+/// # Panics
+/// * [`AmmError::Overflow`] – the product does not fit in `i128`.
+pub fn mul(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_mul(b).unwrap_or_else(|| overflow(env))
+}
+
+/// `a * b / c`, rounded towards zero, with a 256-bit intermediate product.
+/// # Panics
+/// * [`AmmError::Overflow`] – the result does not fit in `i128`.
+pub fn mul_div(env: &Env, a: i128, b: i128, c: i128) -> i128 {
+    I256::from_i128(env, a)
+        .mul(&I256::from_i128(env, b))
+        .div(&I256::from_i128(env, c))
+        .to_i128()
+        .unwrap_or_else(|| overflow(env))
+}
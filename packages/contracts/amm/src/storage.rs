@@ -0,0 +1,152 @@
+use soroban_sdk::{contracttype, Address, Env, Map};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    OutcomeManager,
+    /// CallRegistry whose calls markets are opened on
+    Registry,
+    Market(u64),
+    Shares(u64, u32, Address),
+    /// Tokens a trader paid into a market, net of sale proceeds
+    CostBasis(u64, Address),
+}
+
+/// An LMSR market on the outcomes of a registry call.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Market {
+    /// Registry call whose finalized outcome settles the market
+    pub call_id: u64,
+    /// Market maker who funded the subsidy
+    pub creator: Address,
+    /// Token trades are settled in; each winning share pays one unit
+    pub token: Address,
+    pub outcome_count: u32,
+    /// LMSR liquidity parameter `b`; larger values move prices less per trade
+    pub liquidity: i128,
+    /// Map of outcome indices to outstanding shares
+    pub quantities: Map<u32, i128>,
+    /// Tokens held for this market: subsidy plus net trading proceeds
+    pub balance: i128,
+    /// Trading stops at this timestamp
+    pub close_ts: u64,
+    /// Finalized outcome: 0 = unresolved
+    pub resolved_outcome: u32,
+    /// Whether the creator has withdrawn the funds left after winners are covered
+    pub subsidy_withdrawn: bool,
+    /// Sum of every trader's positive cost basis, refunded if the call is voided
+    pub total_cost_basis: i128,
+    /// Share of its cost basis, scaled by [`crate::SCALE`], a trader is
+    /// refunded on a voided market; below 1.0 if the balance falls short
+    pub refund_rate: i128,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Get the OutcomeManager address, if initialised
+pub fn get_outcome_manager(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::OutcomeManager)
+}
+
+/// Store the OutcomeManager address
+pub fn set_outcome_manager(env: &Env, outcome_manager: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::OutcomeManager, outcome_manager);
+}
+
+/// Get the CallRegistry address, if initialised
+pub fn get_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Registry)
+}
+
+/// Store the CallRegistry address
+pub fn set_registry(env: &Env, registry: &Address) {
+    env.storage().instance().set(&DataKey::Registry, registry);
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get a market by call ID
+pub fn get_market(env: &Env, call_id: u64) -> Option<Market> {
+    env.storage().persistent().get(&DataKey::Market(call_id))
+}
+
+/// Store a market
+pub fn set_market(env: &Env, market: &Market) {
+    let key = DataKey::Market(market.call_id);
+    env.storage().persistent().set(&key, market);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get a holder's shares of one outcome
+pub fn get_shares(env: &Env, call_id: u64, outcome: u32, holder: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Shares(call_id, outcome, holder.clone()))
+        .unwrap_or(0)
+}
+
+/// Store a holder's shares of one outcome
+pub fn set_shares(env: &Env, call_id: u64, outcome: u32, holder: &Address, shares: i128) {
+    let key = DataKey::Shares(call_id, outcome, holder.clone());
+    if shares == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &shares);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get a trader's cost basis in a market
+pub fn get_cost_basis(env: &Env, call_id: u64, trader: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CostBasis(call_id, trader.clone()))
+        .unwrap_or(0)
+}
+
+/// Store a trader's cost basis in a market
+pub fn set_cost_basis(env: &Env, call_id: u64, trader: &Address, basis: i128) {
+    let key = DataKey::CostBasis(call_id, trader.clone());
+    if basis == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &basis);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
@@ -0,0 +1,307 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, testutils::Ledger as _, token, Address, Env,
+};
+
+use crate::errors::AmmError;
+use crate::{lmsr, Amm, AmmClient, Outcome, SCALE};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+/// OutcomeManager stand-in whose finalized outcomes are set by the test.
+#[contract]
+pub struct MockOutcomeManager;
+
+#[contractimpl]
+impl MockOutcomeManager {
+    pub fn set_outcome(env: Env, registry: Address, call_id: u64, outcome: u32) {
+        let record = Outcome {
+            registry: registry.clone(),
+            call_id,
+            outcome,
+            price: 0,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&(registry, call_id), &record);
+    }
+
+    pub fn get_outcome(env: Env, registry: Address, call_id: u64) -> Outcome {
+        env.storage()
+            .instance()
+            .get(&(registry, call_id))
+            .expect("outcome not finalized")
+    }
+}
+
+/// CallRegistry stand-in holding the outcome count and end of each call.
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_call(env: Env, call_id: u64, outcome_count: u32, end_ts: u64) {
+        env.storage()
+            .instance()
+            .set(&call_id, &(outcome_count, end_ts));
+    }
+
+    pub fn get_call_outcome_count(env: Env, call_id: u64) -> u32 {
+        Self::call(&env, call_id).0
+    }
+
+    pub fn get_call_end_ts(env: Env, call_id: u64) -> u64 {
+        Self::call(&env, call_id).1
+    }
+}
+
+impl MockRegistry {
+    fn call(env: &Env, call_id: u64) -> (u32, u64) {
+        env.storage()
+            .instance()
+            .get(&call_id)
+            .expect("call not found")
+    }
+}
+
+const CALL_ID: u64 = 42;
+const LIQUIDITY: i128 = 100_000_000;
+
+struct Setup {
+    env: Env,
+    client: AmmClient<'static>,
+    om: MockOutcomeManagerClient<'static>,
+    registry: MockRegistryClient<'static>,
+    token: token::Client<'static>,
+    creator: Address,
+    trader: Address,
+}
+
+/// Register the AMM, a mock OutcomeManager and registry and a SAC token,
+/// fund a creator and a trader, and open a binary market on `CALL_ID`
+/// closing at t = 2000, when the call ends.
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000);
+
+    let admin = Address::generate(&env);
+    let om_id = env.register(MockOutcomeManager, ());
+    let registry = MockRegistryClient::new(&env, &env.register(MockRegistry, ()));
+    registry.set_call(&CALL_ID, &2, &2000);
+    let client = AmmClient::new(&env, &env.register(Amm, ()));
+    client.initialize(&admin, &om_id, &registry.address);
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let creator = Address::generate(&env);
+    let trader = Address::generate(&env);
+    let sac = token::StellarAssetClient::new(&env, &token_id);
+    sac.mint(&creator, &1_000_000_000);
+    sac.mint(&trader, &1_000_000_000);
+
+    client.create_market(&creator, &CALL_ID, &token_id, &2, &LIQUIDITY, &2000);
+
+    Setup {
+        om: MockOutcomeManagerClient::new(&env, &om_id),
+        registry,
+        token: token::Client::new(&env, &token_id),
+        env,
+        client,
+        creator,
+        trader,
+    }
+}
+
+// ─── Fixed-point math ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_exp_and_ln_match_reference_values() {
+    let env = Env::default();
+    // e^-1 = 0.367879441171442321...
+    assert!((lmsr::exp_neg(&env, -SCALE) - 367_879_441_171_442_321).abs() < 1_000);
+    // ln 2 = 0.693147180559945309...
+    assert!((lmsr::ln(&env, 2 * SCALE) - 693_147_180_559_945_309).abs() < 1_000);
+    // ln 10 = 2.302585092994045684...
+    assert!((lmsr::ln(&env, 10 * SCALE) - 2_302_585_092_994_045_684).abs() < 1_000);
+    assert_eq!(lmsr::exp_neg(&env, 0), SCALE);
+    assert_eq!(lmsr::exp_neg(&env, -100 * SCALE), 0);
+}
+
+#[test]
+fn test_quantities_past_i128_fail_with_overflow() {
+    let s = setup();
+    s.client
+        .buy(&s.trader, &CALL_ID, &1, &LIQUIDITY, &i128::MAX);
+
+    let result = s.client.try_quote_buy(&CALL_ID, &1, &i128::MAX);
+    assert_eq!(result, Err(Ok(AmmError::Overflow)));
+    // A far larger position elsewhere only drives the other weights to 0
+    let cost = s.client.quote_buy(&CALL_ID, &2, &(i128::MAX / 2));
+    assert!(cost < i128::MAX / 2 && cost > i128::MAX / 2 - 2 * LIQUIDITY);
+}
+
+// ─── Market lifecycle ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_create_market_takes_subsidy_and_quotes_even_odds() {
+    let s = setup();
+
+    // b · ln 2, rounded up
+    let market = s.client.get_market(&CALL_ID);
+    assert_eq!(market.balance, 69_314_719);
+    assert_eq!(s.token.balance(&s.creator), 1_000_000_000 - 69_314_719);
+    assert_eq!(s.client.get_price(&CALL_ID, &1), SCALE / 2);
+
+    let result = s.client.try_create_market(
+        &s.creator,
+        &CALL_ID,
+        &s.token.address,
+        &2,
+        &LIQUIDITY,
+        &2000,
+    );
+    assert_eq!(result, Err(Ok(AmmError::MarketExists)));
+    let result =
+        s.client
+            .try_create_market(&s.creator, &7, &s.token.address, &2, &LIQUIDITY, &2000);
+    assert_eq!(result, Err(Ok(AmmError::CallNotFound)));
+
+    // The market must match the registry call it is opened on
+    s.registry.set_call(&7, &3, &3000);
+    let result =
+        s.client
+            .try_create_market(&s.creator, &7, &s.token.address, &2, &LIQUIDITY, &3000);
+    assert_eq!(result, Err(Ok(AmmError::InvalidOutcomeCount)));
+    let result =
+        s.client
+            .try_create_market(&s.creator, &7, &s.token.address, &3, &LIQUIDITY, &3001);
+    assert_eq!(result, Err(Ok(AmmError::InvalidCloseTime)));
+    s.client
+        .create_market(&s.creator, &7, &s.token.address, &3, &LIQUIDITY, &3000);
+}
+
+#[test]
+fn test_buy_raises_price_and_sell_returns_less_than_cost() {
+    let s = setup();
+
+    // b · ln((e + 1) / 2) ≈ 62_011_450
+    let cost = s
+        .client
+        .buy(&s.trader, &CALL_ID, &1, &LIQUIDITY, &70_000_000);
+    assert!((cost - 62_011_450).abs() <= 2);
+    assert!(s.client.get_price(&CALL_ID, &1) > SCALE * 7 / 10);
+    assert_eq!(s.client.get_shares(&CALL_ID, &1, &s.trader), LIQUIDITY);
+
+    let quote = s.client.quote_sell(&CALL_ID, &1, &LIQUIDITY);
+    let proceeds = s.client.sell(&s.trader, &CALL_ID, &1, &LIQUIDITY, &quote);
+    assert!(proceeds < cost && cost - proceeds <= 3);
+    assert_eq!(s.client.get_price(&CALL_ID, &1), SCALE / 2);
+}
+
+#[test]
+fn test_trades_reject_slippage_oversell_and_closed_market() {
+    let s = setup();
+
+    let result = s
+        .client
+        .try_buy(&s.trader, &CALL_ID, &1, &LIQUIDITY, &50_000_000);
+    assert_eq!(result, Err(Ok(AmmError::SlippageExceeded)));
+    let result = s.client.try_sell(&s.trader, &CALL_ID, &1, &1, &0);
+    assert_eq!(result, Err(Ok(AmmError::InsufficientShares)));
+    let result = s.client.try_buy(&s.trader, &CALL_ID, &3, &1, &i128::MAX);
+    assert_eq!(result, Err(Ok(AmmError::InvalidOutcome)));
+
+    s.env.ledger().set_timestamp(2000);
+    let result = s.client.try_buy(&s.trader, &CALL_ID, &1, &1, &i128::MAX);
+    assert_eq!(result, Err(Ok(AmmError::TradingClosed)));
+}
+
+#[test]
+fn test_resolved_market_pays_winners_and_returns_remainder_to_creator() {
+    let s = setup();
+    let loser = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&loser, &1_000_000_000);
+
+    s.client
+        .buy(&s.trader, &CALL_ID, &1, &50_000_000, &i128::MAX);
+    s.client.buy(&loser, &CALL_ID, &2, &80_000_000, &i128::MAX);
+
+    let result = s.client.try_resolve_market(&CALL_ID);
+    assert_eq!(result, Err(Ok(AmmError::OutcomeNotFinal)));
+
+    s.om.set_outcome(&s.registry.address, &CALL_ID, &1);
+    assert_eq!(s.client.resolve_market(&CALL_ID), 1);
+
+    assert_eq!(s.client.redeem(&s.trader, &CALL_ID), 50_000_000);
+    assert_eq!(s.client.redeem(&loser, &CALL_ID), 0);
+    let result = s.client.try_redeem(&s.trader, &CALL_ID);
+    assert_eq!(result, Err(Ok(AmmError::NothingToRedeem)));
+
+    let remainder = s.client.withdraw_subsidy(&s.creator, &CALL_ID);
+    assert!(remainder > 0);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    let result = s.client.try_withdraw_subsidy(&s.creator, &CALL_ID);
+    assert_eq!(result, Err(Ok(AmmError::SubsidyWithdrawn)));
+}
+
+#[test]
+fn test_creator_withdrawal_keeps_unredeemed_winnings() {
+    let s = setup();
+    s.client
+        .buy(&s.trader, &CALL_ID, &2, &LIQUIDITY, &i128::MAX);
+
+    s.om.set_outcome(&s.registry.address, &CALL_ID, &2);
+    s.client.resolve_market(&CALL_ID);
+
+    let result = s.client.try_withdraw_subsidy(&s.trader, &CALL_ID);
+    assert_eq!(result, Err(Ok(AmmError::Unauthorized)));
+    s.client.withdraw_subsidy(&s.creator, &CALL_ID);
+    assert_eq!(s.token.balance(&s.client.address), LIQUIDITY);
+    assert_eq!(s.client.redeem(&s.trader, &CALL_ID), LIQUIDITY);
+}
+
+#[test]
+fn test_draw_splits_every_share_across_outcomes() {
+    let s = setup();
+    s.client
+        .buy(&s.trader, &CALL_ID, &1, &30_000_000, &i128::MAX);
+    s.client
+        .buy(&s.trader, &CALL_ID, &2, &10_000_000, &i128::MAX);
+
+    s.om.set_outcome(&s.registry.address, &CALL_ID, &backit_shared::OUTCOME_DRAW);
+    s.client.resolve_market(&CALL_ID);
+    assert_eq!(s.client.redeem(&s.trader, &CALL_ID), 20_000_000);
+}
+
+#[test]
+fn test_void_refunds_cost_basis_and_returns_subsidy() {
+    let s = setup();
+    let other = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&other, &1_000_000_000);
+
+    let paid = s
+        .client
+        .buy(&s.trader, &CALL_ID, &1, &50_000_000, &i128::MAX);
+    let other_paid = s.client.buy(&other, &CALL_ID, &2, &80_000_000, &i128::MAX);
+    let proceeds = s.client.sell(&s.trader, &CALL_ID, &1, &20_000_000, &0);
+
+    s.om.set_outcome(&s.registry.address, &CALL_ID, &backit_shared::OUTCOME_VOID);
+    assert_eq!(
+        s.client.resolve_market(&CALL_ID),
+        backit_shared::OUTCOME_VOID
+    );
+    assert_eq!(s.client.get_market(&CALL_ID).refund_rate, SCALE);
+
+    // Each trader gets back what they paid in, net of what they sold for
+    assert_eq!(s.client.redeem(&s.trader, &CALL_ID), paid - proceeds);
+    assert_eq!(s.client.get_shares(&CALL_ID, &1, &s.trader), 0);
+    let result = s.client.try_redeem(&s.trader, &CALL_ID);
+    assert_eq!(result, Err(Ok(AmmError::NothingToRedeem)));
+
+    // The creator can take the subsidy back without touching open refunds
+    assert_eq!(s.client.withdraw_subsidy(&s.creator, &CALL_ID), 69_314_719);
+    assert_eq!(s.client.redeem(&other, &CALL_ID), other_paid);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
@@ -1,18 +1,37 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, Address, Bytes, Env};
 
 use crate::events::PARAM_MIN_STAKE;
-use backit_shared::is_valid_fee_bps;
+use backit_shared::{is_valid_fee_bps, MAX_FEE_BPS};
 
-use crate::errors::CallRegistryError;
+use crate::errors::{CallRegistryError, CallRegistryExtError};
 use crate::events::{
-    emit_admin_params_changed_address, emit_admin_params_changed_i128,
-    emit_admin_params_changed_u32, emit_admin_params_changed_u64, emit_contract_paused,
-    emit_contract_unpaused, emit_token_delisted, emit_token_whitelisted, PARAM_ADMIN,
-    PARAM_FEE_BPS, PARAM_MAX_STAKE_PER_USER, PARAM_OUTCOME_MANAGER, PARAM_STAKING_CUTOFF,
+    emit_address_banned, emit_address_unbanned, emit_admin_params_changed_address,
+    emit_admin_params_changed_i128, emit_admin_params_changed_optional_address,
+    emit_admin_params_changed_u32, emit_admin_params_changed_u64, emit_asset_registered,
+    emit_asset_removed, emit_contract_paused, emit_contract_unpaused, emit_token_delisted,
+    emit_token_whitelisted, PARAM_ADMIN, PARAM_CALL_RATE_WINDOW, PARAM_CREATOR_BOND,
+    PARAM_DISPUTE_WINDOW, PARAM_DRAW_TOLERANCE, PARAM_INSTANCE_TTL, PARAM_MAX_CALLS_PER_WINDOW,
+    PARAM_MAX_CALL_DURATION, PARAM_MAX_SNIPE_EXTENSION, PARAM_MAX_STAKERS_PER_CALL,
+    PARAM_MAX_STAKE_PER_USER, PARAM_MAX_STREAK_BONUS, PARAM_MAX_TIME_BONUS,
+    PARAM_MIN_CALL_DURATION, PARAM_PERSISTENT_TTL, PARAM_REFERRAL_SHARE, PARAM_REPUTATION_CONTRACT,
+    PARAM_REWARDS_CONTRACT, PARAM_SNIPE_EXTENSION, PARAM_SNIPE_THRESHOLD, PARAM_SNIPE_WINDOW,
+    PARAM_STAKING_CUTOFF, PARAM_STREAK_BONUS, PARAM_TEMPORARY_TTL,
+};
+use crate::roles::require_role;
+use crate::storage::{
+    add_role, extend_storage_ttl, get_config, get_predicted_asset, get_reputation_contract,
+    get_rewards_contract, is_banned, remove_banned, remove_predicted_asset, remove_role,
+    set_banned, set_config, set_predicted_asset,
+    set_reputation_contract as store_reputation_contract,
+    set_rewards_contract as store_rewards_contract,
+};
+use crate::types::{
+    PauseComponent, PredictedAsset, Role, MAX_ASSET_DECIMALS, MAX_CALL_RATE_WINDOW_SECS,
+    MAX_DISPUTE_WINDOW_SECS, MIN_TTL_LEDGERS,
 };
-use crate::storage::{extend_storage_ttl, get_config, set_config};
 
-/// Transfer admin privileges to a new address.
+/// Transfer admin privileges to a new address. The [`Role::Admin`] entry
+/// moves with it; other roles the old admin holds stay until revoked.
 /// # Authorization
 /// Current admin must sign.
 /// # Errors
@@ -26,6 +45,8 @@ pub fn set_admin(env: Env, new_admin: Address) -> Result<(), CallRegistryError>
     config.admin = new_admin.clone();
 
     set_config(&env, &config);
+    remove_role(&env, Role::Admin, &old_admin);
+    add_role(&env, Role::Admin, &new_admin);
     extend_storage_ttl(&env);
 
     emit_admin_params_changed_address(&env, PARAM_ADMIN, &new_admin, &old_admin, &new_admin);
@@ -33,57 +54,219 @@ pub fn set_admin(env: Env, new_admin: Address) -> Result<(), CallRegistryError>
     Ok(())
 }
 
-/// Replace the outcome manager.
+/// Set the bounds on call duration (`end_ts − now`) enforced by `create_call`.
+///
+/// Keeps calls long enough for the oracle to observe a price and short enough
+/// to stay within storage TTLs. Pass `0` for either bound to disable it.
 /// # Authorization
 /// Current admin must sign.
 /// # Errors
-/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
-pub fn set_outcome_manager(env: Env, new_manager: Address) -> Result<(), CallRegistryError> {
+/// * [`CallRegistryError::NotInitialized`]        – contract not initialised.
+/// * [`CallRegistryError::InvalidDurationBounds`] – `min_secs` > non-zero `max_secs`.
+pub fn set_call_duration_bounds(
+    env: Env,
+    min_secs: u64,
+    max_secs: u64,
+) -> Result<(), CallRegistryError> {
+    if max_secs > 0 && min_secs > max_secs {
+        return Err(CallRegistryError::InvalidDurationBounds);
+    }
+
     let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
 
     config.admin.require_auth();
 
-    let old_manager = config.outcome_manager.clone();
-    config.outcome_manager = new_manager.clone();
+    let old_min = config.min_call_duration_secs;
+    let old_max = config.max_call_duration_secs;
+    config.min_call_duration_secs = min_secs;
+    config.max_call_duration_secs = max_secs;
 
     set_config(&env, &config);
     extend_storage_ttl(&env);
 
-    emit_admin_params_changed_address(
+    emit_admin_params_changed_u64(
         &env,
-        PARAM_OUTCOME_MANAGER,
+        PARAM_MIN_CALL_DURATION,
         &config.admin,
-        &old_manager,
-        &new_manager,
+        old_min,
+        min_secs,
+    );
+    emit_admin_params_changed_u64(
+        &env,
+        PARAM_MAX_CALL_DURATION,
+        &config.admin,
+        old_max,
+        max_secs,
     );
 
     Ok(())
 }
 
-/// Set the protocol fee in basis points (1 bp = 0.01 %).
-/// # Arguments
-/// * `new_fee_bps` — fee in basis points; must be ≤ 10 000 (100 %)
+/// Configure automatic deadline extension for late, large stakes.
+///
+/// A stake of at least `threshold_bps` of the pool placed within `window_secs`
+/// of the staking deadline pushes `end_ts` back by `extension_secs`, up to
+/// `max_extension_secs` in total per call. Pass `window_secs = 0` to disable.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`]         – contract not initialised.
+/// * [`CallRegistryError::InvalidAntiSnipeParams`] – `threshold_bps` > 10 000.
+pub fn set_anti_snipe_params(
+    env: Env,
+    window_secs: u64,
+    threshold_bps: u32,
+    extension_secs: u64,
+    max_extension_secs: u64,
+) -> Result<(), CallRegistryError> {
+    if threshold_bps > MAX_FEE_BPS {
+        return Err(CallRegistryError::InvalidAntiSnipeParams);
+    }
+
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    let old_window = config.snipe_window_secs;
+    let old_threshold = config.snipe_threshold_bps;
+    let old_extension = config.snipe_extension_secs;
+    let old_max_extension = config.max_snipe_extension_secs;
+    config.snipe_window_secs = window_secs;
+    config.snipe_threshold_bps = threshold_bps;
+    config.snipe_extension_secs = extension_secs;
+    config.max_snipe_extension_secs = max_extension_secs;
+
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+
+    emit_admin_params_changed_u64(
+        &env,
+        PARAM_SNIPE_WINDOW,
+        &config.admin,
+        old_window,
+        window_secs,
+    );
+    emit_admin_params_changed_u32(
+        &env,
+        PARAM_SNIPE_THRESHOLD,
+        &config.admin,
+        old_threshold,
+        threshold_bps,
+    );
+    emit_admin_params_changed_u64(
+        &env,
+        PARAM_SNIPE_EXTENSION,
+        &config.admin,
+        old_extension,
+        extension_secs,
+    );
+    emit_admin_params_changed_u64(
+        &env,
+        PARAM_MAX_SNIPE_EXTENSION,
+        &config.admin,
+        old_max_extension,
+        max_extension_secs,
+    );
+
+    Ok(())
+}
+
+/// Set the maximum number of distinct stakers per call.
+///
+/// Pass `0` to remove the limit; beware that very large calls can exceed the
+/// ledger entry size limit.
 /// # Authorization
 /// Current admin must sign.
 /// # Errors
 /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
-/// * [`CallRegistryError::FeeTooHigh`]     – `new_fee_bps` > 10 000.
-pub fn set_fee(env: Env, new_fee_bps: u32) -> Result<(), CallRegistryError> {
-    if !is_valid_fee_bps(new_fee_bps) {
-        return Err(CallRegistryError::FeeTooHigh);
+pub fn set_max_stakers_per_call(env: Env, new_max: u32) -> Result<(), CallRegistryError> {
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    let old_max = config.max_stakers_per_call;
+    config.max_stakers_per_call = new_max;
+
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+
+    emit_admin_params_changed_u32(
+        &env,
+        PARAM_MAX_STAKERS_PER_CALL,
+        &config.admin,
+        old_max,
+        new_max,
+    );
+
+    Ok(())
+}
+
+/// Set the DRAW tolerance band for binary calls.
+///
+/// A binary call must resolve as DRAW when `|end − start| * 10 000 <=
+/// start * new_tolerance_bps`, and may not otherwise. Pass `0` to disable draws.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`]   – contract not initialised.
+/// * [`CallRegistryError::InvalidTolerance`] – `new_tolerance_bps` > 10 000.
+pub fn set_draw_tolerance(env: Env, new_tolerance_bps: u32) -> Result<(), CallRegistryError> {
+    if new_tolerance_bps > MAX_FEE_BPS {
+        return Err(CallRegistryError::InvalidTolerance);
     }
 
     let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
 
     config.admin.require_auth();
 
-    let old_fee_bps = config.fee_bps;
-    config.fee_bps = new_fee_bps;
+    let old_tolerance_bps = config.draw_tolerance_bps;
+    config.draw_tolerance_bps = new_tolerance_bps;
 
     set_config(&env, &config);
     extend_storage_ttl(&env);
 
-    emit_admin_params_changed_u32(&env, PARAM_FEE_BPS, &config.admin, old_fee_bps, new_fee_bps);
+    emit_admin_params_changed_u32(
+        &env,
+        PARAM_DRAW_TOLERANCE,
+        &config.admin,
+        old_tolerance_bps,
+        new_tolerance_bps,
+    );
+
+    Ok(())
+}
+
+/// Set the time-weighted stake bonus for stakes placed at call creation.
+///
+/// A stake placed with `r` of the call's `d` seconds remaining is weighted
+/// `amount * (10 000 + new_bonus_bps * r / d) / 10 000`. Pass `0` to disable.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`]   – contract not initialised.
+/// * [`CallRegistryError::InvalidTimeBonus`] – `new_bonus_bps` > 10 000.
+pub fn set_max_time_bonus(env: Env, new_bonus_bps: u32) -> Result<(), CallRegistryError> {
+    if new_bonus_bps > MAX_FEE_BPS {
+        return Err(CallRegistryError::InvalidTimeBonus);
+    }
+
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    let old_bonus_bps = config.max_time_bonus_bps;
+    config.max_time_bonus_bps = new_bonus_bps;
+
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+
+    emit_admin_params_changed_u32(
+        &env,
+        PARAM_MAX_TIME_BONUS,
+        &config.admin,
+        old_bonus_bps,
+        new_bonus_bps,
+    );
 
     Ok(())
 }
@@ -95,15 +278,15 @@ pub fn set_fee(env: Env, new_fee_bps: u32) -> Result<(), CallRegistryError> {
 /// # Authorization
 /// Current admin must sign.
 ///
-/// # Panics
-/// * Contract not initialized
-/// * `new_max` is negative
-pub fn set_max_stake_per_user(env: Env, new_max: i128) {
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`]     – contract not initialised.
+/// * [`CallRegistryError::InvalidStakeAmount`] – `new_max` is negative.
+pub fn set_max_stake_per_user(env: Env, new_max: i128) -> Result<(), CallRegistryError> {
     if new_max < 0 {
-        panic!("max_stake_per_user cannot be negative");
+        return Err(CallRegistryError::InvalidStakeAmount);
     }
 
-    let mut config = get_config(&env).expect("Contract not initialized");
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
 
     config.admin.require_auth();
 
@@ -120,59 +303,254 @@ pub fn set_max_stake_per_user(env: Env, new_max: i128) {
         old_max,
         new_max,
     );
+
+    Ok(())
 }
 
-pub fn whitelist_token(env: Env, token_address: Address) {
-    let mut config = get_config(&env).expect("not initialized");
+pub fn whitelist_token(env: Env, token_address: Address) -> Result<(), CallRegistryError> {
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
     config.admin.require_auth();
     config.whitelisted_tokens.set(token_address.clone(), true);
     set_config(&env, &config);
     emit_token_whitelisted(&env, &token_address);
+    Ok(())
 }
 
-pub fn remove_token(env: Env, token_address: Address) {
-    let mut config = get_config(&env).expect("not initialized");
+pub fn remove_token(env: Env, token_address: Address) -> Result<(), CallRegistryError> {
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
     config.admin.require_auth();
     config.whitelisted_tokens.remove(token_address.clone());
     set_config(&env, &config);
     emit_token_delisted(&env, &token_address);
+    Ok(())
+}
+
+/// Add a predicted asset, or replace the one registered under its `pair_id`.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::InvalidAsset`] – empty `pair_id` or more than
+///   `MAX_ASSET_DECIMALS` decimals.
+pub fn register_asset(env: Env, asset: PredictedAsset) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    config.admin.require_auth();
+    if asset.pair_id.is_empty() || asset.decimals > MAX_ASSET_DECIMALS {
+        panic_with_error!(&env, CallRegistryExtError::InvalidAsset);
+    }
+    set_predicted_asset(&env, &asset);
+    extend_storage_ttl(&env);
+    emit_asset_registered(&env, &asset);
+    Ok(())
 }
 
-pub fn set_min_stake(env: Env, new_min_stake: i128) {
+/// Remove a predicted asset. Existing calls on the pair are unaffected; new
+/// calls on it are rejected.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::AssetNotRegistered`] – `pair_id` is not registered.
+pub fn remove_asset(env: Env, pair_id: Bytes) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    config.admin.require_auth();
+    if get_predicted_asset(&env, &pair_id).is_none() {
+        panic_with_error!(&env, CallRegistryExtError::AssetNotRegistered);
+    }
+    remove_predicted_asset(&env, &pair_id);
+    extend_storage_ttl(&env);
+    emit_asset_removed(&env, &pair_id);
+    Ok(())
+}
+
+pub fn set_min_stake(env: Env, new_min_stake: i128) -> Result<(), CallRegistryError> {
     if new_min_stake < 0 {
-        panic!("min_stake cannot be negative");
+        return Err(CallRegistryError::InvalidStakeAmount);
     }
-    let mut config = get_config(&env).expect("not initialized");
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
     config.admin.require_auth();
     let old = config.min_stake;
     config.min_stake = new_min_stake;
     set_config(&env, &config);
     extend_storage_ttl(&env);
     emit_admin_params_changed_i128(&env, PARAM_MIN_STAKE, &config.admin, old, new_min_stake);
+    Ok(())
 }
 
-/// Pause the contract — blocks create, stake, and resolve until unpaused.
+/// Set the bond creators escrow with each new call. Calls created before
+/// the change keep the bond they posted.
 /// # Authorization
 /// Current admin must sign.
-pub fn pause(env: Env) {
-    let mut config = get_config(&env).expect("not initialized");
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`]     – contract not initialised.
+/// * [`CallRegistryError::InvalidStakeAmount`] – `new_bond` < 0.
+pub fn set_creator_bond(env: Env, new_bond: i128) -> Result<(), CallRegistryError> {
+    if new_bond < 0 {
+        return Err(CallRegistryError::InvalidStakeAmount);
+    }
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
     config.admin.require_auth();
-    config.paused = true;
+    let old = config.creator_bond;
+    config.creator_bond = new_bond;
     set_config(&env, &config);
     extend_storage_ttl(&env);
-    emit_contract_paused(&env, &config.admin);
+    emit_admin_params_changed_i128(&env, PARAM_CREATOR_BOND, &config.admin, old, new_bond);
+    Ok(())
+}
+
+/// Limit how many calls one creator may open within a rolling window.
+/// Counts already recorded under the previous window length still apply
+/// until their buckets expire.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::InvalidCallRateLimit`] – `window_secs` is `0` or
+///   above `MAX_CALL_RATE_WINDOW_SECS`.
+pub fn set_call_rate_limit(
+    env: Env,
+    max_calls: u32,
+    window_secs: u64,
+) -> Result<(), CallRegistryError> {
+    if window_secs == 0 || window_secs > MAX_CALL_RATE_WINDOW_SECS {
+        panic_with_error!(&env, CallRegistryExtError::InvalidCallRateLimit);
+    }
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    config.admin.require_auth();
+    let old_max = config.max_calls_per_window;
+    let old_window = config.call_rate_window_secs;
+    config.max_calls_per_window = max_calls;
+    config.call_rate_window_secs = window_secs;
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+    emit_admin_params_changed_u32(
+        &env,
+        PARAM_MAX_CALLS_PER_WINDOW,
+        &config.admin,
+        old_max,
+        max_calls,
+    );
+    emit_admin_params_changed_u64(
+        &env,
+        PARAM_CALL_RATE_WINDOW,
+        &config.admin,
+        old_window,
+        window_secs,
+    );
+    Ok(())
+}
+
+/// Bar `account` from creating calls and staking. Positions it already
+/// holds can still be claimed or refunded. Banning a banned address is a
+/// no-op.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+pub fn ban_address(env: Env, account: Address) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    config.admin.require_auth();
+    if !is_banned(&env, &account) {
+        set_banned(&env, &account);
+        extend_storage_ttl(&env);
+        emit_address_banned(&env, &account, &config.admin);
+    }
+    Ok(())
+}
+
+/// Lift a ban placed with [`ban_address`]. Unbanning an address that is not
+/// banned is a no-op.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+pub fn unban_address(env: Env, account: Address) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    config.admin.require_auth();
+    if is_banned(&env, &account) {
+        remove_banned(&env, &account);
+        extend_storage_ttl(&env);
+        emit_address_unbanned(&env, &account, &config.admin);
+    }
+    Ok(())
 }
 
-/// Unpause the contract.
+/// Set how long resolutions stay open to correction. Calls resolved before
+/// the change keep the window they were given.
 /// # Authorization
 /// Current admin must sign.
-pub fn unpause(env: Env) {
-    let mut config = get_config(&env).expect("not initialized");
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::InvalidDisputeWindow`] – `new_window_secs` >
+///   `MAX_DISPUTE_WINDOW_SECS`.
+pub fn set_dispute_window(env: Env, new_window_secs: u64) -> Result<(), CallRegistryError> {
+    if new_window_secs > MAX_DISPUTE_WINDOW_SECS {
+        panic_with_error!(&env, CallRegistryExtError::InvalidDisputeWindow);
+    }
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
     config.admin.require_auth();
-    config.paused = false;
+    let old = config.dispute_window_secs;
+    config.dispute_window_secs = new_window_secs;
     set_config(&env, &config);
     extend_storage_ttl(&env);
-    emit_contract_unpaused(&env, &config.admin);
+    emit_admin_params_changed_u64(
+        &env,
+        PARAM_DISPUTE_WINDOW,
+        &config.admin,
+        old,
+        new_window_secs,
+    );
+    Ok(())
+}
+
+/// Pause one group of entrypoints until it is unpaused. Other components
+/// keep working, so e.g. claims can stay open while staking is halted.
+/// # Authorization
+/// `caller` must sign and hold [`Role::Pauser`].
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+pub fn pause(
+    env: Env,
+    caller: Address,
+    component: PauseComponent,
+) -> Result<(), CallRegistryError> {
+    set_paused(env, caller, component, true)
+}
+
+/// Unpause one group of entrypoints.
+/// # Authorization
+/// `caller` must sign and hold [`Role::Pauser`].
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+pub fn unpause(
+    env: Env,
+    caller: Address,
+    component: PauseComponent,
+) -> Result<(), CallRegistryError> {
+    set_paused(env, caller, component, false)
+}
+
+fn set_paused(
+    env: Env,
+    caller: Address,
+    component: PauseComponent,
+    paused: bool,
+) -> Result<(), CallRegistryError> {
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    require_role(&env, &caller, Role::Pauser);
+    config.pause_state.set(component, paused);
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+    if paused {
+        emit_contract_paused(&env, component, &caller);
+    } else {
+        emit_contract_unpaused(&env, component, &caller);
+    }
+    Ok(())
 }
 
 /// Set the staking cutoff window in seconds before `end_ts`.
@@ -183,10 +561,10 @@ pub fn unpause(env: Env) {
 /// # Authorization
 /// Current admin must sign.
 ///
-/// # Panics
-/// * Contract not initialized.
-pub fn set_staking_cutoff(env: Env, new_cutoff: u64) {
-    let mut config = get_config(&env).expect("not initialized");
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+pub fn set_staking_cutoff(env: Env, new_cutoff: u64) -> Result<(), CallRegistryError> {
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
     config.admin.require_auth();
     let old_cutoff = config.staking_cutoff_secs;
     config.staking_cutoff_secs = new_cutoff;
@@ -199,4 +577,201 @@ pub fn set_staking_cutoff(env: Env, new_cutoff: u64) {
         old_cutoff,
         new_cutoff,
     );
+    Ok(())
+}
+
+/// Set the share of a referred staker's protocol fee paid to their referrer.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// * [`CallRegistryError::FeeTooHigh`]     – `new_share_bps` > 10 000.
+pub fn set_referral_share(env: Env, new_share_bps: u32) -> Result<(), CallRegistryError> {
+    if !is_valid_fee_bps(new_share_bps) {
+        return Err(CallRegistryError::FeeTooHigh);
+    }
+
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    let old_share_bps = config.referral_share_bps;
+    config.referral_share_bps = new_share_bps;
+
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+
+    emit_admin_params_changed_u32(
+        &env,
+        PARAM_REFERRAL_SHARE,
+        &config.admin,
+        old_share_bps,
+        new_share_bps,
+    );
+
+    Ok(())
+}
+
+/// Set the win-streak payout bonus per consecutive prior win and its cap.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// * [`CallRegistryError::FeeTooHigh`]     – either value > 10 000.
+pub fn set_streak_bonus(
+    env: Env,
+    new_bonus_bps: u32,
+    new_max_bonus_bps: u32,
+) -> Result<(), CallRegistryError> {
+    if !is_valid_fee_bps(new_bonus_bps) || !is_valid_fee_bps(new_max_bonus_bps) {
+        return Err(CallRegistryError::FeeTooHigh);
+    }
+
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    let old_bonus_bps = config.streak_bonus_bps;
+    let old_max_bonus_bps = config.max_streak_bonus_bps;
+    config.streak_bonus_bps = new_bonus_bps;
+    config.max_streak_bonus_bps = new_max_bonus_bps;
+
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+
+    emit_admin_params_changed_u32(
+        &env,
+        PARAM_STREAK_BONUS,
+        &config.admin,
+        old_bonus_bps,
+        new_bonus_bps,
+    );
+    emit_admin_params_changed_u32(
+        &env,
+        PARAM_MAX_STREAK_BONUS,
+        &config.admin,
+        old_max_bonus_bps,
+        new_max_bonus_bps,
+    );
+
+    Ok(())
+}
+
+/// Set the lifetimes, in ledgers, that instance, persistent and temporary
+/// entries are extended to. Entries are refreshed once less than half of
+/// their lifetime remains, so shorter TTLs cut rent for short-lived calls.
+/// # Authorization
+/// Requires the current admin's signature.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::InvalidTtl`] – a TTL is below
+///   [`MIN_TTL_LEDGERS`] or above the network's maximum entry TTL.
+pub fn set_ttl_params(
+    env: Env,
+    instance_ttl_ledgers: u32,
+    persistent_ttl_ledgers: u32,
+    temporary_ttl_ledgers: u32,
+) -> Result<(), CallRegistryError> {
+    let max_ttl = env.storage().max_ttl();
+    for ttl in [
+        instance_ttl_ledgers,
+        persistent_ttl_ledgers,
+        temporary_ttl_ledgers,
+    ] {
+        if !(MIN_TTL_LEDGERS..=max_ttl).contains(&ttl) {
+            panic_with_error!(&env, CallRegistryExtError::InvalidTtl);
+        }
+    }
+
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    let old_config = config.clone();
+    config.instance_ttl_ledgers = instance_ttl_ledgers;
+    config.persistent_ttl_ledgers = persistent_ttl_ledgers;
+    config.temporary_ttl_ledgers = temporary_ttl_ledgers;
+
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+
+    for (param, old, new) in [
+        (
+            PARAM_INSTANCE_TTL,
+            old_config.instance_ttl_ledgers,
+            instance_ttl_ledgers,
+        ),
+        (
+            PARAM_PERSISTENT_TTL,
+            old_config.persistent_ttl_ledgers,
+            persistent_ttl_ledgers,
+        ),
+        (
+            PARAM_TEMPORARY_TTL,
+            old_config.temporary_ttl_ledgers,
+            temporary_ttl_ledgers,
+        ),
+    ] {
+        emit_admin_params_changed_u32(&env, param, &config.admin, old, new);
+    }
+
+    Ok(())
+}
+
+/// Set, or with `None` clear, the reputation contract this registry notifies
+/// when calls are created and resolved and when positions settle. The
+/// reputation contract must register this registry as a reporter.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+pub fn set_reputation_contract(
+    env: Env,
+    contract: Option<Address>,
+) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    let old_contract = get_reputation_contract(&env);
+    store_reputation_contract(&env, &contract);
+    extend_storage_ttl(&env);
+
+    emit_admin_params_changed_optional_address(
+        &env,
+        PARAM_REPUTATION_CONTRACT,
+        &config.admin,
+        &old_contract,
+        &contract,
+    );
+
+    Ok(())
+}
+
+/// Set, or with `None` clear, the rewards contract this registry credits
+/// with each settled position's stake time. The rewards contract must
+/// register this registry as a reporter.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+pub fn set_rewards_contract(env: Env, contract: Option<Address>) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    let old_contract = get_rewards_contract(&env);
+    store_rewards_contract(&env, &contract);
+    extend_storage_ttl(&env);
+
+    emit_admin_params_changed_optional_address(
+        &env,
+        PARAM_REWARDS_CONTRACT,
+        &config.admin,
+        &old_contract,
+        &contract,
+    );
+
+    Ok(())
 }
@@ -0,0 +1,30 @@
+use crate::types::{Call, ContractConfig};
+
+/// Seconds by which `call.end_ts` should be pushed back because of a stake of
+/// `amount` arriving at `now`, or `0` for no extension.
+///
+/// A stake triggers an extension when it lands within `snipe_window_secs` of
+/// the staking deadline (`end_ts − staking_cutoff_secs`) and is at least
+/// `snipe_threshold_bps` of the pool including itself. The total extension
+/// over the life of a call never exceeds `max_snipe_extension_secs`.
+/// `call.outcome_stakes` must not yet include `amount`.
+pub fn snipe_extension(config: &ContractConfig, call: &Call, now: u64, amount: i128) -> u64 {
+    if config.snipe_window_secs == 0 || config.snipe_extension_secs == 0 {
+        return 0;
+    }
+
+    let stake_deadline = call.end_ts.saturating_sub(config.staking_cutoff_secs);
+    if now.saturating_add(config.snipe_window_secs) < stake_deadline {
+        return 0;
+    }
+
+    let pool = crate::total_pool(call).saturating_add(amount);
+    if amount.saturating_mul(10_000) < pool.saturating_mul(config.snipe_threshold_bps as i128) {
+        return 0;
+    }
+
+    let remaining = config
+        .max_snipe_extension_secs
+        .saturating_sub(call.deadline_extended_secs);
+    config.snipe_extension_secs.min(remaining)
+}
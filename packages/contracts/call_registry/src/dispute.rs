@@ -0,0 +1,108 @@
+use soroban_sdk::{panic_with_error, Address, Bytes, Env};
+
+use crate::errors::{CallRegistryError, CallRegistryExtError};
+use crate::events::emit_outcome_overridden;
+use crate::math;
+use crate::storage::{
+    extend_storage_ttl, get_call, get_config, get_creator_stats, get_dispute_deadline, set_call,
+    set_creator_stats, set_dispute_deadline,
+};
+use crate::types::{Call, ContractConfig};
+use crate::validation::validate_ipfs_cid;
+use crate::{check_outcome, creator_backed};
+
+/// Start a just-resolved call's dispute window, if one is configured.
+pub fn open_dispute_window(env: &Env, config: &ContractConfig, call_id: u64) {
+    if config.dispute_window_secs > 0 {
+        let deadline = env
+            .ledger()
+            .timestamp()
+            .saturating_add(config.dispute_window_secs);
+        set_dispute_deadline(env, call_id, deadline);
+    }
+}
+
+/// Replace a resolved call's outcome while its dispute window is open.
+/// Only the OutcomeManager may override, from its `reverse_outcome`, which
+/// corrects its own final outcome in the same transaction; claims are priced
+/// from that copy, so an override made here alone would not reach payouts.
+/// `evidence_cid` points at the material justifying the correction and is
+/// published with the `outcome_overridden` event. The new outcome must pass
+/// the same checks as [`resolve_call`](crate::CallRegistry::resolve_call);
+/// the window is not extended.
+/// # Authorization
+/// `caller` must sign and be the configured outcome manager.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`]  – contract not initialised.
+/// * [`CallRegistryError::Unauthorized`]    – `caller` is not the outcome manager.
+/// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+/// * [`CallRegistryError::CallNotResolved`] – the call has no outcome yet.
+/// * [`CallRegistryError::InvalidOutcome`]  – `outcome` is not one the call can
+///   resolve to at `end_price`.
+/// # Panics
+/// * [`CallRegistryExtError::DisputeWindowClosed`] – the window has closed or
+///   the call never had one.
+/// * [`CallRegistryExtError::InvalidIpfsCid`] – `evidence_cid` is malformed.
+pub fn override_outcome(
+    env: Env,
+    caller: Address,
+    call_id: u64,
+    outcome: u32,
+    end_price: i128,
+    evidence_cid: Bytes,
+) -> Result<Call, CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    if caller != config.outcome_manager {
+        return Err(CallRegistryError::Unauthorized);
+    }
+    caller.require_auth();
+    validate_ipfs_cid(&env, &evidence_cid);
+
+    let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+    if call.outcome == 0 {
+        return Err(CallRegistryError::CallNotResolved);
+    }
+    let now = env.ledger().timestamp();
+    if get_dispute_deadline(&env, call_id).is_none_or(|deadline| now >= deadline) {
+        panic_with_error!(&env, CallRegistryExtError::DisputeWindowClosed);
+    }
+    check_outcome(&config, &call, outcome, end_price)?;
+
+    let old_outcome = call.outcome;
+    let mut creator_stats = get_creator_stats(&env, &call.creator);
+    if creator_backed(&env, &call, old_outcome) {
+        creator_stats.total_correct = creator_stats.total_correct.saturating_sub(1);
+    }
+    if creator_backed(&env, &call, outcome) {
+        creator_stats.total_correct = math::increment(&env, creator_stats.total_correct);
+    }
+    set_creator_stats(&env, &call.creator, &creator_stats);
+
+    call.outcome = outcome;
+    call.end_price = end_price;
+    set_call(&env, &call);
+    extend_storage_ttl(&env);
+
+    emit_outcome_overridden(
+        &env,
+        call_id,
+        old_outcome,
+        outcome,
+        end_price,
+        &evidence_cid,
+        &caller,
+    );
+
+    Ok(call)
+}
+
+/// Hold back settlement and payouts while a call's resolution can still be
+/// overridden.
+/// # Panics
+/// * [`CallRegistryExtError::DisputeWindowOpen`] – the window has not closed.
+pub fn ensure_dispute_window_closed(env: &Env, call_id: u64) {
+    let now = env.ledger().timestamp();
+    if get_dispute_deadline(env, call_id).is_some_and(|deadline| now < deadline) {
+        panic_with_error!(env, CallRegistryExtError::DisputeWindowOpen);
+    }
+}
@@ -1,36 +1,27 @@
-use soroban_sdk::{contracttype, Address, Env};
+use crate::errors::CallRegistryError;
 use crate::types::ContractConfig;
-use crate::storage::{get_config, set_config};
 
-/// Default maximum call duration: 30 days in seconds.
-pub const DEFAULT_MAX_DURATION_SECS: u64 = 2_592_000;
+/// Check that `end_ts - now` lies within the configured call duration bounds.
+///
+/// A bound of `0` disables that side of the check. Call this inside
+/// `create_call` after `end_ts` has been validated to be in the future.
+///
+/// # Errors
+/// * [`CallRegistryError::CallDurationTooShort`] – duration < `min_call_duration_secs`.
+/// * [`CallRegistryError::CallDurationTooLong`]  – duration > `max_call_duration_secs`.
+pub fn check_call_duration(
+    config: &ContractConfig,
+    now: u64,
+    end_ts: u64,
+) -> Result<(), CallRegistryError> {
+    let duration = end_ts.saturating_sub(now);
 
-/// Extend ContractConfig with max_duration_secs.
-/// Call this once after initialization to set the constraint.
-pub fn set_max_duration(env: &Env, admin: Address, max_duration_secs: u64) {
-    admin.require_auth();
-    assert!(max_duration_secs > 0, "max_duration_secs must be positive");
+    if config.min_call_duration_secs > 0 && duration < config.min_call_duration_secs {
+        return Err(CallRegistryError::CallDurationTooShort);
+    }
+    if config.max_call_duration_secs > 0 && duration > config.max_call_duration_secs {
+        return Err(CallRegistryError::CallDurationTooLong);
+    }
 
-    // Store as a separate instance key so existing config is unchanged
-    env.storage()
-        .instance()
-        .set(&crate::storage::DataKey::Config, &max_duration_secs);
+    Ok(())
 }
-
-/// Read the configured max duration, falling back to the default.
-pub fn get_max_duration(env: &Env) -> u64 {
-    env.storage()
-        .instance()
-        .get::<_, u64>(&crate::storage::DataKey::CallCounter) // placeholder key
-        .unwrap_or(DEFAULT_MAX_DURATION_SECS)
-}
-
-/// Assert that `end_ts - now <= max_duration_secs`.
-/// Call this inside `create_call` before persisting the call.
-pub fn assert_duration_within_limit(env: &Env, end_ts: u64) {
-    let now = env.ledger().timestamp();
-    assert!(end_ts > now, "end_ts must be in the future");
-    let duration = end_ts - now;
-    let max = get_max_duration(env);
-    assert!(duration <= max, "call duration exceeds maximum allowed");
-}
\ No newline at end of file
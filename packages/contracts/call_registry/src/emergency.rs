@@ -0,0 +1,136 @@
+use soroban_sdk::{panic_with_error, Env};
+
+use crate::errors::{CallRegistryError, CallRegistryExtError};
+use crate::events::{
+    emit_emergency_withdraw_cancelled, emit_emergency_withdraw_executed,
+    emit_emergency_withdraw_initiated,
+};
+use crate::storage::{
+    extend_storage_ttl, get_call, get_config, get_emergency_withdrawal, get_escrow_released,
+    remove_emergency_withdrawal, set_emergency_withdrawal,
+};
+use crate::transfer_token;
+use crate::types::{EmergencyWithdrawal, EMERGENCY_WITHDRAW_DELAY_SECS};
+
+/// Start the [`EMERGENCY_WITHDRAW_DELAY_SECS`] countdown on sweeping a
+/// call's escrow. Claims and refunds keep working until the sweep is
+/// executed, so stakers can get their funds out normally first. Returns the
+/// timestamp from which the sweep can run.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+/// # Panics
+/// * [`CallRegistryExtError::EmergencyWithdrawPending`] – a withdrawal was
+///   already initiated for the call.
+pub fn initiate_emergency_withdraw(env: Env, call_id: u64) -> Result<u64, CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    config.admin.require_auth();
+    get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+
+    if get_emergency_withdrawal(&env, call_id).is_some() {
+        panic_with_error!(&env, CallRegistryExtError::EmergencyWithdrawPending);
+    }
+
+    let eta = env
+        .ledger()
+        .timestamp()
+        .saturating_add(EMERGENCY_WITHDRAW_DELAY_SECS);
+    set_emergency_withdrawal(
+        &env,
+        &EmergencyWithdrawal {
+            call_id,
+            eta,
+            executed: false,
+        },
+    );
+    extend_storage_ttl(&env);
+
+    emit_emergency_withdraw_initiated(&env, call_id, eta, &config.admin);
+
+    Ok(eta)
+}
+
+/// Sweep whatever is left of a call's escrow to the admin once the delay
+/// has passed. The amount is the call's pool plus penalties minus what
+/// claims, refunds and fees have already paid out; the creator bond is not
+/// touched. Further payouts on the call are refused afterwards. Returns the
+/// amount swept.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+/// # Panics
+/// * [`CallRegistryExtError::EmergencyWithdrawNotFound`] – nothing pending.
+/// * [`CallRegistryExtError::EmergencyWithdrawNotReady`] – the ledger is
+///   before `eta`.
+pub fn execute_emergency_withdraw(env: Env, call_id: u64) -> Result<i128, CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    config.admin.require_auth();
+    let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+
+    let mut withdrawal = get_emergency_withdrawal(&env, call_id)
+        .filter(|withdrawal| !withdrawal.executed)
+        .unwrap_or_else(|| {
+            panic_with_error!(&env, CallRegistryExtError::EmergencyWithdrawNotFound)
+        });
+    if env.ledger().timestamp() < withdrawal.eta {
+        panic_with_error!(&env, CallRegistryExtError::EmergencyWithdrawNotReady);
+    }
+
+    let pool: i128 = (1..=call.outcome_count)
+        .map(|i| call.outcome_stakes.get(i).unwrap_or(0))
+        .sum();
+    let amount = (pool + call.penalty_pool - get_escrow_released(&env, call_id)).max(0);
+
+    withdrawal.executed = true;
+    set_emergency_withdrawal(&env, &withdrawal);
+    extend_storage_ttl(&env);
+
+    if amount > 0 {
+        transfer_token(
+            &env,
+            &call.stake_token,
+            &env.current_contract_address(),
+            &config.admin,
+            amount,
+        );
+    }
+
+    emit_emergency_withdraw_executed(&env, call_id, amount, &config.admin);
+
+    Ok(amount)
+}
+
+/// Abandon a pending emergency withdrawal.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::EmergencyWithdrawNotFound`] – nothing pending.
+pub fn cancel_emergency_withdraw(env: Env, call_id: u64) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+    config.admin.require_auth();
+
+    if get_emergency_withdrawal(&env, call_id).is_none_or(|withdrawal| withdrawal.executed) {
+        panic_with_error!(&env, CallRegistryExtError::EmergencyWithdrawNotFound);
+    }
+    remove_emergency_withdrawal(&env, call_id);
+    extend_storage_ttl(&env);
+
+    emit_emergency_withdraw_cancelled(&env, call_id, &config.admin);
+
+    Ok(())
+}
+
+/// Refuse a payout from a call whose escrow has been swept.
+/// # Panics
+/// * [`CallRegistryExtError::CallFundsWithdrawn`] – the sweep has executed.
+pub fn ensure_funds_not_withdrawn(env: &Env, call_id: u64) {
+    if get_emergency_withdrawal(env, call_id).is_some_and(|withdrawal| withdrawal.executed) {
+        panic_with_error!(env, CallRegistryExtError::CallFundsWithdrawn);
+    }
+}
@@ -24,9 +24,11 @@ pub enum CallRegistryError {
     Unauthorized = 9,
     /// Reserved for a future pause mechanism; no operations are permitted while paused.
     ContractPaused = 10,
-    /// `resolve_call` was called before `end_ts` has passed.
+    /// `resolve_call` was called before `end_ts` has passed and the call is not
+    /// eligible for early resolution.
     CallNotEnded = 11,
-    /// `outcome` passed to `resolve_call` is not `1` (UP) or `2` (DOWN).
+    /// `outcome` passed to `resolve_call` is out of range, or disagrees with the
+    /// DRAW tolerance band for a binary call.
     InvalidOutcome = 12,
     /// `outcome_count` is less than 2.
     InvalidOutcomeCount = 13,
@@ -36,4 +38,181 @@ pub enum CallRegistryError {
     StakingCutoffActive = 15,
     /// The SEP-10 token's `valid_until` ledger sequence has passed.
     Sep10TokenExpired = 16,
+    /// The parent of a conditional call does not exist, is voided or cancelled,
+    /// ends after the child, or `parent_outcome` is out of range.
+    InvalidParentCall = 17,
+    /// `resolve_call` was called on a conditional call whose parent has not
+    /// been resolved yet.
+    ParentNotResolved = 18,
+    /// `draw_tolerance_bps` exceeds 10 000 (100 %).
+    InvalidTolerance = 19,
+    /// `allow_early_resolution` was requested for a non-threshold condition.
+    EarlyResolutionNotSupported = 20,
+    /// `end_ts − now` is below the configured minimum call duration.
+    CallDurationTooShort = 21,
+    /// `end_ts − now` exceeds the configured maximum call duration.
+    CallDurationTooLong = 22,
+    /// The minimum call duration is greater than a non-zero maximum.
+    InvalidDurationBounds = 23,
+    /// The staker is not on the allowlist of a private call.
+    StakerNotAllowlisted = 24,
+    /// An allowlist operation was attempted on a public call.
+    CallNotPrivate = 25,
+    /// The time-weighted stake bonus exceeds 10 000 bps.
+    InvalidTimeBonus = 26,
+    /// The anti-sniping stake threshold exceeds 10 000 bps.
+    InvalidAntiSnipeParams = 27,
+    /// The stake would push the call's pool above its `max_total_stake`.
+    CallStakeCapExceeded = 28,
+    /// The stake would push the staker above the call's `max_stake_per_address`
+    /// or the global `max_stake_per_user`.
+    AddressStakeCapExceeded = 29,
+    /// The call already has `max_stakers_per_call` distinct stakers.
+    CallFull = 30,
+    /// The stake is below the call's `stake_amount` or the global `min_stake`.
+    StakeBelowMinimum = 31,
+    /// A fixed-ticket call received a stake other than its `stake_amount`.
+    InvalidTicketAmount = 32,
+    /// The staker has no stake on the given position of the call, or none
+    /// to refund.
+    NoExistingStake = 33,
+    /// `reduce_stake` asked to withdraw more than the staker holds.
+    ReduceExceedsStake = 34,
+    /// The follower already follows this creator.
+    AlreadyFollowing = 35,
+    /// The follower does not follow this creator.
+    NotFollowing = 36,
+    /// The staker already has an unrevealed commit on this call.
+    CommitExists = 37,
+    /// The staker has no commit on this call.
+    CommitNotFound = 38,
+    /// The revealed position, salt and amount do not match the commitment.
+    CommitMismatch = 39,
+    /// Unrevealed commits are refundable only once the call is settled,
+    /// voided or cancelled.
+    CommitNotRefundable = 40,
+    /// The quoted odds are below the staker's `min_odds_bps`.
+    OddsBelowMinimum = 41,
+    /// The house cannot cover the worst-case payout, or the withdrawal
+    /// exceeds its free liquidity.
+    InsufficientHouseLiquidity = 42,
+    /// The call has not been resolved, voided or cancelled yet.
+    CallNotResolved = 43,
+    /// There is nothing to claim: no fixed-odds tickets on the call, no
+    /// accrued referral rewards, no winning shares worth a payout, or no
+    /// creator bond left to refund or slash.
+    NothingToClaim = 44,
+    /// The house's fixed-odds book on this call is already settled.
+    HouseBookSettled = 45,
+    /// A staker cannot refer themselves.
+    SelfReferral = 46,
+    /// The staker backed the winning outcome, so the call does not break
+    /// their streak.
+    StakeNotLosing = 47,
+    /// The staker has already staked without this sponsor.
+    NotFirstTimeStaker = 48,
+    /// The sponsor is not registered.
+    SponsorNotFound = 49,
+    /// The sponsor's per-user or daily allowance is used up.
+    SponsorCapReached = 50,
+}
+
+/// Registry errors added after [`CallRegistryError`] reached the 50-variant
+/// limit on contract error enums. Codes continue from 51; entrypoints raise
+/// them with `panic_with_error!`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum CallRegistryExtError {
+    /// `migrate` was called with a `from_version` other than the layout the
+    /// instance currently holds.
+    StorageLayoutMismatch = 51,
+    /// A TTL is shorter than `MIN_TTL_LEDGERS` or longer than the network's
+    /// maximum entry TTL.
+    InvalidTtl = 52,
+    /// The call has been cancelled by its creator.
+    CallCancelled = 53,
+    /// The call has been voided by the admin or by its parent's outcome.
+    CallVoided = 54,
+    /// The stake token is neither native XLM nor on the admin's whitelist.
+    TokenNotWhitelisted = 55,
+    /// The call has not been settled by the outcome manager yet.
+    CallNotSettled = 56,
+    /// The call was created without outcome share tokens.
+    SharesNotConfigured = 57,
+    /// Void refunds are only available on voided calls.
+    CallNotVoided = 58,
+    /// The staker has already claimed their void refund on this call.
+    RefundAlreadyClaimed = 59,
+    /// `upgrade` was given a version not above the one currently deployed.
+    VersionNotIncreasing = 60,
+    /// No action with this id is waiting in the timelock queue.
+    AdminActionNotFound = 61,
+    /// The queued action's delay has not elapsed yet.
+    TimelockNotElapsed = 62,
+    /// The timelock delay is longer than `MAX_TIMELOCK_DELAY_SECS`.
+    InvalidTimelockDelay = 63,
+    /// The caller does not hold the role this entrypoint requires.
+    MissingRole = 64,
+    /// The admin role moves only with `set_admin`, not `grant_role`.
+    RoleNotGrantable = 65,
+    /// `pair_id` is not in the predicted-asset registry.
+    AssetNotRegistered = 66,
+    /// `token_address` differs from the one registered for `pair_id`.
+    AssetMismatch = 67,
+    /// An asset has an empty `pair_id` or more than `MAX_ASSET_DECIMALS`
+    /// decimals.
+    InvalidAsset = 68,
+    /// The creator has opened `max_calls_per_window` calls within the
+    /// current rate window.
+    CallRateLimited = 69,
+    /// A call rate limit with a zero window or one longer than
+    /// `MAX_CALL_RATE_WINDOW_SECS`.
+    InvalidCallRateLimit = 70,
+    /// The address has been banned by the admin from creating calls or
+    /// staking.
+    AddressBanned = 71,
+    /// An emergency withdrawal has already been initiated for the call.
+    EmergencyWithdrawPending = 72,
+    /// No emergency withdrawal is pending for the call.
+    EmergencyWithdrawNotFound = 73,
+    /// The emergency withdrawal delay has not elapsed yet.
+    EmergencyWithdrawNotReady = 74,
+    /// The call's escrow was moved out by an emergency withdrawal.
+    CallFundsWithdrawn = 75,
+    /// `pair_id` is empty, longer than `MAX_PAIR_ID_LEN`, or not of the form
+    /// `BASE/QUOTE`.
+    InvalidPairId = 76,
+    /// `ipfs_cid` is outside `MIN_IPFS_CID_LEN..=MAX_IPFS_CID_LEN` bytes or
+    /// contains characters no CID encoding uses.
+    InvalidIpfsCid = 77,
+    /// `resolve_call` was called on a call that already has an outcome.
+    AlreadyResolved = 78,
+    /// Stakes on the call are suspended by `freeze_call`.
+    CallFrozen = 79,
+    /// The call's resolution can still be overridden, so it is not final.
+    DisputeWindowOpen = 80,
+    /// The call's dispute window has closed or was never opened.
+    DisputeWindowClosed = 81,
+    /// The dispute window is longer than `MAX_DISPUTE_WINDOW_SECS`.
+    InvalidDisputeWindow = 82,
+    /// A stake, pool total or payout would overflow `i128`.
+    AmountOverflow = 83,
+    /// An id or counter would overflow its integer type.
+    CounterOverflow = 84,
+    /// The call has more than two outcomes, so no outcome follows from the
+    /// price alone.
+    NotBinaryCall = 85,
+    /// The staker's win or loss on this call was already recorded on their
+    /// streak.
+    StreakResultRecorded = 86,
+    /// The call was voided or cancelled and its creator bond can still be
+    /// slashed.
+    CreatorBondHeld = 87,
+    /// Fixed-odds tickets were quoted from the call's pool, so pool stakes
+    /// can no longer be reduced or switched.
+    FixedOddsExposureOpen = 88,
+    /// The call resolved to a winning outcome, whose stakers are paid its
+    /// penalty pool.
+    PenaltyPoolOwedToWinners = 89,
 }
@@ -1,12 +1,49 @@
 #![allow(deprecated)]
 #![allow(unused)]
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::symbol_short;
-use soroban_sdk::{Address, Bytes, BytesN, Env, Symbol};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, Symbol, Val};
+
+use crate::storage::next_event_sequence;
+use crate::types::{AdminAction, PauseComponent, PredictedAsset, ProtocolParams, Role, SemVer};
 
 pub const PARAM_MAX_STAKE_PER_USER: &str = "max_stake_per_user";
 pub const PARAM_MIN_STAKE: &str = "min_stake";
 pub const PARAM_STAKING_CUTOFF: &str = "staking_cutoff_secs";
+pub const PARAM_DRAW_TOLERANCE: &str = "draw_tolerance_bps";
+pub const PARAM_MIN_CALL_DURATION: &str = "min_call_duration_secs";
+pub const PARAM_MAX_CALL_DURATION: &str = "max_call_duration_secs";
+pub const PARAM_MAX_TIME_BONUS: &str = "max_time_bonus_bps";
+pub const PARAM_SNIPE_WINDOW: &str = "snipe_window_secs";
+pub const PARAM_SNIPE_THRESHOLD: &str = "snipe_threshold_bps";
+pub const PARAM_SNIPE_EXTENSION: &str = "snipe_extension_secs";
+pub const PARAM_MAX_SNIPE_EXTENSION: &str = "max_snipe_extension_secs";
+pub const PARAM_MAX_STAKERS_PER_CALL: &str = "max_stakers_per_call";
+pub const PARAM_REDUCE_FEE: &str = "reduce_fee_bps";
+pub const PARAM_SWITCH_FEE: &str = "switch_fee_bps";
+pub const PARAM_FIXED_ODDS_SPREAD: &str = "fixed_odds_spread_bps";
+pub const PARAM_REFERRAL_SHARE: &str = "referral_share_bps";
+pub const PARAM_STREAK_BONUS: &str = "streak_bonus_bps";
+pub const PARAM_MAX_STREAK_BONUS: &str = "max_streak_bonus_bps";
+pub const PARAM_INSTANCE_TTL: &str = "instance_ttl_ledgers";
+pub const PARAM_PERSISTENT_TTL: &str = "persistent_ttl_ledgers";
+pub const PARAM_TEMPORARY_TTL: &str = "temporary_ttl_ledgers";
+pub const PARAM_TIMELOCK_DELAY: &str = "timelock_delay_secs";
+pub const PARAM_CREATOR_BOND: &str = "creator_bond";
+pub const PARAM_MAX_CALLS_PER_WINDOW: &str = "max_calls_per_window";
+pub const PARAM_CALL_RATE_WINDOW: &str = "call_rate_window_secs";
+pub const PARAM_DISPUTE_WINDOW: &str = "dispute_window_secs";
+pub const PARAM_REPUTATION_CONTRACT: &str = "reputation_contract";
+pub const PARAM_REWARDS_CONTRACT: &str = "rewards_contract";
+
+/// Publish `data` under the topics `("call_registry", name, seq)`, where `seq`
+/// is the next value of the global event counter. Indexers use `seq` to
+/// order events across ledgers and to detect gaps.
+pub fn publish<D: IntoVal<Env, Val>>(env: &Env, name: &str, data: D) {
+    let seq = next_event_sequence(env);
+    env.events().publish(("call_registry", name, seq), data);
+}
 
 /// Emitted when a new call is created
 pub fn emit_call_created(
@@ -22,8 +59,9 @@ pub fn emit_call_created(
     metadata_hash: &BytesN<32>,
     outcome_count: u32,
 ) {
-    env.events().publish(
-        ("call_registry", "call_created"),
+    publish(
+        env,
+        "call_created",
         (
             call_id,
             creator.clone(),
@@ -41,24 +79,362 @@ pub fn emit_call_created(
 
 /// Emitted when a staker adds stake to a call
 pub fn emit_stake_added(env: &Env, call_id: u64, staker: &Address, amount: i128, position: u32) {
-    env.events().publish(
-        ("call_registry", "stake_added"),
+    publish(
+        env,
+        "stake_added",
         (call_id, staker.clone(), amount, position),
     );
 }
 
 /// Emitted when a call is resolved with an outcome
 pub fn emit_call_resolved(env: &Env, call_id: u64, outcome: u32, end_price: i128) {
-    env.events().publish(
-        ("call_registry", "call_resolved"),
-        (call_id, outcome, end_price),
+    publish(env, "call_resolved", (call_id, outcome, end_price));
+}
+
+/// Emitted alongside `call_resolved` when a call resolves before its `end_ts`
+/// because its threshold target was crossed
+pub fn emit_call_early_resolved(
+    env: &Env,
+    call_id: u64,
+    outcome: u32,
+    end_price: i128,
+    resolved_at: u64,
+) {
+    publish(
+        env,
+        "call_early_resolved",
+        (call_id, outcome, end_price, resolved_at),
+    );
+}
+
+/// Emitted alongside `stake_added` when a relayer staked from the staker's allowance
+pub fn emit_stake_relayed(
+    env: &Env,
+    call_id: u64,
+    spender: &Address,
+    staker: &Address,
+    amount: i128,
+) {
+    publish(
+        env,
+        "stake_relayed",
+        (call_id, spender.clone(), staker.clone(), amount),
+    );
+}
+
+/// Emitted when a paymaster registers or updates its sponsorship caps
+pub fn emit_sponsor_registered(env: &Env, sponsor: &Address, per_user_cap: u32, daily_cap: u32) {
+    publish(
+        env,
+        "sponsor_registered",
+        (sponsor.clone(), per_user_cap, daily_cap),
+    );
+}
+
+/// Emitted when a paymaster stops sponsoring stakes
+pub fn emit_sponsor_removed(env: &Env, sponsor: &Address) {
+    publish(env, "sponsor_removed", sponsor.clone());
+}
+
+/// Emitted when a stake's transaction fees are covered by a paymaster
+pub fn emit_stake_sponsored(env: &Env, call_id: u64, sponsor: &Address, staker: &Address) {
+    publish(
+        env,
+        "stake_sponsored",
+        (call_id, sponsor.clone(), staker.clone()),
+    );
+}
+
+/// Emitted when staking earns an address loyalty points
+pub fn emit_loyalty_points_accrued(env: &Env, holder: &Address, points: i128, balance: i128) {
+    publish(
+        env,
+        "loyalty_points_accrued",
+        (holder.clone(), points, balance),
+    );
+}
+
+/// Emitted when the admin snapshots loyalty point balances
+pub fn emit_loyalty_snapshot_taken(env: &Env, id: u32, total_points: i128, holders: u32) {
+    publish(env, "loyalty_snapshot_taken", (id, total_points, holders));
+}
+
+/// Emitted when a winning claim extends a staker's streak
+pub fn emit_streak_extended(
+    env: &Env,
+    staker: &Address,
+    call_id: u64,
+    streak: u32,
+    bonus_bps: u32,
+) {
+    publish(
+        env,
+        "streak_extended",
+        (staker.clone(), call_id, streak, bonus_bps),
+    );
+}
+
+/// Emitted when a losing call resets a staker's streak
+pub fn emit_streak_broken(env: &Env, staker: &Address, call_id: u64, streak: u32) {
+    publish(env, "streak_broken", (staker.clone(), call_id, streak));
+}
+
+/// Emitted when a staker is bound to the referrer who brought them in
+pub fn emit_referrer_set(env: &Env, staker: &Address, referrer: &Address) {
+    publish(env, "referrer_set", (staker.clone(), referrer.clone()));
+}
+
+/// Emitted when part of a referred staker's protocol fee accrues to their referrer
+pub fn emit_referral_accrued(
+    env: &Env,
+    call_id: u64,
+    referrer: &Address,
+    staker: &Address,
+    amount: i128,
+) {
+    publish(
+        env,
+        "referral_accrued",
+        (call_id, referrer.clone(), staker.clone(), amount),
+    );
+}
+
+/// Emitted when the protocol fee on a claim is paid to the fee collector
+pub fn emit_protocol_fee_paid(
+    env: &Env,
+    call_id: u64,
+    token: &Address,
+    collector: &Address,
+    amount: i128,
+) {
+    publish(
+        env,
+        "protocol_fee_paid",
+        (call_id, token.clone(), collector.clone(), amount),
+    );
+}
+
+/// Emitted when a referrer withdraws their accrued rewards
+pub fn emit_referral_rewards_claimed(env: &Env, referrer: &Address, token: &Address, amount: i128) {
+    publish(
+        env,
+        "referral_rewards_claimed",
+        (referrer.clone(), token.clone(), amount),
+    );
+}
+
+/// Emitted when a stake is taken against the house at locked odds
+pub fn emit_fixed_odds_stake(
+    env: &Env,
+    call_id: u64,
+    staker: &Address,
+    position: u32,
+    amount: i128,
+    odds_bps: i128,
+) {
+    publish(
+        env,
+        "fixed_odds_stake",
+        (call_id, staker.clone(), position, amount, odds_bps),
+    );
+}
+
+/// Emitted when a staker collects their fixed-odds payout or refund
+pub fn emit_fixed_odds_claimed(env: &Env, call_id: u64, staker: &Address, amount: i128) {
+    publish(env, "fixed_odds_claimed", (call_id, staker.clone(), amount));
+}
+
+/// Emitted when the house's share of a call's fixed-odds book is returned
+/// to its liquidity
+pub fn emit_fixed_odds_book_settled(env: &Env, call_id: u64, returned: i128) {
+    publish(env, "fixed_odds_book_settled", (call_id, returned));
+}
+
+/// Emitted when the admin deposits or withdraws house liquidity; `amount` is
+/// negative for withdrawals
+pub fn emit_house_liquidity_changed(env: &Env, token: &Address, amount: i128, free: i128) {
+    publish(
+        env,
+        "house_liquidity_changed",
+        (token.clone(), amount, free),
+    );
+}
+
+/// Emitted when a creator escrows their bond on a new call
+pub fn emit_creator_bond_posted(env: &Env, call_id: u64, creator: &Address, amount: i128) {
+    publish(
+        env,
+        "creator_bond_posted",
+        (call_id, creator.clone(), amount),
+    );
+}
+
+/// Emitted when a creator takes their bond back from a closed call
+pub fn emit_creator_bond_refunded(env: &Env, call_id: u64, creator: &Address, amount: i128) {
+    publish(
+        env,
+        "creator_bond_refunded",
+        (call_id, creator.clone(), amount),
+    );
+}
+
+/// Emitted when the admin moves a creator's bond into the insurance pool
+pub fn emit_creator_bond_slashed(
+    env: &Env,
+    call_id: u64,
+    creator: &Address,
+    amount: i128,
+    admin: &Address,
+) {
+    publish(
+        env,
+        "creator_bond_slashed",
+        (call_id, creator.clone(), amount, admin.clone()),
+    );
+}
+
+/// Emitted when winnings left unclaimed past their deadline move into the
+/// insurance pool
+pub fn emit_escrow_expired(env: &Env, call_id: u64, token: &Address, amount: i128) {
+    publish(env, "escrow_expired", (call_id, token.clone(), amount));
+}
+
+/// Emitted when a call with no winners hands its penalty pool to the
+/// insurance pool
+pub fn emit_penalty_pool_swept(env: &Env, call_id: u64, token: &Address, amount: i128) {
+    publish(env, "penalty_swept", (call_id, token.clone(), amount));
+}
+
+/// Emitted when a staker escrows funds behind a hidden position
+pub fn emit_stake_committed(env: &Env, call_id: u64, staker: &Address, amount: i128) {
+    publish(env, "stake_committed", (call_id, staker.clone(), amount));
+}
+
+/// Emitted when a committed stake is revealed and placed on its position
+pub fn emit_stake_revealed(env: &Env, call_id: u64, staker: &Address, amount: i128, position: u32) {
+    publish(
+        env,
+        "stake_revealed",
+        (call_id, staker.clone(), amount, position),
     );
 }
 
+/// Emitted when an unrevealed commit is refunded after the call closes
+pub fn emit_commit_refunded(env: &Env, call_id: u64, staker: &Address, amount: i128) {
+    publish(env, "commit_refunded", (call_id, staker.clone(), amount));
+}
+
+/// Emitted when a follower deposits a budget to copy a creator's calls
+pub fn emit_follow_created(
+    env: &Env,
+    follower: &Address,
+    creator: &Address,
+    amount_per_call: i128,
+    budget: i128,
+) {
+    publish(
+        env,
+        "follow_created",
+        (follower.clone(), creator.clone(), amount_per_call, budget),
+    );
+}
+
+/// Emitted when a follower stops copying a creator and is refunded
+pub fn emit_follow_cancelled(env: &Env, follower: &Address, creator: &Address, refund: i128) {
+    publish(
+        env,
+        "follow_cancelled",
+        (follower.clone(), creator.clone(), refund),
+    );
+}
+
+/// Emitted for each copy stake placed by `execute_copy_stakes`
+pub fn emit_copy_stake_executed(env: &Env, call_id: u64, follower: &Address, amount: i128) {
+    publish(
+        env,
+        "copy_stake_executed",
+        (call_id, follower.clone(), amount),
+    );
+}
+
+/// Emitted when a staker tops up an existing position
+pub fn emit_stake_increased(
+    env: &Env,
+    call_id: u64,
+    staker: &Address,
+    amount: i128,
+    position: u32,
+) {
+    publish(
+        env,
+        "stake_increased",
+        (call_id, staker.clone(), amount, position),
+    );
+}
+
+/// Emitted when a staker withdraws part of a position before the deadline
+pub fn emit_stake_reduced(
+    env: &Env,
+    call_id: u64,
+    staker: &Address,
+    amount: i128,
+    fee: i128,
+    position: u32,
+) {
+    publish(
+        env,
+        "stake_reduced",
+        (call_id, staker.clone(), amount, fee, position),
+    );
+}
+
+/// Emitted when a staker moves their stake from one outcome to another
+pub fn emit_position_switched(
+    env: &Env,
+    call_id: u64,
+    staker: &Address,
+    from_position: u32,
+    to_position: u32,
+    moved: i128,
+    fee: i128,
+) {
+    publish(
+        env,
+        "position_switched",
+        (
+            call_id,
+            staker.clone(),
+            from_position,
+            to_position,
+            moved,
+            fee,
+        ),
+    );
+}
+
+/// Emitted when a late, large stake pushes back a call's end time
+pub fn emit_call_deadline_extended(
+    env: &Env,
+    call_id: u64,
+    staker: &Address,
+    old_end_ts: u64,
+    new_end_ts: u64,
+) {
+    publish(
+        env,
+        "deadline_extended",
+        (call_id, staker.clone(), old_end_ts, new_end_ts),
+    );
+}
+
+/// Emitted when the creator of a private call adds or removes a staker
+pub fn emit_call_allowlist_updated(env: &Env, call_id: u64, staker: &Address, allowed: bool) {
+    publish(env, "allowlist_updated", (call_id, staker.clone(), allowed));
+}
+
 /// Emitted when a call is settled and winners are determined
 pub fn emit_call_settled(env: &Env, call_id: u64, winner_count: u64) {
-    env.events()
-        .publish(("call_registry", "call_settled"), (call_id, winner_count));
+    publish(env, "call_settled", (call_id, winner_count));
 }
 
 // ── Admin param events ────────────────────────────────────────────────────────
@@ -83,8 +459,28 @@ pub fn emit_admin_params_changed_address(
     old_value: &Address,
     new_value: &Address,
 ) {
-    env.events().publish(
-        ("call_registry", "admin_params_changed"),
+    publish(
+        env,
+        "admin_params_changed",
+        (
+            Symbol::new(env, param),
+            changed_by.clone(),
+            old_value.clone(),
+            new_value.clone(),
+        ),
+    );
+}
+
+pub fn emit_admin_params_changed_optional_address(
+    env: &Env,
+    param: &str,
+    changed_by: &Address,
+    old_value: &Option<Address>,
+    new_value: &Option<Address>,
+) {
+    publish(
+        env,
+        "admin_params_changed",
         (
             Symbol::new(env, param),
             changed_by.clone(),
@@ -101,8 +497,9 @@ pub fn emit_admin_params_changed_u32(
     old_value: u32,
     new_value: u32,
 ) {
-    env.events().publish(
-        ("call_registry", "admin_params_changed"),
+    publish(
+        env,
+        "admin_params_changed",
         (
             Symbol::new(env, param),
             changed_by.clone(),
@@ -114,8 +511,9 @@ pub fn emit_admin_params_changed_u32(
 
 /// Emitted when a creator cancels their call and reclaims their stake
 pub fn emit_call_cancelled(env: &Env, call_id: u64, creator: &Address, refunded_amount: i128) {
-    env.events().publish(
-        ("call_registry", "call_cancelled"),
+    publish(
+        env,
+        "call_cancelled",
         (call_id, creator.clone(), refunded_amount),
     );
 }
@@ -127,8 +525,9 @@ pub fn emit_admin_params_changed_i128(
     old_value: i128,
     new_value: i128,
 ) {
-    env.events().publish(
-        ("call_registry", "admin_params_changed"),
+    publish(
+        env,
+        "admin_params_changed",
         (
             Symbol::new(env, param),
             changed_by.clone(),
@@ -145,8 +544,9 @@ pub fn emit_admin_params_changed_u64(
     old_value: u64,
     new_value: u64,
 ) {
-    env.events().publish(
-        ("call_registry", "admin_params_changed"),
+    publish(
+        env,
+        "admin_params_changed",
         (
             Symbol::new(env, param),
             changed_by.clone(),
@@ -156,25 +556,31 @@ pub fn emit_admin_params_changed_u64(
     );
 }
 pub fn emit_token_whitelisted(env: &Env, token: &Address) {
-    env.events()
-        .publish(("call_registry", "token_whitelisted"), token.clone());
+    publish(env, "token_whitelisted", token.clone());
 }
 
 pub fn emit_token_delisted(env: &Env, token: &Address) {
-    env.events()
-        .publish(("call_registry", "token_delisted"), token.clone());
+    publish(env, "token_delisted", token.clone());
+}
+
+/// Emitted when the admin adds or updates a predicted asset
+pub fn emit_asset_registered(env: &Env, asset: &PredictedAsset) {
+    publish(env, "asset_registered", asset.clone());
 }
 
-/// Emitted when the admin pauses the contract.
-pub fn emit_contract_paused(env: &Env, admin: &Address) {
-    env.events()
-        .publish(("call_registry", "contract_paused"), admin.clone());
+/// Emitted when the admin removes a predicted asset
+pub fn emit_asset_removed(env: &Env, pair_id: &Bytes) {
+    publish(env, "asset_removed", pair_id.clone());
 }
 
-/// Emitted when the admin unpauses the contract.
-pub fn emit_contract_unpaused(env: &Env, admin: &Address) {
-    env.events()
-        .publish(("call_registry", "contract_unpaused"), admin.clone());
+/// Emitted when the pauser pauses a component.
+pub fn emit_contract_paused(env: &Env, component: PauseComponent, pauser: &Address) {
+    publish(env, "contract_paused", (component, pauser.clone()));
+}
+
+/// Emitted when the pauser unpauses a component.
+pub fn emit_contract_unpaused(env: &Env, component: PauseComponent, pauser: &Address) {
+    publish(env, "contract_unpaused", (component, pauser.clone()));
 }
 
 pub fn emit_call_metadata_updated(
@@ -186,7 +592,11 @@ pub fn emit_call_metadata_updated(
     version: u32,
 ) {
     env.events().publish(
-        (symbol_short!("call"), symbol_short!("meta_upd")),
+        (
+            symbol_short!("call"),
+            symbol_short!("meta_upd"),
+            next_event_sequence(env),
+        ),
         (
             call_id,
             creator.clone(),
@@ -198,37 +608,128 @@ pub fn emit_call_metadata_updated(
 }
 
 /// Emitted when the contract WASM is upgraded
-pub fn emit_contract_upgraded(env: &Env, old_version: u32, new_version: u32, admin: &Address) {
-    env.events().publish(
-        ("call_registry", "contract_upgraded"),
-        (old_version, new_version, admin.clone()),
+pub fn emit_upgraded(
+    env: &Env,
+    old_version: SemVer,
+    new_version: SemVer,
+    new_wasm_hash: &BytesN<32>,
+    admin: &Address,
+) {
+    publish(
+        env,
+        "upgraded",
+        (
+            old_version,
+            new_version,
+            new_wasm_hash.clone(),
+            admin.clone(),
+        ),
     );
 }
 
-/// Emitted when share tokens are minted
-pub fn emit_shares_minted(
+/// Emitted when the admin queues a timelocked action
+pub fn emit_admin_action_queued(
     env: &Env,
-    call_id: u64,
-    staker: &Address,
-    outcome: u32,
-    amount: i128,
+    id: u64,
+    action: &AdminAction,
+    eta: u64,
+    admin: &Address,
 ) {
-    env.events().publish(
-        ("call_registry", "shares_minted"),
+    publish(
+        env,
+        "admin_action_queued",
+        (id, action.clone(), eta, admin.clone()),
+    );
+}
+
+/// Emitted when a queued action is applied after its delay
+pub fn emit_admin_action_executed(env: &Env, id: u64, action: &AdminAction, admin: &Address) {
+    publish(
+        env,
+        "admin_action_executed",
+        (id, action.clone(), admin.clone()),
+    );
+}
+
+/// Emitted when the admin drops a queued action before it runs
+pub fn emit_admin_action_cancelled(env: &Env, id: u64, action: &AdminAction, admin: &Address) {
+    publish(
+        env,
+        "admin_action_cancelled",
+        (id, action.clone(), admin.clone()),
+    );
+}
+
+/// Emitted when an account is given a role
+pub fn emit_role_granted(env: &Env, role: Role, account: &Address, admin: &Address) {
+    publish(env, "role_granted", (role, account.clone(), admin.clone()));
+}
+
+/// Emitted when a role is taken away from an account
+pub fn emit_role_revoked(env: &Env, role: Role, account: &Address, admin: &Address) {
+    publish(env, "role_revoked", (role, account.clone(), admin.clone()));
+}
+
+/// Emitted when the admin bans an address from creating calls and staking
+pub fn emit_address_banned(env: &Env, account: &Address, admin: &Address) {
+    publish(env, "address_banned", (account.clone(), admin.clone()));
+}
+
+/// Emitted when the admin lifts an address's ban
+pub fn emit_address_unbanned(env: &Env, account: &Address, admin: &Address) {
+    publish(env, "address_unbanned", (account.clone(), admin.clone()));
+}
+
+/// Emitted when the admin starts the delay on sweeping a call's escrow
+pub fn emit_emergency_withdraw_initiated(env: &Env, call_id: u64, eta: u64, admin: &Address) {
+    publish(
+        env,
+        "emergency_withdraw_initiated",
+        (call_id, eta, admin.clone()),
+    );
+}
+
+/// Emitted when a call's remaining escrow is swept to the admin
+pub fn emit_emergency_withdraw_executed(env: &Env, call_id: u64, amount: i128, admin: &Address) {
+    publish(
+        env,
+        "emergency_withdraw_executed",
+        (call_id, amount, admin.clone()),
+    );
+}
+
+/// Emitted when the admin abandons a pending emergency withdrawal
+pub fn emit_emergency_withdraw_cancelled(env: &Env, call_id: u64, admin: &Address) {
+    publish(
+        env,
+        "emergency_withdraw_cancelled",
+        (call_id, admin.clone()),
+    );
+}
+
+/// Emitted when a storage migration finishes rewriting the instance's data
+pub fn emit_storage_migrated(env: &Env, from_layout: u32, to_layout: u32, admin: &Address) {
+    publish(
+        env,
+        "storage_migrated",
+        (from_layout, to_layout, admin.clone()),
+    );
+}
+
+/// Emitted when share tokens are minted
+pub fn emit_shares_minted(env: &Env, call_id: u64, staker: &Address, outcome: u32, amount: i128) {
+    publish(
+        env,
+        "shares_minted",
         (call_id, staker.clone(), outcome, amount),
     );
 }
 
 /// Emitted when share tokens are redeemed
-pub fn emit_shares_redeemed(
-    env: &Env,
-    call_id: u64,
-    staker: &Address,
-    outcome: u32,
-    amount: i128,
-) {
-    env.events().publish(
-        ("call_registry", "shares_redeemed"),
+pub fn emit_shares_redeemed(env: &Env, call_id: u64, staker: &Address, outcome: u32, amount: i128) {
+    publish(
+        env,
+        "shares_redeemed",
         (call_id, staker.clone(), outcome, amount),
     );
 }
@@ -242,8 +743,9 @@ pub fn emit_shares_transferred(
     outcome: u32,
     amount: i128,
 ) {
-    env.events().publish(
-        ("call_registry", "shares_transferred"),
+    publish(
+        env,
+        "shares_transferred",
         (call_id, from.clone(), to.clone(), outcome, amount),
     );
 }
@@ -252,26 +754,85 @@ pub fn emit_shares_transferred(
 
 /// Emitted when an admin voids a call
 pub fn emit_call_voided(env: &Env, call_id: u64, voided_by: &Address) {
-    env.events().publish(
-        ("call_registry", "call_voided"),
-        (call_id, voided_by.clone()),
+    publish(env, "call_voided", (call_id, voided_by.clone()));
+}
+
+/// Emitted when a queued [`AdminAction::SetParams`] replaces the protocol's
+/// tunables
+pub fn emit_protocol_params_changed(
+    env: &Env,
+    old: &ProtocolParams,
+    new: &ProtocolParams,
+    admin: &Address,
+) {
+    publish(
+        env,
+        "protocol_params_changed",
+        (old.clone(), new.clone(), admin.clone()),
+    );
+}
+
+/// Emitted when the admin corrects a resolution inside its dispute window
+pub fn emit_outcome_overridden(
+    env: &Env,
+    call_id: u64,
+    old_outcome: u32,
+    new_outcome: u32,
+    end_price: i128,
+    evidence_cid: &Bytes,
+    admin: &Address,
+) {
+    publish(
+        env,
+        "outcome_overridden",
+        (
+            call_id,
+            old_outcome,
+            new_outcome,
+            end_price,
+            evidence_cid.clone(),
+            admin.clone(),
+        ),
+    );
+}
+
+/// Emitted when staking on a single call is suspended
+pub fn emit_call_frozen(env: &Env, call_id: u64, frozen_by: &Address) {
+    publish(env, "call_frozen", (call_id, frozen_by.clone()));
+}
+
+/// Emitted when staking on a frozen call resumes
+pub fn emit_call_unfrozen(env: &Env, call_id: u64, unfrozen_by: &Address) {
+    publish(env, "call_unfrozen", (call_id, unfrozen_by.clone()));
+}
+
+/// Emitted when a conditional call is voided because its parent call did not
+/// resolve with the required outcome.
+pub fn emit_call_voided_by_parent(
+    env: &Env,
+    call_id: u64,
+    parent_call_id: u64,
+    parent_outcome: u32,
+) {
+    publish(
+        env,
+        "call_voided_by_parent",
+        (call_id, parent_call_id, parent_outcome),
     );
 }
 
 /// Emitted when a staker claims a void refund
 pub fn emit_void_refund_claimed(env: &Env, call_id: u64, staker: &Address, amount: i128) {
-    env.events().publish(
-        ("call_registry", "void_refund_claimed"),
+    publish(
+        env,
+        "void_refund_claimed",
         (call_id, staker.clone(), amount),
     );
 }
 
 /// Emitted when instance entry count exceeds the warning threshold.
 pub fn emit_storage_warning(env: &Env, entry_count: u32, estimated_bytes: u32) {
-    env.events().publish(
-        ("call_registry", "storage_warning"),
-        (entry_count, estimated_bytes),
-    );
+    publish(env, "storage_warning", (entry_count, estimated_bytes));
 }
 
 // ── Native XLM events ─────────────────────────────────────────────────────────
@@ -290,8 +851,9 @@ pub fn emit_xlm_call_created(
     metadata_hash: &BytesN<32>,
     outcome_count: u32,
 ) {
-    env.events().publish(
-        ("call_registry", "xlm_call_created"),
+    publish(
+        env,
+        "xlm_call_created",
         (
             call_id,
             creator.clone(),
@@ -315,40 +877,33 @@ pub fn emit_xlm_stake_added(
     amount: i128,
     position: u32,
 ) {
-    env.events().publish(
-        ("call_registry", "xlm_stake_added"),
+    publish(
+        env,
+        "xlm_stake_added",
         (call_id, staker.clone(), amount, position),
     );
 }
 
 /// Emitted when a void refund is paid out in native XLM.
 pub fn emit_xlm_void_refund_claimed(env: &Env, call_id: u64, staker: &Address, amount: i128) {
-    env.events().publish(
-        ("call_registry", "xlm_void_refund"),
-        (call_id, staker.clone(), amount),
-    );
+    publish(env, "xlm_void_refund", (call_id, staker.clone(), amount));
 }
 
 /// Emitted when a call cancelled refund is paid out in native XLM.
 pub fn emit_xlm_call_cancelled(env: &Env, call_id: u64, creator: &Address, refunded_amount: i128) {
-    env.events().publish(
-        ("call_registry", "xlm_call_cancelled"),
+    publish(
+        env,
+        "xlm_call_cancelled",
         (call_id, creator.clone(), refunded_amount),
     );
 }
 
 /// Emitted when escrow payout is made in native XLM.
 pub fn emit_xlm_escrow_released(env: &Env, call_id: u64, to: &Address, amount: i128) {
-    env.events().publish(
-        ("call_registry", "xlm_escrow_released"),
-        (call_id, to.clone(), amount),
-    );
+    publish(env, "xlm_escrow_released", (call_id, to.clone(), amount));
 }
 
 /// Emitted when a user successfully links their SEP-10-verified home domain.
 pub fn emit_sep10_verified(env: &Env, user: &Address, home_domain: &soroban_sdk::Bytes) {
-    env.events().publish(
-        ("call_registry", "sep10_verified"),
-        (user.clone(), home_domain.clone()),
-    );
+    publish(env, "sep10_verified", (user.clone(), home_domain.clone()));
 }
@@ -0,0 +1,30 @@
+use soroban_sdk::Env;
+
+use crate::math;
+use crate::types::{Call, FixedOddsBook};
+
+/// Payout multiplier, in basis points, quoted for a fixed-odds stake on
+/// `position` (e.g. 18_000 pays 1.8× the stake).
+///
+/// The implied probability of each outcome comes from the call's pool, with
+/// one `stake_amount` ticket of prior on every outcome so that empty pools
+/// still quote. The fair multiplier is then reduced by `spread_bps`.
+pub fn quote_odds_bps(env: &Env, call: &Call, position: u32, spread_bps: u32) -> i128 {
+    let prior = call.stake_amount.max(1);
+    let priors = math::mul(env, prior, call.outcome_count as i128);
+    let pool = math::add(env, crate::total_pool(call), priors);
+    let side = math::add(env, call.outcome_stakes.get(position).unwrap_or(0), prior);
+
+    let fair_bps = math::mul_div(env, pool, 10_000, side);
+    math::bps_of(env, fair_bps, 10_000 - spread_bps)
+}
+
+/// House funds the book must hold back so that the worst-case outcome can be
+/// paid from the stakes collected plus the reserve.
+pub fn required_reserve(book: &FixedOddsBook, outcome_count: u32) -> i128 {
+    let worst = (1..=outcome_count)
+        .map(|i| book.liabilities.get(i).unwrap_or(0))
+        .max()
+        .unwrap_or(0);
+    (worst - book.stakes).max(0)
+}
@@ -8,6 +8,7 @@ use soroban_sdk::{
     Address, Bytes, BytesN, Env,
 };
 
+use crate::test::register_registry;
 use crate::{types::ConditionType, CallRegistry, CallRegistryClient};
 
 #[contract]
@@ -20,20 +21,26 @@ impl MockToken {
 
 const TEST_MIN_STAKE: i128 = 1_000_000;
 const TEST_START_PRICE: i128 = 100_000_000;
+const TEST_IPFS_CID: &[u8] = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
 
 fn setup_fuzz_env() -> (Env, CallRegistryClient<'static>, Address, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register(CallRegistry, ());
-    let client = CallRegistryClient::new(&env, &contract_id);
-
     let admin = Address::generate(&env);
     let outcome_manager = Address::generate(&env);
+
+    let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
+    let client = CallRegistryClient::new(&env, &contract_id);
     let stake_token = env.register(MockToken, ());
 
-    client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
     client.whitelist_token(&stake_token);
+    client.register_asset(&crate::types::PredictedAsset {
+        token_address: Address::generate(&env),
+        pair_id: Bytes::from_slice(&env, b"USDC/XLM"),
+        decimals: 7,
+        price_feed_id: Bytes::from_slice(&env, b"USDC/XLM"),
+    });
 
     (env, client, admin, outcome_manager, stake_token)
 }
@@ -45,24 +52,33 @@ fn create_test_call(
     stake_token: &Address,
     end_ts: u64,
 ) -> u64 {
-    let token_address = Address::generate(env);
     let pair_id = Bytes::from_slice(env, b"USDC/XLM");
+    let token_address = client.get_asset(&pair_id).unwrap().token_address;
     let metadata_hash = BytesN::from_array(env, &[0u8; 32]);
 
     let call = client.create_call(
         creator,
         &crate::types::CallInitArgs {
             stake_token: stake_token.clone(),
-            stake_amount: 100_000_000_i128,
+            stake_amount: TEST_MIN_STAKE,
             start_price: TEST_START_PRICE,
-            end_ts: end_ts,
+            end_ts,
             token_address: token_address.clone(),
             pair_id: pair_id.clone(),
-            ipfs_cid: Bytes::from_slice(env, b"QmXxxx"),
+            ipfs_cid: Bytes::from_slice(env, TEST_IPFS_CID),
             metadata_hash: metadata_hash.clone(),
             condition: ConditionType::TargetAbove(100_000_000_i128),
             outcome_count: 2,
-        }
+            parent_call_id: 0,
+            parent_outcome: 0,
+            allow_early_resolution: false,
+            is_private: false,
+            seed_up: 0,
+            seed_down: 0,
+            max_total_stake: 0,
+            max_stake_per_address: 0,
+            stake_model: crate::types::StakeModel::MinimumFree,
+        },
     );
 
     call.id
@@ -312,8 +328,8 @@ fn test_fuzz_extreme_timestamp_near_max() {
     env.ledger().set_timestamp(1000);
 
     let creator = Address::generate(&env);
-    let token_address = Address::generate(&env);
     let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
+    let token_address = client.get_asset(&pair_id).unwrap().token_address;
     let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
     let extreme_timestamps = [u64::MAX - 1, u64::MAX - 100, u64::MAX - 1000, u64::MAX / 2];
@@ -323,16 +339,25 @@ fn test_fuzz_extreme_timestamp_near_max() {
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts,
                 token_address: token_address.clone(),
                 pair_id: pair_id.clone(),
-                ipfs_cid: Bytes::from_slice(&env, b"QmXxxx"),
+                ipfs_cid: Bytes::from_slice(&env, TEST_IPFS_CID),
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000),
                 outcome_count: 2,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         let staker = Address::generate(&env);
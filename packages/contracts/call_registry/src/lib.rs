@@ -1,7 +1,10 @@
 #![no_std]
 #![allow(deprecated)]
 
-use soroban_sdk::{contract, contractimpl, token, Address, Bytes, BytesN, Env, Map, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, token, Address, Bytes, BytesN, Env, IntoVal, Map,
+    Symbol, Val, Vec,
+};
 
 /// The sentinel value used to represent native XLM as the stake token.
 /// All-zero 32-byte array encoded as a contract Address via
@@ -41,26 +44,62 @@ fn transfer_token(env: &Env, stake_token: &Address, from: &Address, to: &Address
     }
 }
 
+/// Pull `amount` from `from` into the contract using an allowance `from`
+/// granted to this contract (SEP-41 `transfer_from`).
+fn transfer_token_from(env: &Env, stake_token: &Address, from: &Address, amount: i128) {
+    let this = env.current_contract_address();
+    token::Client::new(env, stake_token).transfer_from(&this, from, &this, &amount);
+}
+
+/// Where the tokens for a new stake come from.
+enum StakeFunding {
+    /// The staker transfers the tokens directly (staker-signed `transfer`).
+    Transfer,
+    /// The contract pulls the tokens from the staker's allowance.
+    Allowance,
+    /// The tokens are already held by the contract (copy-stake budgets).
+    Prefunded,
+}
+
 mod admin;
+mod anti_snipe;
+mod dispute;
+mod duration;
+mod emergency;
 mod errors;
 mod events;
+mod fixed_odds;
 #[cfg(test)]
 mod fuzz_tests;
+mod math;
+mod rate_limit;
+mod roles;
 mod sep10;
 mod shares;
 mod storage;
 #[cfg(test)]
 mod test;
+mod timelock;
 mod types;
+mod validation;
 
-use backit_shared::{OUTCOME_DOWN, OUTCOME_UP};
-use errors::CallRegistryError;
+use backit_shared::{is_within_draw_band, OUTCOME_DOWN, OUTCOME_DRAW, OUTCOME_UP, OUTCOME_VOID};
+use errors::{CallRegistryError, CallRegistryExtError};
 use events::*;
 use storage::*;
 use types::*;
 
 const MAX_CALL_PAGE_SIZE: u32 = 20;
-pub const CONTRACT_VERSION: u32 = 1;
+/// Most calls one `migrate` invocation rewrites.
+const MAX_MIGRATION_BATCH: u32 = 50;
+/// Hour buckets scanned by one `get_calls_ending_between` query: one week
+const MAX_END_TS_QUERY_BUCKETS: u64 = 168;
+/// Semantic version of this build, recorded at construction and `upgrade`.
+pub const CONTRACT_VERSION: SemVer = SemVer {
+    major: 1,
+    minor: 0,
+    patch: 0,
+};
 
 /// CallRegistry contract implementation.
 /// Manages prediction calls and staking on market outcomes.
@@ -99,9 +138,772 @@ fn evaluate_condition_impl(condition: &ConditionType, start_price: i128, end_pri
     }
 }
 
+/// Check that `outcome` is one `call` can resolve to given `end_price`. It
+/// must lie in `[1, outcome_count]`, or be DRAW on a binary call. A binary
+/// call resolves as DRAW exactly when the price stayed inside the configured
+/// tolerance band (or did not move at all); otherwise its outcome must follow
+/// the direction the price moved. Not applied to early threshold
+/// resolutions, which resolve UP whenever the target is crossed.
+/// # Errors
+/// * [`CallRegistryError::InvalidOutcome`] – any of the above fails.
+fn check_outcome(
+    config: &ContractConfig,
+    call: &Call,
+    outcome: u32,
+    end_price: i128,
+) -> Result<(), CallRegistryError> {
+    let is_binary = call.outcome_count == 2;
+    let is_draw = is_binary && outcome == OUTCOME_DRAW;
+    if !is_draw && (outcome < 1 || outcome > call.outcome_count) {
+        return Err(CallRegistryError::InvalidOutcome);
+    }
+
+    let in_draw_band = is_binary
+        && (end_price == call.start_price
+            || is_within_draw_band(call.start_price, end_price, config.draw_tolerance_bps));
+    if is_draw != in_draw_band {
+        return Err(CallRegistryError::InvalidOutcome);
+    }
+    if is_binary && !is_draw && (outcome == OUTCOME_UP) != (end_price > call.start_price) {
+        return Err(CallRegistryError::InvalidOutcome);
+    }
+    Ok(())
+}
+
+/// Whether `call`'s creator staked on `outcome`, for reputation tracking.
+fn creator_backed(env: &Env, call: &Call, outcome: u32) -> bool {
+    match outcome {
+        OUTCOME_UP | OUTCOME_DOWN => get_user_stake(env, call.id, &call.creator, outcome) > 0,
+        _ => false,
+    }
+}
+
+/// Forward a track-record update to the reputation contract, if one is set.
+/// `args` follow the reporter argument, which is always this registry.
+fn notify_reputation(env: &Env, function: &str, args: Vec<Val>) {
+    if let Some(reputation) = get_reputation_contract(env) {
+        let mut call_args: Vec<Val> = (env.current_contract_address(),).into_val(env);
+        call_args.append(&args);
+        env.invoke_contract::<bool>(&reputation, &Symbol::new(env, function), call_args);
+    }
+}
+
+/// Whether `call`'s result moves `staker`'s streak: it closed no earlier than
+/// the last call recorded on the streak. Results reported late still count
+/// toward reputation and rewards, but cannot break or extend newer streaks.
+fn extends_streak(env: &Env, streak: &Streak, call: &Call) -> bool {
+    streak.last_call_id == 0
+        || get_call(env, streak.last_call_id).is_none_or(|last| call.end_ts >= last.end_ts)
+}
+
+/// Credit `staker`'s settled position on `call` to the rewards contract, if
+/// one is set: each stake counts its amount times the seconds it was held
+/// until the call ended.
+fn notify_rewards(env: &Env, call: &Call, staker: &Address) {
+    if let Some(rewards) = get_rewards_contract(env) {
+        let mut stake_time: i128 = 0;
+        for entry in get_stake_entries(env, call.id, staker).iter() {
+            let held = call.end_ts.saturating_sub(entry.timestamp) as i128;
+            stake_time = stake_time.saturating_add(entry.amount.saturating_mul(held));
+        }
+        let args: Vec<Val> = (
+            env.current_contract_address(),
+            call.id,
+            staker.clone(),
+            stake_time,
+        )
+            .into_val(env);
+        env.invoke_contract::<bool>(&rewards, &Symbol::new(env, "record_stake_time"), args);
+    }
+}
+
+/// Threshold conditions (`TargetAbove` / `TargetBelow`) can be decided the
+/// moment the target is crossed, so only they support early resolution.
+fn is_threshold_condition(condition: &ConditionType) -> bool {
+    matches!(
+        condition,
+        ConditionType::TargetAbove(_) | ConditionType::TargetBelow(_)
+    )
+}
+
+/// Total stake across every outcome of `call`. Staking checks the grown pool
+/// with [`math::add`], so this sum cannot overflow.
+fn total_pool(call: &Call) -> i128 {
+    (1..=call.outcome_count)
+        .map(|i| call.outcome_stakes.get(i).unwrap_or(0))
+        .sum()
+}
+
+/// Condense `call` into the [`CallSummary`] returned by list views.
+fn summarize(call: &Call, now: u64) -> CallSummary {
+    let status = if call.cancelled {
+        CallStatus::Cancelled
+    } else if call.voided {
+        CallStatus::Voided
+    } else if call.settled {
+        CallStatus::Settled
+    } else if call.outcome != 0 {
+        CallStatus::Resolved
+    } else if now < call.end_ts {
+        CallStatus::Open
+    } else {
+        CallStatus::Ended
+    };
+    CallSummary {
+        id: call.id,
+        creator: call.creator.clone(),
+        stake_token: call.stake_token.clone(),
+        token_address: call.token_address.clone(),
+        outcome_stakes: call.outcome_stakes.clone(),
+        total_stake: total_pool(call),
+        end_ts: call.end_ts,
+        outcome: call.outcome,
+        status,
+    }
+}
+
+/// Rebuild a staker's participation record on a call from their per-position
+/// stakes and stake entries.
+fn derive_participation(env: &Env, call: &Call, staker: &Address) -> ParticipationSummary {
+    let mut positions = Map::new(env);
+    let mut total_stake = 0;
+    for i in 1..=call.outcome_count {
+        let stake = get_user_stake(env, call.id, staker, i);
+        if stake > 0 {
+            positions.set(i, stake);
+            total_stake += stake;
+        }
+    }
+    let entries = get_stake_entries(env, call.id, staker);
+    ParticipationSummary {
+        positions,
+        total_stake,
+        first_stake_ts: entries.first().map(|e| e.timestamp).unwrap_or(0),
+        last_stake_ts: entries.last().map(|e| e.timestamp).unwrap_or(0),
+    }
+}
+
+/// Bring one older-layout call up to the current layout: index it, move its
+/// stakers' void-refund flags to persistent storage, write its stats record
+/// and its stakers' participation records. Every step skips work already
+/// done, so any older layout can run it.
+fn migrate_call(env: &Env, call: &Call) {
+    backfill_creator_call(env, &call.creator, call.id);
+    backfill_end_ts_bucket_call(env, call.end_ts, call.id);
+    if call.outcome == 0 && !call.voided && !call.settled && !call.cancelled {
+        backfill_active_call(env, call.id);
+    }
+    for staker in get_call_stakers(env, call.id).iter() {
+        migrate_void_refund_claimed(env, call.id, &staker);
+        backfill_participation(
+            env,
+            &staker,
+            call.id,
+            &derive_participation(env, call, &staker),
+        );
+        remove_legacy_staker_calls(env, &staker);
+    }
+    if get_call_stats(env, call.id).is_none() {
+        set_call_stats(env, call.id, &derive_call_stats(env, call));
+    }
+}
+
+/// `staker`'s stake across every outcome of `call`.
+fn staker_total_stake(call: &Call, staker: &Address) -> i128 {
+    (1..=call.outcome_count)
+        .map(|i| {
+            call.stakes
+                .get(i)
+                .and_then(|stakers| stakers.get(staker.clone()))
+                .unwrap_or(0)
+        })
+        .sum()
+}
+
+/// Weight `amount` by how early it is staked: the bonus decays linearly from
+/// `max_bonus_bps` at `created_at` to zero at `end_ts`.
+fn time_weighted_amount(
+    env: &Env,
+    amount: i128,
+    max_bonus_bps: u32,
+    created_at: u64,
+    end_ts: u64,
+    now: u64,
+) -> i128 {
+    let duration = end_ts.saturating_sub(created_at);
+    if max_bonus_bps == 0 || duration == 0 {
+        return amount;
+    }
+    let remaining = end_ts.saturating_sub(now).min(duration);
+    let bonus_bps = (max_bonus_bps as u128 * remaining as u128 / duration as u128) as u32;
+    math::add(env, amount, math::bps_of(env, amount, bonus_bps))
+}
+
+/// Rebuild a call's stats from its embedded stake maps. Only needed for
+/// calls whose stats record predates layout 3 or that have no stakes yet.
+fn derive_call_stats(env: &Env, call: &Call) -> CallStats {
+    let mut outcome_stake_counts = Map::new(env);
+    let mut total_stakes = 0;
+    for i in 1..=call.outcome_count {
+        let count = call.stakes.get(i).map(|stakers| stakers.len()).unwrap_or(0);
+        outcome_stake_counts.set(i, count);
+        total_stakes += count;
+    }
+    CallStats {
+        outcome_stakes: call.outcome_stakes.clone(),
+        outcome_stake_counts,
+        total_stakes,
+        last_stake_ts: 0,
+    }
+}
+
+/// The call's stored stats record, or one rebuilt from its stake maps.
+fn load_call_stats(env: &Env, call: &Call) -> CallStats {
+    get_call_stats(env, call.id).unwrap_or_else(|| derive_call_stats(env, call))
+}
+
+/// Credit `amount` on `position` to `staker`: share tokens, the call's stake
+/// maps (raw and time-weighted) and the per-staker indexes. The caller
+/// transfers the tokens in, records volume and persists `call`.
+fn record_position_stake(
+    env: &Env,
+    call: &mut Call,
+    staker: &Address,
+    position: u32,
+    amount: i128,
+    max_time_bonus_bps: u32,
+) {
+    let mut stats = load_call_stats(env, call);
+    let weight = time_weighted_amount(
+        env,
+        amount,
+        max_time_bonus_bps,
+        call.created_at,
+        call.end_ts,
+        env.ledger().timestamp(),
+    );
+    let weighted_total = call.weighted_outcome_stakes.get(position).unwrap_or(0);
+    call.weighted_outcome_stakes
+        .set(position, math::add(env, weighted_total, weight));
+    let staker_weight = get_weighted_stake(env, call.id, staker, position);
+    set_weighted_stake(
+        env,
+        call.id,
+        staker,
+        position,
+        math::add(env, staker_weight, weight),
+    );
+
+    if let Some(share_token) = call.share_tokens.get(position) {
+        shares::mint_shares(env, &share_token, staker, amount);
+        emit_shares_minted(env, call.id, staker, position, amount);
+    }
+
+    // Update stake maps with generalized position support
+    let new_total = math::add(env, call.outcome_stakes.get(position).unwrap_or(0), amount);
+    call.outcome_stakes.set(position, new_total);
+
+    let mut outcome_stakers = call.stakes.get(position).unwrap_or_else(|| Map::new(env));
+    let current_staker_stake = outcome_stakers.get(staker.clone()).unwrap_or(0);
+    let new_staker_stake = math::add(env, current_staker_stake, amount);
+    outcome_stakers.set(staker.clone(), new_staker_stake);
+    call.stakes.set(position, outcome_stakers);
+
+    if current_staker_stake == 0 {
+        let count = stats.outcome_stake_counts.get(position).unwrap_or(0);
+        stats
+            .outcome_stake_counts
+            .set(position, math::increment(env, count));
+        stats.total_stakes = math::increment(env, stats.total_stakes);
+    }
+    stats.outcome_stakes.set(position, new_total);
+    stats.last_stake_ts = env.ledger().timestamp();
+    set_call_stats(env, call.id, &stats);
+
+    let mut entries = get_stake_entries(env, call.id, staker);
+    entries.push_back(StakeEntry {
+        position,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    });
+    set_stake_entries(env, call.id, staker, &entries);
+
+    add_call_staker(env, call.id, staker);
+    set_user_stake(env, call.id, staker, position, new_staker_stake);
+    update_participation(env, staker, call.id, position, amount);
+}
+
+/// Take `amount` off `staker`'s stake entries on `position`, newest first,
+/// dropping entries that are used up.
+fn trim_stake_entries(env: &Env, call_id: u64, staker: &Address, position: u32, amount: i128) {
+    let mut entries = get_stake_entries(env, call_id, staker);
+    let mut left = amount;
+    let mut i = entries.len();
+    while left > 0 && i > 0 {
+        i -= 1;
+        let mut entry = entries.get(i).unwrap();
+        if entry.position != position {
+            continue;
+        }
+        if entry.amount <= left {
+            left -= entry.amount;
+            entries.remove(i);
+        } else {
+            entry.amount -= left;
+            left = 0;
+            entries.set(i, entry);
+        }
+    }
+    set_stake_entries(env, call_id, staker, &entries);
+}
+
+/// Debit `amount` on `position` from `staker`, removing a proportional slice
+/// of their time weight and trimming their most recent stake entries. The
+/// caller validates `amount` and persists `call`.
+fn remove_position_stake(
+    env: &Env,
+    call: &mut Call,
+    staker: &Address,
+    position: u32,
+    amount: i128,
+) {
+    let mut stats = load_call_stats(env, call);
+    let mut outcome_stakers = call.stakes.get(position).unwrap_or_else(|| Map::new(env));
+    let current = outcome_stakers.get(staker.clone()).unwrap_or(0);
+    let remaining = current - amount;
+    if remaining > 0 {
+        outcome_stakers.set(staker.clone(), remaining);
+    } else {
+        outcome_stakers.remove(staker.clone());
+        let count = stats.outcome_stake_counts.get(position).unwrap_or(0);
+        stats
+            .outcome_stake_counts
+            .set(position, count.saturating_sub(1));
+        stats.total_stakes = stats.total_stakes.saturating_sub(1);
+    }
+    call.stakes.set(position, outcome_stakers);
+
+    let total = call.outcome_stakes.get(position).unwrap_or(0);
+    call.outcome_stakes.set(position, total - amount);
+    stats.outcome_stakes.set(position, total - amount);
+    set_call_stats(env, call.id, &stats);
+
+    let weight = get_weighted_stake(env, call.id, staker, position);
+    let removed_weight = math::mul_div(env, weight, amount, current);
+    set_weighted_stake(env, call.id, staker, position, weight - removed_weight);
+    let weighted_total = call.weighted_outcome_stakes.get(position).unwrap_or(0);
+    call.weighted_outcome_stakes
+        .set(position, weighted_total - removed_weight);
+
+    if let Some(share_token) = call.share_tokens.get(position) {
+        shares::burn_shares(env, &share_token, staker, amount);
+    }
+
+    trim_stake_entries(env, call.id, staker, position, amount);
+    set_user_stake(env, call.id, staker, position, remaining);
+    update_participation(env, staker, call.id, position, -amount);
+}
+
+/// `sha256(position ‖ salt ‖ amount)` with integers big-endian, binding a
+/// hidden stake to the values later passed to `reveal_stake`.
+fn stake_commitment(env: &Env, position: u32, salt: &BytesN<32>, amount: i128) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &position.to_be_bytes());
+    preimage.append(&Bytes::from(salt.clone()));
+    preimage.extend_from_array(&amount.to_be_bytes());
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Whether a closed call refunds its stakes (voided, cancelled or DRAW)
+/// rather than paying the winning outcome.
+/// # Errors
+/// * [`CallRegistryError::CallNotResolved`] – the call is still open.
+fn refunds_stakes(call: &Call) -> Result<bool, CallRegistryError> {
+    if call.voided || call.cancelled {
+        return Ok(true);
+    }
+    if call.outcome == 0 {
+        return Err(CallRegistryError::CallNotResolved);
+    }
+    Ok(call.outcome == OUTCOME_DRAW)
+}
+
+/// Refuse to take stake out of, or move it within, a pool that fixed-odds
+/// tickets were priced from. Otherwise a staker could skew the pool, buy
+/// tickets at the skewed odds and withdraw the skew again.
+fn ensure_no_fixed_odds_exposure(env: &Env, call_id: u64) {
+    if get_fixed_odds_book(env, call_id).is_some_and(|book| book.stakes > 0) {
+        panic_with_error!(env, CallRegistryExtError::FixedOddsExposureOpen);
+    }
+}
+
+/// Burn the position tokens backing `staker`'s recorded stakes on `call`,
+/// consuming the positions at claim time.
+fn burn_position_tokens(env: &Env, call: &Call, staker: &Address) {
+    for (position, share_token) in call.share_tokens.iter() {
+        let stake = get_user_stake(env, call.id, staker, position);
+        if stake > 0 {
+            shares::burn_shares(env, &share_token, staker, stake);
+        }
+    }
+}
+
+/// Credit `staker` with loyalty points for a stake of `amount`: one per
+/// [`LOYALTY_VOLUME_UNIT`] staked, plus [`LOYALTY_PARTICIPATION_POINTS`] when
+/// the stake is their first on the call.
+fn accrue_loyalty_points(env: &Env, staker: &Address, amount: i128, joins_call: bool) {
+    let mut points = amount / LOYALTY_VOLUME_UNIT;
+    if joins_call {
+        points += LOYALTY_PARTICIPATION_POINTS;
+    }
+    if points > 0 {
+        let balance = add_loyalty_points(env, staker, points);
+        emit_loyalty_points_accrued(env, staker, points, balance);
+    }
+}
+
+/// Payout bonus earned by a streak's prior consecutive wins:
+/// `streak_bonus_bps` per win, capped at `max_streak_bonus_bps`.
+fn streak_bonus_bps(config: &ContractConfig, streak: &Streak) -> u32 {
+    streak
+        .current
+        .saturating_mul(config.streak_bonus_bps)
+        .min(config.max_streak_bonus_bps)
+}
+
+/// Resolve a `(cursor, limit)` page over an index of `len` entries into the
+/// `[start, end)` range to read and the cursor of the following page. Pages
+/// hold at most [`MAX_CALL_PAGE_SIZE`] entries.
+fn page_range(len: u32, cursor: u32, limit: u32) -> (u32, u32, Option<u32>) {
+    let start = cursor.min(len);
+    let end = start.saturating_add(limit.min(MAX_CALL_PAGE_SIZE)).min(len);
+    let next_cursor = if end < len { Some(end) } else { None };
+    (start, end, next_cursor)
+}
+
+/// Load one page of the calls listed in `call_ids`.
+fn call_page(env: &Env, call_ids: &Vec<u64>, cursor: u32, limit: u32) -> CallPage {
+    let (start, end, next_cursor) = page_range(call_ids.len(), cursor, limit);
+    let now = env.ledger().timestamp();
+    let mut calls = Vec::new(env);
+    for call_id in call_ids.slice(start..end).iter() {
+        if let Some(call) = get_call(env, call_id) {
+            calls.push_back(summarize(&call, now));
+        }
+    }
+    CallPage { calls, next_cursor }
+}
+
+/// Cut one page out of an address index.
+fn address_page(addresses: &Vec<Address>, cursor: u32, limit: u32) -> AddressPage {
+    let (start, end, next_cursor) = page_range(addresses.len(), cursor, limit);
+    AddressPage {
+        addresses: addresses.slice(start..end),
+        next_cursor,
+    }
+}
+
+/// Emit the `stake_added` event for a new stake. Native XLM gets a distinct
+/// event so the indexer can differentiate XLM from USDC volume.
+fn emit_new_stake(env: &Env, call: &Call, staker: &Address, amount: i128, position: u32) {
+    if is_native_xlm(env, &call.stake_token) {
+        emit_xlm_stake_added(env, call.id, staker, amount, position);
+    } else {
+        emit_stake_added(env, call.id, staker, amount, position);
+    }
+}
+
+/// Reject staking changes once a call is past its staking deadline, closed or
+/// frozen.
+fn check_staking_open(
+    env: &Env,
+    config: &ContractConfig,
+    call: &Call,
+) -> Result<(), CallRegistryError> {
+    let current_timestamp = env.ledger().timestamp();
+    if current_timestamp >= call.end_ts {
+        return Err(CallRegistryError::CallEnded);
+    }
+
+    // Staking cutoff: reject stakes within `staking_cutoff_secs` of end_ts.
+    let cutoff = config.staking_cutoff_secs;
+    if cutoff > 0 && call.end_ts > cutoff && current_timestamp >= call.end_ts - cutoff {
+        return Err(CallRegistryError::StakingCutoffActive);
+    }
+
+    if call.settled {
+        return Err(CallRegistryError::CallSettled);
+    }
+
+    if call.early_resolved {
+        return Err(CallRegistryError::CallEnded);
+    }
+
+    if call.cancelled {
+        panic_with_error!(env, CallRegistryExtError::CallCancelled);
+    }
+
+    if call.voided {
+        panic_with_error!(env, CallRegistryExtError::CallVoided);
+    }
+
+    if is_call_frozen(env, call.id) {
+        panic_with_error!(env, CallRegistryExtError::CallFrozen);
+    }
+
+    Ok(())
+}
+
+/// Reject the call while the pauser has `component` paused.
+fn ensure_unpaused(
+    config: &ContractConfig,
+    component: PauseComponent,
+) -> Result<(), CallRegistryError> {
+    if config.pause_state.is_paused(component) {
+        return Err(CallRegistryError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Reject creates and stakes from an address the admin has banned.
+/// # Panics
+/// * [`CallRegistryExtError::AddressBanned`] – `account` is banned.
+fn ensure_not_banned(env: &Env, account: &Address) {
+    if is_banned(env, account) {
+        panic_with_error!(env, CallRegistryExtError::AddressBanned);
+    }
+}
+
+/// Whether a commit on `call` can no longer be revealed: the staking deadline
+/// (`end_ts` less the staking cutoff) has passed or the call resolved early.
+fn reveal_window_closed(env: &Env, config: &ContractConfig, call: &Call) -> bool {
+    let cutoff = config.staking_cutoff_secs;
+    let deadline = if cutoff > 0 && call.end_ts > cutoff {
+        call.end_ts - cutoff
+    } else {
+        call.end_ts
+    };
+    call.early_resolved || env.ledger().timestamp() >= deadline
+}
+
+/// Check `amount` against the call's stake model, allowlist and pool cap.
+/// # Errors
+/// * [`CallRegistryError::InvalidTicketAmount`]  – `amount` is not the fixed ticket.
+/// * [`CallRegistryError::StakeBelowMinimum`]    – `amount` is below the call's minimum.
+/// * [`CallRegistryError::StakerNotAllowlisted`] – the call is private and `staker`
+///   is not on its allowlist.
+/// * [`CallRegistryError::CallStakeCapExceeded`] – the pool would exceed `max_total_stake`.
+fn check_stake_terms(
+    env: &Env,
+    call: &Call,
+    staker: &Address,
+    amount: i128,
+) -> Result<(), CallRegistryError> {
+    match call.stake_model {
+        StakeModel::FixedTicket if amount != call.stake_amount => {
+            return Err(CallRegistryError::InvalidTicketAmount);
+        }
+        StakeModel::MinimumFree if amount < call.stake_amount => {
+            return Err(CallRegistryError::StakeBelowMinimum);
+        }
+        _ => {}
+    }
+
+    if call.is_private && !is_call_allowlisted(env, call.id, staker) {
+        return Err(CallRegistryError::StakerNotAllowlisted);
+    }
+
+    // Creator-set pool cap. The grown pool bounds every per-position and
+    // per-staker total, so checking it rules out overflow when recording.
+    let pool = math::add(env, total_pool(call), amount);
+    if call.max_total_stake > 0 && pool > call.max_total_stake {
+        return Err(CallRegistryError::CallStakeCapExceeded);
+    }
+    Ok(())
+}
+
+/// Check that adding `amount` to `staker`'s stake on `position` keeps within
+/// the admin's per-position cap and the call's per-address cap. With no
+/// `position` (a hidden commit) `amount` alone must fit the per-position cap.
+/// # Errors
+/// * [`CallRegistryError::AddressStakeCapExceeded`] – either cap would be exceeded.
+fn check_staker_caps(
+    env: &Env,
+    config: &ContractConfig,
+    call: &Call,
+    staker: &Address,
+    position: Option<u32>,
+    amount: i128,
+) -> Result<(), CallRegistryError> {
+    let position_stake = position
+        .and_then(|position| call.stakes.get(position))
+        .and_then(|stakers| stakers.get(staker.clone()))
+        .unwrap_or(0);
+    let user_stake = math::add(env, position_stake, amount);
+    if config.max_stake_per_user > 0 && user_stake > config.max_stake_per_user {
+        return Err(CallRegistryError::AddressStakeCapExceeded);
+    }
+    let address_stake = math::add(env, staker_total_stake(call, staker), amount);
+    if call.max_stake_per_address > 0 && address_stake > call.max_stake_per_address {
+        return Err(CallRegistryError::AddressStakeCapExceeded);
+    }
+    Ok(())
+}
+
+/// Validate and apply a stake of `amount` on `position`, pulling the tokens
+/// from `staker` as described by `funding`. Shared by the staking
+/// entrypoints; the caller handles auth and emits its own event.
+fn place_stake(
+    env: &Env,
+    staker: &Address,
+    call_id: u64,
+    amount: i128,
+    position: u32,
+    funding: StakeFunding,
+) -> Result<Call, CallRegistryError> {
+    if amount <= 0 {
+        return Err(CallRegistryError::InvalidStakeAmount);
+    }
+
+    let config = get_config(env).ok_or(CallRegistryError::NotInitialized)?;
+    ensure_unpaused(&config, PauseComponent::Stake)?;
+    ensure_not_banned(env, staker);
+    if amount < config.min_stake {
+        return Err(CallRegistryError::StakeBelowMinimum);
+    }
+
+    let mut call = get_call(env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+
+    check_staking_open(env, &config, &call)?;
+
+    // Validate position is within valid range
+    if position < 1 || position > call.outcome_count {
+        return Err(CallRegistryError::InvalidPosition);
+    }
+
+    check_stake_terms(env, &call, staker, amount)?;
+    check_staker_caps(env, &config, &call, staker, Some(position), amount)?;
+    let staker_stake = staker_total_stake(&call, staker);
+
+    // New stakers grow the call's stake maps; refuse them once the call is full.
+    if staker_stake == 0
+        && config.max_stakers_per_call > 0
+        && get_call_stakers(env, call_id).len() >= config.max_stakers_per_call
+    {
+        return Err(CallRegistryError::CallFull);
+    }
+
+    // Transfer tokens in — supports both native XLM and SAC-wrapped tokens.
+    match funding {
+        StakeFunding::Transfer => transfer_token(
+            env,
+            &call.stake_token,
+            staker,
+            &env.current_contract_address(),
+            amount,
+        ),
+        StakeFunding::Allowance => transfer_token_from(env, &call.stake_token, staker, amount),
+        StakeFunding::Prefunded => {}
+    }
+
+    let now = env.ledger().timestamp();
+    let extension = anti_snipe::snipe_extension(&config, &call, now, amount);
+
+    record_position_stake(
+        env,
+        &mut call,
+        staker,
+        position,
+        amount,
+        config.max_time_bonus_bps,
+    );
+    record_stake(env, staker, amount);
+    accrue_loyalty_points(env, staker, amount, staker_stake == 0);
+    if extension > 0 {
+        let old_end_ts = call.end_ts;
+        call.end_ts = call.end_ts.saturating_add(extension);
+        call.deadline_extended_secs += extension;
+        if old_end_ts / END_TS_BUCKET_SECS != call.end_ts / END_TS_BUCKET_SECS {
+            remove_end_ts_bucket_call(env, old_end_ts, call_id);
+            add_end_ts_bucket_call(env, call.end_ts, call_id);
+        }
+        emit_call_deadline_extended(env, call_id, staker, old_end_ts, call.end_ts);
+    }
+    set_call(env, &call);
+    extend_storage_ttl(env);
+
+    Ok(call)
+}
+
+/// Write the initial config and grant `admin` every [`Role`]. Shared by the
+/// constructor and the legacy `initialize` entrypoint.
+fn init(env: &Env, admin: Address, outcome_manager: Address, min_stake: i128) {
+    let config = ContractConfig {
+        admin: admin.clone(),
+        outcome_manager: outcome_manager.clone(),
+        fee_bps: 0,
+        max_stake_per_user: 0,
+        whitelisted_tokens: Map::new(env),
+        min_stake,
+        metadata_version: 0,
+        pause_state: PauseState::default(),
+        staking_cutoff_secs: 300,
+        share_wasm_hash: None,
+        draw_tolerance_bps: 0,
+        min_call_duration_secs: 0,
+        max_call_duration_secs: 0,
+        max_time_bonus_bps: 0,
+        snipe_window_secs: 0,
+        snipe_threshold_bps: 0,
+        snipe_extension_secs: 0,
+        max_snipe_extension_secs: 0,
+        max_stakers_per_call: DEFAULT_MAX_STAKERS_PER_CALL,
+        reduce_fee_bps: DEFAULT_REDUCE_FEE_BPS,
+        switch_fee_bps: DEFAULT_SWITCH_FEE_BPS,
+        fixed_odds_spread_bps: DEFAULT_FIXED_ODDS_SPREAD_BPS,
+        referral_share_bps: DEFAULT_REFERRAL_SHARE_BPS,
+        streak_bonus_bps: DEFAULT_STREAK_BONUS_BPS,
+        max_streak_bonus_bps: DEFAULT_MAX_STREAK_BONUS_BPS,
+        instance_ttl_ledgers: DEFAULT_INSTANCE_TTL_LEDGERS,
+        persistent_ttl_ledgers: DEFAULT_PERSISTENT_TTL_LEDGERS,
+        temporary_ttl_ledgers: DEFAULT_TEMPORARY_TTL_LEDGERS,
+        timelock_delay_secs: DEFAULT_TIMELOCK_DELAY_SECS,
+        creator_bond: 0,
+        max_calls_per_window: 0,
+        call_rate_window_secs: DEFAULT_CALL_RATE_WINDOW_SECS,
+        dispute_window_secs: 0,
+    };
+
+    set_config(env, &config);
+    for role in ALL_ROLES {
+        add_role(env, role, &admin);
+    }
+    set_contract_version(env, &CONTRACT_VERSION);
+    set_build_info(env, None);
+    set_storage_layout(env, STORAGE_LAYOUT_VERSION);
+    extend_storage_ttl(env);
+
+    events::publish(env, "initialized", (admin, outcome_manager));
+}
+
+#[cfg(not(feature = "legacy-initialize"))]
+#[contractimpl]
+impl CallRegistry {
+    /// Initialise the contract with an admin and an outcome manager as part
+    /// of deployment, so it never exists unconfigured. The admin starts out
+    /// holding every [`Role`].
+    pub fn __constructor(env: Env, admin: Address, outcome_manager: Address, min_stake: i128) {
+        init(&env, admin, outcome_manager, min_stake);
+    }
+}
+
+/// Two-step deployment for targets whose SDK predates constructors. Whoever
+/// calls `initialize` first becomes admin, so deploy and initialise in the
+/// same transaction.
+#[cfg(feature = "legacy-initialize")]
 #[contractimpl]
 impl CallRegistry {
-    /// Initialise the contract with an admin and an outcome manager.
+    /// Initialise the contract with an admin and an outcome manager. The
+    /// admin starts out holding every [`Role`].
     /// # Errors
     /// * [`CallRegistryError::AlreadyInitialized`] – called more than once.
     pub fn initialize(
@@ -115,43 +917,16 @@ impl CallRegistry {
         }
 
         admin.require_auth();
-
-        let config = ContractConfig {
-            admin: admin.clone(),
-            outcome_manager: outcome_manager.clone(),
-            fee_bps: 0,
-            max_stake_per_user: 0,
-            whitelisted_tokens: Map::new(&env),
-            min_stake,
-            metadata_version: 0,
-            paused: false,
-            staking_cutoff_secs: 300,
-            share_wasm_hash: None,
-        };
-
-        set_config(&env, &config);
-        env.storage()
-            .instance()
-            .set(&Symbol::new(&env, "version"), &CONTRACT_VERSION);
-        // Track the 'version' instance key (Config key tracked inside set_config)
-        inc_instance_entry_count(&env, 1);
-        extend_storage_ttl(&env);
-
-        env.events()
-            .publish(("call_registry", "initialized"), (admin, outcome_manager));
+        init(&env, admin, outcome_manager, min_stake);
 
         Ok(())
     }
+}
 
-    /// Test-only: register the XLM SAC address so is_native_xlm works in tests.
-    #[cfg(test)]
-    pub fn set_xlm_sac_address(env: Env, xlm_sac: Address) {
-        env.storage()
-            .instance()
-            .set(&Symbol::new(&env, "xlm_sac_addr"), &xlm_sac);
-    }
-
-    /// Set the share token WASM hash (admin only).
+#[contractimpl]
+impl CallRegistry {
+    /// Set the share token WASM hash (admin only): the `position_token`
+    /// contract deployed per outcome of each new call.
     /// Must be called after initialize before create_call can deploy share tokens.
     pub fn set_share_wasm_hash(
         env: Env,
@@ -166,9 +941,32 @@ impl CallRegistry {
 
     /// Create a new prediction call.
     /// # Errors
-    /// * [`CallRegistryError::InvalidStakeAmount`] – `stake_amount` ≤ 0.
+    /// * [`CallRegistryError::InvalidStakeAmount`] – `stake_amount` ≤ 0, or a negative
+    ///   seed or cap.
+    /// * [`CallRegistryError::CallStakeCapExceeded`] – seeds exceed `max_total_stake`.
+    /// * Any [`Self::stake_on_call`] error for a seed: `seed_up` / `seed_down` are
+    ///   placed as the creator's stakes, so they must meet the minimum stake, the
+    ///   stake model and the per-address cap. A private call cannot be seeded, as
+    ///   its creator is not yet on the allowlist.
     /// * [`CallRegistryError::InvalidEndTime`] – `end_ts` is not in the future.
+    /// * [`CallRegistryError::CallDurationTooShort`] / [`CallRegistryError::CallDurationTooLong`]
+    ///   – `end_ts − now` is outside the configured duration bounds.
     /// * [`CallRegistryError::InvalidOutcomeCount`] – `outcome_count` < 2.
+    /// * [`CallRegistryError::InvalidParentCall`]   – `parent_call_id` is set but unusable.
+    /// * [`CallRegistryError::EarlyResolutionNotSupported`] – early resolution requested
+    ///   for a non-threshold condition.
+    /// # Panics
+    /// * [`CallRegistryExtError::InvalidPairId`]  – `pair_id` is not a sane `BASE/QUOTE`.
+    /// * [`CallRegistryExtError::InvalidIpfsCid`] – `ipfs_cid` is not 32–64 CID characters.
+    /// * [`CallRegistryExtError::AssetNotRegistered`] – `pair_id` is not a registered asset.
+    /// * [`CallRegistryExtError::AssetMismatch`]      – `token_address` differs from the
+    ///   asset's.
+    /// * [`CallRegistryExtError::TokenNotWhitelisted`] – `stake_token` is not accepted.
+    /// * [`CallRegistryExtError::CallRateLimited`] – `creator` has hit
+    ///   `max_calls_per_window`.
+    /// * [`CallRegistryExtError::AddressBanned`] – `creator` is banned.
+    /// * [`CallRegistryExtError::AmountOverflow`] – the seeds overflow `i128`.
+    /// * [`CallRegistryExtError::CounterOverflow`] – call ids are exhausted.
     pub fn create_call(
         env: Env,
         creator: Address,
@@ -187,17 +985,33 @@ impl CallRegistry {
             metadata_hash,
             condition,
             outcome_count,
+            parent_call_id,
+            parent_outcome,
+            allow_early_resolution,
+            is_private,
+            seed_up,
+            seed_down,
+            max_total_stake,
+            max_stake_per_address,
+            stake_model,
         } = args;
 
         let mut share_tokens = Map::new(&env);
         let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
-        assert!(!config.paused, "Contract is paused");
+        ensure_unpaused(&config, PauseComponent::Create)?;
+        ensure_not_banned(&env, &creator);
         if stake_amount < config.min_stake || stake_amount <= 0 {
             return Err(CallRegistryError::InvalidStakeAmount);
         }
-        if start_price <= 0 {
+        if start_price <= 0 || seed_up < 0 || seed_down < 0 {
+            return Err(CallRegistryError::InvalidStakeAmount);
+        }
+        if max_total_stake < 0 || max_stake_per_address < 0 {
             return Err(CallRegistryError::InvalidStakeAmount);
         }
+        if max_total_stake > 0 && math::add(&env, seed_up, seed_down) > max_total_stake {
+            return Err(CallRegistryError::CallStakeCapExceeded);
+        }
 
         if outcome_count < 2 {
             return Err(CallRegistryError::InvalidOutcomeCount);
@@ -207,8 +1021,34 @@ impl CallRegistry {
         if end_ts <= current_timestamp {
             return Err(CallRegistryError::InvalidEndTime);
         }
+        duration::check_call_duration(&config, current_timestamp, end_ts)?;
+
+        if allow_early_resolution && !is_threshold_condition(&condition) {
+            return Err(CallRegistryError::EarlyResolutionNotSupported);
+        }
+
+        validation::validate_pair_id(&env, &pair_id);
+        validation::validate_ipfs_cid(&env, &ipfs_cid);
+        let Some(asset) = get_predicted_asset(&env, &pair_id) else {
+            panic_with_error!(&env, CallRegistryExtError::AssetNotRegistered);
+        };
+        if asset.token_address != token_address {
+            panic_with_error!(&env, CallRegistryExtError::AssetMismatch);
+        }
+
+        if parent_call_id != 0 {
+            let parent =
+                get_call(&env, parent_call_id).ok_or(CallRegistryError::InvalidParentCall)?;
+            if parent.voided
+                || parent.cancelled
+                || parent.end_ts > end_ts
+                || parent_outcome < 1
+                || parent_outcome > parent.outcome_count
+            {
+                return Err(CallRegistryError::InvalidParentCall);
+            }
+        }
 
-        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
         // Native XLM (sentinel address) is always allowed; SAC tokens must be whitelisted.
         if !is_native_xlm(&env, &stake_token)
             && !config
@@ -216,27 +1056,38 @@ impl CallRegistry {
                 .get(stake_token.clone())
                 .unwrap_or(false)
         {
-            panic!("stake token not whitelisted");
+            panic_with_error!(&env, CallRegistryExtError::TokenNotWhitelisted);
         }
+        rate_limit::record_call_creation(&env, &config, &creator, current_timestamp);
         let call_id = next_call_id(&env);
 
         let mut outcome_stakes = Map::new(&env);
+        let mut weighted_outcome_stakes = Map::new(&env);
         let mut stakes = Map::new(&env);
 
         // Initialize maps for each outcome
         for i in 1..=outcome_count {
             outcome_stakes.set(i, 0);
+            weighted_outcome_stakes.set(i, 0);
             stakes.set(i, Map::new(&env));
         }
 
         if let Some(ref wasm_hash) = config.share_wasm_hash {
+            let decimals = token::Client::new(&env, &stake_token).decimals();
             for i in 1..=outcome_count {
-                let token_addr = shares::deploy_share_token(&env, wasm_hash, call_id, i);
+                let token_addr = shares::deploy_share_token(
+                    &env,
+                    wasm_hash,
+                    call_id,
+                    i,
+                    outcome_count,
+                    decimals,
+                );
                 share_tokens.set(i, token_addr);
             }
         }
 
-        let call = Call {
+        let mut call = Call {
             id: call_id,
             creator: creator.clone(),
             stake_token: stake_token.clone(),
@@ -258,15 +1109,60 @@ impl CallRegistry {
             cancelled: false,
             metadata_version: 0,
             share_tokens,
+            parent_call_id,
+            parent_outcome,
+            allow_early_resolution,
+            early_resolved: false,
+            is_private,
+            weighted_outcome_stakes,
+            deadline_extended_secs: 0,
+            max_total_stake,
+            max_stake_per_address,
+            stake_model,
+            penalty_pool: 0,
         };
 
+        if config.creator_bond > 0 {
+            transfer_token(
+                &env,
+                &stake_token,
+                &creator,
+                &env.current_contract_address(),
+                config.creator_bond,
+            );
+            set_creator_bond(&env, call_id, config.creator_bond);
+        }
+
         set_call(&env, &call);
+        add_creator_call(&env, &creator, call_id);
+        add_active_call(&env, call_id);
+        add_end_ts_bucket_call(&env, end_ts, call_id);
         record_call_created(&env);
 
+        // Creator-seeded liquidity is a regular stake on UP / DOWN, validated
+        // exactly like one placed through `stake_on_call`.
+        for (position, seed) in [(OUTCOME_UP, seed_up), (OUTCOME_DOWN, seed_down)] {
+            if seed > 0 {
+                call = place_stake(
+                    &env,
+                    &creator,
+                    call_id,
+                    seed,
+                    position,
+                    StakeFunding::Transfer,
+                )?;
+            }
+        }
+
         // Track creator reputation: increment total_created
         let mut creator_stats = get_creator_stats(&env, &creator);
-        creator_stats.total_created += 1;
+        creator_stats.total_created = math::increment(&env, creator_stats.total_created);
         set_creator_stats(&env, &creator, &creator_stats);
+        notify_reputation(
+            &env,
+            "record_call_created",
+            (call_id, creator.clone()).into_val(&env),
+        );
 
         extend_storage_ttl(&env);
 
@@ -298,6 +1194,18 @@ impl CallRegistry {
                 outcome_count,
             );
         }
+        if config.creator_bond > 0 {
+            emit_creator_bond_posted(&env, call_id, &creator, config.creator_bond);
+        }
+        for (position, seed) in [(OUTCOME_UP, seed_up), (OUTCOME_DOWN, seed_down)] {
+            if seed > 0 {
+                if is_native_xlm(&env, &stake_token) {
+                    emit_xlm_stake_added(&env, call_id, &creator, seed, position);
+                } else {
+                    emit_stake_added(&env, call_id, &creator, seed, position);
+                }
+            }
+        }
 
         // Write immutable metadata to the contract's Stellar account DataEntries.
         // Key names: `call_{call_id}_cid` and `call_{call_id}_hash`.
@@ -310,7 +1218,9 @@ impl CallRegistry {
             let mut i = 0u32;
             let input_len = input.len();
             while i + 3 <= input_len {
-                if out_idx + 4 > out_buf.len() { break; } // safety bound
+                if out_idx + 4 > out_buf.len() {
+                    break;
+                } // safety bound
                 let b0 = input.get(i).unwrap_or(0);
                 let b1 = input.get(i + 1).unwrap_or(0);
                 let b2 = input.get(i + 2).unwrap_or(0);
@@ -391,7 +1301,7 @@ impl CallRegistry {
     /// Documentation: DataEntry vs Soroban Storage
     /// * DataEntry: Use for immutable metadata (e.g. IPFS CID at call creation). Costs 0.5 XLM once, free to read off-chain.
     /// * Soroban Storage: Use for mutable state (e.g. stakes, resolution status) that the contract logic must update and read.
-    /// 
+    ///
     /// Cost Comparison:
     /// Storing a 60-byte IPFS string in Soroban persistent storage inflates ledger size and increases rent.
     /// Using a 32-byte hash reference saves ~50% byte allocation in state, while the full CID is available
@@ -412,14 +1322,17 @@ impl CallRegistry {
         let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
 
         if call.creator != creator {
-            panic!("not the call creator");
+            return Err(CallRegistryError::Unauthorized);
+        }
+        if call.settled {
+            return Err(CallRegistryError::CallSettled);
         }
-        if call.settled || call.cancelled {
-            panic!("call is ended or cancelled");
+        if call.cancelled {
+            panic_with_error!(&env, CallRegistryExtError::CallCancelled);
         }
         let current_ts = env.ledger().timestamp();
         if current_ts >= call.end_ts {
-            panic!("call has expired");
+            return Err(CallRegistryError::CallEnded);
         }
 
         let old_hash = call.metadata_hash.clone();
@@ -437,103 +1350,1042 @@ impl CallRegistry {
         );
         Ok(())
     }
-    /// Extend the TTL of a specific call's persistent storage entry.
-    /// Anyone may call this to prevent an active call from being archived.
+    /// Add (`allowed = true`) or remove stakers from a private call's allowlist.
+    /// Only the call's creator may manage the list.
     /// # Errors
-    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
-    pub fn extend_call_ttl(env: Env, call_id: u64) -> Result<(), CallRegistryError> {
-        let key = storage::DataKey::Call(call_id);
-        if !env.storage().persistent().has(&key) {
-            return Err(CallRegistryError::CallNotFound);
+    /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    /// * [`CallRegistryError::Unauthorized`]   – `creator` did not create the call.
+    /// * [`CallRegistryError::CallNotPrivate`] – the call is public.
+    pub fn update_call_allowlist(
+        env: Env,
+        creator: Address,
+        call_id: u64,
+        stakers: Vec<Address>,
+        allowed: bool,
+    ) -> Result<(), CallRegistryError> {
+        creator.require_auth();
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+
+        if call.creator != creator {
+            return Err(CallRegistryError::Unauthorized);
+        }
+        if !call.is_private {
+            return Err(CallRegistryError::CallNotPrivate);
+        }
+
+        for staker in stakers.iter() {
+            set_call_allowlisted(&env, call_id, &staker, allowed);
+            emit_call_allowlist_updated(&env, call_id, &staker, allowed);
         }
-        env.storage().persistent().extend_ttl(
-            &key,
-            storage::PERSISTENT_LIFETIME_THRESHOLD,
-            storage::PERSISTENT_BUMP_AMOUNT,
-        );
         Ok(())
     }
 
-    pub fn whitelist_token(env: Env, token_address: Address) {
-        admin::whitelist_token(env, token_address);
+    /// Whether `staker` may stake on `call_id`. Always `true` for public calls.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn is_allowlisted(
+        env: Env,
+        call_id: u64,
+        staker: Address,
+    ) -> Result<bool, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(!call.is_private || is_call_allowlisted(&env, call_id, &staker))
     }
 
-    pub fn remove_token(env: Env, token_address: Address) {
-        admin::remove_token(env, token_address);
-    }
+    /// Extend the TTL of a specific call's persistent storage entry.
+    /// Anyone may call this to prevent an active call from being archived.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn extend_call_ttl(env: Env, call_id: u64) -> Result<(), CallRegistryError> {
+        if !call_exists(&env, call_id) {
+            return Err(CallRegistryError::CallNotFound);
+        }
+        extend_persistent_ttl(&env, &storage::DataKey::Call(call_id));
+        Ok(())
+    }
+
+    pub fn whitelist_token(env: Env, token_address: Address) -> Result<(), CallRegistryError> {
+        admin::whitelist_token(env, token_address)
+    }
+
+    pub fn remove_token(env: Env, token_address: Address) -> Result<(), CallRegistryError> {
+        admin::remove_token(env, token_address)
+    }
+
+    pub fn is_token_whitelisted(env: Env, token_address: Address) -> bool {
+        let Some(config) = get_config(&env) else {
+            panic_with_error!(&env, CallRegistryError::NotInitialized);
+        };
+        config
+            .whitelisted_tokens
+            .get(token_address)
+            .unwrap_or(false)
+    }
+
+    /// Add a predicted asset, or replace the one registered under its
+    /// `pair_id` (admin only).
+    /// # Errors
+    /// Propagates errors from [`admin::register_asset`].
+    pub fn register_asset(env: Env, asset: PredictedAsset) -> Result<(), CallRegistryError> {
+        admin::register_asset(env, asset)
+    }
+
+    /// Remove a predicted asset so no new calls can be made on it (admin only).
+    /// # Errors
+    /// Propagates errors from [`admin::remove_asset`].
+    pub fn remove_asset(env: Env, pair_id: Bytes) -> Result<(), CallRegistryError> {
+        admin::remove_asset(env, pair_id)
+    }
+
+    /// Return the predicted asset registered for `pair_id`, if any.
+    pub fn get_asset(env: Env, pair_id: Bytes) -> Option<PredictedAsset> {
+        get_predicted_asset(&env, &pair_id)
+    }
+
+    /// Add stake to an existing call.
+    /// # Errors
+    /// * [`CallRegistryError::InvalidStakeAmount`] – `amount` ≤ 0.
+    /// * [`CallRegistryError::CallNotFound`]        – `call_id` does not exist.
+    /// * [`CallRegistryError::CallEnded`]           – call's `end_ts` has passed.
+    /// * [`CallRegistryError::CallSettled`]         – call is already settled.
+    /// * [`CallRegistryError::InvalidPosition`]     – `position` ∉ [1, outcome_count].
+    /// * [`CallRegistryError::StakeBelowMinimum`]   – `amount` < the call's `stake_amount`.
+    /// * [`CallRegistryError::InvalidTicketAmount`] – fixed-ticket call and `amount` ≠
+    ///   `stake_amount`.
+    /// * [`CallRegistryError::StakerNotAllowlisted`] – private call and `staker` is not listed.
+    /// * [`CallRegistryError::CallStakeCapExceeded`] – pool would exceed `max_total_stake`.
+    /// * [`CallRegistryError::AddressStakeCapExceeded`] – staker would exceed
+    ///   `max_stake_per_address`.
+    /// * [`CallRegistryError::CallFull`] – a new staker on a call at `max_stakers_per_call`.
+    /// # Panics
+    /// * [`CallRegistryExtError::AddressBanned`] – `staker` is banned.
+    /// * [`CallRegistryExtError::AmountOverflow`] – the pool would overflow `i128`.
+    pub fn stake_on_call(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+        amount: i128,
+        position: u32,
+    ) -> Result<Call, CallRegistryError> {
+        staker.require_auth();
+
+        let call = place_stake(
+            &env,
+            &staker,
+            call_id,
+            amount,
+            position,
+            StakeFunding::Transfer,
+        )?;
+        emit_new_stake(&env, &call, &staker, amount, position);
+
+        Ok(call)
+    }
+
+    /// Stake on behalf of `staker` using an allowance `staker` granted to this
+    /// contract, so a relayer (`spender`) can submit the transaction for
+    /// session-key and smart-wallet flows. Both `spender` and `staker` must
+    /// authorise the call; the tokens move via SEP-41 `transfer_from`.
+    /// # Errors
+    /// Same as [`stake_on_call`](Self::stake_on_call).
+    pub fn stake_on_call_from(
+        env: Env,
+        spender: Address,
+        staker: Address,
+        call_id: u64,
+        amount: i128,
+        position: u32,
+    ) -> Result<Call, CallRegistryError> {
+        spender.require_auth();
+        staker.require_auth();
+
+        let call = place_stake(
+            &env,
+            &staker,
+            call_id,
+            amount,
+            position,
+            StakeFunding::Allowance,
+        )?;
+        emit_new_stake(&env, &call, &staker, amount, position);
+        emit_stake_relayed(&env, call_id, &spender, &staker, amount);
+
+        Ok(call)
+    }
+
+    /// Register `sponsor` as a paymaster, or update its caps. The sponsor
+    /// covers transaction fees for first-time stakers by fee-bumping their
+    /// [`sponsored_stake`](Self::sponsored_stake) transactions. A zero cap
+    /// suspends sponsorship without deregistering.
+    pub fn register_sponsor(env: Env, sponsor: Address, per_user_cap: u32, daily_cap: u32) {
+        sponsor.require_auth();
+
+        set_sponsor(
+            &env,
+            &Sponsor {
+                sponsor: sponsor.clone(),
+                per_user_cap,
+                daily_cap,
+            },
+        );
+        emit_sponsor_registered(&env, &sponsor, per_user_cap, daily_cap);
+    }
+
+    /// Stop sponsoring stakes.
+    /// # Errors
+    /// * [`CallRegistryError::SponsorNotFound`] – `sponsor` is not registered.
+    pub fn remove_sponsor(env: Env, sponsor: Address) -> Result<(), CallRegistryError> {
+        sponsor.require_auth();
+
+        if get_sponsor(&env, &sponsor).is_none() {
+            return Err(CallRegistryError::SponsorNotFound);
+        }
+        remove_sponsor(&env, &sponsor);
+        emit_sponsor_removed(&env, &sponsor);
+
+        Ok(())
+    }
+
+    /// Stake as [`stake_on_call`](Self::stake_on_call) in a transaction whose
+    /// fees `sponsor` pays. Both must authorise. Only stakers new to the
+    /// protocol, or already onboarded by this sponsor, qualify, and the
+    /// sponsor's per-user and per-day caps are enforced here.
+    /// # Errors
+    /// * [`CallRegistryError::SponsorNotFound`]    – `sponsor` is not registered.
+    /// * [`CallRegistryError::NotFirstTimeStaker`] – `staker` already staked
+    ///   without this sponsor.
+    /// * [`CallRegistryError::SponsorCapReached`]  – `staker` has used up
+    ///   `per_user_cap`, or the sponsor has used up today's `daily_cap`.
+    /// * Any error returned by [`stake_on_call`](Self::stake_on_call).
+    pub fn sponsored_stake(
+        env: Env,
+        sponsor: Address,
+        staker: Address,
+        call_id: u64,
+        amount: i128,
+        position: u32,
+    ) -> Result<Call, CallRegistryError> {
+        sponsor.require_auth();
+        staker.require_auth();
+
+        let caps = get_sponsor(&env, &sponsor).ok_or(CallRegistryError::SponsorNotFound)?;
+        let used = get_sponsored_count(&env, &sponsor, &staker);
+        if used == 0 && is_known_staker(&env, &staker) {
+            return Err(CallRegistryError::NotFirstTimeStaker);
+        }
+        if used >= caps.per_user_cap {
+            return Err(CallRegistryError::SponsorCapReached);
+        }
+        let day = env.ledger().timestamp() / 86_400;
+        let used_today = get_sponsor_day_usage(&env, &sponsor, day);
+        if used_today >= caps.daily_cap {
+            return Err(CallRegistryError::SponsorCapReached);
+        }
+
+        let call = place_stake(
+            &env,
+            &staker,
+            call_id,
+            amount,
+            position,
+            StakeFunding::Transfer,
+        )?;
+        set_sponsored_count(&env, &sponsor, &staker, used + 1);
+        set_sponsor_day_usage(&env, &sponsor, day, used_today + 1);
+        emit_new_stake(&env, &call, &staker, amount, position);
+        emit_stake_sponsored(&env, call_id, &sponsor, &staker);
+
+        Ok(call)
+    }
+
+    /// Get a paymaster's sponsorship caps.
+    pub fn get_sponsor(env: Env, sponsor: Address) -> Option<Sponsor> {
+        get_sponsor(&env, &sponsor)
+    }
+
+    /// Get how many stakes `sponsor` has covered for `staker`.
+    pub fn get_sponsored_count(env: Env, sponsor: Address, staker: Address) -> u32 {
+        get_sponsored_count(&env, &sponsor, &staker)
+    }
+
+    /// Get how many stakes `sponsor` has covered today (UTC).
+    pub fn get_sponsor_usage_today(env: Env, sponsor: Address) -> u32 {
+        get_sponsor_day_usage(&env, &sponsor, env.ledger().timestamp() / 86_400)
+    }
+
+    /// Stake as [`stake_on_call`](Self::stake_on_call), crediting `referrer` for
+    /// bringing `staker` in. The first referrer a staker stakes with is kept
+    /// for good; on every later payout to the staker, `referral_share_bps` of
+    /// the protocol fee accrues to that referrer.
+    /// # Errors
+    /// * [`CallRegistryError::SelfReferral`] – `referrer` is `staker`.
+    /// * Any error returned by [`stake_on_call`](Self::stake_on_call).
+    pub fn stake_on_call_with_referrer(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+        amount: i128,
+        position: u32,
+        referrer: Address,
+    ) -> Result<Call, CallRegistryError> {
+        staker.require_auth();
+
+        if referrer == staker {
+            return Err(CallRegistryError::SelfReferral);
+        }
+
+        let call = place_stake(
+            &env,
+            &staker,
+            call_id,
+            amount,
+            position,
+            StakeFunding::Transfer,
+        )?;
+        if get_referrer(&env, &staker).is_none() {
+            set_referrer(&env, &staker, &referrer);
+            emit_referrer_set(&env, &staker, &referrer);
+        }
+        emit_new_stake(&env, &call, &staker, amount, position);
+
+        Ok(call)
+    }
+
+    /// Get the referrer a staker is bound to, if any.
+    pub fn get_referrer(env: Env, staker: Address) -> Option<Address> {
+        get_referrer(&env, &staker)
+    }
+
+    /// Get the number of stakers bound to a referrer.
+    pub fn get_referred_count(env: Env, referrer: Address) -> u32 {
+        get_referred_count(&env, &referrer)
+    }
+
+    /// Get a referrer's unclaimed rewards in `token`.
+    pub fn get_referral_rewards(env: Env, referrer: Address, token: Address) -> i128 {
+        get_referral_rewards(&env, &referrer, &token)
+    }
+
+    /// Total protocol fees paid to the fee collector in `token`, net of
+    /// referral cuts.
+    pub fn get_protocol_fees(env: Env, token: Address) -> i128 {
+        get_protocol_fees(&env, &token)
+    }
+
+    /// Withdraw a referrer's accrued rewards in `token`. Returns the amount.
+    /// # Errors
+    /// * [`CallRegistryError::NothingToClaim`] – no rewards accrued in `token`.
+    pub fn claim_referral_rewards(
+        env: Env,
+        referrer: Address,
+        token: Address,
+    ) -> Result<i128, CallRegistryError> {
+        referrer.require_auth();
+
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Claim)?;
+
+        let amount = get_referral_rewards(&env, &referrer, &token);
+        if amount <= 0 {
+            return Err(CallRegistryError::NothingToClaim);
+        }
+
+        set_referral_rewards(&env, &referrer, &token, 0);
+        transfer_token(
+            &env,
+            &token,
+            &env.current_contract_address(),
+            &referrer,
+            amount,
+        );
+        emit_referral_rewards_claimed(&env, &referrer, &token, amount);
+
+        Ok(amount)
+    }
+
+    /// Top up an existing position. Follows the same rules as
+    /// [`stake_on_call`](Self::stake_on_call) but emits `stake_increased`.
+    /// # Errors
+    /// * [`CallRegistryError::NoExistingStake`] – `staker` has no stake on `position`.
+    /// * Any error returned by [`stake_on_call`](Self::stake_on_call).
+    pub fn increase_stake(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+        amount: i128,
+        position: u32,
+    ) -> Result<Call, CallRegistryError> {
+        staker.require_auth();
+
+        if get_user_stake(&env, call_id, &staker, position) == 0 {
+            return Err(CallRegistryError::NoExistingStake);
+        }
+
+        let call = place_stake(
+            &env,
+            &staker,
+            call_id,
+            amount,
+            position,
+            StakeFunding::Transfer,
+        )?;
+        emit_stake_increased(&env, call_id, &staker, amount, position);
+
+        Ok(call)
+    }
+
+    /// Withdraw part or all of a position before the staking deadline.
+    /// `reduce_fee_bps` of `amount` is kept in the call's `penalty_pool`; the
+    /// rest is returned to `staker`.
+    /// # Errors
+    /// * [`CallRegistryError::InvalidStakeAmount`]  – `amount` ≤ 0.
+    /// * [`CallRegistryError::CallNotFound`]        – `call_id` does not exist.
+    /// * [`CallRegistryError::CallEnded`] / [`CallRegistryError::StakingCutoffActive`] /
+    ///   [`CallRegistryError::CallSettled`] – staking is closed.
+    /// * [`CallRegistryError::InvalidPosition`]     – `position` ∉ [1, outcome_count].
+    /// * [`CallRegistryError::NoExistingStake`]     – `staker` has no stake on `position`.
+    /// * [`CallRegistryError::ReduceExceedsStake`]  – `amount` > the staker's stake.
+    /// * [`CallRegistryError::StakeBelowMinimum`]   – the remainder is below `stake_amount`.
+    /// * [`CallRegistryError::InvalidTicketAmount`] – fixed-ticket call and `amount` is not
+    ///   a whole number of tickets.
+    /// * [`CallRegistryExtError::FixedOddsExposureOpen`] – the call has fixed-odds tickets.
+    pub fn reduce_stake(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+        amount: i128,
+        position: u32,
+    ) -> Result<Call, CallRegistryError> {
+        staker.require_auth();
+
+        if amount <= 0 {
+            return Err(CallRegistryError::InvalidStakeAmount);
+        }
+
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Stake)?;
+
+        let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        check_staking_open(&env, &config, &call)?;
+        ensure_no_fixed_odds_exposure(&env, call_id);
+
+        if position < 1 || position > call.outcome_count {
+            return Err(CallRegistryError::InvalidPosition);
+        }
+
+        let current = call
+            .stakes
+            .get(position)
+            .and_then(|stakers| stakers.get(staker.clone()))
+            .unwrap_or(0);
+        if current == 0 {
+            return Err(CallRegistryError::NoExistingStake);
+        }
+        if amount > current {
+            return Err(CallRegistryError::ReduceExceedsStake);
+        }
+
+        let remaining = current - amount;
+        match call.stake_model {
+            StakeModel::FixedTicket if amount % call.stake_amount != 0 => {
+                return Err(CallRegistryError::InvalidTicketAmount);
+            }
+            StakeModel::MinimumFree if remaining > 0 && remaining < call.stake_amount => {
+                return Err(CallRegistryError::StakeBelowMinimum);
+            }
+            _ => {}
+        }
+
+        let fee = math::bps_of(&env, amount, config.reduce_fee_bps);
+        remove_position_stake(&env, &mut call, &staker, position, amount);
+        call.penalty_pool = math::add(&env, call.penalty_pool, fee);
+        set_call(&env, &call);
+        extend_storage_ttl(&env);
+
+        transfer_token(
+            &env,
+            &call.stake_token,
+            &env.current_contract_address(),
+            &staker,
+            amount - fee,
+        );
+
+        emit_stake_reduced(&env, call_id, &staker, amount, fee, position);
+
+        Ok(call)
+    }
+
+    /// Move the staker's whole stake from `from_position` to `to_position`
+    /// before the staking deadline. `switch_fee_bps` of the stake stays in the
+    /// call's `penalty_pool`; the rest is re-staked (and re-weighted) on
+    /// `to_position`.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::CallEnded`] / [`CallRegistryError::StakingCutoffActive`] /
+    ///   [`CallRegistryError::CallSettled`] – staking is closed.
+    /// * [`CallRegistryError::InvalidPosition`] – either position ∉ [1, outcome_count], or
+    ///   they are equal.
+    /// * [`CallRegistryError::NoExistingStake`] – `staker` has no stake on `from_position`.
+    /// * [`CallRegistryError::AddressStakeCapExceeded`] – the moved stake would exceed the
+    ///   per-position or per-address cap on `to_position`.
+    /// * [`CallRegistryExtError::FixedOddsExposureOpen`] – the call has fixed-odds tickets.
+    pub fn switch_position(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+        from_position: u32,
+        to_position: u32,
+    ) -> Result<Call, CallRegistryError> {
+        staker.require_auth();
+
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Stake)?;
+
+        let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        check_staking_open(&env, &config, &call)?;
+        ensure_no_fixed_odds_exposure(&env, call_id);
+
+        if from_position == to_position
+            || from_position < 1
+            || from_position > call.outcome_count
+            || to_position < 1
+            || to_position > call.outcome_count
+        {
+            return Err(CallRegistryError::InvalidPosition);
+        }
+
+        let stake = call
+            .stakes
+            .get(from_position)
+            .and_then(|stakers| stakers.get(staker.clone()))
+            .unwrap_or(0);
+        if stake == 0 {
+            return Err(CallRegistryError::NoExistingStake);
+        }
+
+        let fee = math::bps_of(&env, stake, config.switch_fee_bps);
+        let moved = stake - fee;
+        remove_position_stake(&env, &mut call, &staker, from_position, stake);
+        check_staker_caps(&env, &config, &call, &staker, Some(to_position), moved)?;
+        if moved > 0 {
+            record_position_stake(
+                &env,
+                &mut call,
+                &staker,
+                to_position,
+                moved,
+                config.max_time_bonus_bps,
+            );
+        }
+        call.penalty_pool = math::add(&env, call.penalty_pool, fee);
+        set_call(&env, &call);
+        extend_storage_ttl(&env);
+
+        emit_position_switched(
+            &env,
+            call_id,
+            &staker,
+            from_position,
+            to_position,
+            moved,
+            fee,
+        );
+
+        Ok(call)
+    }
+
+    /// Deposit `budget` to automatically stake `amount_per_call` on UP for every
+    /// new call `creator` makes in `stake_token`. Stakes are placed by a keeper
+    /// through [`execute_copy_stakes`](Self::execute_copy_stakes).
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`]     – contract not initialised.
+    /// * [`CallRegistryError::InvalidStakeAmount`] – `amount_per_call` below the
+    ///   minimum stake, or `budget` < `amount_per_call`.
+    /// * [`CallRegistryError::AlreadyFollowing`]   – `follower` already follows `creator`.
+    pub fn follow_creator(
+        env: Env,
+        follower: Address,
+        creator: Address,
+        stake_token: Address,
+        amount_per_call: i128,
+        budget: i128,
+        max_calls: u32,
+    ) -> Result<(), CallRegistryError> {
+        follower.require_auth();
+
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        if amount_per_call <= 0 || amount_per_call < config.min_stake || budget < amount_per_call {
+            return Err(CallRegistryError::InvalidStakeAmount);
+        }
+        if !is_native_xlm(&env, &stake_token)
+            && !config
+                .whitelisted_tokens
+                .get(stake_token.clone())
+                .unwrap_or(false)
+        {
+            panic_with_error!(&env, CallRegistryExtError::TokenNotWhitelisted);
+        }
+        if get_follow(&env, &follower, &creator).is_some() {
+            return Err(CallRegistryError::AlreadyFollowing);
+        }
+
+        transfer_token(
+            &env,
+            &stake_token,
+            &follower,
+            &env.current_contract_address(),
+            budget,
+        );
+
+        set_follow(
+            &env,
+            &Follow {
+                follower: follower.clone(),
+                creator: creator.clone(),
+                stake_token,
+                amount_per_call,
+                budget,
+                max_calls,
+                calls_copied: 0,
+            },
+        );
+        add_creator_follower(&env, &creator, &follower);
+        emit_follow_created(&env, &follower, &creator, amount_per_call, budget);
+
+        Ok(())
+    }
+
+    /// Stop copying `creator` and refund the unspent budget.
+    /// # Errors
+    /// * [`CallRegistryError::NotFollowing`] – `follower` does not follow `creator`.
+    pub fn unfollow_creator(
+        env: Env,
+        follower: Address,
+        creator: Address,
+    ) -> Result<i128, CallRegistryError> {
+        follower.require_auth();
+
+        let follow =
+            get_follow(&env, &follower, &creator).ok_or(CallRegistryError::NotFollowing)?;
+        remove_follow(&env, &follower, &creator);
+
+        if follow.budget > 0 {
+            transfer_token(
+                &env,
+                &follow.stake_token,
+                &env.current_contract_address(),
+                &follower,
+                follow.budget,
+            );
+        }
+        emit_follow_cancelled(&env, &follower, &creator, follow.budget);
+
+        Ok(follow.budget)
+    }
+
+    /// Keeper entrypoint: place copy stakes on `call_id` for up to `limit`
+    /// followers of its creator, starting at index `start` of the follower
+    /// list. Followers whose stake would be rejected (budget or cap exhausted,
+    /// wrong token, private call, …) are skipped. Returns the number of copy
+    /// stakes placed.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    /// * [`CallRegistryError::CallEnded`] / [`CallRegistryError::StakingCutoffActive`] /
+    ///   [`CallRegistryError::CallSettled`] – staking is closed.
+    pub fn execute_copy_stakes(
+        env: Env,
+        call_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<u32, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        check_staking_open(&env, &config, &call)?;
+
+        let followers = get_creator_followers(&env, &call.creator);
+        let end = start
+            .saturating_add(limit.min(MAX_CALL_PAGE_SIZE))
+            .min(followers.len());
+        let mut executed = 0u32;
+
+        for i in start..end {
+            let follower = followers.get(i).unwrap();
+            let Some(mut follow) = get_follow(&env, &follower, &call.creator) else {
+                continue;
+            };
+            if follow.stake_token != call.stake_token
+                || follow.budget < follow.amount_per_call
+                || (follow.max_calls > 0 && follow.calls_copied >= follow.max_calls)
+                || is_copy_executed(&env, call_id, &follower)
+                || is_banned(&env, &follower)
+            {
+                continue;
+            }
+
+            let amount = follow.amount_per_call;
+            let placed = place_stake(
+                &env,
+                &follower,
+                call_id,
+                amount,
+                OUTCOME_UP,
+                StakeFunding::Prefunded,
+            );
+            let Ok(staked) = placed else {
+                continue;
+            };
+
+            follow.budget -= amount;
+            follow.calls_copied = math::increment(&env, follow.calls_copied);
+            set_follow(&env, &follow);
+            set_copy_executed(&env, call_id, &follower);
+            emit_new_stake(&env, &staked, &follower, amount, OUTCOME_UP);
+            emit_copy_stake_executed(&env, call_id, &follower, amount);
+            executed += 1;
+        }
+
+        Ok(executed)
+    }
 
-    pub fn is_token_whitelisted(env: Env, token_address: Address) -> bool {
-        let config = get_config(&env).expect("not initialized");
-        config
-            .whitelisted_tokens
-            .get(token_address)
-            .unwrap_or(false)
+    /// Get a follower's copy-stake order for a creator, if any.
+    pub fn get_follow(env: Env, follower: Address, creator: Address) -> Option<Follow> {
+        get_follow(&env, &follower, &creator)
     }
 
-    /// Add stake to an existing call.
+    /// Get a page of the addresses copying a creator's calls, in follow order.
+    pub fn get_followers(env: Env, creator: Address, cursor: u32, limit: u32) -> AddressPage {
+        address_page(&get_creator_followers(&env, &creator), cursor, limit)
+    }
+
+    /// Escrow `amount` behind a hidden position. The staker later calls
+    /// [`reveal_stake`](Self::reveal_stake) with the position and salt behind
+    /// `commitment` (see [`stake_commitment`](Self::stake_commitment)) before the
+    /// staking deadline; the stake is weighted at reveal time. `amount` is
+    /// checked up front against the same minimums and caps as a stake, so a
+    /// commit that could never be revealed is refused.
     /// # Errors
     /// * [`CallRegistryError::InvalidStakeAmount`] – `amount` ≤ 0.
-    /// * [`CallRegistryError::CallNotFound`]        – `call_id` does not exist.
-    /// * [`CallRegistryError::CallEnded`]           – call's `end_ts` has passed.
-    /// * [`CallRegistryError::CallSettled`]         – call is already settled.
-    /// * [`CallRegistryError::InvalidPosition`]     – `position` ∉ [1, outcome_count].
-    pub fn stake_on_call(
+    /// * [`CallRegistryError::StakeBelowMinimum`] / [`CallRegistryError::InvalidTicketAmount`]
+    ///   – `amount` does not meet the minimum stake or the call's stake model.
+    /// * [`CallRegistryError::StakerNotAllowlisted`] – the call is private.
+    /// * [`CallRegistryError::CallStakeCapExceeded`] /
+    ///   [`CallRegistryError::AddressStakeCapExceeded`] – `amount` would exceed a stake cap.
+    /// * [`CallRegistryError::CallNotFound`]       – `call_id` does not exist.
+    /// * [`CallRegistryError::CommitExists`]       – an unrevealed commit is pending.
+    /// * [`CallRegistryError::CallEnded`] / [`CallRegistryError::StakingCutoffActive`] /
+    ///   [`CallRegistryError::CallSettled`] – staking is closed.
+    pub fn commit_stake(
         env: Env,
         staker: Address,
         call_id: u64,
+        commitment: BytesN<32>,
         amount: i128,
-        position: u32,
-    ) -> Result<Call, CallRegistryError> {
+    ) -> Result<(), CallRegistryError> {
         staker.require_auth();
 
         if amount <= 0 {
             return Err(CallRegistryError::InvalidStakeAmount);
         }
-
-        let config = get_config(&env).expect("not initialized");
-        assert!(!config.paused, "Contract is paused");
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Stake)?;
+        ensure_not_banned(&env, &staker);
         if amount < config.min_stake {
-            panic!("stake below minimum");
+            return Err(CallRegistryError::StakeBelowMinimum);
         }
 
-        let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        check_staking_open(&env, &config, &call)?;
+        if get_stake_commit(&env, call_id, &staker).is_some() {
+            return Err(CallRegistryError::CommitExists);
+        }
+        check_stake_terms(&env, &call, &staker, amount)?;
+        check_staker_caps(&env, &config, &call, &staker, None, amount)?;
 
-        let current_timestamp = env.ledger().timestamp();
-        if current_timestamp >= call.end_ts {
-            return Err(CallRegistryError::CallEnded);
+        transfer_token(
+            &env,
+            &call.stake_token,
+            &staker,
+            &env.current_contract_address(),
+            amount,
+        );
+        let commit = StakeCommit {
+            commitment,
+            amount,
+            committed_at: env.ledger().timestamp(),
+        };
+        set_stake_commit(&env, call_id, &staker, &commit);
+        emit_stake_committed(&env, call_id, &staker, amount);
+
+        Ok(())
+    }
+
+    /// Reveal a committed stake and place it on `position`. The reveal is
+    /// subject to the same checks as [`stake_on_call`](Self::stake_on_call).
+    /// # Errors
+    /// * [`CallRegistryError::CommitNotFound`] – no pending commit.
+    /// * [`CallRegistryError::CommitMismatch`] – `position` / `salt` do not match.
+    /// * Any error returned by [`stake_on_call`](Self::stake_on_call).
+    pub fn reveal_stake(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+        position: u32,
+        salt: BytesN<32>,
+    ) -> Result<Call, CallRegistryError> {
+        staker.require_auth();
+
+        let commit =
+            get_stake_commit(&env, call_id, &staker).ok_or(CallRegistryError::CommitNotFound)?;
+        if stake_commitment(&env, position, &salt, commit.amount) != commit.commitment {
+            return Err(CallRegistryError::CommitMismatch);
+        }
+
+        remove_stake_commit(&env, call_id, &staker);
+        let call = place_stake(
+            &env,
+            &staker,
+            call_id,
+            commit.amount,
+            position,
+            StakeFunding::Prefunded,
+        )?;
+
+        emit_new_stake(&env, &call, &staker, commit.amount, position);
+        emit_stake_revealed(&env, call_id, &staker, commit.amount, position);
+
+        Ok(call)
+    }
+
+    /// Refund a commit that was never revealed, once the reveal window has
+    /// closed (the staking deadline passed, or the call was settled, voided or
+    /// cancelled). Returns the refunded amount.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]        – `call_id` does not exist.
+    /// * [`CallRegistryError::CommitNotFound`]      – no pending commit.
+    /// * [`CallRegistryError::CommitNotRefundable`] – the commit can still be revealed.
+    pub fn refund_commit(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+    ) -> Result<i128, CallRegistryError> {
+        staker.require_auth();
+
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Claim)?;
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        let commit =
+            get_stake_commit(&env, call_id, &staker).ok_or(CallRegistryError::CommitNotFound)?;
+        if !(call.settled
+            || call.voided
+            || call.cancelled
+            || reveal_window_closed(&env, &config, &call))
+        {
+            return Err(CallRegistryError::CommitNotRefundable);
         }
 
-        // Staking cutoff: reject stakes within `staking_cutoff_secs` of end_ts.
-        let cutoff = config.staking_cutoff_secs;
-        if cutoff > 0 && call.end_ts > cutoff && current_timestamp >= call.end_ts - cutoff {
-            return Err(CallRegistryError::StakingCutoffActive);
+        remove_stake_commit(&env, call_id, &staker);
+        transfer_token(
+            &env,
+            &call.stake_token,
+            &env.current_contract_address(),
+            &staker,
+            commit.amount,
+        );
+        emit_commit_refunded(&env, call_id, &staker, commit.amount);
+
+        Ok(commit.amount)
+    }
+
+    /// Get a staker's unrevealed commit on a call, if any.
+    pub fn get_stake_commit(env: Env, call_id: u64, staker: Address) -> Option<StakeCommit> {
+        get_stake_commit(&env, call_id, &staker)
+    }
+
+    /// Compute the commitment expected by [`reveal_stake`](Self::reveal_stake):
+    /// `sha256(position ‖ salt ‖ amount)` with integers big-endian.
+    pub fn stake_commitment(env: Env, position: u32, salt: BytesN<32>, amount: i128) -> BytesN<32> {
+        stake_commitment(&env, position, &salt, amount)
+    }
+
+    /// Deposit `amount` of `token` from `caller` into the house liquidity
+    /// backing fixed-odds stakes (treasury manager only).
+    /// # Errors
+    /// * [`CallRegistryError::InvalidStakeAmount`] – `amount` ≤ 0.
+    /// # Panics
+    /// * [`CallRegistryExtError::MissingRole`] – `caller` lacks
+    ///   [`Role::TreasuryManager`].
+    pub fn deposit_house_liquidity(
+        env: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), CallRegistryError> {
+        get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        roles::require_role(&env, &caller, Role::TreasuryManager);
+        if amount <= 0 {
+            return Err(CallRegistryError::InvalidStakeAmount);
         }
 
-        if call.settled {
-            return Err(CallRegistryError::CallSettled);
+        transfer_token(
+            &env,
+            &token,
+            &caller,
+            &env.current_contract_address(),
+            amount,
+        );
+        let free = math::add(&env, get_house_liquidity(&env, &token), amount);
+        set_house_liquidity(&env, &token, free);
+        emit_house_liquidity_changed(&env, &token, amount, free);
+
+        Ok(())
+    }
+
+    /// Withdraw unreserved house liquidity to `caller` (treasury manager
+    /// only).
+    /// # Errors
+    /// * [`CallRegistryError::InvalidStakeAmount`]         – `amount` ≤ 0.
+    /// * [`CallRegistryError::InsufficientHouseLiquidity`] – `amount` exceeds the
+    ///   free liquidity.
+    /// # Panics
+    /// * [`CallRegistryExtError::MissingRole`] – `caller` lacks
+    ///   [`Role::TreasuryManager`].
+    pub fn withdraw_house_liquidity(
+        env: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), CallRegistryError> {
+        get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        roles::require_role(&env, &caller, Role::TreasuryManager);
+        if amount <= 0 {
+            return Err(CallRegistryError::InvalidStakeAmount);
         }
 
-        if call.cancelled {
-            panic!("Call has been cancelled");
+        let free = get_house_liquidity(&env, &token);
+        if amount > free {
+            return Err(CallRegistryError::InsufficientHouseLiquidity);
         }
+        set_house_liquidity(&env, &token, free - amount);
+        transfer_token(
+            &env,
+            &token,
+            &env.current_contract_address(),
+            &caller,
+            amount,
+        );
+        emit_house_liquidity_changed(&env, &token, -amount, free - amount);
 
-        if call.voided {
-            panic!("Call has been voided");
+        Ok(())
+    }
+
+    /// Get the house's free (unreserved) liquidity in `token`.
+    pub fn get_house_liquidity(env: Env, token: Address) -> i128 {
+        get_house_liquidity(&env, &token)
+    }
+
+    /// Get the house liquidity in `token` held back to cover open fixed-odds
+    /// books. Free plus reserved liquidity is what the house has at stake.
+    pub fn get_house_reserved(env: Env, token: Address) -> i128 {
+        get_house_reserved(&env, &token)
+    }
+
+    /// Current fixed-odds payout multiplier for `position`, in basis points.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::InvalidPosition`] – `position` ∉ [1, outcome_count].
+    pub fn quote_fixed_odds(
+        env: Env,
+        call_id: u64,
+        position: u32,
+    ) -> Result<i128, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        if position < 1 || position > call.outcome_count {
+            return Err(CallRegistryError::InvalidPosition);
+        }
+        Ok(fixed_odds::quote_odds_bps(
+            &env,
+            &call,
+            position,
+            config.fixed_odds_spread_bps,
+        ))
+    }
+
+    /// Stake `amount` on `position` against the house at the currently quoted
+    /// odds, which are locked into a ticket. Fixed-odds stakes do not enter the
+    /// call's pool. The house must hold enough free liquidity in the stake token
+    /// to cover the worst-case outcome of the call's book. Once a call has
+    /// tickets, its pool stakes can no longer be reduced or switched.
+    /// # Errors
+    /// * [`CallRegistryError::InvalidStakeAmount`]         – `amount` ≤ 0.
+    /// * [`CallRegistryError::CallNotFound`]               – `call_id` does not exist.
+    /// * [`CallRegistryError::InvalidPosition`]            – `position` out of range.
+    /// * [`CallRegistryError::StakerNotAllowlisted`]       – private call.
+    /// * [`CallRegistryError::OddsBelowMinimum`]           – quote < `min_odds_bps`.
+    /// * [`CallRegistryError::InsufficientHouseLiquidity`] – the house cannot cover
+    ///   the new exposure.
+    /// * [`CallRegistryError::CallEnded`] / [`CallRegistryError::StakingCutoffActive`] /
+    ///   [`CallRegistryError::CallSettled`] – staking is closed.
+    pub fn stake_fixed_odds(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+        amount: i128,
+        position: u32,
+        min_odds_bps: i128,
+    ) -> Result<FixedOddsTicket, CallRegistryError> {
+        staker.require_auth();
+
+        if amount <= 0 {
+            return Err(CallRegistryError::InvalidStakeAmount);
+        }
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Stake)?;
+        ensure_not_banned(&env, &staker);
+        if amount < config.min_stake {
+            return Err(CallRegistryError::StakeBelowMinimum);
         }
 
-        // Validate position is within valid range
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        check_staking_open(&env, &config, &call)?;
         if position < 1 || position > call.outcome_count {
             return Err(CallRegistryError::InvalidPosition);
         }
+        if call.is_private && !is_call_allowlisted(&env, call_id, &staker) {
+            return Err(CallRegistryError::StakerNotAllowlisted);
+        }
+
+        let spread_bps = config.fixed_odds_spread_bps;
+        let odds_bps = fixed_odds::quote_odds_bps(&env, &call, position, spread_bps);
+        if odds_bps < min_odds_bps {
+            return Err(CallRegistryError::OddsBelowMinimum);
+        }
+        let payout = math::mul_div(&env, amount, odds_bps, 10_000);
 
-        // Per-user stake cap
-        let config = get_config(&env).expect("Contract not initialized");
-        let current_stake = get_user_stake(&env, call_id, &staker, position);
-        if config.max_stake_per_user > 0 && current_stake + amount > config.max_stake_per_user {
-            panic!("Stake exceeds max_stake_per_user cap");
+        let mut book = get_fixed_odds_book(&env, call_id).unwrap_or(FixedOddsBook {
+            stakes: 0,
+            liabilities: Map::new(&env),
+            reserved: 0,
+            settled: false,
+        });
+        book.stakes = math::add(&env, book.stakes, amount);
+        let liability = book.liabilities.get(position).unwrap_or(0);
+        book.liabilities
+            .set(position, math::add(&env, liability, payout));
+
+        // Move the change in worst-case shortfall between the house's free
+        // liquidity and this book's reserve.
+        let reserve = fixed_odds::required_reserve(&book, call.outcome_count);
+        let free = get_house_liquidity(&env, &call.stake_token);
+        let delta = reserve - book.reserved;
+        if delta > free {
+            return Err(CallRegistryError::InsufficientHouseLiquidity);
         }
+        set_house_liquidity(&env, &call.stake_token, free - delta);
+        add_house_reserved(&env, &call.stake_token, delta);
+        book.reserved = reserve;
 
-        // Transfer tokens in — supports both native XLM and SAC-wrapped tokens.
         transfer_token(
             &env,
             &call.stake_token,
@@ -542,42 +2394,119 @@ impl CallRegistry {
             amount,
         );
 
-        if let Some(share_token) = call.share_tokens.get(position) {
-            shares::mint_shares(&env, &share_token, &staker, amount);
-            emit_shares_minted(&env, call_id, &staker, position, amount);
+        let ticket = FixedOddsTicket {
+            position,
+            amount,
+            odds_bps,
+            payout,
+        };
+        let mut tickets = get_fixed_odds_tickets(&env, call_id, &staker);
+        tickets.push_back(ticket.clone());
+        set_fixed_odds_tickets(&env, call_id, &staker, &tickets);
+        set_fixed_odds_book(&env, call_id, &book);
+        record_stake(&env, &staker, amount);
+        extend_storage_ttl(&env);
+
+        emit_fixed_odds_stake(&env, call_id, &staker, position, amount, odds_bps);
+
+        Ok(ticket)
+    }
+
+    /// Collect the payout of winning fixed-odds tickets once the call is
+    /// resolved. Voided, cancelled and DRAW calls refund every ticket's stake.
+    /// Returns the amount paid, which is `0` when every ticket lost.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::CallNotResolved`] – the call is still open.
+    /// * [`CallRegistryError::NothingToClaim`]  – no fixed-odds tickets.
+    pub fn claim_fixed_odds(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+    ) -> Result<i128, CallRegistryError> {
+        staker.require_auth();
+
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Claim)?;
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        let refund = refunds_stakes(&call)?;
+        dispute::ensure_dispute_window_closed(&env, call_id);
+        let tickets = get_fixed_odds_tickets(&env, call_id, &staker);
+        if tickets.is_empty() {
+            return Err(CallRegistryError::NothingToClaim);
         }
 
-        // Update stake maps with generalized position support
-        let current_total = call.outcome_stakes.get(position).unwrap_or(0);
-        call.outcome_stakes.set(position, current_total + amount);
+        let amount: i128 = tickets
+            .iter()
+            .map(|t| {
+                if refund {
+                    t.amount
+                } else if t.position == call.outcome {
+                    t.payout
+                } else {
+                    0
+                }
+            })
+            .sum();
 
-        let mut outcome_stakers = call.stakes.get(position).unwrap_or_else(|| Map::new(&env));
-        let current_staker_stake = outcome_stakers.get(staker.clone()).unwrap_or(0);
-        outcome_stakers.set(staker.clone(), current_staker_stake + amount);
-        call.stakes.set(position, outcome_stakers);
+        remove_fixed_odds_tickets(&env, call_id, &staker);
+        if amount > 0 {
+            transfer_token(
+                &env,
+                &call.stake_token,
+                &env.current_contract_address(),
+                &staker,
+                amount,
+            );
+        }
+        emit_fixed_odds_claimed(&env, call_id, &staker, amount);
 
-        add_call_staker(&env, call_id, &staker);
-        set_user_stake(
-            &env,
-            call_id,
-            &staker,
-            position,
-            current_staker_stake + amount,
-        );
+        Ok(amount)
+    }
 
-        set_call(&env, &call);
-        add_staker_call(&env, &staker, call_id);
-        record_stake(&env, &staker, amount);
-        extend_storage_ttl(&env);
+    /// Return the house's share of a closed call's fixed-odds book — the stakes
+    /// collected plus the reserve, less what winners are owed — to its free
+    /// liquidity. Callable by anyone. Returns the amount released.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]     – `call_id` does not exist.
+    /// * [`CallRegistryError::CallNotResolved`]  – the call is still open.
+    /// * [`CallRegistryError::NothingToClaim`]   – the call has no fixed-odds book.
+    /// * [`CallRegistryError::HouseBookSettled`] – already settled.
+    pub fn settle_fixed_odds_book(env: Env, call_id: u64) -> Result<i128, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        let refund = refunds_stakes(&call)?;
+        dispute::ensure_dispute_window_closed(&env, call_id);
+        let mut book =
+            get_fixed_odds_book(&env, call_id).ok_or(CallRegistryError::NothingToClaim)?;
+        if book.settled {
+            return Err(CallRegistryError::HouseBookSettled);
+        }
 
-        // Emit distinct XLM event so the indexer can differentiate XLM from USDC volume.
-        if is_native_xlm(&env, &call.stake_token) {
-            emit_xlm_stake_added(&env, call_id, &staker, amount, position);
+        let owed = if refund {
+            book.stakes
         } else {
-            emit_stake_added(&env, call_id, &staker, amount, position);
-        }
+            book.liabilities.get(call.outcome).unwrap_or(0)
+        };
+        let returned = book.stakes + book.reserved - owed;
+        let free = get_house_liquidity(&env, &call.stake_token);
+        set_house_liquidity(&env, &call.stake_token, math::add(&env, free, returned));
+        add_house_reserved(&env, &call.stake_token, -book.reserved);
+        book.settled = true;
+        set_fixed_odds_book(&env, call_id, &book);
+        emit_fixed_odds_book_settled(&env, call_id, returned);
+
+        Ok(returned)
+    }
 
-        Ok(call)
+    /// Get the house's fixed-odds book for a call, if any stake was taken.
+    pub fn get_fixed_odds_book(env: Env, call_id: u64) -> Option<FixedOddsBook> {
+        get_fixed_odds_book(&env, call_id)
+    }
+
+    /// Get a staker's unclaimed fixed-odds tickets on a call.
+    pub fn get_fixed_odds_tickets(env: Env, call_id: u64, staker: Address) -> Vec<FixedOddsTicket> {
+        get_fixed_odds_tickets(&env, call_id, &staker)
     }
 
     pub fn redeem_shares(
@@ -587,25 +2516,27 @@ impl CallRegistry {
     ) -> Result<i128, CallRegistryError> {
         redeemer.require_auth();
 
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Claim)?;
+
         let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
 
         if call.outcome == 0 {
-            panic!("call not yet resolved");
+            return Err(CallRegistryError::CallNotResolved);
         }
         if !call.settled {
-            panic!("call not yet settled");
+            panic_with_error!(&env, CallRegistryExtError::CallNotSettled);
         }
 
         let winning_outcome = call.outcome;
-        let share_token = match call.share_tokens.get(winning_outcome) {
-            Some(t) => t,
-            None => panic!("share tokens not configured for this call"),
+        let Some(share_token) = call.share_tokens.get(winning_outcome) else {
+            panic_with_error!(&env, CallRegistryExtError::SharesNotConfigured);
         };
 
         // Check redeemer's winning share balance
         let balance = shares::share_balance(&env, &share_token, &redeemer);
         if balance <= 0 {
-            panic!("no winning shares to redeem");
+            return Err(CallRegistryError::NothingToClaim);
         }
 
         // Total winning pool and total stakes
@@ -617,15 +2548,18 @@ impl CallRegistry {
         // Payout: redeemer's share of winning pool gets proportional total pot
         // Each winning share is worth: total_all_stakes / total_winning_stakes
         let payout = if total_winning_stakes > 0 {
-            (balance as i128) * total_all_stakes / total_winning_stakes
+            math::mul_div(&env, balance, total_all_stakes, total_winning_stakes)
         } else {
             0
         };
 
         if payout <= 0 {
-            panic!("zero payout");
+            return Err(CallRegistryError::NothingToClaim);
         }
 
+        emergency::ensure_funds_not_withdrawn(&env, call_id);
+        add_escrow_released(&env, call_id, payout);
+
         // Burn the winning shares
         shares::burn_shares(&env, &share_token, &redeemer, balance);
 
@@ -673,37 +2607,140 @@ impl CallRegistry {
 
     /// Set the maximum individual stake per user per position per call (admin only).
     /// Pass `0` to remove the cap.
-    pub fn set_max_stake_per_user(env: Env, new_max: i128) {
-        admin::set_max_stake_per_user(env, new_max);
+    pub fn set_max_stake_per_user(env: Env, new_max: i128) -> Result<(), CallRegistryError> {
+        admin::set_max_stake_per_user(env, new_max)
+    }
+
+    pub fn set_min_stake(env: Env, new_min_stake: i128) -> Result<(), CallRegistryError> {
+        admin::set_min_stake(env, new_min_stake)
+    }
+
+    /// Set the bond creators escrow with each new call (admin only). Pass `0`
+    /// to stop requiring a bond.
+    /// # Errors
+    /// Propagates errors from [`admin::set_creator_bond`].
+    pub fn set_creator_bond(env: Env, new_bond: i128) -> Result<(), CallRegistryError> {
+        admin::set_creator_bond(env, new_bond)
+    }
+
+    /// Limit how many calls one creator may open per rolling window (admin
+    /// only). Pass `max_calls = 0` to lift the limit.
+    /// # Errors
+    /// Propagates errors from [`admin::set_call_rate_limit`].
+    pub fn set_call_rate_limit(
+        env: Env,
+        max_calls: u32,
+        window_secs: u64,
+    ) -> Result<(), CallRegistryError> {
+        admin::set_call_rate_limit(env, max_calls, window_secs)
+    }
+
+    /// Set how long a resolution stays open to correction before claims can
+    /// start (admin only). Pass `0` to make resolutions final at once.
+    /// # Errors
+    /// Propagates errors from [`admin::set_dispute_window`].
+    pub fn set_dispute_window(env: Env, new_window_secs: u64) -> Result<(), CallRegistryError> {
+        admin::set_dispute_window(env, new_window_secs)
+    }
+
+    /// Pause one component: create, stake, resolve or claim (pauser only).
+    /// # Errors
+    /// Propagates errors from [`admin::pause`].
+    pub fn pause(
+        env: Env,
+        caller: Address,
+        component: PauseComponent,
+    ) -> Result<(), CallRegistryError> {
+        admin::pause(env, caller, component)
+    }
+
+    /// Unpause one component (pauser only).
+    /// # Errors
+    /// Propagates errors from [`admin::unpause`].
+    pub fn unpause(
+        env: Env,
+        caller: Address,
+        component: PauseComponent,
+    ) -> Result<(), CallRegistryError> {
+        admin::unpause(env, caller, component)
+    }
+
+    /// Give `account` a pauser, resolver, treasury-manager or fee-setter
+    /// role (admin only).
+    /// # Errors
+    /// Propagates errors from [`roles::grant_role`].
+    pub fn grant_role(env: Env, role: Role, account: Address) -> Result<(), CallRegistryError> {
+        roles::grant_role(env, role, account)
+    }
+
+    /// Take a role away from `account` (admin only).
+    /// # Errors
+    /// Propagates errors from [`roles::revoke_role`].
+    pub fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), CallRegistryError> {
+        roles::revoke_role(env, role, account)
     }
 
-    pub fn set_min_stake(env: Env, new_min_stake: i128) {
-        admin::set_min_stake(env, new_min_stake);
+    /// Whether `account` holds `role`.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        has_role(&env, role, &account)
     }
 
-    /// Pause the contract (admin only).
-    pub fn pause(env: Env) {
-        admin::pause(env);
+    /// Bar `account` from creating calls and staking (admin only).
+    /// # Errors
+    /// Propagates errors from [`admin::ban_address`].
+    pub fn ban_address(env: Env, account: Address) -> Result<(), CallRegistryError> {
+        admin::ban_address(env, account)
+    }
+
+    /// Lift a ban placed with [`ban_address`](Self::ban_address) (admin only).
+    /// # Errors
+    /// Propagates errors from [`admin::unban_address`].
+    pub fn unban_address(env: Env, account: Address) -> Result<(), CallRegistryError> {
+        admin::unban_address(env, account)
     }
 
-    /// Unpause the contract (admin only).
-    pub fn unpause(env: Env) {
-        admin::unpause(env);
+    /// Whether `account` is banned from creating calls and staking.
+    pub fn is_banned(env: Env, account: Address) -> bool {
+        is_banned(&env, &account)
+    }
+
+    /// Return which components are paused.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    pub fn get_pause_state(env: Env) -> Result<PauseState, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        Ok(config.pause_state)
     }
 
     /// Set the staking cutoff window in seconds before `end_ts` (admin only).
     /// Staking is blocked when `current_timestamp >= call.end_ts - new_cutoff`.
     /// Pass `0` to disable the cutoff.
-    pub fn set_staking_cutoff(env: Env, new_cutoff: u64) {
-        admin::set_staking_cutoff(env, new_cutoff);
+    pub fn set_staking_cutoff(env: Env, new_cutoff: u64) -> Result<(), CallRegistryError> {
+        admin::set_staking_cutoff(env, new_cutoff)
     }
 
     /// Resolve a call with an outcome (outcome_manager only).
+    ///
+    /// A call created with `allow_early_resolution` may resolve as UP before
+    /// `end_ts` if `end_price` already satisfies its threshold condition; it is
+    /// flagged `early_resolved` and `call_early_resolved` is emitted.
+    ///
+    /// A conditional call whose parent resolved with a different outcome (or
+    /// was voided) is voided instead of resolved; stakers then use
+    /// `claim_void_refund`. Resolving as [`OUTCOME_VOID`] voids the call the
+    /// same way, at any time.
     /// # Errors
     /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
     /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
-    /// * [`CallRegistryError::InvalidOutcome`] – `outcome` ∉ [1, outcome_count].
-    /// * [`CallRegistryError::CallNotEnded`]   – `end_ts` has not yet passed.
+    /// * [`CallRegistryError::InvalidOutcome`] – `outcome` ∉ [1, outcome_count], or a
+    ///   binary call's outcome disagrees with the DRAW tolerance band or with the
+    ///   sign of `end_price − start_price`.
+    /// * [`CallRegistryError::CallNotEnded`]   – `end_ts` has not yet passed and the
+    ///   call is not eligible for early resolution.
+    /// * [`CallRegistryError::ParentNotResolved`] – the parent call has no outcome yet.
+    /// # Panics
+    /// * [`CallRegistryExtError::AlreadyResolved`] – the call already has an outcome.
+    /// * [`CallRegistryExtError::CallVoided`]      – the call was voided.
     pub fn resolve_call(
         env: Env,
         call_id: u64,
@@ -711,70 +2748,141 @@ impl CallRegistry {
         end_price: i128,
     ) -> Result<Call, CallRegistryError> {
         let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
-        assert!(!config.paused, "Contract is paused");
+        ensure_unpaused(&config, PauseComponent::Resolve)?;
         config.outcome_manager.require_auth();
 
         let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        if call.outcome != 0 {
+            panic_with_error!(&env, CallRegistryExtError::AlreadyResolved);
+        }
+        if call.voided {
+            panic_with_error!(&env, CallRegistryExtError::CallVoided);
+        }
 
-        // Validate outcome is within valid range
-        if outcome < 1 || outcome > call.outcome_count {
-            return Err(CallRegistryError::InvalidOutcome);
+        // A VOID outcome puts the call into refund mode instead of settling it.
+        if outcome == OUTCOME_VOID {
+            call.voided = true;
+            set_call(&env, &call);
+            remove_active_call(&env, call_id);
+            hold_creator_bond(&env, call_id);
+            extend_storage_ttl(&env);
+            emit_call_voided(&env, call_id, &config.outcome_manager);
+            return Ok(call);
         }
 
+        // Before `end_ts` only opted-in threshold calls whose target has been
+        // crossed may resolve, and only as UP.
         let current_timestamp = env.ledger().timestamp();
-        if current_timestamp < call.end_ts {
+        let early = current_timestamp < call.end_ts;
+        if early
+            && !(call.allow_early_resolution
+                && outcome == OUTCOME_UP
+                && evaluate_condition_impl(&call.condition, call.start_price, end_price))
+        {
             return Err(CallRegistryError::CallNotEnded);
         }
 
-        if call.voided {
-            panic!("Call has been voided");
+        // An early UP means the threshold was crossed, which need not be above
+        // the start price (e.g. a TargetBelow call), so the direction check
+        // only applies at expiry.
+        if !early {
+            check_outcome(&config, &call, outcome, end_price)?;
+        }
+
+        // Conditional calls only stand if the parent resolved the required way;
+        // otherwise they are voided so stakers can claim refunds.
+        if call.parent_call_id != 0 {
+            let parent =
+                get_call(&env, call.parent_call_id).ok_or(CallRegistryError::InvalidParentCall)?;
+            if !parent.voided && parent.outcome == 0 {
+                return Err(CallRegistryError::ParentNotResolved);
+            }
+            if parent.voided || parent.outcome != call.parent_outcome {
+                call.voided = true;
+                set_call(&env, &call);
+                remove_active_call(&env, call_id);
+                hold_creator_bond(&env, call_id);
+                extend_storage_ttl(&env);
+                emit_call_voided_by_parent(&env, call_id, parent.id, parent.outcome);
+                return Ok(call);
+            }
         }
 
         call.outcome = outcome;
         call.end_price = end_price;
+        call.early_resolved = early;
 
         // Track creator reputation: increment total_resolved and conditionally total_correct
-        let mut creator_stats = get_creator_stats(&env, &call.creator);
-        creator_stats.total_resolved += 1;
-
-        // Check if creator staked on the winning position
-        let creator_winning_stake = match outcome {
-            OUTCOME_UP => get_user_stake(&env, call.id, &call.creator, 1),
-            OUTCOME_DOWN => get_user_stake(&env, call.id, &call.creator, 2),
-            _ => 0,
-        };
+        let mut creator_stats = get_creator_stats(&env, &call.creator);
+        creator_stats.total_resolved = math::increment(&env, creator_stats.total_resolved);
 
-        if creator_winning_stake > 0 {
-            creator_stats.total_correct += 1;
+        let creator_correct = creator_backed(&env, &call, outcome);
+        if creator_correct {
+            creator_stats.total_correct = math::increment(&env, creator_stats.total_correct);
         }
 
         set_creator_stats(&env, &call.creator, &creator_stats);
+        notify_reputation(
+            &env,
+            "record_call_resolved",
+            (call_id, call.creator.clone(), creator_correct).into_val(&env),
+        );
 
         set_call(&env, &call);
+        remove_active_call(&env, call_id);
+        dispute::open_dispute_window(&env, &config, call_id);
         extend_storage_ttl(&env);
 
+        if early {
+            emit_call_early_resolved(&env, call_id, outcome, end_price, current_timestamp);
+        }
         emit_call_resolved(&env, call_id, outcome, end_price);
 
         Ok(call)
     }
 
+    /// Correct a resolution while its dispute window is open (outcome_manager only).
+    /// # Errors
+    /// Propagates errors from [`dispute::override_outcome`].
+    pub fn override_outcome(
+        env: Env,
+        caller: Address,
+        call_id: u64,
+        outcome: u32,
+        end_price: i128,
+        evidence_cid: Bytes,
+    ) -> Result<Call, CallRegistryError> {
+        dispute::override_outcome(env, caller, call_id, outcome, end_price, evidence_cid)
+    }
+
+    /// When a resolved call's dispute window closes, if it was given one.
+    pub fn get_dispute_deadline(env: Env, call_id: u64) -> Option<u64> {
+        get_dispute_deadline(&env, call_id)
+    }
+
     /// Mark a call as settled (outcome_manager only).
     /// # Errors
     /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
     /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
     /// * [`CallRegistryError::CallSettled`]     – call is already settled.
+    /// # Panics
+    /// * [`CallRegistryExtError::DisputeWindowOpen`] – the resolution can still be
+    ///   overridden.
     pub fn mark_settled(env: Env, call_id: u64) -> Result<(), CallRegistryError> {
         let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
         config.outcome_manager.require_auth();
+        ensure_unpaused(&config, PauseComponent::Resolve)?;
 
         let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
 
         if call.settled {
             return Err(CallRegistryError::CallSettled);
         }
+        dispute::ensure_dispute_window_closed(&env, call_id);
 
         call.settled = true;
         set_call(&env, &call);
+        remove_active_call(&env, call_id);
 
         Ok(())
     }
@@ -783,6 +2891,11 @@ impl CallRegistry {
     /// # Errors
     /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
     /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    /// # Panics
+    /// * [`CallRegistryExtError::CallFundsWithdrawn`] – the escrow was swept by
+    ///   an emergency withdrawal.
+    /// * [`CallRegistryExtError::DisputeWindowOpen`]  – the resolution can still
+    ///   be overridden.
     pub fn release_escrow(
         env: Env,
         call_id: u64,
@@ -791,8 +2904,12 @@ impl CallRegistry {
     ) -> Result<(), CallRegistryError> {
         let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
         config.outcome_manager.require_auth();
+        ensure_unpaused(&config, PauseComponent::Claim)?;
 
         let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        emergency::ensure_funds_not_withdrawn(&env, call_id);
+        dispute::ensure_dispute_window_closed(&env, call_id);
+        add_escrow_released(&env, call_id, amount);
 
         // Dispatch to native XLM or SAC-wrapped token path.
         transfer_token(
@@ -811,6 +2928,364 @@ impl CallRegistry {
         Ok(())
     }
 
+    /// Release escrow from several calls to one recipient (outcome_manager
+    /// only). `releases` maps call ids to amounts; each call is checked and
+    /// accounted as in [`Self::release_escrow`], but only one transfer is
+    /// made per stake token. Returns the amount sent per token.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]   – a call id does not exist.
+    /// # Panics
+    /// * [`CallRegistryExtError::CallFundsWithdrawn`] – an escrow was swept by
+    ///   an emergency withdrawal.
+    /// * [`CallRegistryExtError::DisputeWindowOpen`]  – a resolution can still
+    ///   be overridden.
+    pub fn release_escrows(
+        env: Env,
+        to: Address,
+        releases: Map<u64, i128>,
+    ) -> Result<Map<Address, i128>, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        config.outcome_manager.require_auth();
+        ensure_unpaused(&config, PauseComponent::Claim)?;
+
+        let mut totals: Map<Address, i128> = Map::new(&env);
+        for (call_id, amount) in releases.iter() {
+            let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+            emergency::ensure_funds_not_withdrawn(&env, call_id);
+            dispute::ensure_dispute_window_closed(&env, call_id);
+            add_escrow_released(&env, call_id, amount);
+
+            if is_native_xlm(&env, &call.stake_token) {
+                emit_xlm_escrow_released(&env, call_id, &to, amount);
+            }
+            let total = totals.get(call.stake_token.clone()).unwrap_or(0);
+            totals.set(call.stake_token, math::add(&env, total, amount));
+        }
+
+        for (stake_token, amount) in totals.iter() {
+            transfer_token(
+                &env,
+                &stake_token,
+                &env.current_contract_address(),
+                &to,
+                amount,
+            );
+        }
+
+        Ok(totals)
+    }
+
+    /// Move winnings left unclaimed past their claim deadline from a call's
+    /// escrow into the insurance pool for its stake token (outcome_manager
+    /// only).
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    /// # Panics
+    /// * [`CallRegistryExtError::CallFundsWithdrawn`] – the escrow was swept by
+    ///   an emergency withdrawal.
+    pub fn expire_escrow(env: Env, call_id: u64, amount: i128) -> Result<(), CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        config.outcome_manager.require_auth();
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        emergency::ensure_funds_not_withdrawn(&env, call_id);
+        add_escrow_released(&env, call_id, amount);
+        add_insurance_pool(&env, &call.stake_token, amount);
+
+        emit_escrow_expired(&env, call_id, &call.stake_token, amount);
+        Ok(())
+    }
+
+    /// Move the penalty pool of a call that pays no winners (voided,
+    /// cancelled or DRAW) into the insurance pool for its stake token, as its
+    /// stakers are refunded their own stakes. Callable by anyone. Returns the
+    /// amount moved.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`]  – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::CallNotResolved`] – the call is still open.
+    /// * [`CallRegistryError::NothingToClaim`]  – the penalty pool is empty.
+    /// # Panics
+    /// * [`CallRegistryExtError::PenaltyPoolOwedToWinners`] – the call resolved
+    ///   to a winning outcome.
+    /// * [`CallRegistryExtError::CallFundsWithdrawn`] – the escrow was swept by
+    ///   an emergency withdrawal.
+    /// * [`CallRegistryExtError::DisputeWindowOpen`]  – a DRAW can still be
+    ///   overridden.
+    pub fn sweep_penalty_pool(env: Env, call_id: u64) -> Result<i128, CallRegistryError> {
+        get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        if !refunds_stakes(&call)? {
+            panic_with_error!(&env, CallRegistryExtError::PenaltyPoolOwedToWinners);
+        }
+        emergency::ensure_funds_not_withdrawn(&env, call_id);
+        dispute::ensure_dispute_window_closed(&env, call_id);
+
+        let amount = call.penalty_pool;
+        if amount <= 0 {
+            return Err(CallRegistryError::NothingToClaim);
+        }
+        call.penalty_pool = 0;
+        set_call(&env, &call);
+        add_insurance_pool(&env, &call.stake_token, amount);
+        extend_storage_ttl(&env);
+
+        emit_penalty_pool_swept(&env, call_id, &call.stake_token, amount);
+        Ok(amount)
+    }
+
+    /// Release the protocol fee on `staker`'s payout (outcome_manager only).
+    /// If `staker` has a referrer, `referral_share_bps` of `amount` accrues
+    /// to them and stays in escrow until claimed; the rest goes to `to` and
+    /// is added to [`Self::get_protocol_fees`]. Returns the referrer's cut.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    /// # Panics
+    /// * [`CallRegistryExtError::CallFundsWithdrawn`] – the escrow was swept by
+    ///   an emergency withdrawal.
+    /// * [`CallRegistryExtError::DisputeWindowOpen`]  – the resolution can still
+    ///   be overridden.
+    pub fn release_fee(
+        env: Env,
+        call_id: u64,
+        staker: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        config.outcome_manager.require_auth();
+        ensure_unpaused(&config, PauseComponent::Claim)?;
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        emergency::ensure_funds_not_withdrawn(&env, call_id);
+        dispute::ensure_dispute_window_closed(&env, call_id);
+        add_escrow_released(&env, call_id, amount);
+
+        let mut referral = 0;
+        if let Some(referrer) = get_referrer(&env, &staker) {
+            referral = math::bps_of(&env, amount, config.referral_share_bps);
+            if referral > 0 {
+                let pending = get_referral_rewards(&env, &referrer, &call.stake_token);
+                let pending = math::add(&env, pending, referral);
+                set_referral_rewards(&env, &referrer, &call.stake_token, pending);
+                emit_referral_accrued(&env, call_id, &referrer, &staker, referral);
+            }
+        }
+
+        let remainder = amount - referral;
+        if remainder > 0 {
+            transfer_token(
+                &env,
+                &call.stake_token,
+                &env.current_contract_address(),
+                &to,
+                remainder,
+            );
+            add_protocol_fees(&env, &call.stake_token, remainder);
+            emit_protocol_fee_paid(&env, call_id, &call.stake_token, &to, remainder);
+        }
+
+        Ok(referral)
+    }
+
+    /// Record a winning claim on `staker`'s streak (outcome_manager only).
+    /// Returns the payout bonus earned by the wins before this one:
+    /// `streak_bonus_bps` per prior consecutive win, capped at
+    /// `max_streak_bonus_bps`. The outcome_manager funds it from the
+    /// protocol fee. A win on a call that closed before the last call on
+    /// the streak leaves the streak alone and earns no bonus.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`]  – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::CallNotResolved`] – call not resolved to a
+    ///   winning outcome (unresolved, DRAW, voided or cancelled).
+    /// * [`CallRegistryError::NothingToClaim`]  – `staker` did not back the
+    ///   winning outcome.
+    /// # Panics
+    /// * [`CallRegistryExtError::StreakResultRecorded`] – already recorded.
+    pub fn record_streak_win(
+        env: Env,
+        call_id: u64,
+        staker: Address,
+    ) -> Result<u32, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        config.outcome_manager.require_auth();
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        if refunds_stakes(&call)? {
+            return Err(CallRegistryError::CallNotResolved);
+        }
+        if get_user_stake(&env, call_id, &staker, call.outcome) <= 0 {
+            return Err(CallRegistryError::NothingToClaim);
+        }
+
+        if !mark_streak_result(&env, call_id, &staker) {
+            panic_with_error!(&env, CallRegistryExtError::StreakResultRecorded);
+        }
+
+        let mut streak = get_streak(&env, &staker);
+        let in_order = extends_streak(&env, &streak, &call);
+        let mut bonus_bps = 0;
+        if in_order {
+            bonus_bps = streak_bonus_bps(&config, &streak);
+            streak.current += 1;
+            streak.best = streak.best.max(streak.current);
+            streak.last_call_id = call_id;
+            set_streak(&env, &staker, &streak);
+            emit_streak_extended(&env, &staker, call_id, streak.current, bonus_bps);
+        }
+        let volume = staker_total_stake(&call, &staker);
+        notify_reputation(
+            &env,
+            "record_position",
+            (call_id, staker.clone(), volume, true, in_order).into_val(&env),
+        );
+        notify_rewards(&env, &call, &staker);
+
+        Ok(bonus_bps)
+    }
+
+    /// Reset `staker`'s streak because they lost `call_id`. Losers never
+    /// claim, so anyone may report the loss once the call is resolved. A
+    /// loss on a call that closed before the last call on the streak is
+    /// recorded without resetting it, so replaying an old loss cannot wipe
+    /// out newer wins.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::CallNotResolved`] – call not resolved to a
+    ///   winning outcome (unresolved, DRAW, voided or cancelled).
+    /// * [`CallRegistryError::NoExistingStake`] – `staker` has no stake on
+    ///   the call.
+    /// * [`CallRegistryError::StakeNotLosing`]  – `staker` backed the
+    ///   winning outcome.
+    /// # Panics
+    /// * [`CallRegistryExtError::StreakResultRecorded`] – already recorded.
+    pub fn record_streak_loss(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+    ) -> Result<Streak, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        if refunds_stakes(&call)? {
+            return Err(CallRegistryError::CallNotResolved);
+        }
+        if staker_total_stake(&call, &staker) <= 0 {
+            return Err(CallRegistryError::NoExistingStake);
+        }
+        if get_user_stake(&env, call_id, &staker, call.outcome) > 0 {
+            return Err(CallRegistryError::StakeNotLosing);
+        }
+
+        if !mark_streak_result(&env, call_id, &staker) {
+            panic_with_error!(&env, CallRegistryExtError::StreakResultRecorded);
+        }
+
+        let mut streak = get_streak(&env, &staker);
+        let in_order = extends_streak(&env, &streak, &call);
+        if in_order {
+            if streak.current > 0 {
+                emit_streak_broken(&env, &staker, call_id, streak.current);
+                streak.current = 0;
+            }
+            streak.last_call_id = call_id;
+            set_streak(&env, &staker, &streak);
+        }
+        let volume = staker_total_stake(&call, &staker);
+        notify_reputation(
+            &env,
+            "record_position",
+            (call_id, staker.clone(), volume, false, in_order).into_val(&env),
+        );
+        notify_rewards(&env, &call, &staker);
+
+        Ok(streak)
+    }
+
+    /// Get a staker's current and best win streak.
+    pub fn get_streak(env: Env, staker: Address) -> Streak {
+        get_streak(&env, &staker)
+    }
+
+    /// The payout bonus `staker`'s next winning claim would earn, as
+    /// [`Self::record_streak_win`] would return it, without recording a win.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    pub fn get_streak_bonus_bps(env: Env, staker: Address) -> Result<u32, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        Ok(streak_bonus_bps(&config, &get_streak(&env, &staker)))
+    }
+
+    /// Get an address's loyalty points. Points are earned by staking and
+    /// cannot be transferred.
+    pub fn get_loyalty_points(env: Env, holder: Address) -> i128 {
+        get_loyalty_points(&env, &holder)
+    }
+
+    /// Get the loyalty points issued across all addresses.
+    pub fn get_total_loyalty_points(env: Env) -> i128 {
+        get_total_loyalty_points(&env)
+    }
+
+    /// Record the current loyalty balances of `holders` under a new snapshot
+    /// id (admin only), e.g. to size an airdrop. Holders are supplied by the
+    /// caller, typically from indexed `loyalty_points_accrued` events.
+    pub fn snapshot_loyalty_points(env: Env, holders: Vec<Address>) -> LoyaltySnapshot {
+        let Some(config) = get_config(&env) else {
+            panic_with_error!(&env, CallRegistryError::NotInitialized);
+        };
+        config.admin.require_auth();
+
+        let id = next_loyalty_snapshot_id(&env);
+        for holder in holders.iter() {
+            let points = get_loyalty_points(&env, &holder);
+            set_loyalty_snapshot_points(&env, id, &holder, points);
+        }
+
+        let snapshot = LoyaltySnapshot {
+            id,
+            taken_at: env.ledger().timestamp(),
+            total_points: get_total_loyalty_points(&env),
+            holders: holders.len(),
+        };
+        set_loyalty_snapshot(&env, &snapshot);
+        emit_loyalty_snapshot_taken(&env, id, snapshot.total_points, snapshot.holders);
+
+        snapshot
+    }
+
+    /// Get a loyalty snapshot record.
+    pub fn get_loyalty_snapshot(env: Env, id: u32) -> Option<LoyaltySnapshot> {
+        get_loyalty_snapshot(&env, id)
+    }
+
+    /// Get an address's points as recorded in a loyalty snapshot.
+    pub fn get_loyalty_snapshot_points(env: Env, id: u32, holder: Address) -> i128 {
+        get_loyalty_snapshot_points(&env, id, &holder)
+    }
+
+    /// Burn `staker`'s position tokens for a call as their payout is claimed
+    /// (outcome_manager only). The staker must still hold tokens matching
+    /// their recorded stakes.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    pub fn burn_positions(
+        env: Env,
+        call_id: u64,
+        staker: Address,
+    ) -> Result<(), CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        config.outcome_manager.require_auth();
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        burn_position_tokens(&env, &call, &staker);
+
+        Ok(())
+    }
+
     /// Transfer admin privileges to a new address (admin only).
     /// # Errors
     /// Propagates errors from [`admin::set_admin`].
@@ -818,18 +3293,130 @@ impl CallRegistry {
         admin::set_admin(env, new_admin)
     }
 
-    /// Replace the outcome manager (admin only).
+    /// Set the minimum and maximum call duration (`end_ts − now`) in seconds
+    /// accepted by `create_call` (admin only). `0` disables a bound.
+    /// # Errors
+    /// Propagates errors from [`admin::set_call_duration_bounds`].
+    pub fn set_call_duration_bounds(
+        env: Env,
+        min_secs: u64,
+        max_secs: u64,
+    ) -> Result<(), CallRegistryError> {
+        admin::set_call_duration_bounds(env, min_secs, max_secs)
+    }
+
+    /// Configure automatic deadline extension for late, large stakes (admin only).
+    /// Pass `window_secs = 0` to disable.
     /// # Errors
-    /// Propagates errors from [`admin::set_outcome_manager`].
-    pub fn set_outcome_manager(env: Env, new_manager: Address) -> Result<(), CallRegistryError> {
-        admin::set_outcome_manager(env, new_manager)
+    /// Propagates errors from [`admin::set_anti_snipe_params`].
+    pub fn set_anti_snipe_params(
+        env: Env,
+        window_secs: u64,
+        threshold_bps: u32,
+        extension_secs: u64,
+        max_extension_secs: u64,
+    ) -> Result<(), CallRegistryError> {
+        admin::set_anti_snipe_params(
+            env,
+            window_secs,
+            threshold_bps,
+            extension_secs,
+            max_extension_secs,
+        )
+    }
+
+    /// Set the maximum number of distinct stakers per call (admin only).
+    /// Pass `0` to remove the limit.
+    /// # Errors
+    /// Propagates errors from [`admin::set_max_stakers_per_call`].
+    pub fn set_max_stakers_per_call(env: Env, new_max: u32) -> Result<(), CallRegistryError> {
+        admin::set_max_stakers_per_call(env, new_max)
+    }
+
+    /// Set the share of referred stakers' protocol fees paid to referrers, in
+    /// basis points (admin only).
+    /// # Errors
+    /// Propagates errors from [`admin::set_referral_share`].
+    pub fn set_referral_share(env: Env, new_share_bps: u32) -> Result<(), CallRegistryError> {
+        admin::set_referral_share(env, new_share_bps)
+    }
+
+    /// Set the win-streak payout bonus per prior win and its cap, in basis
+    /// points (admin only).
+    /// # Errors
+    /// Propagates errors from [`admin::set_streak_bonus`].
+    pub fn set_streak_bonus(
+        env: Env,
+        new_bonus_bps: u32,
+        new_max_bonus_bps: u32,
+    ) -> Result<(), CallRegistryError> {
+        admin::set_streak_bonus(env, new_bonus_bps, new_max_bonus_bps)
+    }
+
+    /// Set the lifetimes, in ledgers, of instance, persistent and temporary
+    /// entries (admin only).
+    /// # Errors
+    /// Propagates errors from [`admin::set_ttl_params`].
+    pub fn set_ttl_params(
+        env: Env,
+        instance_ttl_ledgers: u32,
+        persistent_ttl_ledgers: u32,
+        temporary_ttl_ledgers: u32,
+    ) -> Result<(), CallRegistryError> {
+        admin::set_ttl_params(
+            env,
+            instance_ttl_ledgers,
+            persistent_ttl_ledgers,
+            temporary_ttl_ledgers,
+        )
+    }
+
+    /// Set the DRAW tolerance band in basis points of the start price (admin only).
+    /// Pass `0` to disable draws.
+    /// # Errors
+    /// Propagates errors from [`admin::set_draw_tolerance`].
+    pub fn set_draw_tolerance(env: Env, new_tolerance_bps: u32) -> Result<(), CallRegistryError> {
+        admin::set_draw_tolerance(env, new_tolerance_bps)
+    }
+
+    /// Set the time-weighted stake bonus in basis points (admin only).
+    /// Pass `0` to weight every stake equally.
+    /// # Errors
+    /// Propagates errors from [`admin::set_max_time_bonus`].
+    pub fn set_max_time_bonus(env: Env, new_bonus_bps: u32) -> Result<(), CallRegistryError> {
+        admin::set_max_time_bonus(env, new_bonus_bps)
     }
 
-    /// Set the protocol fee in basis points, e.g. 100 = 1 % (admin only).
+    /// Set or clear the reputation contract notified of call creations,
+    /// resolutions and settled positions (admin only).
     /// # Errors
-    /// Propagates errors from [`admin::set_fee`].
-    pub fn set_fee(env: Env, new_fee_bps: u32) -> Result<(), CallRegistryError> {
-        admin::set_fee(env, new_fee_bps)
+    /// Propagates errors from [`admin::set_reputation_contract`].
+    pub fn set_reputation_contract(
+        env: Env,
+        contract: Option<Address>,
+    ) -> Result<(), CallRegistryError> {
+        admin::set_reputation_contract(env, contract)
+    }
+
+    /// Get the reputation contract this registry notifies, if any.
+    pub fn get_reputation_contract(env: Env) -> Option<Address> {
+        get_reputation_contract(&env)
+    }
+
+    /// Set or clear the rewards contract credited with the stake time of
+    /// settled positions (admin only).
+    /// # Errors
+    /// Propagates errors from [`admin::set_rewards_contract`].
+    pub fn set_rewards_contract(
+        env: Env,
+        contract: Option<Address>,
+    ) -> Result<(), CallRegistryError> {
+        admin::set_rewards_contract(env, contract)
+    }
+
+    /// Get the rewards contract this registry reports stake time to, if any.
+    pub fn get_rewards_contract(env: Env) -> Option<Address> {
+        get_rewards_contract(&env)
     }
 
     /// Get current contract configuration.
@@ -854,6 +3441,76 @@ impl CallRegistry {
         Ok(call.metadata_hash)
     }
 
+    /// Get the end timestamp of a specific call.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_call_end_ts(env: Env, call_id: u64) -> Result<u64, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(call.end_ts)
+    }
+
+    /// Get the start price of a specific call.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_call_start_price(env: Env, call_id: u64) -> Result<i128, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(call.start_price)
+    }
+
+    /// Get the number of positions a specific call can resolve to.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_call_outcome_count(env: Env, call_id: u64) -> Result<u32, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(call.outcome_count)
+    }
+
+    /// Get the creator of a specific call.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_call_creator(env: Env, call_id: u64) -> Result<Address, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(call.creator)
+    }
+
+    /// Get the token a specific call is staked in.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_call_stake_token(env: Env, call_id: u64) -> Result<Address, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(call.stake_token)
+    }
+
+    /// The outcome a binary call resolves to at `end_price`, as `resolve_call`
+    /// accepts it: DRAW when the price stayed inside the draw band, otherwise
+    /// UP or DOWN by the direction it moved from `start_price`.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialized.
+    /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    /// # Panics
+    /// * [`CallRegistryExtError::NotBinaryCall`] – the call has more than two outcomes.
+    pub fn get_binary_outcome(
+        env: Env,
+        call_id: u64,
+        end_price: i128,
+    ) -> Result<u32, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        if call.outcome_count != 2 {
+            panic_with_error!(&env, CallRegistryExtError::NotBinaryCall);
+        }
+        if end_price == call.start_price
+            || is_within_draw_band(call.start_price, end_price, config.draw_tolerance_bps)
+        {
+            return Ok(OUTCOME_DRAW);
+        }
+        if end_price > call.start_price {
+            Ok(OUTCOME_UP)
+        } else {
+            Ok(OUTCOME_DOWN)
+        }
+    }
+
     /// Get the condition type for a specific call.
     /// # Errors
     /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
@@ -872,27 +3529,17 @@ impl CallRegistry {
         evaluate_condition_impl(&condition, start_price, end_price)
     }
 
-    /// Get all calls created by a specific address (unbounded scan — prefer paginated variant).
-    pub fn get_calls_by_creator(env: Env, creator: Address) -> Vec<Call> {
-        let mut calls = Vec::new(&env);
-        let total_calls = get_call_counter(&env);
-
-        for i in 1..=total_calls {
-            if let Some(call) = get_call(&env, i) {
-                if call.creator == creator {
-                    calls.push_back(call);
-                }
-            }
-        }
-
-        calls
+    /// Get a page of the calls created by a specific address, oldest first.
+    pub fn get_calls_by_creator(env: Env, creator: Address, cursor: u32, limit: u32) -> CallPage {
+        call_page(&env, &get_creator_calls(&env, &creator), cursor, limit)
     }
 
-    /// Get a paginated slice of calls starting at `start_id`.
+    /// Get a paginated slice of call summaries starting at `start_id`.
     /// Returns at most [`MAX_CALL_PAGE_SIZE`] calls.
-    pub fn get_calls_paginated(env: Env, start_id: u64, limit: u32) -> Vec<Call> {
+    pub fn get_calls_paginated(env: Env, start_id: u64, limit: u32) -> Vec<CallSummary> {
         let mut calls = Vec::new(&env);
         let total_calls = get_call_counter(&env);
+        let now = env.ledger().timestamp();
         let page_size = limit.min(MAX_CALL_PAGE_SIZE);
 
         if page_size == 0 {
@@ -904,7 +3551,7 @@ impl CallRegistry {
 
         while count < page_size && current <= total_calls {
             if let Some(call) = get_call(&env, current) {
-                calls.push_back(call);
+                calls.push_back(summarize(&call, now));
                 count += 1;
             }
             current += 1;
@@ -913,59 +3560,44 @@ impl CallRegistry {
         calls
     }
 
-    /// Get a paginated slice of calls created by a specific address.
-    /// Returns at most [`MAX_CALL_PAGE_SIZE`] calls starting from `start_id`.
+    /// Get a paginated slice of summaries of calls created by a specific
+    /// address. Returns at most [`MAX_CALL_PAGE_SIZE`] calls starting from
+    /// `start_id`.
     pub fn get_calls_by_creator_paginated(
         env: Env,
         creator: Address,
         start_id: u64,
         limit: u32,
-    ) -> Vec<Call> {
+    ) -> Vec<CallSummary> {
         let mut calls = Vec::new(&env);
-        let total_calls = get_call_counter(&env);
-        let page_size = limit.min(MAX_CALL_PAGE_SIZE);
-
-        if page_size == 0 {
-            return calls;
-        }
-
-        let mut count = 0;
-        let mut current = if start_id < 1 { 1 } else { start_id };
-
-        while count < page_size && current <= total_calls {
-            if let Some(call) = get_call(&env, current) {
-                if call.creator == creator {
-                    calls.push_back(call);
-                    count += 1;
-                }
+        let page_size = limit.min(MAX_CALL_PAGE_SIZE);
+        let now = env.ledger().timestamp();
+
+        for call_id in get_creator_calls(&env, &creator).iter() {
+            if calls.len() >= page_size {
+                break;
+            }
+            if call_id < start_id {
+                continue;
+            }
+            if let Some(call) = get_call(&env, call_id) {
+                calls.push_back(summarize(&call, now));
             }
-            current += 1;
         }
 
         calls
     }
 
-    /// Get statistics for a specific call.
+    /// Get statistics for a specific call, read from the record updated on
+    /// every stake. Calls with no stakes yet report zero counts.
     /// # Errors
     /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
     pub fn get_call_stats(env: Env, call_id: u64) -> Result<CallStats, CallRegistryError> {
-        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
-
-        let mut outcome_stake_counts = Map::new(&env);
-        let mut total_stakes = 0;
-
-        for i in 1..=call.outcome_count {
-            let outcome_stakers = call.stakes.get(i).unwrap_or_else(|| Map::new(&env));
-            let count = outcome_stakers.len();
-            outcome_stake_counts.set(i, count);
-            total_stakes += count;
+        if let Some(stats) = get_call_stats(&env, call_id) {
+            return Ok(stats);
         }
-
-        Ok(CallStats {
-            outcome_stakes: call.outcome_stakes,
-            outcome_stake_counts,
-            total_stakes,
-        })
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(derive_call_stats(&env, &call))
     }
 
     /// Get creator reputation statistics
@@ -973,30 +3605,141 @@ impl CallRegistry {
         get_creator_stats(&env, &creator)
     }
 
-    /// Get all calls a staker has participated in.
-    pub fn get_staker_calls(env: Env, staker: Address) -> Vec<Call> {
-        let call_ids = get_staker_calls(&env, &staker);
+    /// Get a page of the calls still open for staking, oldest first. The
+    /// cursor walks the index of unresolved calls; entries past their
+    /// `end_ts` but not yet resolved are skipped, so a page may hold fewer
+    /// than `limit` calls while `next_cursor` is still set.
+    pub fn get_active_calls(env: Env, cursor: u32, limit: u32) -> CallPage {
+        let mut page = call_page(&env, &get_active_calls(&env), cursor, limit);
         let mut calls = Vec::new(&env);
-
-        for call_id in call_ids.iter() {
-            if let Some(call) = get_call(&env, call_id) {
+        for call in page.calls.iter() {
+            if call.status == CallStatus::Open {
                 calls.push_back(call);
             }
         }
+        page.calls = calls;
+        page
+    }
 
-        calls
+    /// Extend the storage TTL of a call and its per-call indexes so a
+    /// long-running call is not archived. Anyone may pay to keep a call alive.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn bump_call(env: Env, call_id: u64) -> Result<(), CallRegistryError> {
+        if !bump_call(&env, call_id) {
+            return Err(CallRegistryError::CallNotFound);
+        }
+        Ok(())
+    }
+
+    /// Get a page of the calls whose `end_ts` lies in `[from_ts, to_ts]`,
+    /// including resolved ones, for keepers looking for calls to resolve.
+    /// Calls come grouped by the hour their `end_ts` falls in. A query
+    /// covers at most one week from `from_ts`; later calls are left out.
+    pub fn get_calls_ending_between(
+        env: Env,
+        from_ts: u64,
+        to_ts: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> CallPage {
+        let page_size = limit.min(MAX_CALL_PAGE_SIZE);
+        let mut page = CallPage {
+            calls: Vec::new(&env),
+            next_cursor: None,
+        };
+        if to_ts < from_ts || page_size == 0 {
+            return page;
+        }
+
+        let now = env.ledger().timestamp();
+        let first = from_ts / END_TS_BUCKET_SECS;
+        let last = (to_ts / END_TS_BUCKET_SECS).min(first + MAX_END_TS_QUERY_BUCKETS - 1);
+        let mut skipped = 0u32;
+        for bucket in first..=last {
+            for call_id in get_end_ts_bucket(&env, bucket).iter() {
+                let Some(call) = get_call(&env, call_id) else {
+                    continue;
+                };
+                if call.end_ts < from_ts || call.end_ts > to_ts {
+                    continue;
+                }
+                if skipped < cursor {
+                    skipped += 1;
+                    continue;
+                }
+                if page.calls.len() == page_size {
+                    page.next_cursor = Some(cursor + page_size);
+                    return page;
+                }
+                page.calls.push_back(summarize(&call, now));
+            }
+        }
+        page
+    }
+
+    /// Get a page of the calls a staker has participated in, by call ID.
+    pub fn get_staker_calls(env: Env, staker: Address, cursor: u32, limit: u32) -> CallPage {
+        call_page(
+            &env,
+            &get_staker_portfolio(&env, &staker).keys(),
+            cursor,
+            limit,
+        )
+    }
+
+    /// Get a page of a staker's portfolio by call ID: each call they have
+    /// staked on with their current stake per outcome. Calls they have fully
+    /// withdrawn from stay listed with zero stake.
+    pub fn get_staker_portfolio(
+        env: Env,
+        staker: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> PortfolioPage {
+        let portfolio = get_staker_portfolio(&env, &staker);
+        let (start, end, next_cursor) = page_range(portfolio.len(), cursor, limit);
+        let now = env.ledger().timestamp();
+        let mut entries = Vec::new(&env);
+        let page = portfolio
+            .iter()
+            .skip(start as usize)
+            .take((end - start) as usize);
+        for (call_id, participation) in page {
+            if let Some(call) = get_call(&env, call_id) {
+                entries.push_back(PortfolioEntry {
+                    call: summarize(&call, now),
+                    participation,
+                });
+            }
+        }
+        PortfolioPage {
+            entries,
+            next_cursor,
+        }
     }
 
-    /// Get all stakers that have participated in a call.
-    pub fn get_call_stakers(env: Env, call_id: u64) -> Result<Vec<Address>, CallRegistryError> {
+    /// Get a page of the stakers that have participated in a call.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_call_stakers(
+        env: Env,
+        call_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<AddressPage, CallRegistryError> {
         get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
-        Ok(storage::get_call_stakers(&env, call_id))
+        Ok(address_page(
+            &storage::get_call_stakers(&env, call_id),
+            cursor,
+            limit,
+        ))
     }
 
     /// Get the number of unique stakers that have participated in a call.
     pub fn get_call_staker_count(env: Env, call_id: u64) -> Result<u32, CallRegistryError> {
         get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
-        Ok(storage::get_call_stakers(&env, call_id).len() as u32)
+        Ok(storage::get_call_stakers(&env, call_id).len())
     }
 
     /// Get the stake amount a staker has on a specific call position.
@@ -1019,6 +3762,65 @@ impl CallRegistry {
         Ok(outcome_stakers.get(staker).unwrap_or(0))
     }
 
+    /// Get each stake `staker` has placed on a call, oldest first, with its
+    /// position and timestamp. Reductions and switches trim the most recent
+    /// entries on the affected position.
+    pub fn get_stake_entries(env: Env, call_id: u64, staker: Address) -> Vec<StakeEntry> {
+        get_stake_entries(&env, call_id, &staker)
+    }
+
+    /// Get a staker's time-weighted stake on a specific position. Winners
+    /// split the losing pool in proportion to this weight.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::InvalidPosition`] – `position` ∉ [1, outcome_count].
+    pub fn get_weighted_stake(
+        env: Env,
+        call_id: u64,
+        staker: Address,
+        position: u32,
+    ) -> Result<i128, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+
+        if position < 1 || position > call.outcome_count {
+            return Err(CallRegistryError::InvalidPosition);
+        }
+
+        Ok(get_weighted_stake(&env, call_id, &staker, position))
+    }
+
+    /// Remaining room in a call's pool before `max_total_stake` is hit;
+    /// `None` when the call is uncapped.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_remaining_capacity(
+        env: Env,
+        call_id: u64,
+    ) -> Result<Option<i128>, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        if call.max_total_stake == 0 {
+            return Ok(None);
+        }
+        Ok(Some((call.max_total_stake - total_pool(&call)).max(0)))
+    }
+
+    /// Remaining amount `staker` may add to a call before `max_stake_per_address`
+    /// is hit; `None` when the call has no per-address cap.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_remaining_address_capacity(
+        env: Env,
+        call_id: u64,
+        staker: Address,
+    ) -> Result<Option<i128>, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        if call.max_stake_per_address == 0 {
+            return Ok(None);
+        }
+        let used = staker_total_stake(&call, &staker);
+        Ok(Some((call.max_stake_per_address - used).max(0)))
+    }
+
     /// Get the total stakes for each outcome of a call.
     /// # Errors
     /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
@@ -1027,6 +3829,57 @@ impl CallRegistry {
         Ok(call.outcome_stakes)
     }
 
+    /// Get the total time-weighted stake for each outcome of a call. Winners
+    /// split the losing pool in proportion to their share of this total.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_weighted_outcome_stakes(
+        env: Env,
+        call_id: u64,
+    ) -> Result<Map<u32, i128>, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(call.weighted_outcome_stakes)
+    }
+
+    /// Get the reduce and switch fees a call has collected. They are paid out
+    /// to winners together with the losing stakes, or moved to the insurance
+    /// pool by [`Self::sweep_penalty_pool`] if the call has no winners.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`] – `call_id` does not exist.
+    pub fn get_penalty_pool(env: Env, call_id: u64) -> Result<i128, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        Ok(call.penalty_pool)
+    }
+
+    /// Get the position token for `outcome` of a call, its stake token and
+    /// whether the position can still be traded.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::InvalidPosition`] – the call has no position token
+    ///   for `outcome`.
+    pub fn get_position_token(
+        env: Env,
+        call_id: u64,
+        outcome: u32,
+    ) -> Result<PositionTokenInfo, CallRegistryError> {
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        let token = call
+            .share_tokens
+            .get(outcome)
+            .ok_or(CallRegistryError::InvalidPosition)?;
+        let tradable = call.outcome == 0
+            && !call.settled
+            && !call.voided
+            && !call.cancelled
+            && !call.early_resolved;
+
+        Ok(PositionTokenInfo {
+            token,
+            stake_token: call.stake_token,
+            tradable,
+        })
+    }
+
     /// Get total number of calls created.
     pub fn get_call_count(env: Env) -> u64 {
         get_call_counter(&env)
@@ -1057,10 +3910,10 @@ impl CallRegistry {
             return Err(CallRegistryError::CallSettled);
         }
         if call.cancelled {
-            panic!("Call has been cancelled");
+            panic_with_error!(&env, CallRegistryExtError::CallCancelled);
         }
         if call.voided {
-            panic!("Call has been voided");
+            panic_with_error!(&env, CallRegistryExtError::CallVoided);
         }
 
         let message = build_start_price_message(&env, call_id, price);
@@ -1093,90 +3946,410 @@ impl CallRegistry {
         stats
     }
 
-    /// Return the current contract version.
-    pub fn version(env: Env) -> u32 {
-        env.storage()
-            .instance()
-            .get(&Symbol::new(&env, "version"))
-            .unwrap_or(CONTRACT_VERSION)
+    /// Return the sequence number of the most recently emitted event. Every
+    /// event carries its sequence number as its last topic.
+    pub fn get_event_sequence(env: Env) -> u64 {
+        get_event_sequence(&env)
+    }
+
+    /// Return the semantic version of the deployed code.
+    pub fn version(env: Env) -> SemVer {
+        get_contract_version(&env).unwrap_or(CONTRACT_VERSION)
+    }
+
+    /// Return the semantic version together with the build installed on
+    /// this instance, so callers can gate on capabilities across upgrades.
+    /// `build_id` is the wasm hash of the latest upgrade, `None` for the
+    /// originally deployed code.
+    pub fn get_version(env: Env) -> VersionInfo {
+        let build = get_build_info(&env);
+        VersionInfo {
+            version: get_contract_version(&env).unwrap_or(CONTRACT_VERSION),
+            build_id: build.as_ref().and_then(|info| info.build_id.clone()),
+            installed_ledger: build.map_or(0, |info| info.installed_ledger),
+        }
+    }
+
+    /// Queue a fee, spread, outcome manager, upgrade or timelock-delay change.
+    /// Fee and spread changes need the fee-setter role, the rest the admin
+    /// role. It can be executed once the configured timelock delay has
+    /// passed, so stakers can exit before it takes effect. Returns the
+    /// action id.
+    /// # Errors
+    /// Propagates errors from [`timelock::queue_admin_action`].
+    pub fn queue_admin_action(
+        env: Env,
+        caller: Address,
+        action: AdminAction,
+    ) -> Result<u64, CallRegistryError> {
+        timelock::queue_admin_action(env, caller, action)
+    }
+
+    /// Apply a queued admin action whose delay has elapsed. Needs the same
+    /// role as queueing it. An upgrade replaces the WASM; run
+    /// [`CallRegistry::migrate`] afterwards if the new code expects a newer
+    /// storage layout.
+    /// # Errors
+    /// Propagates errors from [`timelock::execute_admin_action`].
+    pub fn execute_admin_action(
+        env: Env,
+        caller: Address,
+        id: u64,
+    ) -> Result<(), CallRegistryError> {
+        timelock::execute_admin_action(env, caller, id)
+    }
+
+    /// Drop a queued admin action without applying it. Needs the same role
+    /// as queueing it.
+    /// # Errors
+    /// Propagates errors from [`timelock::cancel_admin_action`].
+    pub fn cancel_admin_action(
+        env: Env,
+        caller: Address,
+        id: u64,
+    ) -> Result<(), CallRegistryError> {
+        timelock::cancel_admin_action(env, caller, id)
+    }
+
+    /// Return a queued admin action, or `None` once it has been executed or
+    /// cancelled.
+    pub fn get_admin_action(env: Env, id: u64) -> Option<QueuedAction> {
+        get_queued_action(&env, id)
+    }
+
+    /// Queue a replacement of every [`ProtocolParams`] tunable. Shorthand for
+    /// [`queue_admin_action`](Self::queue_admin_action) with
+    /// [`AdminAction::SetParams`]; returns the queued action's id.
+    /// # Errors
+    /// Propagates errors from [`timelock::queue_admin_action`].
+    pub fn set_params(
+        env: Env,
+        caller: Address,
+        params: ProtocolParams,
+    ) -> Result<u64, CallRegistryError> {
+        timelock::queue_admin_action(env, caller, AdminAction::SetParams(params))
+    }
+
+    /// Return the tunables currently in force.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    pub fn get_params(env: Env) -> Result<ProtocolParams, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        Ok(config.params())
     }
 
-    /// Upgrade the contract WASM to a new hash (admin only).
+    /// Return the storage layout this instance's data is in. Instances
+    /// initialised before layouts were tracked report layout 1 until
+    /// [`CallRegistry::migrate`] rewrites them.
+    pub fn get_storage_layout(env: Env) -> u32 {
+        get_storage_layout(&env)
+    }
+
+    /// Rewrite data written under an older storage layout into the current
+    /// one (admin only). Run after `upgrade` installs code that expects the
+    /// new layout.
+    ///
+    /// Layout 1 → 2 moves the contract version off the `"version"` string
+    /// key, moves void-refund flags from instance to persistent storage and
+    /// back-fills the creator, active-call and `end_ts` indexes. Layout
+    /// 2 → 3 writes a stats record for every call. Layout 3 → 4 replaces each
+    /// staker's call-ID list with participation records. Layout 4 → 5 stores
+    /// the contract version as a semantic version. Layout 5 → 6 grants every
+    /// [`Role`] to the admin. Calls are
+    /// processed in ID order, at most `limit` per invocation (capped at
+    /// `MAX_MIGRATION_BATCH`); call `migrate` again until it returns the
+    /// current layout. Calling it on an up-to-date instance is a no-op.
+    ///
+    /// Returns the layout the instance holds after this batch.
     ///
     /// # Errors
     /// * [`CallRegistryError::NotInitialized`] -- contract not initialised.
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), CallRegistryError> {
+    ///
+    /// # Panics
+    /// * [`CallRegistryExtError::StorageLayoutMismatch`] -- `from_version` is
+    ///   not the layout the instance currently holds.
+    pub fn migrate(env: Env, from_version: u32, limit: u32) -> Result<u32, CallRegistryError> {
         let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
         config.admin.require_auth();
 
-        let old_version: u32 = env
-            .storage()
-            .instance()
-            .get(&Symbol::new(&env, "version"))
-            .unwrap_or(CONTRACT_VERSION);
-        let new_version = old_version + 1;
+        let layout = get_storage_layout(&env);
+        if from_version != layout {
+            panic_with_error!(&env, CallRegistryExtError::StorageLayoutMismatch);
+        }
+        if layout == STORAGE_LAYOUT_VERSION {
+            return Ok(layout);
+        }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        let start = get_migration_cursor(&env);
+        let end = start.saturating_add(limit.clamp(1, MAX_MIGRATION_BATCH) as u64);
+        let last = get_call_counter(&env);
+        for call_id in start..end.min(last + 1) {
+            if let Some(call) = get_call(&env, call_id) {
+                migrate_call(&env, &call);
+            }
+        }
 
-        env.storage()
-            .instance()
-            .set(&Symbol::new(&env, "version"), &new_version);
+        if end <= last {
+            set_migration_cursor(&env, end);
+            extend_storage_ttl(&env);
+            return Ok(layout);
+        }
 
-        emit_contract_upgraded(&env, old_version, new_version, &config.admin);
+        migrate_contract_version(&env);
+        migrate_roles(&env, &config.admin);
+        clear_migration_cursor(&env);
+        set_storage_layout(&env, STORAGE_LAYOUT_VERSION);
+        extend_storage_ttl(&env);
 
-        Ok(())
+        emit_storage_migrated(&env, layout, STORAGE_LAYOUT_VERSION, &config.admin);
+
+        Ok(STORAGE_LAYOUT_VERSION)
     }
 
-    /// Void a call (admin only). Can be called at any time.
+    /// Void a call (resolver only). Can be called at any time.
     /// Once voided, no new stakes or resolutions are accepted.
     /// Emits CallVoided.
-    pub fn void_call(env: Env, call_id: u64) {
-        let config = get_config(&env).expect("Not initialized");
-        config.admin.require_auth();
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    /// * [`CallRegistryError::CallSettled`]    – the call is already settled.
+    /// # Panics
+    /// * [`CallRegistryExtError::CallVoided`] – the call is already voided.
+    /// * [`CallRegistryExtError::MissingRole`] – `caller` lacks [`Role::Resolver`].
+    pub fn void_call(env: Env, caller: Address, call_id: u64) -> Result<(), CallRegistryError> {
+        get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        roles::require_role(&env, &caller, Role::Resolver);
 
-        let mut call = get_call(&env, call_id).expect("Call not found");
+        let mut call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
 
         if call.voided {
-            panic!("Call already voided");
+            panic_with_error!(&env, CallRegistryExtError::CallVoided);
         }
 
         if call.settled {
-            panic!("Call already settled");
+            return Err(CallRegistryError::CallSettled);
         }
 
         call.voided = true;
         set_call(&env, &call);
+        remove_active_call(&env, call_id);
+        hold_creator_bond(&env, call_id);
+        extend_storage_ttl(&env);
+
+        emit_call_voided(&env, call_id, &caller);
+
+        Ok(())
+    }
+
+    /// Suspend stakes, top-ups, reductions and switches on one call while it
+    /// is investigated (admin or resolver). Unlike voiding, the call keeps
+    /// its stakes and can resume with [`unfreeze_call`](Self::unfreeze_call).
+    /// Freezing a frozen call is a no-op.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    /// # Panics
+    /// * [`CallRegistryExtError::MissingRole`] – `caller` holds neither
+    ///   [`Role::Admin`] nor [`Role::Resolver`].
+    pub fn freeze_call(env: Env, caller: Address, call_id: u64) -> Result<(), CallRegistryError> {
+        get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        roles::require_any_role(&env, &caller, &[Role::Admin, Role::Resolver]);
+        get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+
+        if !is_call_frozen(&env, call_id) {
+            set_call_frozen(&env, call_id);
+            extend_storage_ttl(&env);
+            emit_call_frozen(&env, call_id, &caller);
+        }
+
+        Ok(())
+    }
+
+    /// Resume staking on a call suspended by [`freeze_call`](Self::freeze_call)
+    /// (admin or resolver). Unfreezing a call that is not frozen is a no-op.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    /// # Panics
+    /// * [`CallRegistryExtError::MissingRole`] – `caller` holds neither
+    ///   [`Role::Admin`] nor [`Role::Resolver`].
+    pub fn unfreeze_call(env: Env, caller: Address, call_id: u64) -> Result<(), CallRegistryError> {
+        get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        roles::require_any_role(&env, &caller, &[Role::Admin, Role::Resolver]);
+
+        if is_call_frozen(&env, call_id) {
+            remove_call_frozen(&env, call_id);
+            extend_storage_ttl(&env);
+            emit_call_unfrozen(&env, call_id, &caller);
+        }
+
+        Ok(())
+    }
+
+    /// Whether staking on a call is suspended by [`freeze_call`](Self::freeze_call).
+    pub fn is_call_frozen(env: Env, call_id: u64) -> bool {
+        is_call_frozen(&env, call_id)
+    }
+
+    /// Return a creator's bond once their call has closed. A resolved call's
+    /// bond is refunded once its dispute window closes; a voided or cancelled
+    /// call's bond stays slashable for [`CREATOR_BOND_SLASH_GRACE_SECS`]
+    /// first. Returns the amount refunded.
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::CallNotResolved`] – the call is still open.
+    /// * [`CallRegistryError::NothingToClaim`]  – no bond is escrowed for the call.
+    /// # Panics
+    /// * [`CallRegistryExtError::CreatorBondHeld`] – the call was voided or
+    ///   cancelled less than [`CREATOR_BOND_SLASH_GRACE_SECS`] ago.
+    pub fn claim_creator_bond(env: Env, call_id: u64) -> Result<i128, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Claim)?;
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        call.creator.require_auth();
+
+        if call.outcome == 0 && !call.voided && !call.cancelled {
+            return Err(CallRegistryError::CallNotResolved);
+        }
+        let bond = get_creator_bond(&env, call_id);
+        if bond <= 0 {
+            return Err(CallRegistryError::NothingToClaim);
+        }
+        if call.voided || call.cancelled {
+            let held_until = get_creator_bond_held_until(&env, call_id)
+                .unwrap_or(call.end_ts.saturating_add(CREATOR_BOND_SLASH_GRACE_SECS));
+            if env.ledger().timestamp() < held_until {
+                panic_with_error!(&env, CallRegistryExtError::CreatorBondHeld);
+            }
+        }
+        dispute::ensure_dispute_window_closed(&env, call_id);
+
+        remove_creator_bond(&env, call_id);
+        extend_storage_ttl(&env);
+        transfer_token(
+            &env,
+            &call.stake_token,
+            &env.current_contract_address(),
+            &call.creator,
+            bond,
+        );
+
+        emit_creator_bond_refunded(&env, call_id, &call.creator, bond);
+
+        Ok(bond)
+    }
+
+    /// Slash a creator's bond into the insurance pool for the call's stake
+    /// token (admin only), e.g. when the call is judged spam or
+    /// unresolvable. A voided or cancelled call's bond can be slashed until
+    /// [`CREATOR_BOND_SLASH_GRACE_SECS`] after it closed. Returns the amount
+    /// slashed.
+    /// # Errors
+    /// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+    /// * [`CallRegistryError::CallNotFound`]   – `call_id` does not exist.
+    /// * [`CallRegistryError::NothingToClaim`] – no bond is escrowed for the call.
+    pub fn slash_creator_bond(env: Env, call_id: u64) -> Result<i128, CallRegistryError> {
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        config.admin.require_auth();
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
+        let bond = get_creator_bond(&env, call_id);
+        if bond <= 0 {
+            return Err(CallRegistryError::NothingToClaim);
+        }
+
+        remove_creator_bond(&env, call_id);
+        add_insurance_pool(&env, &call.stake_token, bond);
         extend_storage_ttl(&env);
 
-        emit_call_voided(&env, call_id, &config.admin);
+        emit_creator_bond_slashed(&env, call_id, &call.creator, bond, &config.admin);
+
+        Ok(bond)
+    }
+
+    /// Return the creator bond still escrowed for a call.
+    pub fn get_creator_bond(env: Env, call_id: u64) -> i128 {
+        get_creator_bond(&env, call_id)
+    }
+
+    /// Return the slashed bonds and expired winnings held in the insurance
+    /// pool for `token`.
+    pub fn get_insurance_pool(env: Env, token: Address) -> i128 {
+        get_insurance_pool(&env, &token)
+    }
+
+    /// Start the 7-day countdown on sweeping a call's escrow (admin only).
+    /// Returns the timestamp from which the sweep can be executed.
+    /// # Errors
+    /// Propagates errors from [`emergency::initiate_emergency_withdraw`].
+    pub fn initiate_emergency_withdraw(env: Env, call_id: u64) -> Result<u64, CallRegistryError> {
+        emergency::initiate_emergency_withdraw(env, call_id)
+    }
+
+    /// Sweep a call's remaining escrow to the admin once the delay has
+    /// passed (admin only). Returns the amount swept.
+    /// # Errors
+    /// Propagates errors from [`emergency::execute_emergency_withdraw`].
+    pub fn execute_emergency_withdraw(env: Env, call_id: u64) -> Result<i128, CallRegistryError> {
+        emergency::execute_emergency_withdraw(env, call_id)
+    }
+
+    /// Abandon a pending emergency withdrawal (admin only).
+    /// # Errors
+    /// Propagates errors from [`emergency::cancel_emergency_withdraw`].
+    pub fn cancel_emergency_withdraw(env: Env, call_id: u64) -> Result<(), CallRegistryError> {
+        emergency::cancel_emergency_withdraw(env, call_id)
+    }
+
+    /// Get a call's pending or executed emergency withdrawal, if any.
+    pub fn get_emergency_withdrawal(env: Env, call_id: u64) -> Option<EmergencyWithdrawal> {
+        get_emergency_withdrawal(&env, call_id)
     }
 
     /// Claim a full refund for a voided call.
     /// Refunds the exact stake the caller placed (up + down combined).
     /// Emits VoidRefundClaimed.
-    pub fn claim_void_refund(env: Env, staker: Address, call_id: u64) {
+    /// # Errors
+    /// * [`CallRegistryError::CallNotFound`]    – `call_id` does not exist.
+    /// * [`CallRegistryError::NoExistingStake`] – the caller has nothing to refund.
+    /// # Panics
+    /// * [`CallRegistryExtError::CallNotVoided`]        – the call is not voided.
+    /// * [`CallRegistryExtError::RefundAlreadyClaimed`] – already refunded.
+    /// * [`CallRegistryExtError::CallFundsWithdrawn`]   – the escrow was swept by
+    ///   an emergency withdrawal.
+    pub fn claim_void_refund(
+        env: Env,
+        staker: Address,
+        call_id: u64,
+    ) -> Result<(), CallRegistryError> {
         staker.require_auth();
 
-        let call = get_call(&env, call_id).expect("Call not found");
+        let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+        ensure_unpaused(&config, PauseComponent::Claim)?;
+
+        let call = get_call(&env, call_id).ok_or(CallRegistryError::CallNotFound)?;
 
         if !call.voided {
-            panic!("Call is not voided");
+            panic_with_error!(&env, CallRegistryExtError::CallNotVoided);
         }
 
         if is_void_refund_claimed(&env, call_id, &staker) {
-            panic!("Refund already claimed");
+            panic_with_error!(&env, CallRegistryExtError::RefundAlreadyClaimed);
         }
 
-        let up_stake = get_user_stake(&env, call_id, &staker, 1);
-        let down_stake = get_user_stake(&env, call_id, &staker, 2);
-        let total_refund = up_stake + down_stake;
+        let total_refund: i128 = (1..=call.outcome_count)
+            .map(|position| get_user_stake(&env, call_id, &staker, position))
+            .sum();
 
         if total_refund <= 0 {
-            panic!("No stake to refund");
+            return Err(CallRegistryError::NoExistingStake);
         }
 
+        emergency::ensure_funds_not_withdrawn(&env, call_id);
         set_void_refund_claimed(&env, call_id, &staker);
+        add_escrow_released(&env, call_id, total_refund);
         extend_storage_ttl(&env);
+        burn_position_tokens(&env, &call, &staker);
 
         // Dispatch to native XLM or SAC-wrapped token path.
         transfer_token(
@@ -1192,6 +4365,8 @@ impl CallRegistry {
         } else {
             emit_void_refund_claimed(&env, call_id, &staker, total_refund);
         }
+
+        Ok(())
     }
 
     /// Returns the sentinel `Address` that represents native XLM.
@@ -1271,3 +4446,14 @@ impl CallRegistry {
         get_sep10_domain(&env, &user)
     }
 }
+
+#[cfg(test)]
+#[contractimpl]
+impl CallRegistry {
+    /// Test-only: register the XLM SAC address so is_native_xlm works in tests.
+    pub fn set_xlm_sac_address(env: Env, xlm_sac: Address) {
+        env.storage()
+            .instance()
+            .set(&soroban_sdk::Symbol::new(&env, "xlm_sac_addr"), &xlm_sac);
+    }
+}
@@ -0,0 +1,69 @@
+use soroban_sdk::{panic_with_error, Env, I256};
+
+use crate::errors::CallRegistryExtError;
+
+/// `a + b` for token amounts and pool totals.
+/// # Panics
+/// * [`CallRegistryExtError::AmountOverflow`] – the sum does not fit in `i128`.
+pub fn add(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b)
+        .unwrap_or_else(|| panic_with_error!(env, CallRegistryExtError::AmountOverflow))
+}
+
+/// `a * b` for token amounts.
+/// # Panics
+/// * [`CallRegistryExtError::AmountOverflow`] – the product does not fit in `i128`.
+pub fn mul(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_mul(b)
+        .unwrap_or_else(|| panic_with_error!(env, CallRegistryExtError::AmountOverflow))
+}
+
+/// `a * b / c`, rounded towards zero, with a 256-bit intermediate product so
+/// that pro-rata shares of large pools stay exact.
+/// # Panics
+/// * [`CallRegistryExtError::AmountOverflow`] – the result does not fit in `i128`.
+pub fn mul_div(env: &Env, a: i128, b: i128, c: i128) -> i128 {
+    I256::from_i128(env, a)
+        .mul(&I256::from_i128(env, b))
+        .div(&I256::from_i128(env, c))
+        .to_i128()
+        .unwrap_or_else(|| panic_with_error!(env, CallRegistryExtError::AmountOverflow))
+}
+
+/// The `bps` basis-point share of `amount`, rounded towards zero. Splits
+/// `amount` around 10 000 so that shares of at most 100 % never overflow.
+/// # Panics
+/// * [`CallRegistryExtError::AmountOverflow`] – the share does not fit in `i128`.
+pub fn bps_of(env: &Env, amount: i128, bps: u32) -> i128 {
+    let bps = bps as i128;
+    add(
+        env,
+        mul(env, amount / 10_000, bps),
+        amount % 10_000 * bps / 10_000,
+    )
+}
+
+/// An unsigned counter that can be bumped by one without wrapping.
+pub trait Counter: Copy {
+    fn checked_inc(self) -> Option<Self>;
+}
+
+impl Counter for u32 {
+    fn checked_inc(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+}
+
+impl Counter for u64 {
+    fn checked_inc(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+}
+
+/// `n + 1` for ids and counters.
+/// # Panics
+/// * [`CallRegistryExtError::CounterOverflow`] – `n` is already at its maximum.
+pub fn increment<T: Counter>(env: &Env, n: T) -> T {
+    n.checked_inc()
+        .unwrap_or_else(|| panic_with_error!(env, CallRegistryExtError::CounterOverflow))
+}
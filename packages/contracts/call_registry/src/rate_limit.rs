@@ -0,0 +1,42 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::errors::CallRegistryExtError;
+use crate::storage::{get_call_rate_count, set_call_rate_count};
+use crate::types::{ContractConfig, CALL_RATE_BUCKETS};
+
+/// Count a new call against `creator`'s rate limit.
+///
+/// The window is split into [`CALL_RATE_BUCKETS`] buckets kept in temporary
+/// storage. Every bucket overlapping `(now - window, now]` is counted, so the
+/// limit is enforced over a window that slides one bucket at a time and may
+/// briefly include up to one bucket more than `call_rate_window_secs`.
+/// A `max_calls_per_window` of `0` disables the check.
+///
+/// # Panics
+/// * [`CallRegistryExtError::CallRateLimited`] – `creator` already opened
+///   `max_calls_per_window` calls in the window.
+pub fn record_call_creation(env: &Env, config: &ContractConfig, creator: &Address, now: u64) {
+    if config.max_calls_per_window == 0 || config.call_rate_window_secs == 0 {
+        return;
+    }
+
+    let window = config.call_rate_window_secs;
+    let bucket_secs = window.div_ceil(CALL_RATE_BUCKETS);
+    let current = now - now % bucket_secs;
+    let oldest = now.saturating_sub(window - 1);
+    let mut bucket = oldest - oldest % bucket_secs;
+
+    let mut used: u32 = 0;
+    while bucket < current {
+        used = used.saturating_add(get_call_rate_count(env, creator, bucket));
+        bucket += bucket_secs;
+    }
+    let in_current = get_call_rate_count(env, creator, current);
+    used = used.saturating_add(in_current);
+
+    if used >= config.max_calls_per_window {
+        panic_with_error!(env, CallRegistryExtError::CallRateLimited);
+    }
+
+    set_call_rate_count(env, creator, current, in_current + 1);
+}
@@ -0,0 +1,76 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::errors::{CallRegistryError, CallRegistryExtError};
+use crate::events::{emit_role_granted, emit_role_revoked};
+use crate::storage::{add_role, extend_storage_ttl, get_config, has_role, remove_role};
+use crate::types::Role;
+
+/// Require `caller` to sign and to hold `role`.
+/// # Panics
+/// * [`CallRegistryExtError::MissingRole`] – `caller` does not hold `role`.
+pub fn require_role(env: &Env, caller: &Address, role: Role) {
+    caller.require_auth();
+    if !has_role(env, role, caller) {
+        panic_with_error!(env, CallRegistryExtError::MissingRole);
+    }
+}
+
+/// Require `caller` to sign and to hold at least one of `roles`.
+/// # Panics
+/// * [`CallRegistryExtError::MissingRole`] – `caller` holds none of `roles`.
+pub fn require_any_role(env: &Env, caller: &Address, roles: &[Role]) {
+    caller.require_auth();
+    if !roles.iter().any(|role| has_role(env, *role, caller)) {
+        panic_with_error!(env, CallRegistryExtError::MissingRole);
+    }
+}
+
+/// Give `account` an operational role. Granting a role it already holds is
+/// a no-op.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::RoleNotGrantable`] – `role` is [`Role::Admin`].
+pub fn grant_role(env: Env, role: Role, account: Address) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    if role == Role::Admin {
+        panic_with_error!(&env, CallRegistryExtError::RoleNotGrantable);
+    }
+
+    if add_role(&env, role, &account) {
+        extend_storage_ttl(&env);
+        emit_role_granted(&env, role, &account, &config.admin);
+    }
+
+    Ok(())
+}
+
+/// Take an operational role away from `account`. Revoking a role it does
+/// not hold is a no-op.
+/// # Authorization
+/// Current admin must sign.
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::RoleNotGrantable`] – `role` is [`Role::Admin`].
+pub fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    config.admin.require_auth();
+
+    if role == Role::Admin {
+        panic_with_error!(&env, CallRegistryExtError::RoleNotGrantable);
+    }
+
+    if remove_role(&env, role, &account) {
+        extend_storage_ttl(&env);
+        emit_role_revoked(&env, role, &account, &config.admin);
+    }
+
+    Ok(())
+}
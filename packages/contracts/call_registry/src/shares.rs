@@ -1,4 +1,4 @@
-use soroban_sdk::{token::StellarAssetClient, Address, Bytes, BytesN, Env};
+use soroban_sdk::{token::StellarAssetClient, Address, Bytes, BytesN, Env, String};
 
 /// Derives a deterministic 32-byte salt for a (call_id, outcome) pair.
 pub fn share_token_salt(env: &Env, call_id: u64, outcome: u32) -> BytesN<32> {
@@ -8,18 +8,70 @@ pub fn share_token_salt(env: &Env, call_id: u64, outcome: u32) -> BytesN<32> {
     env.crypto().sha256(&raw).into()
 }
 
-/// Deploy a SAC token for (call_id, outcome) using the contract's own address
-/// as the admin, returning the new token's Address.
+/// Position token symbol for (call_id, outcome): `CALL42-UP` / `CALL42-DOWN`
+/// for binary calls, `CALL42-O3` for outcome 3 of a multi-outcome call.
+pub fn position_symbol(env: &Env, call_id: u64, outcome: u32, outcome_count: u32) -> String {
+    let mut raw = Bytes::from_slice(env, b"CALL");
+    append_decimal(&mut raw, call_id);
+    match (outcome_count, outcome) {
+        (2, 1) => raw.append(&Bytes::from_slice(env, b"-UP")),
+        (2, 2) => raw.append(&Bytes::from_slice(env, b"-DOWN")),
+        _ => {
+            raw.append(&Bytes::from_slice(env, b"-O"));
+            append_decimal(&mut raw, outcome as u64);
+        }
+    }
+
+    let mut buf = [0u8; 32];
+    let len = raw.len() as usize;
+    raw.copy_into_slice(&mut buf[..len]);
+    String::from_bytes(env, &buf[..len])
+}
+
+/// Append the decimal digits of `value` to `raw`.
+fn append_decimal(raw: &mut Bytes, value: u64) {
+    let mut digits = [0u8; 20];
+    let mut start = digits.len();
+    let mut rest = value;
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (rest % 10) as u8;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    raw.extend_from_slice(&digits[start..]);
+}
+
+/// Deploy the position token for (call_id, outcome) from `share_wasm_hash`
+/// (the `position_token` contract), with the registry as admin and the stake
+/// token's decimals, returning the new token's Address.
 pub fn deploy_share_token(
     env: &Env,
     share_wasm_hash: &BytesN<32>,
     call_id: u64,
     outcome: u32,
+    outcome_count: u32,
+    decimals: u32,
 ) -> Address {
     let salt = share_token_salt(env, call_id, outcome);
-    env.deployer()
-        .with_current_contract(salt)
-        .deploy_v2(share_wasm_hash.clone(), ())
+    let symbol = position_symbol(env, call_id, outcome, outcome_count);
+    let mut name = Bytes::from_slice(env, b"BACKit ");
+    name.append(&symbol.to_bytes());
+    let mut buf = [0u8; 40];
+    let len = name.len() as usize;
+    name.copy_into_slice(&mut buf[..len]);
+
+    env.deployer().with_current_contract(salt).deploy_v2(
+        share_wasm_hash.clone(),
+        (
+            env.current_contract_address(),
+            decimals,
+            String::from_bytes(env, &buf[..len]),
+            symbol,
+        ),
+    )
 }
 
 /// Mint `amount` share tokens to `to`.
@@ -1,13 +1,14 @@
-use crate::types::{Call, ContractConfig, CreatorStats, GlobalStats, StorageStats};
-use soroban_sdk::{contracttype, Address, Bytes, Env};
-
-// ~120 days in ledgers (5s per ledger): 120 * 24 * 3600 / 5 = 2_073_600
-pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = 1_036_800; // ~60 days
-pub const PERSISTENT_BUMP_AMOUNT: u32 = 2_073_600; // ~120 days
-
-// Instance TTL: ~7 days (instance storage is cheaper, refresh frequently)
-const INSTANCE_LIFETIME_THRESHOLD: u32 = 60_480; // ~3.5 days
-const INSTANCE_BUMP_AMOUNT: u32 = 120_960; // ~7 days
+use crate::math;
+use crate::types::{
+    BuildInfo, Call, CallStats, ContractConfig, CreatorStats, EmergencyWithdrawal, FixedOddsBook,
+    FixedOddsTicket, Follow, GlobalStats, LoyaltySnapshot, ParticipationSummary, PredictedAsset,
+    QueuedAction, Role, SemVer, Sponsor, StakeCommit, StakeEntry, StorageStats, Streak,
+    CREATOR_BOND_SLASH_GRACE_SECS, DEFAULT_INSTANCE_TTL_LEDGERS, DEFAULT_PERSISTENT_TTL_LEDGERS,
+    DEFAULT_TEMPORARY_TTL_LEDGERS,
+};
+use soroban_sdk::{
+    contracttype, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
+};
 
 #[contracttype]
 pub enum DataKey {
@@ -25,6 +26,62 @@ pub enum DataKey {
     VoidRefundClaimed(u64, Address),
     InstanceEntryCount,
     Sep10Domain(Address),
+    CallAllowlist(u64, Address),
+    WeightedStake(u64, Address, u32),
+    Follow(Address, Address),
+    CreatorFollowers(Address),
+    CopyExecuted(u64, Address),
+    StakeCommit(u64, Address),
+    HouseLiquidity(Address),
+    FixedOddsBook(u64),
+    FixedOddsTickets(u64, Address),
+    Referrer(Address),
+    ReferredCount(Address),
+    ReferralRewards(Address, Address),
+    Streak(Address),
+    LoyaltyPoints(Address),
+    TotalLoyaltyPoints,
+    LoyaltySnapshotCounter,
+    LoyaltySnapshot(u32),
+    LoyaltySnapshotPoints(u32, Address),
+    Sponsor(Address),
+    SponsoredCount(Address, Address),
+    SponsorDayUsage(Address, u64),
+    StakeEntries(u64, Address),
+    CreatorCalls(Address),
+    ActiveCalls,
+    EndTsBucket(u64),
+    EventSequence,
+    StorageLayout,
+    ContractVersion,
+    MigrationCursor,
+    CallStats(u64),
+    StakerPortfolio(Address),
+    AdminActionCounter,
+    QueuedAction(u64),
+    Roles,
+    PredictedAsset(Bytes),
+}
+
+/// Storage keys added after [`DataKey`] reached the 50-case limit on
+/// contract type enums.
+#[contracttype]
+pub enum DataKeyExt {
+    CreatorBond(u64),
+    InsurancePool(Address),
+    CallRateBucket(Address, u64),
+    Banned(Address),
+    EmergencyWithdrawal(u64),
+    EscrowReleased(u64),
+    FrozenCall(u64),
+    DisputeDeadline(u64),
+    BuildInfo,
+    ProtocolFees(Address),
+    ReputationContract,
+    RewardsContract,
+    HouseReserved(Address),
+    StreakResult(u64, Address),
+    CreatorBondHeldUntil(u64),
 }
 
 /// Store contract configuration
@@ -49,7 +106,7 @@ pub fn next_call_id(env: &Env) -> u64 {
         .get(&DataKey::CallCounter)
         .unwrap_or(0);
 
-    let next_id = counter + 1;
+    let next_id = math::increment(env, counter);
     if counter == 0 {
         // First write of CallCounter key
         inc_instance_entry_count(env, 1);
@@ -61,15 +118,12 @@ pub fn next_call_id(env: &Env) -> u64 {
     next_id
 }
 
-/// Store a call in persistent storage
+/// Store a call in persistent storage under its own TTL. Calls must never
+/// live in instance storage, which is a single size-capped ledger entry.
 pub fn set_call(env: &Env, call: &Call) {
     let key = DataKey::Call(call.id);
     env.storage().persistent().set(&key, call);
-    env.storage().persistent().extend_ttl(
-        &key,
-        PERSISTENT_LIFETIME_THRESHOLD,
-        PERSISTENT_BUMP_AMOUNT,
-    );
+    extend_persistent_ttl(env, &key);
 }
 
 /// Retrieve a call by ID from persistent storage, refreshing its TTL on access
@@ -77,55 +131,197 @@ pub fn get_call(env: &Env, call_id: u64) -> Option<Call> {
     let key = DataKey::Call(call_id);
     let result: Option<Call> = env.storage().persistent().get(&key);
     if result.is_some() {
-        env.storage().persistent().extend_ttl(
-            &key,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
+        extend_persistent_ttl(env, &key);
     }
     result
 }
 
+/// Extend the TTL of a call and of its per-call index entries. Returns
+/// `false` if the call does not exist (or has already been archived).
+pub fn bump_call(env: &Env, call_id: u64) -> bool {
+    let persistent = env.storage().persistent();
+    if !persistent.has(&DataKey::Call(call_id)) {
+        return false;
+    }
+    for key in [
+        DataKey::Call(call_id),
+        DataKey::CallStakers(call_id),
+        DataKey::FixedOddsBook(call_id),
+        DataKey::CallStats(call_id),
+    ] {
+        if persistent.has(&key) {
+            extend_persistent_ttl(env, &key);
+        }
+    }
+    true
+}
+
+/// Store a call's running stake statistics
+pub fn set_call_stats(env: &Env, call_id: u64, stats: &CallStats) {
+    let key = DataKey::CallStats(call_id);
+    env.storage().persistent().set(&key, stats);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a call's running stake statistics, if any stake has been
+/// recorded since the record was introduced
+pub fn get_call_stats(env: &Env, call_id: u64) -> Option<CallStats> {
+    env.storage().persistent().get(&DataKey::CallStats(call_id))
+}
+
 /// Check whether a call exists in persistent storage
 pub fn call_exists(env: &Env, call_id: u64) -> bool {
     env.storage().persistent().has(&DataKey::Call(call_id))
 }
 
-/// Track which calls a staker has participated in
-pub fn add_staker_call(env: &Env, staker: &Address, call_id: u64) {
-    let key = DataKey::StakerCalls(staker.clone());
+/// Append a new call to its creator's index. Call IDs are allocated in
+/// increasing order, so the index stays sorted.
+pub fn add_creator_call(env: &Env, creator: &Address, call_id: u64) {
+    let key = DataKey::CreatorCalls(creator.clone());
+    let mut call_ids = get_creator_calls(env, creator);
+    call_ids.push_back(call_id);
+    env.storage().persistent().set(&key, &call_ids);
+    extend_persistent_ttl(env, &key);
+}
 
-    let mut call_ids: soroban_sdk::Vec<u64> = env
-        .storage()
+/// Retrieve the IDs of the calls a creator has made, oldest first
+pub fn get_creator_calls(env: &Env, creator: &Address) -> Vec<u64> {
+    env.storage()
         .persistent()
-        .get(&key)
-        .unwrap_or_else(|| soroban_sdk::Vec::new(env));
+        .get(&DataKey::CreatorCalls(creator.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
 
-    if !call_ids.iter().any(|id| id == call_id) {
-        call_ids.push_back(call_id);
-        env.storage().persistent().set(&key, &call_ids);
+/// Add a new call to the index of calls still open or awaiting resolution
+pub fn add_active_call(env: &Env, call_id: u64) {
+    let mut call_ids = get_active_calls(env);
+    call_ids.push_back(call_id);
+    set_active_calls(env, &call_ids);
+}
+
+/// Drop a resolved, voided or settled call from the active-calls index
+pub fn remove_active_call(env: &Env, call_id: u64) {
+    let mut call_ids = get_active_calls(env);
+    if let Some(index) = call_ids.first_index_of(call_id) {
+        call_ids.remove(index);
+        set_active_calls(env, &call_ids);
     }
-    env.storage().persistent().extend_ttl(
-        &key,
-        PERSISTENT_LIFETIME_THRESHOLD,
-        PERSISTENT_BUMP_AMOUNT,
-    );
 }
 
-/// Retrieve all call IDs a staker has participated in, refreshing TTL if non-empty
-pub fn get_staker_calls(env: &Env, staker: &Address) -> soroban_sdk::Vec<u64> {
-    let key = DataKey::StakerCalls(staker.clone());
+fn set_active_calls(env: &Env, call_ids: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ActiveCalls, call_ids);
+    extend_persistent_ttl(env, &DataKey::ActiveCalls);
+}
+
+/// Retrieve the IDs of calls not yet resolved, voided or settled, oldest first
+pub fn get_active_calls(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActiveCalls)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Width of an `end_ts` index bucket: one hour
+pub const END_TS_BUCKET_SECS: u64 = 3_600;
+
+/// File a call under the hour bucket of its `end_ts`
+pub fn add_end_ts_bucket_call(env: &Env, end_ts: u64, call_id: u64) {
+    let bucket = end_ts / END_TS_BUCKET_SECS;
+    let mut call_ids = get_end_ts_bucket(env, bucket);
+    call_ids.push_back(call_id);
+    set_end_ts_bucket(env, bucket, &call_ids);
+}
+
+/// Take a call out of the hour bucket of its old `end_ts`
+pub fn remove_end_ts_bucket_call(env: &Env, end_ts: u64, call_id: u64) {
+    let bucket = end_ts / END_TS_BUCKET_SECS;
+    let mut call_ids = get_end_ts_bucket(env, bucket);
+    if let Some(index) = call_ids.first_index_of(call_id) {
+        call_ids.remove(index);
+        set_end_ts_bucket(env, bucket, &call_ids);
+    }
+}
+
+fn set_end_ts_bucket(env: &Env, bucket: u64, call_ids: &Vec<u64>) {
+    let key = DataKey::EndTsBucket(bucket);
+    env.storage().persistent().set(&key, call_ids);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve the IDs of calls whose `end_ts` falls in an hour bucket
+pub fn get_end_ts_bucket(env: &Env, bucket: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EndTsBucket(bucket))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Apply a stake change of `delta` on `position` to the staker's
+/// participation record for a call, creating the record on first stake.
+pub fn update_participation(env: &Env, staker: &Address, call_id: u64, position: u32, delta: i128) {
+    let now = env.ledger().timestamp();
+    let mut portfolio = get_staker_portfolio(env, staker);
+    let mut summary = portfolio
+        .get(call_id)
+        .unwrap_or_else(|| ParticipationSummary {
+            positions: Map::new(env),
+            total_stake: 0,
+            first_stake_ts: now,
+            last_stake_ts: now,
+        });
+    let current = summary.positions.get(position).unwrap_or(0);
+    summary
+        .positions
+        .set(position, math::add(env, current, delta));
+    summary.total_stake = math::add(env, summary.total_stake, delta);
+    if delta > 0 {
+        summary.last_stake_ts = now;
+    }
+    portfolio.set(call_id, summary);
+    set_staker_portfolio(env, staker, &portfolio);
+}
+
+/// Add a participation record built elsewhere, unless the staker already
+/// has one for the call. Used when migrating older layouts.
+pub fn backfill_participation(
+    env: &Env,
+    staker: &Address,
+    call_id: u64,
+    summary: &ParticipationSummary,
+) {
+    let mut portfolio = get_staker_portfolio(env, staker);
+    if !portfolio.contains_key(call_id) {
+        portfolio.set(call_id, summary.clone());
+        set_staker_portfolio(env, staker, &portfolio);
+    }
+}
+
+/// Drop the layout-3 list of a staker's call IDs
+pub fn remove_legacy_staker_calls(env: &Env, staker: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::StakerCalls(staker.clone()));
+}
+
+fn set_staker_portfolio(env: &Env, staker: &Address, portfolio: &Map<u64, ParticipationSummary>) {
+    let key = DataKey::StakerPortfolio(staker.clone());
+    env.storage().persistent().set(&key, portfolio);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a staker's participation records keyed by call ID, refreshing
+/// TTL if non-empty
+pub fn get_staker_portfolio(env: &Env, staker: &Address) -> Map<u64, ParticipationSummary> {
+    let key = DataKey::StakerPortfolio(staker.clone());
     let result = env
         .storage()
         .persistent()
         .get(&key)
-        .unwrap_or_else(|| soroban_sdk::Vec::new(env));
+        .unwrap_or_else(|| Map::new(env));
     if !result.is_empty() {
-        env.storage().persistent().extend_ttl(
-            &key,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
+        extend_persistent_ttl(env, &key);
     }
     result
 }
@@ -143,11 +339,7 @@ pub fn add_call_staker(env: &Env, call_id: u64, staker: &Address) {
         env.storage().persistent().set(&key, &stakers);
     }
 
-    env.storage().persistent().extend_ttl(
-        &key,
-        PERSISTENT_LIFETIME_THRESHOLD,
-        PERSISTENT_BUMP_AMOUNT,
-    );
+    extend_persistent_ttl(env, &key);
 }
 
 pub fn get_call_stakers(env: &Env, call_id: u64) -> soroban_sdk::Vec<Address> {
@@ -158,11 +350,7 @@ pub fn get_call_stakers(env: &Env, call_id: u64) -> soroban_sdk::Vec<Address> {
         .get(&key)
         .unwrap_or_else(|| soroban_sdk::Vec::new(env));
     if !result.is_empty() {
-        env.storage().persistent().extend_ttl(
-            &key,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
+        extend_persistent_ttl(env, &key);
     }
     result
 }
@@ -181,7 +369,7 @@ pub fn get_global_stats(env: &Env) -> GlobalStats {
 pub fn record_call_created(env: &Env) {
     let is_new = !env.storage().instance().has(&DataKey::GlobalStats);
     let mut stats = get_global_stats(env);
-    stats.total_calls += 1;
+    stats.total_calls = math::increment(env, stats.total_calls);
     env.storage().instance().set(&DataKey::GlobalStats, &stats);
     if is_new {
         inc_instance_entry_count(env, 1);
@@ -190,12 +378,12 @@ pub fn record_call_created(env: &Env) {
 
 pub fn record_stake(env: &Env, staker: &Address, amount: i128) {
     let mut stats = get_global_stats(env);
-    stats.total_stake_volume += amount;
+    stats.total_stake_volume = math::add(env, stats.total_stake_volume, amount);
 
     let seen_key = DataKey::GlobalStakerSeen(staker.clone());
     if !env.storage().persistent().has(&seen_key) {
         env.storage().persistent().set(&seen_key, &true);
-        stats.total_unique_stakers += 1;
+        stats.total_unique_stakers = math::increment(env, stats.total_unique_stakers);
     }
 
     env.storage().instance().set(&DataKey::GlobalStats, &stats);
@@ -213,11 +401,7 @@ pub fn get_call_counter(env: &Env) -> u64 {
 pub fn set_user_stake(env: &Env, call_id: u64, staker: &Address, position: u32, amount: i128) {
     let key = DataKey::UserStake(call_id, staker.clone(), position);
     env.storage().persistent().set(&key, &amount);
-    env.storage().persistent().extend_ttl(
-        &key,
-        PERSISTENT_LIFETIME_THRESHOLD,
-        PERSISTENT_BUMP_AMOUNT,
-    );
+    extend_persistent_ttl(env, &key);
 }
 
 /// Retrieve user stake
@@ -225,54 +409,62 @@ pub fn get_user_stake(env: &Env, call_id: u64, staker: &Address, position: u32)
     let key = DataKey::UserStake(call_id, staker.clone(), position);
     let result: Option<i128> = env.storage().persistent().get(&key);
     if result.is_some() {
-        env.storage().persistent().extend_ttl(
-            &key,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
+        extend_persistent_ttl(env, &key);
     }
     result.unwrap_or(0)
 }
 
-/// Get up staker count for a call
-pub fn get_up_staker_count(env: &Env, call_id: u64) -> u32 {
-    let key = DataKey::UpStakerCount(call_id);
-    env.storage().persistent().get(&key).unwrap_or(0)
+/// Store a staker's time-weighted stake on a position
+pub fn set_weighted_stake(env: &Env, call_id: u64, staker: &Address, position: u32, weight: i128) {
+    let key = DataKey::WeightedStake(call_id, staker.clone(), position);
+    env.storage().persistent().set(&key, &weight);
+    extend_persistent_ttl(env, &key);
 }
 
-/// Set up staker count for a call
-pub fn set_up_staker_count(env: &Env, call_id: u64, count: u32) {
-    let key = DataKey::UpStakerCount(call_id);
-    env.storage().persistent().set(&key, &count);
-    env.storage().persistent().extend_ttl(
-        &key,
-        PERSISTENT_LIFETIME_THRESHOLD,
-        PERSISTENT_BUMP_AMOUNT,
-    );
+/// Retrieve a staker's time-weighted stake on a position
+pub fn get_weighted_stake(env: &Env, call_id: u64, staker: &Address, position: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::WeightedStake(call_id, staker.clone(), position))
+        .unwrap_or(0)
 }
 
-/// Get down staker count for a call
-pub fn get_down_staker_count(env: &Env, call_id: u64) -> u32 {
-    let key = DataKey::DownStakerCount(call_id);
-    env.storage().persistent().get(&key).unwrap_or(0)
+/// Store a staker's individual stake entries on a call
+pub fn set_stake_entries(env: &Env, call_id: u64, staker: &Address, entries: &Vec<StakeEntry>) {
+    let key = DataKey::StakeEntries(call_id, staker.clone());
+    env.storage().persistent().set(&key, entries);
+    extend_persistent_ttl(env, &key);
 }
 
-/// Set down staker count for a call
-pub fn set_down_staker_count(env: &Env, call_id: u64, count: u32) {
-    let key = DataKey::DownStakerCount(call_id);
-    env.storage().persistent().set(&key, &count);
-    env.storage().persistent().extend_ttl(
-        &key,
-        PERSISTENT_LIFETIME_THRESHOLD,
-        PERSISTENT_BUMP_AMOUNT,
-    );
+/// Retrieve a staker's individual stake entries on a call, oldest first
+pub fn get_stake_entries(env: &Env, call_id: u64, staker: &Address) -> Vec<StakeEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakeEntries(call_id, staker.clone()))
+        .unwrap_or_else(|| Vec::new(env))
 }
 
 /// Extend contract storage lifetime (for long-term persistence)
 pub fn extend_storage_ttl(env: &Env) {
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    let ttl = get_config(env)
+        .map(|config| config.instance_ttl_ledgers)
+        .unwrap_or(DEFAULT_INSTANCE_TTL_LEDGERS);
+    env.storage().instance().extend_ttl(ttl / 2, ttl);
+}
+
+/// Refresh a persistent entry to the configured lifetime once less than
+/// half of it remains
+pub fn extend_persistent_ttl<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    let ttl = get_config(env)
+        .map(|config| config.persistent_ttl_ledgers)
+        .unwrap_or(DEFAULT_PERSISTENT_TTL_LEDGERS);
+    env.storage().persistent().extend_ttl(key, ttl / 2, ttl);
+}
+
+fn temporary_ttl(env: &Env) -> u32 {
+    get_config(env)
+        .map(|config| config.temporary_ttl_ledgers)
+        .unwrap_or(DEFAULT_TEMPORARY_TTL_LEDGERS)
 }
 
 /// Get creator reputation stats, initializing if not found
@@ -292,28 +484,275 @@ pub fn get_creator_stats(env: &Env, creator: &Address) -> CreatorStats {
 pub fn set_creator_stats(env: &Env, creator: &Address, stats: &CreatorStats) {
     let key = DataKey::CreatorStats(creator.clone());
     env.storage().persistent().set(&key, stats);
-    env.storage().persistent().extend_ttl(
-        &key,
-        PERSISTENT_LIFETIME_THRESHOLD,
-        PERSISTENT_BUMP_AMOUNT,
-    );
+    extend_persistent_ttl(env, &key);
 }
 
-/// Mark that a staker has claimed their void refund for a call
+/// Mark that a staker has claimed their void refund for a call. Kept in
+/// persistent storage: one entry per staker must not grow the instance entry.
 pub fn set_void_refund_claimed(env: &Env, call_id: u64, staker: &Address) {
     let key = DataKey::VoidRefundClaimed(call_id, staker.clone());
-    let is_new = !env.storage().instance().has(&key);
-    env.storage().instance().set(&key, &true);
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Check whether a staker has already claimed their void refund
+pub fn is_void_refund_claimed(env: &Env, call_id: u64, staker: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::VoidRefundClaimed(call_id, staker.clone()))
+}
+
+/// Increment and return the global event sequence number
+pub fn next_event_sequence(env: &Env) -> u64 {
+    let seq = get_event_sequence(env) + 1;
+    env.storage().instance().set(&DataKey::EventSequence, &seq);
+    if seq == 1 {
+        inc_instance_entry_count(env, 1);
+    }
+    seq
+}
+
+/// Sequence number of the most recently emitted event; `0` = none yet
+pub fn get_event_sequence(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EventSequence)
+        .unwrap_or(0)
+}
+
+// ── Storage layout ────────────────────────────────────────────────────────────
+
+/// Current storage layout. Layout 1 kept the contract version under the
+/// string key `"version"` and void-refund flags in instance storage, and
+/// had no creator, active-call or `end_ts` indexes. Layout 2 had no per-call
+/// stats records. Layout 3 listed each staker's call IDs in a `Vec` under
+/// `StakerCalls` instead of keeping participation records. Layout 4 stored
+/// the contract version as a plain upgrade counter.
+pub const STORAGE_LAYOUT_VERSION: u32 = 6;
+
+/// Layout of the data this instance holds. Instances initialised before
+/// layouts were tracked have no entry and report layout 1.
+pub fn get_storage_layout(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StorageLayout)
+        .unwrap_or(1)
+}
+
+/// Record the layout of the data this instance holds
+pub fn set_storage_layout(env: &Env, layout: u32) {
+    let is_new = !env.storage().instance().has(&DataKey::StorageLayout);
+    env.storage()
+        .instance()
+        .set(&DataKey::StorageLayout, &layout);
     if is_new {
         inc_instance_entry_count(env, 1);
     }
 }
 
-/// Check whether a staker has already claimed their void refund
-pub fn is_void_refund_claimed(env: &Env, call_id: u64, staker: &Address) -> bool {
+fn legacy_version_key(env: &Env) -> Symbol {
+    Symbol::new(env, "version")
+}
+
+/// Read a stored contract version. Layouts 4 and earlier stored a plain
+/// upgrade counter `n`, which reads as `n.0.0`.
+fn read_contract_version(env: &Env, val: Val) -> SemVer {
+    SemVer::try_from_val(env, &val).unwrap_or_else(|_| SemVer {
+        major: u32::try_from_val(env, &val).unwrap_or(0),
+        minor: 0,
+        patch: 0,
+    })
+}
+
+/// Stored contract version, falling back to the layout-1 string key
+pub fn get_contract_version(env: &Env) -> Option<SemVer> {
+    let instance = env.storage().instance();
+    instance
+        .get::<_, Val>(&DataKey::ContractVersion)
+        .or_else(|| instance.get(&legacy_version_key(env)))
+        .map(|val| read_contract_version(env, val))
+}
+
+/// Store the contract version
+pub fn set_contract_version(env: &Env, version: &SemVer) {
+    let is_new = get_contract_version(env).is_none();
     env.storage()
         .instance()
-        .has(&DataKey::VoidRefundClaimed(call_id, staker.clone()))
+        .set(&DataKey::ContractVersion, version);
+    if is_new {
+        inc_instance_entry_count(env, 1);
+    }
+}
+
+/// Build installed on this instance; `None` on instances deployed before
+/// builds were recorded
+pub fn get_build_info(env: &Env) -> Option<BuildInfo> {
+    env.storage().instance().get(&DataKeyExt::BuildInfo)
+}
+
+/// Get the reputation contract notified of calls and positions, if any
+pub fn get_reputation_contract(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKeyExt::ReputationContract)
+}
+
+/// Set or clear the reputation contract
+pub fn set_reputation_contract(env: &Env, contract: &Option<Address>) {
+    let had = env
+        .storage()
+        .instance()
+        .has(&DataKeyExt::ReputationContract);
+    match contract {
+        Some(contract) => {
+            env.storage()
+                .instance()
+                .set(&DataKeyExt::ReputationContract, contract);
+            if !had {
+                inc_instance_entry_count(env, 1);
+            }
+        }
+        None => {
+            env.storage()
+                .instance()
+                .remove(&DataKeyExt::ReputationContract);
+            if had {
+                dec_instance_entry_count(env, 1);
+            }
+        }
+    }
+}
+
+/// Get the rewards contract credited with settled positions' stake time, if any
+pub fn get_rewards_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKeyExt::RewardsContract)
+}
+
+/// Set or clear the rewards contract
+pub fn set_rewards_contract(env: &Env, contract: &Option<Address>) {
+    let had = env.storage().instance().has(&DataKeyExt::RewardsContract);
+    match contract {
+        Some(contract) => {
+            env.storage()
+                .instance()
+                .set(&DataKeyExt::RewardsContract, contract);
+            if !had {
+                inc_instance_entry_count(env, 1);
+            }
+        }
+        None => {
+            env.storage()
+                .instance()
+                .remove(&DataKeyExt::RewardsContract);
+            if had {
+                dec_instance_entry_count(env, 1);
+            }
+        }
+    }
+}
+
+/// Record the build installed at construction or upgrade
+pub fn set_build_info(env: &Env, build_id: Option<BytesN<32>>) {
+    let is_new = get_build_info(env).is_none();
+    let info = BuildInfo {
+        build_id,
+        installed_ledger: env.ledger().sequence(),
+    };
+    env.storage().instance().set(&DataKeyExt::BuildInfo, &info);
+    if is_new {
+        inc_instance_entry_count(env, 1);
+    }
+}
+
+/// Move the contract version off the layout-1 string key and store it as a
+/// semantic version
+pub fn migrate_contract_version(env: &Env) {
+    if let Some(version) = get_contract_version(env) {
+        env.storage().instance().remove(&legacy_version_key(env));
+        env.storage()
+            .instance()
+            .set(&DataKey::ContractVersion, &version);
+    }
+}
+
+/// Move a layout-1 void-refund flag from instance to persistent storage.
+/// Returns `true` when a flag was moved.
+pub fn migrate_void_refund_claimed(env: &Env, call_id: u64, staker: &Address) -> bool {
+    let key = DataKey::VoidRefundClaimed(call_id, staker.clone());
+    if !env.storage().instance().has(&key) {
+        return false;
+    }
+    env.storage().instance().remove(&key);
+    dec_instance_entry_count(env, 1);
+    set_void_refund_claimed(env, call_id, staker);
+    true
+}
+
+/// Insert `call_id` into a sorted index unless it is already there.
+/// Returns `false` when the index already held it.
+fn insert_sorted(call_ids: &mut Vec<u64>, call_id: u64) -> bool {
+    match call_ids.binary_search(call_id) {
+        Ok(_) => false,
+        Err(index) => {
+            call_ids.insert(index, call_id);
+            true
+        }
+    }
+}
+
+/// Add a layout-1 call to its creator's index, keeping the index sorted.
+/// Calls created since the upgrade are already indexed and are skipped.
+pub fn backfill_creator_call(env: &Env, creator: &Address, call_id: u64) {
+    let mut call_ids = get_creator_calls(env, creator);
+    if insert_sorted(&mut call_ids, call_id) {
+        let key = DataKey::CreatorCalls(creator.clone());
+        env.storage().persistent().set(&key, &call_ids);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Add a layout-1 call to the active-calls index, keeping it sorted
+pub fn backfill_active_call(env: &Env, call_id: u64) {
+    let mut call_ids = get_active_calls(env);
+    if insert_sorted(&mut call_ids, call_id) {
+        set_active_calls(env, &call_ids);
+    }
+}
+
+/// File a layout-1 call under the hour bucket of its `end_ts`
+pub fn backfill_end_ts_bucket_call(env: &Env, end_ts: u64, call_id: u64) {
+    let bucket = end_ts / END_TS_BUCKET_SECS;
+    let mut call_ids = get_end_ts_bucket(env, bucket);
+    if insert_sorted(&mut call_ids, call_id) {
+        set_end_ts_bucket(env, bucket, &call_ids);
+    }
+}
+
+/// Next call ID a batched migration will process; `1` = not started
+pub fn get_migration_cursor(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(1)
+}
+
+/// Store the next call ID a batched migration will process
+pub fn set_migration_cursor(env: &Env, call_id: u64) {
+    let is_new = !env.storage().instance().has(&DataKey::MigrationCursor);
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationCursor, &call_id);
+    if is_new {
+        inc_instance_entry_count(env, 1);
+    }
+}
+
+/// Drop the migration cursor once a migration completes
+pub fn clear_migration_cursor(env: &Env) {
+    if env.storage().instance().has(&DataKey::MigrationCursor) {
+        env.storage().instance().remove(&DataKey::MigrationCursor);
+        dec_instance_entry_count(env, 1);
+    }
 }
 
 // ── Instance entry counter ────────────────────────────────────────────────────
@@ -330,6 +769,14 @@ pub fn inc_instance_entry_count(env: &Env, delta: u32) {
         .set(&DataKey::InstanceEntryCount, &(current + delta));
 }
 
+/// Decrement the instance entry counter by `delta` (call when removing instance keys).
+pub fn dec_instance_entry_count(env: &Env, delta: u32) {
+    let current = get_instance_entry_count(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::InstanceEntryCount, &current.saturating_sub(delta));
+}
+
 /// Return the number of tracked instance storage entries.
 pub fn get_instance_entry_count(env: &Env) -> u32 {
     env.storage()
@@ -348,15 +795,585 @@ pub fn get_storage_stats(env: &Env) -> StorageStats {
     }
 }
 
+/// Add or remove `staker` from a private call's allowlist.
+pub fn set_call_allowlisted(env: &Env, call_id: u64, staker: &Address, allowed: bool) {
+    let key = DataKey::CallAllowlist(call_id, staker.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent_ttl(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether `staker` is on a private call's allowlist.
+pub fn is_call_allowlisted(env: &Env, call_id: u64, staker: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CallAllowlist(call_id, staker.clone()))
+}
+
+/// Store a follower's copy-stake order for a creator
+pub fn set_follow(env: &Env, follow: &Follow) {
+    let key = DataKey::Follow(follow.follower.clone(), follow.creator.clone());
+    env.storage().persistent().set(&key, follow);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a follower's copy-stake order for a creator
+pub fn get_follow(env: &Env, follower: &Address, creator: &Address) -> Option<Follow> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Follow(follower.clone(), creator.clone()))
+}
+
+/// Delete a follower's copy-stake order and drop them from the creator's list
+pub fn remove_follow(env: &Env, follower: &Address, creator: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Follow(follower.clone(), creator.clone()));
+
+    let key = DataKey::CreatorFollowers(creator.clone());
+    let mut followers = get_creator_followers(env, creator);
+    if let Some(index) = followers.first_index_of(follower) {
+        followers.remove(index);
+        env.storage().persistent().set(&key, &followers);
+    }
+}
+
+/// Append a follower to a creator's follower list
+pub fn add_creator_follower(env: &Env, creator: &Address, follower: &Address) {
+    let key = DataKey::CreatorFollowers(creator.clone());
+    let mut followers = get_creator_followers(env, creator);
+    followers.push_back(follower.clone());
+    env.storage().persistent().set(&key, &followers);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve the addresses following a creator, in follow order
+pub fn get_creator_followers(env: &Env, creator: &Address) -> soroban_sdk::Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorFollowers(creator.clone()))
+        .unwrap_or_else(|| soroban_sdk::Vec::new(env))
+}
+
+/// Mark that a follower's copy stake on a call has been executed
+pub fn set_copy_executed(env: &Env, call_id: u64, follower: &Address) {
+    let key = DataKey::CopyExecuted(call_id, follower.clone());
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Check whether a follower's copy stake on a call has been executed
+pub fn is_copy_executed(env: &Env, call_id: u64, follower: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CopyExecuted(call_id, follower.clone()))
+}
+
+/// Store a staker's unrevealed commit on a call
+pub fn set_stake_commit(env: &Env, call_id: u64, staker: &Address, commit: &StakeCommit) {
+    let key = DataKey::StakeCommit(call_id, staker.clone());
+    env.storage().persistent().set(&key, commit);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a staker's unrevealed commit on a call
+pub fn get_stake_commit(env: &Env, call_id: u64, staker: &Address) -> Option<StakeCommit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakeCommit(call_id, staker.clone()))
+}
+
+/// Delete a staker's commit once revealed or refunded
+pub fn remove_stake_commit(env: &Env, call_id: u64, staker: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::StakeCommit(call_id, staker.clone()));
+}
+
+/// Store the house's free (unreserved) liquidity in a token
+pub fn set_house_liquidity(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::HouseLiquidity(token.clone());
+    env.storage().persistent().set(&key, &amount);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve the house's free (unreserved) liquidity in a token
+pub fn get_house_liquidity(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HouseLiquidity(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Add `delta` (possibly negative) to the house liquidity reserved across
+/// open fixed-odds books in a token, flooring at zero
+pub fn add_house_reserved(env: &Env, token: &Address, delta: i128) {
+    let key = DataKeyExt::HouseReserved(token.clone());
+    let reserved = (get_house_reserved(env, token) + delta).max(0);
+    env.storage().persistent().set(&key, &reserved);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve the house liquidity reserved across open fixed-odds books in a
+/// token
+pub fn get_house_reserved(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKeyExt::HouseReserved(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Store the creator bond escrowed for a call
+pub fn set_creator_bond(env: &Env, call_id: u64, amount: i128) {
+    let key = DataKeyExt::CreatorBond(call_id);
+    env.storage().persistent().set(&key, &amount);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve the creator bond still escrowed for a call
+pub fn get_creator_bond(env: &Env, call_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKeyExt::CreatorBond(call_id))
+        .unwrap_or(0)
+}
+
+/// Drop a call's creator bond once it is refunded or slashed
+pub fn remove_creator_bond(env: &Env, call_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKeyExt::CreatorBond(call_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKeyExt::CreatorBondHeldUntil(call_id));
+}
+
+/// Keep a just-voided call's creator bond slashable for
+/// `CREATOR_BOND_SLASH_GRACE_SECS`, if one is escrowed
+pub fn hold_creator_bond(env: &Env, call_id: u64) {
+    if get_creator_bond(env, call_id) > 0 {
+        let key = DataKeyExt::CreatorBondHeldUntil(call_id);
+        let until = env
+            .ledger()
+            .timestamp()
+            .saturating_add(CREATOR_BOND_SLASH_GRACE_SECS);
+        env.storage().persistent().set(&key, &until);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Retrieve when a voided call's creator bond stops being slashable, if it
+/// was held when the call was voided
+pub fn get_creator_bond_held_until(env: &Env, call_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKeyExt::CreatorBondHeldUntil(call_id))
+}
+
+/// Add slashed or expired funds to the insurance pool for a token
+pub fn add_insurance_pool(env: &Env, token: &Address, amount: i128) {
+    let key = DataKeyExt::InsurancePool(token.clone());
+    let balance = math::add(env, get_insurance_pool(env, token), amount);
+    env.storage().persistent().set(&key, &balance);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve the insurance pool balance for a token
+pub fn get_insurance_pool(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKeyExt::InsurancePool(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Store how many calls a creator opened in the rate bucket starting at
+/// `bucket_start`
+pub fn set_call_rate_count(env: &Env, creator: &Address, bucket_start: u64, count: u32) {
+    let key = DataKeyExt::CallRateBucket(creator.clone(), bucket_start);
+    env.storage().temporary().set(&key, &count);
+    let ttl = temporary_ttl(env);
+    env.storage().temporary().extend_ttl(&key, ttl / 2, ttl);
+}
+
+/// Number of calls a creator opened in the rate bucket starting at
+/// `bucket_start`
+pub fn get_call_rate_count(env: &Env, creator: &Address, bucket_start: u64) -> u32 {
+    env.storage()
+        .temporary()
+        .get(&DataKeyExt::CallRateBucket(creator.clone(), bucket_start))
+        .unwrap_or(0)
+}
+
+/// Ban an address from creating calls and staking
+pub fn set_banned(env: &Env, account: &Address) {
+    let key = DataKeyExt::Banned(account.clone());
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Lift an address's ban
+pub fn remove_banned(env: &Env, account: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKeyExt::Banned(account.clone()));
+}
+
+/// Whether an address is banned
+pub fn is_banned(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKeyExt::Banned(account.clone()))
+}
+
+/// Store a call's emergency withdrawal
+pub fn set_emergency_withdrawal(env: &Env, withdrawal: &EmergencyWithdrawal) {
+    let key = DataKeyExt::EmergencyWithdrawal(withdrawal.call_id);
+    env.storage().persistent().set(&key, withdrawal);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a call's emergency withdrawal, if one was initiated
+pub fn get_emergency_withdrawal(env: &Env, call_id: u64) -> Option<EmergencyWithdrawal> {
+    env.storage()
+        .persistent()
+        .get(&DataKeyExt::EmergencyWithdrawal(call_id))
+}
+
+/// Drop a cancelled emergency withdrawal
+pub fn remove_emergency_withdrawal(env: &Env, call_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKeyExt::EmergencyWithdrawal(call_id));
+}
+
+/// Add to the amount paid out of a call's escrow
+pub fn add_escrow_released(env: &Env, call_id: u64, amount: i128) {
+    let key = DataKeyExt::EscrowReleased(call_id);
+    let released = math::add(env, get_escrow_released(env, call_id), amount);
+    env.storage().persistent().set(&key, &released);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Total paid out of a call's escrow by claims, refunds and fees
+pub fn get_escrow_released(env: &Env, call_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKeyExt::EscrowReleased(call_id))
+        .unwrap_or(0)
+}
+
+/// Add to the protocol fees paid to the fee collector in a token
+pub fn add_protocol_fees(env: &Env, token: &Address, amount: i128) {
+    let key = DataKeyExt::ProtocolFees(token.clone());
+    let collected = math::add(env, get_protocol_fees(env, token), amount);
+    env.storage().persistent().set(&key, &collected);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Total protocol fees paid to the fee collector in a token
+pub fn get_protocol_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKeyExt::ProtocolFees(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Suspend staking on a call
+pub fn set_call_frozen(env: &Env, call_id: u64) {
+    let key = DataKeyExt::FrozenCall(call_id);
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Resume staking on a frozen call
+pub fn remove_call_frozen(env: &Env, call_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKeyExt::FrozenCall(call_id));
+}
+
+/// Whether staking on a call is suspended
+pub fn is_call_frozen(env: &Env, call_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKeyExt::FrozenCall(call_id))
+}
+
+/// Store when a resolved call's dispute window closes
+pub fn set_dispute_deadline(env: &Env, call_id: u64, deadline: u64) {
+    let key = DataKeyExt::DisputeDeadline(call_id);
+    env.storage().persistent().set(&key, &deadline);
+    extend_persistent_ttl(env, &key);
+}
+
+/// When a resolved call's dispute window closes, if it had one
+pub fn get_dispute_deadline(env: &Env, call_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKeyExt::DisputeDeadline(call_id))
+}
+
+/// Store the house's fixed-odds book for a call
+pub fn set_fixed_odds_book(env: &Env, call_id: u64, book: &FixedOddsBook) {
+    let key = DataKey::FixedOddsBook(call_id);
+    env.storage().persistent().set(&key, book);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve the house's fixed-odds book for a call
+pub fn get_fixed_odds_book(env: &Env, call_id: u64) -> Option<FixedOddsBook> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FixedOddsBook(call_id))
+}
+
+/// Store a staker's fixed-odds tickets on a call
+pub fn set_fixed_odds_tickets(
+    env: &Env,
+    call_id: u64,
+    staker: &Address,
+    tickets: &soroban_sdk::Vec<FixedOddsTicket>,
+) {
+    let key = DataKey::FixedOddsTickets(call_id, staker.clone());
+    env.storage().persistent().set(&key, tickets);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a staker's fixed-odds tickets on a call
+pub fn get_fixed_odds_tickets(
+    env: &Env,
+    call_id: u64,
+    staker: &Address,
+) -> soroban_sdk::Vec<FixedOddsTicket> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FixedOddsTickets(call_id, staker.clone()))
+        .unwrap_or_else(|| soroban_sdk::Vec::new(env))
+}
+
+/// Delete a staker's fixed-odds tickets once claimed
+pub fn remove_fixed_odds_tickets(env: &Env, call_id: u64, staker: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::FixedOddsTickets(call_id, staker.clone()));
+}
+
+/// Bind a staker to the referrer who brought them in
+pub fn set_referrer(env: &Env, staker: &Address, referrer: &Address) {
+    let key = DataKey::Referrer(staker.clone());
+    env.storage().persistent().set(&key, referrer);
+    extend_persistent_ttl(env, &key);
+
+    let count_key = DataKey::ReferredCount(referrer.clone());
+    let count = get_referred_count(env, referrer) + 1;
+    env.storage().persistent().set(&count_key, &count);
+    extend_persistent_ttl(env, &count_key);
+}
+
+/// Retrieve a staker's referrer
+pub fn get_referrer(env: &Env, staker: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referrer(staker.clone()))
+}
+
+/// Number of stakers bound to a referrer
+pub fn get_referred_count(env: &Env, referrer: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferredCount(referrer.clone()))
+        .unwrap_or(0)
+}
+
+/// Store a referrer's unclaimed rewards in a token
+pub fn set_referral_rewards(env: &Env, referrer: &Address, token: &Address, amount: i128) {
+    let key = DataKey::ReferralRewards(referrer.clone(), token.clone());
+    env.storage().persistent().set(&key, &amount);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a referrer's unclaimed rewards in a token
+pub fn get_referral_rewards(env: &Env, referrer: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralRewards(referrer.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Store a staker's win streak
+pub fn set_streak(env: &Env, staker: &Address, streak: &Streak) {
+    let key = DataKey::Streak(staker.clone());
+    env.storage().persistent().set(&key, streak);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a staker's win streak, empty if none is recorded
+pub fn get_streak(env: &Env, staker: &Address) -> Streak {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Streak(staker.clone()))
+        .unwrap_or_default()
+}
+
+/// Mark `staker`'s result on `call_id` as recorded on their streak. Returns
+/// `false` if it already was.
+pub fn mark_streak_result(env: &Env, call_id: u64, staker: &Address) -> bool {
+    let key = DataKeyExt::StreakResult(call_id, staker.clone());
+    if env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+    true
+}
+
+/// Add loyalty points to an address and the global total. Returns the new balance.
+pub fn add_loyalty_points(env: &Env, holder: &Address, points: i128) -> i128 {
+    let key = DataKey::LoyaltyPoints(holder.clone());
+    let balance = get_loyalty_points(env, holder) + points;
+    env.storage().persistent().set(&key, &balance);
+    extend_persistent_ttl(env, &key);
+
+    let is_new = !env.storage().instance().has(&DataKey::TotalLoyaltyPoints);
+    let total = get_total_loyalty_points(env) + points;
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalLoyaltyPoints, &total);
+    if is_new {
+        inc_instance_entry_count(env, 1);
+    }
+    balance
+}
+
+/// Retrieve an address's loyalty points
+pub fn get_loyalty_points(env: &Env, holder: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LoyaltyPoints(holder.clone()))
+        .unwrap_or(0)
+}
+
+/// Loyalty points issued across all addresses
+pub fn get_total_loyalty_points(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalLoyaltyPoints)
+        .unwrap_or(0)
+}
+
+/// Increment and return the loyalty snapshot counter
+pub fn next_loyalty_snapshot_id(env: &Env) -> u32 {
+    let id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::LoyaltySnapshotCounter)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::LoyaltySnapshotCounter, &id);
+    if id == 1 {
+        inc_instance_entry_count(env, 1);
+    }
+    id
+}
+
+/// Store a loyalty snapshot record
+pub fn set_loyalty_snapshot(env: &Env, snapshot: &LoyaltySnapshot) {
+    let key = DataKey::LoyaltySnapshot(snapshot.id);
+    env.storage().persistent().set(&key, snapshot);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a loyalty snapshot record
+pub fn get_loyalty_snapshot(env: &Env, id: u32) -> Option<LoyaltySnapshot> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LoyaltySnapshot(id))
+}
+
+/// Record an address's points balance in a snapshot
+pub fn set_loyalty_snapshot_points(env: &Env, id: u32, holder: &Address, points: i128) {
+    let key = DataKey::LoyaltySnapshotPoints(id, holder.clone());
+    env.storage().persistent().set(&key, &points);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve an address's points balance in a snapshot
+pub fn get_loyalty_snapshot_points(env: &Env, id: u32, holder: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LoyaltySnapshotPoints(id, holder.clone()))
+        .unwrap_or(0)
+}
+
+/// Whether an address has ever staked on any call
+pub fn is_known_staker(env: &Env, staker: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::GlobalStakerSeen(staker.clone()))
+}
+
+/// Store a paymaster's sponsorship caps
+pub fn set_sponsor(env: &Env, sponsor: &Sponsor) {
+    let key = DataKey::Sponsor(sponsor.sponsor.clone());
+    env.storage().persistent().set(&key, sponsor);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a paymaster's sponsorship caps
+pub fn get_sponsor(env: &Env, sponsor: &Address) -> Option<Sponsor> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Sponsor(sponsor.clone()))
+}
+
+/// Deregister a paymaster
+pub fn remove_sponsor(env: &Env, sponsor: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Sponsor(sponsor.clone()));
+}
+
+/// Store how many stakes a sponsor has covered for a staker
+pub fn set_sponsored_count(env: &Env, sponsor: &Address, staker: &Address, count: u32) {
+    let key = DataKey::SponsoredCount(sponsor.clone(), staker.clone());
+    env.storage().persistent().set(&key, &count);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Number of stakes a sponsor has covered for a staker
+pub fn get_sponsored_count(env: &Env, sponsor: &Address, staker: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SponsoredCount(sponsor.clone(), staker.clone()))
+        .unwrap_or(0)
+}
+
+/// Store how many stakes a sponsor has covered on a day
+pub fn set_sponsor_day_usage(env: &Env, sponsor: &Address, day: u64, count: u32) {
+    let key = DataKey::SponsorDayUsage(sponsor.clone(), day);
+    env.storage().temporary().set(&key, &count);
+    let ttl = temporary_ttl(env);
+    env.storage().temporary().extend_ttl(&key, ttl / 2, ttl);
+}
+
+/// Number of stakes a sponsor has covered on a day
+pub fn get_sponsor_day_usage(env: &Env, sponsor: &Address, day: u64) -> u32 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::SponsorDayUsage(sponsor.clone(), day))
+        .unwrap_or(0)
+}
+
 /// Persist a verified SEP-10 home_domain for a user.
 pub fn set_sep10_domain(env: &Env, user: &Address, domain: &Bytes) {
     let key = DataKey::Sep10Domain(user.clone());
     env.storage().persistent().set(&key, domain);
-    env.storage().persistent().extend_ttl(
-        &key,
-        PERSISTENT_LIFETIME_THRESHOLD,
-        PERSISTENT_BUMP_AMOUNT,
-    );
+    extend_persistent_ttl(env, &key);
 }
 
 /// Retrieve a user's verified SEP-10 home_domain, if any.
@@ -364,3 +1381,137 @@ pub fn get_sep10_domain(env: &Env, user: &Address) -> Option<Bytes> {
     let key = DataKey::Sep10Domain(user.clone());
     env.storage().persistent().get(&key)
 }
+
+/// Get the next timelock action ID and increment the counter
+pub fn next_admin_action_id(env: &Env) -> u64 {
+    let counter: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::AdminActionCounter)
+        .unwrap_or(0);
+    if counter == 0 {
+        inc_instance_entry_count(env, 1);
+    }
+
+    let next_id = math::increment(env, counter);
+    env.storage()
+        .instance()
+        .set(&DataKey::AdminActionCounter, &next_id);
+    next_id
+}
+
+/// Store a queued admin action
+pub fn set_queued_action(env: &Env, queued: &QueuedAction) {
+    let key = DataKey::QueuedAction(queued.id);
+    env.storage().persistent().set(&key, queued);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve a queued admin action
+pub fn get_queued_action(env: &Env, id: u64) -> Option<QueuedAction> {
+    env.storage().persistent().get(&DataKey::QueuedAction(id))
+}
+
+/// Drop an executed or cancelled admin action from the queue
+pub fn remove_queued_action(env: &Env, id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QueuedAction(id));
+}
+
+/// Role assignments, keyed by account
+fn get_roles(env: &Env) -> Option<Map<Address, Vec<Role>>> {
+    env.storage().instance().get(&DataKey::Roles)
+}
+
+fn set_roles(env: &Env, roles: &Map<Address, Vec<Role>>) {
+    let is_new = !env.storage().instance().has(&DataKey::Roles);
+    env.storage().instance().set(&DataKey::Roles, roles);
+    if is_new {
+        inc_instance_entry_count(env, 1);
+    }
+}
+
+/// Whether `account` holds `role`
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    get_roles(env)
+        .and_then(|roles| roles.get(account.clone()))
+        .is_some_and(|held| held.contains(role))
+}
+
+/// Give `account` a role. Returns `false` if it already held it.
+pub fn add_role(env: &Env, role: Role, account: &Address) -> bool {
+    let mut roles = get_roles(env).unwrap_or_else(|| Map::new(env));
+    let mut held = roles.get(account.clone()).unwrap_or_else(|| Vec::new(env));
+    if held.contains(role) {
+        return false;
+    }
+    held.push_back(role);
+    roles.set(account.clone(), held);
+    set_roles(env, &roles);
+    true
+}
+
+/// Take a role away from `account`. Returns `false` if it did not hold it.
+pub fn remove_role(env: &Env, role: Role, account: &Address) -> bool {
+    let Some(mut roles) = get_roles(env) else {
+        return false;
+    };
+    let Some(mut held) = roles.get(account.clone()) else {
+        return false;
+    };
+    let Some(index) = held.first_index_of(role) else {
+        return false;
+    };
+    held.remove(index);
+    if held.is_empty() {
+        roles.remove(account.clone());
+    } else {
+        roles.set(account.clone(), held);
+    }
+    set_roles(env, &roles);
+    true
+}
+
+/// Grant every role to the admin of an instance created before roles
+/// existed
+pub fn migrate_roles(env: &Env, admin: &Address) {
+    if get_roles(env).is_none() {
+        for role in ALL_ROLES {
+            add_role(env, role, admin);
+        }
+    }
+}
+
+/// Every role, in declaration order
+pub const ALL_ROLES: [Role; 5] = [
+    Role::Admin,
+    Role::Pauser,
+    Role::Resolver,
+    Role::TreasuryManager,
+    Role::FeeSetter,
+];
+
+/// Add or replace a predicted asset
+pub fn set_predicted_asset(env: &Env, asset: &PredictedAsset) {
+    let key = DataKey::PredictedAsset(asset.pair_id.clone());
+    env.storage().persistent().set(&key, asset);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Retrieve the predicted asset registered for `pair_id`, keeping it alive
+pub fn get_predicted_asset(env: &Env, pair_id: &Bytes) -> Option<PredictedAsset> {
+    let key = DataKey::PredictedAsset(pair_id.clone());
+    let asset = env.storage().persistent().get(&key);
+    if asset.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    asset
+}
+
+/// Drop a predicted asset from the registry
+pub fn remove_predicted_asset(env: &Env, pair_id: &Bytes) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PredictedAsset(pair_id.clone()));
+}
@@ -1,6 +1,7 @@
 #![cfg(test)]
 #![allow(deprecated)]
 #![allow(unused)]
+#![allow(clippy::too_many_arguments)]
 
 extern crate std;
 
@@ -22,12 +23,135 @@ impl MockToken {
     pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
 }
 
+// ── Mock reputation contract ──────────────────────────────────────────────────
+
+/// Stores the arguments of the latest report of each kind.
+#[contract]
+pub struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn record_call_created(
+        env: Env,
+        reporter: Address,
+        call_id: u64,
+        creator: Address,
+    ) -> bool {
+        let key = Symbol::new(&env, "created");
+        env.storage()
+            .instance()
+            .set(&key, &(reporter, call_id, creator));
+        true
+    }
+
+    pub fn record_call_resolved(
+        env: Env,
+        _reporter: Address,
+        call_id: u64,
+        _creator: Address,
+        correct: bool,
+    ) -> bool {
+        let key = Symbol::new(&env, "resolved");
+        env.storage().instance().set(&key, &(call_id, correct));
+        true
+    }
+
+    pub fn record_position(
+        env: Env,
+        _reporter: Address,
+        call_id: u64,
+        staker: Address,
+        volume: i128,
+        won: bool,
+        _in_order: bool,
+    ) -> bool {
+        let key = Symbol::new(&env, "position");
+        env.storage()
+            .instance()
+            .set(&key, &(call_id, staker, volume, won));
+        true
+    }
+}
+
+// ── Mock rewards contract ─────────────────────────────────────────────────────
+
+/// Stores each staker's latest stake-time report.
+#[contract]
+pub struct MockRewards;
+
+#[contractimpl]
+impl MockRewards {
+    pub fn record_stake_time(
+        env: Env,
+        reporter: Address,
+        call_id: u64,
+        staker: Address,
+        stake_time: i128,
+    ) -> bool {
+        env.storage()
+            .instance()
+            .set(&staker, &(reporter, call_id, stake_time));
+        true
+    }
+}
+
+// ── Predicted assets ──────────────────────────────────────────────────────────
+
+/// A well-formed CIDv0 for calls that do not care about their metadata.
+const TEST_IPFS_CID: &[u8] = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+/// Token address every test call's predicted asset points at.
+fn asset_token(env: &Env) -> Address {
+    Address::from_str(
+        env,
+        "CCVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKVKUD2U",
+    )
+}
+
+/// Register the pairs the tests create calls on.
+fn register_test_assets(env: &Env, client: &crate::CallRegistryClient<'_>) {
+    for pair in [&b"USDC/XLM"[..], &b"XLM/USD"[..]] {
+        client.register_asset(&crate::types::PredictedAsset {
+            token_address: asset_token(env),
+            pair_id: Bytes::from_slice(env, pair),
+            decimals: 7,
+            price_feed_id: Bytes::from_slice(env, pair),
+        });
+    }
+}
+
+// ── Registration ──────────────────────────────────────────────────────────────
+
+/// Register a CallRegistry set up with `admin`, `outcome_manager` and
+/// `min_stake`: through the constructor, or through `initialize` when built
+/// with `legacy-initialize`.
+pub(crate) fn register_registry(
+    env: &Env,
+    admin: &Address,
+    outcome_manager: &Address,
+    min_stake: i128,
+) -> Address {
+    #[cfg(not(feature = "legacy-initialize"))]
+    let contract_id = env.register(crate::CallRegistry, (admin, outcome_manager, &min_stake));
+    #[cfg(feature = "legacy-initialize")]
+    let contract_id = {
+        let contract_id = env.register(crate::CallRegistry, ());
+        crate::CallRegistryClient::new(env, &contract_id)
+            .mock_all_auths()
+            .initialize(admin, outcome_manager, &min_stake);
+        contract_id
+    };
+    contract_id
+}
+
 // ── Test module ───────────────────────────────────────────────────────────────
 
 mod call_registry {
     use super::*;
     use crate::storage::DataKey;
-    use crate::types::ConditionType;
+    use crate::types::{
+        AdminAction, ConditionType, PauseComponent, PauseState, PredictedAsset, Role,
+    };
     use crate::{CallRegistry, CallRegistryClient};
     use ed25519_dalek::{Signer, SigningKey};
 
@@ -41,17 +165,33 @@ mod call_registry {
         let env = Env::default();
         env.mock_all_auths();
 
-    let contract_id = env.register(CallRegistry, ());
-        let client = CallRegistryClient::new(&env, &contract_id);
-
         let admin = Address::generate(&env);
         let outcome_manager = Address::generate(&env);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
+        let client = CallRegistryClient::new(&env, &contract_id);
+
+        register_test_assets(&env, &client);
 
         (env, client, admin, outcome_manager)
     }
 
+    /// Queue an admin action, wait out the timelock and execute it.
+    fn run_admin_action(env: &Env, client: &CallRegistryClient<'_>, action: &AdminAction) {
+        let admin = client.get_config().admin;
+        let id = client.queue_admin_action(&admin, action);
+        let eta = client.get_admin_action(&id).unwrap().eta;
+        env.ledger().set_timestamp(eta);
+        client.execute_admin_action(&admin, &id);
+    }
+
+    /// Payload of the `admin_params_changed` event an executed action emits
+    /// just before `admin_action_executed`.
+    fn admin_params_changed_payload(env: &Env) -> soroban_sdk::Val {
+        let events = env.events().all();
+        events.get(events.len() - 2).expect("no events").2
+    }
+
     fn create_test_env() -> (Env, Address, Address, Address) {
         let env = Env::default();
         env.mock_all_auths();
@@ -104,8 +244,9 @@ mod call_registry {
         outcome_count: &u32,
     ) -> crate::types::Call {
         client.whitelist_token(stake_token);
+        register_test_assets(&client.env, client);
         // Use a default IPFS CID for tests
-        let ipfs_cid = Bytes::from_slice(&client.env, b"QmXxxx");
+        let ipfs_cid = Bytes::from_slice(&client.env, TEST_IPFS_CID);
         client.create_call(
             creator,
             &crate::types::CallInitArgs {
@@ -119,40 +260,57 @@ mod call_registry {
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: *outcome_count,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         )
     }
 
-    // ── initialize ────────────────────────────────────────────────────────────
+    // ── constructor ───────────────────────────────────────────────────────────
 
     #[test]
-    fn test_initialize() {
+    fn test_constructor_initializes() {
         let (env, admin, outcome_manager, _) = create_test_env();
-    let contract_id = env.register(CallRegistry, ());
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
-
         let config = client.get_config();
         assert_eq!(config.admin, admin);
         assert_eq!(config.outcome_manager, outcome_manager);
-        assert!(!config.paused);
+        assert_eq!(config.pause_state, PauseState::default());
+        assert!(client.has_role(&Role::Pauser, &admin));
+        assert!(client.has_role(&Role::FeeSetter, &admin));
     }
 
+    #[cfg(feature = "legacy-initialize")]
     #[test]
-    fn test_initialize_twice_fails() {
-        let (env, admin, outcome_manager, _) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
-        let client = CallRegistryClient::new(&env, &contract_id);
-
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
+    fn test_legacy_initialize_requires_admin_and_runs_once() {
+        let env = Env::default();
+        let admin = Address::generate(&env);
+        let outcome_manager = Address::generate(&env);
+        let client = CallRegistryClient::new(&env, &env.register(CallRegistry, ()));
 
+        // Unsigned by the admin
         let result = client.try_initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
-        assert_eq!(
-            result,
-            Err(Ok(CallRegistryError::AlreadyInitialized)),
-            "second initialize should return AlreadyInitialized"
-        );
+        assert!(result.is_err());
+
+        client
+            .mock_all_auths()
+            .initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
+        assert_eq!(client.get_config().admin, admin);
+        assert!(client.has_role(&Role::Pauser, &admin));
+        let result =
+            client
+                .mock_all_auths()
+                .try_initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
+        assert_eq!(result, Err(Ok(CallRegistryError::AlreadyInitialized)));
     }
 
     // ── set_admin ─────────────────────────────────────────────────────────────
@@ -183,6 +341,7 @@ mod call_registry {
                 &env,
                 "call_registry".into_val(&env),
                 "admin_params_changed".into_val(&env),
+                client.get_event_sequence().into_val(&env),
             ]
         );
 
@@ -198,10 +357,9 @@ mod call_registry {
     fn test_set_admin() {
         let (env, admin, outcome_manager, _) = create_test_env();
         let new_admin = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         client.set_admin(&new_admin);
 
         assert_eq!(client.get_config().admin, new_admin);
@@ -214,7 +372,11 @@ mod call_registry {
         let (env, client, _admin, _om) = setup();
         let new_om = Address::generate(&env);
 
-        client.set_outcome_manager(&new_om);
+        run_admin_action(
+            &env,
+            &client,
+            &AdminAction::SetOutcomeManager(new_om.clone()),
+        );
 
         assert_eq!(client.get_config().outcome_manager, new_om);
     }
@@ -227,7 +389,7 @@ mod call_registry {
         let creator = Address::generate(&env);
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         // Use a deterministic metadata hash for test
         let metadata_hash = {
@@ -237,12 +399,12 @@ mod call_registry {
         };
 
         // Create the call
-        let ipfs_cid = Bytes::from_slice(&env, b"QmXxxx");
+        let ipfs_cid = Bytes::from_slice(&env, TEST_IPFS_CID);
         let call = client.create_call(
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
@@ -251,7 +413,16 @@ mod call_registry {
                 metadata_hash: metadata_hash.clone(),
                 condition: crate::types::ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 2u32,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         // Read back the stored DataEntry for the metadata hash
@@ -261,7 +432,7 @@ mod call_registry {
         assert!(entry.is_some(), "DataEntry should be set");
         let entry_bytes = entry.unwrap();
         // Expect base-64 encoded byte hash
-        assert!(entry_bytes.len() > 0u32);
+        assert!(!entry_bytes.is_empty());
     }
 
     #[test]
@@ -269,13 +440,14 @@ mod call_registry {
         let (env, client, _admin, old_om) = setup();
         let new_om = Address::generate(&env);
 
-        client.set_outcome_manager(&new_om);
-
-        let events = env.events().all();
-        let last = events.last().expect("no events");
+        run_admin_action(
+            &env,
+            &client,
+            &AdminAction::SetOutcomeManager(new_om.clone()),
+        );
 
         let (param, _changed_by, old_val, new_val): (Symbol, Address, Address, Address) =
-            last.2.into_val(&env);
+            admin_params_changed_payload(&env).into_val(&env);
 
         assert_eq!(param, Symbol::new(&env, "outcome_manager"));
         assert_eq!(old_val, old_om);
@@ -286,11 +458,14 @@ mod call_registry {
     fn test_set_outcome_manager() {
         let (env, admin, outcome_manager, _) = create_test_env();
         let new_manager = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
-        client.set_outcome_manager(&new_manager);
+        run_admin_action(
+            &env,
+            &client,
+            &AdminAction::SetOutcomeManager(new_manager.clone()),
+        );
 
         assert_eq!(client.get_config().outcome_manager, new_manager);
     }
@@ -299,21 +474,18 @@ mod call_registry {
 
     #[test]
     fn test_set_fee_updates_config() {
-        let (_env, client, _admin, _om) = setup();
-        client.set_fee(&250_u32);
+        let (env, client, _admin, _om) = setup();
+        run_admin_action(&env, &client, &AdminAction::SetFee(250));
         assert_eq!(client.get_config().fee_bps, 250);
     }
 
     #[test]
     fn test_set_fee_emits_admin_params_changed() {
         let (env, client, _admin, _om) = setup();
-        client.set_fee(&100_u32);
-
-        let events = env.events().all();
-        let last = events.last().expect("no events");
+        run_admin_action(&env, &client, &AdminAction::SetFee(100));
 
         let (param, _changed_by, old_val, new_val): (Symbol, Address, u32, u32) =
-            last.2.into_val(&env);
+            admin_params_changed_payload(&env).into_val(&env);
 
         assert_eq!(param, Symbol::new(&env, "fee_bps"));
         assert_eq!(old_val, 0_u32);
@@ -322,22 +494,22 @@ mod call_registry {
 
     #[test]
     fn test_set_fee_zero_is_valid() {
-        let (_env, client, _admin, _om) = setup();
-        client.set_fee(&0_u32);
+        let (env, client, _admin, _om) = setup();
+        run_admin_action(&env, &client, &AdminAction::SetFee(0));
         assert_eq!(client.get_config().fee_bps, 0);
     }
 
     #[test]
     fn test_set_fee_max_boundary_is_valid() {
-        let (_env, client, _admin, _om) = setup();
-        client.set_fee(&10_000_u32);
+        let (env, client, _admin, _om) = setup();
+        run_admin_action(&env, &client, &AdminAction::SetFee(10_000));
         assert_eq!(client.get_config().fee_bps, 10_000);
     }
 
     #[test]
     fn test_set_fee_above_max_returns_fee_too_high() {
-        let (_env, client, _admin, _om) = setup();
-        let result = client.try_set_fee(&10_001_u32);
+        let (_env, client, admin, _om) = setup();
+        let result = client.try_queue_admin_action(&admin, &AdminAction::SetFee(10_001));
         assert_eq!(
             result,
             Err(Ok(CallRegistryError::FeeTooHigh)),
@@ -345,20 +517,150 @@ mod call_registry {
         );
     }
 
+    // ── timelock ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_queue_admin_action_waits_for_delay() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1_000);
+
+        let id = client.queue_admin_action(&admin, &AdminAction::SetFee(300));
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "admin_action_queued".into_val(&env),
+            ]
+        );
+        let queued = client.get_admin_action(&id).unwrap();
+        let delay = crate::types::DEFAULT_TIMELOCK_DELAY_SECS;
+        assert_eq!(queued.eta, 1_000 + delay);
+        assert_eq!(client.get_config().fee_bps, 0);
+
+        env.ledger().set_timestamp(queued.eta);
+        client.execute_admin_action(&admin, &id);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "admin_action_executed".into_val(&env),
+            ]
+        );
+        assert_eq!(client.get_config().fee_bps, 300);
+        assert!(client.get_admin_action(&id).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #62)")]
+    fn test_execute_admin_action_before_eta_panics() {
+        let (env, client, admin, _om) = setup();
+        let id = client.queue_admin_action(&admin, &AdminAction::SetSwitchFee(50));
+        let eta = client.get_admin_action(&id).unwrap().eta;
+        env.ledger().set_timestamp(eta - 1);
+        client.execute_admin_action(&admin, &id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #61)")]
+    fn test_cancelled_admin_action_cannot_execute() {
+        let (env, client, admin, _om) = setup();
+        let new_om = Address::generate(&env);
+        let id = client.queue_admin_action(&admin, &AdminAction::SetOutcomeManager(new_om));
+
+        client.cancel_admin_action(&admin, &id);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "admin_action_cancelled".into_val(&env),
+            ]
+        );
+        assert!(client.get_admin_action(&id).is_none());
+
+        env.ledger().set_timestamp(u64::MAX);
+        client.execute_admin_action(&admin, &id);
+    }
+
+    #[test]
+    fn test_timelock_delay_change_is_itself_timelocked() {
+        let (env, client, admin, _om) = setup();
+        run_admin_action(&env, &client, &AdminAction::SetTimelockDelay(3_600));
+        assert_eq!(client.get_config().timelock_delay_secs, 3_600);
+
+        let now = env.ledger().timestamp();
+        let id = client.queue_admin_action(&admin, &AdminAction::SetReduceFee(25));
+        assert_eq!(client.get_admin_action(&id).unwrap().eta, now + 3_600);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #63)")]
+    fn test_timelock_delay_above_max_panics() {
+        let (_env, client, admin, _om) = setup();
+        let delay = crate::types::MAX_TIMELOCK_DELAY_SECS + 1;
+        client.queue_admin_action(&admin, &AdminAction::SetTimelockDelay(delay));
+    }
+
+    #[test]
+    fn test_set_params_applies_all_tunables_after_timelock() {
+        let (env, client, admin, _om) = setup();
+        let mut params = client.get_params();
+        assert_eq!(params.min_stake, TEST_MIN_STAKE);
+        params.fee_bps = 250;
+        params.min_call_duration_secs = 600;
+        params.max_stakers_per_call = 10;
+        params.dispute_window_secs = 3_600;
+
+        let id = client.set_params(&admin, &params);
+        assert_ne!(client.get_params(), params);
+        env.ledger()
+            .set_timestamp(client.get_admin_action(&id).unwrap().eta);
+        client.execute_admin_action(&admin, &id);
+
+        let events = env.events().all();
+        let topics = events.get(events.len() - 2).unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "protocol_params_changed".into_val(&env)
+            ]
+        );
+        assert_eq!(client.get_params(), params);
+        let config = client.get_config();
+        assert_eq!(config.fee_bps, 250);
+        assert_eq!(config.dispute_window_secs, 3_600);
+    }
+
+    #[test]
+    fn test_set_params_rejects_inverted_duration_bounds() {
+        let (_env, client, admin, _om) = setup();
+        let mut params = client.get_params();
+        params.min_call_duration_secs = 7_200;
+        params.max_call_duration_secs = 3_600;
+        let result = client.try_set_params(&admin, &params);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidDurationBounds)));
+    }
+
     // ── extend_call_ttl ───────────────────────────────────────────────────────
 
     #[test]
     fn test_extend_call_ttl_succeeds_for_existing_call() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -366,7 +668,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -381,11 +683,9 @@ mod call_registry {
     #[test]
     fn test_extend_call_ttl_missing_call_returns_error() {
         let (env, admin, outcome_manager, _) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
-
         let result = client.try_extend_call_ttl(&999u64);
         assert_eq!(
             result,
@@ -399,15 +699,14 @@ mod call_registry {
     #[test]
     fn test_set_call_uses_persistent_storage() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -415,7 +714,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -431,15 +730,14 @@ mod call_registry {
     fn test_staker_calls_ttl_extended_on_stake() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -447,7 +745,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -458,25 +756,61 @@ mod call_registry {
         env.budget().reset_unlimited();
         client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
 
-        let staker_calls = client.get_staker_calls(&staker);
+        let staker_calls = client.get_staker_calls(&staker, &0, &10).calls;
         assert_eq!(staker_calls.len(), 1);
         assert_eq!(staker_calls.get(0).unwrap().id, call.id);
     }
 
+    #[test]
+    fn test_staker_portfolio_aggregates_positions() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (first, _) = make_call(&env, &client, &creator);
+        let (second, _) = make_call(&env, &client, &creator);
+
+        client.stake_on_call(&staker, &second.id, &30_000_000_i128, &2);
+        client.stake_on_call(&staker, &first.id, &50_000_000_i128, &1);
+        env.ledger().set_timestamp(1100);
+        client.stake_on_call(&staker, &first.id, &20_000_000_i128, &1);
+        client.stake_on_call(&staker, &first.id, &40_000_000_i128, &2);
+        client.reduce_stake(&staker, &first.id, &40_000_000_i128, &2);
+
+        let page = client.get_staker_portfolio(&staker, &0, &10);
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(page.entries.len(), 2);
+
+        let entry = page.entries.get(0).unwrap();
+        assert_eq!(entry.call.id, first.id);
+        assert_eq!(entry.participation.positions.get(1), Some(70_000_000));
+        assert_eq!(entry.participation.positions.get(2), Some(0));
+        assert_eq!(entry.participation.total_stake, 70_000_000);
+        assert_eq!(entry.participation.first_stake_ts, 1000);
+        assert_eq!(entry.participation.last_stake_ts, 1100);
+
+        let entry = page.entries.get(1).unwrap();
+        assert_eq!(entry.call.id, second.id);
+        assert_eq!(entry.participation.total_stake, 30_000_000);
+
+        let page = client.get_staker_portfolio(&staker, &1, &1);
+        assert_eq!(page.entries.get(0).unwrap().call.id, second.id);
+        assert_eq!(client.get_staker_calls(&staker, &0, &10).calls.len(), 2);
+    }
+
     #[test]
     fn test_call_stakers_tracked_without_duplicates() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker1 = Address::generate(&env);
         let staker2 = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -484,7 +818,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -496,7 +830,7 @@ mod call_registry {
         client.stake_on_call(&staker1, &call.id, &20_000_000_i128, &2);
         client.stake_on_call(&staker2, &call.id, &30_000_000_i128, &1);
 
-        let stakers = client.get_call_stakers(&call.id);
+        let stakers = client.get_call_stakers(&call.id, &0, &10).addresses;
         assert_eq!(stakers.len(), 2);
         assert_eq!(stakers.get(0).unwrap(), staker1);
         assert_eq!(stakers.get(1).unwrap(), staker2);
@@ -515,7 +849,7 @@ mod call_registry {
         let staker2 = Address::generate(&env);
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -528,7 +862,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -539,7 +873,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -565,15 +899,14 @@ mod call_registry {
     #[test]
     fn test_create_call_success() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -581,7 +914,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -591,7 +924,7 @@ mod call_registry {
 
         assert_eq!(call.id, 1);
         assert_eq!(call.creator, creator);
-        assert_eq!(call.stake_amount, 100_000_000);
+        assert_eq!(call.stake_amount, TEST_MIN_STAKE);
         assert_eq!(call.outcome_stakes.get(1).unwrap_or(0), 0);
         assert_eq!(call.outcome_stakes.get(2).unwrap_or(0), 0);
         assert_eq!(call.outcome, 0);
@@ -604,15 +937,15 @@ mod call_registry {
     #[test]
     fn test_create_call_zero_start_price_returns_error() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -620,16 +953,25 @@ mod call_registry {
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: 0_i128,
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
                 pair_id: pair_id.clone(),
-                ipfs_cid: Bytes::from_slice(&env, b"QmXxxx"),
+                ipfs_cid: Bytes::from_slice(&env, TEST_IPFS_CID),
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 2,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         assert_eq!(result, Err(Ok(CallRegistryError::InvalidStakeAmount)));
@@ -638,22 +980,21 @@ mod call_registry {
     #[test]
     fn test_set_start_price_updates_call() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
         let call = create_call_with_default_condition(
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -673,15 +1014,15 @@ mod call_registry {
     #[test]
     fn test_create_call_invalid_stake_returns_error() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -694,11 +1035,20 @@ mod call_registry {
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
                 pair_id: pair_id.clone(),
-                ipfs_cid: Bytes::from_slice(&env, b"QmXxxx"),
+                ipfs_cid: Bytes::from_slice(&env, TEST_IPFS_CID),
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 2,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         assert_eq!(
@@ -711,15 +1061,15 @@ mod call_registry {
     #[test]
     fn test_create_call_past_timestamp_returns_error() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -727,16 +1077,25 @@ mod call_registry {
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts: 500u64, // in the past
                 token_address: token_address.clone(),
                 pair_id: pair_id.clone(),
-                ipfs_cid: Bytes::from_slice(&env, b"QmXxxx"),
+                ipfs_cid: Bytes::from_slice(&env, TEST_IPFS_CID),
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 2,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         assert_eq!(
@@ -752,15 +1111,14 @@ mod call_registry {
     fn test_stake_on_call_up() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -768,7 +1126,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -788,15 +1146,14 @@ mod call_registry {
     fn test_stake_on_call_down() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -804,7 +1161,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -822,15 +1179,14 @@ mod call_registry {
     fn test_stake_on_ended_call_returns_error() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -838,7 +1194,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -860,15 +1216,14 @@ mod call_registry {
     fn test_stake_invalid_position_returns_error() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -876,7 +1231,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -897,15 +1252,14 @@ mod call_registry {
     #[test]
     fn test_get_call() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -913,7 +1267,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -925,17 +1279,15 @@ mod call_registry {
 
         assert_eq!(retrieved.id, created_call.id);
         assert_eq!(retrieved.creator, creator);
-        assert_eq!(retrieved.stake_amount, 100_000_000);
+        assert_eq!(retrieved.stake_amount, TEST_MIN_STAKE);
     }
 
     #[test]
     fn test_get_nonexistent_call_returns_error() {
         let (env, admin, outcome_manager, _) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
-
         let result = client.try_get_call(&999);
         assert_eq!(
             result,
@@ -951,15 +1303,14 @@ mod call_registry {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker1 = Address::generate(&env);
         let staker2 = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -967,7 +1318,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -984,6 +1335,39 @@ mod call_registry {
         assert_eq!(stats.outcome_stakes.get(2).unwrap_or(0), 30_000_000);
         assert_eq!(stats.outcome_stake_counts.get(1).unwrap_or(0), 1);
         assert_eq!(stats.outcome_stake_counts.get(2).unwrap_or(0), 1);
+        assert_eq!(stats.total_stakes, 2);
+        assert_eq!(stats.last_stake_ts, 1000);
+    }
+
+    #[test]
+    fn test_call_stats_track_top_ups_and_reductions() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _stake_token) = make_call(&env, &client, &creator);
+
+        let stats = client.get_call_stats(&call.id);
+        assert_eq!(stats.total_stakes, 0);
+        assert_eq!(stats.last_stake_ts, 0);
+
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+        env.ledger().set_timestamp(1200);
+        client.stake_on_call(&staker, &call.id, &20_000_000_i128, &1);
+
+        let stats = client.get_call_stats(&call.id);
+        assert_eq!(stats.outcome_stake_counts.get(1), Some(1));
+        assert_eq!(stats.outcome_stakes.get(1), Some(70_000_000));
+        assert_eq!(stats.total_stakes, 1);
+        assert_eq!(stats.last_stake_ts, 1200);
+
+        client.reduce_stake(&staker, &call.id, &70_000_000_i128, &1);
+
+        let stats = client.get_call_stats(&call.id);
+        assert_eq!(stats.outcome_stake_counts.get(1), Some(0));
+        assert_eq!(stats.outcome_stakes.get(1), Some(0));
+        assert_eq!(stats.total_stakes, 0);
+        assert_eq!(stats.last_stake_ts, 1200);
     }
 
     // ── resolve_call ──────────────────────────────────────────────────────────
@@ -991,15 +1375,14 @@ mod call_registry {
     #[test]
     fn test_resolve_call() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1007,7 +1390,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1026,15 +1409,14 @@ mod call_registry {
     #[test]
     fn test_resolve_call_before_end_returns_error() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1042,7 +1424,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1065,16 +1447,15 @@ mod call_registry {
     fn test_get_call_count() {
         let (env, admin, outcome_manager, creator) = create_test_env();
 
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         assert_eq!(client.get_call_count(), 0);
 
         let stake_token = Address::generate(&env);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1082,7 +1463,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1093,7 +1474,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &3000u64,
             &token_address,
             &pair_id,
@@ -1109,15 +1490,14 @@ mod call_registry {
     #[test]
     fn test_get_calls_paginated_respects_limit_and_start_id() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let token_admin = Address::generate(&env);
         let stake_token = env.register_stellar_asset_contract(token_admin);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1125,7 +1505,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1136,7 +1516,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &3000u64,
             &token_address,
             &pair_id,
@@ -1147,7 +1527,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &4000u64,
             &token_address,
             &pair_id,
@@ -1165,14 +1545,13 @@ mod call_registry {
     #[test]
     fn test_get_calls_paginated_respects_maximum_limit() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = Address::generate(&env);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1181,7 +1560,7 @@ mod call_registry {
                 &client,
                 &creator,
                 &stake_token,
-                &100_000_000_i128,
+                &TEST_MIN_STAKE,
                 &2000u64,
                 &token_address,
                 &pair_id,
@@ -1200,14 +1579,13 @@ mod call_registry {
     fn test_get_calls_by_creator_paginated_returns_creator_specific_results() {
         let (env, admin, outcome_manager, creator1) = create_test_env();
         let creator2 = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = Address::generate(&env);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1215,7 +1593,7 @@ mod call_registry {
             &client,
             &creator1,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1226,7 +1604,7 @@ mod call_registry {
             &client,
             &creator2,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &3000u64,
             &token_address,
             &pair_id,
@@ -1237,7 +1615,7 @@ mod call_registry {
             &client,
             &creator1,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &4000u64,
             &token_address,
             &pair_id,
@@ -1258,14 +1636,13 @@ mod call_registry {
     fn test_get_calls_by_creator_paginated_handles_gaps_and_max_limit() {
         let (env, admin, outcome_manager, creator1) = create_test_env();
         let creator2 = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = Address::generate(&env);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1273,7 +1650,7 @@ mod call_registry {
             &client,
             &creator1,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1284,7 +1661,7 @@ mod call_registry {
             &client,
             &creator2,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &3000u64,
             &token_address,
             &pair_id,
@@ -1295,7 +1672,7 @@ mod call_registry {
             &client,
             &creator1,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &4000u64,
             &token_address,
             &pair_id,
@@ -1321,7 +1698,7 @@ mod call_registry {
     ) -> (crate::types::Call, Address) {
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(env);
+        let token_address = asset_token(env);
         let pair_id = Bytes::from_slice(env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(env, &[0u8; 32]);
 
@@ -1329,7 +1706,7 @@ mod call_registry {
             client,
             creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1339,6 +1716,31 @@ mod call_registry {
         (call, stake_token)
     }
 
+    /// Default creation args matching `make_call`, for tests that tweak one field.
+    fn default_args(env: &Env, stake_token: &Address) -> crate::types::CallInitArgs {
+        crate::types::CallInitArgs {
+            stake_token: stake_token.clone(),
+            stake_amount: TEST_MIN_STAKE,
+            start_price: TEST_START_PRICE,
+            end_ts: 2000u64,
+            token_address: asset_token(env),
+            pair_id: Bytes::from_slice(env, b"USDC/XLM"),
+            ipfs_cid: Bytes::from_slice(env, TEST_IPFS_CID),
+            metadata_hash: BytesN::from_array(env, &[0u8; 32]),
+            condition: ConditionType::TargetAbove(100_000_000_i128),
+            outcome_count: 2,
+            parent_call_id: 0,
+            parent_outcome: 0,
+            allow_early_resolution: false,
+            is_private: false,
+            seed_up: 0,
+            seed_down: 0,
+            max_total_stake: 0,
+            max_stake_per_address: 0,
+            stake_model: crate::types::StakeModel::MinimumFree,
+        }
+    }
+
     fn mint(env: &Env, token: &Address, to: &Address, amount: i128) {
         use soroban_sdk::token::StellarAssetClient;
         let sac = StellarAssetClient::new(env, token);
@@ -1347,12 +1749,12 @@ mod call_registry {
 
     #[test]
     fn test_void_call_succeeds() {
-        let (env, client, _admin, _om) = setup();
+        let (env, client, admin, _om) = setup();
         env.ledger().set_timestamp(1000);
         let creator = Address::generate(&env);
         let (call, _) = make_call(&env, &client, &creator);
 
-        client.void_call(&call.id);
+        client.void_call(&admin, &call.id);
 
         let updated = client.get_call(&call.id);
         assert!(updated.voided);
@@ -1360,7 +1762,7 @@ mod call_registry {
 
     #[test]
     fn test_claim_void_refund_succeeds() {
-        let (env, client, _admin, _om) = setup();
+        let (env, client, admin, _om) = setup();
         env.ledger().set_timestamp(1000);
         let creator = Address::generate(&env);
         let staker = Address::generate(&env);
@@ -1368,130 +1770,3048 @@ mod call_registry {
 
         client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
 
-        client.void_call(&call.id);
+        client.void_call(&admin, &call.id);
         client.claim_void_refund(&staker, &call.id);
     }
 
     #[test]
-    #[should_panic(expected = "No stake to refund")]
-    fn test_claim_refund_with_no_stake_panics() {
-        let (env, client, _admin, _om) = setup();
+    fn test_claim_void_refund_covers_every_outcome() {
+        use soroban_sdk::token::TokenClient;
+
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+        mint(&env, &stake_token, &staker, 90_000_000);
+        let mut args = default_args(&env, &stake_token);
+        args.outcome_count = 3;
+        let call = client.create_call(&Address::generate(&env), &args);
+
+        client.stake_on_call(&staker, &call.id, &20_000_000_i128, &1);
+        client.stake_on_call(&staker, &call.id, &30_000_000_i128, &3);
+        client.void_call(&admin, &call.id);
+        client.claim_void_refund(&staker, &call.id);
+
+        let token = TokenClient::new(&env, &stake_token);
+        assert_eq!(token.balance(&staker), 90_000_000);
+    }
+
+    #[test]
+    fn test_claim_refund_with_no_stake_fails() {
+        let (env, client, admin, _om) = setup();
         env.ledger().set_timestamp(1000);
         let creator = Address::generate(&env);
         let non_staker = Address::generate(&env);
         let (call, _) = make_call(&env, &client, &creator);
 
-        client.void_call(&call.id);
-        client.claim_void_refund(&non_staker, &call.id);
+        client.void_call(&admin, &call.id);
+        let result = client.try_claim_void_refund(&non_staker, &call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::NoExistingStake)));
     }
 
-    // ── 3-outcome market tests ───────────────────────────────────────────────
-
     #[test]
-    fn test_create_3_outcome_call_success() {
-        let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
-        let client = CallRegistryClient::new(&env, &contract_id);
-
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
+    #[should_panic(expected = "Error(Contract, #59)")]
+    fn test_claim_refund_twice_panics_with_error_code() {
+        let (env, client, admin, _om) = setup();
         env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
 
-        let stake_token = env.register_contract(None, MockToken);
-        client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
-        let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
-        let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+        client.void_call(&admin, &call.id);
+        client.claim_void_refund(&staker, &call.id);
+        client.claim_void_refund(&staker, &call.id);
+    }
 
-        let ipfs_cid = Bytes::from_slice(&env, b"QmXxxx");
-        let call = client.create_call(
-            &creator,
-            &crate::types::CallInitArgs {
-                stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
-                start_price: TEST_START_PRICE,
-                end_ts: 2000u64,
-                token_address: token_address.clone(),
-                pair_id: pair_id.clone(),
-                ipfs_cid: ipfs_cid.clone(),
-                metadata_hash: metadata_hash.clone(),
-                condition: ConditionType::TargetAbove(100_000_000_i128),
-                outcome_count: 3,
-            }
-        );
+    #[test]
+    #[should_panic(expected = "Error(Contract, #54)")]
+    fn test_stake_on_voided_call_panics_with_error_code() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
 
-        assert_eq!(call.id, 1);
-        assert_eq!(call.outcome_count, 3);
-        assert_eq!(call.outcome_stakes.get(1).unwrap_or(0), 0);
-        assert_eq!(call.outcome_stakes.get(2).unwrap_or(0), 0);
-        assert_eq!(call.outcome_stakes.get(3).unwrap_or(0), 0);
+        client.void_call(&admin, &call.id);
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
     }
 
     #[test]
-    fn test_stake_on_3_outcome_call() {
-        let (env, admin, outcome_manager, creator) = create_test_env();
+    fn test_paused_contract_rejects_stakes_with_error_code() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
-        let client = CallRegistryClient::new(&env, &contract_id);
+        let (call, _) = make_call(&env, &client, &creator);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
-        env.ledger().set_timestamp(1000);
+        client.pause(&admin, &PauseComponent::Stake);
+        let result = client.try_stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::ContractPaused)));
 
-        let stake_token = env.register_contract(None, MockToken);
-        client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
-        let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
-        let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+        let result = client.try_set_min_stake(&-1);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidStakeAmount)));
+    }
 
-        let ipfs_cid = Bytes::from_slice(&env, b"QmXxxx");
-        let call = client.create_call(
-            &creator,
-            &crate::types::CallInitArgs {
-                stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
-                start_price: TEST_START_PRICE,
-                end_ts: 2000u64,
-                token_address: token_address.clone(),
-                pair_id: pair_id.clone(),
-                ipfs_cid: ipfs_cid.clone(),
-                metadata_hash: metadata_hash.clone(),
-                condition: ConditionType::TargetAbove(100_000_000_i128),
-                outcome_count: 3,
-            }
-        );
+    #[test]
+    fn test_pausing_stakes_leaves_creation_and_claims_open() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
 
-        env.budget().reset_unlimited();
+        client.pause(&admin, &PauseComponent::Stake);
+        let state = client.get_pause_state();
+        assert!(state.stake);
+        assert!(!state.create && !state.resolve && !state.claim);
 
-        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
-        client.stake_on_call(&staker, &call.id, &30_000_000_i128, &2);
-        client.stake_on_call(&staker, &call.id, &20_000_000_i128, &3);
+        let result = client.try_reduce_stake(&staker, &call.id, &50_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::ContractPaused)));
+        make_call(&env, &client, &creator);
+        client.void_call(&admin, &call.id);
+        client.claim_void_refund(&staker, &call.id);
 
-        let updated_call = client.get_call(&call.id);
-        assert_eq!(updated_call.outcome_stakes.get(1).unwrap_or(0), 50_000_000);
-        assert_eq!(updated_call.outcome_stakes.get(2).unwrap_or(0), 30_000_000);
-        assert_eq!(updated_call.outcome_stakes.get(3).unwrap_or(0), 20_000_000);
+        client.unpause(&admin, &PauseComponent::Stake);
+        assert_eq!(client.get_pause_state(), PauseState::default());
+        let (open, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &open.id, &50_000_000_i128, &1);
+    }
+
+    #[test]
+    fn test_paused_claims_block_void_refunds() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+        client.void_call(&admin, &call.id);
+
+        client.pause(&admin, &PauseComponent::Claim);
+        let result = client.try_claim_void_refund(&staker, &call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::ContractPaused)));
+
+        client.pause(&admin, &PauseComponent::Create);
+        let token = Address::generate(&env);
+        let result = client.try_create_call(&creator, &default_args(&env, &token));
+        assert_eq!(result, Err(Ok(CallRegistryError::ContractPaused)));
+    }
+
+    #[test]
+    fn test_pause_requires_pauser() {
+        let (env, client, _admin, _om) = setup();
+        let pauser = Address::generate(&env);
+        assert!(client.try_pause(&pauser, &PauseComponent::Resolve).is_err());
+
+        client.grant_role(&Role::Pauser, &pauser);
+        assert!(client.has_role(&Role::Pauser, &pauser));
+
+        env.mock_auths(&[]);
+        assert!(client.try_pause(&pauser, &PauseComponent::Resolve).is_err());
+
+        client
+            .mock_auths(&[soroban_sdk::testutils::MockAuth {
+                address: &pauser,
+                invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                    contract: &client.address,
+                    fn_name: "pause",
+                    args: (pauser.clone(), PauseComponent::Resolve).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .pause(&pauser, &PauseComponent::Resolve);
+        assert!(client.get_pause_state().resolve);
+    }
+
+    // ── roles ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_grant_and_revoke_role() {
+        let (env, client, _admin, _om) = setup();
+        let resolver = Address::generate(&env);
+
+        client.grant_role(&Role::Resolver, &resolver);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "role_granted".into_val(&env)
+            ]
+        );
+        assert!(client.has_role(&Role::Resolver, &resolver));
+        assert!(!client.has_role(&Role::Pauser, &resolver));
+
+        client.revoke_role(&Role::Resolver, &resolver);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "role_revoked".into_val(&env)
+            ]
+        );
+        assert!(!client.has_role(&Role::Resolver, &resolver));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #65)")]
+    fn test_admin_role_cannot_be_granted() {
+        let (env, client, _admin, _om) = setup();
+        client.grant_role(&Role::Admin, &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_set_admin_moves_admin_role() {
+        let (env, client, admin, _om) = setup();
+        let new_admin = Address::generate(&env);
+        client.set_admin(&new_admin);
+        assert!(client.has_role(&Role::Admin, &new_admin));
+        assert!(!client.has_role(&Role::Admin, &admin));
+        assert!(client.has_role(&Role::Pauser, &admin));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #64)")]
+    fn test_void_call_requires_resolver_role() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let (call, _) = make_call(&env, &client, &Address::generate(&env));
+        client.revoke_role(&Role::Resolver, &admin);
+        client.void_call(&admin, &call.id);
+    }
+
+    #[test]
+    fn test_fee_setter_queues_fee_changes_only() {
+        let (env, client, _admin, _om) = setup();
+        let fee_setter = Address::generate(&env);
+        client.grant_role(&Role::FeeSetter, &fee_setter);
+
+        let id = client.queue_admin_action(&fee_setter, &AdminAction::SetFee(150));
+        let eta = client.get_admin_action(&id).unwrap().eta;
+        env.ledger().set_timestamp(eta);
+        client.execute_admin_action(&fee_setter, &id);
+        assert_eq!(client.get_config().fee_bps, 150);
+
+        let manager = AdminAction::SetOutcomeManager(Address::generate(&env));
+        let result = client.try_queue_admin_action(&fee_setter, &manager);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(64)
+        );
+    }
+
+    // ── conditional calls ─────────────────────────────────────────────────────
+
+    fn child_args(
+        env: &Env,
+        stake_token: &Address,
+        parent_call_id: u64,
+        parent_outcome: u32,
+    ) -> crate::types::CallInitArgs {
+        crate::types::CallInitArgs {
+            stake_token: stake_token.clone(),
+            stake_amount: TEST_MIN_STAKE,
+            start_price: TEST_START_PRICE,
+            end_ts: 3000u64,
+            token_address: asset_token(env),
+            pair_id: Bytes::from_slice(env, b"USDC/XLM"),
+            ipfs_cid: Bytes::from_slice(env, TEST_IPFS_CID),
+            metadata_hash: BytesN::from_array(env, &[0u8; 32]),
+            condition: ConditionType::TargetAbove(100_000_000_i128),
+            outcome_count: 2,
+            parent_call_id,
+            parent_outcome,
+            allow_early_resolution: false,
+            is_private: false,
+            seed_up: 0,
+            seed_down: 0,
+            max_total_stake: 0,
+            max_stake_per_address: 0,
+            stake_model: crate::types::StakeModel::MinimumFree,
+        }
+    }
+
+    #[test]
+    fn test_create_conditional_call_with_missing_parent_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let result = client.try_create_call(&creator, &child_args(&env, &stake_token, 99, 1));
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidParentCall)));
+    }
+
+    #[test]
+    fn test_conditional_call_resolves_when_parent_matches() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (parent, stake_token) = make_call(&env, &client, &creator);
+        let child = client.create_call(&creator, &child_args(&env, &stake_token, parent.id, 1));
+
+        env.ledger().set_timestamp(3000);
+        let result = client.try_resolve_call(&child.id, &1, &150_000_000);
+        assert_eq!(result, Err(Ok(CallRegistryError::ParentNotResolved)));
+
+        client.resolve_call(&parent.id, &1, &150_000_000);
+        let resolved = client.resolve_call(&child.id, &2, &90_000_000);
+        assert_eq!(resolved.outcome, 2);
+        assert!(!resolved.voided);
+    }
+
+    #[test]
+    fn test_conditional_call_voids_when_parent_mismatches() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (parent, stake_token) = make_call(&env, &client, &creator);
+        let child = client.create_call(&creator, &child_args(&env, &stake_token, parent.id, 1));
+        client.stake_on_call(&staker, &child.id, &50_000_000_i128, &1);
+
+        env.ledger().set_timestamp(3000);
+        client.resolve_call(&parent.id, &2, &90_000_000);
+        let resolved = client.resolve_call(&child.id, &1, &150_000_000);
+
+        assert!(resolved.voided);
+        assert_eq!(resolved.outcome, 0);
+        client.claim_void_refund(&staker, &child.id);
+    }
+
+    #[test]
+    fn test_resolve_call_as_void_enters_refund_mode() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+
+        let void = backit_shared::OUTCOME_VOID;
+        let resolved = client.resolve_call(&call.id, &void, &0);
+        assert!(resolved.voided);
+        assert_eq!(resolved.outcome, 0);
+        client.claim_void_refund(&staker, &call.id);
+
+        let result = client.try_resolve_call(&call.id, &1, &150_000_000);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(54)
+        );
+    }
+
+    // ── DRAW tolerance band ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_set_draw_tolerance_above_max_returns_error() {
+        let (_env, client, _admin, _om) = setup();
+        let result = client.try_set_draw_tolerance(&10_001u32);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidTolerance)));
+    }
+
+    #[test]
+    fn test_resolve_call_as_draw_within_tolerance() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.set_draw_tolerance(&100u32); // 1 %
+
+        env.ledger().set_timestamp(2000);
+        // 0.5 % move is inside the band, so UP is rejected and DRAW accepted
+        let end_price = TEST_START_PRICE + TEST_START_PRICE / 200;
+        let result = client.try_resolve_call(&call.id, &1, &end_price);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidOutcome)));
+
+        let resolved = client.resolve_call(&call.id, &backit_shared::OUTCOME_DRAW, &end_price);
+        assert_eq!(resolved.outcome, backit_shared::OUTCOME_DRAW);
+    }
+
+    #[test]
+    fn test_get_binary_outcome_matches_resolve_rules() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.set_draw_tolerance(&100u32);
+
+        let inside = TEST_START_PRICE + TEST_START_PRICE / 200;
+        let draw = backit_shared::OUTCOME_DRAW;
+        assert_eq!(client.get_binary_outcome(&call.id, &inside), draw);
+        let up = client.get_binary_outcome(&call.id, &(TEST_START_PRICE * 2));
+        assert_eq!(up, backit_shared::OUTCOME_UP);
+        let down = client.get_binary_outcome(&call.id, &(TEST_START_PRICE / 2));
+        assert_eq!(down, backit_shared::OUTCOME_DOWN);
+    }
+
+    #[test]
+    fn test_resolve_call_as_draw_outside_tolerance_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.set_draw_tolerance(&100u32);
+
+        env.ledger().set_timestamp(2000);
+        let end_price = TEST_START_PRICE * 2;
+        let result = client.try_resolve_call(&call.id, &backit_shared::OUTCOME_DRAW, &end_price);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidOutcome)));
+    }
+
+    #[test]
+    fn test_resolve_call_outcome_must_follow_price_move() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        env.ledger().set_timestamp(2000);
+        let (up, down) = (backit_shared::OUTCOME_UP, backit_shared::OUTCOME_DOWN);
+        let higher = TEST_START_PRICE + 1;
+        let lower = TEST_START_PRICE - 1;
+        let result = client.try_resolve_call(&call.id, &down, &higher);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidOutcome)));
+        let result = client.try_resolve_call(&call.id, &up, &lower);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidOutcome)));
+
+        // An unchanged price is a DRAW even with no tolerance configured.
+        let result = client.try_resolve_call(&call.id, &up, &TEST_START_PRICE);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidOutcome)));
+
+        let resolved = client.resolve_call(&call.id, &up, &higher);
+        assert_eq!(resolved.outcome, up);
+    }
+
+    #[test]
+    fn test_resolve_call_twice_is_rejected_and_keeps_first_outcome() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&call.id, &1u32, &150_000_000_i128);
+        let result = client.try_resolve_call(&call.id, &2u32, &50_000_000_i128);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(78)
+        );
+
+        let stored = client.get_call(&call.id);
+        assert_eq!(stored.outcome, 1);
+        assert_eq!(stored.end_price, 150_000_000);
+    }
+
+    // ── dispute window ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_dispute_window_allows_override_and_holds_back_settlement() {
+        let (env, client, _admin, om) = setup();
+        env.ledger().set_timestamp(1000);
+        client.set_dispute_window(&3600u64);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&call.id, &1u32, &150_000_000_i128);
+        assert_eq!(client.get_dispute_deadline(&call.id), Some(2000 + 3600));
+        let result = client.try_mark_settled(&call.id);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(80)
+        );
+
+        let evidence = Bytes::from_slice(&env, TEST_IPFS_CID);
+        let corrected = client.override_outcome(&om, &call.id, &2u32, &80_000_000, &evidence);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "outcome_overridden".into_val(&env)
+            ]
+        );
+        assert_eq!(corrected.outcome, 2);
+        assert_eq!(corrected.end_price, 80_000_000);
+
+        env.ledger().set_timestamp(2000 + 3600);
+        let result = client.try_override_outcome(&om, &call.id, &1u32, &150_000_000, &evidence);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(81)
+        );
+        client.mark_settled(&call.id);
+        assert_eq!(client.get_call(&call.id).outcome, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #81)")]
+    fn test_override_outcome_without_dispute_window_panics() {
+        let (env, client, _admin, om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&call.id, &1u32, &150_000_000_i128);
+        let evidence = Bytes::from_slice(&env, TEST_IPFS_CID);
+        client.override_outcome(&om, &call.id, &2u32, &80_000_000, &evidence);
+    }
+
+    #[test]
+    fn test_override_outcome_only_through_outcome_manager() {
+        // OutcomeManager pays claims from its own final outcome, so an admin
+        // override here would close the window with the old winners paid.
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        client.set_dispute_window(&3600u64);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&call.id, &1u32, &150_000_000_i128);
+        let evidence = Bytes::from_slice(&env, TEST_IPFS_CID);
+        let result = client.try_override_outcome(&admin, &call.id, &2u32, &80_000_000, &evidence);
+        assert_eq!(result, Err(Ok(CallRegistryError::Unauthorized)));
+        assert_eq!(client.get_call(&call.id).outcome, 1);
+    }
+
+    // ── early resolution ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_early_resolution_requires_threshold_condition() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.condition = ConditionType::PercentUp(10);
+        args.allow_early_resolution = true;
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(
+            result,
+            Err(Ok(CallRegistryError::EarlyResolutionNotSupported))
+        );
+    }
+
+    #[test]
+    fn test_early_resolution_when_target_hit() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.allow_early_resolution = true;
+        let call = client.create_call(&creator, &args);
+
+        // Target not crossed yet: still too early
+        let result = client.try_resolve_call(&call.id, &1, &90_000_000);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotEnded)));
+
+        let resolved = client.resolve_call(&call.id, &1, &120_000_000);
+        assert!(resolved.early_resolved);
+        assert_eq!(resolved.outcome, 1);
+
+        let result = client.try_stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallEnded)));
+    }
+
+    #[test]
+    fn test_early_resolution_when_target_below_hit() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.condition = ConditionType::TargetBelow(90_000_000);
+        args.allow_early_resolution = true;
+        let call = client.create_call(&creator, &args);
+
+        // Below the start price but not yet under the target
+        let result = client.try_resolve_call(&call.id, &1, &95_000_000);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotEnded)));
+
+        // Crossing the target resolves UP even though the price fell
+        let resolved = client.resolve_call(&call.id, &1, &80_000_000);
+        assert!(resolved.early_resolved);
+        assert_eq!(resolved.outcome, 1);
+    }
+
+    #[test]
+    fn test_resolve_before_end_without_opt_in_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        let result = client.try_resolve_call(&call.id, &1, &120_000_000);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotEnded)));
+    }
+
+    // ── call duration bounds ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_set_call_duration_bounds_min_above_max_returns_error() {
+        let (_env, client, _admin, _om) = setup();
+        let result = client.try_set_call_duration_bounds(&7200u64, &3600u64);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidDurationBounds)));
+    }
+
+    #[test]
+    fn test_create_call_outside_duration_bounds_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+        client.set_call_duration_bounds(&600u64, &86_400u64);
+
+        let mut args = default_args(&env, &stake_token);
+        args.end_ts = 1005;
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallDurationTooShort)));
+
+        args.end_ts = 1000 + 86_401;
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallDurationTooLong)));
+
+        args.end_ts = 1000 + 3600;
+        let call = client.create_call(&creator, &args);
+        assert_eq!(call.end_ts, 4600);
+    }
+
+    // ── private calls ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_private_call_rejects_unlisted_staker() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let friend = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.is_private = true;
+        let call = client.create_call(&creator, &args);
+        assert!(call.is_private);
+
+        client.update_call_allowlist(&creator, &call.id, &vec![&env, friend.clone()], &true);
+        assert!(client.is_allowlisted(&call.id, &friend));
+        assert!(!client.is_allowlisted(&call.id, &stranger));
+
+        client.stake_on_call(&friend, &call.id, &50_000_000_i128, &1);
+        let result = client.try_stake_on_call(&stranger, &call.id, &50_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakerNotAllowlisted)));
+
+        client.update_call_allowlist(&creator, &call.id, &vec![&env, friend.clone()], &false);
+        let result = client.try_stake_on_call(&friend, &call.id, &50_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakerNotAllowlisted)));
+    }
+
+    #[test]
+    fn test_update_call_allowlist_requires_private_call_and_creator() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+        let (public_call, stake_token) = make_call(&env, &client, &creator);
+        let stakers = vec![&env, other.clone()];
+
+        let result = client.try_update_call_allowlist(&creator, &public_call.id, &stakers, &true);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotPrivate)));
+        assert!(client.is_allowlisted(&public_call.id, &other));
+
+        let mut args = default_args(&env, &stake_token);
+        args.is_private = true;
+        let call = client.create_call(&creator, &args);
+        let result = client.try_update_call_allowlist(&other, &call.id, &stakers, &true);
+        assert_eq!(result, Err(Ok(CallRegistryError::Unauthorized)));
+    }
+
+    // ── creator-seeded liquidity ──────────────────────────────────────────────
+
+    #[test]
+    fn test_create_call_with_seed_liquidity() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.seed_up = 300_000_000;
+        args.seed_down = 200_000_000;
+        let call = client.create_call(&creator, &args);
+
+        assert_eq!(call.outcome_stakes.get(1), Some(300_000_000));
+        assert_eq!(call.outcome_stakes.get(2), Some(200_000_000));
+        assert_eq!(client.get_staker_stake(&call.id, &creator, &1), 300_000_000);
+        assert_eq!(client.get_staker_stake(&call.id, &creator, &2), 200_000_000);
+        let stakers = client.get_call_stakers(&call.id, &0, &10).addresses;
+        assert_eq!(stakers, vec![&env, creator.clone()]);
+    }
+
+    #[test]
+    fn test_create_call_negative_seed_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.seed_down = -1;
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidStakeAmount)));
+    }
+
+    #[test]
+    fn test_create_call_seeds_are_validated_like_stakes() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.seed_up = TEST_MIN_STAKE - 1;
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakeBelowMinimum)));
+
+        let mut args = default_args(&env, &stake_token);
+        args.stake_model = crate::types::StakeModel::FixedTicket;
+        args.seed_down = TEST_MIN_STAKE * 2;
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidTicketAmount)));
+
+        // Each seed fits the cap on its own, but together they exceed it
+        let mut args = default_args(&env, &stake_token);
+        args.max_stake_per_address = TEST_MIN_STAKE * 3;
+        args.seed_up = TEST_MIN_STAKE * 2;
+        args.seed_down = TEST_MIN_STAKE * 2;
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(result, Err(Ok(CallRegistryError::AddressStakeCapExceeded)));
+
+        let mut args = default_args(&env, &stake_token);
+        args.is_private = true;
+        args.seed_up = TEST_MIN_STAKE;
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakerNotAllowlisted)));
+    }
+
+    // ── predicted assets ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_register_asset_stores_metadata() {
+        let (env, client, _admin, _om) = setup();
+        let pair_id = Bytes::from_slice(&env, b"BTC/USD");
+        let asset = PredictedAsset {
+            token_address: Address::generate(&env),
+            pair_id: pair_id.clone(),
+            decimals: 8,
+            price_feed_id: Bytes::from_slice(&env, b"BTC"),
+        };
+
+        client.register_asset(&asset);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "asset_registered".into_val(&env)
+            ]
+        );
+        assert_eq!(client.get_asset(&pair_id), Some(asset));
+
+        client.remove_asset(&pair_id);
+        assert_eq!(client.get_asset(&pair_id), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #66)")]
+    fn test_create_call_on_unregistered_pair_panics() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.pair_id = Bytes::from_slice(&env, b"USDC/XMl");
+        client.create_call(&creator, &args);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #67)")]
+    fn test_create_call_with_wrong_asset_token_panics() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.token_address = Address::generate(&env);
+        client.create_call(&creator, &args);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #68)")]
+    fn test_register_asset_rejects_excess_decimals() {
+        let (env, client, _admin, _om) = setup();
+        client.register_asset(&PredictedAsset {
+            token_address: Address::generate(&env),
+            pair_id: Bytes::from_slice(&env, b"ETH/USD"),
+            decimals: crate::types::MAX_ASSET_DECIMALS + 1,
+            price_feed_id: Bytes::from_slice(&env, b"ETH"),
+        });
+    }
+
+    #[test]
+    fn test_create_call_rejects_malformed_pair_id() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        for pair in [
+            &b""[..],
+            b"USDCXLM",
+            b"/XLM",
+            b"USDC/",
+            b"USDC/XLM/X",
+            b"USDC XLM/X",
+        ] {
+            let mut args = default_args(&env, &stake_token);
+            args.pair_id = Bytes::from_slice(&env, pair);
+            let result = client.try_create_call(&creator, &args);
+            assert_eq!(
+                result.err().unwrap().unwrap_err(),
+                soroban_sdk::InvokeError::Contract(76)
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_call_rejects_malformed_ipfs_cid() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let too_long = [b'a'; 65];
+        let with_space = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPp HdWEz79ojWnPbdG";
+        for cid in [&b"QmXxxx"[..], &too_long[..], &with_space[..]] {
+            let mut args = default_args(&env, &stake_token);
+            args.ipfs_cid = Bytes::from_slice(&env, cid);
+            let result = client.try_create_call(&creator, &args);
+            assert_eq!(
+                result.err().unwrap().unwrap_err(),
+                soroban_sdk::InvokeError::Contract(77)
+            );
+        }
+
+        let mut args = default_args(&env, &stake_token);
+        args.ipfs_cid = Bytes::from_slice(
+            &env,
+            b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        );
+        client.create_call(&creator, &args);
+    }
+
+    // ── time-weighted stakes ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_stake_weight_decays_towards_end() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let early = Address::generate(&env);
+        let late = Address::generate(&env);
+        client.set_max_time_bonus(&5000u32);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        client.stake_on_call(&early, &call.id, &100_000_000_i128, &1);
+        env.ledger().set_timestamp(1500);
+        let call = client.stake_on_call(&late, &call.id, &100_000_000_i128, &1);
+
+        // 1.5x at creation, 1.25x halfway to end_ts
+        assert_eq!(client.get_weighted_stake(&call.id, &early, &1), 150_000_000);
+        assert_eq!(client.get_weighted_stake(&call.id, &late, &1), 125_000_000);
+        assert_eq!(call.weighted_outcome_stakes.get(1), Some(275_000_000));
+        assert_eq!(call.outcome_stakes.get(1), Some(200_000_000));
+    }
+
+    #[test]
+    fn test_stake_weight_equals_amount_when_disabled() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        client.stake_on_call(&staker, &call.id, &100_000_000_i128, &2);
+        assert_eq!(
+            client.get_weighted_stake(&call.id, &staker, &2),
+            100_000_000
+        );
+    }
+
+    #[test]
+    fn test_set_max_time_bonus_above_limit_returns_error() {
+        let (_env, client, _admin, _om) = setup();
+        let result = client.try_set_max_time_bonus(&10_001u32);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidTimeBonus)));
+    }
+
+    // ── anti-sniping deadline extension ───────────────────────────────────────
+
+    #[test]
+    fn test_late_large_stake_extends_deadline_up_to_cap() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let early = Address::generate(&env);
+        let sniper = Address::generate(&env);
+        // 10-minute window, 20% of pool, +2 min per trigger, +3 min in total
+        client.set_anti_snipe_params(&600u64, &2000u32, &120u64, &180u64);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        // Staking deadline is 2000 - 300 = 1700; 1000 is outside the window
+        let call = client.stake_on_call(&early, &call.id, &100_000_000_i128, &1);
+        assert_eq!(call.end_ts, 2000);
+
+        env.ledger().set_timestamp(1650);
+        let call = client.stake_on_call(&sniper, &call.id, &100_000_000_i128, &2);
+        assert_eq!(call.end_ts, 2120);
+        assert_eq!(call.deadline_extended_secs, 120);
+
+        env.ledger().set_timestamp(1800);
+        let call = client.stake_on_call(&sniper, &call.id, &100_000_000_i128, &2);
+        assert_eq!(call.end_ts, 2180);
+        assert_eq!(call.deadline_extended_secs, 180);
+    }
+
+    #[test]
+    fn test_late_small_stake_does_not_extend_deadline() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let whale = Address::generate(&env);
+        let minnow = Address::generate(&env);
+        client.set_anti_snipe_params(&600u64, &2000u32, &120u64, &180u64);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        client.stake_on_call(&whale, &call.id, &1_000_000_000_i128, &1);
+        env.ledger().set_timestamp(1650);
+        let call = client.stake_on_call(&minnow, &call.id, &100_000_000_i128, &2);
+        assert_eq!(call.end_ts, 2000);
+    }
+
+    #[test]
+    fn test_set_anti_snipe_params_invalid_threshold_returns_error() {
+        let (_env, client, _admin, _om) = setup();
+        let result = client.try_set_anti_snipe_params(&600u64, &10_001u32, &120u64, &180u64);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidAntiSnipeParams)));
+    }
+
+    // ── per-call stake caps ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_stake_over_call_cap_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.max_total_stake = 300_000_000;
+        let call = client.create_call(&creator, &args);
+        assert_eq!(client.get_remaining_capacity(&call.id), Some(300_000_000));
+
+        client.stake_on_call(&alice, &call.id, &200_000_000_i128, &1);
+        assert_eq!(client.get_remaining_capacity(&call.id), Some(100_000_000));
+
+        let result = client.try_stake_on_call(&bob, &call.id, &150_000_000_i128, &2);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallStakeCapExceeded)));
+        client.stake_on_call(&bob, &call.id, &100_000_000_i128, &2);
+        assert_eq!(client.get_remaining_capacity(&call.id), Some(0));
+    }
+
+    #[test]
+    fn test_stake_over_address_cap_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let whale = Address::generate(&env);
+        let (uncapped, stake_token) = make_call(&env, &client, &creator);
+        assert_eq!(
+            client.get_remaining_address_capacity(&uncapped.id, &whale),
+            None
+        );
+
+        let mut args = default_args(&env, &stake_token);
+        args.max_stake_per_address = 150_000_000;
+        let call = client.create_call(&creator, &args);
+
+        // The cap spans both sides of the call
+        client.stake_on_call(&whale, &call.id, &100_000_000_i128, &1);
+        let result = client.try_stake_on_call(&whale, &call.id, &100_000_000_i128, &2);
+        assert_eq!(result, Err(Ok(CallRegistryError::AddressStakeCapExceeded)));
+        assert_eq!(
+            client.get_remaining_address_capacity(&call.id, &whale),
+            Some(50_000_000)
+        );
+    }
+
+    // ── max stakers per call ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_stake_on_full_call_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        client.set_max_stakers_per_call(&2u32);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        client.stake_on_call(&alice, &call.id, &100_000_000_i128, &1);
+        client.stake_on_call(&bob, &call.id, &100_000_000_i128, &2);
+
+        let result = client.try_stake_on_call(&carol, &call.id, &100_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallFull)));
+
+        // Existing stakers can still add to their positions
+        client.stake_on_call(&alice, &call.id, &50_000_000_i128, &2);
+        assert_eq!(client.get_call_staker_count(&call.id), 2);
+    }
+
+    // ── stake model ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_stake_below_call_minimum_returns_error() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.stake_amount = 50_000_000;
+        let call = client.create_call(&creator, &args);
+
+        let result = client.try_stake_on_call(&staker, &call.id, &49_999_999_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakeBelowMinimum)));
+        client.stake_on_call(&staker, &call.id, &75_000_000_i128, &1);
+    }
+
+    #[test]
+    fn test_fixed_ticket_call_requires_exact_amount() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let mut args = default_args(&env, &stake_token);
+        args.stake_amount = 50_000_000;
+        args.stake_model = crate::types::StakeModel::FixedTicket;
+        let call = client.create_call(&creator, &args);
+
+        let result = client.try_stake_on_call(&staker, &call.id, &75_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidTicketAmount)));
+        let call = client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+        assert_eq!(call.outcome_stakes.get(1), Some(50_000_000));
+    }
+
+    // ── increase / reduce stake ───────────────────────────────────────────────
+
+    #[test]
+    fn test_increase_stake_requires_existing_position() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        let result = client.try_increase_stake(&staker, &call.id, &50_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::NoExistingStake)));
+
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+        let call = client.increase_stake(&staker, &call.id, &25_000_000_i128, &1);
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &1), 75_000_000);
+        assert_eq!(call.outcome_stakes.get(1), Some(75_000_000));
+    }
+
+    #[test]
+    fn test_reduce_stake_charges_fee_into_penalty_pool() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &call.id, &100_000_000_i128, &1);
+
+        // Default reduce fee is 1%
+        let call = client.reduce_stake(&staker, &call.id, &40_000_000_i128, &1);
+        assert_eq!(call.penalty_pool, 400_000);
+        assert_eq!(client.get_penalty_pool(&call.id), 400_000);
+        assert_eq!(call.outcome_stakes.get(1), Some(60_000_000));
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &1), 60_000_000);
+
+        let call = client.reduce_stake(&staker, &call.id, &60_000_000_i128, &1);
+        assert_eq!(call.outcome_stakes.get(1), Some(0));
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &1), 0);
+        assert_eq!(client.get_weighted_stake(&call.id, &staker, &1), 0);
+    }
+
+    #[test]
+    fn test_penalty_pool_without_winners_goes_to_insurance_pool() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (draw, _) = make_call(&env, &client, &creator);
+        let (voided, _) = make_call(&env, &client, &creator);
+        let (won, _) = make_call(&env, &client, &creator);
+        for call in [&draw, &voided, &won] {
+            client.stake_on_call(&staker, &call.id, &100_000_000_i128, &1);
+            client.reduce_stake(&staker, &call.id, &40_000_000_i128, &1);
+        }
+        let result = client.try_sweep_penalty_pool(&draw.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotResolved)));
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&draw.id, &backit_shared::OUTCOME_DRAW, &TEST_START_PRICE);
+        client.void_call(&admin, &voided.id);
+        client.resolve_call(&won.id, &1, &150_000_000_i128);
+
+        assert_eq!(client.sweep_penalty_pool(&draw.id), 400_000);
+        assert_eq!(client.get_penalty_pool(&draw.id), 0);
+        assert_eq!(client.get_insurance_pool(&draw.stake_token), 400_000);
+        let result = client.try_sweep_penalty_pool(&draw.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::NothingToClaim)));
+        assert_eq!(client.sweep_penalty_pool(&voided.id), 400_000);
+
+        // A winning outcome's stakers are paid the penalty pool instead
+        let result = client.try_sweep_penalty_pool(&won.id);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(89)
+        );
+    }
+
+    #[test]
+    fn test_reduce_stake_rejects_invalid_amounts() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+
+        let result = client.try_reduce_stake(&staker, &call.id, &60_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::ReduceExceedsStake)));
+        let result = client.try_reduce_stake(&staker, &call.id, &10_000_000_i128, &2);
+        assert_eq!(result, Err(Ok(CallRegistryError::NoExistingStake)));
+        // Leaving less than the call's minimum is not allowed
+        let result = client.try_reduce_stake(&staker, &call.id, &49_500_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakeBelowMinimum)));
+
+        env.ledger().set_timestamp(1800);
+        let result = client.try_reduce_stake(&staker, &call.id, &10_000_000_i128, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakingCutoffActive)));
+    }
+
+    // ── switch position ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_switch_position_moves_stake_minus_fee() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &call.id, &100_000_000_i128, &1);
+
+        // Default switch fee is 2%
+        let call = client.switch_position(&staker, &call.id, &1, &2);
+        assert_eq!(call.outcome_stakes.get(1), Some(0));
+        assert_eq!(call.outcome_stakes.get(2), Some(98_000_000));
+        assert_eq!(call.penalty_pool, 2_000_000);
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &1), 0);
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &2), 98_000_000);
+        assert_eq!(client.get_global_stats().total_stake_volume, 100_000_000);
+    }
+
+    #[test]
+    fn test_switch_position_rejects_invalid_requests() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        let result = client.try_switch_position(&staker, &call.id, &1, &2);
+        assert_eq!(result, Err(Ok(CallRegistryError::NoExistingStake)));
+
+        client.stake_on_call(&staker, &call.id, &100_000_000_i128, &1);
+        let result = client.try_switch_position(&staker, &call.id, &1, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidPosition)));
+        let result = client.try_switch_position(&staker, &call.id, &1, &3);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidPosition)));
+    }
+
+    #[test]
+    fn test_switch_position_respects_stake_caps() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.set_max_stake_per_user(&150_000_000);
+        client.stake_on_call(&staker, &call.id, &100_000_000_i128, &1);
+        client.stake_on_call(&staker, &call.id, &100_000_000_i128, &2);
+
+        // 98M moved onto the 100M already on DOWN would breach the cap
+        let result = client.try_switch_position(&staker, &call.id, &1, &2);
+        assert_eq!(result, Err(Ok(CallRegistryError::AddressStakeCapExceeded)));
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &1), 100_000_000);
+
+        client.set_max_stake_per_user(&200_000_000);
+        let call = client.switch_position(&staker, &call.id, &1, &2);
+        assert_eq!(call.outcome_stakes.get(2), Some(198_000_000));
+    }
+
+    // ── allowance-based staking ───────────────────────────────────────────────
+
+    #[test]
+    fn test_stake_on_call_from_pulls_allowance() {
+        use soroban_sdk::token::TokenClient;
+
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let relayer = Address::generate(&env);
+
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+        mint(&env, &stake_token, &staker, 100_000_000);
+        let token = TokenClient::new(&env, &stake_token);
+        token.approve(&staker, &client.address, &60_000_000, &1000);
+
+        let call = client.create_call(&creator, &default_args(&env, &stake_token));
+        let call = client.stake_on_call_from(&relayer, &staker, &call.id, &60_000_000_i128, &1);
+
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &1), 60_000_000);
+        assert_eq!(token.balance(&staker), 40_000_000);
+        assert_eq!(token.balance(&client.address), 60_000_000);
+        assert_eq!(token.allowance(&staker, &client.address), 0);
+    }
+
+    // ── creator bond ──────────────────────────────────────────────────────────
+
+    /// A bonded call on a real token, with the creator funded for the bond.
+    fn make_bonded_call(
+        env: &Env,
+        client: &CallRegistryClient<'_>,
+        creator: &Address,
+        bond: i128,
+    ) -> (crate::types::Call, soroban_sdk::token::TokenClient<'static>) {
+        let stake_token = env.register_stellar_asset_contract(Address::generate(env));
+        client.whitelist_token(&stake_token);
+        client.set_creator_bond(&bond);
+        mint(env, &stake_token, creator, bond);
+        let call = client.create_call(creator, &default_args(env, &stake_token));
+        (
+            call,
+            soroban_sdk::token::TokenClient::new(env, &stake_token),
+        )
+    }
+
+    #[test]
+    fn test_creator_bond_refunded_after_resolution() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, token) = make_bonded_call(&env, &client, &creator, 5_000_000);
+        assert_eq!(token.balance(&creator), 0);
+        assert_eq!(client.get_creator_bond(&call.id), 5_000_000);
+
+        let result = client.try_claim_creator_bond(&call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotResolved)));
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&call.id, &1u32, &150_000_000_i128);
+        assert_eq!(client.claim_creator_bond(&call.id), 5_000_000);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "creator_bond_refunded".into_val(&env)
+            ]
+        );
+        assert_eq!(token.balance(&creator), 5_000_000);
+
+        let result = client.try_claim_creator_bond(&call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::NothingToClaim)));
+    }
+
+    #[test]
+    fn test_slashed_creator_bond_funds_insurance_pool() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, token) = make_bonded_call(&env, &client, &creator, 5_000_000);
+
+        assert_eq!(client.slash_creator_bond(&call.id), 5_000_000);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "creator_bond_slashed".into_val(&env)
+            ]
+        );
+        assert_eq!(client.get_insurance_pool(&token.address), 5_000_000);
+        assert_eq!(client.get_creator_bond(&call.id), 0);
+
+        client.void_call(&admin, &call.id);
+        let result = client.try_claim_creator_bond(&call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::NothingToClaim)));
+        assert_eq!(token.balance(&creator), 0);
+    }
+
+    #[test]
+    fn test_voided_call_bond_stays_slashable_for_grace_period() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, token) = make_bonded_call(&env, &client, &creator, 5_000_000);
+        let (spam, _) = make_bonded_call(&env, &client, &creator, 5_000_000);
+
+        // Voiding does not hand the bond straight back
+        client.void_call(&admin, &call.id);
+        client.void_call(&admin, &spam.id);
+        let result = client.try_claim_creator_bond(&call.id);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(87)
+        );
+        assert_eq!(client.slash_creator_bond(&spam.id), 5_000_000);
+
+        // An unslashed bond is refunded once the grace period ends
+        env.ledger()
+            .set_timestamp(1000 + crate::types::CREATOR_BOND_SLASH_GRACE_SECS - 1);
+        let result = client.try_claim_creator_bond(&call.id);
+        assert!(result.is_err());
+        env.ledger()
+            .set_timestamp(1000 + crate::types::CREATOR_BOND_SLASH_GRACE_SECS);
+        assert_eq!(client.claim_creator_bond(&call.id), 5_000_000);
+        assert_eq!(token.balance(&creator), 5_000_000);
+        let result = client.try_claim_creator_bond(&spam.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::NothingToClaim)));
+    }
+
+    #[test]
+    fn test_set_creator_bond_rejects_negative() {
+        let (_env, client, _admin, _om) = setup();
+        let result = client.try_set_creator_bond(&-1);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidStakeAmount)));
+    }
+
+    // ── call rate limit ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_call_rate_limit_blocks_then_resets_after_window() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let stake_token = env.register_contract(None, MockToken);
+        client.whitelist_token(&stake_token);
+        client.set_call_rate_limit(&2u32, &86_400u64);
+        let creator = Address::generate(&env);
+        let mut args = default_args(&env, &stake_token);
+        args.end_ts = 200_000;
+
+        client.create_call(&creator, &args);
+        env.ledger().set_timestamp(40_000);
+        client.create_call(&creator, &args);
+        let result = client.try_create_call(&creator, &args);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(69)
+        );
+
+        // Other creators have their own budget.
+        client.create_call(&Address::generate(&env), &args);
+
+        // Once the first call's hourly bucket leaves the window it stops
+        // counting; the second call is still in it.
+        env.ledger().set_timestamp(90_000);
+        client.create_call(&creator, &args);
+        assert!(client.try_create_call(&creator, &args).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #70)")]
+    fn test_set_call_rate_limit_rejects_zero_window() {
+        let (_env, client, _admin, _om) = setup();
+        client.set_call_rate_limit(&5u32, &0u64);
+    }
+
+    // ── address bans ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_banned_address_cannot_create_or_stake_until_unbanned() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, stake_token) = make_call(&env, &client, &creator);
+
+        client.ban_address(&staker);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "address_banned".into_val(&env)
+            ]
+        );
+        assert!(client.is_banned(&staker));
+        let result = client.try_stake_on_call(&staker, &call.id, &TEST_MIN_STAKE, &1u32);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(71)
+        );
+        let result = client.try_create_call(&staker, &default_args(&env, &stake_token));
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(71)
+        );
+
+        client.unban_address(&staker);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "address_unbanned".into_val(&env)
+            ]
+        );
+        assert!(!client.is_banned(&staker));
+        client.stake_on_call(&staker, &call.id, &TEST_MIN_STAKE, &1u32);
+    }
+
+    // ── per-call freeze ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_frozen_call_rejects_stakes_until_unfrozen() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        let (other, _) = make_call(&env, &client, &creator);
+        let resolver = Address::generate(&env);
+        client.grant_role(&Role::Resolver, &resolver);
+
+        client.freeze_call(&resolver, &call.id);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "call_frozen".into_val(&env)
+            ]
+        );
+        assert!(client.is_call_frozen(&call.id));
+        let result = client.try_stake_on_call(&staker, &call.id, &TEST_MIN_STAKE, &1u32);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(79)
+        );
+        // Other calls keep accepting stakes.
+        client.stake_on_call(&staker, &other.id, &TEST_MIN_STAKE, &1u32);
+
+        client.unfreeze_call(&admin, &call.id);
+        assert!(!client.is_call_frozen(&call.id));
+        client.stake_on_call(&staker, &call.id, &TEST_MIN_STAKE, &1u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #64)")]
+    fn test_freeze_call_requires_admin_or_resolver() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        client.freeze_call(&Address::generate(&env), &call.id);
+    }
+
+    // ── emergency withdrawal ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_emergency_withdraw_sweeps_unclaimed_escrow_after_delay() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+        let token = soroban_sdk::token::TokenClient::new(&env, &stake_token);
+        let creator = Address::generate(&env);
+        let call = client.create_call(&creator, &default_args(&env, &stake_token));
+        let staker = Address::generate(&env);
+        mint(&env, &stake_token, &staker, 3 * TEST_MIN_STAKE);
+        client.stake_on_call(&staker, &call.id, &(3 * TEST_MIN_STAKE), &1u32);
+
+        let eta = client.initiate_emergency_withdraw(&call.id);
+        let topics = env.events().all().last().unwrap().1;
+        let name = "emergency_withdraw_initiated";
+        assert_eq!(
+            topics.slice(0..2),
+            vec![&env, "call_registry".into_val(&env), name.into_val(&env)]
+        );
+        assert_eq!(eta, 1000 + 604_800);
+        let result = client.try_execute_emergency_withdraw(&call.id);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(74)
+        );
+
+        // Payouts made during the delay are not swept again.
+        client.release_escrow(&call.id, &staker, &TEST_MIN_STAKE);
+
+        env.ledger().set_timestamp(eta);
+        assert_eq!(
+            client.execute_emergency_withdraw(&call.id),
+            2 * TEST_MIN_STAKE
+        );
+        let topics = env.events().all().last().unwrap().1;
+        let name = "emergency_withdraw_executed";
+        assert_eq!(
+            topics.slice(0..2),
+            vec![&env, "call_registry".into_val(&env), name.into_val(&env)]
+        );
+        assert_eq!(token.balance(&admin), 2 * TEST_MIN_STAKE);
+        assert!(client.get_emergency_withdrawal(&call.id).unwrap().executed);
+
+        let result = client.try_release_escrow(&call.id, &staker, &TEST_MIN_STAKE);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(75)
+        );
+    }
+
+    #[test]
+    fn test_cancelled_emergency_withdraw_cannot_execute() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        client.initiate_emergency_withdraw(&call.id);
+        client.cancel_emergency_withdraw(&call.id);
+        assert_eq!(client.get_emergency_withdrawal(&call.id), None);
+
+        env.ledger().set_timestamp(1000 + 604_800);
+        let result = client.try_execute_emergency_withdraw(&call.id);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(73)
+        );
+    }
+
+    // ── copy-stake follows ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_copy_stakes_follow_creator_until_cap() {
+        use soroban_sdk::token::TokenClient;
+
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let follower = Address::generate(&env);
+
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+        mint(&env, &stake_token, &follower, 100_000_000);
+        let token = TokenClient::new(&env, &stake_token);
+
+        client.follow_creator(
+            &follower,
+            &creator,
+            &stake_token,
+            &10_000_000_i128,
+            &50_000_000,
+            &2,
+        );
+        assert_eq!(token.balance(&follower), 50_000_000);
+        let followers = client.get_followers(&creator, &0, &10).addresses;
+        assert_eq!(followers, vec![&env, follower.clone()]);
+
+        let first = client.create_call(&creator, &default_args(&env, &stake_token));
+        assert_eq!(client.execute_copy_stakes(&first.id, &0, &10), 1);
+        assert_eq!(
+            client.get_staker_stake(&first.id, &follower, &1),
+            10_000_000
+        );
+        // A second run for the same call is a no-op
+        assert_eq!(client.execute_copy_stakes(&first.id, &0, &10), 0);
+
+        let second = client.create_call(&creator, &default_args(&env, &stake_token));
+        let third = client.create_call(&creator, &default_args(&env, &stake_token));
+        assert_eq!(client.execute_copy_stakes(&second.id, &0, &10), 1);
+        // max_calls = 2 reached
+        assert_eq!(client.execute_copy_stakes(&third.id, &0, &10), 0);
+
+        let follow = client.get_follow(&follower, &creator).unwrap();
+        assert_eq!(follow.calls_copied, 2);
+        assert_eq!(follow.budget, 30_000_000);
+
+        assert_eq!(client.unfollow_creator(&follower, &creator), 30_000_000);
+        assert_eq!(token.balance(&follower), 80_000_000);
+        assert_eq!(token.balance(&client.address), 20_000_000);
+        assert_eq!(client.get_follow(&follower, &creator), None);
+        assert_eq!(client.get_followers(&creator, &0, &10).addresses.len(), 0);
+    }
+
+    #[test]
+    fn test_copy_stakes_skip_exhausted_budget_and_other_tokens() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let follower = Address::generate(&env);
+        let (call, stake_token) = make_call(&env, &client, &creator);
+        let (other_call, _) = make_call(&env, &client, &creator);
+
+        client.follow_creator(
+            &follower,
+            &creator,
+            &stake_token,
+            &10_000_000_i128,
+            &15_000_000,
+            &0,
+        );
+        assert_eq!(client.execute_copy_stakes(&other_call.id, &0, &10), 0);
+        assert_eq!(client.execute_copy_stakes(&call.id, &0, &10), 1);
+
+        let next = client.create_call(&creator, &default_args(&env, &stake_token));
+        assert_eq!(client.execute_copy_stakes(&next.id, &0, &10), 0);
+        assert_eq!(client.get_staker_stake(&next.id, &follower, &1), 0);
+    }
+
+    #[test]
+    fn test_follow_creator_rejects_invalid_requests() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let follower = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+
+        let token = &stake_token;
+        // Budget below one copy, and a per-call amount below the minimum stake
+        let result =
+            client.try_follow_creator(&follower, &creator, token, &10_000_000, &5_000_000, &0);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidStakeAmount)));
+        let result = client.try_follow_creator(&follower, &creator, token, &1, &5_000_000, &0);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidStakeAmount)));
+
+        client.follow_creator(&follower, &creator, token, &10_000_000, &10_000_000, &0);
+        let result =
+            client.try_follow_creator(&follower, &creator, token, &10_000_000, &10_000_000, &0);
+        assert_eq!(result, Err(Ok(CallRegistryError::AlreadyFollowing)));
+
+        let stranger = Address::generate(&env);
+        let result = client.try_unfollow_creator(&stranger, &creator);
+        assert_eq!(result, Err(Ok(CallRegistryError::NotFollowing)));
+    }
+
+    #[test]
+    fn test_execute_copy_stakes_rejects_closed_call() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        env.ledger().set_timestamp(1800);
+        let result = client.try_execute_copy_stakes(&call.id, &0, &10);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakingCutoffActive)));
+    }
+
+    // ── commit–reveal staking ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_commit_then_reveal_places_hidden_stake() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        let salt = BytesN::from_array(&env, &[7u8; 32]);
+        let commitment = client.stake_commitment(&2, &salt, &40_000_000);
+        client.commit_stake(&staker, &call.id, &commitment, &40_000_000_i128);
+
+        // Nothing is visible on either outcome until the reveal
+        let pending = client.get_call(&call.id);
+        assert_eq!(pending.outcome_stakes.get(2), Some(0));
+        assert_eq!(
+            client.get_stake_commit(&call.id, &staker).unwrap().amount,
+            40_000_000
+        );
+
+        let result = client.try_reveal_stake(&staker, &call.id, &1, &salt);
+        assert_eq!(result, Err(Ok(CallRegistryError::CommitMismatch)));
+
+        let call = client.reveal_stake(&staker, &call.id, &2, &salt);
+        assert_eq!(call.outcome_stakes.get(2), Some(40_000_000));
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &2), 40_000_000);
+        assert_eq!(client.get_stake_commit(&call.id, &staker), None);
+    }
+
+    #[test]
+    fn test_commit_stake_rejects_duplicate_and_closed_call() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        let commitment = BytesN::from_array(&env, &[1u8; 32]);
+
+        client.commit_stake(&staker, &call.id, &commitment, &10_000_000_i128);
+        let result = client.try_commit_stake(&staker, &call.id, &commitment, &10_000_000_i128);
+        assert_eq!(result, Err(Ok(CallRegistryError::CommitExists)));
+
+        let late = Address::generate(&env);
+        env.ledger().set_timestamp(1800);
+        let result = client.try_commit_stake(&late, &call.id, &commitment, &10_000_000_i128);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakingCutoffActive)));
+    }
+
+    #[test]
+    fn test_unrevealed_commit_refundable_after_resolution() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        let commitment = BytesN::from_array(&env, &[1u8; 32]);
+        client.commit_stake(&staker, &call.id, &commitment, &10_000_000_i128);
+
+        let result = client.try_refund_commit(&staker, &call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::CommitNotRefundable)));
+
+        client.void_call(&admin, &call.id);
+        assert_eq!(client.refund_commit(&staker, &call.id), 10_000_000);
+        let result = client.try_refund_commit(&staker, &call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::CommitNotFound)));
+    }
+
+    #[test]
+    fn test_unrevealed_commit_refundable_after_reveal_window() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        let commitment = BytesN::from_array(&env, &[1u8; 32]);
+        client.commit_stake(&staker, &call.id, &commitment, &10_000_000_i128);
+
+        // Past end_ts the commit can no longer be revealed, before any resolution
+        env.ledger().set_timestamp(call.end_ts);
+        assert_eq!(client.refund_commit(&staker, &call.id), 10_000_000);
+    }
+
+    #[test]
+    fn test_commit_stake_validates_amount_like_a_stake() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+        let commitment = BytesN::from_array(&env, &[1u8; 32]);
+
+        let mut args = default_args(&env, &stake_token);
+        args.stake_model = crate::types::StakeModel::FixedTicket;
+        let call = client.create_call(&creator, &args);
+        let result = client.try_commit_stake(&staker, &call.id, &commitment, &(TEST_MIN_STAKE * 2));
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidTicketAmount)));
+
+        let mut args = default_args(&env, &stake_token);
+        args.max_stake_per_address = TEST_MIN_STAKE * 2;
+        let call = client.create_call(&creator, &args);
+        let result = client.try_commit_stake(&staker, &call.id, &commitment, &(TEST_MIN_STAKE * 3));
+        assert_eq!(result, Err(Ok(CallRegistryError::AddressStakeCapExceeded)));
+
+        let mut args = default_args(&env, &stake_token);
+        args.max_total_stake = TEST_MIN_STAKE * 2;
+        let call = client.create_call(&creator, &args);
+        let result = client.try_commit_stake(&staker, &call.id, &commitment, &(TEST_MIN_STAKE * 3));
+        assert_eq!(result, Err(Ok(CallRegistryError::CallStakeCapExceeded)));
+
+        let mut args = default_args(&env, &stake_token);
+        args.is_private = true;
+        let call = client.create_call(&creator, &args);
+        let result = client.try_commit_stake(&staker, &call.id, &commitment, &TEST_MIN_STAKE);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakerNotAllowlisted)));
+    }
+
+    // ── fixed-odds staking ────────────────────────────────────────────────────
+
+    /// Register a SAC stake token, fund `staker` and the house, and open a call.
+    fn setup_fixed_odds(
+        env: &Env,
+        client: &CallRegistryClient<'_>,
+        admin: &Address,
+        staker: &Address,
+        house: i128,
+    ) -> (crate::types::Call, Address) {
+        let stake_token = env.register_stellar_asset_contract(Address::generate(env));
+        client.whitelist_token(&stake_token);
+        mint(env, &stake_token, staker, 100_000_000);
+        mint(env, &stake_token, admin, house);
+        if house > 0 {
+            client.deposit_house_liquidity(admin, &stake_token, &house);
+        }
+        let creator = Address::generate(env);
+        let call = client.create_call(&creator, &default_args(env, &stake_token));
+        (call, stake_token)
+    }
+
+    #[test]
+    fn test_fixed_odds_locks_quote_and_pays_winner() {
+        use soroban_sdk::token::TokenClient;
+
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &staker, 100_000_000);
+        let token = TokenClient::new(&env, &stake_token);
+
+        // Empty pool: 2× fair odds less the default 5% spread
+        assert_eq!(client.quote_fixed_odds(&call.id, &1), 19_000);
+        let ticket = client.stake_fixed_odds(&staker, &call.id, &10_000_000_i128, &1, &19_000);
+        assert_eq!(ticket.payout, 19_000_000);
+
+        // Fixed-odds stakes stay out of the pool; the house reserves the shortfall
+        assert_eq!(client.get_call(&call.id).outcome_stakes.get(1), Some(0));
+        assert_eq!(
+            client.get_fixed_odds_book(&call.id).unwrap().reserved,
+            9_000_000
+        );
+        assert_eq!(client.get_house_liquidity(&stake_token), 91_000_000);
+        assert_eq!(client.get_house_reserved(&stake_token), 9_000_000);
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&call.id, &1, &150_000_000_i128);
+        assert_eq!(client.claim_fixed_odds(&staker, &call.id), 19_000_000);
+        assert_eq!(token.balance(&staker), 109_000_000);
+        assert_eq!(client.settle_fixed_odds_book(&call.id), 0);
+        assert_eq!(client.get_house_liquidity(&stake_token), 91_000_000);
+        assert_eq!(client.get_house_reserved(&stake_token), 0);
+
+        let result = client.try_settle_fixed_odds_book(&call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::HouseBookSettled)));
+    }
+
+    #[test]
+    fn test_fixed_odds_losing_stake_returns_to_house() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &staker, 100_000_000);
+        client.stake_fixed_odds(&staker, &call.id, &10_000_000_i128, &1, &0);
+
+        let result = client.try_claim_fixed_odds(&staker, &call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotResolved)));
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&call.id, &2, &90_000_000_i128);
+        assert_eq!(client.claim_fixed_odds(&staker, &call.id), 0);
+        assert_eq!(client.settle_fixed_odds_book(&call.id), 19_000_000);
+        assert_eq!(client.get_house_liquidity(&stake_token), 110_000_000);
+
+        client.withdraw_house_liquidity(&admin, &stake_token, &110_000_000);
+        let result = client.try_withdraw_house_liquidity(&admin, &stake_token, &1);
+        assert_eq!(
+            result,
+            Err(Ok(CallRegistryError::InsufficientHouseLiquidity))
+        );
+    }
+
+    #[test]
+    fn test_fixed_odds_rejects_uncovered_exposure_and_low_odds() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 5_000_000);
+
+        let result = client.try_stake_fixed_odds(&staker, &call.id, &10_000_000_i128, &1, &0);
+        assert_eq!(
+            result,
+            Err(Ok(CallRegistryError::InsufficientHouseLiquidity))
+        );
+
+        // The pool leaning UP shortens UP odds below the staker's limit
+        client.stake_on_call(&staker, &call.id, &30_000_000_i128, &1);
+        let result = client.try_stake_fixed_odds(&staker, &call.id, &1_000_000_i128, &1, &19_000);
+        assert_eq!(result, Err(Ok(CallRegistryError::OddsBelowMinimum)));
+    }
+
+    #[test]
+    fn test_fixed_odds_pool_skew_cannot_be_withdrawn() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let attacker = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &attacker, 100_000_000);
+        mint(&env, &stake_token, &attacker, 100_000_000);
+
+        // Skew the pool onto UP so DOWN quotes long odds, then buy DOWN
+        client.stake_on_call(&attacker, &call.id, &80_000_000_i128, &1);
+        let ticket = client.stake_fixed_odds(&attacker, &call.id, &1_000_000_i128, &2, &0);
+        assert!(ticket.odds_bps > 19_000);
+
+        // The skew that priced the ticket cannot be taken back out
+        let result = client.try_reduce_stake(&attacker, &call.id, &80_000_000_i128, &1);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(88)
+        );
+        let result = client.try_switch_position(&attacker, &call.id, &1, &2);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(88)
+        );
+        assert_eq!(
+            client.get_call(&call.id).outcome_stakes.get(1),
+            Some(80_000_000)
+        );
+    }
+
+    #[test]
+    fn test_fixed_odds_refunded_on_void() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &staker, 100_000_000);
+        client.stake_fixed_odds(&staker, &call.id, &10_000_000_i128, &2, &0);
+
+        client.void_call(&admin, &call.id);
+        assert_eq!(client.claim_fixed_odds(&staker, &call.id), 10_000_000);
+        assert_eq!(client.settle_fixed_odds_book(&call.id), 9_000_000);
+        assert_eq!(client.get_house_liquidity(&stake_token), 100_000_000);
+        let result = client.try_claim_fixed_odds(&staker, &call.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::NothingToClaim)));
+    }
+
+    // ── position tokens ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_position_symbols_name_call_and_side() {
+        use crate::shares::position_symbol;
+        use soroban_sdk::String;
+
+        let env = Env::default();
+        assert_eq!(
+            position_symbol(&env, 42, 1, 2),
+            String::from_str(&env, "CALL42-UP")
+        );
+        assert_eq!(
+            position_symbol(&env, 42, 2, 2),
+            String::from_str(&env, "CALL42-DOWN")
+        );
+        assert_eq!(
+            position_symbol(&env, 7, 3, 3),
+            String::from_str(&env, "CALL7-O3")
+        );
+        assert_eq!(
+            position_symbol(&env, 0, 10, 12),
+            String::from_str(&env, "CALL0-O10")
+        );
+    }
+
+    #[test]
+    fn test_get_position_token_requires_tokenized_call() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        let result = client.try_get_position_token(&call.id, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidPosition)));
+        let result = client.try_get_position_token(&999, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotFound)));
+    }
+
+    // ── referrals ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_referral_share_of_fee_accrues_and_is_claimable() {
+        use soroban_sdk::token::TokenClient;
+
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let referrer = Address::generate(&env);
+        let collector = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        let token = TokenClient::new(&env, &stake_token);
+
+        client.stake_on_call_with_referrer(&staker, &call.id, &10_000_000_i128, &1, &referrer);
+        assert_eq!(client.get_referrer(&staker), Some(referrer.clone()));
+        assert_eq!(client.get_referred_count(&referrer), 1);
+
+        // Default 10% of the fee goes to the referrer; the rest to the collector
+        assert_eq!(
+            client.release_fee(&call.id, &staker, &collector, &200_000),
+            20_000
+        );
+        assert_eq!(token.balance(&collector), 180_000);
+        assert_eq!(client.get_protocol_fees(&stake_token), 180_000);
+        assert_eq!(client.get_referral_rewards(&referrer, &stake_token), 20_000);
+
+        assert_eq!(
+            client.claim_referral_rewards(&referrer, &stake_token),
+            20_000
+        );
+        assert_eq!(token.balance(&referrer), 20_000);
+        assert_eq!(client.get_referral_rewards(&referrer, &stake_token), 0);
+
+        let result = client.try_claim_referral_rewards(&referrer, &stake_token);
+        assert_eq!(result, Err(Ok(CallRegistryError::NothingToClaim)));
+    }
+
+    #[test]
+    fn test_first_referrer_is_kept() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+
+        client.stake_on_call_with_referrer(&staker, &call.id, &10_000_000_i128, &1, &first);
+        client.stake_on_call_with_referrer(&staker, &call.id, &10_000_000_i128, &1, &second);
+
+        assert_eq!(client.get_referrer(&staker), Some(first.clone()));
+        assert_eq!(client.get_referred_count(&first), 1);
+        assert_eq!(client.get_referred_count(&second), 0);
+    }
+
+    #[test]
+    fn test_self_referral_rejected() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+
+        let (id, amount) = (call.id, 10_000_000_i128);
+        let result = client.try_stake_on_call_with_referrer(&staker, &id, &amount, &1, &staker);
+        assert_eq!(result, Err(Ok(CallRegistryError::SelfReferral)));
+    }
+
+    #[test]
+    fn test_release_fee_without_referrer_pays_collector_in_full() {
+        use soroban_sdk::token::TokenClient;
+
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let collector = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+
+        client.set_referral_share(&5_000);
+        assert_eq!(
+            client.release_fee(&call.id, &staker, &collector, &200_000),
+            0
+        );
+        assert_eq!(
+            TokenClient::new(&env, &stake_token).balance(&collector),
+            200_000
+        );
+        assert_eq!(client.get_protocol_fees(&stake_token), 200_000);
+        assert_eq!(
+            client.try_set_referral_share(&10_001),
+            Err(Ok(CallRegistryError::FeeTooHigh))
+        );
+    }
+
+    #[test]
+    fn test_release_escrows_sends_one_transfer_per_token() {
+        use soroban_sdk::token::TokenClient;
+
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let (first, token_a) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        let (second, token_b) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        let creator = Address::generate(&env);
+        let third = client.create_call(&creator, &default_args(&env, &token_a));
+        for call in [&first, &second, &third] {
+            client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+        }
+
+        let releases = soroban_sdk::map![
+            &env,
+            (first.id, 300_i128),
+            (second.id, 200),
+            (third.id, 100)
+        ];
+        let sent = client.release_escrows(&winner, &releases);
+        assert_eq!(
+            sent,
+            soroban_sdk::map![&env, (token_a.clone(), 400_i128), (token_b.clone(), 200)]
+        );
+        assert_eq!(TokenClient::new(&env, &token_a).balance(&winner), 400);
+        assert_eq!(TokenClient::new(&env, &token_b).balance(&winner), 200);
+    }
+
+    #[test]
+    fn test_expire_escrow_moves_winnings_to_insurance_pool() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+
+        client.expire_escrow(&call.id, &4_000_000);
+        assert_eq!(client.get_insurance_pool(&stake_token), 4_000_000);
+    }
+
+    // ── win streaks ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_streak_bonus_grows_and_caps() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        client.set_streak_bonus(&100, &150);
+
+        let mut bonuses = soroban_sdk::vec![&env];
+        for _ in 0..3 {
+            let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+            client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+            env.ledger().set_timestamp(2000);
+            client.resolve_call(&call.id, &1, &150_000_000_i128);
+            let preview = client.get_streak_bonus_bps(&staker);
+            bonuses.push_back(client.record_streak_win(&call.id, &staker));
+            assert_eq!(bonuses.last().unwrap(), preview);
+            env.ledger().set_timestamp(1000);
+        }
+
+        // No bonus for the first win, 1% for the second, capped at 1.5% after
+        assert_eq!(bonuses, soroban_sdk::vec![&env, 0u32, 100, 150]);
+        let streak = client.get_streak(&staker);
+        assert_eq!((streak.current, streak.best), (3, 3));
+    }
+
+    #[test]
+    fn test_reported_loss_breaks_streak() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (won, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        let (lost, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        client.stake_on_call(&staker, &won.id, &10_000_000_i128, &1);
+        client.stake_on_call(&staker, &lost.id, &10_000_000_i128, &2);
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&won.id, &1, &150_000_000_i128);
+        client.record_streak_win(&won.id, &staker);
+
+        // A winning call cannot be reported as a loss
+        let result = client.try_record_streak_loss(&staker, &won.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::StakeNotLosing)));
+
+        // Nor can a call that has not been resolved
+        let result = client.try_record_streak_loss(&staker, &lost.id);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotResolved)));
+
+        client.resolve_call(&lost.id, &1, &150_000_000_i128);
+        let streak = client.record_streak_loss(&staker, &lost.id);
+        assert_eq!((streak.current, streak.best), (0, 1));
+
+        let result = client.try_record_streak_win(&lost.id, &staker);
+        assert_eq!(result, Err(Ok(CallRegistryError::NothingToClaim)));
+    }
+
+    #[test]
+    fn test_replayed_old_loss_keeps_newer_streak() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (old, stake_token) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        let newer = client.create_call(
+            &Address::generate(&env),
+            &crate::types::CallInitArgs {
+                end_ts: 3000,
+                ..default_args(&env, &stake_token)
+            },
+        );
+        client.stake_on_call(&staker, &old.id, &10_000_000_i128, &2);
+        client.stake_on_call(&staker, &newer.id, &10_000_000_i128, &1);
+
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&old.id, &1, &150_000_000_i128);
+        env.ledger().set_timestamp(3000);
+        client.resolve_call(&newer.id, &1, &150_000_000_i128);
+        client.record_streak_win(&newer.id, &staker);
+
+        // The older loss is recorded late but cannot break the newer streak
+        let streak = client.record_streak_loss(&staker, &old.id);
+        assert_eq!((streak.current, streak.last_call_id), (1, newer.id));
+        let result = client.try_record_streak_loss(&staker, &old.id);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(86)
+        );
+        assert_eq!(client.get_streak(&staker).current, 1);
+    }
+
+    #[test]
+    fn test_reputation_contract_receives_call_and_position_reports() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let reputation = env.register(MockReputation, ());
+        client.set_reputation_contract(&Some(reputation.clone()));
+        assert_eq!(client.get_reputation_contract(), Some(reputation.clone()));
+
+        let staker = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+        env.ledger().set_timestamp(2000);
+        client.resolve_call(&call.id, &1, &150_000_000_i128);
+        client.record_streak_win(&call.id, &staker);
+
+        env.as_contract(&reputation, || {
+            let storage = env.storage().instance();
+            let created: (Address, u64, Address) =
+                storage.get(&Symbol::new(&env, "created")).unwrap();
+            assert_eq!(
+                created,
+                (client.address.clone(), call.id, call.creator.clone())
+            );
+            let resolved: (u64, bool) = storage.get(&Symbol::new(&env, "resolved")).unwrap();
+            assert_eq!(resolved, (call.id, false));
+            let position: (u64, Address, i128, bool) =
+                storage.get(&Symbol::new(&env, "position")).unwrap();
+            assert_eq!(position, (call.id, staker.clone(), 10_000_000, true));
+        });
+
+        client.set_reputation_contract(&None);
+        assert_eq!(client.get_reputation_contract(), None);
+    }
+
+    #[test]
+    fn test_rewards_contract_receives_stake_time_of_settled_positions() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let rewards = env.register(MockRewards, ());
+        client.set_rewards_contract(&Some(rewards.clone()));
+        assert_eq!(client.get_rewards_contract(), Some(rewards.clone()));
+
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &winner, 0);
+        mint(&env, &stake_token, &loser, 100_000_000);
+        client.stake_on_call(&winner, &call.id, &10_000_000_i128, &1);
+        env.ledger().set_timestamp(1500);
+        client.stake_on_call(&loser, &call.id, &20_000_000_i128, &2);
+        let call = client.get_call(&call.id);
+        env.ledger().set_timestamp(call.end_ts);
+        client.resolve_call(&call.id, &1, &150_000_000_i128);
+        client.record_streak_win(&call.id, &winner);
+        client.record_streak_loss(&loser, &call.id);
+
+        env.as_contract(&rewards, || {
+            let storage = env.storage().instance();
+            let report: (Address, u64, i128) = storage.get(&winner).unwrap();
+            let held = (call.end_ts - 1000) as i128;
+            assert_eq!(report, (client.address.clone(), call.id, 10_000_000 * held));
+            let report: (Address, u64, i128) = storage.get(&loser).unwrap();
+            assert_eq!(report.2, 20_000_000 * (call.end_ts - 1500) as i128);
+        });
+
+        client.set_rewards_contract(&None);
+        assert_eq!(client.get_rewards_contract(), None);
+    }
+
+    // ── loyalty points ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_loyalty_points_from_volume_and_participation() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+
+        // 2 tokens staked + joining the call
+        client.stake_on_call(&staker, &call.id, &20_000_000_i128, &1);
+        assert_eq!(client.get_loyalty_points(&staker), 12);
+
+        // Topping up earns volume points only
+        client.stake_on_call(&staker, &call.id, &30_000_000_i128, &1);
+        assert_eq!(client.get_loyalty_points(&staker), 15);
+        assert_eq!(client.get_total_loyalty_points(), 15);
+    }
+
+    #[test]
+    fn test_loyalty_snapshot_freezes_balances() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let idle = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+
+        let holders = soroban_sdk::vec![&env, staker.clone(), idle.clone()];
+        let snapshot = client.snapshot_loyalty_points(&holders);
+        assert_eq!(snapshot.id, 1);
+        assert_eq!((snapshot.total_points, snapshot.holders), (11, 2));
+
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+        assert_eq!(client.get_loyalty_points(&staker), 12);
+        assert_eq!(client.get_loyalty_snapshot_points(&1, &staker), 11);
+        assert_eq!(client.get_loyalty_snapshot_points(&1, &idle), 0);
+        assert_eq!(client.get_loyalty_snapshot(&1), Some(snapshot));
+    }
+
+    // ── sponsored staking ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_sponsored_stake_enforces_caps() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let sponsor = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &alice, 0);
+        mint(&env, &stake_token, &bob, 100_000_000);
+        mint(&env, &stake_token, &carol, 100_000_000);
+        client.register_sponsor(&sponsor, &2, &3);
+
+        let amount = 10_000_000_i128;
+        client.sponsored_stake(&sponsor, &alice, &call.id, &amount, &1);
+        client.sponsored_stake(&sponsor, &alice, &call.id, &amount, &1);
+        let result = client.try_sponsored_stake(&sponsor, &alice, &call.id, &amount, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::SponsorCapReached)));
+
+        client.sponsored_stake(&sponsor, &bob, &call.id, &amount, &1);
+        assert_eq!(client.get_sponsor_usage_today(&sponsor), 3);
+        let result = client.try_sponsored_stake(&sponsor, &carol, &call.id, &amount, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::SponsorCapReached)));
+
+        // The daily allowance resets the next day
+        env.ledger().set_timestamp(1000 + 86_400);
+        assert_eq!(client.get_sponsor_usage_today(&sponsor), 0);
+        assert_eq!(client.get_sponsored_count(&sponsor, &alice), 2);
+    }
+
+    #[test]
+    fn test_sponsored_stake_requires_first_time_staker() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let sponsor = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+
+        let amount = 10_000_000_i128;
+        let result = client.try_sponsored_stake(&sponsor, &staker, &call.id, &amount, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::SponsorNotFound)));
+
+        client.register_sponsor(&sponsor, &5, &5);
+        client.stake_on_call(&staker, &call.id, &amount, &1);
+        let result = client.try_sponsored_stake(&sponsor, &staker, &call.id, &amount, &1);
+        assert_eq!(result, Err(Ok(CallRegistryError::NotFirstTimeStaker)));
+
+        client.remove_sponsor(&sponsor);
+        assert_eq!(client.get_sponsor(&sponsor), None);
+    }
+
+    // ── stake entries ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_stake_entries_record_each_stake() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+        env.ledger().set_timestamp(1200);
+        client.stake_on_call(&staker, &call.id, &20_000_000_i128, &2);
+        env.ledger().set_timestamp(1400);
+        client.stake_on_call(&staker, &call.id, &30_000_000_i128, &1);
+
+        let entries = client.get_stake_entries(&call.id, &staker);
+        assert_eq!(entries.len(), 3);
+        let entry = entries.get(1).unwrap();
+        assert_eq!(
+            (entry.position, entry.amount, entry.timestamp),
+            (2, 20_000_000, 1200)
+        );
+    }
+
+    #[test]
+    fn test_reduce_stake_trims_newest_entries() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, _) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+
+        client.stake_on_call(&staker, &call.id, &20_000_000_i128, &1);
+        env.ledger().set_timestamp(1100);
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &2);
+        env.ledger().set_timestamp(1200);
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+
+        // 15 off UP uses up the 10 staked at t=1200 and 5 of the first entry
+        client.reduce_stake(&staker, &call.id, &15_000_000_i128, &1);
+        let entries = client.get_stake_entries(&call.id, &staker);
+        assert_eq!(entries.len(), 2);
+        let first = entries.get(0).unwrap();
+        assert_eq!(
+            (first.position, first.amount, first.timestamp),
+            (1, 15_000_000, 1000)
+        );
+        assert_eq!(entries.get(1).unwrap().position, 2);
+
+        // Switching moves the whole remaining stake into a fresh entry
+        client.switch_position(&staker, &call.id, &1, &2);
+        let entries = client.get_stake_entries(&call.id, &staker);
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|entry| entry.position == 2));
+    }
+
+    // ── creator index ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_get_calls_by_creator_reads_creator_index() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+
+        for who in [&creator, &other, &creator, &other, &creator] {
+            client.create_call(who, &default_args(&env, &stake_token));
+        }
+
+        let calls = client.get_calls_by_creator(&creator, &0, &10).calls;
+        let ids: soroban_sdk::Vec<u64> =
+            soroban_sdk::Vec::from_iter(&env, calls.iter().map(|call| call.id));
+        assert_eq!(ids, soroban_sdk::vec![&env, 1u64, 3, 5]);
+
+        let page = client.get_calls_by_creator_paginated(&creator, &2, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, 3);
+    }
+
+    // ── cursor pagination ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_list_views_page_with_cursor() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+        mint(&env, &stake_token, &staker, 100_000_000);
+
+        for _ in 0..5 {
+            let call = client.create_call(&creator, &default_args(&env, &stake_token));
+            client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+        }
+
+        let first = client.get_calls_by_creator(&creator, &0, &2);
+        assert_eq!(first.calls.len(), 2);
+        assert_eq!(first.next_cursor, Some(2));
+        let last = client.get_calls_by_creator(&creator, &4, &2);
+        assert_eq!(last.calls.get(0).unwrap().id, 5);
+        assert_eq!(last.next_cursor, None);
+
+        let page = client.get_staker_calls(&staker, &3, &10);
+        assert_eq!(page.calls.len(), 2);
+        assert_eq!(page.next_cursor, None);
+
+        // Limits are capped at the page size and cursors past the end are empty
+        let page = client.get_staker_calls(&staker, &0, &u32::MAX);
+        assert_eq!(page.calls.len(), 5);
+        assert!(client.get_staker_calls(&staker, &9, &10).calls.is_empty());
+    }
+
+    // ── active calls ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_active_calls_track_open_calls() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+
+        let resolved = client.create_call(&creator, &default_args(&env, &stake_token));
+        let voided = client.create_call(&creator, &default_args(&env, &stake_token));
+        let mut args = default_args(&env, &stake_token);
+        args.end_ts = 5000;
+        let open = client.create_call(&creator, &args);
+        assert_eq!(client.get_active_calls(&0, &10).calls.len(), 3);
+
+        client.void_call(&admin, &voided.id);
+        env.ledger().set_timestamp(2000);
+        // Past its end but unresolved: still indexed, but not listed
+        assert_eq!(client.get_active_calls(&0, &10).calls.len(), 1);
+
+        client.resolve_call(&resolved.id, &1, &150_000_000_i128);
+        let page = client.get_active_calls(&0, &10);
+        assert_eq!(page.calls.len(), 1);
+        assert_eq!(page.calls.get(0).unwrap().id, open.id);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    // ── end_ts buckets ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_calls_ending_between_reads_hour_buckets() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+
+        for end_ts in [5_000u64, 5_500, 9_000, 20_000] {
+            let mut args = default_args(&env, &stake_token);
+            args.end_ts = end_ts;
+            client.create_call(&creator, &args);
+        }
+
+        let page = client.get_calls_ending_between(&5_200, &10_000, &0, &10);
+        let ids: soroban_sdk::Vec<u64> =
+            soroban_sdk::Vec::from_iter(&env, page.calls.iter().map(|call| call.id));
+        assert_eq!(ids, soroban_sdk::vec![&env, 2u64, 3]);
+
+        let first = client.get_calls_ending_between(&0, &30_000, &0, &3);
+        assert_eq!(first.calls.len(), 3);
+        assert_eq!(first.next_cursor, Some(3));
+        let rest = client.get_calls_ending_between(&0, &30_000, &3, &3);
+        assert_eq!(rest.calls.get(0).unwrap().id, 4);
+        assert_eq!(rest.next_cursor, None);
+
+        assert!(client
+            .get_calls_ending_between(&10_000, &5_000, &0, &10)
+            .calls
+            .is_empty());
+    }
+
+    // ── TTL bumping ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_bump_call_extends_call_ttl() {
+        use soroban_sdk::testutils::storage::Persistent as _;
+
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        let key = crate::storage::DataKey::Call(call.id);
+        let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+        let initial = ttl();
+        env.ledger().with_mut(|li| li.sequence_number += 1_100_000);
+        assert_eq!(ttl(), initial - 1_100_000);
+
+        client.bump_call(&call.id);
+        assert_eq!(ttl(), crate::types::DEFAULT_PERSISTENT_TTL_LEDGERS);
+
+        let result = client.try_bump_call(&99);
+        assert_eq!(result, Err(Ok(CallRegistryError::CallNotFound)));
+    }
+
+    #[test]
+    fn test_set_ttl_params_applies_to_new_entries() {
+        use soroban_sdk::testutils::storage::Persistent as _;
+
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        client.set_ttl_params(&20_000, &40_000, &20_000);
+
+        let config = client.get_config();
+        assert_eq!(config.instance_ttl_ledgers, 20_000);
+        assert_eq!(config.persistent_ttl_ledgers, 40_000);
+        assert_eq!(config.temporary_ttl_ledgers, 20_000);
+
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+        env.as_contract(&client.address, || {
+            let key = crate::storage::DataKey::Call(call.id);
+            assert_eq!(env.storage().persistent().get_ttl(&key), 40_000);
+        });
+
+        // Refreshed only once less than half of the lifetime remains
+        env.ledger().with_mut(|li| li.sequence_number += 20_001);
+        client.bump_call(&call.id);
+        env.as_contract(&client.address, || {
+            let key = crate::storage::DataKey::Call(call.id);
+            assert_eq!(env.storage().persistent().get_ttl(&key), 40_000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #52)")]
+    fn test_set_ttl_params_rejects_ttl_below_minimum() {
+        let (_env, client, _admin, _om) = setup();
+        client.set_ttl_params(&1_000, &40_000, &20_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #52)")]
+    fn test_set_ttl_params_rejects_ttl_above_network_max() {
+        let (env, client, _admin, _om) = setup();
+        let too_long = env.storage().max_ttl() + 1;
+        client.set_ttl_params(&20_000, &too_long, &20_000);
+    }
+
+    // ── call summaries ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_call_summary_reports_totals_and_status() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let staker = Address::generate(&env);
+        let (call, stake_token) = setup_fixed_odds(&env, &client, &admin, &staker, 0);
+        client.stake_on_call(&staker, &call.id, &10_000_000_i128, &1);
+        client.stake_on_call(&staker, &call.id, &5_000_000_i128, &2);
+
+        use crate::types::CallStatus;
+
+        let status = |client: &CallRegistryClient<'_>| {
+            client
+                .get_staker_calls(&staker, &0, &10)
+                .calls
+                .get(0)
+                .unwrap()
+                .status
+        };
+        let summary = client
+            .get_staker_calls(&staker, &0, &10)
+            .calls
+            .get(0)
+            .unwrap();
+        assert_eq!(summary.stake_token, stake_token);
+        assert_eq!(summary.total_stake, 15_000_000);
+        assert_eq!(summary.status, CallStatus::Open);
+
+        env.ledger().set_timestamp(2000);
+        assert_eq!(status(&client), CallStatus::Ended);
+        client.resolve_call(&call.id, &1, &150_000_000_i128);
+        assert_eq!(status(&client), CallStatus::Resolved);
+    }
+
+    // ── event sequence ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_events_carry_consecutive_sequence_numbers() {
+        use soroban_sdk::TryFromVal;
+
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let stake_token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.whitelist_token(&stake_token);
+        mint(&env, &stake_token, &creator, 100_000_000);
+        let mut args = default_args(&env, &stake_token);
+        args.seed_up = 10_000_000;
+        client.create_call(&creator, &args);
+
+        // A seeded create_call emits several events; their sequence numbers run on
+        // without gaps up to the counter
+        let seqs: soroban_sdk::Vec<u64> = soroban_sdk::Vec::from_iter(
+            &env,
+            env.events().all().iter().filter_map(|event| {
+                let seq = event.1.get(2)?;
+                u64::try_from_val(&env, &seq).ok()
+            }),
+        );
+        assert!(seqs.len() > 1);
+        let first = seqs.get(0).unwrap();
+        for (i, seq) in seqs.iter().enumerate() {
+            assert_eq!(seq, first + i as u64);
+        }
+        assert_eq!(client.get_event_sequence(), seqs.last().unwrap());
+    }
+
+    // ── Overflow-checked accounting ──────────────────────────────────────────
+
+    #[test]
+    fn test_stake_overflowing_pool_fails() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        client.stake_on_call(&Address::generate(&env), &call.id, &(i128::MAX / 2), &1);
+        let staker = Address::generate(&env);
+        let result = client.try_stake_on_call(&staker, &call.id, &(i128::MAX / 2 + 2), &2);
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(83)
+        );
+        assert_eq!(client.get_staker_stake(&call.id, &staker, &2), 0);
+    }
+
+    #[test]
+    fn test_pool_at_i128_max_charges_exact_fee() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (call, _) = make_call(&env, &client, &creator);
+
+        let call = client.stake_on_call(&staker, &call.id, &i128::MAX, &1);
+        assert_eq!(call.outcome_stakes.get(1), Some(i128::MAX));
+
+        // 1% of the reduction, computed without overflowing `amount * bps`
+        let amount = i128::MAX - TEST_MIN_STAKE;
+        let call = client.reduce_stake(&staker, &call.id, &amount, &1);
+        assert_eq!(
+            call.penalty_pool,
+            amount / 10_000 * 100 + amount % 10_000 * 100 / 10_000
+        );
+        assert_eq!(call.outcome_stakes.get(1), Some(TEST_MIN_STAKE));
+    }
+
+    #[test]
+    fn test_call_counter_overflow_fails() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let stake_token = env.register(MockToken, ());
+        client.whitelist_token(&stake_token);
+        env.as_contract(&client.address, || {
+            let key = crate::storage::DataKey::CallCounter;
+            env.storage().instance().set(&key, &u64::MAX);
+        });
+
+        let result = client.try_create_call(&creator, &default_args(&env, &stake_token));
+        assert_eq!(
+            result.err().unwrap().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(84)
+        );
+    }
+
+    // ── Storage layout migration ─────────────────────────────────────────────
+
+    /// Rewind the registry's storage to layout 1: version under the string
+    /// key, void-refund flags in instance storage, no indexes, stats or
+    /// roles, and stakers' calls listed in a `Vec`.
+    fn downgrade_to_layout_1(env: &Env, client: &CallRegistryClient, refunded: &[(u64, Address)]) {
+        use crate::storage::DataKey;
+        env.as_contract(&client.address, || {
+            let instance = env.storage().instance();
+            let persistent = env.storage().persistent();
+            instance.remove(&DataKey::ContractVersion);
+            instance.set(&soroban_sdk::Symbol::new(env, "version"), &1u32);
+            instance.remove(&DataKey::StorageLayout);
+            instance.remove(&DataKey::Roles);
+            crate::storage::dec_instance_entry_count(env, 2);
+            persistent.remove(&DataKey::ActiveCalls);
+            for call_id in 1..=crate::storage::get_call_counter(env) {
+                let call = crate::storage::get_call(env, call_id).unwrap();
+                persistent.remove(&DataKey::CreatorCalls(call.creator.clone()));
+                persistent.remove(&DataKey::CallStats(call_id));
+                persistent.remove(&DataKey::EndTsBucket(
+                    call.end_ts / crate::storage::END_TS_BUCKET_SECS,
+                ));
+            }
+            for call_id in 1..=crate::storage::get_call_counter(env) {
+                for staker in crate::storage::get_call_stakers(env, call_id).iter() {
+                    persistent.remove(&DataKey::StakerPortfolio(staker.clone()));
+                    persistent.set(&DataKey::StakerCalls(staker), &vec![env, call_id]);
+                }
+            }
+            for (call_id, staker) in refunded {
+                let key = DataKey::VoidRefundClaimed(*call_id, staker.clone());
+                persistent.remove(&key);
+                instance.set(&key, &true);
+                crate::storage::inc_instance_entry_count(env, 1);
+            }
+        });
+    }
+
+    #[test]
+    fn test_fresh_instance_is_on_current_layout() {
+        let (_env, client, _admin, _om) = setup();
+        assert_eq!(
+            client.get_storage_layout(),
+            crate::storage::STORAGE_LAYOUT_VERSION
+        );
+        assert_eq!(client.migrate(&6, &10), 6);
+        assert_eq!(client.version(), crate::CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn test_version_reads_legacy_upgrade_counter() {
+        let (env, client, _admin, _om) = setup();
+        env.as_contract(&client.address, || {
+            let key = crate::storage::DataKey::ContractVersion;
+            env.storage().instance().set(&key, &3u32);
+        });
+        let version = client.version();
+        assert_eq!((version.major, version.minor, version.patch), (3, 0, 0));
+    }
+
+    #[test]
+    fn test_get_version_reports_build() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_sequence_number(42);
+        let admin = Address::generate(&env);
+        let outcome_manager = Address::generate(&env);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
+        let client = CallRegistryClient::new(&env, &contract_id);
+
+        let info = client.get_version();
+        assert_eq!(info.version, crate::CONTRACT_VERSION);
+        assert_eq!(info.build_id, None);
+        assert_eq!(info.installed_ledger, 42);
+
+        let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+        env.ledger().set_sequence_number(50);
+        env.as_contract(&client.address, || {
+            crate::storage::set_build_info(&env, Some(wasm_hash.clone()));
+        });
+        let info = client.get_version();
+        assert_eq!(info.build_id, Some(wasm_hash));
+        assert_eq!(info.installed_ledger, 50);
+        assert_eq!(client.get_instance_entry_count(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #60)")]
+    fn test_upgrade_rejects_non_increasing_version() {
+        let (env, client, admin, _om) = setup();
+        let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let upgrade = AdminAction::Upgrade(wasm_hash, crate::CONTRACT_VERSION);
+        client.queue_admin_action(&admin, &upgrade);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #51)")]
+    fn test_migrate_rejects_wrong_from_version() {
+        let (_env, client, _admin, _om) = setup();
+        client.migrate(&1, &10);
+    }
+
+    #[test]
+    fn test_migrate_rewrites_layout_1_in_batches() {
+        let (env, client, admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let (voided, _) = make_call(&env, &client, &creator);
+        let (open, _) = make_call(&env, &client, &creator);
+        let (other, _) = make_call(&env, &client, &creator);
+        client.stake_on_call(&staker, &voided.id, &50_000_000_i128, &1);
+        client.void_call(&admin, &voided.id);
+        client.claim_void_refund(&staker, &voided.id);
+        let entries_before = client.get_instance_entry_count();
+
+        downgrade_to_layout_1(&env, &client, &[(voided.id, staker.clone())]);
+        assert_eq!(client.get_storage_layout(), 1);
+        assert_eq!(client.version(), crate::CONTRACT_VERSION);
+
+        assert_eq!(client.migrate(&1, &2), 1);
+        assert_eq!(client.get_storage_layout(), 1);
+        assert_eq!(client.migrate(&1, &2), 6);
+        let topics = env.events().all().last().unwrap().1;
+        assert_eq!(
+            topics.slice(0..2),
+            vec![
+                &env,
+                "call_registry".into_val(&env),
+                "storage_migrated".into_val(&env),
+            ]
+        );
+
+        assert_eq!(client.get_storage_layout(), 6);
+        assert_eq!(client.version(), crate::CONTRACT_VERSION);
+        assert!(client.has_role(&Role::Resolver, &client.get_config().admin));
+        assert_eq!(client.get_instance_entry_count(), entries_before);
+        env.as_contract(&client.address, || {
+            assert!(!env.storage().instance().has(&Symbol::new(&env, "version")));
+            assert!(crate::storage::is_void_refund_claimed(
+                &env, voided.id, &staker
+            ));
+            let ids = crate::storage::get_creator_calls(&env, &creator);
+            assert_eq!(ids, vec![&env, voided.id, open.id, other.id]);
+            let active = crate::storage::get_active_calls(&env);
+            assert_eq!(active, vec![&env, open.id, other.id]);
+        });
+        let ending = client.get_calls_ending_between(&0, &10_000, &0, &10);
+        assert_eq!(ending.calls.len(), 3);
+        env.as_contract(&client.address, || {
+            let stats = crate::storage::get_call_stats(&env, voided.id).unwrap();
+            assert_eq!(stats.total_stakes, 1);
+            assert_eq!(stats.outcome_stakes.get(1), Some(50_000_000));
+            let key = crate::storage::DataKey::StakerCalls(staker.clone());
+            assert!(!env.storage().persistent().has(&key));
+        });
+        let portfolio = client.get_staker_portfolio(&staker, &0, &10);
+        assert_eq!(portfolio.entries.len(), 1);
+        let participation = portfolio.entries.get(0).unwrap().participation;
+        assert_eq!(participation.positions.get(1), Some(50_000_000));
+        assert_eq!(participation.first_stake_ts, 1000);
+    }
+
+    // ── 3-outcome market tests ───────────────────────────────────────────────
+
+    #[test]
+    fn test_create_3_outcome_call_success() {
+        let (env, admin, outcome_manager, creator) = create_test_env();
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
+        let client = CallRegistryClient::new(&env, &contract_id);
+
+        env.ledger().set_timestamp(1000);
+
+        let stake_token = env.register_contract(None, MockToken);
+        client.whitelist_token(&stake_token);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
+        let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
+        let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+
+        let ipfs_cid = Bytes::from_slice(&env, TEST_IPFS_CID);
+        let call = client.create_call(
+            &creator,
+            &crate::types::CallInitArgs {
+                stake_token: stake_token.clone(),
+                stake_amount: TEST_MIN_STAKE,
+                start_price: TEST_START_PRICE,
+                end_ts: 2000u64,
+                token_address: token_address.clone(),
+                pair_id: pair_id.clone(),
+                ipfs_cid: ipfs_cid.clone(),
+                metadata_hash: metadata_hash.clone(),
+                condition: ConditionType::TargetAbove(100_000_000_i128),
+                outcome_count: 3,
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
+        );
+
+        assert_eq!(call.id, 1);
+        assert_eq!(call.outcome_count, 3);
+        assert_eq!(call.outcome_stakes.get(1).unwrap_or(0), 0);
+        assert_eq!(call.outcome_stakes.get(2).unwrap_or(0), 0);
+        assert_eq!(call.outcome_stakes.get(3).unwrap_or(0), 0);
+    }
+
+    #[test]
+    fn test_stake_on_3_outcome_call() {
+        let (env, admin, outcome_manager, creator) = create_test_env();
+        let staker = Address::generate(&env);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
+        let client = CallRegistryClient::new(&env, &contract_id);
+
+        env.ledger().set_timestamp(1000);
+
+        let stake_token = env.register_contract(None, MockToken);
+        client.whitelist_token(&stake_token);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
+        let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
+        let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+
+        let ipfs_cid = Bytes::from_slice(&env, TEST_IPFS_CID);
+        let call = client.create_call(
+            &creator,
+            &crate::types::CallInitArgs {
+                stake_token: stake_token.clone(),
+                stake_amount: TEST_MIN_STAKE,
+                start_price: TEST_START_PRICE,
+                end_ts: 2000u64,
+                token_address: token_address.clone(),
+                pair_id: pair_id.clone(),
+                ipfs_cid: ipfs_cid.clone(),
+                metadata_hash: metadata_hash.clone(),
+                condition: ConditionType::TargetAbove(100_000_000_i128),
+                outcome_count: 3,
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
+        );
+
+        env.budget().reset_unlimited();
+
+        client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
+        client.stake_on_call(&staker, &call.id, &30_000_000_i128, &2);
+        client.stake_on_call(&staker, &call.id, &20_000_000_i128, &3);
+
+        let updated_call = client.get_call(&call.id);
+        assert_eq!(updated_call.outcome_stakes.get(1).unwrap_or(0), 50_000_000);
+        assert_eq!(updated_call.outcome_stakes.get(2).unwrap_or(0), 30_000_000);
+        assert_eq!(updated_call.outcome_stakes.get(3).unwrap_or(0), 20_000_000);
     }
 
     #[test]
     fn test_resolve_3_outcome_call() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
-        let ipfs_cid = Bytes::from_slice(&env, b"QmXxxx");
+        let ipfs_cid = Bytes::from_slice(&env, TEST_IPFS_CID);
         let call = client.create_call(
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
@@ -1500,7 +4820,16 @@ mod call_registry {
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 3,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         env.ledger().set_timestamp(3000); // after end_ts
@@ -1511,27 +4840,50 @@ mod call_registry {
         assert_eq!(resolved.end_price, 150_000_000);
     }
 
+    #[test]
+    fn test_resolve_3_outcome_call_to_position_3() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (_, stake_token) = make_call(&env, &client, &creator);
+        let args = crate::types::CallInitArgs {
+            outcome_count: 3,
+            ..default_args(&env, &stake_token)
+        };
+        let call = client.create_call(&creator, &args);
+        assert_eq!(client.get_call_outcome_count(&call.id), 3);
+        client.set_draw_tolerance(&100u32);
+
+        // Position 3 is a real outcome, never a draw, even inside the band
+        env.ledger().set_timestamp(3000);
+        let draw = backit_shared::OUTCOME_DRAW;
+        let result = client.try_resolve_call(&call.id, &draw, &TEST_START_PRICE);
+        assert_eq!(result, Err(Ok(CallRegistryError::InvalidOutcome)));
+        let resolved = client.resolve_call(&call.id, &3, &TEST_START_PRICE);
+        assert_eq!(resolved.outcome, 3);
+    }
+
     #[test]
     fn test_resolve_3_outcome_call_invalid_outcome_returns_error() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
-        let ipfs_cid = Bytes::from_slice(&env, b"QmXxxx");
+        let ipfs_cid = Bytes::from_slice(&env, TEST_IPFS_CID);
         let call = client.create_call(
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
@@ -1540,7 +4892,16 @@ mod call_registry {
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 3,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         env.ledger().set_timestamp(3000);
@@ -1557,25 +4918,25 @@ mod call_registry {
     fn test_stake_invalid_position_on_3_outcome_call_returns_error() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
-        let ipfs_cid = Bytes::from_slice(&env, b"QmXxxx");
+        let ipfs_cid = Bytes::from_slice(&env, TEST_IPFS_CID);
 
         let call = client.create_call(
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
@@ -1584,7 +4945,16 @@ mod call_registry {
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 3,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         let result = client.try_stake_on_call(&staker, &call.id, &50_000_000_i128, &4);
@@ -1599,15 +4969,15 @@ mod call_registry {
     fn test_get_outcome_stakes() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1615,16 +4985,25 @@ mod call_registry {
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
                 pair_id: pair_id.clone(),
-                ipfs_cid: Bytes::from_slice(&env, b"QmXxxx"),
+                ipfs_cid: Bytes::from_slice(&env, TEST_IPFS_CID),
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 3,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         env.budget().reset_unlimited();
@@ -1637,21 +5016,23 @@ mod call_registry {
         assert_eq!(outcome_stakes.get(1).unwrap_or(0), 50_000_000);
         assert_eq!(outcome_stakes.get(2).unwrap_or(0), 30_000_000);
         assert_eq!(outcome_stakes.get(3).unwrap_or(0), 20_000_000);
+        // No time bonus configured, so weights equal raw stakes
+        assert_eq!(client.get_weighted_outcome_stakes(&call.id), outcome_stakes);
     }
 
     #[test]
     fn test_get_staker_stake_multi_outcome() {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1659,16 +5040,25 @@ mod call_registry {
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
                 pair_id: pair_id.clone(),
-                ipfs_cid: Bytes::from_slice(&env, b"QmXxxx"),
+                ipfs_cid: Bytes::from_slice(&env, TEST_IPFS_CID),
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 3,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         env.budget().reset_unlimited();
@@ -1694,15 +5084,15 @@ mod call_registry {
         let (env, admin, outcome_manager, creator) = create_test_env();
         let staker1 = Address::generate(&env);
         let staker2 = Address::generate(&env);
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        register_test_assets(&env, &client);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1710,16 +5100,25 @@ mod call_registry {
             &creator,
             &crate::types::CallInitArgs {
                 stake_token: stake_token.clone(),
-                stake_amount: 100_000_000_i128,
+                stake_amount: TEST_MIN_STAKE,
                 start_price: TEST_START_PRICE,
                 end_ts: 2000u64,
                 token_address: token_address.clone(),
                 pair_id: pair_id.clone(),
-                ipfs_cid: Bytes::from_slice(&env, b"QmXxxx"),
+                ipfs_cid: Bytes::from_slice(&env, TEST_IPFS_CID),
                 metadata_hash: metadata_hash.clone(),
                 condition: ConditionType::TargetAbove(100_000_000_i128),
                 outcome_count: 3,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         );
 
         env.budget().reset_unlimited();
@@ -1745,15 +5144,14 @@ mod call_registry {
     #[test]
     fn test_creator_stats_increment_on_create() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
         client.whitelist_token(&stake_token);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1768,7 +5166,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1786,7 +5184,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &3000u64,
             &token_address,
             &pair_id,
@@ -1803,14 +5201,13 @@ mod call_registry {
     #[test]
     fn test_creator_stats_resolved_and_correct_on_win() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1821,7 +5218,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1845,14 +5242,13 @@ mod call_registry {
     #[test]
     fn test_creator_stats_resolved_but_not_correct_on_loss() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1863,7 +5259,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1887,14 +5283,13 @@ mod call_registry {
     #[test]
     fn test_creator_stats_multiple_calls_mixed_outcomes() {
         let (env, admin, outcome_manager, creator) = create_test_env();
-        let contract_id = env.register_contract(None, CallRegistry);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, TEST_MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &TEST_MIN_STAKE);
         env.ledger().set_timestamp(1000);
 
         let stake_token = env.register_contract(None, MockToken);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1905,7 +5300,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -1919,7 +5314,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &3000u64,
             &token_address,
             &pair_id,
@@ -1933,7 +5328,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &4000u64,
             &token_address,
             &pair_id,
@@ -1963,19 +5358,20 @@ mod call_registry {
     // ── Storage Stats ─────────────────────────────────────────────────────────
 
     #[test]
-    fn test_get_storage_stats_after_initialize() {
+    fn test_get_storage_stats_after_construction() {
         let (_env, client, _admin, _om) = setup();
         let stats = client.get_storage_stats();
-        // After initialize: Config + version = 2 instance entries
+        // After construction: Config + Roles + version + BuildInfo + StorageLayout
+        // + EventSequence = 6
         assert_eq!(stats.call_count, 0);
-        assert_eq!(stats.instance_entry_count, 2);
-        assert_eq!(stats.estimated_instance_bytes, 2 * 128);
+        assert_eq!(stats.instance_entry_count, 6);
+        assert_eq!(stats.estimated_instance_bytes, 6 * 128);
     }
 
     #[test]
-    fn test_get_instance_entry_count_after_initialize() {
+    fn test_get_instance_entry_count_after_construction() {
         let (_env, client, _admin, _om) = setup();
-        assert_eq!(client.get_instance_entry_count(), 2);
+        assert_eq!(client.get_instance_entry_count(), 6);
     }
 
     #[test]
@@ -1985,7 +5381,7 @@ mod call_registry {
 
         let creator = Address::generate(&env);
         let stake_token = env.register_contract(None, MockToken);
-        let token_address = Address::generate(&env);
+        let token_address = asset_token(&env);
         let pair_id = Bytes::from_slice(&env, b"USDC/XLM");
         let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1995,7 +5391,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &2000u64,
             &token_address,
             &pair_id,
@@ -2006,7 +5402,7 @@ mod call_registry {
             &client,
             &creator,
             &stake_token,
-            &100_000_000_i128,
+            &TEST_MIN_STAKE,
             &3000u64,
             &token_address,
             &pair_id,
@@ -2016,14 +5412,15 @@ mod call_registry {
 
         let stats = client.get_storage_stats();
         assert_eq!(stats.call_count, 2);
-        // Config + version + CallCounter + GlobalStats = 4
-        assert_eq!(stats.instance_entry_count, 4);
-        assert_eq!(stats.estimated_instance_bytes, 4 * 128);
+        // Config + Roles + version + BuildInfo + StorageLayout + EventSequence
+        // + CallCounter + GlobalStats = 8
+        assert_eq!(stats.instance_entry_count, 8);
+        assert_eq!(stats.estimated_instance_bytes, 8 * 128);
     }
 
     #[test]
-    fn test_storage_stats_instance_entry_count_increases_with_void_refund() {
-        let (env, client, _admin, _om) = setup();
+    fn test_storage_stats_instance_entry_count_unchanged_by_void_refund() {
+        let (env, client, admin, _om) = setup();
         env.ledger().set_timestamp(1000);
         let creator = Address::generate(&env);
         let staker = Address::generate(&env);
@@ -2032,12 +5429,31 @@ mod call_registry {
         client.stake_on_call(&staker, &call.id, &50_000_000_i128, &1);
 
         let before = client.get_instance_entry_count();
-        client.void_call(&call.id);
+        client.void_call(&admin, &call.id);
         client.claim_void_refund(&staker, &call.id);
         let after = client.get_instance_entry_count();
 
-        // One new VoidRefundClaimed entry added
-        assert_eq!(after, before + 1);
+        // VoidRefundClaimed is per staker, so it lives in persistent storage
+        assert_eq!(after, before);
+        env.as_contract(&client.address, || {
+            assert!(crate::storage::is_void_refund_claimed(
+                &env, call.id, &staker
+            ));
+        });
+    }
+
+    #[test]
+    fn test_calls_live_in_persistent_storage() {
+        let (env, client, _admin, _om) = setup();
+        env.ledger().set_timestamp(1000);
+        let creator = Address::generate(&env);
+        let (call, _stake_token) = make_call(&env, &client, &creator);
+
+        env.as_contract(&client.address, || {
+            let key = crate::storage::DataKey::Call(call.id);
+            assert!(env.storage().persistent().has(&key));
+            assert!(!env.storage().instance().has(&key));
+        });
     }
 
     #[test]
@@ -2049,11 +5465,12 @@ mod call_registry {
 
         let events = env.events().all();
         let has_warning = events.iter().any(|e| {
-            e.1 == soroban_sdk::vec![
-                &env,
-                "call_registry".into_val(&env),
-                "storage_warning".into_val(&env),
-            ]
+            e.1.slice(0..2)
+                == soroban_sdk::vec![
+                    &env,
+                    "call_registry".into_val(&env),
+                    "storage_warning".into_val(&env),
+                ]
         });
         assert!(!has_warning);
     }
@@ -2105,10 +5522,10 @@ mod native_xlm {
         let admin = Address::generate(&env);
         let outcome_manager = Address::generate(&env);
 
-        let contract_id = env.register(CallRegistry, ());
+        let contract_id = register_registry(&env, &admin, &outcome_manager, MIN_STAKE);
         let client = CallRegistryClient::new(&env, &contract_id);
 
-        client.initialize(&admin, &outcome_manager, &MIN_STAKE);
+        register_test_assets(&env, &client);
 
         // Register a SAC at the sentinel address so token::StellarAssetClient
         // can resolve transfers in the test environment.
@@ -2129,16 +5546,16 @@ mod native_xlm {
         creator: &Address,
         xlm_sentinel: &Address,
     ) -> crate::types::Call {
-        let token_address = Address::generate(env);
+        let token_address = asset_token(env);
         let pair_id = Bytes::from_slice(env, b"XLM/USD");
         let metadata_hash = BytesN::from_array(env, &[0u8; 32]);
-        let ipfs_cid = Bytes::from_slice(env, b"QmXxxx");
+        let ipfs_cid = Bytes::from_slice(env, TEST_IPFS_CID);
 
         client.create_call(
             creator,
             &crate::types::CallInitArgs {
                 stake_token: xlm_sentinel.clone(),
-                stake_amount: STAKE_AMOUNT,
+                stake_amount: MIN_STAKE,
                 start_price: 100_000_000_i128, // start_price
                 end_ts: 10_000u64,             // end_ts
                 token_address,
@@ -2147,7 +5564,16 @@ mod native_xlm {
                 metadata_hash,
                 condition: ConditionType::TargetAbove(105_000_000_i128),
                 outcome_count: 2u32,
-            }
+                parent_call_id: 0,
+                parent_outcome: 0,
+                allow_early_resolution: false,
+                is_private: false,
+                seed_up: 0,
+                seed_down: 0,
+                max_total_stake: 0,
+                max_stake_per_address: 0,
+                stake_model: crate::types::StakeModel::MinimumFree,
+            },
         )
     }
 
@@ -2169,7 +5595,7 @@ mod native_xlm {
         let call = create_xlm_call(&env, &client, &creator, &sentinel);
 
         assert_eq!(call.stake_token, sentinel);
-        assert_eq!(call.stake_amount, STAKE_AMOUNT);
+        assert_eq!(call.stake_amount, MIN_STAKE);
     }
 
     #[test]
@@ -2182,11 +5608,12 @@ mod native_xlm {
 
         let events = env.events().all();
         let has_xlm_event = events.iter().any(|e| {
-            e.1 == soroban_sdk::vec![
-                &env,
-                "call_registry".into_val(&env),
-                "xlm_call_created".into_val(&env),
-            ]
+            e.1.slice(0..2)
+                == soroban_sdk::vec![
+                    &env,
+                    "call_registry".into_val(&env),
+                    "xlm_call_created".into_val(&env),
+                ]
         });
         assert!(has_xlm_event, "xlm_call_created event should be emitted");
     }
@@ -2201,11 +5628,12 @@ mod native_xlm {
 
         let events = env.events().all();
         let has_sac_event = events.iter().any(|e| {
-            e.1 == soroban_sdk::vec![
-                &env,
-                "call_registry".into_val(&env),
-                "call_created".into_val(&env),
-            ]
+            e.1.slice(0..2)
+                == soroban_sdk::vec![
+                    &env,
+                    "call_registry".into_val(&env),
+                    "call_created".into_val(&env),
+                ]
         });
         assert!(
             !has_sac_event,
@@ -2245,18 +5673,19 @@ mod native_xlm {
 
         let events = env.events().all();
         let has_xlm_event = events.iter().any(|e| {
-            e.1 == soroban_sdk::vec![
-                &env,
-                "call_registry".into_val(&env),
-                "xlm_stake_added".into_val(&env),
-            ]
+            e.1.slice(0..2)
+                == soroban_sdk::vec![
+                    &env,
+                    "call_registry".into_val(&env),
+                    "xlm_stake_added".into_val(&env),
+                ]
         });
         assert!(has_xlm_event, "xlm_stake_added event should be emitted");
     }
 
     #[test]
     fn test_void_refund_in_native_xlm_emits_xlm_event() {
-        let (env, client, _admin, _om, xlm_sac) = setup_with_xlm();
+        let (env, client, admin, _om, xlm_sac) = setup_with_xlm();
         let creator = Address::generate(&env);
         let staker = Address::generate(&env);
         let sentinel = xlm_sac.clone();
@@ -2265,23 +5694,24 @@ mod native_xlm {
 
         let call = create_xlm_call(&env, &client, &creator, &sentinel);
         client.stake_on_call(&staker, &call.id, &STAKE_AMOUNT, &1u32);
-        client.void_call(&call.id);
+        client.void_call(&admin, &call.id);
         client.claim_void_refund(&staker, &call.id);
 
         let events = env.events().all();
         let has_xlm_refund = events.iter().any(|e| {
-            e.1 == soroban_sdk::vec![
-                &env,
-                "call_registry".into_val(&env),
-                "xlm_void_refund".into_val(&env),
-            ]
+            e.1.slice(0..2)
+                == soroban_sdk::vec![
+                    &env,
+                    "call_registry".into_val(&env),
+                    "xlm_void_refund".into_val(&env),
+                ]
         });
         assert!(has_xlm_refund, "xlm_void_refund event should be emitted");
     }
 
     #[test]
     fn test_void_refund_in_native_xlm_does_not_emit_sac_event() {
-        let (env, client, _admin, _om, xlm_sac) = setup_with_xlm();
+        let (env, client, admin, _om, xlm_sac) = setup_with_xlm();
         let creator = Address::generate(&env);
         let staker = Address::generate(&env);
         let sentinel = xlm_sac.clone();
@@ -2290,16 +5720,17 @@ mod native_xlm {
 
         let call = create_xlm_call(&env, &client, &creator, &sentinel);
         client.stake_on_call(&staker, &call.id, &STAKE_AMOUNT, &1u32);
-        client.void_call(&call.id);
+        client.void_call(&admin, &call.id);
         client.claim_void_refund(&staker, &call.id);
 
         let events = env.events().all();
         let has_sac_refund = events.iter().any(|e| {
-            e.1 == soroban_sdk::vec![
-                &env,
-                "call_registry".into_val(&env),
-                "void_refund_claimed".into_val(&env),
-            ]
+            e.1.slice(0..2)
+                == soroban_sdk::vec![
+                    &env,
+                    "call_registry".into_val(&env),
+                    "void_refund_claimed".into_val(&env),
+                ]
         });
         assert!(
             !has_sac_refund,
@@ -2328,11 +5759,12 @@ mod native_xlm {
 
         let events = env.events().all();
         let has_xlm_escrow = events.iter().any(|e| {
-            e.1 == soroban_sdk::vec![
-                &env,
-                "call_registry".into_val(&env),
-                "xlm_escrow_released".into_val(&env),
-            ]
+            e.1.slice(0..2)
+                == soroban_sdk::vec![
+                    &env,
+                    "call_registry".into_val(&env),
+                    "xlm_escrow_released".into_val(&env),
+                ]
         });
         assert!(
             has_xlm_escrow,
@@ -2414,7 +5846,12 @@ mod sep10_tests {
         BytesN::from_array(env, &signing_key.verifying_key().to_bytes())
     }
 
-    fn sign(env: &Env, signing_key: &SigningKey, valid_until: u32, home_domain: &[u8]) -> BytesN<64> {
+    fn sign(
+        env: &Env,
+        signing_key: &SigningKey,
+        valid_until: u32,
+        home_domain: &[u8],
+    ) -> BytesN<64> {
         let msg = build_message_native(valid_until, home_domain);
         let sig = signing_key.sign(&msg);
         BytesN::from_array(env, &sig.to_bytes())
@@ -2423,11 +5860,10 @@ mod sep10_tests {
     fn setup() -> (Env, CallRegistryClient<'static>, Address, Address) {
         let env = Env::default();
         env.mock_all_auths();
-        let contract_id = env.register(CallRegistry, ());
-        let client = CallRegistryClient::new(&env, &contract_id);
         let admin = Address::generate(&env);
         let outcome_manager = Address::generate(&env);
-        client.initialize(&admin, &outcome_manager, &0i128);
+        let contract_id = register_registry(&env, &admin, &outcome_manager, 0i128);
+        let client = CallRegistryClient::new(&env, &contract_id);
         (env, client, admin, outcome_manager)
     }
 
@@ -2492,7 +5928,9 @@ mod sep10_tests {
         env.ledger().set_sequence_number(1);
 
         let pubkey = pubkey_to_soroban(&env, &signing_key);
-        let mut sig_bytes = signing_key.sign(&build_message_native(valid_until, home_domain)).to_bytes();
+        let mut sig_bytes = signing_key
+            .sign(&build_message_native(valid_until, home_domain))
+            .to_bytes();
         sig_bytes[0] ^= 0xFF;
         let tampered_token = BytesN::from_array(&env, &sig_bytes);
         let domain_bytes = Bytes::from_slice(&env, home_domain);
@@ -2533,7 +5971,8 @@ mod sep10_tests {
         let token = sign(&env, &signing_key, valid_until, home_domain);
         let domain_bytes = Bytes::from_slice(&env, home_domain);
 
-        let result = client.try_link_sep10_domain(&user, &pubkey, &token, &valid_until, &domain_bytes);
+        let result =
+            client.try_link_sep10_domain(&user, &pubkey, &token, &valid_until, &domain_bytes);
         assert!(result.is_err());
         assert_eq!(client.get_sep10_home_domain(&user), None);
     }
@@ -0,0 +1,237 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use backit_shared::is_valid_fee_bps;
+
+use crate::errors::{CallRegistryError, CallRegistryExtError};
+use crate::events::{
+    emit_admin_action_cancelled, emit_admin_action_executed, emit_admin_action_queued,
+    emit_admin_params_changed_address, emit_admin_params_changed_u32,
+    emit_admin_params_changed_u64, emit_protocol_params_changed, emit_upgraded, PARAM_FEE_BPS,
+    PARAM_FIXED_ODDS_SPREAD, PARAM_OUTCOME_MANAGER, PARAM_REDUCE_FEE, PARAM_SWITCH_FEE,
+    PARAM_TIMELOCK_DELAY,
+};
+use crate::roles::require_role;
+use crate::storage::{
+    extend_storage_ttl, get_config, get_contract_version, get_queued_action, next_admin_action_id,
+    remove_queued_action, set_build_info, set_config, set_contract_version, set_queued_action,
+};
+use crate::types::{
+    AdminAction, ContractConfig, ProtocolParams, QueuedAction, Role, MAX_DISPUTE_WINDOW_SECS,
+    MAX_TIMELOCK_DELAY_SECS, MIN_TTL_LEDGERS,
+};
+use crate::CONTRACT_VERSION;
+
+/// Queue a privileged change. It can be executed once
+/// `timelock_delay_secs` have passed, giving stakers time to exit first.
+/// Arguments are validated here so a change that could never apply is
+/// rejected up front.
+/// # Authorization
+/// `caller` must sign and hold the action's [`required_role`].
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// * [`CallRegistryError::FeeTooHigh`]     – a fee or spread > 10 000 bps.
+/// * [`CallRegistryError::InvalidDurationBounds`] / [`CallRegistryError::InvalidStakeAmount`]
+///   – [`ProtocolParams`] the matching individual setter would reject.
+/// # Panics
+/// * [`CallRegistryExtError::VersionNotIncreasing`] – an upgrade's version is
+///   not above the current one.
+/// * [`CallRegistryExtError::InvalidTimelockDelay`] – a delay above
+///   `MAX_TIMELOCK_DELAY_SECS`.
+/// * [`CallRegistryExtError::InvalidDisputeWindow`] / [`CallRegistryExtError::InvalidTtl`]
+///   – [`ProtocolParams`] out of range.
+pub fn queue_admin_action(
+    env: Env,
+    caller: Address,
+    action: AdminAction,
+) -> Result<u64, CallRegistryError> {
+    let config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    require_role(&env, &caller, required_role(&action));
+
+    validate_action(&env, &action)?;
+
+    let id = next_admin_action_id(&env);
+    let eta = env
+        .ledger()
+        .timestamp()
+        .saturating_add(config.timelock_delay_secs);
+    set_queued_action(
+        &env,
+        &QueuedAction {
+            id,
+            action: action.clone(),
+            eta,
+        },
+    );
+    extend_storage_ttl(&env);
+
+    emit_admin_action_queued(&env, id, &action, eta, &caller);
+
+    Ok(id)
+}
+
+/// Apply a queued change whose delay has elapsed and drop it from the queue.
+/// # Authorization
+/// `caller` must sign and hold the action's [`required_role`].
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// * [`CallRegistryError::FeeTooHigh`]     – a fee or spread > 10 000 bps.
+/// # Panics
+/// * [`CallRegistryExtError::AdminActionNotFound`] – no action with this id.
+/// * [`CallRegistryExtError::TimelockNotElapsed`]  – the ledger is before `eta`.
+/// * [`CallRegistryExtError::VersionNotIncreasing`] – another upgrade has
+///   since installed this version or a newer one.
+pub fn execute_admin_action(env: Env, caller: Address, id: u64) -> Result<(), CallRegistryError> {
+    let mut config = get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    let queued = get_queued_action(&env, id)
+        .unwrap_or_else(|| panic_with_error!(&env, CallRegistryExtError::AdminActionNotFound));
+    require_role(&env, &caller, required_role(&queued.action));
+    if env.ledger().timestamp() < queued.eta {
+        panic_with_error!(&env, CallRegistryExtError::TimelockNotElapsed);
+    }
+    validate_action(&env, &queued.action)?;
+
+    remove_queued_action(&env, id);
+    apply_action(&env, &mut config, &caller, &queued.action);
+    set_config(&env, &config);
+    extend_storage_ttl(&env);
+
+    emit_admin_action_executed(&env, id, &queued.action, &caller);
+
+    Ok(())
+}
+
+/// Drop a queued change without applying it.
+/// # Authorization
+/// `caller` must sign and hold the action's [`required_role`].
+/// # Errors
+/// * [`CallRegistryError::NotInitialized`] – contract not initialised.
+/// # Panics
+/// * [`CallRegistryExtError::AdminActionNotFound`] – no action with this id.
+pub fn cancel_admin_action(env: Env, caller: Address, id: u64) -> Result<(), CallRegistryError> {
+    get_config(&env).ok_or(CallRegistryError::NotInitialized)?;
+
+    let queued = get_queued_action(&env, id)
+        .unwrap_or_else(|| panic_with_error!(&env, CallRegistryExtError::AdminActionNotFound));
+    require_role(&env, &caller, required_role(&queued.action));
+    remove_queued_action(&env, id);
+    extend_storage_ttl(&env);
+
+    emit_admin_action_cancelled(&env, id, &queued.action, &caller);
+
+    Ok(())
+}
+
+/// Fee and spread changes belong to [`Role::FeeSetter`]; everything else
+/// needs [`Role::Admin`].
+pub fn required_role(action: &AdminAction) -> Role {
+    match action {
+        AdminAction::SetFee(_)
+        | AdminAction::SetReduceFee(_)
+        | AdminAction::SetSwitchFee(_)
+        | AdminAction::SetFixedOddsSpread(_) => Role::FeeSetter,
+        AdminAction::SetOutcomeManager(_)
+        | AdminAction::Upgrade(..)
+        | AdminAction::SetTimelockDelay(_)
+        | AdminAction::SetParams(_) => Role::Admin,
+    }
+}
+
+fn validate_action(env: &Env, action: &AdminAction) -> Result<(), CallRegistryError> {
+    match action {
+        AdminAction::SetFee(bps)
+        | AdminAction::SetReduceFee(bps)
+        | AdminAction::SetSwitchFee(bps)
+        | AdminAction::SetFixedOddsSpread(bps) => {
+            if !is_valid_fee_bps(*bps) {
+                return Err(CallRegistryError::FeeTooHigh);
+            }
+        }
+        AdminAction::SetOutcomeManager(_) => {}
+        AdminAction::Upgrade(_, new_version) => {
+            let current = get_contract_version(env).unwrap_or(CONTRACT_VERSION);
+            if *new_version <= current {
+                panic_with_error!(env, CallRegistryExtError::VersionNotIncreasing);
+            }
+        }
+        AdminAction::SetTimelockDelay(secs) => {
+            if *secs > MAX_TIMELOCK_DELAY_SECS {
+                panic_with_error!(env, CallRegistryExtError::InvalidTimelockDelay);
+            }
+        }
+        AdminAction::SetParams(params) => validate_params(env, params)?,
+    }
+    Ok(())
+}
+
+/// Apply the checks the individual setters make to every field at once.
+fn validate_params(env: &Env, params: &ProtocolParams) -> Result<(), CallRegistryError> {
+    if !is_valid_fee_bps(params.fee_bps) {
+        return Err(CallRegistryError::FeeTooHigh);
+    }
+    if params.max_call_duration_secs > 0
+        && params.min_call_duration_secs > params.max_call_duration_secs
+    {
+        return Err(CallRegistryError::InvalidDurationBounds);
+    }
+    if params.min_stake < 0 || params.max_stake_per_user < 0 || params.creator_bond < 0 {
+        return Err(CallRegistryError::InvalidStakeAmount);
+    }
+    if params.dispute_window_secs > MAX_DISPUTE_WINDOW_SECS {
+        panic_with_error!(env, CallRegistryExtError::InvalidDisputeWindow);
+    }
+    let max_ttl = env.storage().max_ttl();
+    for ttl in [
+        params.instance_ttl_ledgers,
+        params.persistent_ttl_ledgers,
+        params.temporary_ttl_ledgers,
+    ] {
+        if !(MIN_TTL_LEDGERS..=max_ttl).contains(&ttl) {
+            panic_with_error!(env, CallRegistryExtError::InvalidTtl);
+        }
+    }
+    Ok(())
+}
+
+fn apply_action(env: &Env, config: &mut ContractConfig, caller: &Address, action: &AdminAction) {
+    match action {
+        AdminAction::SetFee(bps) => {
+            let old = core::mem::replace(&mut config.fee_bps, *bps);
+            emit_admin_params_changed_u32(env, PARAM_FEE_BPS, caller, old, *bps);
+        }
+        AdminAction::SetReduceFee(bps) => {
+            let old = core::mem::replace(&mut config.reduce_fee_bps, *bps);
+            emit_admin_params_changed_u32(env, PARAM_REDUCE_FEE, caller, old, *bps);
+        }
+        AdminAction::SetSwitchFee(bps) => {
+            let old = core::mem::replace(&mut config.switch_fee_bps, *bps);
+            emit_admin_params_changed_u32(env, PARAM_SWITCH_FEE, caller, old, *bps);
+        }
+        AdminAction::SetFixedOddsSpread(bps) => {
+            let old = core::mem::replace(&mut config.fixed_odds_spread_bps, *bps);
+            emit_admin_params_changed_u32(env, PARAM_FIXED_ODDS_SPREAD, caller, old, *bps);
+        }
+        AdminAction::SetOutcomeManager(manager) => {
+            let old = core::mem::replace(&mut config.outcome_manager, manager.clone());
+            emit_admin_params_changed_address(env, PARAM_OUTCOME_MANAGER, caller, &old, manager);
+        }
+        AdminAction::Upgrade(wasm_hash, new_version) => {
+            let old_version = get_contract_version(env).unwrap_or(CONTRACT_VERSION);
+            env.deployer()
+                .update_current_contract_wasm(wasm_hash.clone());
+            set_contract_version(env, new_version);
+            set_build_info(env, Some(wasm_hash.clone()));
+            emit_upgraded(env, old_version, *new_version, wasm_hash, caller);
+        }
+        AdminAction::SetTimelockDelay(secs) => {
+            let old = core::mem::replace(&mut config.timelock_delay_secs, *secs);
+            emit_admin_params_changed_u64(env, PARAM_TIMELOCK_DELAY, caller, old, *secs);
+        }
+        AdminAction::SetParams(params) => {
+            let old = config.params();
+            config.apply_params(params);
+            emit_protocol_params_changed(env, &old, params, caller);
+        }
+    }
+}
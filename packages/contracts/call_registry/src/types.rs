@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, Vec};
 
 /// Describes the condition used to determine whether a call resolves as UP.
 #[contracttype]
@@ -11,6 +11,16 @@ pub enum ConditionType {
     Range(i128, i128),
 }
 
+/// How a call's `stake_amount` constrains individual stakes.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum StakeModel {
+    /// Every stake must equal `stake_amount` exactly (fixed-ticket lottery).
+    FixedTicket,
+    /// `stake_amount` is the minimum; stakers choose any larger size.
+    MinimumFree,
+}
+
 /// Arguments for initializing a new Call
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -25,6 +35,26 @@ pub struct CallInitArgs {
     pub metadata_hash: BytesN<32>,
     pub condition: ConditionType,
     pub outcome_count: u32,
+    /// Call this call is conditional on; `0` for an unconditional call
+    pub parent_call_id: u64,
+    /// Parent outcome under which this call stays active (ignored when
+    /// `parent_call_id` is `0`)
+    pub parent_outcome: u32,
+    /// Opt in to resolving before `end_ts` once a threshold target is crossed
+    /// (only valid for `TargetAbove` / `TargetBelow` conditions)
+    pub allow_early_resolution: bool,
+    /// Restrict staking to addresses the creator adds to the call's allowlist
+    pub is_private: bool,
+    /// Creator liquidity placed on UP at creation; `0` for none
+    pub seed_up: i128,
+    /// Creator liquidity placed on DOWN at creation; `0` for none
+    pub seed_down: i128,
+    /// Cap on the call's total pool across all outcomes; `0` means unlimited
+    pub max_total_stake: i128,
+    /// Cap on any one address's stake across all outcomes; `0` means unlimited
+    pub max_stake_per_address: i128,
+    /// Whether `stake_amount` is a fixed ticket size or a minimum
+    pub stake_model: StakeModel,
 }
 
 /// Represents a prediction call with all its metadata
@@ -37,7 +67,7 @@ pub struct Call {
     pub creator: Address,
     /// Token address used for staking
     pub stake_token: Address,
-    /// Amount of stake required to participate
+    /// Minimum stake, or the exact ticket size under `StakeModel::FixedTicket`
     pub stake_amount: i128,
     /// Timestamp when the call ends
     pub end_ts: u64,
@@ -72,6 +102,31 @@ pub struct Call {
     pub metadata_version: u32,
     /// Map of outcome indices to the deployed share token contract addresses
     pub share_tokens: Map<u32, Address>,
+    /// Call this call is conditional on; `0` = unconditional
+    pub parent_call_id: u64,
+    /// Parent outcome required for this call to stand; any other parent
+    /// result voids it
+    pub parent_outcome: u32,
+    /// Whether the creator opted in to early resolution on target hit
+    pub allow_early_resolution: bool,
+    /// Whether the call was resolved before `end_ts` because its target was hit
+    pub early_resolved: bool,
+    /// Whether staking is restricted to the creator-managed allowlist
+    pub is_private: bool,
+    /// Map of outcome indices to time-weighted stake totals, used to split
+    /// the losing pool among winners
+    pub weighted_outcome_stakes: Map<u32, i128>,
+    /// Total seconds `end_ts` has been pushed back by anti-sniping extensions
+    pub deadline_extended_secs: u64,
+    /// Cap on the call's total pool across all outcomes; `0` means unlimited
+    pub max_total_stake: i128,
+    /// Cap on any one address's stake across all outcomes; `0` means unlimited
+    pub max_stake_per_address: i128,
+    /// Whether `stake_amount` is a fixed ticket size or a minimum
+    pub stake_model: StakeModel,
+    /// Fees from reduced or switched stakes; paid to winners on top of the
+    /// losing pool, or swept into the insurance pool if the call has none
+    pub penalty_pool: i128,
 }
 
 /// Enum representing stake positions on a call
@@ -117,13 +172,344 @@ pub struct ContractConfig {
     pub whitelisted_tokens: Map<Address, bool>,
     pub min_stake: i128,
     pub metadata_version: u32,
-    /// When true, create/stake/resolve operations are blocked.
-    pub paused: bool,
+    /// Which groups of entrypoints are currently paused.
+    pub pause_state: PauseState,
     /// Number of seconds before `end_ts` during which staking is no longer
     /// accepted. Default: 300 (5 minutes). Set to 0 to disable the buffer.
     pub staking_cutoff_secs: u64,
     /// Wasm hash for the share token contract (if enabled)
     pub share_wasm_hash: Option<BytesN<32>>,
+    /// Maximum |end − start| price move, in basis points of the start price,
+    /// for which a binary call resolves as DRAW. `0` disables draws.
+    pub draw_tolerance_bps: u32,
+    /// Minimum `end_ts − now` accepted by `create_call`. `0` means no minimum.
+    pub min_call_duration_secs: u64,
+    /// Maximum `end_ts − now` accepted by `create_call`. `0` means no maximum.
+    pub max_call_duration_secs: u64,
+    /// Extra weight, in basis points, given to a stake placed at creation.
+    /// The bonus decays linearly to zero at `end_ts` (e.g. 5000 = 1.5x → 1.0x).
+    /// `0` disables time weighting.
+    pub max_time_bonus_bps: u32,
+    /// Window before the staking deadline in which a large stake extends the
+    /// call. `0` disables anti-sniping.
+    pub snipe_window_secs: u64,
+    /// Minimum share of the pool, in basis points, for a stake to count as large.
+    pub snipe_threshold_bps: u32,
+    /// Seconds added to `end_ts` for each triggering stake.
+    pub snipe_extension_secs: u64,
+    /// Cap on the total extension of any single call.
+    pub max_snipe_extension_secs: u64,
+    /// Maximum distinct stakers per call, keeping the embedded stake maps
+    /// within ledger entry size limits. `0` means unlimited.
+    pub max_stakers_per_call: u32,
+    /// Fee, in basis points of the withdrawn amount, charged by `reduce_stake`.
+    /// The fee stays in the call's `penalty_pool`.
+    pub reduce_fee_bps: u32,
+    /// Fee, in basis points of the moved stake, charged by `switch_position`.
+    /// The fee stays in the call's `penalty_pool`.
+    pub switch_fee_bps: u32,
+    /// Spread, in basis points, taken off the fair odds quoted to
+    /// fixed-odds stakes.
+    pub fixed_odds_spread_bps: u32,
+    /// Share, in basis points, of the protocol fee on a referred staker's
+    /// winnings that accrues to their referrer.
+    pub referral_share_bps: u32,
+    /// Payout bonus, in basis points, per consecutive prior win
+    pub streak_bonus_bps: u32,
+    /// Cap on the total win-streak payout bonus, in basis points
+    pub max_streak_bonus_bps: u32,
+    /// Lifetime, in ledgers, that instance storage is extended to on each
+    /// write. Extension kicks in once less than half of it remains; the same
+    /// rule applies to the two TTLs below.
+    pub instance_ttl_ledgers: u32,
+    /// Lifetime, in ledgers, of persistent entries: calls, their indexes and
+    /// per-staker records
+    pub persistent_ttl_ledgers: u32,
+    /// Lifetime, in ledgers, of temporary entries such as sponsor day counters
+    pub temporary_ttl_ledgers: u32,
+    /// Seconds a queued [`AdminAction`] must wait before it can be executed
+    pub timelock_delay_secs: u64,
+    /// Bond, in the call's stake token, a creator escrows with each new call.
+    /// Returned once the call closes unless the admin slashes it first. `0`
+    /// disables the bond.
+    pub creator_bond: i128,
+    /// Most calls one creator may open within `call_rate_window_secs`.
+    /// `0` disables the limit.
+    pub max_calls_per_window: u32,
+    /// Length of the rolling window `max_calls_per_window` applies to
+    pub call_rate_window_secs: u64,
+    /// Seconds after `resolve_call` during which the admin may correct the
+    /// outcome and claims are held back. `0` makes resolutions final at once.
+    pub dispute_window_secs: u64,
+}
+
+/// A price series calls can be made on. `create_call` only accepts a
+/// `pair_id` the admin has registered, so calls on a mistyped pair that no
+/// oracle will ever report are rejected up front.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PredictedAsset {
+    /// Token whose price the pair tracks; calls must name the same address
+    pub token_address: Address,
+    pub pair_id: Bytes,
+    /// Decimal places of prices reported for this pair
+    pub decimals: u32,
+    /// Identifier of the pair on the oracle's price feed
+    pub price_feed_id: Bytes,
+}
+
+/// The protocol's economic and storage tunables, read and written as one
+/// unit. Each field mirrors the [`ContractConfig`] field of the same name.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolParams {
+    pub fee_bps: u32,
+    pub min_call_duration_secs: u64,
+    pub max_call_duration_secs: u64,
+    /// Grace period before `end_ts` in which staking is closed
+    pub staking_cutoff_secs: u64,
+    /// Window after resolution in which claims wait for disputes
+    pub dispute_window_secs: u64,
+    pub min_stake: i128,
+    pub max_stake_per_user: i128,
+    pub max_stakers_per_call: u32,
+    pub creator_bond: i128,
+    pub instance_ttl_ledgers: u32,
+    pub persistent_ttl_ledgers: u32,
+    pub temporary_ttl_ledgers: u32,
+}
+
+impl ContractConfig {
+    /// The tunables currently in force
+    pub fn params(&self) -> ProtocolParams {
+        ProtocolParams {
+            fee_bps: self.fee_bps,
+            min_call_duration_secs: self.min_call_duration_secs,
+            max_call_duration_secs: self.max_call_duration_secs,
+            staking_cutoff_secs: self.staking_cutoff_secs,
+            dispute_window_secs: self.dispute_window_secs,
+            min_stake: self.min_stake,
+            max_stake_per_user: self.max_stake_per_user,
+            max_stakers_per_call: self.max_stakers_per_call,
+            creator_bond: self.creator_bond,
+            instance_ttl_ledgers: self.instance_ttl_ledgers,
+            persistent_ttl_ledgers: self.persistent_ttl_ledgers,
+            temporary_ttl_ledgers: self.temporary_ttl_ledgers,
+        }
+    }
+
+    /// Overwrite every tunable covered by [`ProtocolParams`]
+    pub fn apply_params(&mut self, params: &ProtocolParams) {
+        self.fee_bps = params.fee_bps;
+        self.min_call_duration_secs = params.min_call_duration_secs;
+        self.max_call_duration_secs = params.max_call_duration_secs;
+        self.staking_cutoff_secs = params.staking_cutoff_secs;
+        self.dispute_window_secs = params.dispute_window_secs;
+        self.min_stake = params.min_stake;
+        self.max_stake_per_user = params.max_stake_per_user;
+        self.max_stakers_per_call = params.max_stakers_per_call;
+        self.creator_bond = params.creator_bond;
+        self.instance_ttl_ledgers = params.instance_ttl_ledgers;
+        self.persistent_ttl_ledgers = params.persistent_ttl_ledgers;
+        self.temporary_ttl_ledgers = params.temporary_ttl_ledgers;
+    }
+}
+
+/// A privileged change that must sit in the timelock queue for
+/// [`ContractConfig::timelock_delay_secs`] before it takes effect
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    /// Protocol fee, in basis points
+    SetFee(u32),
+    /// `reduce_stake` fee, in basis points
+    SetReduceFee(u32),
+    /// `switch_position` fee, in basis points
+    SetSwitchFee(u32),
+    /// Fixed-odds spread, in basis points
+    SetFixedOddsSpread(u32),
+    SetOutcomeManager(Address),
+    /// New WASM hash and the semantic version of that code
+    Upgrade(BytesN<32>, SemVer),
+    SetTimelockDelay(u64),
+    /// Replace every [`ProtocolParams`] tunable at once
+    SetParams(ProtocolParams),
+}
+
+/// An [`AdminAction`] waiting in the timelock queue
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAction {
+    pub id: u64,
+    pub action: AdminAction,
+    /// Earliest ledger timestamp at which the action can be executed
+    pub eta: u64,
+}
+
+/// An admin's pending or completed sweep of a call's escrow
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawal {
+    pub call_id: u64,
+    /// Earliest ledger timestamp at which the sweep can be executed
+    pub eta: u64,
+    /// Set once the escrow has been moved out
+    pub executed: bool,
+}
+
+/// Semantic version of the deployed registry code. Fields compare in
+/// declaration order, so `1.2.0 < 1.10.0 < 2.0.0`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct SemVer {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+/// Build installed on this instance, recorded at construction and on every
+/// upgrade.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildInfo {
+    /// Wasm hash installed by the most recent upgrade; `None` while the
+    /// originally deployed code is still running.
+    pub build_id: Option<BytesN<32>>,
+    /// Ledger sequence at which this build was installed.
+    pub installed_ledger: u32,
+}
+
+/// Version and build of the running code, as returned by `get_version`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionInfo {
+    pub version: SemVer,
+    pub build_id: Option<BytesN<32>>,
+    pub installed_ledger: u32,
+}
+
+/// An operational duty that can be granted to accounts other than the admin
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Grants and revokes the other roles; held by `ContractConfig::admin`
+    Admin,
+    /// Pauses and unpauses components
+    Pauser,
+    /// Voids calls that cannot be resolved
+    Resolver,
+    /// Deposits and withdraws house liquidity
+    TreasuryManager,
+    /// Queues, executes and cancels timelocked fee and spread changes
+    FeeSetter,
+}
+
+/// A group of entrypoints that can be paused on its own
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseComponent {
+    /// `create_call`
+    Create,
+    /// Placing, committing, reducing and switching stakes
+    Stake,
+    /// `resolve_call` and `mark_settled`
+    Resolve,
+    /// Payouts, refunds, share redemptions and referral rewards
+    Claim,
+}
+
+/// Pause flags per [`PauseComponent`]
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PauseState {
+    pub create: bool,
+    pub stake: bool,
+    pub resolve: bool,
+    pub claim: bool,
+}
+
+impl PauseState {
+    pub fn is_paused(&self, component: PauseComponent) -> bool {
+        match component {
+            PauseComponent::Create => self.create,
+            PauseComponent::Stake => self.stake,
+            PauseComponent::Resolve => self.resolve,
+            PauseComponent::Claim => self.claim,
+        }
+    }
+
+    pub fn set(&mut self, component: PauseComponent, paused: bool) {
+        match component {
+            PauseComponent::Create => self.create = paused,
+            PauseComponent::Stake => self.stake = paused,
+            PauseComponent::Resolve => self.resolve = paused,
+            PauseComponent::Claim => self.claim = paused,
+        }
+    }
+}
+
+/// A follower's standing order to back every new call by `creator`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Follow {
+    pub follower: Address,
+    pub creator: Address,
+    /// Only calls staked in this token are copied
+    pub stake_token: Address,
+    /// Amount staked on UP for each copied call
+    pub amount_per_call: i128,
+    /// Deposited funds not yet staked
+    pub budget: i128,
+    /// Maximum number of calls to copy; `0` means until the budget runs out
+    pub max_calls: u32,
+    /// Number of calls copied so far
+    pub calls_copied: u32,
+}
+
+/// Funds escrowed behind a hidden position, awaiting `reveal_stake`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeCommit {
+    /// `sha256(position ‖ salt ‖ amount)`, see `stake_commitment`
+    pub commitment: BytesN<32>,
+    pub amount: i128,
+    pub committed_at: u64,
+}
+
+/// A stake taken against the house at odds locked when it was placed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixedOddsTicket {
+    pub position: u32,
+    pub amount: i128,
+    /// Locked payout multiplier in basis points (18_000 = 1.8×)
+    pub odds_bps: i128,
+    /// Amount paid out if `position` wins
+    pub payout: i128,
+}
+
+/// The house's fixed-odds exposure on a call.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixedOddsBook {
+    /// Total fixed-odds stakes collected
+    pub stakes: i128,
+    /// Map of outcome indices to the payouts owed if that outcome wins
+    pub liabilities: Map<u32, i128>,
+    /// House liquidity held back to cover the worst-case outcome
+    pub reserved: i128,
+    /// Whether the house's share has been returned to its liquidity
+    pub settled: bool,
+}
+
+/// The position token for one outcome of a call, as seen by secondary markets.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionTokenInfo {
+    pub token: Address,
+    pub stake_token: Address,
+    /// Whether the call is still unresolved and not voided or cancelled
+    pub tradable: bool,
 }
 
 /// Contract-wide aggregated statistics for dashboards.
@@ -135,7 +521,7 @@ pub struct GlobalStats {
     pub total_unique_stakers: u64,
 }
 
-/// Statistics for a call
+/// Statistics for a call, kept up to date on every stake and withdrawal
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct CallStats {
@@ -145,6 +531,8 @@ pub struct CallStats {
     pub outcome_stake_counts: Map<u32, u32>,
     /// Total number of stakes across all outcomes
     pub total_stakes: u32,
+    /// Ledger timestamp of the most recent stake; `0` = none recorded yet
+    pub last_stake_ts: u64,
 }
 
 /// Creator reputation statistics tracked on-chain
@@ -156,6 +544,219 @@ pub struct CreatorStats {
     pub total_correct: u32,
 }
 
+/// Default for [`ContractConfig::max_stakers_per_call`]. Each staker adds an
+/// entry to the `Call`'s stake maps, which share one persistent ledger entry.
+pub const DEFAULT_MAX_STAKERS_PER_CALL: u32 = 500;
+
+/// Default for [`ContractConfig::reduce_fee_bps`] (1%).
+pub const DEFAULT_REDUCE_FEE_BPS: u32 = 100;
+
+/// Default for [`ContractConfig::switch_fee_bps`] (2%).
+pub const DEFAULT_SWITCH_FEE_BPS: u32 = 200;
+
+/// Default for [`ContractConfig::fixed_odds_spread_bps`] (5%).
+pub const DEFAULT_FIXED_ODDS_SPREAD_BPS: u32 = 500;
+
+/// Default for [`ContractConfig::referral_share_bps`] (10%).
+pub const DEFAULT_REFERRAL_SHARE_BPS: u32 = 1_000;
+
+/// Default for [`ContractConfig::streak_bonus_bps`] (1% per prior win).
+pub const DEFAULT_STREAK_BONUS_BPS: u32 = 100;
+
+/// Default for [`ContractConfig::max_streak_bonus_bps`] (5%).
+pub const DEFAULT_MAX_STREAK_BONUS_BPS: u32 = 500;
+
+/// Default for [`ContractConfig::instance_ttl_ledgers`] (~7 days at 5s per ledger).
+pub const DEFAULT_INSTANCE_TTL_LEDGERS: u32 = 120_960;
+
+/// Default for [`ContractConfig::persistent_ttl_ledgers`] (~120 days).
+pub const DEFAULT_PERSISTENT_TTL_LEDGERS: u32 = 2_073_600;
+
+/// Default for [`ContractConfig::temporary_ttl_ledgers`] (~2 days).
+pub const DEFAULT_TEMPORARY_TTL_LEDGERS: u32 = 34_560;
+
+/// Longest `pair_id` a call may reference, in bytes.
+pub const MAX_PAIR_ID_LEN: u32 = 32;
+
+/// Shortest `ipfs_cid` accepted by `create_call`, in bytes.
+pub const MIN_IPFS_CID_LEN: u32 = 32;
+
+/// Longest `ipfs_cid` accepted by `create_call`, in bytes.
+pub const MAX_IPFS_CID_LEN: u32 = 64;
+
+/// Most decimal places a [`PredictedAsset`] may declare.
+pub const MAX_ASSET_DECIMALS: u32 = 18;
+
+/// Default for [`ContractConfig::timelock_delay_secs`] (48 hours).
+pub const DEFAULT_TIMELOCK_DELAY_SECS: u64 = 172_800;
+
+/// Longest dispute window the admin may configure (7 days).
+pub const MAX_DISPUTE_WINDOW_SECS: u64 = 604_800;
+
+/// Delay between initiating and executing an emergency withdrawal (7 days).
+pub const EMERGENCY_WITHDRAW_DELAY_SECS: u64 = 604_800;
+
+/// How long a voided or cancelled call's creator bond stays slashable
+/// before the creator may reclaim it (7 days).
+pub const CREATOR_BOND_SLASH_GRACE_SECS: u64 = 604_800;
+
+/// Longest timelock delay the admin may configure (30 days).
+pub const MAX_TIMELOCK_DELAY_SECS: u64 = 2_592_000;
+
+/// Default for [`ContractConfig::call_rate_window_secs`] (24 hours).
+pub const DEFAULT_CALL_RATE_WINDOW_SECS: u64 = 86_400;
+
+/// Longest call rate window the admin may configure (24 hours), so its
+/// counters never outlive `MIN_TTL_LEDGERS`.
+pub const MAX_CALL_RATE_WINDOW_SECS: u64 = 86_400;
+
+/// Number of time buckets a call rate window is split into. Calls are
+/// counted per bucket, so the window slides one bucket at a time.
+pub const CALL_RATE_BUCKETS: u64 = 24;
+
+/// Shortest TTL the admin may configure (~1 day), so sponsor day and call
+/// rate counters outlive the period they count.
+pub const MIN_TTL_LEDGERS: u32 = 17_280;
+
+/// A staker's run of consecutive winning calls
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Streak {
+    /// Consecutive wins since the last recorded loss
+    pub current: u32,
+    /// Longest streak the staker has reached
+    pub best: u32,
+    /// Latest-closing call whose win or loss moved the streak; `0` = none yet
+    pub last_call_id: u64,
+}
+
+/// Stake volume that earns one loyalty point: one whole token at Stellar's
+/// standard 7 decimals.
+pub const LOYALTY_VOLUME_UNIT: i128 = 10_000_000;
+
+/// Loyalty points for joining a call, on top of the volume points.
+pub const LOYALTY_PARTICIPATION_POINTS: i128 = 10;
+
+/// Record of an admin snapshot of loyalty points, e.g. for an airdrop
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoyaltySnapshot {
+    pub id: u32,
+    pub taken_at: u64,
+    /// Points issued across all addresses when the snapshot was taken
+    pub total_points: i128,
+    /// Number of addresses whose balances were recorded
+    pub holders: u32,
+}
+
+/// A paymaster that covers transaction fees for first-time stakers by
+/// fee-bumping their stake transactions. The caps are enforced on-chain
+/// through [`CallRegistry::sponsored_stake`](crate::CallRegistry::sponsored_stake).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sponsor {
+    pub sponsor: Address,
+    /// Sponsored stakes allowed per staker
+    pub per_user_cap: u32,
+    /// Sponsored stakes allowed per UTC day, across all stakers
+    pub daily_cap: u32,
+}
+
+/// One stake placed by a staker on a call. A staker's entries on a call
+/// are kept in order; their sum per position is the cumulative stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeEntry {
+    pub position: u32,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Lifecycle stage of a call, as reported in [`CallSummary`]
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CallStatus {
+    /// Accepting stakes until `end_ts`
+    Open = 0,
+    /// Past `end_ts` and awaiting resolution
+    Ended = 1,
+    /// Outcome recorded; payouts are being claimed
+    Resolved = 2,
+    /// Fully settled by the outcome manager
+    Settled = 3,
+    /// Voided; stakers claim refunds
+    Voided = 4,
+    /// Cancelled by its creator
+    Cancelled = 5,
+}
+
+/// Compact view of a call for list endpoints, without the per-staker maps.
+/// Use `get_call` for full detail.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallSummary {
+    pub id: u64,
+    pub creator: Address,
+    pub stake_token: Address,
+    /// Token pair being predicted
+    pub token_address: Address,
+    /// Map of outcome indices to total stake amounts
+    pub outcome_stakes: Map<u32, i128>,
+    /// Pool across all outcomes
+    pub total_stake: i128,
+    pub end_ts: u64,
+    /// Resolved outcome: 0 = unresolved
+    pub outcome: u32,
+    pub status: CallStatus,
+}
+
+/// One page of a call listing. Pass `next_cursor` back to fetch the next
+/// page; `None` means the listing is exhausted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallPage {
+    pub calls: Vec<CallSummary>,
+    pub next_cursor: Option<u32>,
+}
+
+/// One page of an address listing; see [`CallPage`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressPage {
+    pub addresses: Vec<Address>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A staker's aggregate position on one call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParticipationSummary {
+    /// Map of outcome indices to the staker's current stake
+    pub positions: Map<u32, i128>,
+    /// Current stake across all outcomes
+    pub total_stake: i128,
+    /// Ledger timestamp of the staker's first stake; `0` = unknown
+    pub first_stake_ts: u64,
+    /// Ledger timestamp of the staker's latest stake; `0` = unknown
+    pub last_stake_ts: u64,
+}
+
+/// One call in a staker's portfolio
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PortfolioEntry {
+    pub call: CallSummary,
+    pub participation: ParticipationSummary,
+}
+
+/// One page of a staker's portfolio; see [`CallPage`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PortfolioPage {
+    pub entries: Vec<PortfolioEntry>,
+    pub next_cursor: Option<u32>,
+}
+
 /// Instance storage is capped at 64 KB. Warn when entry count exceeds this.
 pub const INSTANCE_ENTRY_WARNING_THRESHOLD: u32 = 500;
 
@@ -0,0 +1,46 @@
+use soroban_sdk::{panic_with_error, Bytes, Env};
+
+use crate::errors::CallRegistryExtError;
+use crate::types::{MAX_IPFS_CID_LEN, MAX_PAIR_ID_LEN, MIN_IPFS_CID_LEN};
+
+/// Check that `pair_id` reads as `BASE/QUOTE`: at most [`MAX_PAIR_ID_LEN`]
+/// bytes of ASCII letters, digits, `-`, `_` or `.`, split by exactly one `/`
+/// with a non-empty symbol on each side.
+///
+/// # Panics
+/// * [`CallRegistryExtError::InvalidPairId`] – `pair_id` breaks any of the above.
+pub fn validate_pair_id(env: &Env, pair_id: &Bytes) {
+    let len = pair_id.len();
+    if len == 0 || len > MAX_PAIR_ID_LEN {
+        panic_with_error!(env, CallRegistryExtError::InvalidPairId);
+    }
+
+    let mut separator = None;
+    for (i, byte) in pair_id.iter().enumerate() {
+        match byte {
+            b'/' if separator.is_none() => separator = Some(i as u32),
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' => {}
+            _ => panic_with_error!(env, CallRegistryExtError::InvalidPairId),
+        }
+    }
+    match separator {
+        Some(at) if at > 0 && at < len - 1 => {}
+        _ => panic_with_error!(env, CallRegistryExtError::InvalidPairId),
+    }
+}
+
+/// Check that `ipfs_cid` is a plausible text-encoded CID: between
+/// [`MIN_IPFS_CID_LEN`] and [`MAX_IPFS_CID_LEN`] bytes, all ASCII letters or
+/// digits. This covers base58 CIDv0 (`Qm…`, 46 bytes) and base32 CIDv1
+/// (`b…`, 59 bytes) without decoding them.
+///
+/// # Panics
+/// * [`CallRegistryExtError::InvalidIpfsCid`] – `ipfs_cid` breaks any of the above.
+pub fn validate_ipfs_cid(env: &Env, ipfs_cid: &Bytes) {
+    let len = ipfs_cid.len();
+    if !(MIN_IPFS_CID_LEN..=MAX_IPFS_CID_LEN).contains(&len)
+        || !ipfs_cid.iter().all(|byte| byte.is_ascii_alphanumeric())
+    {
+        panic_with_error!(env, CallRegistryExtError::InvalidIpfsCid);
+    }
+}
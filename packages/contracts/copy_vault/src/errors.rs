@@ -0,0 +1,37 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum CopyVaultError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// The caller is not the vault's keeper or strategist.
+    Unauthorized = 3,
+    /// A basis-point setting exceeds 10 000.
+    InvalidBps = 4,
+    /// A deposit or share amount is not positive.
+    InvalidAmount = 5,
+    /// The deposit is too small to mint a share.
+    ZeroShares = 6,
+    /// The depositor holds fewer shares than requested.
+    InsufficientShares = 7,
+    /// The strategist has no stake on the call position.
+    NothingToMirror = 8,
+    /// The vault already mirrored a stake on the call.
+    AlreadyMirrored = 9,
+    /// The vault has no open stake on the call.
+    MirrorNotFound = 10,
+    /// The call's outcome has not been finalized.
+    OutcomeNotFinal = 11,
+    /// No queued withdrawal with this id belongs to the caller.
+    RequestNotFound = 12,
+    /// The vault holds the maximum number of open stakes.
+    TooManyOpenMirrors = 13,
+    /// Nothing is left to stake once queued withdrawals and fees are covered.
+    InsufficientFunds = 14,
+    /// A finalized call's stake must be settled before shares are priced.
+    UnsettledMirror = 15,
+}
@@ -0,0 +1,61 @@
+use soroban_sdk::{Address, Env};
+
+/// Emitted when a depositor deposits the stake token for shares
+pub fn emit_deposited(env: &Env, depositor: &Address, amount: i128, shares: i128) {
+    env.events().publish(
+        ("copy_vault", "deposited"),
+        (depositor.clone(), amount, shares),
+    );
+}
+
+/// Emitted when the keeper copies a strategist stake from the vault
+pub fn emit_stake_mirrored(
+    env: &Env,
+    call_id: u64,
+    position: u32,
+    strategist_stake: i128,
+    amount: i128,
+) {
+    env.events().publish(
+        ("copy_vault", "stake_mirrored"),
+        (call_id, position, strategist_stake, amount),
+    );
+}
+
+/// Emitted when a mirrored stake is paid out, refunded or written off
+pub fn emit_mirror_settled(env: &Env, call_id: u64, amount: i128, payout: i128, fee: i128) {
+    env.events().publish(
+        ("copy_vault", "mirror_settled"),
+        (call_id, amount, payout, fee),
+    );
+}
+
+/// Emitted when a depositor queues shares for withdrawal
+pub fn emit_withdrawal_requested(env: &Env, id: u64, owner: &Address, shares: i128) {
+    env.events().publish(
+        ("copy_vault", "withdrawal_requested"),
+        (id, owner.clone(), shares),
+    );
+}
+
+/// Emitted when a depositor takes a queued withdrawal back
+pub fn emit_withdrawal_cancelled(env: &Env, id: u64, owner: &Address, shares: i128) {
+    env.events().publish(
+        ("copy_vault", "withdrawal_cancelled"),
+        (id, owner.clone(), shares),
+    );
+}
+
+/// Emitted when a queued withdrawal is paid
+pub fn emit_withdrawal_paid(env: &Env, id: u64, owner: &Address, shares: i128, amount: i128) {
+    env.events().publish(
+        ("copy_vault", "withdrawal_paid"),
+        (id, owner.clone(), shares, amount),
+    );
+}
+
+/// Emitted when the strategist collects accrued performance fees
+pub fn emit_fees_claimed(env: &Env, strategist: &Address, amount: i128) {
+    env.events()
+        .publish(("copy_vault", "fees_claimed"), (strategist.clone(), amount));
+}
@@ -0,0 +1,617 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Copy-trading vault following one strategist's stakes on a CallRegistry.
+//!
+//! Depositors pool the stake token for vault shares. Whenever the strategist
+//! stakes on a call, the keeper mirrors it from the vault: the vault stakes
+//! the same fraction of its assets as the strategist's stake is of the
+//! strategist's own capital (stake plus token balance), capped at
+//! `max_stake_bps` of the vault. Once the call is final anyone can settle
+//! the mirrored stake, claiming the payout or void refund into the vault.
+//!
+//! The strategist earns `performance_fee_bps` of settled gains that lift the
+//! share price above its high-water mark, so losses must be recovered before
+//! fees accrue again. Open stakes are valued at cost, so deposits and
+//! withdrawals wait until every stake on a finalized call is settled;
+//! otherwise a known win or loss could be bought into or escaped at cost.
+//!
+//! Withdrawals are queued and paid in order, at the share price when paid,
+//! as the vault holds enough idle tokens. Idle tokens owed to queued
+//! withdrawals are never staked.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, contracttype, token, vec, Address, Env, IntoVal, Symbol, Val, Vec,
+    I256,
+};
+
+use backit_shared::OUTCOME_VOID;
+use errors::CopyVaultError;
+use events::*;
+use storage::*;
+
+pub use storage::{Mirror, VaultConfig, VaultState, WithdrawalRequest};
+
+/// Basis-point settings are expressed over this.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Fixed-point scale of the share price behind the high-water mark.
+pub const PRICE_SCALE: i128 = 1_000_000_000;
+
+/// Maximum number of mirrored stakes open at once.
+pub const MAX_OPEN_MIRRORS: u32 = 50;
+
+/// Mirror of the OutcomeManager's finalized `Outcome` record.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub registry: Address,
+    pub call_id: u64,
+    pub outcome: u32,
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[contract]
+pub struct CopyVault;
+
+// ─── Cross-contract helpers ────────────────────────────────────────────────────
+
+/// Read the finalized outcome of `call_id` on the vault's registry from the
+/// OutcomeManager.
+fn finalized_outcome(env: &Env, config: &VaultConfig, call_id: u64) -> Option<Outcome> {
+    let args = (config.registry.clone(), call_id).into_val(env);
+    env.try_invoke_contract::<Outcome, soroban_sdk::Error>(
+        &config.outcome_manager,
+        &Symbol::new(env, "get_outcome"),
+        args,
+    )
+    .ok()
+    .and_then(|result| result.ok())
+}
+
+/// Stake `amount` of the vault's tokens on `position` of `call_id`.
+fn registry_stake(env: &Env, config: &VaultConfig, call_id: u64, position: u32, amount: i128) {
+    let this = env.current_contract_address();
+    // The registry pulls the stake with a transfer the vault must authorise.
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: config.token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (this.clone(), config.registry.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    env.invoke_contract::<Val>(
+        &config.registry,
+        &Symbol::new(env, "stake_on_call"),
+        (this, call_id, amount, position).into_val(env),
+    );
+}
+
+/// Claim whatever the vault's stake on a final call returns: the payout of
+/// a win, the refund of a void, nothing for a loss.
+fn claim_settled_stake(env: &Env, config: &VaultConfig, call_id: u64, outcome: u32) {
+    let this = env.current_contract_address();
+    if outcome == OUTCOME_VOID {
+        env.invoke_contract::<()>(
+            &config.registry,
+            &Symbol::new(env, "claim_void_refund"),
+            (this, call_id).into_val(env),
+        );
+        return;
+    }
+
+    let claimable: i128 = env.invoke_contract(
+        &config.outcome_manager,
+        &Symbol::new(env, "get_claimable"),
+        (config.registry.clone(), call_id, this.clone()).into_val(env),
+    );
+    if claimable > 0 {
+        env.invoke_contract::<()>(
+            &config.outcome_manager,
+            &Symbol::new(env, "claim_payout"),
+            (config.registry.clone(), call_id, this).into_val(env),
+        );
+    }
+}
+
+// ─── Accounting ───────────────────────────────────────────────────────────────
+
+/// `a × b / c` rounded down, without intermediate overflow.
+fn mul_div(env: &Env, a: i128, b: i128, c: i128) -> i128 {
+    I256::from_i128(env, a)
+        .mul(&I256::from_i128(env, b))
+        .div(&I256::from_i128(env, c))
+        .to_i128()
+        .expect("overflow")
+}
+
+fn vault_state(env: &Env, config: &VaultConfig) -> VaultState {
+    let fees_owed = get_fees_owed(env);
+    let idle =
+        token::Client::new(env, &config.token).balance(&env.current_contract_address()) - fees_owed;
+    let deployed = get_deployed(env);
+    VaultState {
+        idle,
+        deployed,
+        total_assets: idle + deployed,
+        total_shares: get_total_shares(env),
+        queued_shares: get_queued_shares(env),
+        fees_owed,
+        high_water_mark: get_high_water_mark(env),
+    }
+}
+
+/// Stake token `shares` redeem for. One virtual share and unit of assets
+/// keep the first deposit from setting an exploitable share price.
+fn shares_to_assets(env: &Env, shares: i128, total_assets: i128, total_shares: i128) -> i128 {
+    mul_div(env, shares, total_assets + 1, total_shares + 1)
+}
+
+/// Accrue the strategist's cut of the gain lifting the share price above
+/// the high-water mark, and raise the mark to the price after the fee.
+fn charge_performance_fee(env: &Env, config: &VaultConfig) -> i128 {
+    let state = vault_state(env, config);
+    if state.total_shares <= 0 {
+        return 0;
+    }
+    let price = mul_div(env, state.total_assets, PRICE_SCALE, state.total_shares);
+    let high_water_mark = state.high_water_mark;
+    if price <= high_water_mark {
+        return 0;
+    }
+
+    let gain = mul_div(
+        env,
+        price - high_water_mark,
+        state.total_shares,
+        PRICE_SCALE,
+    );
+    let fee = mul_div(
+        env,
+        gain,
+        config.performance_fee_bps as i128,
+        BPS_DENOMINATOR as i128,
+    );
+    set_fees_owed(env, state.fees_owed + fee);
+    set_high_water_mark(
+        env,
+        mul_div(
+            env,
+            state.total_assets - fee,
+            PRICE_SCALE,
+            state.total_shares,
+        ),
+    );
+    fee
+}
+
+/// Fail while an open stake's call is final, as it is still valued at cost.
+fn require_settled_mirrors(env: &Env, config: &VaultConfig) -> Result<(), CopyVaultError> {
+    for call_id in get_open_mirrors(env).iter() {
+        if finalized_outcome(env, config, call_id).is_some() {
+            return Err(CopyVaultError::UnsettledMirror);
+        }
+    }
+    Ok(())
+}
+
+fn require_initialized(env: &Env) -> Result<VaultConfig, CopyVaultError> {
+    get_config(env).ok_or(CopyVaultError::NotInitialized)
+}
+
+fn validate_bps(performance_fee_bps: u32, max_stake_bps: u32) -> Result<(), CopyVaultError> {
+    if performance_fee_bps > BPS_DENOMINATOR || max_stake_bps > BPS_DENOMINATOR {
+        return Err(CopyVaultError::InvalidBps);
+    }
+    Ok(())
+}
+
+fn require_admin(env: &Env) -> Result<(), CopyVaultError> {
+    let admin = get_admin(env).ok_or(CopyVaultError::NotInitialized)?;
+    admin.require_auth();
+    Ok(())
+}
+
+#[contractimpl]
+impl CopyVault {
+    /// Initialise the vault.
+    /// # Errors
+    /// * [`CopyVaultError::AlreadyInitialized`] – called more than once.
+    /// * [`CopyVaultError::InvalidBps`]         – a bps setting > 10 000.
+    pub fn initialize(env: Env, admin: Address, config: VaultConfig) -> Result<(), CopyVaultError> {
+        if get_admin(&env).is_some() {
+            return Err(CopyVaultError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        validate_bps(config.performance_fee_bps, config.max_stake_bps)?;
+
+        set_admin(&env, &admin);
+        set_config(&env, &config);
+        set_high_water_mark(&env, PRICE_SCALE);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Change the performance fee and the per-stake cap (admin only).
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`] – contract not initialised.
+    /// * [`CopyVaultError::InvalidBps`]     – a bps setting > 10 000.
+    pub fn set_params(
+        env: Env,
+        performance_fee_bps: u32,
+        max_stake_bps: u32,
+    ) -> Result<(), CopyVaultError> {
+        require_admin(&env)?;
+        validate_bps(performance_fee_bps, max_stake_bps)?;
+
+        let mut config = require_initialized(&env)?;
+        config.performance_fee_bps = performance_fee_bps;
+        config.max_stake_bps = max_stake_bps;
+        set_config(&env, &config);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Replace the keeper (admin only).
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`] – contract not initialised.
+    pub fn set_keeper(env: Env, keeper: Address) -> Result<(), CopyVaultError> {
+        require_admin(&env)?;
+
+        let mut config = require_initialized(&env)?;
+        config.keeper = keeper;
+        set_config(&env, &config);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Deposit `amount` of the stake token from `depositor` for shares at the
+    /// current share price. Returns the shares minted.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`]  – contract not initialised.
+    /// * [`CopyVaultError::InvalidAmount`]   – `amount` ≤ 0.
+    /// * [`CopyVaultError::ZeroShares`]      – `amount` is worth no share.
+    /// * [`CopyVaultError::UnsettledMirror`] – a finalized call's stake is unsettled.
+    pub fn deposit(env: Env, depositor: Address, amount: i128) -> Result<i128, CopyVaultError> {
+        depositor.require_auth();
+        let config = require_initialized(&env)?;
+        if amount <= 0 {
+            return Err(CopyVaultError::InvalidAmount);
+        }
+        require_settled_mirrors(&env, &config)?;
+
+        let state = vault_state(&env, &config);
+        let shares = mul_div(&env, amount, state.total_shares + 1, state.total_assets + 1);
+        if shares == 0 {
+            return Err(CopyVaultError::ZeroShares);
+        }
+
+        token::Client::new(&env, &config.token).transfer(
+            &depositor,
+            env.current_contract_address(),
+            &amount,
+        );
+        set_shares(&env, &depositor, get_shares(&env, &depositor) + shares);
+        set_total_shares(&env, state.total_shares + shares);
+        extend_instance_ttl(&env);
+
+        emit_deposited(&env, &depositor, amount, shares);
+
+        Ok(shares)
+    }
+
+    /// Copy the strategist's stake on `position` of `call_id` from the vault
+    /// (keeper only). Returns the amount staked.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`]     – contract not initialised.
+    /// * [`CopyVaultError::Unauthorized`]       – `keeper` is not the keeper.
+    /// * [`CopyVaultError::AlreadyMirrored`]    – the vault already staked on the call.
+    /// * [`CopyVaultError::TooManyOpenMirrors`] – [`MAX_OPEN_MIRRORS`] stakes are open.
+    /// * [`CopyVaultError::NothingToMirror`]    – the strategist has no stake there.
+    /// * [`CopyVaultError::InsufficientFunds`]  – nothing is free to stake.
+    /// # Panics
+    /// If the registry rejects the stake, e.g. below its minimum stake.
+    pub fn mirror_stake(
+        env: Env,
+        keeper: Address,
+        call_id: u64,
+        position: u32,
+    ) -> Result<i128, CopyVaultError> {
+        keeper.require_auth();
+        let config = require_initialized(&env)?;
+        if keeper != config.keeper {
+            return Err(CopyVaultError::Unauthorized);
+        }
+        if get_mirror(&env, call_id).is_some() {
+            return Err(CopyVaultError::AlreadyMirrored);
+        }
+        let mut open = get_open_mirrors(&env);
+        if open.len() >= MAX_OPEN_MIRRORS {
+            return Err(CopyVaultError::TooManyOpenMirrors);
+        }
+
+        let strategist_stake: i128 = env.invoke_contract(
+            &config.registry,
+            &Symbol::new(&env, "get_staker_stake"),
+            (call_id, config.strategist.clone(), position).into_val(&env),
+        );
+        if strategist_stake <= 0 {
+            return Err(CopyVaultError::NothingToMirror);
+        }
+        let strategist_free = token::Client::new(&env, &config.token).balance(&config.strategist);
+
+        let state = vault_state(&env, &config);
+        let queued = shares_to_assets(
+            &env,
+            state.queued_shares,
+            state.total_assets,
+            state.total_shares,
+        );
+        let amount = mul_div(
+            &env,
+            state.total_assets,
+            strategist_stake,
+            strategist_stake + strategist_free,
+        )
+        .min(mul_div(
+            &env,
+            state.total_assets,
+            config.max_stake_bps as i128,
+            BPS_DENOMINATOR as i128,
+        ))
+        .min(state.idle - queued);
+        if amount <= 0 {
+            return Err(CopyVaultError::InsufficientFunds);
+        }
+
+        registry_stake(&env, &config, call_id, position, amount);
+        set_mirror(
+            &env,
+            &Mirror {
+                call_id,
+                position,
+                strategist_stake,
+                amount,
+                mirrored_at: env.ledger().timestamp(),
+            },
+        );
+        open.push_back(call_id);
+        set_open_mirrors(&env, &open);
+        set_deployed(&env, state.deployed + amount);
+        extend_instance_ttl(&env);
+
+        emit_stake_mirrored(&env, call_id, position, strategist_stake, amount);
+
+        Ok(amount)
+    }
+
+    /// Close the vault's stake on a finalized call, claiming its payout or
+    /// void refund, and accrue the performance fee on any new high. Callable
+    /// by anyone. Returns the amount the stake returned.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`]  – contract not initialised.
+    /// * [`CopyVaultError::MirrorNotFound`]  – no open stake on the call.
+    /// * [`CopyVaultError::OutcomeNotFinal`] – the call is not finalized.
+    pub fn settle_mirror(env: Env, call_id: u64) -> Result<i128, CopyVaultError> {
+        let config = require_initialized(&env)?;
+        let mirror = get_mirror(&env, call_id).ok_or(CopyVaultError::MirrorNotFound)?;
+        let outcome =
+            finalized_outcome(&env, &config, call_id).ok_or(CopyVaultError::OutcomeNotFinal)?;
+
+        let token = token::Client::new(&env, &config.token);
+        let this = env.current_contract_address();
+        let before = token.balance(&this);
+        claim_settled_stake(&env, &config, call_id, outcome.outcome);
+        let payout = token.balance(&this) - before;
+
+        remove_mirror(&env, call_id);
+        let mut open = get_open_mirrors(&env);
+        if let Some(index) = open.first_index_of(call_id) {
+            open.remove(index);
+        }
+        set_open_mirrors(&env, &open);
+        set_deployed(&env, get_deployed(&env) - mirror.amount);
+        let fee = charge_performance_fee(&env, &config);
+        extend_instance_ttl(&env);
+
+        emit_mirror_settled(&env, call_id, mirror.amount, payout, fee);
+
+        Ok(payout)
+    }
+
+    /// Queue `shares` of `owner` for withdrawal. The shares keep sharing in
+    /// gains and losses until paid. Returns the request id.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`]     – contract not initialised.
+    /// * [`CopyVaultError::InvalidAmount`]      – `shares` ≤ 0.
+    /// * [`CopyVaultError::InsufficientShares`] – `owner` holds fewer free shares.
+    pub fn request_withdrawal(
+        env: Env,
+        owner: Address,
+        shares: i128,
+    ) -> Result<u64, CopyVaultError> {
+        owner.require_auth();
+        require_initialized(&env)?;
+        if shares <= 0 {
+            return Err(CopyVaultError::InvalidAmount);
+        }
+        let balance = get_shares(&env, &owner);
+        if balance < shares {
+            return Err(CopyVaultError::InsufficientShares);
+        }
+
+        let (_, id) = get_queue_bounds(&env);
+        set_shares(&env, &owner, balance - shares);
+        set_queued_shares(&env, get_queued_shares(&env) + shares);
+        set_withdrawal(
+            &env,
+            &WithdrawalRequest {
+                id,
+                owner: owner.clone(),
+                shares,
+                requested_at: env.ledger().timestamp(),
+            },
+        );
+        set_queue_tail(&env, id + 1);
+        extend_instance_ttl(&env);
+
+        emit_withdrawal_requested(&env, id, &owner, shares);
+
+        Ok(id)
+    }
+
+    /// Take a queued withdrawal out of the queue, returning its shares.
+    /// # Errors
+    /// * [`CopyVaultError::RequestNotFound`] – no queued request `id` of `owner`.
+    pub fn cancel_withdrawal(env: Env, owner: Address, id: u64) -> Result<(), CopyVaultError> {
+        owner.require_auth();
+        let request = get_withdrawal(&env, id)
+            .filter(|request| request.owner == owner)
+            .ok_or(CopyVaultError::RequestNotFound)?;
+
+        remove_withdrawal(&env, id);
+        set_queued_shares(&env, get_queued_shares(&env) - request.shares);
+        set_shares(&env, &owner, get_shares(&env, &owner) + request.shares);
+        extend_instance_ttl(&env);
+
+        emit_withdrawal_cancelled(&env, id, &owner, request.shares);
+
+        Ok(())
+    }
+
+    /// Pay queued withdrawals in order, looking at up to `limit` queue
+    /// entries, until one exceeds the idle tokens. Callable by anyone.
+    /// Returns the number of withdrawals paid.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`]  – contract not initialised.
+    /// * [`CopyVaultError::UnsettledMirror`] – a finalized call's stake is unsettled.
+    pub fn process_withdrawals(env: Env, limit: u32) -> Result<u32, CopyVaultError> {
+        let config = require_initialized(&env)?;
+        require_settled_mirrors(&env, &config)?;
+        let token = token::Client::new(&env, &config.token);
+        let mut state = vault_state(&env, &config);
+        let (mut head, tail) = get_queue_bounds(&env);
+        let mut paid = 0;
+
+        for _ in 0..limit {
+            if head >= tail {
+                break;
+            }
+            let Some(request) = get_withdrawal(&env, head) else {
+                // Cancelled
+                head += 1;
+                continue;
+            };
+            let amount =
+                shares_to_assets(&env, request.shares, state.total_assets, state.total_shares);
+            if amount > state.idle {
+                break;
+            }
+
+            token.transfer(&env.current_contract_address(), &request.owner, &amount);
+            remove_withdrawal(&env, head);
+            state.idle -= amount;
+            state.total_assets -= amount;
+            state.total_shares -= request.shares;
+            state.queued_shares -= request.shares;
+            emit_withdrawal_paid(&env, head, &request.owner, request.shares, amount);
+            head += 1;
+            paid += 1;
+        }
+
+        set_queue_head(&env, head);
+        set_total_shares(&env, state.total_shares);
+        set_queued_shares(&env, state.queued_shares);
+        extend_instance_ttl(&env);
+
+        Ok(paid)
+    }
+
+    /// Pay the strategist the performance fees accrued so far. Returns the
+    /// amount paid.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`] – contract not initialised.
+    pub fn claim_fees(env: Env) -> Result<i128, CopyVaultError> {
+        let config = require_initialized(&env)?;
+        config.strategist.require_auth();
+
+        let amount = get_fees_owed(&env);
+        if amount > 0 {
+            set_fees_owed(&env, 0);
+            token::Client::new(&env, &config.token).transfer(
+                &env.current_contract_address(),
+                &config.strategist,
+                &amount,
+            );
+            emit_fees_claimed(&env, &config.strategist, amount);
+        }
+        extend_instance_ttl(&env);
+
+        Ok(amount)
+    }
+
+    /// Get where the vault's assets are, its shares and its fee state.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`] – contract not initialised.
+    pub fn get_vault_state(env: Env) -> Result<VaultState, CopyVaultError> {
+        let config = require_initialized(&env)?;
+        Ok(vault_state(&env, &config))
+    }
+
+    /// Stake token `shares` would be paid now, open stakes valued at cost.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`] – contract not initialised.
+    pub fn convert_to_assets(env: Env, shares: i128) -> Result<i128, CopyVaultError> {
+        let config = require_initialized(&env)?;
+        let state = vault_state(&env, &config);
+        Ok(shares_to_assets(
+            &env,
+            shares,
+            state.total_assets,
+            state.total_shares,
+        ))
+    }
+
+    /// Get the vault parameters.
+    /// # Errors
+    /// * [`CopyVaultError::NotInitialized`] – contract not initialised.
+    pub fn get_config(env: Env) -> Result<VaultConfig, CopyVaultError> {
+        require_initialized(&env)
+    }
+
+    /// Free (unqueued) shares of `owner`.
+    pub fn get_shares(env: Env, owner: Address) -> i128 {
+        get_shares(&env, &owner)
+    }
+
+    /// Get the vault's open stake on a call.
+    /// # Errors
+    /// * [`CopyVaultError::MirrorNotFound`] – no open stake on the call.
+    pub fn get_mirror(env: Env, call_id: u64) -> Result<Mirror, CopyVaultError> {
+        get_mirror(&env, call_id).ok_or(CopyVaultError::MirrorNotFound)
+    }
+
+    /// Call ids of the vault's open stakes, oldest first.
+    pub fn get_open_mirrors(env: Env) -> Vec<u64> {
+        get_open_mirrors(&env)
+    }
+
+    /// Get a queued withdrawal; `None` once paid or cancelled.
+    pub fn get_withdrawal(env: Env, id: u64) -> Option<WithdrawalRequest> {
+        get_withdrawal(&env, id)
+    }
+}
@@ -0,0 +1,270 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    TotalShares,
+    Shares(Address),
+    /// Shares waiting in the withdrawal queue
+    QueuedShares,
+    /// Stake token held in open mirrored stakes, at cost
+    Deployed,
+    /// Performance fees accrued to the strategist and not yet claimed
+    FeesOwed,
+    /// Highest share price performance fees have been charged up to
+    HighWaterMark,
+    /// Call ids of the vault's open mirrored stakes
+    OpenMirrors,
+    Mirror(u64),
+    /// Id of the oldest queued withdrawal not yet paid
+    QueueHead,
+    /// Id the next queued withdrawal gets
+    QueueTail,
+    Withdrawal(u64),
+}
+
+/// Vault parameters.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultConfig {
+    /// CallRegistry the strategist and the vault stake on
+    pub registry: Address,
+    /// OutcomeManager the vault claims winning stakes from
+    pub outcome_manager: Address,
+    /// Stake token depositors deposit
+    pub token: Address,
+    /// Address whose stakes the vault copies; earns the performance fee
+    pub strategist: Address,
+    /// Address allowed to mirror the strategist's stakes
+    pub keeper: Address,
+    /// Strategist's share of gains above the high-water mark
+    pub performance_fee_bps: u32,
+    /// Most of the vault's assets one mirrored stake may use
+    pub max_stake_bps: u32,
+}
+
+/// A stake the vault copied from the strategist.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mirror {
+    pub call_id: u64,
+    pub position: u32,
+    /// Strategist's stake on the position when it was mirrored
+    pub strategist_stake: i128,
+    /// Stake token the vault staked
+    pub amount: i128,
+    pub mirrored_at: u64,
+}
+
+/// Shares queued for withdrawal, paid in queue order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawalRequest {
+    pub id: u64,
+    pub owner: Address,
+    pub shares: i128,
+    pub requested_at: u64,
+}
+
+/// Where the vault's assets are, as reported by [`crate::CopyVault::get_vault_state`].
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultState {
+    /// Stake token held by the vault, net of fees owed
+    pub idle: i128,
+    /// Stake token in open mirrored stakes, at cost
+    pub deployed: i128,
+    /// `idle + deployed`
+    pub total_assets: i128,
+    pub total_shares: i128,
+    pub queued_shares: i128,
+    pub fees_owed: i128,
+    pub high_water_mark: i128,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+fn get_i128(env: &Env, key: &DataKey) -> i128 {
+    env.storage().instance().get(key).unwrap_or(0)
+}
+
+fn set_i128(env: &Env, key: &DataKey, value: i128) {
+    env.storage().instance().set(key, &value);
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the vault parameters, if initialised
+pub fn get_config(env: &Env) -> Option<VaultConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+/// Store the vault parameters
+pub fn set_config(env: &Env, config: &VaultConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Get the number of shares outstanding, queued ones included
+pub fn get_total_shares(env: &Env) -> i128 {
+    get_i128(env, &DataKey::TotalShares)
+}
+
+/// Store the number of shares outstanding
+pub fn set_total_shares(env: &Env, shares: i128) {
+    set_i128(env, &DataKey::TotalShares, shares);
+}
+
+/// Get the shares waiting in the withdrawal queue
+pub fn get_queued_shares(env: &Env) -> i128 {
+    get_i128(env, &DataKey::QueuedShares)
+}
+
+/// Store the shares waiting in the withdrawal queue
+pub fn set_queued_shares(env: &Env, shares: i128) {
+    set_i128(env, &DataKey::QueuedShares, shares);
+}
+
+/// Get the stake token held in open mirrored stakes
+pub fn get_deployed(env: &Env) -> i128 {
+    get_i128(env, &DataKey::Deployed)
+}
+
+/// Store the stake token held in open mirrored stakes
+pub fn set_deployed(env: &Env, amount: i128) {
+    set_i128(env, &DataKey::Deployed, amount);
+}
+
+/// Get the performance fees owed to the strategist
+pub fn get_fees_owed(env: &Env) -> i128 {
+    get_i128(env, &DataKey::FeesOwed)
+}
+
+/// Store the performance fees owed to the strategist
+pub fn set_fees_owed(env: &Env, amount: i128) {
+    set_i128(env, &DataKey::FeesOwed, amount);
+}
+
+/// Get the share price performance fees have been charged up to
+pub fn get_high_water_mark(env: &Env) -> i128 {
+    get_i128(env, &DataKey::HighWaterMark)
+}
+
+/// Store the share price performance fees have been charged up to
+pub fn set_high_water_mark(env: &Env, price: i128) {
+    set_i128(env, &DataKey::HighWaterMark, price);
+}
+
+/// Get an address's free (unqueued) shares
+pub fn get_shares(env: &Env, owner: &Address) -> i128 {
+    let key = DataKey::Shares(owner.clone());
+    let shares = env.storage().persistent().get(&key);
+    if shares.is_some() {
+        bump_persistent(env, &key);
+    }
+    shares.unwrap_or(0)
+}
+
+/// Store an address's free shares
+pub fn set_shares(env: &Env, owner: &Address, shares: i128) {
+    let key = DataKey::Shares(owner.clone());
+    env.storage().persistent().set(&key, &shares);
+    bump_persistent(env, &key);
+}
+
+/// Get the call ids of the open mirrored stakes
+pub fn get_open_mirrors(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::OpenMirrors)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the call ids of the open mirrored stakes
+pub fn set_open_mirrors(env: &Env, call_ids: &Vec<u64>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::OpenMirrors, call_ids);
+}
+
+/// Get the vault's mirrored stake on a call, if open
+pub fn get_mirror(env: &Env, call_id: u64) -> Option<Mirror> {
+    env.storage().persistent().get(&DataKey::Mirror(call_id))
+}
+
+/// Store a mirrored stake
+pub fn set_mirror(env: &Env, mirror: &Mirror) {
+    let key = DataKey::Mirror(mirror.call_id);
+    env.storage().persistent().set(&key, mirror);
+    bump_persistent(env, &key);
+}
+
+/// Remove a settled mirrored stake
+pub fn remove_mirror(env: &Env, call_id: u64) {
+    env.storage().persistent().remove(&DataKey::Mirror(call_id));
+}
+
+/// Get the ids bounding the withdrawal queue: `[head, tail)`
+pub fn get_queue_bounds(env: &Env) -> (u64, u64) {
+    let storage = env.storage().instance();
+    (
+        storage.get(&DataKey::QueueHead).unwrap_or(0),
+        storage.get(&DataKey::QueueTail).unwrap_or(0),
+    )
+}
+
+/// Store the id of the oldest unpaid withdrawal
+pub fn set_queue_head(env: &Env, head: u64) {
+    env.storage().instance().set(&DataKey::QueueHead, &head);
+}
+
+/// Store the id the next withdrawal gets
+pub fn set_queue_tail(env: &Env, tail: u64) {
+    env.storage().instance().set(&DataKey::QueueTail, &tail);
+}
+
+/// Get a queued withdrawal, if still queued
+pub fn get_withdrawal(env: &Env, id: u64) -> Option<WithdrawalRequest> {
+    env.storage().persistent().get(&DataKey::Withdrawal(id))
+}
+
+/// Store a queued withdrawal
+pub fn set_withdrawal(env: &Env, request: &WithdrawalRequest) {
+    let key = DataKey::Withdrawal(request.id);
+    env.storage().persistent().set(&key, request);
+    bump_persistent(env, &key);
+}
+
+/// Remove a paid or cancelled withdrawal
+pub fn remove_withdrawal(env: &Env, id: u64) {
+    env.storage().persistent().remove(&DataKey::Withdrawal(id));
+}
@@ -0,0 +1,318 @@
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env};
+
+use backit_shared::OUTCOME_VOID;
+
+use crate::errors::CopyVaultError;
+use crate::{CopyVault, CopyVaultClient, Outcome, VaultConfig, PRICE_SCALE};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+/// CallRegistry stand-in: strategist stakes are set by the test, the vault's
+/// stakes move real tokens.
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage().instance().set(&"token", &token);
+    }
+
+    pub fn set_stake(env: Env, call_id: u64, staker: Address, position: u32, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&(call_id, staker, position), &amount);
+    }
+
+    pub fn get_staker_stake(env: Env, call_id: u64, staker: Address, position: u32) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(call_id, staker, position))
+            .unwrap_or(0)
+    }
+
+    pub fn stake_on_call(env: Env, staker: Address, call_id: u64, amount: i128, position: u32) {
+        staker.require_auth();
+        Self::token(&env).transfer(&staker, env.current_contract_address(), &amount);
+        Self::set_stake(env, call_id, staker, position, amount);
+    }
+
+    pub fn claim_void_refund(env: Env, staker: Address, call_id: u64) {
+        staker.require_auth();
+        let mut refund = 0;
+        for position in 1..=2 {
+            refund += Self::get_staker_stake(env.clone(), call_id, staker.clone(), position);
+        }
+        Self::token(&env).transfer(&env.current_contract_address(), &staker, &refund);
+    }
+}
+
+impl MockRegistry {
+    fn token(env: &Env) -> token::Client<'_> {
+        let token: Address = env.storage().instance().get(&"token").unwrap();
+        token::Client::new(env, &token)
+    }
+}
+
+/// OutcomeManager stand-in paying out claimable amounts set by the test.
+#[contract]
+pub struct MockOutcomeManager;
+
+#[contractimpl]
+impl MockOutcomeManager {
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage().instance().set(&"token", &token);
+    }
+
+    pub fn set_outcome(env: Env, registry: Address, call_id: u64, outcome: u32, claimable: i128) {
+        let record = Outcome {
+            registry: registry.clone(),
+            call_id,
+            outcome,
+            price: 0,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&(registry, call_id), &(record, claimable));
+    }
+
+    pub fn get_outcome(env: Env, registry: Address, call_id: u64) -> Outcome {
+        Self::record(&env, registry, call_id).0
+    }
+
+    pub fn get_claimable(env: Env, registry: Address, call_id: u64, _staker: Address) -> i128 {
+        Self::record(&env, registry, call_id).1
+    }
+
+    pub fn claim_payout(env: Env, registry: Address, call_id: u64, staker: Address) {
+        staker.require_auth();
+        let token: Address = env.storage().instance().get(&"token").unwrap();
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &staker,
+            &Self::record(&env, registry, call_id).1,
+        );
+    }
+}
+
+impl MockOutcomeManager {
+    fn record(env: &Env, registry: Address, call_id: u64) -> (Outcome, i128) {
+        env.storage()
+            .instance()
+            .get(&(registry, call_id))
+            .expect("outcome not finalized")
+    }
+}
+
+struct Setup {
+    env: Env,
+    client: CopyVaultClient<'static>,
+    registry: MockRegistryClient<'static>,
+    om: MockOutcomeManagerClient<'static>,
+    token: token::StellarAssetClient<'static>,
+    strategist: Address,
+    keeper: Address,
+}
+
+fn setup(performance_fee_bps: u32, max_stake_bps: u32) -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let registry = MockRegistryClient::new(&env, &env.register(MockRegistry, (&token_id,)));
+    let om = MockOutcomeManagerClient::new(&env, &env.register(MockOutcomeManager, (&token_id,)));
+    let strategist = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let client = CopyVaultClient::new(&env, &env.register(CopyVault, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &VaultConfig {
+            registry: registry.address.clone(),
+            outcome_manager: om.address.clone(),
+            token: token_id.clone(),
+            strategist: strategist.clone(),
+            keeper: keeper.clone(),
+            performance_fee_bps,
+            max_stake_bps,
+        },
+    );
+
+    Setup {
+        token: token::StellarAssetClient::new(&env, &token_id),
+        env,
+        client,
+        registry,
+        om,
+        strategist,
+        keeper,
+    }
+}
+
+fn deposit(s: &Setup, amount: i128) -> Address {
+    let depositor = Address::generate(&s.env);
+    s.token.mint(&depositor, &amount);
+    s.client.deposit(&depositor, &amount);
+    depositor
+}
+
+/// Have the strategist stake `stake` on `position` of `call_id` while
+/// holding `free` more of the token.
+fn strategist_stakes(s: &Setup, call_id: u64, position: u32, stake: i128, free: i128) {
+    s.registry
+        .set_stake(&call_id, &s.strategist, &position, &stake);
+    s.token.mint(&s.strategist, &free);
+}
+
+fn balance(s: &Setup, id: &Address) -> i128 {
+    token::Client::new(&s.env, &s.token.address).balance(id)
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_keeper_mirrors_strategist_stake_proportionally() {
+    let s = setup(2_000, 5_000);
+    deposit(&s, 600_000);
+    deposit(&s, 400_000);
+
+    // The strategist puts 10% of their capital on call 1
+    strategist_stakes(&s, 1, 1, 100, 900);
+    assert_eq!(s.client.mirror_stake(&s.keeper, &1, &1), 100_000);
+    assert_eq!(
+        s.registry.get_staker_stake(&1, &s.client.address, &1),
+        100_000
+    );
+    let state = s.client.get_vault_state();
+    assert_eq!(state.idle, 900_000);
+    assert_eq!(state.deployed, 100_000);
+
+    let result = s.client.try_mirror_stake(&s.keeper, &1, &1);
+    assert_eq!(result, Err(Ok(CopyVaultError::AlreadyMirrored)));
+    let result = s
+        .client
+        .try_mirror_stake(&Address::generate(&s.env), &2, &1);
+    assert_eq!(result, Err(Ok(CopyVaultError::Unauthorized)));
+    let result = s.client.try_mirror_stake(&s.keeper, &2, &1);
+    assert_eq!(result, Err(Ok(CopyVaultError::NothingToMirror)));
+
+    // Going nearly all in is capped at half the vault
+    strategist_stakes(&s, 2, 2, 90_000, 0);
+    assert_eq!(s.client.mirror_stake(&s.keeper, &2, &2), 500_000);
+    assert_eq!(s.client.get_open_mirrors().len(), 2);
+}
+
+#[test]
+fn test_performance_fee_only_above_high_water_mark() {
+    let s = setup(2_000, 5_000);
+    deposit(&s, 1_000_000);
+
+    strategist_stakes(&s, 1, 1, 100, 900);
+    s.client.mirror_stake(&s.keeper, &1, &1);
+    s.token.mint(&s.om.address, &180_000);
+    s.om.set_outcome(&s.registry.address, &1, &1, &180_000);
+    assert_eq!(s.client.settle_mirror(&1), 180_000);
+
+    // 20% of the 80 000 gain
+    let state = s.client.get_vault_state();
+    assert_eq!(state.fees_owed, 16_000);
+    assert_eq!(state.total_assets, 1_064_000);
+    assert_eq!(state.high_water_mark, 1_064 * PRICE_SCALE / 1_000);
+    assert_eq!(s.client.claim_fees(), 16_000);
+    assert_eq!(balance(&s, &s.strategist), 16_900);
+
+    // A loss earns no fee and leaves the mark in place
+    strategist_stakes(&s, 2, 1, 16_900, 0);
+    s.client.mirror_stake(&s.keeper, &2, &1);
+    s.om.set_outcome(&s.registry.address, &2, &2, &0);
+    assert_eq!(s.client.settle_mirror(&2), 0);
+    let state = s.client.get_vault_state();
+    assert_eq!(state.fees_owed, 0);
+    assert_eq!(state.total_assets, 532_000);
+    assert_eq!(state.high_water_mark, 1_064 * PRICE_SCALE / 1_000);
+}
+
+#[test]
+fn test_withdrawal_queue_waits_for_idle_funds() {
+    let s = setup(2_000, 10_000);
+    let alice = deposit(&s, 1_000_000);
+    strategist_stakes(&s, 1, 1, 100, 0);
+    assert_eq!(s.client.mirror_stake(&s.keeper, &1, &1), 1_000_000);
+
+    let id = s.client.request_withdrawal(&alice, &500_000);
+    assert_eq!(s.client.process_withdrawals(&10), 0);
+    deposit(&s, 200_000);
+    assert_eq!(s.client.process_withdrawals(&10), 0);
+
+    // Idle funds owed to the queue are not staked
+    strategist_stakes(&s, 2, 1, 100, 0);
+    let result = s.client.try_mirror_stake(&s.keeper, &2, &1);
+    assert_eq!(result, Err(Ok(CopyVaultError::InsufficientFunds)));
+
+    s.om.set_outcome(&s.registry.address, &1, &OUTCOME_VOID, &0);
+    assert_eq!(s.client.settle_mirror(&1), 1_000_000);
+    assert_eq!(s.client.process_withdrawals(&10), 1);
+    assert_eq!(balance(&s, &alice), 500_000);
+    assert_eq!(s.client.get_withdrawal(&id), None);
+    assert_eq!(s.client.get_shares(&alice), 500_000);
+    assert_eq!(s.client.get_vault_state().queued_shares, 0);
+}
+
+#[test]
+fn test_settle_and_queue_rules() {
+    let s = setup(2_000, 5_000);
+    let alice = deposit(&s, 1_000_000);
+
+    let result = s.client.try_settle_mirror(&1);
+    assert_eq!(result, Err(Ok(CopyVaultError::MirrorNotFound)));
+    strategist_stakes(&s, 1, 1, 100, 900);
+    s.client.mirror_stake(&s.keeper, &1, &1);
+    let result = s.client.try_settle_mirror(&1);
+    assert_eq!(result, Err(Ok(CopyVaultError::OutcomeNotFinal)));
+
+    let result = s.client.try_request_withdrawal(&alice, &1_000_001);
+    assert_eq!(result, Err(Ok(CopyVaultError::InsufficientShares)));
+    let id = s.client.request_withdrawal(&alice, &400_000);
+    assert_eq!(s.client.get_shares(&alice), 600_000);
+    let result = s
+        .client
+        .try_cancel_withdrawal(&Address::generate(&s.env), &id);
+    assert_eq!(result, Err(Ok(CopyVaultError::RequestNotFound)));
+    s.client.cancel_withdrawal(&alice, &id);
+    assert_eq!(s.client.get_shares(&alice), 1_000_000);
+    // The cancelled entry is skipped
+    assert_eq!(s.client.process_withdrawals(&10), 0);
+
+    let config = s.client.get_config();
+    let result = s.client.try_initialize(&Address::generate(&s.env), &config);
+    assert_eq!(result, Err(Ok(CopyVaultError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_final_mirrors_settle_before_shares_are_priced() {
+    let s = setup(0, 5_000);
+    let alice = deposit(&s, 1_000_000);
+    strategist_stakes(&s, 1, 1, 100, 900);
+    s.client.mirror_stake(&s.keeper, &1, &1);
+    s.client.request_withdrawal(&alice, &500_000);
+
+    // The win is final but the vault still values the stake at cost
+    s.token.mint(&s.om.address, &300_000);
+    s.om.set_outcome(&s.registry.address, &1, &1, &300_000);
+    let bob = Address::generate(&s.env);
+    s.token.mint(&bob, &1_000_000);
+    let result = s.client.try_deposit(&bob, &1_000_000);
+    assert_eq!(result, Err(Ok(CopyVaultError::UnsettledMirror)));
+    let result = s.client.try_process_withdrawals(&10);
+    assert_eq!(result, Err(Ok(CopyVaultError::UnsettledMirror)));
+
+    // Once settled both go through at the realised share price, rounded down
+    s.client.settle_mirror(&1);
+    assert_eq!(s.client.process_withdrawals(&10), 1);
+    assert_eq!(balance(&s, &alice), 599_999);
+    assert_eq!(s.client.deposit(&bob, &600_000), 499_999);
+}
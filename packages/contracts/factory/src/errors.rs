@@ -0,0 +1,13 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum FactoryError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// No deployment exists for the supplied id.
+    DeploymentNotFound = 3,
+}
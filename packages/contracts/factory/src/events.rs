@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+/// Emitted when a community's registry and outcome manager are deployed
+pub fn emit_community_deployed(
+    env: &Env,
+    deployment_id: u32,
+    deployer: &Address,
+    registry: &Address,
+    outcome_manager: &Address,
+) {
+    env.events().publish(
+        ("factory", "community_deployed"),
+        (
+            deployment_id,
+            deployer.clone(),
+            registry.clone(),
+            outcome_manager.clone(),
+        ),
+    );
+}
+
+/// Emitted when the admin replaces the WASM hashes new deployments use
+pub fn emit_wasm_hashes_updated(
+    env: &Env,
+    registry_wasm_hash: &BytesN<32>,
+    outcome_manager_wasm_hash: &BytesN<32>,
+) {
+    env.events().publish(
+        ("factory", "wasm_hashes_updated"),
+        (
+            registry_wasm_hash.clone(),
+            outcome_manager_wasm_hash.clone(),
+        ),
+    );
+}
@@ -0,0 +1,230 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Deploys white-label BACKit communities.
+//!
+//! Each deployment is a fresh `call_registry` bound to a fresh
+//! `outcome_manager`, both built from WASM hashes the factory admin has
+//! uploaded and registered, and both administered by the community's own
+//! admin. The factory records every deployment and lists them by id,
+//! deployer or registry so frontends and indexers can discover communities.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{
+    contract, contractimpl, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+use errors::FactoryError;
+use events::*;
+use storage::*;
+
+pub use storage::{CommunityParams, Deployment, DeploymentPage, WasmHashes};
+
+/// Most deployments returned by one [`Factory::list_deployments`] page.
+pub const MAX_DEPLOYMENT_PAGE: u32 = 50;
+
+#[contract]
+pub struct Factory;
+
+/// Salt for one of a deployer's contracts: sha256(`tag` ‖ deployer ‖ salt).
+/// Mixing in the deployer stops others from claiming a community's
+/// addresses by front-running its salt.
+fn contract_salt(env: &Env, tag: &[u8], deployer: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let mut raw = Bytes::from_slice(env, tag);
+    raw.append(&deployer.clone().to_xdr(env));
+    raw.append(&salt.clone().into());
+    env.crypto().sha256(&raw).into()
+}
+
+/// Addresses of the registry and outcome manager `deployer` gets for `salt`.
+fn community_addresses(env: &Env, deployer: &Address, salt: &BytesN<32>) -> (Address, Address) {
+    let deployer_env = env.deployer();
+    let registry = deployer_env
+        .with_current_contract(contract_salt(env, b"registry:", deployer, salt))
+        .deployed_address();
+    let outcome_manager = deployer_env
+        .with_current_contract(contract_salt(env, b"outcome_manager:", deployer, salt))
+        .deployed_address();
+    (registry, outcome_manager)
+}
+
+#[contractimpl]
+impl Factory {
+    /// Initialise the contract with an admin and the WASM hashes of the
+    /// `call_registry` and `outcome_manager` contracts.
+    /// # Errors
+    /// * [`FactoryError::AlreadyInitialized`] – called more than once.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        wasm_hashes: WasmHashes,
+    ) -> Result<(), FactoryError> {
+        if get_admin(&env).is_some() {
+            return Err(FactoryError::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_wasm_hashes(&env, &wasm_hashes);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Replace the WASM hashes used by future deployments (admin only).
+    /// Existing communities keep the code they were deployed with.
+    /// # Errors
+    /// * [`FactoryError::NotInitialized`] – contract not initialised.
+    pub fn set_wasm_hashes(env: Env, wasm_hashes: WasmHashes) -> Result<(), FactoryError> {
+        let admin = get_admin(&env).ok_or(FactoryError::NotInitialized)?;
+        admin.require_auth();
+
+        set_wasm_hashes(&env, &wasm_hashes);
+        extend_instance_ttl(&env);
+
+        emit_wasm_hashes_updated(&env, &wasm_hashes.registry, &wasm_hashes.outcome_manager);
+
+        Ok(())
+    }
+
+    /// Deploy a community: an outcome manager and a registry bound to it,
+    /// both administered by `params.admin`, who must also authorise the call.
+    /// Returns the recorded deployment.
+    /// # Errors
+    /// * [`FactoryError::NotInitialized`] – contract not initialised.
+    ///
+    /// # Panics
+    /// If `deployer` already used `params.salt`, or the outcome manager
+    /// rejects its settings (quorum, fee).
+    pub fn deploy(
+        env: Env,
+        deployer: Address,
+        params: CommunityParams,
+    ) -> Result<Deployment, FactoryError> {
+        deployer.require_auth();
+
+        let wasm_hashes = get_wasm_hashes(&env).ok_or(FactoryError::NotInitialized)?;
+
+        let outcome_manager = env
+            .deployer()
+            .with_current_contract(contract_salt(
+                &env,
+                b"outcome_manager:",
+                &deployer,
+                &params.salt,
+            ))
+            .deploy_v2(wasm_hashes.outcome_manager.clone(), ());
+        let registry = env
+            .deployer()
+            .with_current_contract(contract_salt(&env, b"registry:", &deployer, &params.salt))
+            .deploy_v2(
+                wasm_hashes.registry.clone(),
+                (
+                    params.admin.clone(),
+                    outcome_manager.clone(),
+                    params.min_stake,
+                ),
+            );
+        env.invoke_contract::<()>(
+            &outcome_manager,
+            &Symbol::new(&env, "initialize"),
+            (
+                params.admin.clone(),
+                registry.clone(),
+                params.oracles,
+                params.quorum,
+                params.fee_collector,
+                params.fee_bps,
+                params.dispute_window_ledgers,
+            )
+                .into_val(&env),
+        );
+
+        let deployment = Deployment {
+            id: next_deployment_id(&env),
+            deployer: deployer.clone(),
+            admin: params.admin,
+            registry,
+            outcome_manager,
+            registry_wasm_hash: wasm_hashes.registry,
+            outcome_manager_wasm_hash: wasm_hashes.outcome_manager,
+            created_at: env.ledger().timestamp(),
+        };
+        add_deployment(&env, &deployment);
+        extend_instance_ttl(&env);
+
+        emit_community_deployed(
+            &env,
+            deployment.id,
+            &deployer,
+            &deployment.registry,
+            &deployment.outcome_manager,
+        );
+
+        Ok(deployment)
+    }
+
+    /// Get the registry and outcome manager addresses `deployer` would get
+    /// by deploying with `salt`.
+    pub fn get_community_addresses(
+        env: Env,
+        deployer: Address,
+        salt: BytesN<32>,
+    ) -> (Address, Address) {
+        community_addresses(&env, &deployer, &salt)
+    }
+
+    /// Get a deployment.
+    /// # Errors
+    /// * [`FactoryError::DeploymentNotFound`] – no deployment `deployment_id`.
+    pub fn get_deployment(env: Env, deployment_id: u32) -> Result<Deployment, FactoryError> {
+        get_deployment(&env, deployment_id).ok_or(FactoryError::DeploymentNotFound)
+    }
+
+    /// Number of deployments so far; ids run from 1 to this.
+    pub fn get_deployment_count(env: Env) -> u32 {
+        get_deployment_count(&env)
+    }
+
+    /// Page through all deployments, oldest first. `cursor` is the number of
+    /// deployments already read; `limit` is capped at [`MAX_DEPLOYMENT_PAGE`].
+    pub fn list_deployments(env: Env, cursor: u32, limit: u32) -> DeploymentPage {
+        let count = get_deployment_count(&env);
+        let start = cursor.min(count);
+        let end = start
+            .saturating_add(limit.min(MAX_DEPLOYMENT_PAGE))
+            .min(count);
+
+        let mut deployments = Vec::new(&env);
+        for id in (start + 1)..=end {
+            if let Some(deployment) = get_deployment(&env, id) {
+                deployments.push_back(deployment);
+            }
+        }
+        DeploymentPage {
+            deployments,
+            next_cursor: if end < count { Some(end) } else { None },
+        }
+    }
+
+    /// Get the ids of the deployments `deployer` made, oldest first.
+    pub fn get_deployer_deployments(env: Env, deployer: Address) -> Vec<u32> {
+        get_deployer_deployments(&env, &deployer)
+    }
+
+    /// Get the deployment a registry belongs to, if this factory deployed it.
+    pub fn get_registry_deployment(env: Env, registry: Address) -> Option<Deployment> {
+        get_registry_deployment(&env, &registry).and_then(|id| get_deployment(&env, id))
+    }
+
+    /// Get the WASM hashes new deployments use.
+    /// # Errors
+    /// * [`FactoryError::NotInitialized`] – contract not initialised.
+    pub fn get_wasm_hashes(env: Env) -> Result<WasmHashes, FactoryError> {
+        get_wasm_hashes(&env).ok_or(FactoryError::NotInitialized)
+    }
+}
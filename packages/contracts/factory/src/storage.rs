@@ -0,0 +1,159 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    WasmHashes,
+    DeploymentCounter,
+    Deployment(u32),
+    /// Ids of the deployments a deployer made, oldest first
+    DeployerDeployments(Address),
+    /// Deployment id of a registry this factory deployed
+    RegistryDeployment(Address),
+}
+
+/// WASM the factory deploys for each community.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmHashes {
+    /// `call_registry` contract
+    pub registry: BytesN<32>,
+    /// `outcome_manager` contract
+    pub outcome_manager: BytesN<32>,
+}
+
+/// Settings for a new community's registry and outcome manager.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommunityParams {
+    /// Combined with the deployer to derive both contract addresses
+    pub salt: BytesN<32>,
+    /// Admin of both contracts
+    pub admin: Address,
+    pub min_stake: i128,
+    pub oracles: Vec<BytesN<32>>,
+    pub quorum: u32,
+    pub fee_collector: Address,
+    pub fee_bps: u32,
+    pub dispute_window_ledgers: u32,
+}
+
+/// A community deployed by the factory.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deployment {
+    pub id: u32,
+    pub deployer: Address,
+    pub admin: Address,
+    pub registry: Address,
+    pub outcome_manager: Address,
+    pub registry_wasm_hash: BytesN<32>,
+    pub outcome_manager_wasm_hash: BytesN<32>,
+    pub created_at: u64,
+}
+
+/// One page of [`Deployment`]s, oldest first.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeploymentPage {
+    pub deployments: Vec<Deployment>,
+    /// Cursor of the next page, `None` on the last page
+    pub next_cursor: Option<u32>,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the WASM hashes new deployments use, if initialised
+pub fn get_wasm_hashes(env: &Env) -> Option<WasmHashes> {
+    env.storage().instance().get(&DataKey::WasmHashes)
+}
+
+/// Store the WASM hashes new deployments use
+pub fn set_wasm_hashes(env: &Env, hashes: &WasmHashes) {
+    env.storage().instance().set(&DataKey::WasmHashes, hashes);
+}
+
+/// Number of deployments so far
+pub fn get_deployment_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DeploymentCounter)
+        .unwrap_or(0)
+}
+
+/// Allocate the next deployment id
+pub fn next_deployment_id(env: &Env) -> u32 {
+    let id = get_deployment_count(env) + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::DeploymentCounter, &id);
+    id
+}
+
+/// Get a deployment
+pub fn get_deployment(env: &Env, id: u32) -> Option<Deployment> {
+    env.storage().persistent().get(&DataKey::Deployment(id))
+}
+
+/// Store a deployment and index it by deployer and registry
+pub fn add_deployment(env: &Env, deployment: &Deployment) {
+    let key = DataKey::Deployment(deployment.id);
+    env.storage().persistent().set(&key, deployment);
+    bump_persistent(env, &key);
+
+    let key = DataKey::DeployerDeployments(deployment.deployer.clone());
+    let mut ids = get_deployer_deployments(env, &deployment.deployer);
+    ids.push_back(deployment.id);
+    env.storage().persistent().set(&key, &ids);
+    bump_persistent(env, &key);
+
+    let key = DataKey::RegistryDeployment(deployment.registry.clone());
+    env.storage().persistent().set(&key, &deployment.id);
+    bump_persistent(env, &key);
+}
+
+/// Get the ids of a deployer's deployments, oldest first
+pub fn get_deployer_deployments(env: &Env, deployer: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DeployerDeployments(deployer.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get the deployment id of a registry, if this factory deployed it
+pub fn get_registry_deployment(env: &Env, registry: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RegistryDeployment(registry.clone()))
+}
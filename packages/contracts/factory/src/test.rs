@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+use crate::errors::FactoryError;
+use crate::storage::{add_deployment, next_deployment_id};
+use crate::{Deployment, Factory, FactoryClient, WasmHashes};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+fn hashes(env: &Env, seed: u8) -> WasmHashes {
+    WasmHashes {
+        registry: BytesN::from_array(env, &[seed; 32]),
+        outcome_manager: BytesN::from_array(env, &[seed + 1; 32]),
+    }
+}
+
+fn setup() -> (Env, FactoryClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = FactoryClient::new(&env, &env.register(Factory, ()));
+    client.initialize(&Address::generate(&env), &hashes(&env, 1));
+
+    (env, client)
+}
+
+/// Record a deployment without deploying, as `deploy` would after its
+/// contracts are up.
+fn record_deployment(env: &Env, client: &FactoryClient, deployer: &Address) -> u32 {
+    env.as_contract(&client.address, || {
+        let wasm_hashes = hashes(env, 1);
+        let deployment = Deployment {
+            id: next_deployment_id(env),
+            deployer: deployer.clone(),
+            admin: Address::generate(env),
+            registry: Address::generate(env),
+            outcome_manager: Address::generate(env),
+            registry_wasm_hash: wasm_hashes.registry,
+            outcome_manager_wasm_hash: wasm_hashes.outcome_manager,
+            created_at: 0,
+        };
+        add_deployment(env, &deployment);
+        deployment.id
+    })
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_wasm_hashes_are_set_once_then_updated_by_admin() {
+    let (env, client) = setup();
+    assert_eq!(client.get_wasm_hashes(), hashes(&env, 1));
+
+    let result = client.try_initialize(&Address::generate(&env), &hashes(&env, 5));
+    assert_eq!(result, Err(Ok(FactoryError::AlreadyInitialized)));
+
+    client.set_wasm_hashes(&hashes(&env, 7));
+    assert_eq!(client.get_wasm_hashes(), hashes(&env, 7));
+}
+
+#[test]
+fn test_community_addresses_depend_on_deployer_and_salt() {
+    let (env, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[9; 32]);
+
+    let (registry, outcome_manager) = client.get_community_addresses(&alice, &salt);
+    assert_ne!(registry, outcome_manager);
+    assert_eq!(
+        client.get_community_addresses(&alice, &salt),
+        (registry.clone(), outcome_manager)
+    );
+    assert_ne!(client.get_community_addresses(&bob, &salt).0, registry);
+    let other_salt = BytesN::from_array(&env, &[8; 32]);
+    assert_ne!(
+        client.get_community_addresses(&alice, &other_salt).0,
+        registry
+    );
+}
+
+#[test]
+fn test_deployments_are_enumerable() {
+    let (env, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    for deployer in [&alice, &bob, &alice] {
+        record_deployment(&env, &client, deployer);
+    }
+
+    assert_eq!(client.get_deployment_count(), 3);
+    let page = client.list_deployments(&0, &2);
+    assert_eq!(page.deployments.len(), 2);
+    assert_eq!(page.next_cursor, Some(2));
+    let page = client.list_deployments(&2, &2);
+    assert_eq!(page.deployments.get_unchecked(0).id, 3);
+    assert_eq!(page.next_cursor, None);
+
+    assert_eq!(
+        client.get_deployer_deployments(&alice),
+        soroban_sdk::vec![&env, 1u32, 3]
+    );
+    let second = client.get_deployment(&2);
+    assert_eq!(second.deployer, bob);
+    assert_eq!(
+        client.get_registry_deployment(&second.registry),
+        Some(second)
+    );
+    assert_eq!(
+        client.get_registry_deployment(&Address::generate(&env)),
+        None
+    );
+
+    let result = client.try_get_deployment(&4);
+    assert_eq!(result, Err(Ok(FactoryError::DeploymentNotFound)));
+}
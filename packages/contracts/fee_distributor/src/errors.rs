@@ -0,0 +1,19 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum FeeDistributorError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// The split weights do not sum to `BPS_DENOMINATOR`, or leave the pools no weight.
+    InvalidWeights = 3,
+    /// The amount deposited is ≤ 0.
+    InvalidAmount = 4,
+    /// The contract holds no unallocated balance of the token.
+    NothingToDistribute = 5,
+    /// The creator has no accrued balance of the token.
+    NothingToClaim = 6,
+}
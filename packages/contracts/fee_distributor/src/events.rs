@@ -0,0 +1,68 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{Recipients, SplitWeights};
+
+/// Emitted when a fee is deposited with its creator attribution
+pub fn emit_fee_deposited(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    creator: &Address,
+    amount: i128,
+    creator_share: i128,
+) {
+    env.events().publish(
+        ("fee_distributor", "fee_deposited"),
+        (
+            token.clone(),
+            from.clone(),
+            creator.clone(),
+            amount,
+            creator_share,
+        ),
+    );
+}
+
+/// Emitted when the crank pays out a token's unallocated balance
+pub fn emit_fees_distributed(
+    env: &Env,
+    token: &Address,
+    oracle_pool: i128,
+    insurance: i128,
+    treasury: i128,
+) {
+    env.events().publish(
+        ("fee_distributor", "fees_distributed"),
+        (token.clone(), oracle_pool, insurance, treasury),
+    );
+}
+
+/// Emitted when a creator withdraws their accrued share
+pub fn emit_creator_fees_claimed(env: &Env, creator: &Address, token: &Address, amount: i128) {
+    env.events().publish(
+        ("fee_distributor", "creator_claimed"),
+        (creator.clone(), token.clone(), amount),
+    );
+}
+
+/// Emitted when governance changes the split weights
+pub fn emit_weights_updated(env: &Env, weights: &SplitWeights) {
+    env.events()
+        .publish(("fee_distributor", "weights_updated"), weights.clone());
+}
+
+/// Emitted when governance changes the pool recipients
+pub fn emit_recipients_updated(env: &Env, recipients: &Recipients) {
+    env.events().publish(
+        ("fee_distributor", "recipients_updated"),
+        recipients.clone(),
+    );
+}
+
+/// Emitted when governance hands its role to a new address
+pub fn emit_governance_changed(env: &Env, old: &Address, new: &Address) {
+    env.events().publish(
+        ("fee_distributor", "governance_changed"),
+        (old.clone(), new.clone()),
+    );
+}
@@ -0,0 +1,300 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Splits protocol fees among call creators, the oracle-reward pool, the
+//! insurance fund and the treasury.
+//!
+//! Fees arrive in two ways. `deposit` pulls a fee in and immediately credits
+//! the creator's weight of it to the named call creator, who withdraws it
+//! with `claim_creator_fees`; the OutcomeManager deposits every claim's fee
+//! this way once it is set as its fee distributor. Anything else the contract
+//! holds, including fees transferred straight in (e.g. by a contract naming
+//! it as plain fee collector), is unallocated and is paid out by the permissionless
+//! `distribute(token)` crank to the three pools in proportion to their
+//! weights. Such fees name no creator, so the creator weight only applies to
+//! `deposit`; fee sources that should reward creators must go through it.
+//! Governance sets the weights and the pool addresses.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+use errors::FeeDistributorError;
+use events::*;
+use storage::*;
+
+pub use storage::{DistributionTotals, Recipients, SplitWeights};
+
+/// The split weights always sum to this many basis points.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+#[contract]
+pub struct FeeDistributor;
+
+/// Require governance's authorisation and return its address.
+fn require_governance(env: &Env) -> Result<Address, FeeDistributorError> {
+    let governance = get_governance(env).ok_or(FeeDistributorError::NotInitialized)?;
+    governance.require_auth();
+    Ok(governance)
+}
+
+/// Weights must sum to [`BPS_DENOMINATOR`] and leave the pools some weight,
+/// since fees transferred straight in are split across the pools alone.
+fn validate_weights(weights: &SplitWeights) -> Result<(), FeeDistributorError> {
+    let total = [
+        weights.creator,
+        weights.oracle_pool,
+        weights.insurance,
+        weights.treasury,
+    ]
+    .iter()
+    .try_fold(0u32, |sum, weight| sum.checked_add(*weight));
+    if total != Some(BPS_DENOMINATOR) || weights.creator == BPS_DENOMINATOR {
+        return Err(FeeDistributorError::InvalidWeights);
+    }
+    Ok(())
+}
+
+/// The token balance not owed to creators.
+fn unallocated(env: &Env, token: &Address) -> i128 {
+    let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+    (balance - get_creator_owed(env, token)).max(0)
+}
+
+/// Split `amount` across the three pools by their relative weights. Rounding
+/// dust goes to the treasury.
+fn pool_shares(weights: &SplitWeights, amount: i128) -> (i128, i128, i128) {
+    let pool_weight = (weights.oracle_pool + weights.insurance + weights.treasury) as i128;
+    let oracle_pool = amount * weights.oracle_pool as i128 / pool_weight;
+    let insurance = amount * weights.insurance as i128 / pool_weight;
+    (oracle_pool, insurance, amount - oracle_pool - insurance)
+}
+
+#[contractimpl]
+impl FeeDistributor {
+    /// Initialise the contract with its governance address, the pool
+    /// recipients and the split weights.
+    /// # Errors
+    /// * [`FeeDistributorError::AlreadyInitialized`] – called more than once.
+    /// * [`FeeDistributorError::InvalidWeights`]     – weights do not sum to
+    ///   [`BPS_DENOMINATOR`], or give the pools none of it.
+    pub fn initialize(
+        env: Env,
+        governance: Address,
+        recipients: Recipients,
+        weights: SplitWeights,
+    ) -> Result<(), FeeDistributorError> {
+        if get_governance(&env).is_some() {
+            return Err(FeeDistributorError::AlreadyInitialized);
+        }
+        governance.require_auth();
+        validate_weights(&weights)?;
+
+        set_governance(&env, &governance);
+        set_recipients(&env, &recipients);
+        set_weights(&env, &weights);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Pull `amount` of `token` from `from`, crediting the creator's weight of
+    /// it to `creator`. The rest joins the unallocated balance paid out by
+    /// [`Self::distribute`]. Returns the creator's share.
+    /// # Errors
+    /// * [`FeeDistributorError::InvalidAmount`]  – `amount` ≤ 0.
+    /// * [`FeeDistributorError::NotInitialized`] – contract not initialised.
+    pub fn deposit(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        creator: Address,
+    ) -> Result<i128, FeeDistributorError> {
+        from.require_auth();
+
+        if amount <= 0 {
+            return Err(FeeDistributorError::InvalidAmount);
+        }
+        let weights = get_weights(&env).ok_or(FeeDistributorError::NotInitialized)?;
+
+        token::Client::new(&env, &token).transfer(&from, env.current_contract_address(), &amount);
+
+        let creator_share = amount * weights.creator as i128 / BPS_DENOMINATOR as i128;
+        if creator_share > 0 {
+            add_creator_balance(&env, &creator, &token, creator_share);
+            let mut totals = get_distributed(&env, &token);
+            totals.creator += creator_share;
+            set_distributed(&env, &token, &totals);
+        }
+        extend_instance_ttl(&env);
+
+        emit_fee_deposited(&env, &token, &from, &creator, amount, creator_share);
+
+        Ok(creator_share)
+    }
+
+    /// Pay the contract's unallocated balance of `token` out to the oracle
+    /// pool, insurance fund and treasury. Anyone may call it. The creator
+    /// weight of fees deposited with [`Self::deposit`] was already credited,
+    /// and fees transferred straight in have no creator, so the whole balance
+    /// is split by the pool weights alone. Returns the amounts paid, with
+    /// `creator` always 0.
+    /// # Errors
+    /// * [`FeeDistributorError::NotInitialized`]      – contract not initialised.
+    /// * [`FeeDistributorError::NothingToDistribute`] – no unallocated balance.
+    pub fn distribute(env: Env, token: Address) -> Result<DistributionTotals, FeeDistributorError> {
+        let weights = get_weights(&env).ok_or(FeeDistributorError::NotInitialized)?;
+        let recipients = get_recipients(&env).ok_or(FeeDistributorError::NotInitialized)?;
+
+        let amount = unallocated(&env, &token);
+        if amount == 0 {
+            return Err(FeeDistributorError::NothingToDistribute);
+        }
+        let (oracle_pool, insurance, treasury) = pool_shares(&weights, amount);
+
+        let client = token::Client::new(&env, &token);
+        let this = env.current_contract_address();
+        for (to, share) in [
+            (&recipients.oracle_pool, oracle_pool),
+            (&recipients.insurance_fund, insurance),
+            (&recipients.treasury, treasury),
+        ] {
+            if share > 0 {
+                client.transfer(&this, to, &share);
+            }
+        }
+
+        let mut totals = get_distributed(&env, &token);
+        totals.oracle_pool += oracle_pool;
+        totals.insurance += insurance;
+        totals.treasury += treasury;
+        set_distributed(&env, &token, &totals);
+        extend_instance_ttl(&env);
+
+        emit_fees_distributed(&env, &token, oracle_pool, insurance, treasury);
+
+        Ok(DistributionTotals {
+            creator: 0,
+            oracle_pool,
+            insurance,
+            treasury,
+        })
+    }
+
+    /// Withdraw a creator's accrued balance of `token`. Returns the amount.
+    /// # Errors
+    /// * [`FeeDistributorError::NothingToClaim`] – no accrued balance.
+    pub fn claim_creator_fees(
+        env: Env,
+        creator: Address,
+        token: Address,
+    ) -> Result<i128, FeeDistributorError> {
+        creator.require_auth();
+
+        let amount = get_creator_balance(&env, &creator, &token);
+        if amount == 0 {
+            return Err(FeeDistributorError::NothingToClaim);
+        }
+        add_creator_balance(&env, &creator, &token, -amount);
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &creator,
+            &amount,
+        );
+
+        emit_creator_fees_claimed(&env, &creator, &token, amount);
+
+        Ok(amount)
+    }
+
+    /// Replace the split weights (governance only). Applies to deposits and
+    /// distributions from now on; creator balances already credited stay.
+    /// # Errors
+    /// * [`FeeDistributorError::NotInitialized`] – contract not initialised.
+    /// * [`FeeDistributorError::InvalidWeights`] – weights do not sum to
+    ///   [`BPS_DENOMINATOR`], or give the pools none of it.
+    pub fn set_weights(env: Env, weights: SplitWeights) -> Result<(), FeeDistributorError> {
+        require_governance(&env)?;
+        validate_weights(&weights)?;
+
+        set_weights(&env, &weights);
+        extend_instance_ttl(&env);
+
+        emit_weights_updated(&env, &weights);
+
+        Ok(())
+    }
+
+    /// Replace the pool recipients (governance only).
+    /// # Errors
+    /// * [`FeeDistributorError::NotInitialized`] – contract not initialised.
+    pub fn set_recipients(env: Env, recipients: Recipients) -> Result<(), FeeDistributorError> {
+        require_governance(&env)?;
+
+        set_recipients(&env, &recipients);
+        extend_instance_ttl(&env);
+
+        emit_recipients_updated(&env, &recipients);
+
+        Ok(())
+    }
+
+    /// Hand the governance role to `new_governance` (governance only).
+    /// # Errors
+    /// * [`FeeDistributorError::NotInitialized`] – contract not initialised.
+    pub fn set_governance(env: Env, new_governance: Address) -> Result<(), FeeDistributorError> {
+        let old = require_governance(&env)?;
+
+        set_governance(&env, &new_governance);
+        extend_instance_ttl(&env);
+
+        emit_governance_changed(&env, &old, &new_governance);
+
+        Ok(())
+    }
+
+    /// Get the governance address.
+    /// # Errors
+    /// * [`FeeDistributorError::NotInitialized`] – contract not initialised.
+    pub fn get_governance(env: Env) -> Result<Address, FeeDistributorError> {
+        get_governance(&env).ok_or(FeeDistributorError::NotInitialized)
+    }
+
+    /// Get the split weights.
+    /// # Errors
+    /// * [`FeeDistributorError::NotInitialized`] – contract not initialised.
+    pub fn get_weights(env: Env) -> Result<SplitWeights, FeeDistributorError> {
+        get_weights(&env).ok_or(FeeDistributorError::NotInitialized)
+    }
+
+    /// Get the pool recipients.
+    /// # Errors
+    /// * [`FeeDistributorError::NotInitialized`] – contract not initialised.
+    pub fn get_recipients(env: Env) -> Result<Recipients, FeeDistributorError> {
+        get_recipients(&env).ok_or(FeeDistributorError::NotInitialized)
+    }
+
+    /// Get the balance of `token` the next [`Self::distribute`] would pay out.
+    pub fn get_pending(env: Env, token: Address) -> i128 {
+        unallocated(&env, &token)
+    }
+
+    /// Get a creator's unclaimed balance of `token`.
+    pub fn get_creator_balance(env: Env, creator: Address, token: Address) -> i128 {
+        get_creator_balance(&env, &creator, &token)
+    }
+
+    /// Get the total of `token` credited to creators but not yet claimed.
+    pub fn get_creator_owed(env: Env, token: Address) -> i128 {
+        get_creator_owed(&env, &token)
+    }
+
+    /// Get the lifetime amounts of `token` allocated to each destination.
+    pub fn get_distributed(env: Env, token: Address) -> DistributionTotals {
+        get_distributed(&env, &token)
+    }
+}
@@ -0,0 +1,149 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Governance,
+    Recipients,
+    Weights,
+    /// Sum of unclaimed creator balances of a token
+    CreatorOwed(Address),
+    /// A creator's unclaimed balance of a token: (creator, token)
+    CreatorBalance(Address, Address),
+    /// Lifetime accounting of a token
+    Distributed(Address),
+}
+
+/// Share of every fee routed to each destination, in basis points. The four
+/// weights always sum to `BPS_DENOMINATOR`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SplitWeights {
+    /// Credited to the call creator named on `deposit`; fees transferred
+    /// straight in carry no creator and skip it
+    pub creator: u32,
+    pub oracle_pool: u32,
+    pub insurance: u32,
+    pub treasury: u32,
+}
+
+/// Addresses the non-creator shares are paid to.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recipients {
+    /// Oracle-reward pool
+    pub oracle_pool: Address,
+    pub insurance_fund: Address,
+    pub treasury: Address,
+}
+
+/// Lifetime amounts of one token allocated to each destination.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DistributionTotals {
+    /// Credited to creators, whether claimed yet or not
+    pub creator: i128,
+    pub oracle_pool: i128,
+    pub insurance: i128,
+    pub treasury: i128,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the governance address, if initialised
+pub fn get_governance(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Governance)
+}
+
+/// Store the governance address
+pub fn set_governance(env: &Env, governance: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Governance, governance);
+}
+
+/// Get the pool recipients, if initialised
+pub fn get_recipients(env: &Env) -> Option<Recipients> {
+    env.storage().instance().get(&DataKey::Recipients)
+}
+
+/// Store the pool recipients
+pub fn set_recipients(env: &Env, recipients: &Recipients) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Recipients, recipients);
+}
+
+/// Get the split weights, if initialised
+pub fn get_weights(env: &Env) -> Option<SplitWeights> {
+    env.storage().instance().get(&DataKey::Weights)
+}
+
+/// Store the split weights
+pub fn set_weights(env: &Env, weights: &SplitWeights) {
+    env.storage().instance().set(&DataKey::Weights, weights);
+}
+
+/// Get the total of a token still owed to creators
+pub fn get_creator_owed(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorOwed(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Get a creator's unclaimed balance of a token
+pub fn get_creator_balance(env: &Env, creator: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorBalance(creator.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Adjust a creator's balance of a token, keeping the owed total in step
+pub fn add_creator_balance(env: &Env, creator: &Address, token: &Address, delta: i128) {
+    let key = DataKey::CreatorBalance(creator.clone(), token.clone());
+    let balance = get_creator_balance(env, creator, token) + delta;
+    env.storage().persistent().set(&key, &balance);
+    bump_persistent(env, &key);
+
+    let key = DataKey::CreatorOwed(token.clone());
+    let owed = get_creator_owed(env, token) + delta;
+    env.storage().persistent().set(&key, &owed);
+    bump_persistent(env, &key);
+}
+
+/// Get the lifetime accounting of a token
+pub fn get_distributed(env: &Env, token: &Address) -> DistributionTotals {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Distributed(token.clone()))
+        .unwrap_or_default()
+}
+
+/// Store the lifetime accounting of a token
+pub fn set_distributed(env: &Env, token: &Address, totals: &DistributionTotals) {
+    let key = DataKey::Distributed(token.clone());
+    env.storage().persistent().set(&key, totals);
+    bump_persistent(env, &key);
+}
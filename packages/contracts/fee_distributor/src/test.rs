@@ -0,0 +1,181 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+use crate::errors::FeeDistributorError;
+use crate::{FeeDistributor, FeeDistributorClient, Recipients, SplitWeights};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+struct Setup {
+    env: Env,
+    client: FeeDistributorClient<'static>,
+    token: token::Client<'static>,
+    recipients: Recipients,
+    payer: Address,
+    creator: Address,
+}
+
+/// 20% creator, 30% oracle pool, 10% insurance, 40% treasury.
+fn weights() -> SplitWeights {
+    SplitWeights {
+        creator: 2_000,
+        oracle_pool: 3_000,
+        insurance: 1_000,
+        treasury: 4_000,
+    }
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let recipients = Recipients {
+        oracle_pool: Address::generate(&env),
+        insurance_fund: Address::generate(&env),
+        treasury: Address::generate(&env),
+    };
+    let client = FeeDistributorClient::new(&env, &env.register(FeeDistributor, ()));
+    client.initialize(&Address::generate(&env), &recipients, &weights());
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_id).mint(&payer, &100_000);
+
+    Setup {
+        token: token::Client::new(&env, &token_id),
+        env: env.clone(),
+        client,
+        recipients,
+        payer,
+        creator: Address::generate(&env),
+    }
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_deposit_credits_creator_and_distribute_splits_the_rest() {
+    let s = setup();
+
+    let share = s
+        .client
+        .deposit(&s.payer, &s.token.address, &1_000, &s.creator);
+    assert_eq!(share, 200);
+    assert_eq!(
+        s.client.get_creator_balance(&s.creator, &s.token.address),
+        200
+    );
+    assert_eq!(s.client.get_pending(&s.token.address), 800);
+
+    // 800 split 3:1:4 across the pools
+    let paid = s.client.distribute(&s.token.address);
+    assert_eq!(
+        (paid.oracle_pool, paid.insurance, paid.treasury),
+        (300, 100, 400)
+    );
+    assert_eq!(s.token.balance(&s.recipients.oracle_pool), 300);
+    assert_eq!(s.token.balance(&s.recipients.insurance_fund), 100);
+    assert_eq!(s.token.balance(&s.recipients.treasury), 400);
+    assert_eq!(s.token.balance(&s.client.address), 200);
+
+    let result = s.client.try_distribute(&s.token.address);
+    assert_eq!(result, Err(Ok(FeeDistributorError::NothingToDistribute)));
+
+    assert_eq!(
+        s.client.claim_creator_fees(&s.creator, &s.token.address),
+        200
+    );
+    assert_eq!(s.token.balance(&s.creator), 200);
+    assert_eq!(s.client.get_creator_owed(&s.token.address), 0);
+    let result = s
+        .client
+        .try_claim_creator_fees(&s.creator, &s.token.address);
+    assert_eq!(result, Err(Ok(FeeDistributorError::NothingToClaim)));
+
+    let totals = s.client.get_distributed(&s.token.address);
+    assert_eq!(totals.creator, 200);
+    assert_eq!(totals.treasury, 400);
+}
+
+#[test]
+fn test_direct_transfers_go_to_pools_and_dust_to_treasury() {
+    let s = setup();
+    s.token.transfer(&s.payer, &s.client.address, &7);
+
+    let paid = s.client.distribute(&s.token.address);
+    assert_eq!((paid.oracle_pool, paid.insurance, paid.treasury), (2, 0, 5));
+    assert_eq!(s.client.get_pending(&s.token.address), 0);
+}
+
+#[test]
+fn test_direct_transfers_skip_the_creator_weight() {
+    let s = setup();
+    s.client
+        .deposit(&s.payer, &s.token.address, &1_000, &s.creator);
+    s.token.transfer(&s.payer, &s.client.address, &1_000);
+
+    // Only the deposit credits the creator; the transfer is split 3:1:4
+    assert_eq!(s.client.get_creator_owed(&s.token.address), 200);
+    assert_eq!(s.client.get_pending(&s.token.address), 1_800);
+    let paid = s.client.distribute(&s.token.address);
+    assert_eq!(
+        (
+            paid.creator,
+            paid.oracle_pool,
+            paid.insurance,
+            paid.treasury
+        ),
+        (0, 675, 225, 900)
+    );
+    assert_eq!(s.token.balance(&s.client.address), 200);
+}
+
+#[test]
+fn test_governance_updates_weights_and_rejects_bad_splits() {
+    let s = setup();
+    let bad = SplitWeights {
+        creator: 5_000,
+        oracle_pool: 5_000,
+        insurance: 1,
+        treasury: 0,
+    };
+    let result = s.client.try_set_weights(&bad);
+    assert_eq!(result, Err(Ok(FeeDistributorError::InvalidWeights)));
+
+    // Fees transferred straight in would have nowhere to go
+    let all_creator = SplitWeights {
+        creator: 10_000,
+        oracle_pool: 0,
+        insurance: 0,
+        treasury: 0,
+    };
+    let result = s.client.try_set_weights(&all_creator);
+    assert_eq!(result, Err(Ok(FeeDistributorError::InvalidWeights)));
+
+    let mostly_creator = SplitWeights {
+        creator: 9_000,
+        oracle_pool: 0,
+        insurance: 0,
+        treasury: 1_000,
+    };
+    s.client.set_weights(&mostly_creator);
+    assert_eq!(s.client.get_weights(), mostly_creator);
+    assert_eq!(
+        s.client
+            .deposit(&s.payer, &s.token.address, &500, &s.creator),
+        450
+    );
+    assert_eq!(s.client.get_pending(&s.token.address), 50);
+
+    let result = s
+        .client
+        .try_deposit(&s.payer, &s.token.address, &0, &s.creator);
+    assert_eq!(result, Err(Ok(FeeDistributorError::InvalidAmount)));
+
+    let governance = Address::generate(&s.env);
+    s.client.set_governance(&governance);
+    assert_eq!(s.client.get_governance(), governance);
+}
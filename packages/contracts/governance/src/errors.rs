@@ -0,0 +1,44 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum GovernanceError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// A voting period of 0, a threshold above 10 000 bps, or a timelock below
+    /// `MIN_TIMELOCK_DELAY`.
+    InvalidConfig = 3,
+    /// The proposer holds fewer voting tokens than the proposal threshold.
+    BelowProposalThreshold = 4,
+    /// The proposal has no actions, or more than `MAX_ACTIONS`.
+    InvalidActions = 5,
+    /// No proposal exists for the supplied id.
+    ProposalNotFound = 6,
+    /// The proposal's voting period is over, or it was cancelled.
+    VotingClosed = 7,
+    /// The voter already voted on the proposal.
+    AlreadyVoted = 8,
+    /// `support` is not one of `AGAINST`, `FOR` or `ABSTAIN`.
+    InvalidSupport = 9,
+    /// The amount of voting tokens locked is ≤ 0.
+    InvalidAmount = 10,
+    /// The proposal did not pass, or has already been queued.
+    ProposalNotSucceeded = 11,
+    /// The proposal is not queued, or its execution window has passed.
+    ProposalNotQueued = 12,
+    /// The proposal's timelock has not elapsed yet.
+    TimelockNotElapsed = 13,
+    /// The caller is not the proposal's proposer.
+    Unauthorized = 14,
+    /// The proposal was already executed.
+    AlreadyExecuted = 15,
+    /// The voting period has not ended yet.
+    VotingNotEnded = 16,
+    /// The voter has no locked tokens on the proposal.
+    NothingToUnlock = 17,
+    /// A proposal action calls the voting token, which holds the locked votes.
+    VotingTokenAction = 18,
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::{Address, Env};
+
+/// Emitted when a proposal is created
+pub fn emit_proposal_created(env: &Env, proposal_id: u32, proposer: &Address, end_ts: u64) {
+    env.events().publish(
+        ("governance", "proposal_created"),
+        (proposal_id, proposer.clone(), end_ts),
+    );
+}
+
+/// Emitted when a voter locks tokens behind a vote
+pub fn emit_vote_cast(env: &Env, proposal_id: u32, voter: &Address, support: u32, amount: i128) {
+    env.events().publish(
+        ("governance", "vote_cast"),
+        (proposal_id, voter.clone(), support, amount),
+    );
+}
+
+/// Emitted when a passed proposal enters its timelock
+pub fn emit_proposal_queued(env: &Env, proposal_id: u32, eta: u64) {
+    env.events()
+        .publish(("governance", "proposal_queued"), (proposal_id, eta));
+}
+
+/// Emitted when a queued proposal's actions run
+pub fn emit_proposal_executed(env: &Env, proposal_id: u32) {
+    env.events()
+        .publish(("governance", "proposal_executed"), proposal_id);
+}
+
+/// Emitted when a proposer withdraws their proposal
+pub fn emit_proposal_cancelled(env: &Env, proposal_id: u32) {
+    env.events()
+        .publish(("governance", "proposal_cancelled"), proposal_id);
+}
+
+/// Emitted when a voter takes back the tokens locked behind a vote
+pub fn emit_votes_unlocked(env: &Env, proposal_id: u32, voter: &Address, amount: i128) {
+    env.events().publish(
+        ("governance", "votes_unlocked"),
+        (proposal_id, voter.clone(), amount),
+    );
+}
@@ -0,0 +1,374 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Token-voted governance for BACKit protocol parameters.
+//!
+//! Holders of the voting token create proposals made of cross-contract calls
+//! (set fees, rotate oracles, upgrade contracts), vote on them by locking
+//! tokens for the voting period, and, once a proposal passes its quorum and
+//! threshold, queue it behind a timelock and execute it. Protocol contracts
+//! take part by making this contract their admin or governance address, so
+//! the calls it makes on execution carry its authorisation.
+//!
+//! Locked votes are returned with `unlock` once voting ends, so the same
+//! tokens cannot be counted twice on one proposal. Proposals may not call the
+//! voting token, so execution can never move the votes held in escrow.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, String, Val, Vec};
+
+use errors::GovernanceError;
+use events::*;
+use storage::*;
+
+pub use storage::{GovernanceConfig, Proposal, ProposalAction, ProposalState, Vote};
+
+/// Ballot values for [`GovernanceContract::vote`].
+pub const AGAINST: u32 = 0;
+pub const FOR: u32 = 1;
+pub const ABSTAIN: u32 = 2;
+
+/// Most actions a single proposal may carry.
+pub const MAX_ACTIONS: u32 = 10;
+
+/// Seconds after its eta within which a queued proposal must be executed.
+pub const GRACE_PERIOD_SECS: u64 = 14 * 24 * 60 * 60;
+
+/// Shortest timelock a passed proposal may wait in the queue.
+pub const MIN_TIMELOCK_DELAY: u64 = 24 * 60 * 60;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+#[contract]
+pub struct GovernanceContract;
+
+fn load_proposal(env: &Env, id: u32) -> Result<Proposal, GovernanceError> {
+    get_proposal(env, id).ok_or(GovernanceError::ProposalNotFound)
+}
+
+/// Whether a proposal's votes meet the quorum and pass threshold.
+fn passed(config: &GovernanceConfig, proposal: &Proposal) -> bool {
+    let decisive = proposal.for_votes + proposal.against_votes;
+    proposal.for_votes > 0
+        && proposal.for_votes + proposal.abstain_votes >= config.quorum
+        && proposal.for_votes * BPS_DENOMINATOR >= config.pass_threshold_bps as i128 * decisive
+}
+
+fn proposal_state(env: &Env, config: &GovernanceConfig, proposal: &Proposal) -> ProposalState {
+    let now = env.ledger().timestamp();
+    if proposal.cancelled {
+        ProposalState::Cancelled
+    } else if proposal.executed {
+        ProposalState::Executed
+    } else if now < proposal.end_ts {
+        ProposalState::Active
+    } else if proposal.eta > 0 {
+        if now > proposal.eta.saturating_add(GRACE_PERIOD_SECS) {
+            ProposalState::Expired
+        } else {
+            ProposalState::Queued
+        }
+    } else if passed(config, proposal) {
+        ProposalState::Succeeded
+    } else {
+        ProposalState::Defeated
+    }
+}
+
+#[contractimpl]
+impl GovernanceContract {
+    /// Initialise the contract with its voting rules. `deployer` must sign.
+    /// # Errors
+    /// * [`GovernanceError::AlreadyInitialized`] – called more than once.
+    /// * [`GovernanceError::InvalidConfig`]      – `voting_period` is 0,
+    ///   `pass_threshold_bps` exceeds 10 000 or `timelock_delay` is below
+    ///   [`MIN_TIMELOCK_DELAY`].
+    pub fn initialize(
+        env: Env,
+        deployer: Address,
+        config: GovernanceConfig,
+    ) -> Result<(), GovernanceError> {
+        if get_config(&env).is_some() {
+            return Err(GovernanceError::AlreadyInitialized);
+        }
+        deployer.require_auth();
+        if config.voting_period == 0
+            || config.pass_threshold_bps as i128 > BPS_DENOMINATOR
+            || config.timelock_delay < MIN_TIMELOCK_DELAY
+        {
+            return Err(GovernanceError::InvalidConfig);
+        }
+
+        set_config(&env, &config);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Open a proposal to run `actions`, voted on from now until
+    /// `voting_period` has elapsed. Returns the proposal id.
+    /// # Errors
+    /// * [`GovernanceError::NotInitialized`]         – contract not initialised.
+    /// * [`GovernanceError::InvalidActions`]         – no actions, or more than
+    ///   [`MAX_ACTIONS`].
+    /// * [`GovernanceError::VotingTokenAction`]      – an action calls the voting
+    ///   token.
+    /// * [`GovernanceError::BelowProposalThreshold`] – `proposer` holds too few
+    ///   voting tokens.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        actions: Vec<ProposalAction>,
+        description: String,
+    ) -> Result<u32, GovernanceError> {
+        proposer.require_auth();
+
+        let config = get_config(&env).ok_or(GovernanceError::NotInitialized)?;
+        if actions.is_empty() || actions.len() > MAX_ACTIONS {
+            return Err(GovernanceError::InvalidActions);
+        }
+        if actions
+            .iter()
+            .any(|action| action.contract == config.voting_token)
+        {
+            return Err(GovernanceError::VotingTokenAction);
+        }
+        let balance = token::Client::new(&env, &config.voting_token).balance(&proposer);
+        if balance < config.proposal_threshold {
+            return Err(GovernanceError::BelowProposalThreshold);
+        }
+
+        let proposal = Proposal {
+            id: next_proposal_id(&env),
+            proposer: proposer.clone(),
+            description,
+            actions,
+            end_ts: env.ledger().timestamp() + config.voting_period,
+            for_votes: 0,
+            against_votes: 0,
+            abstain_votes: 0,
+            eta: 0,
+            executed: false,
+            cancelled: false,
+        };
+        set_proposal(&env, &proposal);
+        extend_instance_ttl(&env);
+
+        emit_proposal_created(&env, proposal.id, &proposer, proposal.end_ts);
+
+        Ok(proposal.id)
+    }
+
+    /// Vote on an active proposal by locking `amount` voting tokens in this
+    /// contract until voting ends. `support` is [`AGAINST`], [`FOR`] or
+    /// [`ABSTAIN`].
+    /// # Errors
+    /// * [`GovernanceError::InvalidSupport`]   – unknown `support` value.
+    /// * [`GovernanceError::InvalidAmount`]    – `amount` ≤ 0.
+    /// * [`GovernanceError::ProposalNotFound`] – no proposal `proposal_id`.
+    /// * [`GovernanceError::VotingClosed`]     – the proposal is not active.
+    /// * [`GovernanceError::AlreadyVoted`]     – `voter` already voted on it.
+    pub fn vote(
+        env: Env,
+        voter: Address,
+        proposal_id: u32,
+        support: u32,
+        amount: i128,
+    ) -> Result<(), GovernanceError> {
+        voter.require_auth();
+
+        if support > ABSTAIN {
+            return Err(GovernanceError::InvalidSupport);
+        }
+        if amount <= 0 {
+            return Err(GovernanceError::InvalidAmount);
+        }
+        let config = get_config(&env).ok_or(GovernanceError::NotInitialized)?;
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        if proposal_state(&env, &config, &proposal) != ProposalState::Active {
+            return Err(GovernanceError::VotingClosed);
+        }
+        if get_vote(&env, proposal_id, &voter).is_some() {
+            return Err(GovernanceError::AlreadyVoted);
+        }
+
+        token::Client::new(&env, &config.voting_token).transfer(
+            &voter,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        match support {
+            AGAINST => proposal.against_votes += amount,
+            FOR => proposal.for_votes += amount,
+            _ => proposal.abstain_votes += amount,
+        }
+        set_proposal(&env, &proposal);
+        let vote = Vote {
+            support,
+            amount,
+            unlocked: false,
+        };
+        set_vote(&env, proposal_id, &voter, &vote);
+
+        emit_vote_cast(&env, proposal_id, &voter, support, amount);
+
+        Ok(())
+    }
+
+    /// Queue a passed proposal; it becomes executable after the timelock
+    /// delay. Anyone may call it. Returns the eta.
+    /// # Errors
+    /// * [`GovernanceError::ProposalNotFound`]     – no proposal `proposal_id`.
+    /// * [`GovernanceError::ProposalNotSucceeded`] – voting is open, the
+    ///   proposal failed, or it is already queued.
+    pub fn queue(env: Env, proposal_id: u32) -> Result<u64, GovernanceError> {
+        let config = get_config(&env).ok_or(GovernanceError::NotInitialized)?;
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        if proposal_state(&env, &config, &proposal) != ProposalState::Succeeded {
+            return Err(GovernanceError::ProposalNotSucceeded);
+        }
+
+        proposal.eta = env.ledger().timestamp() + config.timelock_delay;
+        set_proposal(&env, &proposal);
+
+        emit_proposal_queued(&env, proposal_id, proposal.eta);
+
+        Ok(proposal.eta)
+    }
+
+    /// Run a queued proposal's actions in order once its timelock has
+    /// elapsed. Anyone may call it. Returns each action's result.
+    /// # Errors
+    /// * [`GovernanceError::ProposalNotFound`]   – no proposal `proposal_id`.
+    /// * [`GovernanceError::AlreadyExecuted`]    – the proposal already ran.
+    /// * [`GovernanceError::ProposalNotQueued`]  – not queued, cancelled or
+    ///   past its grace period.
+    /// * [`GovernanceError::TimelockNotElapsed`] – the eta has not been reached.
+    ///
+    /// # Panics
+    /// If any action fails, which rolls back the whole execution.
+    pub fn execute(env: Env, proposal_id: u32) -> Result<Vec<Val>, GovernanceError> {
+        let config = get_config(&env).ok_or(GovernanceError::NotInitialized)?;
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        match proposal_state(&env, &config, &proposal) {
+            ProposalState::Queued => {}
+            ProposalState::Executed => return Err(GovernanceError::AlreadyExecuted),
+            _ => return Err(GovernanceError::ProposalNotQueued),
+        }
+        if env.ledger().timestamp() < proposal.eta {
+            return Err(GovernanceError::TimelockNotElapsed);
+        }
+
+        proposal.executed = true;
+        set_proposal(&env, &proposal);
+
+        let mut results = Vec::new(&env);
+        for action in proposal.actions.iter() {
+            results.push_back(env.invoke_contract::<Val>(
+                &action.contract,
+                &action.function,
+                action.args,
+            ));
+        }
+        extend_instance_ttl(&env);
+
+        emit_proposal_executed(&env, proposal_id);
+
+        Ok(results)
+    }
+
+    /// Withdraw a proposal before it executes (proposer only). Voters can
+    /// unlock their tokens straight away.
+    /// # Errors
+    /// * [`GovernanceError::ProposalNotFound`] – no proposal `proposal_id`.
+    /// * [`GovernanceError::Unauthorized`]     – `caller` is not the proposer.
+    /// * [`GovernanceError::AlreadyExecuted`]  – the proposal already ran.
+    pub fn cancel(env: Env, caller: Address, proposal_id: u32) -> Result<(), GovernanceError> {
+        caller.require_auth();
+
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        if proposal.proposer != caller {
+            return Err(GovernanceError::Unauthorized);
+        }
+        if proposal.executed {
+            return Err(GovernanceError::AlreadyExecuted);
+        }
+
+        proposal.cancelled = true;
+        set_proposal(&env, &proposal);
+
+        emit_proposal_cancelled(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Return the tokens `voter` locked behind their vote, once the voting
+    /// period is over or the proposal was cancelled. Returns the amount.
+    /// # Errors
+    /// * [`GovernanceError::ProposalNotFound`] – no proposal `proposal_id`.
+    /// * [`GovernanceError::VotingNotEnded`]   – the proposal is still active.
+    /// * [`GovernanceError::NothingToUnlock`]  – no vote, or already unlocked.
+    pub fn unlock(env: Env, voter: Address, proposal_id: u32) -> Result<i128, GovernanceError> {
+        voter.require_auth();
+
+        let config = get_config(&env).ok_or(GovernanceError::NotInitialized)?;
+        let proposal = load_proposal(&env, proposal_id)?;
+        if proposal_state(&env, &config, &proposal) == ProposalState::Active {
+            return Err(GovernanceError::VotingNotEnded);
+        }
+        let mut vote = match get_vote(&env, proposal_id, &voter) {
+            Some(vote) if !vote.unlocked => vote,
+            _ => return Err(GovernanceError::NothingToUnlock),
+        };
+
+        vote.unlocked = true;
+        set_vote(&env, proposal_id, &voter, &vote);
+        token::Client::new(&env, &config.voting_token).transfer(
+            &env.current_contract_address(),
+            &voter,
+            &vote.amount,
+        );
+
+        emit_votes_unlocked(&env, proposal_id, &voter, vote.amount);
+
+        Ok(vote.amount)
+    }
+
+    /// Get the voting rules.
+    /// # Errors
+    /// * [`GovernanceError::NotInitialized`] – contract not initialised.
+    pub fn get_config(env: Env) -> Result<GovernanceConfig, GovernanceError> {
+        get_config(&env).ok_or(GovernanceError::NotInitialized)
+    }
+
+    /// Get a proposal.
+    /// # Errors
+    /// * [`GovernanceError::ProposalNotFound`] – no proposal `proposal_id`.
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Result<Proposal, GovernanceError> {
+        load_proposal(&env, proposal_id)
+    }
+
+    /// Get a proposal's current lifecycle state.
+    /// # Errors
+    /// * [`GovernanceError::ProposalNotFound`] – no proposal `proposal_id`.
+    pub fn get_state(env: Env, proposal_id: u32) -> Result<ProposalState, GovernanceError> {
+        let config = get_config(&env).ok_or(GovernanceError::NotInitialized)?;
+        let proposal = load_proposal(&env, proposal_id)?;
+        Ok(proposal_state(&env, &config, &proposal))
+    }
+
+    /// Get a voter's ballot on a proposal, if they voted.
+    pub fn get_vote(env: Env, proposal_id: u32, voter: Address) -> Option<Vote> {
+        get_vote(&env, proposal_id, &voter)
+    }
+
+    /// Number of proposals created so far; ids run from 1 to this.
+    pub fn get_proposal_count(env: Env) -> u32 {
+        get_proposal_count(&env)
+    }
+}
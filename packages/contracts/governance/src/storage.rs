@@ -0,0 +1,154 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Val, Vec};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Config,
+    ProposalCounter,
+    Proposal(u32),
+    /// A voter's ballot on a proposal: (proposal_id, voter)
+    Vote(u32, Address),
+}
+
+/// Voting rules, fixed at initialisation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceConfig {
+    /// Token whose holders vote by locking it
+    pub voting_token: Address,
+    /// Voting tokens a proposer must hold to create a proposal
+    pub proposal_threshold: i128,
+    /// Seconds a proposal is open for votes
+    pub voting_period: u64,
+    /// Minimum FOR plus ABSTAIN votes for a proposal to pass
+    pub quorum: i128,
+    /// Share of FOR among FOR and AGAINST votes needed to pass, in basis points
+    pub pass_threshold_bps: u32,
+    /// Seconds a passed proposal waits in the queue before it can execute
+    pub timelock_delay: u64,
+}
+
+/// One cross-contract call a proposal makes when executed, with this
+/// contract as the caller.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalAction {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proposal {
+    pub id: u32,
+    pub proposer: Address,
+    pub description: String,
+    pub actions: Vec<ProposalAction>,
+    /// Votes are accepted until this timestamp
+    pub end_ts: u64,
+    pub for_votes: i128,
+    pub against_votes: i128,
+    pub abstain_votes: i128,
+    /// Earliest execution time once queued, 0 before
+    pub eta: u64,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+/// Lifecycle of a proposal, derived from its record and the ledger time.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum ProposalState {
+    Active = 0,
+    Defeated = 1,
+    Succeeded = 2,
+    Queued = 3,
+    Executed = 4,
+    Cancelled = 5,
+    /// Queued but not executed within the grace period
+    Expired = 6,
+}
+
+/// A voter's ballot and the tokens locked behind it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vote {
+    pub support: u32,
+    pub amount: i128,
+    pub unlocked: bool,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the voting rules, if initialised
+pub fn get_config(env: &Env) -> Option<GovernanceConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+/// Store the voting rules
+pub fn set_config(env: &Env, config: &GovernanceConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Number of proposals created so far
+pub fn get_proposal_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProposalCounter)
+        .unwrap_or(0)
+}
+
+/// Allocate the next proposal id
+pub fn next_proposal_id(env: &Env) -> u32 {
+    let id = get_proposal_count(env) + 1;
+    env.storage().instance().set(&DataKey::ProposalCounter, &id);
+    id
+}
+
+/// Get a proposal
+pub fn get_proposal(env: &Env, id: u32) -> Option<Proposal> {
+    env.storage().persistent().get(&DataKey::Proposal(id))
+}
+
+/// Store a proposal
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    bump_persistent(env, &key);
+}
+
+/// Get a voter's ballot on a proposal
+pub fn get_vote(env: &Env, proposal_id: u32, voter: &Address) -> Option<Vote> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Vote(proposal_id, voter.clone()))
+}
+
+/// Store a voter's ballot on a proposal
+pub fn set_vote(env: &Env, proposal_id: u32, voter: &Address, vote: &Vote) {
+    let key = DataKey::Vote(proposal_id, voter.clone());
+    env.storage().persistent().set(&key, vote);
+    bump_persistent(env, &key);
+}
@@ -0,0 +1,256 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger as _},
+    token, vec, Address, Env, IntoVal, String,
+};
+
+use crate::errors::GovernanceError;
+use crate::{
+    GovernanceConfig, GovernanceContract, GovernanceContractClient, ProposalAction, ProposalState,
+    AGAINST, FOR, MIN_TIMELOCK_DELAY,
+};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+/// Protocol contract stand-in whose fee only its admin may change.
+#[contract]
+pub struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn set_admin(env: Env, admin: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("admin"), &admin);
+    }
+
+    pub fn set_fee(env: Env, fee_bps: u32) -> u32 {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("admin"))
+            .unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("fee"), &fee_bps);
+        fee_bps
+    }
+
+    pub fn get_fee(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("fee"))
+            .unwrap_or(0)
+    }
+}
+
+const VOTING_PERIOD: u64 = 1_000;
+const TIMELOCK: u64 = MIN_TIMELOCK_DELAY;
+
+fn config(voting_token: &Address) -> GovernanceConfig {
+    GovernanceConfig {
+        voting_token: voting_token.clone(),
+        proposal_threshold: 100,
+        voting_period: VOTING_PERIOD,
+        quorum: 1_000,
+        pass_threshold_bps: 6_000,
+        timelock_delay: TIMELOCK,
+    }
+}
+
+struct Setup {
+    env: Env,
+    client: GovernanceContractClient<'static>,
+    target: MockTargetClient<'static>,
+    voting_token: token::Client<'static>,
+    proposer: Address,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let client = GovernanceContractClient::new(&env, &env.register(GovernanceContract, ()));
+    client.initialize(&Address::generate(&env), &config(&token_id));
+
+    let target = MockTargetClient::new(&env, &env.register(MockTarget, ()));
+    target.set_admin(&client.address);
+
+    let proposer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_id).mint(&proposer, &100);
+
+    Setup {
+        voting_token: token::Client::new(&env, &token_id),
+        env,
+        client,
+        target,
+        proposer,
+    }
+}
+
+/// A voter holding `amount` voting tokens.
+fn voter(s: &Setup, amount: i128) -> Address {
+    let voter = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.voting_token.address).mint(&voter, &amount);
+    voter
+}
+
+fn propose_fee(s: &Setup, fee_bps: u32) -> u32 {
+    let action = ProposalAction {
+        contract: s.target.address.clone(),
+        function: symbol_short!("set_fee"),
+        args: vec![&s.env, fee_bps.into_val(&s.env)],
+    };
+    s.client.propose(
+        &s.proposer,
+        &vec![&s.env, action],
+        &String::from_str(&s.env, "Raise the fee"),
+    )
+}
+
+fn advance(s: &Setup, secs: u64) {
+    let now = s.env.ledger().timestamp();
+    s.env.ledger().set_timestamp(now + secs);
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_passed_proposal_executes_after_timelock() {
+    let s = setup();
+    let id = propose_fee(&s, 250);
+    let yes = voter(&s, 1_000);
+    let no = voter(&s, 500);
+    s.client.vote(&yes, &id, &FOR, &1_000);
+    s.client.vote(&no, &id, &AGAINST, &500);
+    assert_eq!(s.voting_token.balance(&s.client.address), 1_500);
+
+    let result = s.client.try_queue(&id);
+    assert_eq!(result, Err(Ok(GovernanceError::ProposalNotSucceeded)));
+    advance(&s, VOTING_PERIOD);
+    assert_eq!(s.client.get_state(&id), ProposalState::Succeeded);
+
+    let eta = s.client.queue(&id);
+    let result = s.client.try_execute(&id);
+    assert_eq!(result, Err(Ok(GovernanceError::TimelockNotElapsed)));
+    s.env.ledger().set_timestamp(eta);
+    s.client.execute(&id);
+    assert_eq!(s.target.get_fee(), 250);
+    assert_eq!(s.client.get_state(&id), ProposalState::Executed);
+    let result = s.client.try_execute(&id);
+    assert_eq!(result, Err(Ok(GovernanceError::AlreadyExecuted)));
+
+    assert_eq!(s.client.unlock(&yes, &id), 1_000);
+    assert_eq!(s.voting_token.balance(&yes), 1_000);
+    let result = s.client.try_unlock(&yes, &id);
+    assert_eq!(result, Err(Ok(GovernanceError::NothingToUnlock)));
+}
+
+#[test]
+fn test_quorum_and_threshold_defeat_proposals() {
+    let s = setup();
+
+    // Below quorum
+    let short = propose_fee(&s, 100);
+    let yes = voter(&s, 2_099);
+    s.client.vote(&yes, &short, &FOR, &999);
+    let result = s.client.try_vote(&yes, &short, &FOR, &1);
+    assert_eq!(result, Err(Ok(GovernanceError::AlreadyVoted)));
+
+    // Quorum reached, but 55% FOR is under the 60% threshold
+    let split = propose_fee(&s, 200);
+    let no = voter(&s, 900);
+    s.client.vote(&yes, &split, &FOR, &1_100);
+    s.client.vote(&no, &split, &AGAINST, &900);
+
+    advance(&s, VOTING_PERIOD);
+    assert_eq!(s.client.get_state(&short), ProposalState::Defeated);
+    assert_eq!(s.client.get_state(&split), ProposalState::Defeated);
+    let result = s.client.try_vote(&no, &short, &AGAINST, &1);
+    assert_eq!(result, Err(Ok(GovernanceError::VotingClosed)));
+    let result = s.client.try_queue(&split);
+    assert_eq!(result, Err(Ok(GovernanceError::ProposalNotSucceeded)));
+}
+
+#[test]
+fn test_proposal_rules_and_cancellation() {
+    let s = setup();
+    let poor = Address::generate(&s.env);
+    let result = s.client.try_propose(
+        &poor,
+        &vec![
+            &s.env,
+            ProposalAction {
+                contract: s.target.address.clone(),
+                function: symbol_short!("set_fee"),
+                args: vec![&s.env, 1u32.into_val(&s.env)],
+            },
+        ],
+        &String::from_str(&s.env, ""),
+    );
+    assert_eq!(result, Err(Ok(GovernanceError::BelowProposalThreshold)));
+    let result = s
+        .client
+        .try_propose(&s.proposer, &vec![&s.env], &String::from_str(&s.env, ""));
+    assert_eq!(result, Err(Ok(GovernanceError::InvalidActions)));
+
+    let id = propose_fee(&s, 300);
+    let yes = voter(&s, 1_000);
+    s.client.vote(&yes, &id, &FOR, &1_000);
+    let result = s.client.try_unlock(&yes, &id);
+    assert_eq!(result, Err(Ok(GovernanceError::VotingNotEnded)));
+
+    let result = s.client.try_cancel(&yes, &id);
+    assert_eq!(result, Err(Ok(GovernanceError::Unauthorized)));
+    s.client.cancel(&s.proposer, &id);
+    assert_eq!(s.client.get_state(&id), ProposalState::Cancelled);
+    assert_eq!(s.client.unlock(&yes, &id), 1_000);
+    assert_eq!(s.client.get_proposal_count(), 1);
+}
+
+#[test]
+fn test_initialize_requires_deployer_and_minimum_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let token_id = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let client = GovernanceContractClient::new(&env, &env.register(GovernanceContract, ()));
+
+    let mut short = config(&token_id);
+    short.timelock_delay = MIN_TIMELOCK_DELAY - 1;
+    let result = client.try_initialize(&deployer, &short);
+    assert_eq!(result, Err(Ok(GovernanceError::InvalidConfig)));
+
+    client.initialize(&deployer, &config(&token_id));
+    assert_eq!(env.auths()[0].0, deployer);
+    let result = client.try_initialize(&deployer, &config(&token_id));
+    assert_eq!(result, Err(Ok(GovernanceError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_proposals_cannot_call_the_voting_token() {
+    let s = setup();
+    let action = ProposalAction {
+        contract: s.voting_token.address.clone(),
+        function: symbol_short!("transfer"),
+        args: vec![
+            &s.env,
+            s.client.address.into_val(&s.env),
+            s.proposer.into_val(&s.env),
+            1_000_i128.into_val(&s.env),
+        ],
+    };
+    let result = s.client.try_propose(
+        &s.proposer,
+        &vec![&s.env, action],
+        &String::from_str(&s.env, "Drain the votes"),
+    );
+    assert_eq!(result, Err(Ok(GovernanceError::VotingTokenAction)));
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum LiquidityPoolError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// A basis-point setting exceeds 10 000.
+    InvalidBps = 3,
+    /// A deposit, withdrawal or share amount is not positive.
+    InvalidAmount = 4,
+    /// The deposit is too small to mint a share.
+    ZeroShares = 5,
+    /// The LP holds fewer shares than requested.
+    InsufficientShares = 6,
+    /// Too much of the pool backs open fixed-odds books to pay the withdrawal.
+    InsufficientLiquidity = 7,
+    /// The LP's shares are still locked after their last deposit.
+    SharesLocked = 8,
+    /// Share or asset arithmetic overflowed.
+    Overflow = 9,
+    /// A share token amount is negative.
+    NegativeAmount = 10,
+    /// The share balance is lower than the amount spent.
+    InsufficientBalance = 11,
+    /// The share allowance is lower than the amount spent.
+    InsufficientAllowance = 12,
+    /// An allowance would expire at a ledger already passed.
+    InvalidExpiration = 13,
+}
@@ -0,0 +1,32 @@
+use soroban_sdk::{Address, Env};
+
+/// Emitted when an LP deposits the stake token for shares
+pub fn emit_deposited(env: &Env, lp: &Address, amount: i128, shares: i128) {
+    env.events().publish(
+        ("liquidity_pool", "deposited"),
+        (lp.clone(), amount, shares),
+    );
+}
+
+/// Emitted when an LP redeems shares for the stake token
+pub fn emit_withdrawn(env: &Env, lp: &Address, amount: i128, shares: i128) {
+    env.events().publish(
+        ("liquidity_pool", "withdrawn"),
+        (lp.clone(), amount, shares),
+    );
+}
+
+/// Emitted when the treasury's cut of pool gains is minted as shares
+pub fn emit_spread_accrued(env: &Env, gain: i128, treasury_shares: i128) {
+    env.events().publish(
+        ("liquidity_pool", "spread_accrued"),
+        (gain, treasury_shares),
+    );
+}
+
+/// Emitted when liquidity moves between the pool and the registry's house
+/// liquidity; positive amounts move into the registry
+pub fn emit_rebalanced(env: &Env, amount: i128, deployed: i128) {
+    env.events()
+        .publish(("liquidity_pool", "rebalanced"), (amount, deployed));
+}
@@ -0,0 +1,519 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! LP-funded house liquidity for a CallRegistry's fixed-odds markets.
+//!
+//! LPs deposit the stake token and receive pool shares, a SEP-41 token.
+//! The pool holds the registry's treasury manager role and places up to
+//! `max_utilization_bps` of its assets in the registry's house liquidity,
+//! where they back stakes taken against the house; the rest stays in the
+//! pool so LPs can withdraw. Books settling in the house's favour grow the
+//! pool's assets and so the value of each share. Of each gain the treasury
+//! takes the part not left to LPs (`lp_share_bps`), minted to it as shares.
+//! Withdrawals only draw on liquidity no open book has reserved.
+//!
+//! Each deposit locks the LP's shares for `lock_period`, during which they
+//! can be neither withdrawn nor transferred, so liquidity cannot step in
+//! just before a book settles in the house's favour and leave with the gain.
+//!
+//! The pool values its assets from the registry's house liquidity in the
+//! token, so it must be the only house liquidity provider for that token.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, panic_with_error, token,
+    token::TokenInterface,
+    vec, Address, Env, IntoVal, String, Symbol, I256,
+};
+
+use errors::LiquidityPoolError;
+use events::*;
+use storage::*;
+
+pub use storage::{PoolConfig, PoolState};
+
+/// Basis-point settings are expressed over this.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+#[contract]
+pub struct LiquidityPool;
+
+// ─── Cross-contract helpers ────────────────────────────────────────────────────
+
+/// Free and reserved house liquidity the registry holds in the pool's token.
+fn registry_house(env: &Env, config: &PoolConfig) -> (i128, i128) {
+    let args = vec![env, config.token.into_val(env)];
+    let free: i128 = env.invoke_contract(
+        &config.registry,
+        &Symbol::new(env, "get_house_liquidity"),
+        args.clone(),
+    );
+    let reserved: i128 = env.invoke_contract(
+        &config.registry,
+        &Symbol::new(env, "get_house_reserved"),
+        args,
+    );
+    (free, reserved)
+}
+
+/// Move `amount` of the pool's tokens into the registry's house liquidity.
+fn registry_deposit(env: &Env, config: &PoolConfig, amount: i128) {
+    let this = env.current_contract_address();
+    // The registry pulls the tokens with a transfer the pool must authorise.
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: config.token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (this.clone(), config.registry.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    env.invoke_contract::<()>(
+        &config.registry,
+        &Symbol::new(env, "deposit_house_liquidity"),
+        (this, config.token.clone(), amount).into_val(env),
+    );
+}
+
+/// Take `amount` of free house liquidity back from the registry.
+fn registry_withdraw(env: &Env, config: &PoolConfig, amount: i128) {
+    env.invoke_contract::<()>(
+        &config.registry,
+        &Symbol::new(env, "withdraw_house_liquidity"),
+        (env.current_contract_address(), config.token.clone(), amount).into_val(env),
+    );
+}
+
+// ─── Accounting ───────────────────────────────────────────────────────────────
+
+/// `a × b / c` rounded down, without intermediate overflow.
+fn mul_div(env: &Env, a: i128, b: i128, c: i128) -> i128 {
+    I256::from_i128(env, a)
+        .mul(&I256::from_i128(env, b))
+        .div(&I256::from_i128(env, c))
+        .to_i128()
+        .unwrap_or_else(|| panic_with_error!(env, LiquidityPoolError::Overflow))
+}
+
+fn pool_state(env: &Env, config: &PoolConfig) -> PoolState {
+    let idle = token::Client::new(env, &config.token).balance(&env.current_contract_address());
+    let (house_free, house_reserved) = registry_house(env, config);
+    let total_assets = idle + house_free + house_reserved;
+    PoolState {
+        idle,
+        house_free,
+        house_reserved,
+        total_assets,
+        total_shares: get_total_shares(env),
+        utilization_bps: if total_assets > 0 {
+            mul_div(env, house_reserved, BPS_DENOMINATOR as i128, total_assets) as u32
+        } else {
+            0
+        },
+    }
+}
+
+fn mint_shares(env: &Env, to: &Address, shares: i128) {
+    set_balance(env, to, get_balance(env, to) + shares);
+    set_total_shares(env, get_total_shares(env) + shares);
+}
+
+fn burn_shares(env: &Env, from: &Address, shares: i128) {
+    spend_balance(env, from, shares);
+    set_total_shares(env, get_total_shares(env) - shares);
+}
+
+/// Mint the treasury its cut of the gain since the last accrual, as shares
+/// worth that cut at `total_assets`.
+fn accrue(env: &Env, config: &PoolConfig, total_assets: i128) {
+    let gain = total_assets - get_accounted_assets(env);
+    let supply = get_total_shares(env);
+    if gain > 0 && supply > 0 {
+        let fee_bps = (BPS_DENOMINATOR - config.lp_share_bps) as i128;
+        let fee = mul_div(env, gain, fee_bps, BPS_DENOMINATOR as i128);
+        if fee > 0 && fee < total_assets {
+            let shares = mul_div(env, fee, supply, total_assets - fee);
+            mint_shares(env, &config.treasury, shares);
+            emit_spread_accrued(env, gain, shares);
+        }
+    }
+    set_accounted_assets(env, total_assets);
+}
+
+/// Move liquidity between the pool and the registry so that the registry
+/// holds `max_utilization_bps` of the pool's assets, as far as idle and free
+/// liquidity allow. Returns the amount moved into (positive) or out of the
+/// registry.
+fn rebalance(env: &Env, config: &PoolConfig) -> i128 {
+    let state = pool_state(env, config);
+    let target = mul_div(
+        env,
+        state.total_assets,
+        config.max_utilization_bps as i128,
+        BPS_DENOMINATOR as i128,
+    );
+    let deployed = state.house_free + state.house_reserved;
+
+    let moved = if deployed < target {
+        let amount = (target - deployed).min(state.idle);
+        if amount > 0 {
+            registry_deposit(env, config, amount);
+        }
+        amount
+    } else {
+        let amount = (deployed - target).min(state.house_free);
+        if amount > 0 {
+            registry_withdraw(env, config, amount);
+        }
+        -amount
+    };
+    if moved != 0 {
+        emit_rebalanced(env, moved, deployed + moved);
+    }
+    moved
+}
+
+fn require_initialized(env: &Env) -> Result<PoolConfig, LiquidityPoolError> {
+    get_config(env).ok_or(LiquidityPoolError::NotInitialized)
+}
+
+fn validate_bps(lp_share_bps: u32, max_utilization_bps: u32) -> Result<(), LiquidityPoolError> {
+    if lp_share_bps > BPS_DENOMINATOR || max_utilization_bps > BPS_DENOMINATOR {
+        return Err(LiquidityPoolError::InvalidBps);
+    }
+    Ok(())
+}
+
+// ─── SEP-41 helpers ───────────────────────────────────────────────────────────
+
+fn check_nonnegative_amount(env: &Env, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(env, LiquidityPoolError::NegativeAmount);
+    }
+}
+
+/// Whether `id`'s shares are still locked after its last deposit.
+fn is_locked(env: &Env, id: &Address) -> bool {
+    env.ledger().timestamp() < get_locked_until(env, id)
+}
+
+fn spend_balance(env: &Env, from: &Address, amount: i128) {
+    let balance = get_balance(env, from);
+    if balance < amount {
+        panic_with_error!(env, LiquidityPoolError::InsufficientBalance);
+    }
+    set_balance(env, from, balance - amount);
+}
+
+fn receive_balance(env: &Env, to: &Address, amount: i128) {
+    let balance = get_balance(env, to);
+    set_balance(env, to, balance + amount);
+}
+
+fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = get_allowance(env, from, spender);
+    if allowance.amount < amount {
+        panic_with_error!(env, LiquidityPoolError::InsufficientAllowance);
+    }
+    if amount > 0 {
+        set_allowance(
+            env,
+            from,
+            spender,
+            allowance.amount - amount,
+            allowance.expiration_ledger,
+        );
+    }
+}
+
+#[contractimpl]
+impl LiquidityPool {
+    /// Initialise the pool. Shares take the stake token's decimals.
+    /// # Errors
+    /// * [`LiquidityPoolError::AlreadyInitialized`] – called more than once.
+    /// * [`LiquidityPoolError::InvalidBps`]         – a bps setting > 10 000.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        config: PoolConfig,
+        name: String,
+        symbol: String,
+    ) -> Result<(), LiquidityPoolError> {
+        if get_admin(&env).is_some() {
+            return Err(LiquidityPoolError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        validate_bps(config.lp_share_bps, config.max_utilization_bps)?;
+
+        set_admin(&env, &admin);
+        set_metadata(
+            &env,
+            &TokenMetadata {
+                decimal: token::Client::new(&env, &config.token).decimals(),
+                name,
+                symbol,
+            },
+        );
+        set_config(&env, &config);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Change the LPs' share of gains and the utilization cap (admin only).
+    /// Gains so far are accrued at the old share first.
+    /// # Errors
+    /// * [`LiquidityPoolError::NotInitialized`] – contract not initialised.
+    /// * [`LiquidityPoolError::InvalidBps`]     – a bps setting > 10 000.
+    pub fn set_params(
+        env: Env,
+        lp_share_bps: u32,
+        max_utilization_bps: u32,
+    ) -> Result<(), LiquidityPoolError> {
+        let admin = get_admin(&env).ok_or(LiquidityPoolError::NotInitialized)?;
+        admin.require_auth();
+        validate_bps(lp_share_bps, max_utilization_bps)?;
+
+        let mut config = require_initialized(&env)?;
+        accrue(&env, &config, pool_state(&env, &config).total_assets);
+        config.lp_share_bps = lp_share_bps;
+        config.max_utilization_bps = max_utilization_bps;
+        set_config(&env, &config);
+        rebalance(&env, &config);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Deposit `amount` of the stake token from `lp` for shares at the
+    /// current share value, locking all of `lp`'s shares for the lock period.
+    /// Returns the shares minted.
+    /// # Errors
+    /// * [`LiquidityPoolError::NotInitialized`] – contract not initialised.
+    /// * [`LiquidityPoolError::InvalidAmount`]  – `amount` ≤ 0.
+    /// * [`LiquidityPoolError::ZeroShares`]     – `amount` is worth no share.
+    pub fn deposit(env: Env, lp: Address, amount: i128) -> Result<i128, LiquidityPoolError> {
+        lp.require_auth();
+        let config = require_initialized(&env)?;
+        if amount <= 0 {
+            return Err(LiquidityPoolError::InvalidAmount);
+        }
+
+        let assets = pool_state(&env, &config).total_assets;
+        accrue(&env, &config, assets);
+        // One virtual share and unit of assets keep the first deposit from
+        // setting an exploitable share price.
+        let shares = mul_div(&env, amount, get_total_shares(&env) + 1, assets + 1);
+        if shares == 0 {
+            return Err(LiquidityPoolError::ZeroShares);
+        }
+
+        token::Client::new(&env, &config.token).transfer(
+            &lp,
+            env.current_contract_address(),
+            &amount,
+        );
+        mint_shares(&env, &lp, shares);
+        set_locked_until(&env, &lp, env.ledger().timestamp() + config.lock_period);
+        set_accounted_assets(&env, assets + amount);
+        rebalance(&env, &config);
+        extend_instance_ttl(&env);
+
+        emit_deposited(&env, &lp, amount, shares);
+
+        Ok(shares)
+    }
+
+    /// Redeem `shares` of `lp` for the stake token at the current share
+    /// value. Returns the amount paid.
+    /// # Errors
+    /// * [`LiquidityPoolError::NotInitialized`]        – contract not initialised.
+    /// * [`LiquidityPoolError::InvalidAmount`]         – `shares` ≤ 0.
+    /// * [`LiquidityPoolError::InsufficientShares`]    – `lp` holds fewer shares.
+    /// * [`LiquidityPoolError::SharesLocked`]          – `lp` deposited within the
+    ///   lock period.
+    /// * [`LiquidityPoolError::InsufficientLiquidity`] – the payout exceeds
+    ///   what open books leave unreserved.
+    pub fn withdraw(env: Env, lp: Address, shares: i128) -> Result<i128, LiquidityPoolError> {
+        lp.require_auth();
+        let config = require_initialized(&env)?;
+        if shares <= 0 {
+            return Err(LiquidityPoolError::InvalidAmount);
+        }
+        if get_balance(&env, &lp) < shares {
+            return Err(LiquidityPoolError::InsufficientShares);
+        }
+        if is_locked(&env, &lp) {
+            return Err(LiquidityPoolError::SharesLocked);
+        }
+
+        let state = pool_state(&env, &config);
+        accrue(&env, &config, state.total_assets);
+        let amount = mul_div(
+            &env,
+            shares,
+            state.total_assets + 1,
+            get_total_shares(&env) + 1,
+        );
+        if amount > state.idle + state.house_free {
+            return Err(LiquidityPoolError::InsufficientLiquidity);
+        }
+
+        if amount > state.idle {
+            registry_withdraw(&env, &config, amount - state.idle);
+        }
+        burn_shares(&env, &lp, shares);
+        token::Client::new(&env, &config.token).transfer(
+            &env.current_contract_address(),
+            &lp,
+            &amount,
+        );
+        set_accounted_assets(&env, state.total_assets - amount);
+        rebalance(&env, &config);
+        extend_instance_ttl(&env);
+
+        emit_withdrawn(&env, &lp, amount, shares);
+
+        Ok(amount)
+    }
+
+    /// Take the treasury's cut of gains since the last accrual and restore
+    /// the utilization target. Callable by anyone, e.g. after books settle.
+    /// Returns the amount moved into (positive) or out of the registry.
+    /// # Errors
+    /// * [`LiquidityPoolError::NotInitialized`] – contract not initialised.
+    pub fn rebalance(env: Env) -> Result<i128, LiquidityPoolError> {
+        let config = require_initialized(&env)?;
+        accrue(&env, &config, pool_state(&env, &config).total_assets);
+        let moved = rebalance(&env, &config);
+        extend_instance_ttl(&env);
+        Ok(moved)
+    }
+
+    /// Get where the pool's assets are and how much of them back open books.
+    /// # Errors
+    /// * [`LiquidityPoolError::NotInitialized`] – contract not initialised.
+    pub fn get_pool_state(env: Env) -> Result<PoolState, LiquidityPoolError> {
+        let config = require_initialized(&env)?;
+        Ok(pool_state(&env, &config))
+    }
+
+    /// Stake token `shares` would redeem for now, before the treasury's cut
+    /// of unaccrued gains.
+    /// # Errors
+    /// * [`LiquidityPoolError::NotInitialized`] – contract not initialised.
+    pub fn convert_to_assets(env: Env, shares: i128) -> Result<i128, LiquidityPoolError> {
+        let config = require_initialized(&env)?;
+        let assets = pool_state(&env, &config).total_assets;
+        Ok(mul_div(
+            &env,
+            shares,
+            assets + 1,
+            get_total_shares(&env) + 1,
+        ))
+    }
+
+    /// Get the pool parameters.
+    /// # Errors
+    /// * [`LiquidityPoolError::NotInitialized`] – contract not initialised.
+    pub fn get_config(env: Env) -> Result<PoolConfig, LiquidityPoolError> {
+        require_initialized(&env)
+    }
+
+    /// Number of shares outstanding.
+    pub fn total_supply(env: Env) -> i128 {
+        get_total_shares(&env)
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for LiquidityPool {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        extend_instance_ttl(&env);
+        get_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        check_nonnegative_amount(&env, amount);
+
+        extend_instance_ttl(&env);
+        set_allowance(&env, &from, &spender, amount, expiration_ledger);
+        env.events()
+            .publish(("approve", from, spender), (amount, expiration_ledger));
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        extend_instance_ttl(&env);
+        get_balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: soroban_sdk::MuxedAddress, amount: i128) {
+        from.require_auth();
+        check_nonnegative_amount(&env, amount);
+
+        if is_locked(&env, &from) {
+            panic_with_error!(&env, LiquidityPoolError::SharesLocked);
+        }
+
+        extend_instance_ttl(&env);
+        let to = to.address();
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+        env.events().publish(("transfer", from, to), amount);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        check_nonnegative_amount(&env, amount);
+
+        if is_locked(&env, &from) {
+            panic_with_error!(&env, LiquidityPoolError::SharesLocked);
+        }
+
+        extend_instance_ttl(&env);
+        spend_allowance(&env, &from, &spender, amount);
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+        env.events().publish(("transfer", from, to), amount);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        check_nonnegative_amount(&env, amount);
+
+        extend_instance_ttl(&env);
+        burn_shares(&env, &from, amount);
+        env.events().publish(("burn", from), amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        check_nonnegative_amount(&env, amount);
+
+        extend_instance_ttl(&env);
+        spend_allowance(&env, &from, &spender, amount);
+        burn_shares(&env, &from, amount);
+        env.events().publish(("burn", from), amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        get_metadata(&env).decimal
+    }
+
+    fn name(env: Env) -> String {
+        get_metadata(&env).name
+    }
+
+    fn symbol(env: Env) -> String {
+        get_metadata(&env).symbol
+    }
+}
@@ -0,0 +1,250 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+
+use crate::errors::LiquidityPoolError;
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    Metadata,
+    TotalShares,
+    /// Pool assets when the treasury's cut was last taken
+    AccountedAssets,
+    Balance(Address),
+    Allowance(Address, Address),
+    /// Time until which an LP's shares cannot be withdrawn or transferred
+    LockedUntil(Address),
+}
+
+/// Pool parameters.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoolConfig {
+    /// CallRegistry whose house liquidity the pool provides; the pool needs
+    /// its treasury manager role
+    pub registry: Address,
+    /// Stake token LPs deposit
+    pub token: Address,
+    /// Receives the protocol's cut of pool gains, as shares
+    pub treasury: Address,
+    /// Share of pool gains (the spread earned on fixed-odds books) left to
+    /// LPs; the rest goes to the treasury
+    pub lp_share_bps: u32,
+    /// Most of the pool's assets placed in the registry to back books; the
+    /// rest stays in the pool for withdrawals
+    pub max_utilization_bps: u32,
+    /// Seconds an LP's shares stay locked after each deposit, so a deposit
+    /// made just before a book settles cannot take its gain straight out
+    pub lock_period: u64,
+}
+
+/// Where the pool's assets are, as reported by [`crate::LiquidityPool::get_pool_state`].
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoolState {
+    /// Stake token held by the pool itself
+    pub idle: i128,
+    /// House liquidity in the registry free to back new stakes or withdraw
+    pub house_free: i128,
+    /// House liquidity in the registry reserved by open fixed-odds books
+    pub house_reserved: i128,
+    /// `idle + house_free + house_reserved`
+    pub total_assets: i128,
+    pub total_shares: i128,
+    /// `house_reserved / total_assets` in basis points
+    pub utilization_bps: u32,
+}
+
+/// SEP-41 descriptive metadata of the LP share token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenMetadata {
+    pub decimal: u32,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// An allowance and the ledger after which it lapses.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the pool parameters, if initialised
+pub fn get_config(env: &Env) -> Option<PoolConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+/// Store the pool parameters
+pub fn set_config(env: &Env, config: &PoolConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Get the share token metadata
+pub fn get_metadata(env: &Env) -> TokenMetadata {
+    env.storage()
+        .instance()
+        .get(&DataKey::Metadata)
+        .unwrap_or_else(|| panic_with_error!(env, LiquidityPoolError::NotInitialized))
+}
+
+/// Store the share token metadata
+pub fn set_metadata(env: &Env, metadata: &TokenMetadata) {
+    env.storage().instance().set(&DataKey::Metadata, metadata);
+}
+
+/// Get the number of shares outstanding
+pub fn get_total_shares(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalShares)
+        .unwrap_or(0)
+}
+
+/// Store the number of shares outstanding
+pub fn set_total_shares(env: &Env, shares: i128) {
+    env.storage().instance().set(&DataKey::TotalShares, &shares);
+}
+
+/// Get the pool assets when the treasury's cut was last taken
+pub fn get_accounted_assets(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AccountedAssets)
+        .unwrap_or(0)
+}
+
+/// Store the pool assets the treasury's cut has been taken up to
+pub fn set_accounted_assets(env: &Env, assets: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AccountedAssets, &assets);
+}
+
+/// Get an address's share balance
+pub fn get_balance(env: &Env, id: &Address) -> i128 {
+    let key = DataKey::Balance(id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(balance) => {
+            env.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_LIFETIME_THRESHOLD,
+                PERSISTENT_BUMP_AMOUNT,
+            );
+            balance
+        }
+        None => 0,
+    }
+}
+
+/// Store an address's share balance
+pub fn set_balance(env: &Env, id: &Address, amount: i128) {
+    let key = DataKey::Balance(id.clone());
+    env.storage().persistent().set(&key, &amount);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the time until which an LP's shares are locked; 0 if never locked
+pub fn get_locked_until(env: &Env, id: &Address) -> u64 {
+    let key = DataKey::LockedUntil(id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(locked_until) => {
+            env.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_LIFETIME_THRESHOLD,
+                PERSISTENT_BUMP_AMOUNT,
+            );
+            locked_until
+        }
+        None => 0,
+    }
+}
+
+/// Store the time until which an LP's shares are locked
+pub fn set_locked_until(env: &Env, id: &Address, locked_until: u64) {
+    let key = DataKey::LockedUntil(id.clone());
+    env.storage().persistent().set(&key, &locked_until);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the allowance `from` granted `spender`; lapsed allowances read as 0
+pub fn get_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    let allowance = env
+        .storage()
+        .temporary()
+        .get::<_, AllowanceValue>(&key)
+        .unwrap_or(AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        });
+    if allowance.expiration_ledger < env.ledger().sequence() {
+        AllowanceValue {
+            amount: 0,
+            expiration_ledger: allowance.expiration_ledger,
+        }
+    } else {
+        allowance
+    }
+}
+
+/// Store an allowance, keeping the entry alive until it lapses
+pub fn set_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        panic_with_error!(env, LiquidityPoolError::InvalidExpiration);
+    }
+
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    let allowance = AllowanceValue {
+        amount,
+        expiration_ledger,
+    };
+    env.storage().temporary().set(&key, &allowance);
+    if amount > 0 {
+        let live_for = expiration_ledger - env.ledger().sequence();
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, live_for, live_for);
+    }
+}
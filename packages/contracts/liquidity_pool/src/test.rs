@@ -0,0 +1,246 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::errors::LiquidityPoolError;
+use crate::{LiquidityPool, LiquidityPoolClient, PoolConfig};
+
+const LOCK_PERIOD: u64 = 3_600;
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+/// CallRegistry stand-in holding house liquidity for a single token.
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn deposit_house_liquidity(env: Env, caller: Address, token: Address, amount: i128) {
+        caller.require_auth();
+        token::Client::new(&env, &token).transfer(&caller, env.current_contract_address(), &amount);
+        Self::add(&env, "free", amount);
+    }
+
+    pub fn withdraw_house_liquidity(env: Env, caller: Address, token: Address, amount: i128) {
+        caller.require_auth();
+        Self::add(&env, "free", -amount);
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &caller,
+            &amount,
+        );
+    }
+
+    pub fn get_house_liquidity(env: Env, _token: Address) -> i128 {
+        env.storage().instance().get(&"free").unwrap_or(0)
+    }
+
+    pub fn get_house_reserved(env: Env, _token: Address) -> i128 {
+        env.storage().instance().get(&"reserved").unwrap_or(0)
+    }
+
+    /// Lock free liquidity behind an open book.
+    pub fn reserve(env: Env, amount: i128) {
+        Self::add(&env, "free", -amount);
+        Self::add(&env, "reserved", amount);
+    }
+
+    /// Settle a book: release its reserve and book the house's `pnl`, whose
+    /// tokens the test moves separately.
+    pub fn settle(env: Env, reserved: i128, pnl: i128) {
+        Self::add(&env, "reserved", -reserved);
+        Self::add(&env, "free", reserved + pnl);
+    }
+}
+
+impl MockRegistry {
+    fn add(env: &Env, key: &str, delta: i128) {
+        let value: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(value + delta));
+    }
+}
+
+struct Setup {
+    env: Env,
+    client: LiquidityPoolClient<'static>,
+    registry: MockRegistryClient<'static>,
+    token: token::StellarAssetClient<'static>,
+    treasury: Address,
+}
+
+fn setup(lp_share_bps: u32, max_utilization_bps: u32) -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let registry = MockRegistryClient::new(&env, &env.register(MockRegistry, ()));
+    let treasury = Address::generate(&env);
+    let client = LiquidityPoolClient::new(&env, &env.register(LiquidityPool, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &PoolConfig {
+            registry: registry.address.clone(),
+            token: token_id.clone(),
+            treasury: treasury.clone(),
+            lp_share_bps,
+            max_utilization_bps,
+            lock_period: LOCK_PERIOD,
+        },
+        &String::from_str(&env, "House LP"),
+        &String::from_str(&env, "HLP"),
+    );
+
+    Setup {
+        token: token::StellarAssetClient::new(&env, &token_id),
+        env,
+        client,
+        registry,
+        treasury,
+    }
+}
+
+/// Move past the lock period of every deposit made so far.
+fn unlock(s: &Setup) {
+    s.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp += LOCK_PERIOD);
+}
+
+fn funded_lp(s: &Setup, amount: i128) -> Address {
+    let lp = Address::generate(&s.env);
+    s.token.mint(&lp, &amount);
+    lp
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_deposits_mint_shares_and_respect_utilization_cap() {
+    let s = setup(8_000, 8_000);
+    let alice = funded_lp(&s, 1_000_000);
+    let bob = funded_lp(&s, 500_000);
+
+    assert_eq!(s.client.deposit(&alice, &1_000_000), 1_000_000);
+    assert_eq!(s.client.deposit(&bob, &500_000), 500_000);
+    assert_eq!(s.client.balance(&bob), 500_000);
+    assert_eq!(s.client.total_supply(), 1_500_000);
+    assert_eq!(s.client.decimals(), 7);
+
+    let state = s.client.get_pool_state();
+    assert_eq!(state.idle, 300_000);
+    assert_eq!(state.house_free, 1_200_000);
+    assert_eq!(state.total_assets, 1_500_000);
+
+    // Tightening the cap pulls liquidity back into the pool
+    s.client.set_params(&8_000, &5_000);
+    assert_eq!(s.registry.get_house_liquidity(&s.token.address), 750_000);
+
+    let result = s.client.try_deposit(&alice, &0);
+    assert_eq!(result, Err(Ok(LiquidityPoolError::InvalidAmount)));
+}
+
+#[test]
+fn test_house_gains_are_split_with_treasury() {
+    let s = setup(8_000, 10_000);
+    let alice = funded_lp(&s, 1_000_000);
+    s.client.deposit(&alice, &1_000_000);
+
+    // A book settles with the house 100 000 up
+    s.registry.reserve(&400_000);
+    s.token.mint(&s.registry.address, &100_000);
+    s.registry.settle(&400_000, &100_000);
+    s.client.rebalance();
+
+    // The treasury's 20% of the gain, as shares worth 20 000
+    assert_eq!(s.client.balance(&s.treasury), 18_518);
+    assert_eq!(s.client.convert_to_assets(&18_518), 19_999);
+
+    unlock(&s);
+    assert_eq!(s.client.withdraw(&alice, &1_000_000), 1_080_000);
+    assert_eq!(
+        token::Client::new(&s.env, &s.token.address).balance(&alice),
+        1_080_000
+    );
+}
+
+#[test]
+fn test_withdrawals_cannot_touch_reserved_liquidity() {
+    let s = setup(8_000, 10_000);
+    let alice = funded_lp(&s, 1_000_000);
+    s.client.deposit(&alice, &1_000_000);
+    s.registry.reserve(&900_000);
+    assert_eq!(s.client.get_pool_state().utilization_bps, 9_000);
+    unlock(&s);
+
+    let result = s.client.try_withdraw(&alice, &1_000_000);
+    assert_eq!(result, Err(Ok(LiquidityPoolError::InsufficientLiquidity)));
+    let result = s.client.try_withdraw(&alice, &1_000_001);
+    assert_eq!(result, Err(Ok(LiquidityPoolError::InsufficientShares)));
+
+    assert_eq!(s.client.withdraw(&alice, &100_000), 100_000);
+    let state = s.client.get_pool_state();
+    assert_eq!(state.house_free, 0);
+    assert_eq!(state.house_reserved, 900_000);
+}
+
+#[test]
+fn test_deposit_locks_shares_against_just_in_time_capture() {
+    let s = setup(10_000, 10_000);
+    let alice = funded_lp(&s, 1_000_000);
+    s.client.deposit(&alice, &1_000_000);
+    s.registry.reserve(&400_000);
+
+    // Bob steps in right before a winning book settles
+    let bob = funded_lp(&s, 1_000_000);
+    s.client.deposit(&bob, &1_000_000);
+    s.token.mint(&s.registry.address, &100_000);
+    s.registry.settle(&400_000, &100_000);
+
+    let result = s.client.try_withdraw(&bob, &1_000_000);
+    assert_eq!(result, Err(Ok(LiquidityPoolError::SharesLocked)));
+    let carol = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_transfer(&bob, &carol, &1_000_000),
+        Err(Ok(LiquidityPoolError::SharesLocked.into()))
+    );
+    assert_eq!(
+        s.client.try_transfer_from(&bob, &bob, &carol, &1_000_000),
+        Err(Ok(LiquidityPoolError::SharesLocked.into()))
+    );
+
+    unlock(&s);
+    s.client.transfer(&bob, &carol, &500_000);
+    assert_eq!(s.client.withdraw(&carol, &500_000), 524_999);
+
+    // Share token misuse surfaces as pool errors
+    assert_eq!(
+        s.client.try_transfer(&carol, &bob, &-1),
+        Err(Ok(LiquidityPoolError::NegativeAmount.into()))
+    );
+    assert_eq!(
+        s.client.try_transfer(&carol, &bob, &1),
+        Err(Ok(LiquidityPoolError::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_initialize_rules() {
+    let s = setup(8_000, 8_000);
+    let config = s.client.get_config();
+    let result = s.client.try_initialize(
+        &Address::generate(&s.env),
+        &config,
+        &String::from_str(&s.env, "House LP"),
+        &String::from_str(&s.env, "HLP"),
+    );
+    assert_eq!(result, Err(Ok(LiquidityPoolError::AlreadyInitialized)));
+
+    let result = s.client.try_set_params(&10_001, &8_000);
+    assert_eq!(result, Err(Ok(LiquidityPoolError::InvalidBps)));
+}
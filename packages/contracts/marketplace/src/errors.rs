@@ -0,0 +1,24 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum MarketplaceError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// The amount listed or bought is ≤ 0.
+    InvalidAmount = 3,
+    /// The price is ≤ 0.
+    InvalidPrice = 4,
+    /// The call has no position token for the outcome, or is resolved,
+    /// voided or cancelled.
+    PositionNotTradable = 5,
+    /// No open listing exists for the supplied id.
+    ListingNotFound = 6,
+    /// The caller is not the listing's seller.
+    Unauthorized = 7,
+    /// The amount bought exceeds what remains in the listing.
+    InsufficientListing = 8,
+}
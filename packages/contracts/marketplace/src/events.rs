@@ -0,0 +1,33 @@
+use soroban_sdk::{Address, Env};
+
+/// Emitted when a seller escrows position tokens for sale
+pub fn emit_listing_created(
+    env: &Env,
+    listing_id: u64,
+    seller: &Address,
+    call_id: u64,
+    outcome: u32,
+    amount: i128,
+    price: i128,
+) {
+    env.events().publish(
+        ("marketplace", "listing_created"),
+        (listing_id, seller.clone(), call_id, outcome, amount, price),
+    );
+}
+
+/// Emitted when a buyer fills all or part of a listing
+pub fn emit_listing_filled(env: &Env, listing_id: u64, buyer: &Address, amount: i128, cost: i128) {
+    env.events().publish(
+        ("marketplace", "listing_filled"),
+        (listing_id, buyer.clone(), amount, cost),
+    );
+}
+
+/// Emitted when a seller withdraws the unsold remainder of a listing
+pub fn emit_listing_cancelled(env: &Env, listing_id: u64, seller: &Address, returned: i128) {
+    env.events().publish(
+        ("marketplace", "listing_cancelled"),
+        (listing_id, seller.clone(), returned),
+    );
+}
@@ -0,0 +1,233 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Secondary market for open BACKit positions.
+//!
+//! Holders of position tokens (see the `position_token` contract) list them at
+//! a fixed price in the call's stake token. The tokens are escrowed here until
+//! a buyer fills the listing, wholly or in part, or the seller cancels it.
+//! Listings can only be created or filled while the call is unresolved.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, token, Address, Env, IntoVal, Symbol, Vec,
+};
+
+use errors::MarketplaceError;
+use events::*;
+use storage::*;
+
+pub use storage::Listing;
+
+/// Fixed-point scale for listing prices: `PRICE_SCALE` = one stake token
+/// unit per position token unit.
+pub const PRICE_SCALE: i128 = 10_000_000;
+
+/// Mirror of the CallRegistry's `PositionTokenInfo`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionTokenInfo {
+    pub token: Address,
+    pub stake_token: Address,
+    pub tradable: bool,
+}
+
+#[contract]
+pub struct Marketplace;
+
+/// Look up a tradable position token on the registry.
+fn tradable_position(
+    env: &Env,
+    call_id: u64,
+    outcome: u32,
+) -> Result<PositionTokenInfo, MarketplaceError> {
+    let registry = get_registry(env).ok_or(MarketplaceError::NotInitialized)?;
+    let args = (call_id, outcome).into_val(env);
+    let info = env
+        .try_invoke_contract::<PositionTokenInfo, soroban_sdk::Error>(
+            &registry,
+            &Symbol::new(env, "get_position_token"),
+            args,
+        )
+        .ok()
+        .and_then(|result| result.ok())
+        .ok_or(MarketplaceError::PositionNotTradable)?;
+    if !info.tradable {
+        return Err(MarketplaceError::PositionNotTradable);
+    }
+    Ok(info)
+}
+
+/// Stake-token cost of `amount` position tokens at `price`, rounded up.
+fn fill_cost(amount: i128, price: i128) -> i128 {
+    (amount * price + PRICE_SCALE - 1) / PRICE_SCALE
+}
+
+#[contractimpl]
+impl Marketplace {
+    /// Initialise the contract with an admin and the CallRegistry whose
+    /// position tokens it trades.
+    /// # Errors
+    /// * [`MarketplaceError::AlreadyInitialized`] – called more than once.
+    pub fn initialize(env: Env, admin: Address, registry: Address) -> Result<(), MarketplaceError> {
+        if get_admin(&env).is_some() {
+            return Err(MarketplaceError::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_registry(&env, &registry);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Escrow `amount` position tokens for `outcome` of a call and offer them
+    /// at `price` stake-token units per token (scaled by [`PRICE_SCALE`]).
+    /// Returns the listing id.
+    /// # Errors
+    /// * [`MarketplaceError::InvalidAmount`]       – `amount` ≤ 0.
+    /// * [`MarketplaceError::InvalidPrice`]        – `price` ≤ 0.
+    /// * [`MarketplaceError::NotInitialized`]      – contract not initialised.
+    /// * [`MarketplaceError::PositionNotTradable`] – no position token, or the call
+    ///   is closed.
+    pub fn list_position(
+        env: Env,
+        seller: Address,
+        call_id: u64,
+        outcome: u32,
+        amount: i128,
+        price: i128,
+    ) -> Result<u64, MarketplaceError> {
+        seller.require_auth();
+
+        if amount <= 0 {
+            return Err(MarketplaceError::InvalidAmount);
+        }
+        if price <= 0 {
+            return Err(MarketplaceError::InvalidPrice);
+        }
+        let info = tradable_position(&env, call_id, outcome)?;
+
+        token::Client::new(&env, &info.token).transfer(
+            &seller,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let listing = Listing {
+            id: next_listing_id(&env),
+            seller: seller.clone(),
+            call_id,
+            outcome,
+            position_token: info.token,
+            stake_token: info.stake_token,
+            remaining: amount,
+            price,
+        };
+        set_listing(&env, &listing);
+        add_call_listing(&env, call_id, listing.id);
+        extend_instance_ttl(&env);
+
+        emit_listing_created(&env, listing.id, &seller, call_id, outcome, amount, price);
+
+        Ok(listing.id)
+    }
+
+    /// Buy `amount` position tokens from a listing. The buyer pays the seller
+    /// directly in the stake token and receives the escrowed position tokens.
+    /// Returns the cost.
+    /// # Errors
+    /// * [`MarketplaceError::InvalidAmount`]       – `amount` ≤ 0.
+    /// * [`MarketplaceError::ListingNotFound`]     – no open listing `listing_id`.
+    /// * [`MarketplaceError::InsufficientListing`] – `amount` exceeds what remains.
+    /// * [`MarketplaceError::PositionNotTradable`] – the call is closed.
+    pub fn buy_listing(
+        env: Env,
+        buyer: Address,
+        listing_id: u64,
+        amount: i128,
+    ) -> Result<i128, MarketplaceError> {
+        buyer.require_auth();
+
+        if amount <= 0 {
+            return Err(MarketplaceError::InvalidAmount);
+        }
+        let mut listing = get_listing(&env, listing_id).ok_or(MarketplaceError::ListingNotFound)?;
+        if amount > listing.remaining {
+            return Err(MarketplaceError::InsufficientListing);
+        }
+        tradable_position(&env, listing.call_id, listing.outcome)?;
+
+        let cost = fill_cost(amount, listing.price);
+        token::Client::new(&env, &listing.stake_token).transfer(&buyer, &listing.seller, &cost);
+        token::Client::new(&env, &listing.position_token).transfer(
+            &env.current_contract_address(),
+            &buyer,
+            &amount,
+        );
+
+        listing.remaining -= amount;
+        if listing.remaining == 0 {
+            remove_listing(&env, &listing);
+        } else {
+            set_listing(&env, &listing);
+        }
+
+        emit_listing_filled(&env, listing_id, &buyer, amount, cost);
+
+        Ok(cost)
+    }
+
+    /// Cancel a listing and return the unsold position tokens to the seller.
+    /// Allowed at any time, including after the call resolves. Returns the
+    /// amount returned.
+    /// # Errors
+    /// * [`MarketplaceError::ListingNotFound`] – no open listing `listing_id`.
+    /// * [`MarketplaceError::Unauthorized`]    – `seller` did not create the listing.
+    pub fn cancel_listing(
+        env: Env,
+        seller: Address,
+        listing_id: u64,
+    ) -> Result<i128, MarketplaceError> {
+        seller.require_auth();
+
+        let listing = get_listing(&env, listing_id).ok_or(MarketplaceError::ListingNotFound)?;
+        if listing.seller != seller {
+            return Err(MarketplaceError::Unauthorized);
+        }
+
+        remove_listing(&env, &listing);
+        token::Client::new(&env, &listing.position_token).transfer(
+            &env.current_contract_address(),
+            &seller,
+            &listing.remaining,
+        );
+
+        emit_listing_cancelled(&env, listing_id, &seller, listing.remaining);
+
+        Ok(listing.remaining)
+    }
+
+    /// Get an open listing.
+    /// # Errors
+    /// * [`MarketplaceError::ListingNotFound`] – no open listing `listing_id`.
+    pub fn get_listing(env: Env, listing_id: u64) -> Result<Listing, MarketplaceError> {
+        get_listing(&env, listing_id).ok_or(MarketplaceError::ListingNotFound)
+    }
+
+    /// Get the open listings on a call, oldest first.
+    pub fn get_call_listings(env: Env, call_id: u64) -> Vec<Listing> {
+        let mut listings = Vec::new(&env);
+        for id in get_call_listings(&env, call_id).iter() {
+            if let Some(listing) = get_listing(&env, id) {
+                listings.push_back(listing);
+            }
+        }
+        listings
+    }
+}
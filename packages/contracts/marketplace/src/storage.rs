@@ -0,0 +1,127 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Registry,
+    ListingCounter,
+    Listing(u64),
+    CallListings(u64),
+}
+
+/// Position tokens escrowed by a seller and offered at a fixed price.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Listing {
+    pub id: u64,
+    pub seller: Address,
+    pub call_id: u64,
+    pub outcome: u32,
+    /// Position token held in escrow
+    pub position_token: Address,
+    /// Token the buyer pays in (the call's stake token)
+    pub stake_token: Address,
+    /// Position tokens still for sale
+    pub remaining: i128,
+    /// Stake-token price of one position token, scaled by `PRICE_SCALE`
+    pub price: i128,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the CallRegistry address, if initialised
+pub fn get_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Registry)
+}
+
+/// Store the CallRegistry address
+pub fn set_registry(env: &Env, registry: &Address) {
+    env.storage().instance().set(&DataKey::Registry, registry);
+}
+
+/// Allocate the next listing id
+pub fn next_listing_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get(&DataKey::ListingCounter)
+        .unwrap_or(0u64)
+        + 1;
+    env.storage().instance().set(&DataKey::ListingCounter, &id);
+    id
+}
+
+/// Get an open listing
+pub fn get_listing(env: &Env, id: u64) -> Option<Listing> {
+    env.storage().persistent().get(&DataKey::Listing(id))
+}
+
+/// Store an open listing
+pub fn set_listing(env: &Env, listing: &Listing) {
+    let key = DataKey::Listing(listing.id);
+    env.storage().persistent().set(&key, listing);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Delete a filled or cancelled listing and drop it from its call's index
+pub fn remove_listing(env: &Env, listing: &Listing) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Listing(listing.id));
+
+    let mut ids = get_call_listings(env, listing.call_id);
+    if let Some(index) = ids.first_index_of(listing.id) {
+        ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CallListings(listing.call_id), &ids);
+    }
+}
+
+/// Append a listing to its call's index
+pub fn add_call_listing(env: &Env, call_id: u64, id: u64) {
+    let key = DataKey::CallListings(call_id);
+    let mut ids = get_call_listings(env, call_id);
+    ids.push_back(id);
+    env.storage().persistent().set(&key, &ids);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the ids of a call's open listings, oldest first
+pub fn get_call_listings(env: &Env, call_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CallListings(call_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env};
+
+use crate::errors::MarketplaceError;
+use crate::{Marketplace, MarketplaceClient, PositionTokenInfo, PRICE_SCALE};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+/// CallRegistry stand-in serving one position token per call, with a
+/// test-controlled tradable flag.
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_position(env: Env, call_id: u64, info: PositionTokenInfo) {
+        env.storage().instance().set(&call_id, &info);
+    }
+
+    pub fn get_position_token(env: Env, call_id: u64, _outcome: u32) -> PositionTokenInfo {
+        env.storage()
+            .instance()
+            .get(&call_id)
+            .expect("no position token")
+    }
+}
+
+const CALL_ID: u64 = 7;
+
+struct Setup {
+    env: Env,
+    client: MarketplaceClient<'static>,
+    registry: MockRegistryClient<'static>,
+    position: token::Client<'static>,
+    stake: token::Client<'static>,
+    seller: Address,
+    buyer: Address,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = MockRegistryClient::new(&env, &env.register(MockRegistry, ()));
+    let client = MarketplaceClient::new(&env, &env.register(Marketplace, ()));
+    client.initialize(&admin, &registry.address);
+
+    let position_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let stake_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    registry.set_position(
+        &CALL_ID,
+        &PositionTokenInfo {
+            token: position_id.clone(),
+            stake_token: stake_id.clone(),
+            tradable: true,
+        },
+    );
+
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &position_id).mint(&seller, &1_000);
+    token::StellarAssetClient::new(&env, &stake_id).mint(&buyer, &10_000);
+
+    Setup {
+        position: token::Client::new(&env, &position_id),
+        stake: token::Client::new(&env, &stake_id),
+        env,
+        client,
+        registry,
+        seller,
+        buyer,
+    }
+}
+
+fn close_call(s: &Setup) {
+    s.registry.set_position(
+        &CALL_ID,
+        &PositionTokenInfo {
+            token: s.position.address.clone(),
+            stake_token: s.stake.address.clone(),
+            tradable: false,
+        },
+    );
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_listing_escrows_tokens_and_partial_fills_pay_seller() {
+    let s = setup();
+
+    // 1.5 stake tokens per position token
+    let id = s
+        .client
+        .list_position(&s.seller, &CALL_ID, &1, &600, &(PRICE_SCALE * 3 / 2));
+    assert_eq!(s.position.balance(&s.client.address), 600);
+    assert_eq!(s.client.get_call_listings(&CALL_ID).len(), 1);
+
+    assert_eq!(s.client.buy_listing(&s.buyer, &id, &200), 300);
+    assert_eq!(s.position.balance(&s.buyer), 200);
+    assert_eq!(s.stake.balance(&s.seller), 300);
+    assert_eq!(s.client.get_listing(&id).remaining, 400);
+
+    let result = s.client.try_buy_listing(&s.buyer, &id, &401);
+    assert_eq!(result, Err(Ok(MarketplaceError::InsufficientListing)));
+
+    s.client.buy_listing(&s.buyer, &id, &400);
+    let result = s.client.try_get_listing(&id);
+    assert_eq!(result, Err(Ok(MarketplaceError::ListingNotFound)));
+    assert_eq!(s.client.get_call_listings(&CALL_ID).len(), 0);
+}
+
+#[test]
+fn test_cost_rounds_up_in_sellers_favour() {
+    let s = setup();
+    let id = s
+        .client
+        .list_position(&s.seller, &CALL_ID, &1, &10, &(PRICE_SCALE / 3));
+    assert_eq!(s.client.buy_listing(&s.buyer, &id, &1), 1);
+}
+
+#[test]
+fn test_closed_call_blocks_trading_but_not_cancellation() {
+    let s = setup();
+    let id = s
+        .client
+        .list_position(&s.seller, &CALL_ID, &1, &500, &PRICE_SCALE);
+
+    close_call(&s);
+    let result = s.client.try_buy_listing(&s.buyer, &id, &100);
+    assert_eq!(result, Err(Ok(MarketplaceError::PositionNotTradable)));
+    let result = s
+        .client
+        .try_list_position(&s.seller, &CALL_ID, &1, &100, &PRICE_SCALE);
+    assert_eq!(result, Err(Ok(MarketplaceError::PositionNotTradable)));
+
+    let stranger = Address::generate(&s.env);
+    let result = s.client.try_cancel_listing(&stranger, &id);
+    assert_eq!(result, Err(Ok(MarketplaceError::Unauthorized)));
+    assert_eq!(s.client.cancel_listing(&s.seller, &id), 500);
+    assert_eq!(s.position.balance(&s.seller), 1_000);
+}
+
+#[test]
+fn test_list_position_rejects_invalid_terms() {
+    let s = setup();
+    let result = s
+        .client
+        .try_list_position(&s.seller, &CALL_ID, &1, &0, &PRICE_SCALE);
+    assert_eq!(result, Err(Ok(MarketplaceError::InvalidAmount)));
+    let result = s.client.try_list_position(&s.seller, &CALL_ID, &1, &10, &0);
+    assert_eq!(result, Err(Ok(MarketplaceError::InvalidPrice)));
+    let result = s
+        .client
+        .try_list_position(&s.seller, &99, &1, &10, &PRICE_SCALE);
+    assert_eq!(result, Err(Ok(MarketplaceError::PositionNotTradable)));
+}
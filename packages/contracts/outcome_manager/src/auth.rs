@@ -1,7 +1,19 @@
-use crate::storage::InstanceKey;
+use crate::errors::{OutcomeError, OutcomeExtError};
+use crate::storage::{self, InstanceKey};
+use crate::STORAGE_VERSION;
 use soroban_sdk::{Address, Env};
 
+/// Panic unless the stored data has the layout this code was written for.
+/// Guards every admin action, submission and claim, so code upgraded over
+/// an incompatible layout fails loudly until `migrate` has run.
+pub fn require_storage_version(env: &Env) {
+    if storage::get_storage_version(env) != STORAGE_VERSION {
+        soroban_sdk::panic_with_error!(env, OutcomeExtError::StorageVersionMismatch);
+    }
+}
+
 pub fn require_admin(env: &Env) -> Address {
+    require_storage_version(env);
     let admin: Address = env
         .storage()
         .instance()
@@ -11,3 +23,35 @@ pub fn require_admin(env: &Env) -> Address {
     admin.require_auth();
     admin
 }
+
+/// Require `caller` to be the admin or the pauser, and its auth.
+pub fn require_pauser(env: &Env, caller: &Address) {
+    require_storage_version(env);
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&InstanceKey::Admin)
+        .expect("admin not set");
+    if *caller != admin && storage::get_pauser(env).as_ref() != Some(caller) {
+        soroban_sdk::panic_with_error!(env, OutcomeError::Unauthorized);
+    }
+    caller.require_auth();
+}
+
+/// Require `caller` to be the admin or the governance contract, and its auth.
+pub fn require_admin_or_governance(env: &Env, caller: &Address) {
+    require_storage_version(env);
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&InstanceKey::Admin)
+        .expect("admin not set");
+    let is_governance = matches!(
+        storage::get_governance(env),
+        Some((governance, _)) if governance == *caller
+    );
+    if *caller != admin && !is_governance {
+        soroban_sdk::panic_with_error!(env, OutcomeError::Unauthorized);
+    }
+    caller.require_auth();
+}
@@ -30,4 +30,86 @@ pub enum OutcomeError {
     ZeroTimeWindow = 24,
     RegistryNotSet = 25,
     DisputeWindowExpired = 26,
+    ReportExpired = 27,
+    NonceAlreadyUsed = 28,
+    TimestampBeforeCallEnd = 29,
+    InvalidVoteTolerance = 30,
+    OutcomePending = 31,
+    AlreadyChallenged = 32,
+    NoChallenge = 33,
+    ChallengeBondNotSet = 34,
+    ChallengePending = 35,
+    InvalidChallengeBond = 36,
+    GovernanceNotSet = 37,
+    ReversalWindowClosed = 38,
+    PayoutStarted = 39,
+    CallVoided = 40,
+    ResolutionWindowOpen = 41,
+    InvalidResolutionWindow = 42,
+    Unauthorized = 43,
+    CallAlreadyEnded = 44,
+    RegistryNotAllowed = 45,
+    RegistryMismatch = 46,
+    InvalidEpochActivation = 47,
+    PriceFeedNotSet = 48,
+    PriceUnavailable = 49,
+    ResolvedByPriceFeed = 50,
+}
+
+/// Errors added after [`OutcomeError`] reached the 50-variant limit on
+/// contract error enums. Codes continue from 51.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OutcomeExtError {
+    /// A TWAP call's observation falls outside its observation window.
+    ObservationOutsideWindow = 51,
+    /// The call is not resolved by TWAP.
+    TwapNotSet = 52,
+    /// The call's observation window has not closed yet.
+    TwapWindowOpen = 53,
+    /// The call resolves from a TWAP of observations, not signed reports.
+    ResolvedByTwap = 54,
+    /// A price is too far from the call's start price to be plausible.
+    PriceDeviationTooLarge = 55,
+    /// The max price deviation multiple is below 2.
+    InvalidPriceDeviation = 56,
+    /// An oracle label is longer than `MAX_ORACLE_LABEL_LEN` bytes.
+    InvalidOracleLabel = 57,
+    /// No oracle reward has been configured.
+    OracleRewardNotSet = 58,
+    /// The oracle reward or funding amount is not positive, or names a
+    /// different token than the one already configured.
+    InvalidOracleReward = 59,
+    /// The oracle has no payout address to send its rewards to.
+    PayoutAddressNotSet = 60,
+    /// Oracle submissions and finalization are paused.
+    SubmissionsPaused = 61,
+    /// Payouts and reward claims are paused.
+    ClaimsPaused = 62,
+    /// Stored data has a different layout version than this code expects;
+    /// run `migrate` after an upgrade.
+    StorageVersionMismatch = 63,
+    /// No upgrade to this WASM hash is queued.
+    UpgradeNotQueued = 64,
+    /// The queued upgrade's timelock has not elapsed.
+    UpgradeTimelockActive = 65,
+    /// The change weakens the oracle set and must go through
+    /// `queue_param_change` while a parameter timelock is set.
+    ParamChangeTimelocked = 66,
+    /// No parameter change is queued under this id.
+    ParamChangeNotQueued = 67,
+    /// The queued parameter change's timelock has not elapsed.
+    ParamTimelockActive = 68,
+    /// Admin signers are empty, repeated or more than `MAX_ADMIN_SIGNERS`,
+    /// or the threshold is 0 or exceeds their number.
+    InvalidAdminSigners = 69,
+    /// No admin signer set is configured.
+    AdminSignersNotSet = 70,
+    /// Fewer distinct admin signers than the threshold authorized the call.
+    InsufficientAdminSignatures = 71,
+    /// The call's claim deadline has passed.
+    ClaimExpired = 72,
+    /// The call has no claim deadline, or it has not passed yet.
+    ClaimWindowOpen = 73,
 }
@@ -1,4 +1,6 @@
-use soroban_sdk::{symbol_short, Env};
+#![allow(unused)]
+
+use soroban_sdk::{symbol_short, Env, IntoVal, Symbol, Val};
 
 /// Emitted when a new oracle outcome report is accepted (before quorum)
 pub fn emit_outcome_submitted(
@@ -29,6 +31,23 @@ pub fn emit_payout_claimed(env: &Env, call_id: u64, staker: &soroban_sdk::Addres
     );
 }
 
+/// Emitted for each staker whose unclaimed winnings expire into the
+/// insurance pool; the record a governance appeal is judged on
+pub fn emit_claim_expired(env: &Env, call_id: u64, staker: &soroban_sdk::Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("claim"), symbol_short!("expired")),
+        (call_id, staker.clone(), amount),
+    );
+}
+
+/// Emitted when a win-streak bonus is carved out of the protocol fee
+pub fn emit_streak_bonus_paid(env: &Env, call_id: u64, staker: &soroban_sdk::Address, bonus: i128) {
+    env.events().publish(
+        (symbol_short!("streak"), symbol_short!("bonus")),
+        (call_id, staker.clone(), bonus),
+    );
+}
+
 /// Emitted when the protocol fee is collected during payout settlement
 pub fn emit_fee_collected(
     env: &Env,
@@ -57,6 +76,86 @@ pub fn emit_outcome_disputed(env: &Env, call_id: u64, new_outcome: u32, new_pric
     );
 }
 
+/// Emitted when a call is voided because quorum was not reached in time
+pub fn emit_outcome_expired(env: &Env, call_id: u64, deadline: u64) {
+    env.events().publish(
+        (symbol_short!("outcome"), symbol_short!("expired")),
+        (call_id, deadline),
+    );
+}
+
+/// Emitted when a staged oracle epoch replaces the current oracle set
+pub fn emit_oracle_epoch_activated(env: &Env, epoch: u32, oracle_count: u32, quorum: u32) {
+    env.events().publish(
+        (symbol_short!("oracle"), symbol_short!("epoch")),
+        (epoch, oracle_count, quorum),
+    );
+}
+
+/// Emitted when an oracle's accrued rewards are paid to its payout address
+pub fn emit_oracle_rewards_claimed(
+    env: &Env,
+    oracle: &soroban_sdk::BytesN<32>,
+    to: &soroban_sdk::Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("oracle"), symbol_short!("rewarded")),
+        (oracle.clone(), to.clone(), amount),
+    );
+}
+
+/// Emitted when quorum is reached and the outcome enters its challenge window
+pub fn emit_outcome_pending(env: &Env, call_id: u64, outcome: u32, price: i128, deadline: u32) {
+    env.events().publish(
+        (symbol_short!("outcome"), symbol_short!("pending")),
+        (call_id, outcome, price, deadline),
+    );
+}
+
+/// Emitted when a bonded counter-report is posted against a pending outcome
+pub fn emit_outcome_challenged(
+    env: &Env,
+    call_id: u64,
+    challenger: &soroban_sdk::Address,
+    outcome: u32,
+    price: i128,
+) {
+    env.events().publish(
+        (symbol_short!("outcome"), symbol_short!("challenge")),
+        (call_id, challenger.clone(), outcome, price),
+    );
+}
+
+/// Emitted when the admin upholds or rejects a challenge
+pub fn emit_challenge_resolved(env: &Env, call_id: u64, upheld: bool) {
+    env.events().publish(
+        (symbol_short!("challenge"), symbol_short!("resolved")),
+        (call_id, upheld),
+    );
+}
+
+/// Emitted when governance reverses a finalized outcome
+pub fn emit_outcome_reversed(
+    env: &Env,
+    call_id: u64,
+    old_outcome: u32,
+    new_outcome: u32,
+    new_price: i128,
+    evidence_cid: &soroban_sdk::Bytes,
+) {
+    env.events().publish(
+        (symbol_short!("outcome"), symbol_short!("reversed")),
+        (
+            call_id,
+            old_outcome,
+            new_outcome,
+            new_price,
+            evidence_cid.clone(),
+        ),
+    );
+}
+
 pub fn emit_contract_paused(env: &Env) {
     env.events()
         .publish((symbol_short!("contract"), symbol_short!("paused")), ());
@@ -99,3 +198,88 @@ pub fn emit_price_observation_submitted(
         (call_id, oracle.clone(), price, timestamp),
     );
 }
+
+/// Emitted when the admin adds an oracle to the trusted set
+pub fn emit_oracle_added(env: &Env, oracle: &soroban_sdk::BytesN<32>, config_version: u32) {
+    env.events().publish(
+        (symbol_short!("oracle"), symbol_short!("added")),
+        (oracle.clone(), config_version),
+    );
+}
+
+/// Emitted when the admin removes an oracle from the trusted set
+pub fn emit_oracle_removed(env: &Env, oracle: &soroban_sdk::BytesN<32>, config_version: u32) {
+    env.events().publish(
+        (symbol_short!("oracle"), symbol_short!("removed")),
+        (oracle.clone(), config_version),
+    );
+}
+
+/// Emitted when the admin hands over to a new admin
+pub fn emit_admin_changed(
+    env: &Env,
+    old_admin: &soroban_sdk::Address,
+    new_admin: &soroban_sdk::Address,
+    config_version: u32,
+) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("changed")),
+        (old_admin.clone(), new_admin.clone(), config_version),
+    );
+}
+
+/// Emitted when an admin (or governance) changes a configuration parameter;
+/// `param` names the setting and `value` carries its new value
+pub fn emit_config_changed<T: IntoVal<Env, Val>>(
+    env: &Env,
+    param: Symbol,
+    value: T,
+    config_version: u32,
+) {
+    let value: Val = value.into_val(env);
+    env.events()
+        .publish((symbol_short!("config"), param), (value, config_version));
+}
+
+/// Emitted when the admin queues a WASM upgrade behind the timelock
+pub fn emit_upgrade_queued(env: &Env, wasm_hash: &soroban_sdk::BytesN<32>, eta: u64) {
+    env.events().publish(
+        (symbol_short!("upgrade"), symbol_short!("queued")),
+        (wasm_hash.clone(), eta),
+    );
+}
+
+/// Emitted when the admin drops a queued upgrade
+pub fn emit_upgrade_cancelled(env: &Env, wasm_hash: &soroban_sdk::BytesN<32>) {
+    env.events().publish(
+        (symbol_short!("upgrade"), symbol_short!("cancelled")),
+        wasm_hash.clone(),
+    );
+}
+
+/// Emitted when the admin queues a parameter change behind the timelock
+pub fn emit_param_change_queued(
+    env: &Env,
+    id: u32,
+    change: &crate::storage::ParamChange,
+    eta: u64,
+) {
+    env.events().publish(
+        (symbol_short!("param"), symbol_short!("queued")),
+        (id, change.clone(), eta),
+    );
+}
+
+/// Emitted when a queued parameter change is dropped
+pub fn emit_param_change_cancelled(env: &Env, id: u32) {
+    env.events()
+        .publish((symbol_short!("param"), symbol_short!("cancelled")), id);
+}
+
+/// Emitted when `migrate` brings stored data to a new layout version
+pub fn emit_storage_migrated(env: &Env, old_version: u32, new_version: u32) {
+    env.events().publish(
+        (symbol_short!("storage"), symbol_short!("migrated")),
+        (old_version, new_version),
+    );
+}
@@ -1,4 +1,6 @@
 #![no_std]
+#![allow(deprecated)]
+#![allow(clippy::too_many_arguments)]
 
 mod auth;
 mod errors;
@@ -7,27 +9,172 @@ mod storage;
 mod test;
 mod verification;
 
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, Vec};
+use soroban_sdk::{
+    auth::{
+        Context, ContractContext, CustomAccountInterface, InvokerContractAuthEntry,
+        SubContractInvocation,
+    },
+    contract, contractimpl, contracttype,
+    crypto::Hash,
+    symbol_short, token, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, Val, Vec,
+};
 
-use auth::require_admin;
-use backit_shared::{is_valid_fee_bps, is_valid_outcome};
-use errors::OutcomeError;
+use auth::{require_admin, require_admin_or_governance, require_pauser, require_storage_version};
+use backit_shared::{is_valid_fee_bps, is_valid_outcome_for, OUTCOME_DRAW, OUTCOME_VOID};
+use errors::{OutcomeError, OutcomeExtError};
 use events::{
-    emit_admin_params_changed, emit_batch_payout_started, emit_contract_upgraded,
-    emit_fee_collected, emit_outcome_disputed, emit_outcome_finalized, emit_outcome_submitted,
-    emit_payout_claimed, emit_price_observation_submitted,
+    emit_admin_changed, emit_admin_params_changed, emit_batch_payout_started,
+    emit_challenge_resolved, emit_claim_expired, emit_config_changed, emit_contract_upgraded,
+    emit_fee_collected, emit_oracle_added, emit_oracle_epoch_activated, emit_oracle_removed,
+    emit_oracle_rewards_claimed, emit_outcome_challenged, emit_outcome_disputed,
+    emit_outcome_expired, emit_outcome_finalized, emit_outcome_pending, emit_outcome_reversed,
+    emit_outcome_submitted, emit_param_change_cancelled, emit_param_change_queued,
+    emit_payout_claimed, emit_price_observation_submitted, emit_storage_migrated,
+    emit_streak_bonus_paid, emit_upgrade_cancelled, emit_upgrade_queued,
 };
 use storage::{
-    set_dispute_window, set_max_submission_delay, InstanceKey, OracleVote, Outcome, PersistentKey,
-    PriceObservation, SignedOutcome, TempKey,
+    set_dispute_window, set_max_submission_delay, AdminSigners, CallOracleConfig, Challenge,
+    ChallengeBond, Finalization, InstanceKey, OracleInfo, OraclePage, OracleReward,
+    OracleSubmission, OracleVote, Outcome, OutcomePage, ParamChange, PauseState, PendingFinal,
+    PendingParamChange, PendingUpgrade, PersistentKey, PriceFeed, PriceObservation, SignedOutcome,
+    StagedEpoch, SubmissionPage, VoteStatus, VoteTolerance,
 };
-use verification::{build_message, verify_signature};
+use verification::{build_message, build_observation_message, verify_signature};
 
 pub const CONTRACT_VERSION: u32 = 1;
+/// Layout version of the data this code reads and writes. Bump it, and add a
+/// step to `migrate`, whenever an upgrade changes how existing state is stored.
+pub const STORAGE_VERSION: u32 = 2;
+/// Seconds a queued WASM upgrade must wait before `upgrade` may run it.
+pub const UPGRADE_TIMELOCK_SECS: u64 = 172_800;
 pub const MAX_ORACLES: u32 = 20;
+/// Longest label the admin may attach to an oracle, in bytes.
+pub const MAX_ORACLE_LABEL_LEN: u32 = 64;
+/// Accepted reports kept in each oracle's submission history.
+pub const MAX_ORACLE_HISTORY: u32 = 50;
+/// Most outcomes one `get_finalized_outcomes` page returns.
+pub const MAX_OUTCOME_PAGE: u32 = 50;
+/// Most addresses an M-of-N admin signer set may hold.
+pub const MAX_ADMIN_SIGNERS: u32 = 10;
+/// Most stakers one `expire_claims` invocation walks.
+pub const MAX_EXPIRY_BATCH: u32 = 25;
 
 // ─── Cross-contract helpers ────────────────────────────────────────────────────
 
+/// Mirror of the CallRegistry's `CallStats`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallStats {
+    pub outcome_stakes: Map<u32, i128>,
+    pub outcome_stake_counts: Map<u32, u32>,
+    pub total_stakes: u32,
+    pub last_stake_ts: u64,
+}
+
+/// Mirror of the CallRegistry's `AddressPage`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddressPage {
+    pub addresses: Vec<Address>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Mirror of the SEP-40 `PriceData`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Call `lastprice(asset)` on a SEP-40 price feed (e.g. Reflector).
+fn feed_last_price(env: &Env, feed: &PriceFeed) -> Option<PriceData> {
+    let args = (feed.asset.clone(),).into_val(env);
+    env.invoke_contract(&feed.oracle, &Symbol::new(env, "lastprice"), args)
+}
+
+/// Call `get_call_stats(call_id)` on the CallRegistry.
+fn registry_call_stats(env: &Env, registry: &Address, call_id: u64) -> CallStats {
+    let args = (call_id,).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_call_stats"), args)
+}
+
+/// Call `get_staker_stake(call_id, staker, position)` on the CallRegistry.
+fn registry_staker_stake(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    staker: &Address,
+    position: u32,
+) -> i128 {
+    let args = (call_id, staker.clone(), position).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_staker_stake"), args)
+}
+
+/// Call `get_weighted_stake(call_id, staker, position)` on the CallRegistry.
+fn registry_weighted_stake(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    staker: &Address,
+    position: u32,
+) -> i128 {
+    let args = (call_id, staker.clone(), position).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_weighted_stake"), args)
+}
+
+/// Call `get_weighted_outcome_stakes(call_id)` on the CallRegistry.
+fn registry_weighted_outcome_stakes(env: &Env, registry: &Address, call_id: u64) -> Map<u32, i128> {
+    let args = (call_id,).into_val(env);
+    env.invoke_contract(
+        registry,
+        &Symbol::new(env, "get_weighted_outcome_stakes"),
+        args,
+    )
+}
+
+/// Call `get_penalty_pool(call_id)` on the CallRegistry.
+fn registry_penalty_pool(env: &Env, registry: &Address, call_id: u64) -> i128 {
+    let args = (call_id,).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_penalty_pool"), args)
+}
+
+/// Call `get_call_end_ts(call_id)` on the CallRegistry.
+fn registry_call_end_ts(env: &Env, registry: &Address, call_id: u64) -> u64 {
+    let args = (call_id,).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_call_end_ts"), args)
+}
+
+/// Call `get_call_outcome_count(call_id)` on the CallRegistry.
+fn registry_call_outcome_count(env: &Env, registry: &Address, call_id: u64) -> u32 {
+    let args = (call_id,).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_call_outcome_count"), args)
+}
+
+/// Call `get_call_creator(call_id)` on the CallRegistry.
+fn registry_call_creator(env: &Env, registry: &Address, call_id: u64) -> Address {
+    let args = (call_id,).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_call_creator"), args)
+}
+
+/// Call `get_call_stake_token(call_id)` on the CallRegistry.
+fn registry_call_stake_token(env: &Env, registry: &Address, call_id: u64) -> Address {
+    let args = (call_id,).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_call_stake_token"), args)
+}
+
+/// Call `get_call_start_price(call_id)` on the CallRegistry.
+fn registry_call_start_price(env: &Env, registry: &Address, call_id: u64) -> i128 {
+    let args = (call_id,).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_call_start_price"), args)
+}
+
+/// Call `get_binary_outcome(call_id, end_price)` on the CallRegistry.
+fn registry_binary_outcome(env: &Env, registry: &Address, call_id: u64, end_price: i128) -> u32 {
+    let args = (call_id, end_price).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "get_binary_outcome"), args)
+}
+
 /// Call `resolve_call(call_id, outcome, end_price)` on the CallRegistry.
 fn registry_resolve_call(
     env: &Env,
@@ -40,6 +187,28 @@ fn registry_resolve_call(
     env.invoke_contract::<()>(registry, &Symbol::new(env, "resolve_call"), args);
 }
 
+/// Call `override_outcome(caller, call_id, outcome, end_price, evidence_cid)`
+/// on the CallRegistry, as this contract. The registry only accepts
+/// overrides from its outcome manager.
+fn registry_override_outcome(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    outcome: u32,
+    end_price: i128,
+    evidence_cid: &Bytes,
+) {
+    let args = (
+        env.current_contract_address(),
+        call_id,
+        outcome,
+        end_price,
+        evidence_cid.clone(),
+    )
+        .into_val(env);
+    env.invoke_contract::<Val>(registry, &Symbol::new(env, "override_outcome"), args);
+}
+
 /// Call `release_escrow(call_id, to, amount)` on the CallRegistry.
 fn registry_release_escrow(
     env: &Env,
@@ -52,6 +221,79 @@ fn registry_release_escrow(
     env.invoke_contract::<()>(registry, &Symbol::new(env, "release_escrow"), args);
 }
 
+/// Call `release_fee(call_id, staker, to, amount)` on the CallRegistry, which
+/// diverts the staker's referrer's share before paying `to`.
+fn registry_release_fee(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    staker: &Address,
+    to: &Address,
+    amount: i128,
+) -> i128 {
+    let args = (call_id, staker.clone(), to.clone(), amount).into_val(env);
+    env.invoke_contract(registry, &Symbol::new(env, "release_fee"), args)
+}
+
+/// Call `record_streak_win(call_id, staker)` on the CallRegistry, returning
+/// the staker's win-streak bonus in basis points.
+fn registry_record_streak_win(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    staker: &Address,
+) -> u32 {
+    let args = (call_id, staker.clone()).into_val(env);
+    env.invoke_contract::<u32>(registry, &Symbol::new(env, "record_streak_win"), args)
+}
+
+/// Call `get_streak_bonus_bps(staker)` on the CallRegistry.
+fn registry_streak_bonus_bps(env: &Env, registry: &Address, staker: &Address) -> u32 {
+    let args = (staker.clone(),).into_val(env);
+    env.invoke_contract::<u32>(registry, &Symbol::new(env, "get_streak_bonus_bps"), args)
+}
+
+/// Call `release_escrows(to, releases)` on the CallRegistry.
+fn registry_release_escrows(
+    env: &Env,
+    registry: &Address,
+    to: &Address,
+    releases: Map<u64, i128>,
+) -> Map<Address, i128> {
+    let args = (to.clone(), releases).into_val(env);
+    env.invoke_contract::<Map<Address, i128>>(registry, &Symbol::new(env, "release_escrows"), args)
+}
+
+/// Call `get_protocol_fees(token)` on the CallRegistry.
+fn registry_protocol_fees(env: &Env, registry: &Address, token: &Address) -> i128 {
+    let args = (token.clone(),).into_val(env);
+    env.invoke_contract::<i128>(registry, &Symbol::new(env, "get_protocol_fees"), args)
+}
+
+/// Call `get_call_stakers(call_id, cursor, limit)` on the CallRegistry.
+fn registry_call_stakers(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    cursor: u32,
+    limit: u32,
+) -> AddressPage {
+    let args = (call_id, cursor, limit).into_val(env);
+    env.invoke_contract::<AddressPage>(registry, &Symbol::new(env, "get_call_stakers"), args)
+}
+
+/// Call `expire_escrow(call_id, amount)` on the CallRegistry.
+fn registry_expire_escrow(env: &Env, registry: &Address, call_id: u64, amount: i128) {
+    let args = (call_id, amount).into_val(env);
+    env.invoke_contract::<()>(registry, &Symbol::new(env, "expire_escrow"), args);
+}
+
+/// Call `burn_positions(call_id, staker)` on the CallRegistry.
+fn registry_burn_positions(env: &Env, registry: &Address, call_id: u64, staker: &Address) {
+    let args = (call_id, staker.clone()).into_val(env);
+    env.invoke_contract::<()>(registry, &Symbol::new(env, "burn_positions"), args);
+}
+
 /// Call `mark_settled(call_id)` on the CallRegistry.
 fn registry_mark_settled(env: &Env, registry: &Address, call_id: u64) {
     let args = (call_id,).into_val(env);
@@ -67,6 +309,42 @@ fn is_paused(env: &Env) -> bool {
         .unwrap_or(false)
 }
 
+/// Panic unless oracle submissions, and the finalization they lead to, are
+/// open: neither the emergency pause nor the submissions pause is on.
+fn require_submissions_open(env: &Env) {
+    require_storage_version(env);
+    if is_paused(env) {
+        soroban_sdk::panic_with_error!(env, OutcomeError::ContractPaused);
+    }
+    if storage::get_submissions_paused(env) {
+        soroban_sdk::panic_with_error!(env, OutcomeExtError::SubmissionsPaused);
+    }
+}
+
+/// Panic unless payouts are open: neither the emergency pause nor the claims
+/// pause is on.
+fn require_claims_open(env: &Env) {
+    require_storage_version(env);
+    if is_paused(env) {
+        soroban_sdk::panic_with_error!(env, OutcomeError::ContractPaused);
+    }
+    if storage::get_claims_paused(env) {
+        soroban_sdk::panic_with_error!(env, OutcomeExtError::ClaimsPaused);
+    }
+}
+
+/// Require the admin's auth for upgrade and migration. Unlike
+/// `require_admin`, this skips the storage-version guard, since these are
+/// how a layout mismatch gets fixed.
+fn upgrade_admin(env: &Env) -> Address {
+    let admin: Address = match env.storage().instance().get(&InstanceKey::Admin) {
+        Some(admin) => admin,
+        None => soroban_sdk::panic_with_error!(env, OutcomeError::NotInitialized),
+    };
+    admin.require_auth();
+    admin
+}
+
 fn not_initialized<T>(env: &Env) -> T {
     soroban_sdk::panic_with_error!(env, OutcomeError::NotInitialized);
 }
@@ -75,14 +353,242 @@ fn overflow<T>(env: &Env) -> T {
     soroban_sdk::panic_with_error!(env, OutcomeError::Overflow);
 }
 
+/// Bump the configuration version after an admin change and return the new
+/// version, which every admin event carries so indexers can spot gaps.
+fn bump_config_version(env: &Env) -> u32 {
+    let version = storage::get_config_version(env) + 1;
+    storage::set_config_version(env, version);
+    version
+}
+
+/// Record when `oracle` joined the trusted set, unless it is already on record.
+fn record_oracle_added(env: &Env, oracle: &BytesN<32>) {
+    if storage::get_oracle_info(env, oracle).is_none() {
+        storage::set_oracle_info(
+            env,
+            &OracleInfo {
+                pubkey: oracle.clone(),
+                added_at: env.ledger().timestamp(),
+                label: None,
+            },
+        );
+    }
+}
+
+/// Drop `oracle` from the trusted set, if it is in it.
+fn apply_remove_oracle(env: &Env, oracle: BytesN<32>) {
+    let mut oracles = get_oracles(env);
+    let oracle_list: Vec<BytesN<32>> = env
+        .storage()
+        .instance()
+        .get(&InstanceKey::OracleList)
+        .unwrap_or_else(|| Vec::new(env));
+    let mut filtered = Vec::new(env);
+
+    if oracles.remove(oracle.clone()).is_none() {
+        return;
+    }
+    storage::remove_oracle_info(env, &oracle);
+    for existing in oracle_list.iter() {
+        if existing != oracle {
+            filtered.push_back(existing);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&InstanceKey::Oracles, &oracles);
+    env.storage()
+        .instance()
+        .set(&InstanceKey::OracleList, &filtered);
+    emit_oracle_removed(env, &oracle, bump_config_version(env));
+}
+
+fn apply_quorum(env: &Env, quorum: u32) {
+    if quorum == 0 || quorum > get_oracles(env).len() {
+        soroban_sdk::panic_with_error!(env, OutcomeError::InvalidQuorum);
+    }
+    env.storage().instance().set(&InstanceKey::Quorum, &quorum);
+    emit_config_changed(
+        env,
+        symbol_short!("quorum"),
+        quorum,
+        bump_config_version(env),
+    );
+}
+
+fn apply_staged_epoch(env: &Env, epoch: Option<StagedEpoch>) {
+    roll_oracle_epoch(env);
+    if let Some(epoch) = &epoch {
+        if epoch.quorum == 0 || epoch.quorum > epoch.oracles.len() {
+            soroban_sdk::panic_with_error!(env, OutcomeError::InvalidQuorum);
+        }
+        if epoch.oracles.len() > MAX_ORACLES {
+            soroban_sdk::panic_with_error!(env, OutcomeError::MaxOraclesReached);
+        }
+        if epoch.activation_ledger <= env.ledger().sequence() {
+            soroban_sdk::panic_with_error!(env, OutcomeError::InvalidEpochActivation);
+        }
+    }
+    storage::set_staged_epoch(env, &epoch);
+    emit_config_changed(env, symbol_short!("epoch"), epoch, bump_config_version(env));
+}
+
+fn apply_param_timelock(env: &Env, secs: u64) {
+    storage::set_param_timelock(env, secs);
+    emit_config_changed(
+        env,
+        symbol_short!("timelock"),
+        secs,
+        bump_config_version(env),
+    );
+}
+
+fn validate_admin_signers(env: &Env, admin_signers: &AdminSigners) {
+    let signers = &admin_signers.signers;
+    let mut valid = !signers.is_empty()
+        && signers.len() <= MAX_ADMIN_SIGNERS
+        && admin_signers.threshold > 0
+        && admin_signers.threshold <= signers.len();
+    for (index, signer) in signers.iter().enumerate() {
+        if signers.first_index_of(&signer) != Some(index as u32) {
+            valid = false;
+        }
+    }
+    if !valid {
+        soroban_sdk::panic_with_error!(env, OutcomeExtError::InvalidAdminSigners);
+    }
+}
+
+/// `true` once `call_id`'s claim deadline, if it has one, has passed.
+fn claim_expired(env: &Env, registry: &Address, call_id: u64) -> bool {
+    matches!(
+        storage::get_claim_deadline(env, registry, call_id),
+        Some(deadline) if env.ledger().sequence() > deadline
+    )
+}
+
+/// Changes that weaken the oracle set must be queued while a parameter
+/// timelock is set.
+fn require_no_param_timelock(env: &Env) {
+    if storage::get_param_timelock(env) > 0 {
+        soroban_sdk::panic_with_error!(env, OutcomeExtError::ParamChangeTimelocked);
+    }
+}
+
+/// Credit the configured reward to each oracle that voted for `outcome` on
+/// `call_id`, while the rewards budget covers it. Must run before the call's
+/// vote tracking is cleared.
+fn accrue_oracle_rewards(env: &Env, registry: &Address, call_id: u64, outcome: u32) {
+    let reward = match storage::get_oracle_reward(env) {
+        Some(reward) => reward,
+        None => return,
+    };
+    let votes: Vec<OracleVote> = env
+        .storage()
+        .persistent()
+        .get(&PersistentKey::Votes(registry.clone(), call_id))
+        .unwrap_or_else(|| Vec::new(env));
+    let mut budget = storage::get_oracle_reward_budget(env);
+    for vote in votes.iter() {
+        if vote.outcome != outcome || budget < reward.amount {
+            continue;
+        }
+        budget -= reward.amount;
+        let earned = storage::get_oracle_rewards(env, &vote.oracle)
+            .checked_add(reward.amount)
+            .unwrap_or_else(|| overflow(env));
+        storage::set_oracle_rewards(env, &vote.oracle, earned);
+    }
+    storage::set_oracle_reward_budget(env, budget);
+}
+
+/// Swap in the staged oracle epoch once its activation ledger is reached.
+/// Every read of the oracle set or quorum goes through this first, so the
+/// switch happens on the first interaction at or after that ledger.
+fn roll_oracle_epoch(env: &Env) {
+    let staged = match storage::get_staged_epoch(env) {
+        Some(staged) if env.ledger().sequence() >= staged.activation_ledger => staged,
+        _ => return,
+    };
+    let mut oracle_map = Map::<BytesN<32>, bool>::new(env);
+    for oracle in staged.oracles.iter() {
+        record_oracle_added(env, &oracle);
+        oracle_map.set(oracle, true);
+    }
+    let outgoing: Vec<BytesN<32>> = env
+        .storage()
+        .instance()
+        .get(&InstanceKey::OracleList)
+        .unwrap_or_else(|| Vec::new(env));
+    for oracle in outgoing.iter() {
+        if !oracle_map.contains_key(oracle.clone()) {
+            storage::remove_oracle_info(env, &oracle);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&InstanceKey::Oracles, &oracle_map);
+    env.storage()
+        .instance()
+        .set(&InstanceKey::OracleList, &staged.oracles);
+    env.storage()
+        .instance()
+        .set(&InstanceKey::Quorum, &staged.quorum);
+    let epoch = storage::get_oracle_epoch(env) + 1;
+    storage::set_oracle_epoch(env, epoch);
+    storage::set_staged_epoch(env, &None);
+    emit_oracle_epoch_activated(env, epoch, staged.oracles.len(), staged.quorum);
+}
+
 fn get_oracles(env: &Env) -> Map<BytesN<32>, bool> {
+    roll_oracle_epoch(env);
     match env.storage().instance().get(&InstanceKey::Oracles) {
         Some(oracles) => oracles,
         None => not_initialized(env),
     }
 }
 
+/// Whether `call_id` on `registry` can resolve to `outcome`: one of its
+/// positions, VOID, or DRAW on a binary call.
+fn outcome_valid_for_call(env: &Env, registry: &Address, call_id: u64, outcome: u32) -> bool {
+    is_valid_outcome_for(outcome, registry_call_outcome_count(env, registry, call_id))
+}
+
+/// Reject a `registry` that is not allowlisted. Per-call state is keyed by
+/// registry and call id, so calls of different registries never collide.
+fn require_registry(env: &Env, registry: &Address) {
+    if !storage::get_registries(env).contains(registry) {
+        soroban_sdk::panic_with_error!(env, OutcomeError::RegistryNotAllowed);
+    }
+}
+
+/// Reject `price` when it is more than the configured multiple above or below
+/// the call's start price, as a misplaced decimal point would be. Calls with
+/// no positive start price are not checked.
+fn check_price_deviation(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    price: i128,
+) -> Result<(), OutcomeExtError> {
+    let max_multiple = match storage::get_max_price_deviation(env) {
+        Some(max_multiple) => max_multiple as i128,
+        None => return Ok(()),
+    };
+    let start_price = registry_call_start_price(env, registry, call_id);
+    if start_price <= 0 {
+        return Ok(());
+    }
+    let too_high = price > start_price.saturating_mul(max_multiple);
+    let too_low = price.saturating_mul(max_multiple) < start_price;
+    if too_high || too_low {
+        return Err(OutcomeExtError::PriceDeviationTooLarge);
+    }
+    Ok(())
+}
+
 fn get_quorum(env: &Env) -> u32 {
+    roll_oracle_epoch(env);
     match env.storage().instance().get(&InstanceKey::Quorum) {
         Some(quorum) => quorum,
         None => not_initialized(env),
@@ -96,11 +602,245 @@ fn get_fee_collector(env: &Env) -> Address {
     }
 }
 
-fn get_registry(env: &Env) -> Address {
-    match env.storage().instance().get(&InstanceKey::Registry) {
-        Some(registry) => registry,
-        None => soroban_sdk::panic_with_error!(env, OutcomeError::RegistryNotSet),
+/// Release `fee` from `staker`'s claim on `call_id` as protocol fee. With a
+/// fee distributor set, the fee (less any referral cut the registry keeps) is
+/// deposited there on behalf of the call's creator; otherwise the registry
+/// pays it straight to the fee collector.
+fn collect_fee(env: &Env, registry: &Address, call_id: u64, staker: &Address, fee: i128) {
+    let distributor: Option<Address> = env.storage().instance().get(&InstanceKey::FeeDistributor);
+    let Some(distributor) = distributor else {
+        let fee_collector = get_fee_collector(env);
+        registry_release_fee(env, registry, call_id, staker, &fee_collector, fee);
+        emit_fee_collected(env, call_id, fee, &fee_collector);
+        return;
+    };
+
+    let this = env.current_contract_address();
+    let referral = registry_release_fee(env, registry, call_id, staker, &this, fee);
+    let amount = fee - referral;
+    if amount > 0 {
+        let token = registry_call_stake_token(env, registry, call_id);
+        let creator = registry_call_creator(env, registry, call_id);
+        // The distributor pulls the fee with a transfer this contract authorises.
+        env.authorize_as_current_contract(soroban_sdk::vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (this.clone(), distributor.clone(), amount).into_val(env),
+                },
+                sub_invocations: soroban_sdk::vec![env],
+            }),
+        ]);
+        let args = (this, token, amount, creator).into_val(env);
+        env.invoke_contract::<i128>(&distributor, &Symbol::new(env, "deposit"), args);
+    }
+    emit_fee_collected(env, call_id, fee, &distributor);
+}
+
+/// Split a DRAW refund into `(fee, refund)`: the staker gets their stake back
+/// minus `fee_bps` of it.
+fn draw_refund(env: &Env, stake: i128, fee_bps: u32) -> (i128, i128) {
+    let fee = stake
+        .checked_mul(fee_bps as i128)
+        .unwrap_or_else(|| overflow(env))
+        .checked_div(10000)
+        .unwrap_or_else(|| overflow(env));
+    let refund = stake.checked_sub(fee).unwrap_or_else(|| overflow(env));
+    (fee, refund)
+}
+
+/// Pool-wide totals a winning claim is priced against, read from the
+/// registry: `(total_winning_weight, total_losing_stake)`. The losing pool
+/// includes the call's `penalty_pool`.
+fn pool_totals(env: &Env, registry: &Address, call_id: u64, outcome: u32) -> (i128, i128) {
+    let stats = registry_call_stats(env, registry, call_id);
+    let mut total_losing = registry_penalty_pool(env, registry, call_id);
+    for (position, stake) in stats.outcome_stakes.iter() {
+        if position != outcome {
+            total_losing = total_losing
+                .checked_add(stake)
+                .unwrap_or_else(|| overflow(env));
+        }
+    }
+    let total_winning_weight = registry_weighted_outcome_stakes(env, registry, call_id)
+        .get(outcome)
+        .unwrap_or(0);
+    (total_winning_weight, total_losing)
+}
+
+/// The staker's `(stake, weight)` on the winning outcome, read from the
+/// registry. For a DRAW both are the staker's total stake on every outcome.
+fn staker_totals(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    staker: &Address,
+    outcome: u32,
+) -> (i128, i128) {
+    if outcome != OUTCOME_DRAW {
+        return (
+            registry_staker_stake(env, registry, call_id, staker, outcome),
+            registry_weighted_stake(env, registry, call_id, staker, outcome),
+        );
+    }
+    let stats = registry_call_stats(env, registry, call_id);
+    let mut stake: i128 = 0;
+    for position in stats.outcome_stakes.keys().iter() {
+        stake = stake
+            .checked_add(registry_staker_stake(
+                env, registry, call_id, staker, position,
+            ))
+            .unwrap_or_else(|| overflow(env));
+    }
+    (stake, stake)
+}
+
+/// A winning claim's `(fee, payout)`. The protocol fee is `fee_bps` of the
+/// losing pool; the staker pays the share of it matching their share of the
+/// winning weight, and receives their stake back plus the same share of the
+/// losing pool net of the fee.
+fn winning_split(
+    env: &Env,
+    stake: i128,
+    weight: i128,
+    total_winning_weight: i128,
+    total_losing_stake: i128,
+    fee_bps: u32,
+) -> (i128, i128) {
+    let total_fee = total_losing_stake
+        .checked_mul(fee_bps as i128)
+        .unwrap_or_else(|| overflow(env))
+        .checked_div(10000)
+        .unwrap_or_else(|| overflow(env));
+
+    let fee = weight
+        .checked_mul(total_fee)
+        .unwrap_or_else(|| overflow(env))
+        .checked_div(total_winning_weight)
+        .unwrap_or_else(|| overflow(env));
+
+    let net_losing = total_losing_stake
+        .checked_sub(total_fee)
+        .unwrap_or_else(|| overflow(env));
+
+    let prize_share = weight
+        .checked_mul(net_losing)
+        .unwrap_or_else(|| overflow(env))
+        .checked_div(total_winning_weight)
+        .unwrap_or_else(|| overflow(env));
+
+    let payout = stake
+        .checked_add(prize_share)
+        .unwrap_or_else(|| overflow(env));
+    (fee, payout)
+}
+
+/// The streak bonus a winning `payout` earns: `bonus_bps` of it, capped at
+/// the claim's `fee` so it is always funded by the protocol's own cut.
+fn streak_bonus(env: &Env, bonus_bps: u32, fee: i128, payout: i128) -> i128 {
+    payout
+        .checked_mul(bonus_bps as i128)
+        .unwrap_or_else(|| overflow(env))
+        .checked_div(10000)
+        .unwrap_or_else(|| overflow(env))
+        .min(fee)
+}
+
+/// Record a win on the staker's streak and move its bonus from `fee` to
+/// `payout`.
+fn apply_streak_bonus(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    staker: &Address,
+    fee: i128,
+    payout: i128,
+) -> (i128, i128) {
+    let bonus_bps = registry_record_streak_win(env, registry, call_id, staker);
+    let bonus = streak_bonus(env, bonus_bps, fee, payout);
+    if bonus <= 0 {
+        return (fee, payout);
+    }
+
+    emit_streak_bonus_paid(env, call_id, staker, bonus);
+    let payout = payout.checked_add(bonus).unwrap_or_else(|| overflow(env));
+    (fee - bonus, payout)
+}
+
+/// `true` if `vote` is within `tolerance` of the newly submitted report:
+/// timestamps at most `window_secs` apart and prices at most `price_bps` of
+/// the new report's price apart.
+fn within_tolerance(tolerance: &VoteTolerance, vote: &OracleVote, signed: &SignedOutcome) -> bool {
+    if vote.timestamp.abs_diff(signed.timestamp) > tolerance.window_secs {
+        return false;
+    }
+    let diff = match vote
+        .price
+        .checked_sub(signed.price)
+        .and_then(i128::checked_abs)
+    {
+        Some(diff) => diff,
+        None => return false,
+    };
+    let band = signed
+        .price
+        .checked_abs()
+        .and_then(|price| price.checked_mul(tolerance.price_bps as i128));
+    match (diff.checked_mul(10_000), band) {
+        (Some(lhs), Some(rhs)) => lhs <= rhs,
+        _ => false,
+    }
+}
+
+/// Time-weighted average of one oracle's observations, whose timestamps
+/// strictly increase. `None` below 3 observations.
+fn series_twap(env: &Env, observations: &Vec<PriceObservation>) -> Option<i128> {
+    if observations.len() < 3 {
+        return None;
+    }
+    let mut weighted_sum: i128 = 0;
+    let mut total_time: i128 = 0;
+    for i in 0..(observations.len() - 1) {
+        let obs_i = observations.get_unchecked(i);
+        let dt = (observations.get_unchecked(i + 1).timestamp - obs_i.timestamp) as i128;
+        weighted_sum = obs_i
+            .price
+            .checked_mul(dt)
+            .unwrap_or_else(|| overflow(env))
+            .checked_add(weighted_sum)
+            .unwrap_or_else(|| overflow(env));
+        total_time = total_time.checked_add(dt).unwrap_or_else(|| overflow(env));
+    }
+    Some(
+        weighted_sum
+            .checked_div(total_time)
+            .unwrap_or_else(|| overflow(env)),
+    )
+}
+
+/// Median of a non-empty set of prices. With an even count it is the
+/// midpoint of the two middle prices, rounded toward zero.
+fn median_price(mut prices: Vec<i128>) -> i128 {
+    // Insertion sort: at most `MAX_ORACLES` prices.
+    for i in 1..prices.len() {
+        let mut j = i;
+        while j > 0 && prices.get_unchecked(j - 1) > prices.get_unchecked(j) {
+            let prev = prices.get_unchecked(j - 1);
+            prices.set(j - 1, prices.get_unchecked(j));
+            prices.set(j, prev);
+            j -= 1;
+        }
+    }
+    let mid = prices.len() / 2;
+    let upper = prices.get_unchecked(mid);
+    if prices.len() % 2 == 1 {
+        return upper;
     }
+    let lower = prices.get_unchecked(mid - 1);
+    // Halve before adding so extreme prices cannot overflow.
+    lower / 2 + upper / 2 + (lower % 2 + upper % 2) / 2
 }
 
 // ─── Contract ─────────────────────────────────────────────────────────────────
@@ -115,21 +855,26 @@ impl OutcomeManager {
     /// Initialize the contract.
     ///
     /// * `admin`         – address with privileged control
+    /// * `registry`      – first trusted CallRegistry; more can be added with
+    ///   `add_registry`
     /// * `oracles`       – list of trusted oracle ed25519 public keys (32-byte)
     /// * `quorum`        – minimum matching votes required to finalize an outcome
     /// * `fee_collector` – address that receives protocol fees
     /// * `fee_bps`       – protocol fee in basis points (0–10000)
+    /// * `dispute_window_ledgers` – challenge window after quorum; 0 finalizes
+    ///   immediately
     ///
     /// # Panics
     /// If called more than once (`already initialized`).
     pub fn initialize(
         env: Env,
         admin: Address,
+        registry: Address,
         oracles: Vec<BytesN<32>>,
         quorum: u32,
         fee_collector: Address,
         fee_bps: u32,
-        dispute_window_secs: u64,
+        dispute_window_ledgers: u32,
     ) {
         if env.storage().instance().has(&InstanceKey::Admin) {
             soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadyInitialized);
@@ -137,10 +882,10 @@ impl OutcomeManager {
 
         admin.require_auth();
 
-        if quorum == 0 || quorum > oracles.len() as u32 {
+        if quorum == 0 || quorum > oracles.len() {
             soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidQuorum);
         }
-        if oracles.len() as u32 > MAX_ORACLES {
+        if oracles.len() > MAX_ORACLES {
             soroban_sdk::panic_with_error!(&env, OutcomeError::MaxOraclesReached);
         }
         if !is_valid_fee_bps(fee_bps) {
@@ -149,10 +894,12 @@ impl OutcomeManager {
 
         let mut oracle_map = Map::<BytesN<32>, bool>::new(&env);
         for o in oracles.iter() {
+            record_oracle_added(&env, &o);
             oracle_map.set(o, true);
         }
 
         env.storage().instance().set(&InstanceKey::Admin, &admin);
+        storage::set_registries(&env, &Vec::from_array(&env, [registry]));
         env.storage()
             .instance()
             .set(&InstanceKey::Oracles, &oracle_map);
@@ -164,11 +911,12 @@ impl OutcomeManager {
             .instance()
             .set(&InstanceKey::FeeCollector, &fee_collector);
         env.storage().instance().set(&InstanceKey::FeeBps, &fee_bps);
-        set_dispute_window(&env, dispute_window_secs);
+        set_dispute_window(&env, dispute_window_ledgers);
         set_max_submission_delay(&env, 86400);
         env.storage()
             .instance()
             .set(&InstanceKey::Version, &CONTRACT_VERSION);
+        storage::set_storage_version(&env, STORAGE_VERSION);
     }
 
     // ── Admin Controls ─────────────────────────────────────────────────────────
@@ -185,164 +933,974 @@ impl OutcomeManager {
         if oracles.contains_key(oracle.clone()) {
             return;
         }
-        if oracle_list.len() as u32 >= MAX_ORACLES {
+        if oracle_list.len() >= MAX_ORACLES {
             soroban_sdk::panic_with_error!(&env, OutcomeError::MaxOraclesReached);
         }
+        record_oracle_added(&env, &oracle);
         oracles.set(oracle.clone(), true);
-        oracle_list.push_back(oracle);
+        oracle_list.push_back(oracle.clone());
         env.storage()
             .instance()
             .set(&InstanceKey::Oracles, &oracles);
         env.storage()
             .instance()
             .set(&InstanceKey::OracleList, &oracle_list);
+        emit_oracle_added(&env, &oracle, bump_config_version(&env));
     }
 
+    /// Remove a trusted oracle (admin only). While a parameter timelock is
+    /// set, removals must be queued with `queue_param_change` instead.
+    ///
+    /// # Panics
+    /// - `param change timelocked` – a parameter timelock is set
     pub fn remove_oracle(env: Env, oracle: BytesN<32>) {
         require_admin(&env);
-        let mut oracles = get_oracles(&env);
-        let oracle_list: Vec<BytesN<32>> = env
-            .storage()
-            .instance()
-            .get(&InstanceKey::OracleList)
-            .unwrap_or_else(|| Vec::new(&env));
-        let mut filtered = Vec::new(&env);
+        require_no_param_timelock(&env);
+        apply_remove_oracle(&env, oracle);
+    }
 
-        oracles.remove(oracle.clone());
-        for existing in oracle_list.iter() {
-            if existing != oracle {
-                filtered.push_back(existing);
-            }
+    /// Attach (or with `None`, clear) a label to a trusted oracle, shown by
+    /// `get_oracle_info` (admin only).
+    ///
+    /// # Panics
+    /// - `unauthorized oracle`  – `oracle` is not in the trusted set
+    /// - `invalid oracle label` – `label` is longer than `MAX_ORACLE_LABEL_LEN`
+    pub fn set_oracle_label(env: Env, oracle: BytesN<32>, label: Option<Bytes>) {
+        require_admin(&env);
+        let mut info = match Self::get_oracle_info(env.clone(), oracle) {
+            Some(info) => info,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeError::UnauthorizedOracle),
+        };
+        if matches!(&label, Some(label) if label.len() > MAX_ORACLE_LABEL_LEN) {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::InvalidOracleLabel);
         }
-        env.storage()
-            .instance()
-            .set(&InstanceKey::Oracles, &oracles);
-        env.storage()
-            .instance()
-            .set(&InstanceKey::OracleList, &filtered);
+        info.label = label.clone();
+        storage::set_oracle_info(&env, &info);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("label"), (info.pubkey, label), version);
     }
 
+    /// Set the global quorum (admin only). Raising it takes effect at once;
+    /// while a parameter timelock is set, lowering it must be queued with
+    /// `queue_param_change` instead.
+    ///
+    /// # Panics
+    /// - `invalid quorum`          – quorum is 0 or exceeds the oracle count
+    /// - `param change timelocked` – lowers the quorum under a timelock
     pub fn set_quorum(env: Env, quorum: u32) {
         require_admin(&env);
-        let oracles = get_oracles(&env);
-        if quorum == 0 || quorum > oracles.len() as u32 {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidQuorum);
+        if quorum < get_quorum(&env) {
+            require_no_param_timelock(&env);
         }
-        env.storage().instance().set(&InstanceKey::Quorum, &quorum);
+        apply_quorum(&env, quorum);
     }
 
-    pub fn set_admin(env: Env, new_admin: Address) {
+    /// Set how long, in seconds, quorum reductions, oracle removals, oracle
+    /// epochs and reductions of this delay must wait in `queue_param_change` (admin
+    /// only). `0` disables the timelock. Lengthening it takes effect at once.
+    ///
+    /// # Panics
+    /// - `param change timelocked` – shortens an active timelock
+    pub fn set_param_timelock(env: Env, secs: u64) {
         require_admin(&env);
-        env.storage()
-            .instance()
-            .set(&InstanceKey::Admin, &new_admin);
+        if secs < storage::get_param_timelock(&env) {
+            require_no_param_timelock(&env);
+        }
+        apply_param_timelock(&env, secs);
     }
 
-    pub fn set_max_submission_delay(env: Env, new_delay: u64) {
-        require_admin(&env);
-        set_max_submission_delay(&env, new_delay);
-        emit_admin_params_changed(&env, new_delay);
+    pub fn get_param_timelock(env: Env) -> u64 {
+        storage::get_param_timelock(&env)
     }
 
-    pub fn get_max_submission_delay(env: Env) -> u64 {
-        storage::get_max_submission_delay(&env)
+    /// Queue a parameter change to run with `execute_param_change` once the
+    /// parameter timelock has passed (admin only). Returns its id.
+    pub fn queue_param_change(env: Env, change: ParamChange) -> u32 {
+        require_admin(&env);
+        let eta = env
+            .ledger()
+            .timestamp()
+            .saturating_add(storage::get_param_timelock(&env));
+        let id = storage::next_param_change_id(&env);
+        let mut pending = storage::get_pending_param_changes(&env);
+        pending.set(
+            id,
+            PendingParamChange {
+                change: change.clone(),
+                eta,
+            },
+        );
+        storage::set_pending_param_changes(&env, &pending);
+        emit_param_change_queued(&env, id, &change, eta);
+        id
     }
 
-    // ── Emergency Pause ────────────────────────────────────────────────────────
+    /// Drop a queued parameter change (admin or governance), e.g. one
+    /// queued by a compromised admin key.
+    ///
+    /// # Panics
+    /// - `unauthorized`              – `caller` is neither the admin nor governance
+    /// - `param change not queued`   – no change is queued under `id`
+    pub fn cancel_param_change(env: Env, caller: Address, id: u32) {
+        require_admin_or_governance(&env, &caller);
+        let mut pending = storage::get_pending_param_changes(&env);
+        if pending.remove(id).is_none() {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::ParamChangeNotQueued);
+        }
+        storage::set_pending_param_changes(&env, &pending);
+        emit_param_change_cancelled(&env, id);
+    }
 
-    pub fn pause(env: Env) {
+    /// Apply a queued parameter change whose timelock has passed (admin only).
+    ///
+    /// # Panics
+    /// - `param change not queued` – no change is queued under `id`
+    /// - `param timelock active`   – the change's `eta` has not passed
+    /// - `invalid quorum`          – a queued quorum no longer fits the oracle set
+    /// - `invalid epoch activation` – a queued epoch's activation has passed
+    pub fn execute_param_change(env: Env, id: u32) {
         require_admin(&env);
-        env.storage().instance().set(&InstanceKey::Paused, &true);
+        let mut pending = storage::get_pending_param_changes(&env);
+        let queued = match pending.get(id) {
+            Some(queued) => queued,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeExtError::ParamChangeNotQueued),
+        };
+        if env.ledger().timestamp() < queued.eta {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::ParamTimelockActive);
+        }
+        pending.remove(id);
+        storage::set_pending_param_changes(&env, &pending);
+
+        match queued.change {
+            ParamChange::Quorum(quorum) => apply_quorum(&env, quorum),
+            ParamChange::RemoveOracle(oracle) => apply_remove_oracle(&env, oracle),
+            ParamChange::ParamTimelock(secs) => apply_param_timelock(&env, secs),
+            ParamChange::OracleEpoch(epoch) => apply_staged_epoch(&env, Some(epoch)),
+        }
     }
 
-    pub fn unpause(env: Env) {
+    pub fn get_pending_param_changes(env: Env) -> Map<u32, PendingParamChange> {
+        storage::get_pending_param_changes(&env)
+    }
+
+    /// Require a stricter quorum, or a specific committee of trusted oracles,
+    /// for one call (admin or governance). `None` falls back to the global
+    /// settings. Must be set before the call's `end_ts`.
+    ///
+    /// # Panics
+    /// - `unauthorized`        – `caller` is neither the admin nor governance
+    /// - `call already ended`  – the call's `end_ts` has passed
+    /// - `unauthorized oracle` – a committee member is not a trusted oracle
+    /// - `invalid quorum`      – quorum is 0 or exceeds the voters available
+    /// - `registry not allowed` – see `submit_outcome`
+    pub fn set_call_oracle_config(
+        env: Env,
+        caller: Address,
+        registry: Address,
+        call_id: u64,
+        config: Option<CallOracleConfig>,
+    ) {
+        require_admin_or_governance(&env, &caller);
+        require_registry(&env, &registry);
+        if env.ledger().timestamp() >= registry_call_end_ts(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::CallAlreadyEnded);
+        }
+        if let Some(config) = &config {
+            let oracles = get_oracles(&env);
+            for oracle in config.oracles.iter() {
+                if !oracles.contains_key(oracle) {
+                    soroban_sdk::panic_with_error!(&env, OutcomeError::UnauthorizedOracle);
+                }
+            }
+            let voters = if config.oracles.is_empty() {
+                oracles.len()
+            } else {
+                config.oracles.len()
+            };
+            if config.quorum == 0 || config.quorum > voters {
+                soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidQuorum);
+            }
+        }
+        storage::set_call_oracle_config(&env, &registry, call_id, &config);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("call_orc"), (call_id, config), version);
+    }
+
+    pub fn get_call_oracle_config(
+        env: Env,
+        registry: Address,
+        call_id: u64,
+    ) -> Option<CallOracleConfig> {
+        storage::get_call_oracle_config(&env, &registry, call_id)
+    }
+
+    /// Resolve a binary call from a SEP-40 price feed instead of signed
+    /// oracle reports (admin or governance). `None` returns the call to
+    /// oracle resolution. Must be set before the call's `end_ts`.
+    ///
+    /// # Panics
+    /// - `unauthorized`        – `caller` is neither the admin nor governance
+    /// - `call already ended`  – the call's `end_ts` has passed
+    /// - `registry not allowed` – see `submit_outcome`
+    pub fn set_call_price_feed(
+        env: Env,
+        caller: Address,
+        registry: Address,
+        call_id: u64,
+        feed: Option<PriceFeed>,
+    ) {
+        require_admin_or_governance(&env, &caller);
+        require_registry(&env, &registry);
+        if env.ledger().timestamp() >= registry_call_end_ts(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::CallAlreadyEnded);
+        }
+        storage::set_price_feed(&env, &registry, call_id, &feed);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("call_feed"), (call_id, feed), version);
+    }
+
+    pub fn get_call_price_feed(env: Env, registry: Address, call_id: u64) -> Option<PriceFeed> {
+        storage::get_price_feed(&env, &registry, call_id)
+    }
+
+    /// Resolve a binary call from the TWAP of oracle price observations made
+    /// in `[end_ts, end_ts + window_secs]` instead of a single signed report
+    /// (admin or governance). `None` returns the call to report resolution.
+    /// Must be set before the call's `end_ts`.
+    ///
+    /// # Panics
+    /// - `unauthorized`        – `caller` is neither the admin nor governance
+    /// - `call already ended`  – the call's `end_ts` has passed
+    /// - `zero time window`    – `window_secs` is 0
+    /// - `registry not allowed` – see `submit_outcome`
+    pub fn set_call_twap(
+        env: Env,
+        caller: Address,
+        registry: Address,
+        call_id: u64,
+        window_secs: Option<u64>,
+    ) {
+        require_admin_or_governance(&env, &caller);
+        require_registry(&env, &registry);
+        if env.ledger().timestamp() >= registry_call_end_ts(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::CallAlreadyEnded);
+        }
+        if window_secs == Some(0) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::ZeroTimeWindow);
+        }
+        storage::set_twap_window(&env, &registry, call_id, &window_secs);
+        let version = bump_config_version(&env);
+        emit_config_changed(
+            &env,
+            symbol_short!("call_twap"),
+            (call_id, window_secs),
+            version,
+        );
+    }
+
+    pub fn get_call_twap_window(env: Env, registry: Address, call_id: u64) -> Option<u64> {
+        storage::get_twap_window(&env, &registry, call_id)
+    }
+
+    /// Trust another CallRegistry instance (admin only). Calls are tracked
+    /// per registry, so its call ids may overlap those of other registries.
+    pub fn add_registry(env: Env, registry: Address) {
+        require_admin(&env);
+        let mut registries = storage::get_registries(&env);
+        if !registries.contains(&registry) {
+            registries.push_back(registry.clone());
+            storage::set_registries(&env, &registries);
+            let version = bump_config_version(&env);
+            emit_config_changed(&env, symbol_short!("reg_add"), registry, version);
+        }
+    }
+
+    /// Stop accepting submissions for a registry (admin only). Its calls that
+    /// already reached quorum can still be finalized, claimed and settled.
+    pub fn remove_registry(env: Env, registry: Address) {
+        require_admin(&env);
+        let mut registries = storage::get_registries(&env);
+        if let Some(index) = registries.first_index_of(&registry) {
+            registries.remove(index);
+            storage::set_registries(&env, &registries);
+            let version = bump_config_version(&env);
+            emit_config_changed(&env, symbol_short!("reg_rm"), registry, version);
+        }
+    }
+
+    /// Stage the oracle set and quorum for the next epoch (admin or
+    /// governance). It replaces the current set at `activation_ledger`, and
+    /// replaces any epoch staged earlier. `None` cancels the staged epoch.
+    /// While a parameter timelock is set, epochs must be queued with
+    /// `queue_param_change` instead; cancelling stays immediate.
+    ///
+    /// # Panics
+    /// - `unauthorized`               – `caller` is neither the admin nor governance
+    /// - `param change timelocked`    – stages an epoch under a timelock
+    /// - `invalid quorum`             – quorum is 0 or exceeds the staged oracles
+    /// - `max oracles reached`        – more than `MAX_ORACLES` oracles
+    /// - `invalid epoch activation`   – `activation_ledger` is not in the future
+    pub fn stage_oracle_epoch(env: Env, caller: Address, epoch: Option<StagedEpoch>) {
+        require_admin_or_governance(&env, &caller);
+        if epoch.is_some() {
+            require_no_param_timelock(&env);
+        }
+        apply_staged_epoch(&env, epoch);
+    }
+
+    pub fn get_staged_epoch(env: Env) -> Option<StagedEpoch> {
+        roll_oracle_epoch(&env);
+        storage::get_staged_epoch(&env)
+    }
+
+    /// Number of oracle epochs activated since initialization.
+    pub fn get_oracle_epoch(env: Env) -> u32 {
+        roll_oracle_epoch(&env);
+        storage::get_oracle_epoch(&env)
+    }
+
+    /// Hand admin rights to `new_admin`, dropping any M-of-N signer set.
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let old_admin = require_admin(&env);
+        storage::set_admin_signers(&env, &None);
+        env.storage()
+            .instance()
+            .set(&InstanceKey::Admin, &new_admin);
+        emit_admin_changed(&env, &old_admin, &new_admin, bump_config_version(&env));
+    }
+
+    /// Replace the admin with an M-of-N signer set (admin only); calling it
+    /// again rotates the signers. The contract becomes its own admin, and
+    /// each admin call then needs authorization from `threshold` distinct
+    /// `signers`, checked by `__check_auth`. `set_admin` returns to a single
+    /// admin address.
+    ///
+    /// # Panics
+    /// - `invalid admin signers` – `signers` is empty, repeats an address or
+    ///   holds more than `MAX_ADMIN_SIGNERS`, or `threshold` is 0 or exceeds it
+    pub fn set_admin_signers(env: Env, signers: Vec<Address>, threshold: u32) {
+        let old_admin = require_admin(&env);
+        let admin_signers = AdminSigners { signers, threshold };
+        validate_admin_signers(&env, &admin_signers);
+        storage::set_admin_signers(&env, &Some(admin_signers.clone()));
+
+        let version = bump_config_version(&env);
+        let this = env.current_contract_address();
+        if old_admin != this {
+            env.storage().instance().set(&InstanceKey::Admin, &this);
+            emit_admin_changed(&env, &old_admin, &this, version);
+        }
+        emit_config_changed(&env, symbol_short!("signers"), admin_signers, version);
+    }
+
+    /// Change how many admin signers must authorize each admin call (admin
+    /// only).
+    ///
+    /// # Panics
+    /// - `admin signers not set`  – no signer set is configured
+    /// - `invalid admin signers`  – `threshold` is 0 or exceeds the signers
+    pub fn set_admin_threshold(env: Env, threshold: u32) {
+        require_admin(&env);
+        let mut admin_signers = match storage::get_admin_signers(&env) {
+            Some(admin_signers) => admin_signers,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeExtError::AdminSignersNotSet),
+        };
+        admin_signers.threshold = threshold;
+        validate_admin_signers(&env, &admin_signers);
+        storage::set_admin_signers(&env, &Some(admin_signers));
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("threshold"), threshold, version);
+    }
+
+    pub fn get_admin_signers(env: Env) -> Option<AdminSigners> {
+        storage::get_admin_signers(&env)
+    }
+
+    pub fn set_max_submission_delay(env: Env, new_delay: u64) {
+        require_admin(&env);
+        set_max_submission_delay(&env, new_delay);
+        bump_config_version(&env);
+        emit_admin_params_changed(&env, new_delay);
+    }
+
+    /// Set (or with `None`, clear) the band within which votes on the same
+    /// outcome are grouped into one quorum candidate (admin only). Without a
+    /// tolerance, every vote on the same outcome counts.
+    ///
+    /// # Panics
+    /// - `invalid vote tolerance` – `price_bps` exceeds 10 000
+    pub fn set_vote_tolerance(env: Env, tolerance: Option<VoteTolerance>) {
+        require_admin(&env);
+        if let Some(tolerance) = &tolerance {
+            if !is_valid_fee_bps(tolerance.price_bps) {
+                soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidVoteTolerance);
+            }
+        }
+        storage::set_vote_tolerance(&env, &tolerance);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("tolerance"), tolerance, version);
+    }
+
+    pub fn get_vote_tolerance(env: Env) -> Option<VoteTolerance> {
+        storage::get_vote_tolerance(&env)
+    }
+
+    /// Set the bond a challenger must lock to dispute a pending outcome
+    /// (admin only).
+    ///
+    /// # Panics
+    /// - `invalid challenge bond` – `amount` is not positive
+    pub fn set_challenge_bond(env: Env, token: Address, amount: i128) {
+        require_admin(&env);
+        if amount <= 0 {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidChallengeBond);
+        }
+        let bond = ChallengeBond { token, amount };
+        storage::set_challenge_bond(&env, &bond);
+        emit_config_changed(&env, symbol_short!("bond"), bond, bump_config_version(&env));
+    }
+
+    pub fn get_challenge_bond(env: Env) -> Option<ChallengeBond> {
+        storage::get_challenge_bond(&env)
+    }
+
+    /// Set the governance contract allowed to reverse finalized outcomes, and
+    /// how many ledgers after finalization it may do so (admin only).
+    pub fn set_governance(env: Env, governance: Address, reversal_window_ledgers: u32) {
+        require_admin(&env);
+        storage::set_governance(&env, &governance, reversal_window_ledgers);
+        let version = bump_config_version(&env);
+        let value = (governance, reversal_window_ledgers);
+        emit_config_changed(&env, symbol_short!("gov"), value, version);
+    }
+
+    pub fn get_max_submission_delay(env: Env) -> u64 {
+        storage::get_max_submission_delay(&env)
+    }
+
+    /// Set how many seconds after a call's `end_ts` oracles have to reach
+    /// quorum before anyone may void it with `expire_unresolved` (admin only).
+    ///
+    /// # Panics
+    /// - `invalid resolution window` – `window_secs` is shorter than the
+    ///   max submission delay, so valid reports could still arrive
+    pub fn set_resolution_window(env: Env, window_secs: u64) {
+        require_admin(&env);
+        if window_secs < storage::get_max_submission_delay(&env) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidResolutionWindow);
+        }
+        storage::set_resolution_window(&env, window_secs);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("res_win"), window_secs, version);
+    }
+
+    pub fn get_resolution_window(env: Env) -> u64 {
+        storage::get_resolution_window(&env)
+    }
+
+    /// Set (or with `None`, clear) how many times above or below a call's
+    /// start price a reported price may be before it is rejected as a
+    /// decimal-place mistake (admin only). `Some(100)` accepts prices from
+    /// 1/100x to 100x the start price.
+    ///
+    /// # Panics
+    /// - `invalid price deviation` – `max_multiple` is below 2
+    pub fn set_max_price_deviation(env: Env, max_multiple: Option<u32>) {
+        require_admin(&env);
+        if matches!(max_multiple, Some(multiple) if multiple < 2) {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::InvalidPriceDeviation);
+        }
+        storage::set_max_price_deviation(&env, &max_multiple);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("deviation"), max_multiple, version);
+    }
+
+    pub fn get_max_price_deviation(env: Env) -> Option<u32> {
+        storage::get_max_price_deviation(&env)
+    }
+
+    /// Set (or with `None`, clear) how many ledgers after finalization
+    /// winners have to claim before `expire_claims` may move their winnings
+    /// to the insurance pool (admin only). A call's deadline is fixed when it
+    /// finalizes, so changing the window never shortens an open claim.
+    pub fn set_claim_window(env: Env, window: Option<u32>) {
+        require_admin(&env);
+        storage::set_claim_window(&env, &window);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("claim_win"), window, version);
+    }
+
+    pub fn get_claim_window(env: Env) -> Option<u32> {
+        storage::get_claim_window(&env)
+    }
+
+    /// Last ledger at which `call_id`'s winnings may be claimed, if it
+    /// finalized while a claim window was set.
+    pub fn get_claim_deadline(env: Env, registry: Address, call_id: u64) -> Option<u32> {
+        storage::get_claim_deadline(&env, &registry, call_id)
+    }
+
+    // ── Protocol Fee ───────────────────────────────────────────────────────────
+
+    /// Set the protocol fee taken from each winner's prize share, and from
+    /// DRAW refunds, on claim (admin only). Returned principal is never
+    /// charged on a winning claim.
+    ///
+    /// # Panics
+    /// - `invalid fee bps` – `fee_bps` is above 10000
+    pub fn set_fee_bps(env: Env, fee_bps: u32) {
+        require_admin(&env);
+        if !is_valid_fee_bps(fee_bps) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidFeeBps);
+        }
+        env.storage().instance().set(&InstanceKey::FeeBps, &fee_bps);
+        emit_config_changed(
+            &env,
+            symbol_short!("fee_bps"),
+            fee_bps,
+            bump_config_version(&env),
+        );
+    }
+
+    pub fn get_fee_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&InstanceKey::FeeBps)
+            .unwrap_or(0)
+    }
+
+    /// Set the treasury address protocol fees are paid to (admin only).
+    pub fn set_fee_collector(env: Env, fee_collector: Address) {
+        require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&InstanceKey::FeeCollector, &fee_collector);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("fee_to"), fee_collector, version);
+    }
+
+    pub fn get_fee_collector(env: Env) -> Address {
+        get_fee_collector(&env)
+    }
+
+    /// Route protocol fees through a FeeDistributor (admin only): each fee is
+    /// `deposit`ed there with the call's creator, so the creator earns their
+    /// share of it. `None` pays fees straight to the fee collector again.
+    pub fn set_fee_distributor(env: Env, fee_distributor: Option<Address>) {
+        require_admin(&env);
+        match &fee_distributor {
+            Some(distributor) => env
+                .storage()
+                .instance()
+                .set(&InstanceKey::FeeDistributor, distributor),
+            None => env
+                .storage()
+                .instance()
+                .remove(&InstanceKey::FeeDistributor),
+        }
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("fee_dist"), fee_distributor, version);
+    }
+
+    pub fn get_fee_distributor(env: Env) -> Option<Address> {
+        env.storage().instance().get(&InstanceKey::FeeDistributor)
+    }
+
+    /// Total protocol fees paid to fee collectors in `token`, net of
+    /// referral cuts, summed over every allowlisted registry.
+    pub fn get_accrued_fees(env: Env, token: Address) -> i128 {
+        let mut total: i128 = 0;
+        for registry in storage::get_registries(&env).iter() {
+            total = total
+                .checked_add(registry_protocol_fees(&env, &registry, &token))
+                .unwrap_or_else(|| overflow(&env));
+        }
+        total
+    }
+
+    // ── Oracle Rewards ─────────────────────────────────────────────────────────
+
+    /// Set the reward each oracle earns when its vote matches a call's
+    /// finalized outcome (admin only). Rewards are paid from a budget funded
+    /// with `fund_oracle_rewards`; once it runs out, votes go unrewarded. The
+    /// token cannot change once set, as the budget and balances are held in it.
+    ///
+    /// # Panics
+    /// - `invalid oracle reward` – `amount` is not positive, or `token` differs
+    ///   from the configured reward token
+    pub fn set_oracle_reward(env: Env, token: Address, amount: i128) {
+        require_admin(&env);
+        let same_token = match storage::get_oracle_reward(&env) {
+            Some(reward) => reward.token == token,
+            None => true,
+        };
+        if amount <= 0 || !same_token {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::InvalidOracleReward);
+        }
+        let reward = OracleReward { token, amount };
+        storage::set_oracle_reward(&env, &reward);
+        emit_config_changed(
+            &env,
+            symbol_short!("reward"),
+            reward,
+            bump_config_version(&env),
+        );
+    }
+
+    pub fn get_oracle_reward(env: Env) -> Option<OracleReward> {
+        storage::get_oracle_reward(&env)
+    }
+
+    /// Add `amount` of the reward token, paid by `funder`, to the rewards
+    /// budget. Anyone may fund it.
+    ///
+    /// # Panics
+    /// - `oracle reward not set` – no reward is configured
+    /// - `invalid oracle reward` – `amount` is not positive
+    pub fn fund_oracle_rewards(env: Env, funder: Address, amount: i128) {
+        funder.require_auth();
+        let reward = match storage::get_oracle_reward(&env) {
+            Some(reward) => reward,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeExtError::OracleRewardNotSet),
+        };
+        if amount <= 0 {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::InvalidOracleReward);
+        }
+        token::Client::new(&env, &reward.token).transfer(
+            &funder,
+            env.current_contract_address(),
+            &amount,
+        );
+        let budget = storage::get_oracle_reward_budget(&env)
+            .checked_add(amount)
+            .unwrap_or_else(|| overflow(&env));
+        storage::set_oracle_reward_budget(&env, budget);
+    }
+
+    /// Reward tokens held for future oracle rewards.
+    pub fn get_oracle_reward_budget(env: Env) -> i128 {
+        storage::get_oracle_reward_budget(&env)
+    }
+
+    /// Set (or with `None`, clear) the address `oracle`'s rewards are paid
+    /// to (admin only). Oracles that have left the trusted set keep theirs,
+    /// so they can still claim what they earned.
+    pub fn set_oracle_payout_address(env: Env, oracle: BytesN<32>, payout: Option<Address>) {
+        require_admin(&env);
+        storage::set_oracle_payout(&env, &oracle, &payout);
+        let version = bump_config_version(&env);
+        emit_config_changed(&env, symbol_short!("payout"), (oracle, payout), version);
+    }
+
+    pub fn get_oracle_payout_address(env: Env, oracle: BytesN<32>) -> Option<Address> {
+        storage::get_oracle_payout(&env, &oracle)
+    }
+
+    /// Rewards `oracle` has earned and not yet claimed.
+    pub fn get_oracle_rewards(env: Env, oracle: BytesN<32>) -> i128 {
+        storage::get_oracle_rewards(&env, &oracle)
+    }
+
+    /// Pay `oracle`'s accrued rewards to its payout address and return the
+    /// amount. Callable by anyone; the tokens only go to the mapped address.
+    ///
+    /// # Panics
+    /// - `payout address not set` – the oracle has no payout address
+    /// - `nothing to claim`       – the oracle has no accrued rewards
+    pub fn claim_oracle_rewards(env: Env, oracle: BytesN<32>) -> i128 {
+        require_claims_open(&env);
+        let payout = match storage::get_oracle_payout(&env, &oracle) {
+            Some(payout) => payout,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeExtError::PayoutAddressNotSet),
+        };
+        let amount = storage::get_oracle_rewards(&env, &oracle);
+        if amount <= 0 {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::NothingToClaim);
+        }
+        // Only set once a reward exists, so there is a token to pay in
+        let reward = match storage::get_oracle_reward(&env) {
+            Some(reward) => reward,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeExtError::OracleRewardNotSet),
+        };
+        storage::set_oracle_rewards(&env, &oracle, 0);
+        token::Client::new(&env, &reward.token).transfer(
+            &env.current_contract_address(),
+            &payout,
+            &amount,
+        );
+        emit_oracle_rewards_claimed(&env, &oracle, &payout, amount);
+        amount
+    }
+
+    // ── Emergency Pause ────────────────────────────────────────────────────────
+
+    pub fn pause(env: Env) {
+        require_admin(&env);
+        env.storage().instance().set(&InstanceKey::Paused, &true);
+        emit_config_changed(
+            &env,
+            symbol_short!("paused"),
+            true,
+            bump_config_version(&env),
+        );
+    }
+
+    pub fn unpause(env: Env) {
         require_admin(&env);
         env.storage().instance().set(&InstanceKey::Paused, &false);
+        emit_config_changed(
+            &env,
+            symbol_short!("paused"),
+            false,
+            bump_config_version(&env),
+        );
+    }
+
+    /// Number of admin configuration changes made so far; carried by every
+    /// admin event.
+    pub fn get_config_version(env: Env) -> u32 {
+        storage::get_config_version(&env)
     }
 
     pub fn is_paused_view(env: Env) -> bool {
         is_paused(&env)
     }
 
+    /// Set (or with `None`, clear) the address that may pause and unpause
+    /// submissions and claims alongside the admin (admin only).
+    pub fn set_pauser(env: Env, pauser: Option<Address>) {
+        require_admin(&env);
+        storage::set_pauser(&env, &pauser);
+        emit_config_changed(
+            &env,
+            symbol_short!("pauser"),
+            pauser,
+            bump_config_version(&env),
+        );
+    }
+
+    pub fn get_pauser(env: Env) -> Option<Address> {
+        storage::get_pauser(&env)
+    }
+
+    /// Halt oracle reports, price observations and every path that finalizes
+    /// a call (admin or pauser). Escrowed stakes are untouched.
+    pub fn pause_submissions(env: Env, caller: Address) {
+        require_pauser(&env, &caller);
+        Self::set_submissions_paused(&env, true);
+    }
+
+    pub fn unpause_submissions(env: Env, caller: Address) {
+        require_pauser(&env, &caller);
+        Self::set_submissions_paused(&env, false);
+    }
+
+    /// Halt staker payouts and oracle reward claims (admin or pauser).
+    pub fn pause_claims(env: Env, caller: Address) {
+        require_pauser(&env, &caller);
+        Self::set_claims_paused(&env, true);
+    }
+
+    pub fn unpause_claims(env: Env, caller: Address) {
+        require_pauser(&env, &caller);
+        Self::set_claims_paused(&env, false);
+    }
+
+    pub fn get_pause_state(env: Env) -> PauseState {
+        PauseState {
+            paused: is_paused(&env),
+            submissions_paused: storage::get_submissions_paused(&env),
+            claims_paused: storage::get_claims_paused(&env),
+        }
+    }
+
+    fn set_submissions_paused(env: &Env, paused: bool) {
+        storage::set_submissions_paused(env, paused);
+        emit_config_changed(
+            env,
+            symbol_short!("sub_pause"),
+            paused,
+            bump_config_version(env),
+        );
+    }
+
+    fn set_claims_paused(env: &Env, paused: bool) {
+        storage::set_claims_paused(env, paused);
+        emit_config_changed(
+            env,
+            symbol_short!("clm_pause"),
+            paused,
+            bump_config_version(env),
+        );
+    }
+
     // ── Oracle Submission ──────────────────────────────────────────────────────
 
     /// Accept a signed outcome report from a trusted oracle.
     ///
-    /// Once `quorum` oracles submit the **same** outcome (identified by the
-    /// SHA-256 hash of the canonical message), the call is automatically
-    /// finalized and the CallRegistry is updated via cross-contract call.
+    /// Once `quorum` oracles (or the call's own quorum, see
+    /// `set_call_oracle_config`) submit the **same** outcome, the call is
+    /// automatically finalized at the median of those oracles' prices and the
+    /// CallRegistry is updated via cross-contract call. If a vote tolerance is
+    /// set, only votes within it of the new report are counted.
     ///
     /// # Panics
-    /// - `unauthorized oracle`    – pubkey not in the trusted set
+    /// - `unauthorized oracle`    – pubkey not in the trusted set, or not on
+    ///   the call's committee
     /// - `already settled`        – quorum was already reached
     /// - `duplicate submission`   – this oracle already voted on this call
-    /// - `invalid outcome`        – outcome is not 1 (UP) or 2 (DOWN)
+    /// - `invalid outcome`        – outcome is not 1 (UP), 2 (DOWN) or 3 (DRAW)
+    /// - `timestamp before call end` – `timestamp` precedes the call's `end_ts`
+    /// - `submission window expired` – `timestamp` is past `end_ts + max_submission_delay`
+    /// - `report expired`         – the ledger sequence is past `valid_until`
+    /// - `nonce already used`     – `nonce` is not above the oracle's last accepted nonce
+    /// - `registry not allowed`   – `registry` is not on the allowlist
+    /// - `resolved by price feed` – the call resolves from its SEP-40 feed
+    /// - `resolved by twap`       – the call resolves from price observations
+    /// - `price deviation too large` – `price` is further from the call's start
+    ///   price than `set_max_price_deviation` allows
     /// - (ed25519_verify panics)  – signature is invalid; tx is reverted
-    pub fn submit_outcome(env: Env, registry: Address, signed: SignedOutcome, call_end_ts: u64) {
-        if is_paused(&env) {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::ContractPaused);
+    pub fn submit_outcome(env: Env, signed: SignedOutcome) {
+        require_submissions_open(&env);
+
+        if let Err(err) = Self::accept_submission(&env, &signed) {
+            soroban_sdk::panic_with_error!(&env, err);
         }
+    }
 
-        // 1. Validate oracle
-        let oracles = get_oracles(&env);
+    /// Submit several signed reports in one transaction, e.g. one per call
+    /// that ended in the same window. Reports are processed in order, as by
+    /// `submit_outcome`; one that would be rejected is skipped instead of
+    /// reverting the batch. Returns one code per report: 0 if it was
+    /// accepted, otherwise the contract error `submit_outcome` would raise.
+    ///
+    /// Only failures this contract detects are skipped. An invalid signature,
+    /// or a call the registry does not know, still reverts the whole batch.
+    ///
+    /// # Panics
+    /// - `contract paused` / `submissions paused` – submissions are paused
+    /// - `empty batch`     – `reports` is empty
+    pub fn submit_outcomes(env: Env, reports: Vec<SignedOutcome>) -> Vec<u32> {
+        require_submissions_open(&env);
+        if reports.is_empty() {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::EmptyBatch);
+        }
+
+        let mut results = Vec::new(&env);
+        for signed in reports.iter() {
+            let code = match Self::accept_submission(&env, &signed) {
+                Ok(()) => 0,
+                Err(err) => err.get_code(),
+            };
+            results.push_back(code);
+        }
+        results
+    }
+
+    /// Validate and record one signed report, reaching quorum if it completes
+    /// one. Nothing is written when a check fails.
+    fn accept_submission(env: &Env, signed: &SignedOutcome) -> Result<(), soroban_sdk::Error> {
+        // 1. Validate the registry and the oracle, against the call's
+        //    committee if it has one
+        let registry = signed.registry.clone();
+        if !storage::get_registries(env).contains(&registry) {
+            return Err(OutcomeError::RegistryNotAllowed.into());
+        }
+        let oracles = get_oracles(env);
         if !oracles.contains_key(signed.oracle_pubkey.clone()) {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::UnauthorizedOracle);
+            return Err(OutcomeError::UnauthorizedOracle.into());
+        }
+        let call_config = storage::get_call_oracle_config(env, &registry, signed.call_id);
+        if let Some(config) = &call_config {
+            if !config.oracles.is_empty() && !config.oracles.contains(&signed.oracle_pubkey) {
+                return Err(OutcomeError::UnauthorizedOracle.into());
+            }
         }
 
-        // 2. Reject if already settled
-        if env
-            .storage()
-            .instance()
-            .has(&InstanceKey::FinalOutcome(signed.call_id))
-        {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadySettled);
+        // 2. Reject if already settled, or resolved from a price feed or TWAP
+        if storage::get_price_feed(env, &registry, signed.call_id).is_some() {
+            return Err(OutcomeError::ResolvedByPriceFeed.into());
+        }
+        if storage::get_twap_window(env, &registry, signed.call_id).is_some() {
+            return Err(OutcomeExtError::ResolvedByTwap.into());
+        }
+        if storage::has_final_outcome(env, &registry, signed.call_id) {
+            return Err(OutcomeError::AlreadySettled.into());
+        }
+        if storage::get_pending_final(env, &registry, signed.call_id).is_some() {
+            return Err(OutcomeError::OutcomePending.into());
         }
 
         // 3. Guard against duplicate oracle votes
-        let submission_key = TempKey::Submission(signed.oracle_pubkey.clone(), signed.call_id);
-        if env.storage().temporary().has(&submission_key) {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::DuplicateSubmission);
+        let submission_key = PersistentKey::Submission(
+            signed.oracle_pubkey.clone(),
+            registry.clone(),
+            signed.call_id,
+        );
+        if env.storage().persistent().has(&submission_key) {
+            return Err(OutcomeError::DuplicateSubmission.into());
         }
 
-        // 4. Validate outcome range
-        if !is_valid_outcome(signed.outcome) {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidOutcome);
+        // 4. Validate the outcome against the call's positions
+        if !outcome_valid_for_call(env, &registry, signed.call_id, signed.outcome) {
+            return Err(OutcomeError::InvalidOutcome.into());
         }
 
-        // 4b. Enforce submission deadline: oracle timestamp must be within
-        //     call_end_ts + max_submission_delay to reject stale reports
-        let max_delay = storage::get_max_submission_delay(&env);
+        // 4b. Enforce submission window: the oracle must have observed the
+        //     price at or after the registry's end_ts, and within
+        //     end_ts + max_submission_delay to reject stale reports
+        let call_end_ts = registry_call_end_ts(env, &registry, signed.call_id);
+        if signed.timestamp < call_end_ts {
+            return Err(OutcomeError::TimestampBeforeCallEnd.into());
+        }
+        let max_delay = storage::get_max_submission_delay(env);
         let deadline = call_end_ts
             .checked_add(max_delay)
-            .unwrap_or_else(|| overflow(&env));
+            .unwrap_or_else(|| overflow(env));
         if signed.timestamp > deadline {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::SubmissionWindowExpired);
+            return Err(OutcomeError::SubmissionWindowExpired.into());
+        }
+
+        // 4c. Reject expired reports and replayed nonces
+        if env.ledger().sequence() > signed.valid_until {
+            return Err(OutcomeError::ReportExpired.into());
         }
+        let nonce_key = PersistentKey::OracleNonce(signed.oracle_pubkey.clone());
+        if let Some(last_nonce) = env.storage().persistent().get::<_, u64>(&nonce_key) {
+            if signed.nonce <= last_nonce {
+                return Err(OutcomeError::NonceAlreadyUsed.into());
+            }
+        }
+
+        // 4d. Reject prices implausibly far from the call's start price
+        check_price_deviation(env, &registry, signed.call_id, signed.price)?;
 
         // 5. Build canonical message and verify ed25519 signature
         let message = build_message(
-            &env,
+            env,
+            &env.current_contract_address(),
+            &registry,
             signed.call_id,
             signed.outcome,
             signed.price,
             signed.timestamp,
+            signed.nonce,
+            signed.valid_until,
         );
-        verify_signature(&env, &signed.oracle_pubkey, &signed.signature, &message);
+        verify_signature(env, &signed.oracle_pubkey, &signed.signature, &message);
+        env.storage().persistent().set(&nonce_key, &signed.nonce);
 
-        // 6. Hash outcome candidate for vote counting
-        let outcome_hash: BytesN<32> = env.crypto().sha256(&message).into();
-
-        // 7. Record oracle's vote (prevents duplicates)
+        // 6. Record oracle's vote (prevents duplicates until finalization)
         env.storage()
-            .temporary()
-            .set(&submission_key, &outcome_hash);
+            .persistent()
+            .set(&submission_key, &signed.outcome);
 
-        let vote_key = PersistentKey::Votes(signed.call_id);
+        let vote_key = PersistentKey::Votes(registry.clone(), signed.call_id);
         let mut votes_for_call: Vec<OracleVote> = env
             .storage()
             .persistent()
             .get(&vote_key)
-            .unwrap_or_else(|| Vec::new(&env));
+            .unwrap_or_else(|| Vec::new(env));
         votes_for_call.push_back(OracleVote {
             oracle: signed.oracle_pubkey.clone(),
             outcome: signed.outcome,
@@ -350,38 +1908,209 @@ impl OutcomeManager {
             timestamp: signed.timestamp,
         });
         env.storage().persistent().set(&vote_key, &votes_for_call);
+        storage::push_oracle_submission(
+            env,
+            &signed.oracle_pubkey,
+            &OracleSubmission {
+                registry: registry.clone(),
+                call_id: signed.call_id,
+                outcome: signed.outcome,
+                price: signed.price,
+                timestamp: signed.timestamp,
+                nonce: signed.nonce,
+                ledger: env.ledger().sequence(),
+            },
+            MAX_ORACLE_HISTORY,
+        );
 
-        // 8. Tally votes for this outcome candidate
-        let vote_key = TempKey::VoteCount(outcome_hash.clone(), signed.call_id);
-        let votes: u32 = env.storage().temporary().get(&vote_key).unwrap_or(0);
-        let votes = votes + 1;
-        env.storage().temporary().set(&vote_key, &votes);
-
-        emit_outcome_submitted(&env, signed.call_id, &signed.oracle_pubkey, signed.outcome);
+        emit_outcome_submitted(env, signed.call_id, &signed.oracle_pubkey, signed.outcome);
+
+        // 7. Tally votes on the outcome alone; prices may differ between
+        //    honest oracles. With a vote tolerance set, only votes close to
+        //    this one in price and time count towards the same candidate.
+        let tolerance = storage::get_vote_tolerance(env);
+        let mut prices: Vec<i128> = Vec::new(env);
+        for vote in votes_for_call.iter() {
+            let in_band = match &tolerance {
+                Some(tolerance) => within_tolerance(tolerance, &vote, signed),
+                None => true,
+            };
+            if vote.outcome == signed.outcome && in_band {
+                prices.push_back(vote.price);
+            }
+        }
 
-        // 9. Finalize if quorum reached
-        let quorum = get_quorum(&env);
-        if votes >= quorum {
-            Self::finalize(
-                &env,
-                &registry,
+        // 8. Settle at the median price once quorum is reached
+        let quorum = match call_config {
+            Some(config) => config.quorum,
+            None => get_quorum(env),
+        };
+        if prices.len() >= quorum {
+            Self::reach_quorum(
+                env,
                 Outcome {
+                    registry,
                     call_id: signed.call_id,
                     outcome: signed.outcome,
-                    price: signed.price,
+                    price: median_price(prices),
                     timestamp: signed.timestamp,
                 },
             );
         }
+        Ok(())
+    }
+
+    /// Resolve a price-feed call from its SEP-40 feed. The feed's latest
+    /// price must have been observed within `end_ts + max_submission_delay`;
+    /// the registry turns it into UP, DOWN or DRAW against the start price.
+    /// The result goes through the same challenge window as an oracle
+    /// quorum. Callable by anyone.
+    ///
+    /// # Panics
+    /// - `price feed not set`  – the call resolves from signed reports
+    /// - `already settled`     – the call was already finalized
+    /// - `outcome pending`     – the outcome is in its challenge window
+    /// - `price unavailable`   – the feed has no price, or its latest price
+    ///   falls outside the submission window
+    /// - `price deviation too large` – see `set_max_price_deviation`
+    pub fn resolve_from_price_feed(env: Env, registry: Address, call_id: u64) {
+        require_submissions_open(&env);
+        let feed = match storage::get_price_feed(&env, &registry, call_id) {
+            Some(feed) => feed,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeError::PriceFeedNotSet),
+        };
+        if storage::has_final_outcome(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadySettled);
+        }
+        if storage::get_pending_final(&env, &registry, call_id).is_some() {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::OutcomePending);
+        }
+
+        let call_end_ts = registry_call_end_ts(&env, &registry, call_id);
+        let deadline = call_end_ts
+            .checked_add(storage::get_max_submission_delay(&env))
+            .unwrap_or_else(|| overflow(&env));
+        let data = match feed_last_price(&env, &feed) {
+            Some(data) if data.timestamp >= call_end_ts && data.timestamp <= deadline => data,
+            _ => soroban_sdk::panic_with_error!(&env, OutcomeError::PriceUnavailable),
+        };
+
+        if let Err(err) = check_price_deviation(&env, &registry, call_id, data.price) {
+            soroban_sdk::panic_with_error!(&env, err);
+        }
+        let outcome = registry_binary_outcome(&env, &registry, call_id, data.price);
+        Self::reach_quorum(
+            &env,
+            Outcome {
+                registry,
+                call_id,
+                outcome,
+                price: data.price,
+                timestamp: data.timestamp,
+            },
+        );
+    }
+
+    /// Resolve a TWAP call once its observation window has closed, at the
+    /// time-weighted average of the observations submitted in it (see
+    /// `compute_twap`). The registry turns the TWAP into UP, DOWN or DRAW, and
+    /// the result goes through the challenge window. Callable by anyone.
+    ///
+    /// # Panics
+    /// - `twap not set`        – the call is not resolved by TWAP
+    /// - `already settled`     – the call was already finalized
+    /// - `outcome pending`     – the outcome is in its challenge window
+    /// - `twap window open`    – the observation window has not closed
+    /// - `compute_twap` panics – too few oracles with enough observations
+    /// - `price deviation too large` – see `set_max_price_deviation`
+    pub fn finalize_twap(env: Env, registry: Address, call_id: u64) {
+        require_submissions_open(&env);
+        let window = match storage::get_twap_window(&env, &registry, call_id) {
+            Some(window) => window,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeExtError::TwapNotSet),
+        };
+        if storage::has_final_outcome(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadySettled);
+        }
+        if storage::get_pending_final(&env, &registry, call_id).is_some() {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::OutcomePending);
+        }
+
+        let window_end = registry_call_end_ts(&env, &registry, call_id)
+            .checked_add(window)
+            .unwrap_or_else(|| overflow(&env));
+        if env.ledger().timestamp() <= window_end {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::TwapWindowOpen);
+        }
+
+        let price = Self::compute_twap(env.clone(), registry.clone(), call_id);
+        if let Err(err) = check_price_deviation(&env, &registry, call_id, price) {
+            soroban_sdk::panic_with_error!(&env, err);
+        }
+        let outcome = registry_binary_outcome(&env, &registry, call_id, price);
+        Self::reach_quorum(
+            &env,
+            Outcome {
+                registry,
+                call_id,
+                outcome,
+                price,
+                timestamp: window_end,
+            },
+        );
     }
 
     // ── Settlement ─────────────────────────────────────────────────────────────
 
-    fn finalize(env: &Env, registry: &Address, outcome: Outcome) {
+    /// Finalize immediately when no dispute window is configured, otherwise
+    /// hold the outcome as `PendingFinal` until the window has passed.
+    fn reach_quorum(env: &Env, outcome: Outcome) {
+        let window = storage::get_dispute_window(env);
+        if window == 0 {
+            Self::finalize(env, outcome);
+            return;
+        }
+        let challenge_deadline = env
+            .ledger()
+            .sequence()
+            .checked_add(window)
+            .unwrap_or_else(|| overflow(env));
+        storage::set_pending_final(
+            env,
+            &PendingFinal {
+                outcome: outcome.clone(),
+                challenge_deadline,
+            },
+        );
+        emit_outcome_pending(
+            env,
+            outcome.call_id,
+            outcome.outcome,
+            outcome.price,
+            challenge_deadline,
+        );
+    }
+
+    fn finalize(env: &Env, outcome: Outcome) {
+        let registry = &outcome.registry;
         // Persist finalized outcome (blocks re-submission)
-        env.storage()
-            .instance()
-            .set(&InstanceKey::FinalOutcome(outcome.call_id), &outcome);
+        storage::set_final_outcome(env, &outcome);
+        storage::index_finalized_call(env, registry, outcome.call_id);
+        if let Some(window) = storage::get_claim_window(env) {
+            let deadline = env.ledger().sequence().saturating_add(window);
+            storage::set_claim_deadline(env, registry, outcome.call_id, deadline);
+        }
+        storage::set_finalization(
+            env,
+            registry,
+            outcome.call_id,
+            &Finalization {
+                registry: registry.clone(),
+                ledger: env.ledger().sequence(),
+            },
+        );
+        accrue_oracle_rewards(env, registry, outcome.call_id, outcome.outcome);
+        storage::clear_vote_tracking(env, registry, outcome.call_id);
 
         // Cross-contract: resolve the call in the registry
         registry_resolve_call(
@@ -399,12 +2128,28 @@ impl OutcomeManager {
 
     /// Claim a pro-rata payout for a winning staker.
     ///
-    /// **Payout formula** (with protocol fee):
+    /// Every amount is read from the registry: the staker's stake and weight
+    /// (`get_staker_stake` / `get_weighted_stake`), the per-outcome totals
+    /// (`get_call_stats` / `get_weighted_outcome_stakes`) and the
+    /// `get_penalty_pool`, so callers cannot inflate their own payout.
+    ///
+    /// **Payout formula** (with protocol fee). Winners split the losing pool by
+    /// their time-weighted stake, so earlier stakes earn a larger share. The
+    /// losing pool includes the call's `penalty_pool` of reduce / switch fees:
     /// ```text
     /// fee        = total_losing_stake * fee_bps / 10000
     /// net_losing = total_losing_stake - fee
     /// payout     = staker_winning_stake
-    ///            + floor(staker_winning_stake * net_losing / total_winning_stake)
+    ///            + floor(staker_winning_weight * net_losing / total_winning_weight)
+    /// ```
+    /// The staker's share of `fee`, `fee_bps` of their gross prize share, is
+    /// paid to the fee collector; their principal is returned in full.
+    ///
+    /// When the call finalized as DRAW, every staker is refunded instead and
+    /// `staker_winning_stake` is the staker's total stake on either side:
+    /// ```text
+    /// fee    = staker_winning_stake * fee_bps / 10000
+    /// payout = staker_winning_stake - fee
     /// ```
     ///
     /// # Security
@@ -413,228 +2158,581 @@ impl OutcomeManager {
     ///
     /// # Panics
     /// - `call not settled`       – quorum not yet reached
+    /// - `call voided`            – the call finalized as VOID; refunds are
+    ///   claimed from the registry with `claim_void_refund`
     /// - `already claimed`        – staker already claimed
-    /// - `nothing to claim`       – staker_winning_stake ≤ 0
-    /// - `invalid total winning`  – total_winning_stake ≤ 0
-    pub fn claim_payout(
+    /// - `claim expired`          – the call's claim deadline has passed
+    /// - `nothing to claim`       – the staker has no stake or weight on the winning outcome
+    /// - `invalid total winning`  – total_winning_weight ≤ 0
+    pub fn claim_payout(env: Env, registry: Address, call_id: u64, staker: Address) {
+        // 0. Check if payouts are paused (emergency guard)
+        require_claims_open(&env);
+
+        // 1. Require staker's authorization
+        staker.require_auth();
+
+        // 2-9. Settle the claim, then release net payout to staker
+        let payout = Self::settle_claim(&env, &registry, call_id, &staker);
+        registry_release_escrow(&env, &registry, call_id, &staker, payout);
+
+        emit_payout_claimed(&env, call_id, &staker, payout);
+    }
+
+    /// Claim the payouts of several settled calls in one transaction.
+    ///
+    /// Each call is priced and settled exactly as in [`Self::claim_payout`],
+    /// but the payouts are released with one `release_escrows` call per
+    /// registry, so the staker receives a single transfer per stake token.
+    /// Returns the total paid out per stake token.
+    ///
+    /// # Panics
+    /// Any error [`Self::claim_payout`] would raise for one of `calls`
+    /// (including a repeated call, which is `already claimed`) reverts the
+    /// whole batch.
+    pub fn claim_all(env: Env, staker: Address, calls: Vec<(Address, u64)>) -> Map<Address, i128> {
+        require_claims_open(&env);
+        staker.require_auth();
+
+        let mut releases: Map<Address, Map<u64, i128>> = Map::new(&env);
+        for (registry, call_id) in calls.iter() {
+            let payout = Self::settle_claim(&env, &registry, call_id, &staker);
+            let mut batch = releases.get(registry.clone()).unwrap_or(Map::new(&env));
+            batch.set(call_id, payout);
+            releases.set(registry, batch);
+            emit_payout_claimed(&env, call_id, &staker, payout);
+        }
+
+        let mut totals: Map<Address, i128> = Map::new(&env);
+        for (registry, batch) in releases.iter() {
+            let sent = registry_release_escrows(&env, &registry, &staker, batch);
+            for (token, amount) in sent.iter() {
+                let total = totals.get(token.clone()).unwrap_or(0);
+                totals.set(
+                    token,
+                    total.checked_add(amount).unwrap_or_else(|| overflow(&env)),
+                );
+            }
+        }
+        totals
+    }
+
+    /// Steps 2-9 of a claim: price `staker`'s payout on `call_id`, mark it
+    /// claimed and release the protocol fee. Returns the payout still to be
+    /// released from the registry's escrow.
+    fn settle_claim(env: &Env, registry: &Address, call_id: u64, staker: &Address) -> i128 {
+        // 2. Verify the call is settled
+        let final_outcome: Outcome = match storage::get_final_outcome(env, registry, call_id) {
+            Some(outcome) => outcome,
+            None => soroban_sdk::panic_with_error!(env, OutcomeError::CallNotSettled),
+        };
+        if final_outcome.outcome == OUTCOME_VOID {
+            soroban_sdk::panic_with_error!(env, OutcomeError::CallVoided);
+        }
+
+        // 3. Prevent double-claim, and claims past the call's deadline
+        if storage::is_claimed(env, registry, call_id, staker) {
+            soroban_sdk::panic_with_error!(env, OutcomeError::AlreadyClaimed);
+        }
+        if claim_expired(env, registry, call_id) {
+            soroban_sdk::panic_with_error!(env, OutcomeExtError::ClaimExpired);
+        }
+
+        // 4. Read the staker's position from the registry
+        let (staker_winning_stake, staker_winning_weight) =
+            staker_totals(env, registry, call_id, staker, final_outcome.outcome);
+        if staker_winning_stake <= 0 || staker_winning_weight <= 0 {
+            soroban_sdk::panic_with_error!(env, OutcomeError::NothingToClaim);
+        }
+
+        let fee_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&InstanceKey::FeeBps)
+            .unwrap_or(0);
+
+        let (staker_fee_share, payout) = if final_outcome.outcome == OUTCOME_DRAW {
+            // 5-7. DRAW: refund the staker's own stake minus the protocol fee
+            draw_refund(env, staker_winning_stake, fee_bps)
+        } else {
+            let (total_winning_weight, total_losing_stake) =
+                pool_totals(env, registry, call_id, final_outcome.outcome);
+            if total_winning_weight <= 0 {
+                soroban_sdk::panic_with_error!(env, OutcomeError::InvalidWinningStake);
+            }
+
+            // 5-7. Weighted pro-rata share of the losing pool, net of the fee
+            winning_split(
+                env,
+                staker_winning_stake,
+                staker_winning_weight,
+                total_winning_weight,
+                total_losing_stake,
+                fee_bps,
+            )
+        };
+
+        // 8. Mark as claimed BEFORE external calls (reentrancy guard)
+        storage::set_claimed(env, registry, call_id, staker);
+        registry_burn_positions(env, registry, call_id, staker);
+
+        // 8b. Extend the staker's win streak, paying its bonus out of the fee
+        let (staker_fee_share, payout) = if final_outcome.outcome == OUTCOME_DRAW {
+            (staker_fee_share, payout)
+        } else {
+            apply_streak_bonus(env, registry, call_id, staker, staker_fee_share, payout)
+        };
+
+        // 9. Pay the fee out (if non-zero)
+        if staker_fee_share > 0 {
+            collect_fee(env, registry, call_id, staker, staker_fee_share);
+        }
+
+        payout
+    }
+
+    /// Move the unclaimed winnings of a page of `call_id`'s stakers into the
+    /// registry's insurance pool once the call's claim deadline has passed.
+    /// Anyone may call it. Each expired staker's fee share still goes to the
+    /// fee collector, and a `claim expired` event records what they lost so
+    /// they can appeal to governance. Pages walk the registry's
+    /// `get_call_stakers` listing, at most `MAX_EXPIRY_BATCH` at a time;
+    /// returns the cursor of the next page, `None` once all are processed.
+    ///
+    /// # Panics
+    /// - `call not settled`  – the call has not finalized
+    /// - `call voided`       – VOID refunds are claimed from the registry
+    /// - `claim window open` – the call has no deadline, or it has not passed
+    pub fn expire_claims(
         env: Env,
         registry: Address,
         call_id: u64,
-        staker: Address,
-        staker_winning_stake: i128,
-        total_winning_stake: i128,
-        total_losing_stake: i128,
-    ) {
-        // 0. Check if contract is paused (emergency guard)
-        if is_paused(&env) {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::ContractPaused);
+        cursor: u32,
+        limit: u32,
+    ) -> Option<u32> {
+        require_claims_open(&env);
+        let outcome = match storage::get_final_outcome(&env, &registry, call_id) {
+            Some(final_outcome) => final_outcome.outcome,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotSettled),
+        };
+        if outcome == OUTCOME_VOID {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::CallVoided);
+        }
+        if !claim_expired(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::ClaimWindowOpen);
+        }
+
+        let page = registry_call_stakers(
+            &env,
+            &registry,
+            call_id,
+            cursor,
+            limit.min(MAX_EXPIRY_BATCH),
+        );
+        let fee_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&InstanceKey::FeeBps)
+            .unwrap_or(0);
+        let (total_winning_weight, total_losing_stake) = if outcome == OUTCOME_DRAW {
+            (0, 0)
+        } else {
+            pool_totals(&env, &registry, call_id, outcome)
+        };
+
+        let mut expired: i128 = 0;
+        for staker in page.addresses.iter() {
+            if storage::is_claimed(&env, &registry, call_id, &staker) {
+                continue;
+            }
+            let (stake, weight) = staker_totals(&env, &registry, call_id, &staker, outcome);
+            if stake <= 0 || weight <= 0 {
+                continue;
+            }
+            let (fee, payout) = if outcome == OUTCOME_DRAW {
+                draw_refund(&env, stake, fee_bps)
+            } else if total_winning_weight > 0 {
+                winning_split(
+                    &env,
+                    stake,
+                    weight,
+                    total_winning_weight,
+                    total_losing_stake,
+                    fee_bps,
+                )
+            } else {
+                continue;
+            };
+
+            storage::set_claimed(&env, &registry, call_id, &staker);
+            registry_burn_positions(&env, &registry, call_id, &staker);
+            if fee > 0 {
+                collect_fee(&env, &registry, call_id, &staker, fee);
+            }
+            expired = expired
+                .checked_add(payout)
+                .unwrap_or_else(|| overflow(&env));
+            emit_claim_expired(&env, call_id, &staker, payout);
         }
 
-        // 1. Require staker's authorization
-        staker.require_auth();
-
-        // 2. Verify the call is settled
-        if !env
-            .storage()
-            .instance()
-            .has(&InstanceKey::FinalOutcome(call_id))
-        {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotSettled);
+        if expired > 0 {
+            registry_expire_escrow(&env, &registry, call_id, expired);
         }
+        page.next_cursor
+    }
 
-        // 3. Prevent double-claim
-        let claimed_key = InstanceKey::Claimed(call_id, staker.clone());
-        if env.storage().instance().has(&claimed_key) {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadyClaimed);
+    /// Preview the amount [`Self::claim_payout`] would release to `staker`
+    /// right now, including any streak bonus. Returns 0 if the call is not
+    /// settled or was voided, the staker has already claimed, or they hold
+    /// no winning stake.
+    pub fn get_claimable(env: Env, registry: Address, call_id: u64, staker: Address) -> i128 {
+        let outcome = match storage::get_final_outcome(&env, &registry, call_id) {
+            Some(final_outcome) if final_outcome.outcome != OUTCOME_VOID => final_outcome.outcome,
+            _ => return 0,
+        };
+        if storage::is_claimed(&env, &registry, call_id, &staker)
+            || claim_expired(&env, &registry, call_id)
+        {
+            return 0;
         }
 
-        // 4. Validate inputs
-        if staker_winning_stake <= 0 {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::NothingToClaim);
-        }
-        if total_winning_stake <= 0 {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidWinningStake);
+        let (stake, weight) = staker_totals(&env, &registry, call_id, &staker, outcome);
+        if stake <= 0 || weight <= 0 {
+            return 0;
         }
 
-        // 5. Compute protocol fee from losing pool (only on first claim; fee is
-        //    proportional so each claimant effectively pays their share)
         let fee_bps: u32 = env
             .storage()
             .instance()
             .get(&InstanceKey::FeeBps)
             .unwrap_or(0);
-        let fee_collector = get_fee_collector(&env);
+        if outcome == OUTCOME_DRAW {
+            return draw_refund(&env, stake, fee_bps).1;
+        }
 
-        // Staker's proportional share of the total fee
-        let total_fee = (total_losing_stake as i128)
-            .checked_mul(fee_bps as i128)
-            .unwrap_or_else(|| overflow(&env))
-            .checked_div(10000)
-            .unwrap_or_else(|| overflow(&env));
+        let (total_winning_weight, total_losing_stake) =
+            pool_totals(&env, &registry, call_id, outcome);
+        if total_winning_weight <= 0 {
+            return 0;
+        }
+        let (fee, payout) = winning_split(
+            &env,
+            stake,
+            weight,
+            total_winning_weight,
+            total_losing_stake,
+            fee_bps,
+        );
+        let bonus_bps = registry_streak_bonus_bps(&env, &registry, &staker);
+        let bonus = streak_bonus(&env, bonus_bps, fee, payout).max(0);
+        payout.checked_add(bonus).unwrap_or_else(|| overflow(&env))
+    }
 
-        let staker_fee_share = staker_winning_stake
-            .checked_mul(total_fee)
-            .unwrap_or_else(|| overflow(&env))
-            .checked_div(total_winning_stake)
-            .unwrap_or_else(|| overflow(&env));
+    /// Finalize a pending outcome once its challenge window has passed
+    /// without a challenge. Callable by anyone.
+    ///
+    /// # Panics
+    /// - `call not finalized` – no pending outcome, or the window is still open
+    /// - `challenge pending`  – a challenge awaits `resolve_challenge`
+    pub fn finalize_outcome(env: Env, registry: Address, call_id: u64) {
+        require_submissions_open(&env);
+        let pending = match storage::get_pending_final(&env, &registry, call_id) {
+            Some(pending) => pending,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotFinalized),
+        };
+        if storage::get_challenge(&env, &registry, call_id).is_some() {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::ChallengePending);
+        }
+        if env.ledger().sequence() <= pending.challenge_deadline {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotFinalized);
+        }
 
-        // 6. Net losing pool available to winners
-        let net_losing = total_losing_stake
-            .checked_sub(total_fee)
-            .unwrap_or_else(|| overflow(&env));
+        storage::remove_pending_final(&env, &registry, call_id);
+        Self::finalize(&env, pending.outcome);
+    }
 
-        // 7. Pro-rata payout from net losing pool
-        let prize_share = staker_winning_stake
-            .checked_mul(net_losing)
-            .unwrap_or_else(|| overflow(&env))
-            .checked_div(total_winning_stake)
-            .unwrap_or_else(|| overflow(&env));
+    /// Finalize a call as VOID once `end_ts + resolution_window` has passed
+    /// without quorum, so stakers can reclaim their stakes from the registry.
+    /// Callable by anyone.
+    ///
+    /// # Panics
+    /// - `already settled`          – the call was already finalized
+    /// - `outcome pending`          – quorum was reached; the outcome is in its challenge window
+    /// - `resolution window open`   – the resolution deadline has not passed yet
+    /// - `registry not allowed` – see `submit_outcome`
+    pub fn expire_unresolved(env: Env, registry: Address, call_id: u64) {
+        require_submissions_open(&env);
+        if storage::has_final_outcome(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadySettled);
+        }
+        if storage::get_pending_final(&env, &registry, call_id).is_some() {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::OutcomePending);
+        }
 
-        let payout = staker_winning_stake
-            .checked_add(prize_share)
+        require_registry(&env, &registry);
+        let deadline = registry_call_end_ts(&env, &registry, call_id)
+            .checked_add(storage::get_resolution_window(&env))
             .unwrap_or_else(|| overflow(&env));
-
-        // 8. Mark as claimed BEFORE external calls (reentrancy guard)
-        env.storage().instance().set(&claimed_key, &true);
-
-        // 9. Transfer fee to fee_collector (if non-zero)
-        if staker_fee_share > 0 {
-            registry_release_escrow(&env, &registry, call_id, &fee_collector, staker_fee_share);
-            emit_fee_collected(&env, call_id, staker_fee_share, &fee_collector);
+        let now = env.ledger().timestamp();
+        if now <= deadline {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::ResolutionWindowOpen);
         }
 
-        // 10. Release net payout to staker
-        registry_release_escrow(&env, &registry, call_id, &staker, payout);
-
-        emit_payout_claimed(&env, call_id, &staker, payout);
+        emit_outcome_expired(&env, call_id, deadline);
+        Self::finalize(
+            &env,
+            Outcome {
+                registry,
+                call_id,
+                outcome: OUTCOME_VOID,
+                price: 0,
+                timestamp: now,
+            },
+        );
     }
 
-    pub fn finalize_outcome(env: Env, call_id: u64) {
-        let pending: Outcome = match env
-            .storage()
-            .instance()
-            .get(&InstanceKey::PendingOutcome(call_id))
-        {
+    /// Override a pending outcome while its challenge window is open (admin only).
+    ///
+    /// # Panics
+    /// - `call not finalized`      – no pending outcome for this call
+    /// - `dispute window expired`  – the challenge window has closed
+    /// - `invalid outcome`         – `new_outcome` is not a valid outcome
+    pub fn dispute_outcome(
+        env: Env,
+        registry: Address,
+        call_id: u64,
+        new_outcome: u32,
+        new_price: i128,
+    ) {
+        require_admin(&env);
+
+        let mut pending = match storage::get_pending_final(&env, &registry, call_id) {
             Some(pending) => pending,
             None => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotFinalized),
         };
+        if env.ledger().sequence() > pending.challenge_deadline {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::DisputeWindowExpired);
+        }
+        if !outcome_valid_for_call(&env, &registry, call_id, new_outcome) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidOutcome);
+        }
 
-        let window_start: u64 = match env
-            .storage()
-            .instance()
-            .get(&InstanceKey::DisputeWindowStart(call_id))
-        {
-            Some(window_start) => window_start,
+        pending.outcome.outcome = new_outcome;
+        pending.outcome.price = new_price;
+        storage::set_pending_final(&env, &pending);
+        emit_outcome_disputed(&env, call_id, new_outcome, new_price);
+    }
+
+    /// Challenge a pending outcome with a counter-report, locking the
+    /// configured challenge bond in this contract. Only one challenge per call.
+    ///
+    /// # Panics
+    /// - `call not finalized`      – no pending outcome for this call
+    /// - `dispute window expired`  – the challenge window has closed
+    /// - `already challenged`      – the outcome already has a challenge
+    /// - `invalid outcome`         – `outcome` is not a valid outcome
+    /// - `challenge bond not set`  – the admin has not configured a bond
+    pub fn challenge_outcome(
+        env: Env,
+        challenger: Address,
+        registry: Address,
+        call_id: u64,
+        outcome: u32,
+        price: i128,
+    ) {
+        challenger.require_auth();
+
+        let pending = match storage::get_pending_final(&env, &registry, call_id) {
+            Some(pending) => pending,
             None => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotFinalized),
         };
-
-        let dispute_window = storage::get_dispute_window(&env);
-        if env.ledger().timestamp() < window_start + dispute_window {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotFinalized);
+        if env.ledger().sequence() > pending.challenge_deadline {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::DisputeWindowExpired);
+        }
+        if storage::get_challenge(&env, &registry, call_id).is_some() {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadyChallenged);
+        }
+        if !outcome_valid_for_call(&env, &registry, call_id, outcome) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidOutcome);
         }
+        let bond = match storage::get_challenge_bond(&env) {
+            Some(bond) => bond,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeError::ChallengeBondNotSet),
+        };
 
-        env.storage()
-            .instance()
-            .set(&InstanceKey::FinalOutcome(call_id), &pending);
-        let registry = get_registry(&env);
-        registry_resolve_call(
+        token::Client::new(&env, &bond.token).transfer(
+            &challenger,
+            env.current_contract_address(),
+            &bond.amount,
+        );
+        storage::set_challenge(
             &env,
             &registry,
-            pending.call_id,
-            pending.outcome,
-            pending.price,
+            call_id,
+            &Challenge {
+                challenger: challenger.clone(),
+                outcome,
+                price,
+                token: bond.token,
+                bond: bond.amount,
+            },
         );
-        emit_outcome_finalized(&env, call_id, pending.outcome, pending.price);
+        emit_outcome_challenged(&env, call_id, &challenger, outcome, price);
     }
 
-    pub fn dispute_outcome(env: Env, call_id: u64, new_outcome: u32, new_price: i128) {
+    /// Settle a challenge and finalize the call (admin only).
+    ///
+    /// If `upheld`, the counter-report replaces the pending outcome and the
+    /// bond is returned to the challenger; otherwise the pending outcome
+    /// stands and the bond goes to the fee collector.
+    ///
+    /// # Panics
+    /// - `call not finalized` – no pending outcome for this call
+    /// - `no challenge`       – the pending outcome was not challenged
+    pub fn resolve_challenge(env: Env, registry: Address, call_id: u64, upheld: bool) {
         require_admin(&env);
 
-        let mut pending: Outcome = match env
-            .storage()
-            .instance()
-            .get(&InstanceKey::PendingOutcome(call_id))
-        {
+        let mut pending = match storage::get_pending_final(&env, &registry, call_id) {
             Some(pending) => pending,
             None => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotFinalized),
         };
-
-        let window_start: u64 = match env
-            .storage()
-            .instance()
-            .get(&InstanceKey::DisputeWindowStart(call_id))
-        {
-            Some(window_start) => window_start,
-            None => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotFinalized),
+        let challenge = match storage::get_challenge(&env, &registry, call_id) {
+            Some(challenge) => challenge,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeError::NoChallenge),
         };
+        let bond_to = if upheld {
+            pending.outcome.outcome = challenge.outcome;
+            pending.outcome.price = challenge.price;
+            challenge.challenger
+        } else {
+            get_fee_collector(&env)
+        };
+        storage::remove_pending_final(&env, &registry, call_id);
+        storage::remove_challenge(&env, &registry, call_id);
+        token::Client::new(&env, &challenge.token).transfer(
+            &env.current_contract_address(),
+            &bond_to,
+            &challenge.bond,
+        );
+        emit_challenge_resolved(&env, call_id, upheld);
+        Self::finalize(&env, pending.outcome);
+    }
 
-        let dispute_window = storage::get_dispute_window(&env);
-        if env.ledger().timestamp() >= window_start + dispute_window {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::DisputeWindowExpired);
-        }
-
-        if !is_valid_outcome(new_outcome) {
+    /// Correct a finalized outcome that oracle consensus got provably wrong
+    /// (governance only). Allowed within the reversal window after
+    /// finalization and only while no payout on the call has been claimed.
+    /// The correction is propagated to the registry with `override_outcome`,
+    /// which only this contract may call, and the registry's own dispute
+    /// window must still be open.
+    ///
+    /// # Panics
+    /// - `governance not set`      – no governance contract is configured
+    /// - `call not settled`        – the call has no finalized outcome
+    /// - `invalid outcome`         – `corrected_outcome` is not a valid outcome
+    /// - `reversal window closed`  – the reversal window has passed
+    /// - `payout started`          – a staker has already claimed on this call
+    pub fn reverse_outcome(
+        env: Env,
+        registry: Address,
+        call_id: u64,
+        corrected_outcome: u32,
+        corrected_price: i128,
+        evidence_cid: Bytes,
+    ) {
+        let (governance, window) = match storage::get_governance(&env) {
+            Some(governance) => governance,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeError::GovernanceNotSet),
+        };
+        governance.require_auth();
+
+        let (mut outcome, finalization) = match (
+            storage::get_final_outcome(&env, &registry, call_id),
+            storage::get_finalization(&env, &registry, call_id),
+        ) {
+            (Some(outcome), Some(finalization)) => (outcome, finalization),
+            _ => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotSettled),
+        };
+        if !outcome_valid_for_call(&env, &registry, call_id, corrected_outcome) {
             soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidOutcome);
         }
+        let deadline = finalization.ledger.saturating_add(window);
+        if env.ledger().sequence() > deadline {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::ReversalWindowClosed);
+        }
+        if storage::payout_started(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::PayoutStarted);
+        }
 
-        pending.outcome = new_outcome;
-        pending.price = new_price;
-        env.storage()
-            .instance()
-            .set(&InstanceKey::PendingOutcome(call_id), &pending);
-        emit_outcome_disputed(&env, call_id, new_outcome, new_price);
+        let old_outcome = outcome.outcome;
+        outcome.outcome = corrected_outcome;
+        outcome.price = corrected_price;
+        storage::set_final_outcome(&env, &outcome);
+        registry_override_outcome(
+            &env,
+            &registry,
+            call_id,
+            corrected_outcome,
+            corrected_price,
+            &evidence_cid,
+        );
+        emit_outcome_reversed(
+            &env,
+            call_id,
+            old_outcome,
+            corrected_outcome,
+            corrected_price,
+            &evidence_cid,
+        );
     }
 
     /// Batch-settle payouts for multiple winning stakers in a single transaction.
     ///
-    /// Admin-only. Each staker in `stakers` is matched positionally with the
-    /// corresponding amount in `stakes`. Both vecs must be the same length.
+    /// Admin-only. Each staker's stake and time-weighted stake, and the
+    /// pool totals, are read from the registry as in `claim_payout`.
     ///
-    /// Individual `PayoutClaimed` events are emitted for each staker.
+    /// Individual `PayoutClaimed` events are emitted for each staker. For a
+    /// DRAW outcome each staker is refunded their stake minus the protocol fee.
     /// Already-claimed stakers cause the entire batch to panic — callers must
     /// filter them out beforehand using `has_claimed`.
     ///
     /// # Panics
     /// - `not admin`                 – caller is not the contract admin
     /// - `call not settled`          – quorum not yet reached for this call
+    /// - `call voided`               – the call finalized as VOID
+    /// - `claim expired`             – the call's claim deadline has passed
     /// - `empty batch`               – stakers vec is empty
-    /// - `length mismatch`           – stakers and stakes vecs differ in length
-    /// - `invalid total winning`     – total_winning_stake ≤ 0
+    /// - `invalid total winning`     – total_winning_weight ≤ 0
     /// - `already claimed: <staker>` – a staker in the batch already claimed
-    /// - `nothing to claim`          – a staker's stake amount is ≤ 0
-    pub fn batch_claim_payouts(
-        env: Env,
-        registry: Address,
-        call_id: u64,
-        stakers: Vec<Address>,
-        stakes: Vec<i128>,
-        total_winning_stake: i128,
-        total_losing_stake: i128,
-    ) {
-        // 1. Admin only
+    /// - `nothing to claim`          – a staker has no stake or weight on the winning outcome
+    pub fn batch_claim_payouts(env: Env, registry: Address, call_id: u64, stakers: Vec<Address>) {
+        // 1. Admin only, while payouts are open
         require_admin(&env);
+        require_claims_open(&env);
 
         // 2. Verify the call is settled
-        if !env
-            .storage()
-            .instance()
-            .has(&InstanceKey::FinalOutcome(call_id))
-        {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotSettled);
+        let final_outcome: Outcome = match storage::get_final_outcome(&env, &registry, call_id) {
+            Some(outcome) => outcome,
+            None => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotSettled),
+        };
+        if final_outcome.outcome == OUTCOME_VOID {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::CallVoided);
         }
+        if claim_expired(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::ClaimExpired);
+        }
+        let is_draw = final_outcome.outcome == OUTCOME_DRAW;
 
         // 3. Reject empty batches
         if stakers.is_empty() {
             soroban_sdk::panic_with_error!(&env, OutcomeError::EmptyBatch);
         }
 
-        // 4. Vecs must be same length
-        if stakers.len() != stakes.len() {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::LengthMismatch);
-        }
-
-        // 5. Validate shared inputs once
-        if total_winning_stake <= 0 {
+        // 4-5. Read and validate the pool totals once
+        let (total_winning_weight, total_losing_stake) = if is_draw {
+            (0, 0)
+        } else {
+            pool_totals(&env, &registry, call_id, final_outcome.outcome)
+        };
+        if !is_draw && total_winning_weight <= 0 {
             soroban_sdk::panic_with_error!(&env, OutcomeError::InvalidWinningStake);
         }
 
@@ -644,61 +2742,51 @@ impl OutcomeManager {
             .instance()
             .get(&InstanceKey::FeeBps)
             .unwrap_or(0);
-        let fee_collector = get_fee_collector(&env);
-
-        // Pre-compute shared fee values
-        let total_fee = (total_losing_stake as i128)
-            .checked_mul(fee_bps as i128)
-            .unwrap_or_else(|| overflow(&env))
-            .checked_div(10000)
-            .unwrap_or_else(|| overflow(&env));
-
-        let net_losing = total_losing_stake
-            .checked_sub(total_fee)
-            .unwrap_or_else(|| overflow(&env));
 
         emit_batch_payout_started(&env, call_id, stakers.len());
 
         // 7. Process each staker
         for i in 0..stakers.len() {
             let staker = stakers.get(i).unwrap();
-            let staker_winning_stake = stakes.get(i).unwrap();
+            let (staker_winning_stake, staker_winning_weight) =
+                staker_totals(&env, &registry, call_id, &staker, final_outcome.outcome);
 
-            if staker_winning_stake <= 0 {
+            if staker_winning_stake <= 0 || staker_winning_weight <= 0 {
                 soroban_sdk::panic_with_error!(&env, OutcomeError::NothingToClaim);
             }
 
             // Guard against duplicates within the batch and prior claims
-            let claimed_key = InstanceKey::Claimed(call_id, staker.clone());
-            if env.storage().instance().has(&claimed_key) {
+            if storage::is_claimed(&env, &registry, call_id, &staker) {
                 soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadyClaimed);
             }
 
-            // Staker's proportional fee share
-            let staker_fee_share = staker_winning_stake
-                .checked_mul(total_fee)
-                .unwrap_or_else(|| overflow(&env))
-                .checked_div(total_winning_stake)
-                .unwrap_or_else(|| overflow(&env));
-
-            // Pro-rata payout from net losing pool
-            let prize_share = staker_winning_stake
-                .checked_mul(net_losing)
-                .unwrap_or_else(|| overflow(&env))
-                .checked_div(total_winning_stake)
-                .unwrap_or_else(|| overflow(&env));
-
-            let payout = staker_winning_stake
-                .checked_add(prize_share)
-                .unwrap_or_else(|| overflow(&env));
+            let (staker_fee_share, payout) = if is_draw {
+                draw_refund(&env, staker_winning_stake, fee_bps)
+            } else {
+                winning_split(
+                    &env,
+                    staker_winning_stake,
+                    staker_winning_weight,
+                    total_winning_weight,
+                    total_losing_stake,
+                    fee_bps,
+                )
+            };
 
             // Mark claimed BEFORE external calls (reentrancy guard)
-            env.storage().instance().set(&claimed_key, &true);
+            storage::set_claimed(&env, &registry, call_id, &staker);
+            registry_burn_positions(&env, &registry, call_id, &staker);
+
+            // Extend the staker's win streak, paying its bonus out of the fee
+            let (staker_fee_share, payout) = if is_draw {
+                (staker_fee_share, payout)
+            } else {
+                apply_streak_bonus(&env, &registry, call_id, &staker, staker_fee_share, payout)
+            };
 
             // Transfer fee share
             if staker_fee_share > 0 {
-                registry_release_escrow(&env, &registry, call_id, &fee_collector, staker_fee_share);
-                emit_fee_collected(&env, call_id, staker_fee_share, &fee_collector);
+                collect_fee(&env, &registry, call_id, &staker, staker_fee_share);
             }
 
             // Release payout to staker
@@ -716,11 +2804,7 @@ impl OutcomeManager {
     pub fn mark_settled(env: Env, registry: Address, call_id: u64) {
         require_admin(&env);
 
-        if !env
-            .storage()
-            .instance()
-            .has(&InstanceKey::FinalOutcome(call_id))
-        {
+        if !storage::has_final_outcome(&env, &registry, call_id) {
             soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotFinalized);
         }
 
@@ -729,23 +2813,60 @@ impl OutcomeManager {
 
     // ── View Functions ─────────────────────────────────────────────────────────
 
+    /// Registries whose calls this contract accepts submissions for.
+    pub fn get_registries(env: Env) -> Vec<Address> {
+        storage::get_registries(&env)
+    }
+
     /// Return the finalized outcome, or panic if not yet settled.
-    pub fn get_outcome(env: Env, call_id: u64) -> Outcome {
-        match env
-            .storage()
-            .instance()
-            .get(&InstanceKey::FinalOutcome(call_id))
-        {
+    pub fn get_outcome(env: Env, registry: Address, call_id: u64) -> Outcome {
+        match storage::get_final_outcome(&env, &registry, call_id) {
             Some(outcome) => outcome,
             None => soroban_sdk::panic_with_error!(&env, OutcomeError::CallNotSettled),
         }
     }
 
+    /// Return a page of finalized outcomes in the order the calls were
+    /// finalized, reflecting any later governance reversal. Pages hold at
+    /// most `MAX_OUTCOME_PAGE` entries.
+    pub fn get_finalized_outcomes(env: Env, cursor: u32, limit: u32) -> OutcomePage {
+        let count = storage::get_finalized_count(&env);
+        let start = cursor.min(count);
+        let end = start.saturating_add(limit.min(MAX_OUTCOME_PAGE)).min(count);
+        let mut outcomes = Vec::new(&env);
+        for index in start..end {
+            let outcome =
+                storage::get_finalized_call(&env, index).and_then(|(registry, call_id)| {
+                    storage::get_final_outcome(&env, &registry, call_id)
+                });
+            if let Some(outcome) = outcome {
+                outcomes.push_back(outcome);
+            }
+        }
+        OutcomePage {
+            outcomes,
+            next_cursor: if end < count { Some(end) } else { None },
+        }
+    }
+
+    /// Return the number of calls finalized so far.
+    pub fn get_finalized_count(env: Env) -> u32 {
+        storage::get_finalized_count(&env)
+    }
+
+    /// Return the outcome waiting out its challenge window, if any.
+    pub fn get_pending_outcome(env: Env, registry: Address, call_id: u64) -> Option<PendingFinal> {
+        storage::get_pending_final(&env, &registry, call_id)
+    }
+
+    /// Return the open challenge against a pending outcome, if any.
+    pub fn get_challenge(env: Env, registry: Address, call_id: u64) -> Option<Challenge> {
+        storage::get_challenge(&env, &registry, call_id)
+    }
+
     /// `true` if the staker has already claimed their payout for this call.
-    pub fn has_claimed(env: Env, call_id: u64, staker: Address) -> bool {
-        env.storage()
-            .instance()
-            .has(&InstanceKey::Claimed(call_id, staker))
+    pub fn has_claimed(env: Env, registry: Address, call_id: u64, staker: Address) -> bool {
+        storage::is_claimed(&env, &registry, call_id, &staker)
     }
 
     /// Return the current quorum threshold.
@@ -761,28 +2882,150 @@ impl OutcomeManager {
 
     /// Return the trusted oracle public keys.
     pub fn get_oracles(env: Env) -> Vec<BytesN<32>> {
+        roll_oracle_epoch(&env);
         env.storage()
             .instance()
             .get(&InstanceKey::OracleList)
             .unwrap_or_else(|| Vec::new(&env))
     }
 
+    /// Return a trusted oracle's record, or `None` if `oracle` is not in the
+    /// trusted set. Oracles added before records were kept report an
+    /// `added_at` of 0.
+    pub fn get_oracle_info(env: Env, oracle: BytesN<32>) -> Option<OracleInfo> {
+        if !get_oracles(&env).contains_key(oracle.clone()) {
+            return None;
+        }
+        Some(
+            storage::get_oracle_info(&env, &oracle).unwrap_or(OracleInfo {
+                pubkey: oracle,
+                added_at: 0,
+                label: None,
+            }),
+        )
+    }
+
+    /// Return a page of the trusted oracle set with each oracle's record, in
+    /// the order of `get_oracles`. Pages hold at most `MAX_ORACLES` entries.
+    pub fn get_oracle_infos(env: Env, cursor: u32, limit: u32) -> OraclePage {
+        let list = Self::get_oracles(env.clone());
+        let start = cursor.min(list.len());
+        let end = start.saturating_add(limit.min(MAX_ORACLES)).min(list.len());
+        let mut oracles = Vec::new(&env);
+        for oracle in list.slice(start..end).iter() {
+            if let Some(info) = Self::get_oracle_info(env.clone(), oracle) {
+                oracles.push_back(info);
+            }
+        }
+        OraclePage {
+            oracles,
+            next_cursor: if end < list.len() { Some(end) } else { None },
+        }
+    }
+
+    /// Return the exact bytes an oracle must sign for a report with these
+    /// fields, as `submit_outcome` rebuilds them on this deployment, so
+    /// off-chain signers can be checked byte for byte against it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn preview_message(
+        env: Env,
+        registry: Address,
+        call_id: u64,
+        outcome: u32,
+        price: i128,
+        timestamp: u64,
+        nonce: u64,
+        valid_until: u32,
+    ) -> Bytes {
+        build_message(
+            &env,
+            &env.current_contract_address(),
+            &registry,
+            call_id,
+            outcome,
+            price,
+            timestamp,
+            nonce,
+            valid_until,
+        )
+    }
+
+    /// Whether `oracle` has voted on `call_id`. Stays `true` after the call
+    /// finalizes, as the call's votes are kept.
+    pub fn has_submitted(env: Env, registry: Address, call_id: u64, oracle: BytesN<32>) -> bool {
+        Self::get_votes(env, registry, call_id)
+            .iter()
+            .any(|vote| vote.oracle == oracle)
+    }
+
+    /// The highest report nonce accepted from `oracle`; its next report must
+    /// use a larger one.
+    pub fn get_oracle_nonce(env: Env, oracle: BytesN<32>) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::OracleNonce(oracle))
+    }
+
+    /// Return a page of `oracle`'s most recently accepted reports, newest
+    /// first. Only the last `MAX_ORACLE_HISTORY` are kept, and a new report
+    /// shifts every cursor by one, so daemons should page from 0.
+    pub fn get_oracle_submissions(
+        env: Env,
+        oracle: BytesN<32>,
+        cursor: u32,
+        limit: u32,
+    ) -> SubmissionPage {
+        let history = storage::get_oracle_submissions(&env, &oracle);
+        let start = cursor.min(history.len());
+        let end = start
+            .saturating_add(limit.min(MAX_ORACLE_HISTORY))
+            .min(history.len());
+        SubmissionPage {
+            submissions: history.slice(start..end),
+            next_cursor: if end < history.len() { Some(end) } else { None },
+        }
+    }
+
     /// Return the total number of trusted oracles.
     pub fn get_oracle_count(env: Env) -> u32 {
-        Self::get_oracles(env).len() as u32
+        Self::get_oracles(env).len()
     }
 
     /// Return all oracle votes stored for a call.
-    pub fn get_votes(env: Env, call_id: u64) -> Vec<OracleVote> {
+    pub fn get_votes(env: Env, registry: Address, call_id: u64) -> Vec<OracleVote> {
         env.storage()
             .persistent()
-            .get(&PersistentKey::Votes(call_id))
+            .get(&PersistentKey::Votes(registry, call_id))
             .unwrap_or_else(|| Vec::new(&env))
     }
 
+    /// Return how a call's vote stands: votes per outcome, who has voted,
+    /// and how many more votes the leading outcome needs for quorum. With a
+    /// vote tolerance set, votes on one outcome may not all count towards
+    /// the same candidate, so `votes_needed` is a lower bound.
+    pub fn get_vote_status(env: Env, registry: Address, call_id: u64) -> VoteStatus {
+        let quorum = match storage::get_call_oracle_config(&env, &registry, call_id) {
+            Some(config) => config.quorum,
+            None => get_quorum(&env),
+        };
+        let mut votes: Map<u32, u32> = Map::new(&env);
+        let mut voters = Vec::new(&env);
+        for vote in Self::get_votes(env.clone(), registry, call_id).iter() {
+            votes.set(vote.outcome, votes.get(vote.outcome).unwrap_or(0) + 1);
+            voters.push_back(vote.oracle);
+        }
+        let leading = votes.values().iter().max().unwrap_or(0);
+        VoteStatus {
+            votes,
+            voters,
+            quorum,
+            votes_needed: quorum.saturating_sub(leading),
+        }
+    }
+
     /// Return the number of stored oracle votes for a call.
-    pub fn get_vote_count(env: Env, call_id: u64) -> u32 {
-        Self::get_votes(env, call_id).len() as u32
+    pub fn get_vote_count(env: Env, registry: Address, call_id: u64) -> u32 {
+        Self::get_votes(env, registry, call_id).len()
     }
 
     /// Return the current contract version.
@@ -793,18 +3036,67 @@ impl OutcomeManager {
             .unwrap_or(CONTRACT_VERSION)
     }
 
-    /// Upgrade the contract WASM to a new hash (admin only).
-    ///
-    /// Increments the stored version and emits `ContractUpgraded`.
+    /// Layout version of the stored data; see `STORAGE_VERSION`.
+    pub fn get_storage_version(env: Env) -> u32 {
+        storage::get_storage_version(&env)
+    }
+
+    /// Queue an upgrade to `new_wasm_hash` (admin only). It may run with
+    /// `upgrade` once `UPGRADE_TIMELOCK_SECS` have passed, giving oracles and
+    /// stakers notice. Replaces any upgrade already queued.
     ///
     /// # Panics
     /// - `not initialized` if the contract has not been initialized.
+    pub fn queue_upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        upgrade_admin(&env);
+        let eta = env
+            .ledger()
+            .timestamp()
+            .saturating_add(UPGRADE_TIMELOCK_SECS);
+        storage::set_pending_upgrade(
+            &env,
+            &Some(PendingUpgrade {
+                wasm_hash: new_wasm_hash.clone(),
+                eta,
+            }),
+        );
+        emit_upgrade_queued(&env, &new_wasm_hash, eta);
+    }
+
+    /// Drop the queued upgrade, if any (admin only).
+    pub fn cancel_upgrade(env: Env) {
+        upgrade_admin(&env);
+        if let Some(pending) = storage::get_pending_upgrade(&env) {
+            storage::set_pending_upgrade(&env, &None);
+            emit_upgrade_cancelled(&env, &pending.wasm_hash);
+        }
+    }
+
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        storage::get_pending_upgrade(&env)
+    }
+
+    /// Upgrade the contract WASM to a queued hash (admin only).
+    ///
+    /// Increments the stored version and emits `ContractUpgraded`. The new
+    /// code must run `migrate` before anything else if it bumps
+    /// `STORAGE_VERSION`; until then guarded entry points refuse to run.
+    ///
+    /// # Panics
+    /// - `not initialized`         – the contract has not been initialized
+    /// - `upgrade not queued`      – `new_wasm_hash` is not the queued hash
+    /// - `upgrade timelock active` – the queued upgrade's `eta` has not passed
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = match env.storage().instance().get(&InstanceKey::Admin) {
-            Some(admin) => admin,
-            None => soroban_sdk::panic_with_error!(&env, OutcomeError::NotInitialized),
-        };
-        admin.require_auth();
+        let admin = upgrade_admin(&env);
+        match storage::get_pending_upgrade(&env) {
+            Some(pending) if pending.wasm_hash == new_wasm_hash => {
+                if env.ledger().timestamp() < pending.eta {
+                    soroban_sdk::panic_with_error!(&env, OutcomeExtError::UpgradeTimelockActive);
+                }
+            }
+            _ => soroban_sdk::panic_with_error!(&env, OutcomeExtError::UpgradeNotQueued),
+        }
+        storage::set_pending_upgrade(&env, &None);
 
         let old_version: u32 = env
             .storage()
@@ -821,104 +3113,202 @@ impl OutcomeManager {
         emit_contract_upgraded(&env, old_version, new_version, &admin);
     }
 
+    /// Bring stored data up to `STORAGE_VERSION` after an upgrade (admin
+    /// only) and return the resulting version. A no-op when already current.
+    ///
+    /// # Panics
+    /// - `not initialized`          – the contract has not been initialized
+    /// - `storage version mismatch` – the data is newer than this code, as
+    ///   after a downgrade; upgrade to matching code instead
+    pub fn migrate(env: Env) -> u32 {
+        upgrade_admin(&env);
+        let stored = storage::get_storage_version(&env);
+        if stored > STORAGE_VERSION {
+            soroban_sdk::panic_with_error!(&env, OutcomeExtError::StorageVersionMismatch);
+        }
+        if stored < STORAGE_VERSION {
+            // Per-version migration steps go here, oldest first.
+            // 1 -> 2: per-call entries are keyed by (registry, call_id). Layout
+            // 1 entries cannot be re-keyed in place, so calls still open under
+            // it are resolved again from fresh reports.
+            storage::set_storage_version(&env, STORAGE_VERSION);
+            emit_storage_migrated(&env, stored, STORAGE_VERSION);
+        }
+        STORAGE_VERSION
+    }
+
     /// Submit a signed price observation for TWAP calculation.
     ///
-    /// Observations must be submitted in strictly increasing timestamp order.
-    /// A minimum of 3 observations is required before `compute_twap` can be called.
+    /// Each oracle keeps its own series, in strictly increasing timestamp
+    /// order; `compute_twap` takes the median of the oracles' TWAPs. The
+    /// signature covers the network, this contract, `registry` and `nonce`
+    /// (see `build_observation_message`), and `nonce` must exceed the last
+    /// one the oracle used. For a call resolved by TWAP the observations
+    /// must fall in its observation window.
     ///
     /// # Panics
-    /// - `unauthorized oracle`              - pubkey not in the trusted set
-    /// - `observation timestamp must be strictly increasing` - out-of-order submission
+    /// - `unauthorized oracle`              - pubkey not in the trusted set or
+    ///   the call's committee
+    /// - `registry not allowed`             - `registry` is not allowlisted
+    /// - `nonce already used`               - `nonce` is not above the oracle's last
+    /// - `observation timestamp must be strictly increasing` - not after this
+    ///   oracle's previous observation
+    /// - `already settled`                  - the call was already finalized
+    /// - `observation outside window`       - outside a TWAP call's window
     pub fn submit_price_observation(
         env: Env,
+        registry: Address,
         call_id: u64,
         observation: PriceObservation,
+        nonce: u64,
         oracle_pubkey: BytesN<32>,
         signature: BytesN<64>,
     ) {
-        // 1. Validate oracle
+        require_submissions_open(&env);
+
+        // 1. Validate oracle, and the call's committee if it has one
         let oracles = get_oracles(&env);
         if !oracles.contains_key(oracle_pubkey.clone()) {
             soroban_sdk::panic_with_error!(&env, OutcomeError::UnauthorizedOracle);
         }
+        if let Some(config) = storage::get_call_oracle_config(&env, &registry, call_id) {
+            if !config.oracles.is_empty() && !config.oracles.contains(&oracle_pubkey) {
+                soroban_sdk::panic_with_error!(&env, OutcomeError::UnauthorizedOracle);
+            }
+        }
 
-        // 2. Build canonical message and verify ed25519 signature
-        //    Format: b"twap_obs:" | call_id (8 BE) | price (16 BE) | timestamp (8 BE)
-        let mut raw = Bytes::from_slice(&env, b"twap_obs:");
-        raw.append(&Bytes::from_slice(&env, &call_id.to_be_bytes()));
-        raw.append(&Bytes::from_slice(&env, &observation.price.to_be_bytes()));
-        raw.append(&Bytes::from_slice(
+        // 2. Validate the registry and the oracle's nonce
+        require_registry(&env, &registry);
+        let nonce_key = PersistentKey::OracleNonce(oracle_pubkey.clone());
+        if let Some(last_nonce) = env.storage().persistent().get::<_, u64>(&nonce_key) {
+            if nonce <= last_nonce {
+                soroban_sdk::panic_with_error!(&env, OutcomeError::NonceAlreadyUsed);
+            }
+        }
+
+        // 3. Build canonical message and verify ed25519 signature
+        let message = build_observation_message(
             &env,
-            &observation.timestamp.to_be_bytes(),
-        ));
-        verify_signature(&env, &oracle_pubkey, &signature, &raw);
+            &env.current_contract_address(),
+            &registry,
+            call_id,
+            observation.price,
+            observation.timestamp,
+            nonce,
+        );
+        verify_signature(&env, &oracle_pubkey, &signature, &message);
 
-        // 3. Load existing observations or start fresh
-        let key = TempKey::PriceObservations(call_id);
-        let mut observations: Vec<PriceObservation> = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .unwrap_or_else(|| Vec::new(&env));
+        // 4. A TWAP call only takes observations from its window, until settled
+        if storage::has_final_outcome(&env, &registry, call_id) {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::AlreadySettled);
+        }
+        if let Some(window) = storage::get_twap_window(&env, &registry, call_id) {
+            let end_ts = registry_call_end_ts(&env, &registry, call_id);
+            let window_end = end_ts.checked_add(window).unwrap_or_else(|| overflow(&env));
+            if observation.timestamp < end_ts || observation.timestamp > window_end {
+                soroban_sdk::panic_with_error!(&env, OutcomeExtError::ObservationOutsideWindow);
+            }
+        }
 
-        // 4. Enforce monotonically increasing timestamps
+        // 5. Enforce increasing timestamps within this oracle's series
+        let mut observations =
+            storage::get_price_observations(&env, &registry, call_id, &oracle_pubkey);
         if let Some(last) = observations.last() {
             if observation.timestamp <= last.timestamp {
                 soroban_sdk::panic_with_error!(&env, OutcomeError::ObservationOutOfOrder);
             }
+        } else {
+            let mut twap_oracles = storage::get_twap_oracles(&env, &registry, call_id);
+            twap_oracles.push_back(oracle_pubkey.clone());
+            storage::set_twap_oracles(&env, &registry, call_id, &twap_oracles);
         }
 
+        env.storage().persistent().set(&nonce_key, &nonce);
         let price = observation.price;
         let timestamp = observation.timestamp;
         observations.push_back(observation);
-        env.storage().temporary().set(&key, &observations);
+        storage::set_price_observations(&env, &registry, call_id, &oracle_pubkey, &observations);
 
         emit_price_observation_submitted(&env, call_id, &oracle_pubkey, price, timestamp);
     }
 
     /// Compute the time-weighted average price (TWAP) from stored observations.
     ///
-    /// Uses the formula: TWAP = sum(price[i] * dt[i]) / total_dt
-    /// where dt[i] = timestamp[i+1] - timestamp[i].
+    /// Each oracle's series is averaged on its own with
+    /// TWAP = sum(price[i] * dt[i]) / total_dt, where
+    /// dt[i] = timestamp[i+1] - timestamp[i]. The result is the median of the
+    /// oracles' TWAPs, so no single oracle sets it.
     ///
     /// # Panics
     /// - `no price observations for call`        - none submitted yet
-    /// - `minimum 3 price observations required` - fewer than 3 stored
-    /// - `zero time window`                      - all timestamps identical
-    pub fn compute_twap(env: Env, call_id: u64) -> i128 {
-        let key = TempKey::PriceObservations(call_id);
-        let observations: Vec<PriceObservation> = match env.storage().temporary().get(&key) {
-            Some(observations) => observations,
-            None => soroban_sdk::panic_with_error!(&env, OutcomeError::NoPriceObservations),
-        };
-
-        let n = observations.len();
-        if n < 3 {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::InsufficientPriceObservations);
+    /// - `minimum 3 price observations required` - fewer oracles than the
+    ///   call's quorum submitted at least 3 observations each
+    pub fn compute_twap(env: Env, registry: Address, call_id: u64) -> i128 {
+        let oracles = storage::get_twap_oracles(&env, &registry, call_id);
+        if oracles.is_empty() {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::NoPriceObservations);
         }
 
-        let mut weighted_sum: i128 = 0;
-        let mut total_time: i128 = 0;
+        let mut twaps: Vec<i128> = Vec::new(&env);
+        for oracle in oracles.iter() {
+            let observations = storage::get_price_observations(&env, &registry, call_id, &oracle);
+            if let Some(twap) = series_twap(&env, &observations) {
+                twaps.push_back(twap);
+            }
+        }
 
-        for i in 0..(n - 1) {
-            let obs_i = observations.get(i).unwrap();
-            let obs_next = observations.get(i + 1).unwrap();
-            let dt = (obs_next.timestamp - obs_i.timestamp) as i128;
-            weighted_sum = obs_i
-                .price
-                .checked_mul(dt)
-                .unwrap_or_else(|| overflow(&env))
-                .checked_add(weighted_sum)
-                .unwrap_or_else(|| overflow(&env));
-            total_time = total_time.checked_add(dt).unwrap_or_else(|| overflow(&env));
+        let quorum = match storage::get_call_oracle_config(&env, &registry, call_id) {
+            Some(config) => config.quorum,
+            None => get_quorum(&env),
+        };
+        if twaps.is_empty() || twaps.len() < quorum {
+            soroban_sdk::panic_with_error!(&env, OutcomeError::InsufficientPriceObservations);
         }
+        median_price(twaps)
+    }
+}
 
-        if total_time == 0 {
-            soroban_sdk::panic_with_error!(&env, OutcomeError::ZeroTimeWindow);
+#[contractimpl]
+impl CustomAccountInterface for OutcomeManager {
+    /// The admin signers approving the call
+    type Signature = Vec<Address>;
+    type Error = soroban_sdk::Error;
+
+    /// Authorize the contract's own address, as admin, for calls into this
+    /// contract only, once `threshold` distinct registered signers have each
+    /// authorized `signature_payload`.
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Vec<Address>,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let admin_signers = match storage::get_admin_signers(&env) {
+            Some(admin_signers) => admin_signers,
+            None => return Err(OutcomeExtError::AdminSignersNotSet.into()),
+        };
+        let this = env.current_contract_address();
+        for context in auth_contexts.iter() {
+            match context {
+                Context::Contract(call) if call.contract == this => {}
+                _ => return Err(OutcomeError::Unauthorized.into()),
+            }
         }
 
-        weighted_sum
-            .checked_div(total_time)
-            .unwrap_or_else(|| overflow(&env))
+        let mut approvals: u32 = 0;
+        for (index, signer) in signatures.iter().enumerate() {
+            if !admin_signers.signers.contains(&signer) {
+                return Err(OutcomeError::Unauthorized.into());
+            }
+            if signatures.first_index_of(&signer) != Some(index as u32) {
+                continue;
+            }
+            signer.require_auth_for_args((signature_payload.to_bytes(),).into_val(&env));
+            approvals += 1;
+        }
+        if approvals < admin_signers.threshold {
+            return Err(OutcomeExtError::InsufficientAdminSignatures.into());
+        }
+        Ok(())
     }
 }
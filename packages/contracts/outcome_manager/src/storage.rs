@@ -1,11 +1,13 @@
-use soroban_sdk::{contracttype, Address, BytesN, Env};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, Symbol, Vec};
 
 /// Represents a finalized outcome after quorum is reached
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Outcome {
+    /// Registry the call belongs to; call ids are only unique per registry
+    pub registry: Address,
     pub call_id: u64,
-    /// 1 = UP, 2 = DOWN
+    /// 1 = UP, 2 = DOWN, 3 = DRAW, `OUTCOME_VOID` = VOID
     pub outcome: u32,
     /// Final price in the oracle's fixed-point representation
     pub price: i128,
@@ -17,11 +19,17 @@ pub struct Outcome {
 #[contracttype]
 #[derive(Clone)]
 pub struct SignedOutcome {
+    /// Allowlisted registry the call belongs to
+    pub registry: Address,
     pub call_id: u64,
     /// 1 = UP, 2 = DOWN
     pub outcome: u32,
     pub price: i128,
     pub timestamp: u64,
+    /// Per-oracle nonce; must exceed the oracle's last accepted nonce
+    pub nonce: u64,
+    /// Last ledger sequence at which the report may be submitted
+    pub valid_until: u32,
     /// Oracle's raw ed25519 public key (32 bytes)
     pub oracle_pubkey: BytesN<32>,
     /// ed25519 signature of the canonical message
@@ -37,6 +45,223 @@ pub struct OracleVote {
     pub timestamp: u64,
 }
 
+/// Band within which oracle votes on the same outcome count towards one
+/// quorum candidate
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteTolerance {
+    /// Maximum price difference, in bps of the newest vote's price
+    pub price_bps: u32,
+    /// Maximum distance between observation timestamps, in seconds
+    pub window_secs: u64,
+}
+
+/// A counter-report posted against a pending outcome, backed by a bond
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    pub challenger: Address,
+    pub outcome: u32,
+    pub price: i128,
+    /// Token the bond was posted in
+    pub token: Address,
+    /// Bond locked in this contract until the challenge is resolved
+    pub bond: i128,
+}
+
+/// An outcome that reached quorum and is waiting out its challenge window
+/// before the registry is resolved
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingFinal {
+    pub outcome: Outcome,
+    /// Last ledger sequence at which the outcome can be challenged
+    pub challenge_deadline: u32,
+}
+
+/// Where and when a call's outcome was finalized
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Finalization {
+    /// Registry the outcome was resolved in
+    pub registry: Address,
+    /// Ledger sequence at which the outcome was finalized
+    pub ledger: u32,
+}
+
+/// Stricter resolution settings for a single call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallOracleConfig {
+    /// Matching votes required to finalize this call
+    pub quorum: u32,
+    /// Committee allowed to vote on this call; empty means every trusted oracle
+    pub oracles: Vec<BytesN<32>>,
+}
+
+/// Resolution progress of a call, as returned by `get_vote_status`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteStatus {
+    /// Votes per outcome candidate
+    pub votes: Map<u32, u32>,
+    /// Oracles that have voted, in submission order
+    pub voters: Vec<BytesN<32>>,
+    /// Matching votes the call needs to reach quorum
+    pub quorum: u32,
+    /// Further votes the leading candidate needs; 0 once quorum is reached
+    pub votes_needed: u32,
+}
+
+/// A trusted oracle and what is recorded about it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleInfo {
+    pub pubkey: BytesN<32>,
+    /// Ledger timestamp at which the oracle joined the trusted set
+    pub added_at: u64,
+    /// Operator-facing name, set by the admin
+    pub label: Option<Bytes>,
+}
+
+/// One page of the trusted oracle set; `next_cursor` is the cursor of the
+/// next page, `None` once the set is exhausted
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OraclePage {
+    pub oracles: Vec<OracleInfo>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A report accepted from an oracle, as kept in its submission history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleSubmission {
+    pub registry: Address,
+    pub call_id: u64,
+    pub outcome: u32,
+    pub price: i128,
+    pub timestamp: u64,
+    pub nonce: u64,
+    /// Ledger sequence the report was accepted in
+    pub ledger: u32,
+}
+
+/// One page of an oracle's submission history, newest first; `next_cursor`
+/// is the cursor of the next page, `None` once the history is exhausted
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionPage {
+    pub submissions: Vec<OracleSubmission>,
+    pub next_cursor: Option<u32>,
+}
+
+/// One page of finalized outcomes, in finalization order; `next_cursor` is
+/// the cursor of the next page, `None` once the index is exhausted
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutcomePage {
+    pub outcomes: Vec<Outcome>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A WASM upgrade queued by the admin, executable from `eta`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    /// Earliest ledger timestamp at which the upgrade may run
+    pub eta: u64,
+}
+
+/// A change that weakens the oracle set, and so must be queued behind the
+/// parameter timelock while one is set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParamChange {
+    /// Lower the global quorum
+    Quorum(u32),
+    RemoveOracle(BytesN<32>),
+    /// Shorten the parameter timelock itself
+    ParamTimelock(u64),
+    /// Stage a replacement oracle set, as `stage_oracle_epoch`
+    OracleEpoch(StagedEpoch),
+}
+
+/// A parameter change queued by the admin, executable from `eta`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingParamChange {
+    pub change: ParamChange,
+    /// Earliest ledger timestamp at which the change may run
+    pub eta: u64,
+}
+
+/// M-of-N signer set standing in for a single admin address
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminSigners {
+    pub signers: Vec<Address>,
+    /// Distinct signers that must authorize each admin call
+    pub threshold: u32,
+}
+
+/// Which pause switches are on, as returned by `get_pause_state`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseState {
+    /// Emergency pause: submissions and claims
+    pub paused: bool,
+    /// Reports, observations and finalization
+    pub submissions_paused: bool,
+    /// Staker payouts and oracle reward claims
+    pub claims_paused: bool,
+}
+
+/// Mirror of the SEP-40 `Asset`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// SEP-40 price feed a call is resolved from instead of signed reports
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceFeed {
+    /// SEP-40 oracle contract, e.g. Reflector
+    pub oracle: Address,
+    pub asset: Asset,
+}
+
+/// Oracle set and quorum that take over at `activation_ledger`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StagedEpoch {
+    pub oracles: Vec<BytesN<32>>,
+    pub quorum: u32,
+    /// First ledger sequence at which the staged set is in force
+    pub activation_ledger: u32,
+}
+
+/// Paid from the rewards budget to each oracle whose vote matches a call's
+/// finalized outcome
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleReward {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Token and amount a challenger must lock to dispute a pending outcome
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeBond {
+    pub token: Address,
+    pub amount: i128,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum InstanceKey {
@@ -44,24 +269,96 @@ pub enum InstanceKey {
     Oracles,
     OracleList,
     Quorum,
-    FinalOutcome(u64),
-    Claimed(u64, Address),
     FeeCollector,
     FeeBps,
-    /// Stored CallRegistry address; set via set_registry() to avoid caller-supplied forgery
-    Registry,
+    /// Allowlisted CallRegistry addresses; submissions must name one of them
+    Registries,
+    /// Challenge window after quorum, in ledgers
     DisputeWindow,
-    PendingOutcome(u64),     // stores Outcome after quorum, before finalization
-    DisputeWindowStart(u64), // ledger timestamp when quorum was reached
-    Paused,                  // Emergency pause flag for rogue oracle detection
+    ChallengeBond,
+    /// Contract allowed to reverse finalized outcomes
+    Governance,
+    /// Ledgers after finalization during which governance may reverse
+    ReversalWindow,
+    Paused, // Emergency pause flag for rogue oracle detection
     Version,
     MaxSubmissionDelay,
+    /// Seconds after a call's end_ts before it may be voided for lack of quorum
+    ResolutionWindow,
+    VoteTolerance,
+    /// Oracle set staged to replace the current one
+    StagedEpoch,
+    /// Number of staged epochs activated so far
+    OracleEpoch,
+    /// Max multiple a reported price may deviate from the call's start price
+    MaxPriceDeviation,
+    OracleReward,
+    /// Reward tokens held for oracles and not yet owed to any of them
+    OracleRewardBudget,
+    /// Bumped by every admin configuration change
+    ConfigVersion,
+    /// Address allowed to pause submissions and claims besides the admin
+    Pauser,
+    SubmissionsPaused,
+    ClaimsPaused,
+    /// Layout version of the data this contract has stored
+    StorageVersion,
+    /// WASM upgrade waiting out its timelock
+    PendingUpgrade,
+    /// Number of calls in the finalized-call index
+    FinalizedCount,
+    /// Seconds a queued parameter change must wait before it can run
+    ParamTimelock,
+    /// Queued parameter changes, by id
+    PendingParamChanges,
+    NextParamChangeId,
+    /// Signer set that authorizes admin calls once the contract is its own admin
+    AdminSigners,
+    /// Ledgers after finalization during which winnings may be claimed
+    ClaimWindow,
+    /// FeeDistributor protocol fees are deposited into, crediting the call's creator
+    FeeDistributor,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum PersistentKey {
-    Votes(u64),
+    Votes(Address, u64),
+    /// Highest nonce accepted from an oracle (by pubkey)
+    OracleNonce(BytesN<32>),
+    FinalOutcome(Address, u64),
+    Claimed(Address, u64, Address),
+    /// Outcome an oracle voted for on a call that is not yet finalized
+    Submission(BytesN<32>, Address, u64),
+    /// Outcome that reached quorum, inside its challenge window
+    PendingFinal(Address, u64),
+    /// Open challenge against a call's pending outcome
+    Challenge(Address, u64),
+    Finalization(Address, u64),
+    /// Set once the first payout on a call has been claimed
+    PayoutStarted(Address, u64),
+    /// Per-call quorum / oracle committee override
+    CallOracleConfig(Address, u64),
+    /// SEP-40 feed a call resolves from
+    PriceFeed(Address, u64),
+    /// Timestamped prices one oracle submitted for a call's TWAP
+    PriceObservations(Address, u64, BytesN<32>),
+    /// Oracles that submitted prices for a call's TWAP, in first-seen order
+    TwapOracles(Address, u64),
+    /// Observation window, in seconds after end_ts, of a call resolved by TWAP
+    TwapWindow(Address, u64),
+    /// When a trusted oracle was added, and its label
+    OracleInfo(BytesN<32>),
+    /// Rewards an oracle has earned and not yet claimed
+    OracleRewards(BytesN<32>),
+    /// Address an oracle's rewards are paid to
+    OraclePayout(BytesN<32>),
+    /// An oracle's most recent accepted reports, newest first
+    OracleSubmissions(BytesN<32>),
+    /// Call finalized at a position of the finalized-call index
+    FinalizedCall(u32),
+    /// Last ledger at which a call's winnings may be claimed
+    ClaimDeadline(Address, u64),
 }
 
 /// A single price data point submitted by an oracle for TWAP calculation
@@ -71,40 +368,618 @@ pub struct PriceObservation {
     pub price: i128,
     pub timestamp: u64,
 }
-#[contracttype]
-#[derive(Clone)]
-pub enum TempKey {
-    Submission(BytesN<32>, u64),
-    VoteCount(BytesN<32>, u64),
-    PriceObservations(u64),
+
+/// Lifetime persistent per-call entries are refreshed to (~30 days of ledgers).
+pub const PERSISTENT_TTL_LEDGERS: u32 = 518_400;
+
+/// Seven days, in seconds
+pub const DEFAULT_RESOLUTION_WINDOW: u64 = 604_800;
+
+/// Refresh a persistent entry to [`PERSISTENT_TTL_LEDGERS`] once less than
+/// half of it remains.
+fn bump_persistent(env: &Env, key: &PersistentKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_LEDGERS / 2, PERSISTENT_TTL_LEDGERS);
+}
+
+/// Retrieve the finalized outcome of a call, refreshing its TTL on access.
+pub fn get_final_outcome(env: &Env, registry: &Address, call_id: u64) -> Option<Outcome> {
+    let key = PersistentKey::FinalOutcome(registry.clone(), call_id);
+    let outcome: Option<Outcome> = env.storage().persistent().get(&key);
+    if outcome.is_some() {
+        bump_persistent(env, &key);
+    }
+    outcome
+}
+
+pub fn has_final_outcome(env: &Env, registry: &Address, call_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&PersistentKey::FinalOutcome(registry.clone(), call_id))
+}
+
+pub fn set_final_outcome(env: &Env, outcome: &Outcome) {
+    let key = PersistentKey::FinalOutcome(outcome.registry.clone(), outcome.call_id);
+    env.storage().persistent().set(&key, outcome);
+    bump_persistent(env, &key);
 }
 
-/// Store the CallRegistry address in instance storage.
-pub fn set_registry(env: &Env, registry: Address) {
+pub fn get_finalized_count(env: &Env) -> u32 {
     env.storage()
         .instance()
-        .set(&InstanceKey::Registry, &registry);
+        .get(&InstanceKey::FinalizedCount)
+        .unwrap_or(0)
 }
 
-/// Read the stored CallRegistry address; panics if not set.
-pub fn get_registry(env: &Env) -> Address {
+/// Append `call_id` on `registry` to the finalized-call index.
+pub fn index_finalized_call(env: &Env, registry: &Address, call_id: u64) {
+    let index = get_finalized_count(env);
+    let key = PersistentKey::FinalizedCall(index);
+    env.storage()
+        .persistent()
+        .set(&key, &(registry.clone(), call_id));
+    bump_persistent(env, &key);
     env.storage()
         .instance()
-        .get(&InstanceKey::Registry)
-        .expect("registry not set")
+        .set(&InstanceKey::FinalizedCount, &(index + 1));
+}
+
+pub fn get_finalized_call(env: &Env, index: u32) -> Option<(Address, u64)> {
+    let key = PersistentKey::FinalizedCall(index);
+    let call: Option<(Address, u64)> = env.storage().persistent().get(&key);
+    if call.is_some() {
+        bump_persistent(env, &key);
+    }
+    call
+}
+
+/// `true` if `staker` has claimed on `call_id`, refreshing the flag's TTL.
+pub fn is_claimed(env: &Env, registry: &Address, call_id: u64, staker: &Address) -> bool {
+    let key = PersistentKey::Claimed(registry.clone(), call_id, staker.clone());
+    let claimed = env.storage().persistent().has(&key);
+    if claimed {
+        bump_persistent(env, &key);
+    }
+    claimed
+}
+
+pub fn set_claimed(env: &Env, registry: &Address, call_id: u64, staker: &Address) {
+    let key = PersistentKey::Claimed(registry.clone(), call_id, staker.clone());
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+    let key = PersistentKey::PayoutStarted(registry.clone(), call_id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// `true` once any staker has claimed a payout on `call_id`.
+pub fn payout_started(env: &Env, registry: &Address, call_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&PersistentKey::PayoutStarted(registry.clone(), call_id))
 }
 
-pub fn set_dispute_window(env: &Env, secs: u64) {
+pub fn get_registries(env: &Env) -> Vec<Address> {
     env.storage()
         .instance()
-        .set(&InstanceKey::DisputeWindow, &secs);
+        .get(&InstanceKey::Registries)
+        .unwrap_or_else(|| Vec::new(env))
 }
 
-pub fn get_dispute_window(env: &Env) -> u64 {
+pub fn set_registries(env: &Env, registries: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::Registries, registries);
+}
+
+pub fn get_oracle_info(env: &Env, oracle: &BytesN<32>) -> Option<OracleInfo> {
+    let key = PersistentKey::OracleInfo(oracle.clone());
+    let info: Option<OracleInfo> = env.storage().persistent().get(&key);
+    if info.is_some() {
+        bump_persistent(env, &key);
+    }
+    info
+}
+
+pub fn set_oracle_info(env: &Env, info: &OracleInfo) {
+    let key = PersistentKey::OracleInfo(info.pubkey.clone());
+    env.storage().persistent().set(&key, info);
+    bump_persistent(env, &key);
+}
+
+pub fn remove_oracle_info(env: &Env, oracle: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&PersistentKey::OracleInfo(oracle.clone()));
+}
+
+pub fn get_oracle_submissions(env: &Env, oracle: &BytesN<32>) -> Vec<OracleSubmission> {
+    let key = PersistentKey::OracleSubmissions(oracle.clone());
+    match env.storage().persistent().get(&key) {
+        Some(history) => {
+            bump_persistent(env, &key);
+            history
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Prepend `submission` to the oracle's history, keeping the newest `cap`.
+pub fn push_oracle_submission(
+    env: &Env,
+    oracle: &BytesN<32>,
+    submission: &OracleSubmission,
+    cap: u32,
+) {
+    let key = PersistentKey::OracleSubmissions(oracle.clone());
+    let mut history = get_oracle_submissions(env, oracle);
+    history.push_front(submission.clone());
+    while history.len() > cap {
+        history.pop_back();
+    }
+    env.storage().persistent().set(&key, &history);
+    bump_persistent(env, &key);
+}
+
+pub fn get_finalization(env: &Env, registry: &Address, call_id: u64) -> Option<Finalization> {
+    env.storage()
+        .persistent()
+        .get(&PersistentKey::Finalization(registry.clone(), call_id))
+}
+
+pub fn set_finalization(env: &Env, registry: &Address, call_id: u64, finalization: &Finalization) {
+    let key = PersistentKey::Finalization(registry.clone(), call_id);
+    env.storage().persistent().set(&key, finalization);
+    bump_persistent(env, &key);
+}
+
+pub fn set_governance(env: &Env, governance: &Address, reversal_window: u32) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::Governance, governance);
+    env.storage()
+        .instance()
+        .set(&InstanceKey::ReversalWindow, &reversal_window);
+}
+
+pub fn get_governance(env: &Env) -> Option<(Address, u32)> {
+    let governance: Address = env.storage().instance().get(&InstanceKey::Governance)?;
+    let window: u32 = env
+        .storage()
+        .instance()
+        .get(&InstanceKey::ReversalWindow)
+        .unwrap_or(0);
+    Some((governance, window))
+}
+
+/// Drop the per-call submission markers once a call is finalized.
+/// `Votes(registry, call_id)` is kept as the call's audit trail.
+pub fn clear_vote_tracking(env: &Env, registry: &Address, call_id: u64) {
+    let votes: Vec<OracleVote> = env
+        .storage()
+        .persistent()
+        .get(&PersistentKey::Votes(registry.clone(), call_id))
+        .unwrap_or_else(|| Vec::new(env));
+    for vote in votes.iter() {
+        env.storage()
+            .persistent()
+            .remove(&PersistentKey::Submission(
+                vote.oracle,
+                registry.clone(),
+                call_id,
+            ));
+    }
+}
+
+pub fn get_call_oracle_config(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+) -> Option<CallOracleConfig> {
+    let key = PersistentKey::CallOracleConfig(registry.clone(), call_id);
+    let config: Option<CallOracleConfig> = env.storage().persistent().get(&key);
+    if config.is_some() {
+        bump_persistent(env, &key);
+    }
+    config
+}
+
+pub fn set_call_oracle_config(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    config: &Option<CallOracleConfig>,
+) {
+    let key = PersistentKey::CallOracleConfig(registry.clone(), call_id);
+    match config {
+        Some(config) => {
+            env.storage().persistent().set(&key, config);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_price_feed(env: &Env, registry: &Address, call_id: u64) -> Option<PriceFeed> {
+    let key = PersistentKey::PriceFeed(registry.clone(), call_id);
+    let feed: Option<PriceFeed> = env.storage().persistent().get(&key);
+    if feed.is_some() {
+        bump_persistent(env, &key);
+    }
+    feed
+}
+
+pub fn set_price_feed(env: &Env, registry: &Address, call_id: u64, feed: &Option<PriceFeed>) {
+    let key = PersistentKey::PriceFeed(registry.clone(), call_id);
+    match feed {
+        Some(feed) => {
+            env.storage().persistent().set(&key, feed);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_price_observations(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    oracle: &BytesN<32>,
+) -> Vec<PriceObservation> {
+    let key = PersistentKey::PriceObservations(registry.clone(), call_id, oracle.clone());
+    let observations: Option<Vec<PriceObservation>> = env.storage().persistent().get(&key);
+    if observations.is_some() {
+        bump_persistent(env, &key);
+    }
+    observations.unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_price_observations(
+    env: &Env,
+    registry: &Address,
+    call_id: u64,
+    oracle: &BytesN<32>,
+    observations: &Vec<PriceObservation>,
+) {
+    let key = PersistentKey::PriceObservations(registry.clone(), call_id, oracle.clone());
+    env.storage().persistent().set(&key, observations);
+    bump_persistent(env, &key);
+}
+
+pub fn get_twap_oracles(env: &Env, registry: &Address, call_id: u64) -> Vec<BytesN<32>> {
+    let key = PersistentKey::TwapOracles(registry.clone(), call_id);
+    let oracles: Option<Vec<BytesN<32>>> = env.storage().persistent().get(&key);
+    if oracles.is_some() {
+        bump_persistent(env, &key);
+    }
+    oracles.unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_twap_oracles(env: &Env, registry: &Address, call_id: u64, oracles: &Vec<BytesN<32>>) {
+    let key = PersistentKey::TwapOracles(registry.clone(), call_id);
+    env.storage().persistent().set(&key, oracles);
+    bump_persistent(env, &key);
+}
+
+pub fn get_twap_window(env: &Env, registry: &Address, call_id: u64) -> Option<u64> {
+    let key = PersistentKey::TwapWindow(registry.clone(), call_id);
+    let window: Option<u64> = env.storage().persistent().get(&key);
+    if window.is_some() {
+        bump_persistent(env, &key);
+    }
+    window
+}
+
+pub fn set_twap_window(env: &Env, registry: &Address, call_id: u64, window_secs: &Option<u64>) {
+    let key = PersistentKey::TwapWindow(registry.clone(), call_id);
+    match window_secs {
+        Some(window_secs) => {
+            env.storage().persistent().set(&key, window_secs);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_pending_final(env: &Env, registry: &Address, call_id: u64) -> Option<PendingFinal> {
+    let key = PersistentKey::PendingFinal(registry.clone(), call_id);
+    let pending: Option<PendingFinal> = env.storage().persistent().get(&key);
+    if pending.is_some() {
+        bump_persistent(env, &key);
+    }
+    pending
+}
+
+pub fn set_pending_final(env: &Env, pending: &PendingFinal) {
+    let key =
+        PersistentKey::PendingFinal(pending.outcome.registry.clone(), pending.outcome.call_id);
+    env.storage().persistent().set(&key, pending);
+    bump_persistent(env, &key);
+}
+
+pub fn remove_pending_final(env: &Env, registry: &Address, call_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&PersistentKey::PendingFinal(registry.clone(), call_id));
+}
+
+pub fn get_challenge(env: &Env, registry: &Address, call_id: u64) -> Option<Challenge> {
+    env.storage()
+        .persistent()
+        .get(&PersistentKey::Challenge(registry.clone(), call_id))
+}
+
+pub fn set_challenge(env: &Env, registry: &Address, call_id: u64, challenge: &Challenge) {
+    let key = PersistentKey::Challenge(registry.clone(), call_id);
+    env.storage().persistent().set(&key, challenge);
+    bump_persistent(env, &key);
+}
+
+pub fn remove_challenge(env: &Env, registry: &Address, call_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&PersistentKey::Challenge(registry.clone(), call_id));
+}
+
+pub fn set_dispute_window(env: &Env, ledgers: u32) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::DisputeWindow, &ledgers);
+}
+
+pub fn get_dispute_window(env: &Env) -> u32 {
     env.storage()
         .instance()
         .get(&InstanceKey::DisputeWindow)
-        .unwrap_or(3600)
+        .unwrap_or(0)
+}
+
+pub fn set_challenge_bond(env: &Env, bond: &ChallengeBond) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::ChallengeBond, bond);
+}
+
+pub fn get_challenge_bond(env: &Env) -> Option<ChallengeBond> {
+    env.storage().instance().get(&InstanceKey::ChallengeBond)
+}
+
+pub fn set_pauser(env: &Env, pauser: &Option<Address>) {
+    match pauser {
+        Some(pauser) => env.storage().instance().set(&InstanceKey::Pauser, pauser),
+        None => env.storage().instance().remove(&InstanceKey::Pauser),
+    }
+}
+
+pub fn get_pauser(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&InstanceKey::Pauser)
+}
+
+pub fn set_submissions_paused(env: &Env, paused: bool) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::SubmissionsPaused, &paused);
+}
+
+pub fn get_submissions_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::SubmissionsPaused)
+        .unwrap_or(false)
+}
+
+pub fn set_claims_paused(env: &Env, paused: bool) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::ClaimsPaused, &paused);
+}
+
+pub fn get_claims_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::ClaimsPaused)
+        .unwrap_or(false)
+}
+
+pub fn get_storage_version(env: &Env) -> u32 {
+    // Deployments from before the version was stored hold layout 1
+    env.storage()
+        .instance()
+        .get(&InstanceKey::StorageVersion)
+        .unwrap_or(1)
+}
+
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::StorageVersion, &version);
+}
+
+pub fn get_param_timelock(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::ParamTimelock)
+        .unwrap_or(0)
+}
+
+pub fn set_param_timelock(env: &Env, secs: u64) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::ParamTimelock, &secs);
+}
+
+pub fn get_pending_param_changes(env: &Env) -> Map<u32, PendingParamChange> {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::PendingParamChanges)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_pending_param_changes(env: &Env, changes: &Map<u32, PendingParamChange>) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::PendingParamChanges, changes);
+}
+
+/// Allocate the id of the next queued parameter change.
+pub fn next_param_change_id(env: &Env) -> u32 {
+    let id: u32 = env
+        .storage()
+        .instance()
+        .get(&InstanceKey::NextParamChangeId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&InstanceKey::NextParamChangeId, &(id + 1));
+    id
+}
+
+pub fn get_admin_signers(env: &Env) -> Option<AdminSigners> {
+    env.storage().instance().get(&InstanceKey::AdminSigners)
+}
+
+pub fn set_admin_signers(env: &Env, signers: &Option<AdminSigners>) {
+    match signers {
+        Some(signers) => env
+            .storage()
+            .instance()
+            .set(&InstanceKey::AdminSigners, signers),
+        None => env.storage().instance().remove(&InstanceKey::AdminSigners),
+    }
+}
+
+pub fn get_pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+    env.storage().instance().get(&InstanceKey::PendingUpgrade)
+}
+
+pub fn set_pending_upgrade(env: &Env, upgrade: &Option<PendingUpgrade>) {
+    match upgrade {
+        Some(upgrade) => env
+            .storage()
+            .instance()
+            .set(&InstanceKey::PendingUpgrade, upgrade),
+        None => env
+            .storage()
+            .instance()
+            .remove(&InstanceKey::PendingUpgrade),
+    }
+}
+
+pub fn get_config_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::ConfigVersion)
+        .unwrap_or(0)
+}
+
+pub fn set_config_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::ConfigVersion, &version);
+}
+
+pub fn set_oracle_reward(env: &Env, reward: &OracleReward) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::OracleReward, reward);
+}
+
+pub fn get_oracle_reward(env: &Env) -> Option<OracleReward> {
+    env.storage().instance().get(&InstanceKey::OracleReward)
+}
+
+pub fn set_oracle_reward_budget(env: &Env, budget: i128) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::OracleRewardBudget, &budget);
+}
+
+pub fn get_oracle_reward_budget(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::OracleRewardBudget)
+        .unwrap_or(0)
+}
+
+pub fn get_oracle_rewards(env: &Env, oracle: &BytesN<32>) -> i128 {
+    let key = PersistentKey::OracleRewards(oracle.clone());
+    let rewards: Option<i128> = env.storage().persistent().get(&key);
+    if rewards.is_some() {
+        bump_persistent(env, &key);
+    }
+    rewards.unwrap_or(0)
+}
+
+pub fn set_oracle_rewards(env: &Env, oracle: &BytesN<32>, rewards: i128) {
+    let key = PersistentKey::OracleRewards(oracle.clone());
+    if rewards == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &rewards);
+        bump_persistent(env, &key);
+    }
+}
+
+pub fn get_oracle_payout(env: &Env, oracle: &BytesN<32>) -> Option<Address> {
+    let key = PersistentKey::OraclePayout(oracle.clone());
+    let payout: Option<Address> = env.storage().persistent().get(&key);
+    if payout.is_some() {
+        bump_persistent(env, &key);
+    }
+    payout
+}
+
+pub fn set_oracle_payout(env: &Env, oracle: &BytesN<32>, payout: &Option<Address>) {
+    let key = PersistentKey::OraclePayout(oracle.clone());
+    match payout {
+        Some(payout) => {
+            env.storage().persistent().set(&key, payout);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn set_vote_tolerance(env: &Env, tolerance: &Option<VoteTolerance>) {
+    match tolerance {
+        Some(tolerance) => env
+            .storage()
+            .instance()
+            .set(&InstanceKey::VoteTolerance, tolerance),
+        None => env.storage().instance().remove(&InstanceKey::VoteTolerance),
+    }
+}
+
+pub fn get_vote_tolerance(env: &Env) -> Option<VoteTolerance> {
+    env.storage().instance().get(&InstanceKey::VoteTolerance)
+}
+
+pub fn get_staged_epoch(env: &Env) -> Option<StagedEpoch> {
+    env.storage().instance().get(&InstanceKey::StagedEpoch)
+}
+
+pub fn set_staged_epoch(env: &Env, epoch: &Option<StagedEpoch>) {
+    match epoch {
+        Some(epoch) => env
+            .storage()
+            .instance()
+            .set(&InstanceKey::StagedEpoch, epoch),
+        None => env.storage().instance().remove(&InstanceKey::StagedEpoch),
+    }
+}
+
+pub fn get_oracle_epoch(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::OracleEpoch)
+        .unwrap_or(0)
+}
+
+pub fn set_oracle_epoch(env: &Env, epoch: u32) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::OracleEpoch, &epoch);
 }
 
 pub fn set_max_submission_delay(env: &Env, delay: u64) {
@@ -119,3 +994,64 @@ pub fn get_max_submission_delay(env: &Env) -> u64 {
         .get(&InstanceKey::MaxSubmissionDelay)
         .unwrap_or(86400)
 }
+
+pub fn set_resolution_window(env: &Env, window: u64) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::ResolutionWindow, &window);
+}
+
+pub fn get_resolution_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::ResolutionWindow)
+        .unwrap_or(DEFAULT_RESOLUTION_WINDOW)
+}
+
+pub fn set_max_price_deviation(env: &Env, max_multiple: &Option<u32>) {
+    match max_multiple {
+        Some(max_multiple) => env
+            .storage()
+            .instance()
+            .set(&InstanceKey::MaxPriceDeviation, max_multiple),
+        None => env
+            .storage()
+            .instance()
+            .remove(&InstanceKey::MaxPriceDeviation),
+    }
+}
+
+pub fn set_claim_window(env: &Env, window: &Option<u32>) {
+    match window {
+        Some(window) => env
+            .storage()
+            .instance()
+            .set(&InstanceKey::ClaimWindow, window),
+        None => env.storage().instance().remove(&InstanceKey::ClaimWindow),
+    }
+}
+
+pub fn get_claim_window(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&InstanceKey::ClaimWindow)
+}
+
+pub fn set_claim_deadline(env: &Env, registry: &Address, call_id: u64, deadline: u32) {
+    let key = PersistentKey::ClaimDeadline(registry.clone(), call_id);
+    env.storage().persistent().set(&key, &deadline);
+    bump_persistent(env, &key);
+}
+
+pub fn get_claim_deadline(env: &Env, registry: &Address, call_id: u64) -> Option<u32> {
+    let key = PersistentKey::ClaimDeadline(registry.clone(), call_id);
+    let deadline: Option<u32> = env.storage().persistent().get(&key);
+    if deadline.is_some() {
+        bump_persistent(env, &key);
+    }
+    deadline
+}
+
+pub fn get_max_price_deviation(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::MaxPriceDeviation)
+}
@@ -1,13 +1,39 @@
 #![cfg(test)]
 
-use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, BytesN, Env, Vec};
-
-use crate::errors::OutcomeError;
-use crate::storage::{OracleVote, PriceObservation, SignedOutcome};
-use crate::{OutcomeManager, OutcomeManagerClient, MAX_ORACLES};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Ledger as _},
+    token, Address, Bytes, BytesN, Env, Map, Vec,
+};
+
+use crate::errors::{OutcomeError, OutcomeExtError};
+use crate::storage::{
+    Asset, CallOracleConfig, InstanceKey, OracleVote, ParamChange, PauseState, PersistentKey,
+    PriceFeed, PriceObservation, SignedOutcome, StagedEpoch, VoteTolerance, PERSISTENT_TTL_LEDGERS,
+};
+use crate::{
+    AddressPage, CallStats, OutcomeManager, OutcomeManagerClient, PriceData, MAX_ORACLES,
+    STORAGE_VERSION, UPGRADE_TIMELOCK_SECS,
+};
 
 // ─── Test Helpers ─────────────────────────────────────────────────────────────
 
+#[contracttype]
+enum MockKey {
+    Stake(u64, Address, u32),
+    Weight(u64, Address, u32),
+    OutcomeStakes(u64),
+    WeightedStakes(u64),
+    PenaltyPool(u64),
+    EndTs(u64),
+    Override(u64),
+    Stakers(u64),
+    Expired(u64),
+    OutcomeCount(u64),
+    StakeToken,
+    Creator,
+}
+
 #[contract]
 pub struct MockRegistry;
 
@@ -15,7 +41,254 @@ pub struct MockRegistry;
 impl MockRegistry {
     pub fn resolve_call(_env: Env, _call_id: u64, _outcome: u32, _end_price: i128) {}
     pub fn release_escrow(_env: Env, _call_id: u64, _to: Address, _amount: i128) {}
+    /// Every call is staked in one token, reported as the mock's own address.
+    pub fn release_escrows(
+        env: Env,
+        _to: Address,
+        releases: soroban_sdk::Map<u64, i128>,
+    ) -> soroban_sdk::Map<Address, i128> {
+        let total: i128 = releases.values().iter().sum();
+        soroban_sdk::map![&env, (env.current_contract_address(), total)]
+    }
     pub fn mark_settled(_env: Env, _call_id: u64) {}
+    pub fn burn_positions(_env: Env, _call_id: u64, _staker: Address) {}
+    /// Pays the fee out of the mock's balance once a stake token is set.
+    pub fn release_fee(
+        env: Env,
+        _call_id: u64,
+        _staker: Address,
+        to: Address,
+        amount: i128,
+    ) -> i128 {
+        let stake_token: Option<Address> = env.storage().instance().get(&MockKey::StakeToken);
+        if let Some(stake_token) = stake_token {
+            token::Client::new(&env, &stake_token).transfer(
+                &env.current_contract_address(),
+                &to,
+                &amount,
+            );
+        }
+        0
+    }
+    pub fn set_call_terms(env: Env, stake_token: Address, creator: Address) {
+        let storage = env.storage().instance();
+        storage.set(&MockKey::StakeToken, &stake_token);
+        storage.set(&MockKey::Creator, &creator);
+    }
+    pub fn get_call_stake_token(env: Env, _call_id: u64) -> Address {
+        env.storage().instance().get(&MockKey::StakeToken).unwrap()
+    }
+    pub fn get_call_creator(env: Env, _call_id: u64) -> Address {
+        env.storage().instance().get(&MockKey::Creator).unwrap()
+    }
+    pub fn record_streak_win(env: Env, _call_id: u64, _staker: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&soroban_sdk::symbol_short!("bonus"))
+            .unwrap_or(0)
+    }
+    pub fn get_protocol_fees(_env: Env, _token: Address) -> i128 {
+        7
+    }
+    pub fn get_streak_bonus_bps(env: Env, _staker: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&soroban_sdk::symbol_short!("bonus"))
+            .unwrap_or(0)
+    }
+    pub fn set_streak_bonus(env: Env, bonus_bps: u32) {
+        env.storage()
+            .instance()
+            .set(&soroban_sdk::symbol_short!("bonus"), &bonus_bps);
+    }
+    /// Record `staker`'s stake and time weight on `position`.
+    pub fn set_stake(
+        env: Env,
+        call_id: u64,
+        staker: Address,
+        position: u32,
+        stake: i128,
+        weight: i128,
+    ) {
+        let storage = env.storage().instance();
+        storage.set(&MockKey::Stake(call_id, staker.clone(), position), &stake);
+        storage.set(&MockKey::Weight(call_id, staker.clone(), position), &weight);
+        let mut stakers: Vec<Address> = storage
+            .get(&MockKey::Stakers(call_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        if !stakers.contains(&staker) {
+            stakers.push_back(staker);
+        }
+        storage.set(&MockKey::Stakers(call_id), &stakers);
+        for (key, amount) in [
+            (MockKey::OutcomeStakes(call_id), stake),
+            (MockKey::WeightedStakes(call_id), weight),
+        ] {
+            let mut totals: Map<u32, i128> = storage.get(&key).unwrap_or_else(|| Map::new(&env));
+            totals.set(position, totals.get(position).unwrap_or(0) + amount);
+            storage.set(&key, &totals);
+        }
+    }
+    pub fn get_call_stakers(env: Env, call_id: u64, cursor: u32, limit: u32) -> AddressPage {
+        let stakers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&MockKey::Stakers(call_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        let start = cursor.min(stakers.len());
+        let end = start.saturating_add(limit).min(stakers.len());
+        AddressPage {
+            addresses: stakers.slice(start..end),
+            next_cursor: if end < stakers.len() { Some(end) } else { None },
+        }
+    }
+
+    pub fn expire_escrow(env: Env, call_id: u64, amount: i128) {
+        let storage = env.storage().instance();
+        let expired: i128 = storage.get(&MockKey::Expired(call_id)).unwrap_or(0);
+        storage.set(&MockKey::Expired(call_id), &(expired + amount));
+    }
+
+    pub fn get_expired(env: Env, call_id: u64) -> i128 {
+        env.storage()
+            .instance()
+            .get(&MockKey::Expired(call_id))
+            .unwrap_or(0)
+    }
+
+    pub fn set_penalty_pool(env: Env, call_id: u64, amount: i128) {
+        env.storage()
+            .instance()
+            .set(&MockKey::PenaltyPool(call_id), &amount);
+    }
+    pub fn get_call_stats(env: Env, call_id: u64) -> CallStats {
+        CallStats {
+            outcome_stakes: env
+                .storage()
+                .instance()
+                .get(&MockKey::OutcomeStakes(call_id))
+                .unwrap_or_else(|| Map::new(&env)),
+            outcome_stake_counts: Map::new(&env),
+            total_stakes: 0,
+            last_stake_ts: 0,
+        }
+    }
+    pub fn get_staker_stake(env: Env, call_id: u64, staker: Address, position: u32) -> i128 {
+        env.storage()
+            .instance()
+            .get(&MockKey::Stake(call_id, staker, position))
+            .unwrap_or(0)
+    }
+    pub fn get_weighted_stake(env: Env, call_id: u64, staker: Address, position: u32) -> i128 {
+        env.storage()
+            .instance()
+            .get(&MockKey::Weight(call_id, staker, position))
+            .unwrap_or(0)
+    }
+    pub fn get_weighted_outcome_stakes(env: Env, call_id: u64) -> Map<u32, i128> {
+        env.storage()
+            .instance()
+            .get(&MockKey::WeightedStakes(call_id))
+            .unwrap_or_else(|| Map::new(&env))
+    }
+    pub fn get_penalty_pool(env: Env, call_id: u64) -> i128 {
+        env.storage()
+            .instance()
+            .get(&MockKey::PenaltyPool(call_id))
+            .unwrap_or(0)
+    }
+    pub fn set_end_ts(env: Env, call_id: u64, end_ts: u64) {
+        env.storage()
+            .instance()
+            .set(&MockKey::EndTs(call_id), &end_ts);
+    }
+    pub fn override_outcome(
+        env: Env,
+        _caller: Address,
+        call_id: u64,
+        outcome: u32,
+        end_price: i128,
+        _evidence_cid: Bytes,
+    ) {
+        env.storage()
+            .instance()
+            .set(&MockKey::Override(call_id), &(outcome, end_price));
+    }
+    pub fn get_override(env: Env, call_id: u64) -> Option<(u32, i128)> {
+        env.storage().instance().get(&MockKey::Override(call_id))
+    }
+    pub fn get_call_end_ts(env: Env, call_id: u64) -> u64 {
+        env.storage()
+            .instance()
+            .get(&MockKey::EndTs(call_id))
+            .unwrap_or(0)
+    }
+    pub fn get_call_start_price(_env: Env, _call_id: u64) -> i128 {
+        100
+    }
+    pub fn set_outcome_count(env: Env, call_id: u64, outcome_count: u32) {
+        env.storage()
+            .instance()
+            .set(&MockKey::OutcomeCount(call_id), &outcome_count);
+    }
+    /// Calls are binary unless set otherwise.
+    pub fn get_call_outcome_count(env: Env, call_id: u64) -> u32 {
+        env.storage()
+            .instance()
+            .get(&MockKey::OutcomeCount(call_id))
+            .unwrap_or(2)
+    }
+    /// Binary outcome against a fixed start price of 100.
+    pub fn get_binary_outcome(_env: Env, _call_id: u64, end_price: i128) -> u32 {
+        match end_price.cmp(&100) {
+            core::cmp::Ordering::Greater => 1,
+            core::cmp::Ordering::Less => 2,
+            core::cmp::Ordering::Equal => backit_shared::OUTCOME_DRAW,
+        }
+    }
+}
+
+/// Minimal SEP-40 feed returning whatever price was last set.
+#[contract]
+pub struct MockPriceFeed;
+
+#[contractimpl]
+impl MockPriceFeed {
+    pub fn set_price(env: Env, price: i128, timestamp: u64) {
+        let data = PriceData { price, timestamp };
+        env.storage().instance().set(&symbol_short!("price"), &data);
+    }
+    pub fn lastprice(env: Env, _asset: Asset) -> Option<PriceData> {
+        env.storage().instance().get(&symbol_short!("price"))
+    }
+}
+
+/// Record `staker`'s stake and weight on `position` of `call_id` in the mock
+/// registry.
+fn stake(
+    env: &Env,
+    registry_id: &Address,
+    call_id: u64,
+    staker: &Address,
+    position: u32,
+    amount: i128,
+    weight: i128,
+) {
+    MockRegistryClient::new(env, registry_id)
+        .set_stake(&call_id, staker, &position, &amount, &weight);
+}
+
+/// Stake `amount` from a fresh staker on a losing position of `call_id`.
+fn stake_loser(env: &Env, registry_id: &Address, call_id: u64, amount: i128) {
+    stake(
+        env,
+        registry_id,
+        call_id,
+        &Address::generate(env),
+        2,
+        amount,
+        amount,
+    );
 }
 
 /// Generate a deterministic Ed25519 keypair for testing.
@@ -37,22 +310,39 @@ fn gen_keypair(env: &Env) -> (BytesN<32>, BytesN<32>) {
     )
 }
 
+/// Ledger sequence the test reports stay valid until.
+const VALID_UNTIL: u32 = 1_000;
+
 /// Sign the canonical outcome message using ed25519-dalek.
 fn sign_outcome(
     env: &Env,
+    contract: &Address,
+    registry: &Address,
     secret: &BytesN<32>,
     call_id: u64,
     outcome: u32,
     price: i128,
     timestamp: u64,
+    nonce: u64,
+    valid_until: u32,
 ) -> BytesN<64> {
     use crate::verification::build_message;
     use ed25519_dalek::{Signer, SigningKey};
 
-    let msg = build_message(env, call_id, outcome, price, timestamp);
+    let msg = build_message(
+        env,
+        contract,
+        registry,
+        call_id,
+        outcome,
+        price,
+        timestamp,
+        nonce,
+        valid_until,
+    );
 
     // Convert soroban Bytes to fixed-size array for signing
-    let mut msg_bytes = [0u8; 128];
+    let mut msg_bytes = [0u8; 256];
     let msg_len = msg.len() as usize;
     msg.copy_into_slice(&mut msg_bytes[..msg_len]);
 
@@ -70,7 +360,7 @@ fn setup_single_oracle(
     Address,
     BytesN<32>,
     BytesN<32>,
-        OutcomeManagerClient<'_>, 
+    OutcomeManagerClient<'_>,
 ) {
     env.mock_all_auths();
     let admin = Address::generate(env);
@@ -83,71 +373,119 @@ fn setup_single_oracle(
     oracles.push_back(oracle_pubkey.clone());
 
     let fee_collector = Address::generate(env);
-    client.initialize(&admin, &oracles, &1u32, &fee_collector, &0u32, &0u64);
-
     // Register a mock registry contract
     let registry_id = env.register_contract(None, MockRegistry);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &1u32,
+        &fee_collector,
+        &0u32,
+        &0u32,
+    );
 
     (admin, registry_id, oracle_secret, oracle_pubkey, client)
 }
 
 fn assert_contract_error<T, E>(
     result: Result<Result<T, E>, Result<soroban_sdk::Error, soroban_sdk::InvokeError>>,
-    expected: OutcomeError,
+    expected: impl Into<soroban_sdk::Error>,
 ) {
-    assert!(matches!(
-        result,
-        Err(Ok(err)) if err == soroban_sdk::Error::from_contract_error(expected as u32)
-    ));
+    let expected = expected.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected));
 }
 
 // ─── Initialization Tests ──────────────────────────────────────────────────────
 
 fn sign_observation(
     env: &Env,
+    client: &OutcomeManagerClient,
+    registry: &Address,
     secret: &BytesN<32>,
     call_id: u64,
     price: i128,
     timestamp: u64,
+    nonce: u64,
 ) -> BytesN<64> {
+    use crate::verification::build_observation_message;
     use ed25519_dalek::{Signer, SigningKey};
-    use soroban_sdk::Bytes;
-
-    let mut raw = Bytes::from_slice(env, b"twap_obs:");
-    raw.append(&Bytes::from_slice(env, &call_id.to_be_bytes()));
-    raw.append(&Bytes::from_slice(env, &price.to_be_bytes()));
-    raw.append(&Bytes::from_slice(env, &timestamp.to_be_bytes()));
 
-    let msg_len = raw.len() as usize;
-    let mut buf = [0u8; 64];
-    raw.copy_into_slice(&mut buf[..msg_len]);
+    let msg = build_observation_message(
+        env,
+        &client.address,
+        registry,
+        call_id,
+        price,
+        timestamp,
+        nonce,
+    );
+    let mut msg_bytes = [0u8; 256];
+    let msg_len = msg.len() as usize;
+    msg.copy_into_slice(&mut msg_bytes[..msg_len]);
 
     let signing_key = SigningKey::from_bytes(&secret.to_array());
-    let sig = signing_key.sign(&buf[..msg_len]);
+    let sig = signing_key.sign(&msg_bytes[..msg_len]);
     BytesN::from_array(env, &sig.to_bytes())
 }
 
+/// Submit a signed observation of `price` at `ts` for `call_id` on the
+/// manager's first registry, under the oracle's next nonce.
+fn try_observe(
+    env: &Env,
+    client: &OutcomeManagerClient,
+    secret: &BytesN<32>,
+    pubkey: &BytesN<32>,
+    call_id: u64,
+    price: i128,
+    ts: u64,
+) -> Result<
+    Result<(), soroban_sdk::ConversionError>,
+    Result<soroban_sdk::Error, soroban_sdk::InvokeError>,
+> {
+    let registry_id = client.get_registries().get_unchecked(0);
+    let nonce = client.get_oracle_nonce(pubkey).unwrap_or(0) + 1;
+    let sig = sign_observation(env, client, &registry_id, secret, call_id, price, ts, nonce);
+    let observation = PriceObservation {
+        price,
+        timestamp: ts,
+    };
+    client.try_submit_price_observation(&registry_id, &call_id, &observation, &nonce, pubkey, &sig)
+}
+
+/// Submit a signed observation of `price` at `ts` for `call_id`; `true` if accepted.
+fn observe(
+    env: &Env,
+    client: &OutcomeManagerClient,
+    secret: &BytesN<32>,
+    pubkey: &BytesN<32>,
+    call_id: u64,
+    price: i128,
+    ts: u64,
+) -> bool {
+    try_observe(env, client, secret, pubkey, call_id, price, ts).is_ok()
+}
+
 #[test]
 fn test_twap_three_equal_intervals() {
     // prices 100, 200, 300 at t=1000, 2000, 3000
     // intervals: 1000s each
     // TWAP = (100*1000 + 200*1000) / 2000 = 150
     let env = Env::default();
-    let (_admin, _registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
+    let (_admin, registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
     let call_id = 42u64;
     for (price, ts) in [(100_i128, 1000u64), (200, 2000), (300, 3000)] {
-        let sig = sign_observation(&env, &oracle_secret, call_id, price, ts);
-        client.submit_price_observation(
-            &call_id,
-            &PriceObservation {
-                price,
-                timestamp: ts,
-            },
+        assert!(observe(
+            &env,
+            &client,
+            &oracle_secret,
             &oracle_pubkey,
-            &sig,
-        );
+            call_id,
+            price,
+            ts
+        ));
     }
-    assert_eq!(client.compute_twap(&call_id), 150);
+    assert_eq!(client.compute_twap(&registry_id, &call_id), 150);
 }
 
 #[test]
@@ -155,41 +493,39 @@ fn test_twap_unequal_intervals() {
     // price 100 for 100s, then 900 for 900s
     // TWAP = (100*100 + 900*900) / 1000 = 820
     let env = Env::default();
-    let (_admin, _reg, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
+    let (_admin, reg, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
     let call_id = 43u64;
     for (price, ts) in [(100_i128, 0u64), (900, 100), (900, 1000)] {
-        let sig = sign_observation(&env, &oracle_secret, call_id, price, ts);
-        client.submit_price_observation(
-            &call_id,
-            &PriceObservation {
-                price,
-                timestamp: ts,
-            },
+        assert!(observe(
+            &env,
+            &client,
+            &oracle_secret,
             &oracle_pubkey,
-            &sig,
-        );
+            call_id,
+            price,
+            ts
+        ));
     }
-    assert_eq!(client.compute_twap(&call_id), 820);
+    assert_eq!(client.compute_twap(&reg, &call_id), 820);
 }
 
 #[test]
 fn test_twap_requires_minimum_3_observations() {
     let env = Env::default();
-    let (_admin, _reg, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
+    let (_admin, reg, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
     let call_id = 44u64;
     for (price, ts) in [(100_i128, 1000u64), (200, 2000)] {
-        let sig = sign_observation(&env, &oracle_secret, call_id, price, ts);
-        client.submit_price_observation(
-            &call_id,
-            &PriceObservation {
-                price,
-                timestamp: ts,
-            },
+        assert!(observe(
+            &env,
+            &client,
+            &oracle_secret,
             &oracle_pubkey,
-            &sig,
-        );
+            call_id,
+            price,
+            ts
+        ));
     }
-    let result = client.try_compute_twap(&call_id);
+    let result = client.try_compute_twap(&reg, &call_id);
     assert_contract_error(result, OutcomeError::InsufficientPriceObservations);
 }
 
@@ -198,29 +534,85 @@ fn test_twap_rejects_non_increasing_timestamp() {
     let env = Env::default();
     let (_admin, _reg, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
     let call_id = 45u64;
-    let sig1 = sign_observation(&env, &oracle_secret, call_id, 100, 1000);
-    client.submit_price_observation(
-        &call_id,
-        &PriceObservation {
-            price: 100,
-            timestamp: 1000,
-        },
+    assert!(observe(
+        &env,
+        &client,
+        &oracle_secret,
         &oracle_pubkey,
-        &sig1,
-    );
-    let sig2 = sign_observation(&env, &oracle_secret, call_id, 200, 1000);
-    let result = client.try_submit_price_observation(
-        &call_id,
-        &PriceObservation {
-            price: 200,
-            timestamp: 1000,
-        },
+        call_id,
+        100,
+        1000
+    ));
+    let result = try_observe(
+        &env,
+        &client,
+        &oracle_secret,
         &oracle_pubkey,
-        &sig2,
+        call_id,
+        200,
+        1000,
     );
     assert_contract_error(result, OutcomeError::ObservationOutOfOrder);
 }
 
+#[test]
+fn test_twap_is_median_of_quorum_of_oracles() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 3, 2);
+    let (a_secret, a) = oracles.get_unchecked(0);
+    let (b_secret, b) = oracles.get_unchecked(1);
+    let (c_secret, c) = oracles.get_unchecked(2);
+
+    // One oracle alone cannot set the TWAP
+    for (price, ts) in [(900_i128, 1000u64), (900, 2000), (900, 9000)] {
+        assert!(observe(&env, &client, &a_secret, &a, 1, price, ts));
+    }
+    let result = client.try_compute_twap(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeError::InsufficientPriceObservations);
+
+    // Its late timestamp does not block the others' series
+    for (price, ts) in [(100_i128, 1000u64), (100, 2000), (100, 3000)] {
+        assert!(observe(&env, &client, &b_secret, &b, 1, price, ts));
+        assert!(observe(&env, &client, &c_secret, &c, 1, price + 10, ts));
+    }
+    assert_eq!(client.compute_twap(&registry_id, &1u64), 110);
+}
+
+#[test]
+fn test_observation_signature_is_bound_to_registry_and_nonce() {
+    let env = Env::default();
+    let (_admin, registry_id, secret, pubkey, client) = setup_single_oracle(&env);
+    let other_registry = env.register_contract(None, MockRegistry);
+    client.add_registry(&other_registry);
+    let observation = PriceObservation {
+        price: 100,
+        timestamp: 1000,
+    };
+
+    // Signed for one registry, it does not verify for another
+    let sig = sign_observation(&env, &client, &registry_id, &secret, 1, 100, 1000, 1);
+    let result = client.try_submit_price_observation(
+        &other_registry,
+        &1u64,
+        &observation,
+        &1u64,
+        &pubkey,
+        &sig,
+    );
+    assert!(result.is_err());
+
+    client.submit_price_observation(&registry_id, &1u64, &observation, &1u64, &pubkey, &sig);
+    let result = client.try_submit_price_observation(
+        &registry_id,
+        &1u64,
+        &observation,
+        &1u64,
+        &pubkey,
+        &sig,
+    );
+    assert_contract_error(result, OutcomeError::NonceAlreadyUsed);
+}
+
 #[test]
 fn test_initialize_success() {
     let env = Env::default();
@@ -235,8 +627,21 @@ fn test_initialize_success() {
     oracles.push_back(pubkey.clone());
 
     let fee_collector = Address::generate(&env);
-    client.initialize(&admin, &oracles, &1u32, &fee_collector, &100u32, &0u64);
+    let registry_id = env.register_contract(None, MockRegistry);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &1u32,
+        &fee_collector,
+        &100u32,
+        &0u32,
+    );
 
+    assert_eq!(
+        client.get_registries(),
+        Vec::from_array(&env, [registry_id])
+    );
     assert_eq!(client.get_quorum(), 1);
     assert!(client.is_oracle(&pubkey));
 }
@@ -244,12 +649,20 @@ fn test_initialize_success() {
 #[test]
 fn test_initialize_twice_fails() {
     let env = Env::default();
-    let (admin, _, _, pubkey, client) = setup_single_oracle(&env);
+    let (admin, registry_id, _, pubkey, client) = setup_single_oracle(&env);
 
     let fee_collector = Address::generate(&env);
     let mut oracles = Vec::new(&env);
     oracles.push_back(pubkey);
-    let result = client.try_initialize(&admin, &oracles, &1u32, &fee_collector, &0u32, &0u64);
+    let result = client.try_initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &1u32,
+        &fee_collector,
+        &0u32,
+        &0u32,
+    );
     assert_contract_error(result, OutcomeError::AlreadyInitialized);
 }
 
@@ -266,7 +679,16 @@ fn test_initialize_quorum_zero_fails() {
     let fee_collector = Address::generate(&env);
     let mut oracles = Vec::new(&env);
     oracles.push_back(pubkey);
-    let result = client.try_initialize(&admin, &oracles, &0u32, &fee_collector, &0u32, &0u64);
+    let registry_id = Address::generate(&env);
+    let result = client.try_initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &0u32,
+        &fee_collector,
+        &0u32,
+        &0u32,
+    );
     assert_contract_error(result, OutcomeError::InvalidQuorum);
 }
 
@@ -288,50 +710,86 @@ fn test_quorum_reached_with_two_oracles() {
     oracles.push_back(p1.clone());
     oracles.push_back(p2.clone());
     let fee_collector = Address::generate(&env);
-    client.initialize(&admin, &oracles, &2u32, &fee_collector, &0u32, &0u64);
-
     let registry_id = env.register_contract(None, MockRegistry);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &2u32,
+        &fee_collector,
+        &0u32,
+        &0u32,
+    );
     let call_id = 42u64;
     let outcome_val = 1u32;
     let price = 150_000_000i128;
     let ts = 9000u64;
 
     // First oracle vote
-    let sig1 = sign_outcome(&env, &s1, call_id, outcome_val, price, ts);
-    client.submit_outcome(
+    let sig1 = sign_outcome(
+        &env,
+        &client.address,
         &registry_id,
-        &SignedOutcome {
-            call_id,
-            outcome: outcome_val,
-            price,
-            timestamp: ts,
-            oracle_pubkey: p1.clone(),
-            signature: sig1,
-        },
-        &0u64,
+        &s1,
+        call_id,
+        outcome_val,
+        price,
+        ts,
+        1,
+        VALID_UNTIL,
     );
-
-    // Second oracle vote
-    let sig2 = sign_outcome(&env, &s2, call_id, outcome_val, price, ts);
-    client.submit_outcome(
+    client.submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome: outcome_val,
+        price,
+        timestamp: ts,
+        oracle_pubkey: p1.clone(),
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sig1,
+    });
+
+    // The pending vote is tracked in persistent storage
+    let submission_key = PersistentKey::Submission(p1.clone(), registry_id.clone(), call_id);
+    env.as_contract(&contract_id, || {
+        assert!(env.storage().persistent().has(&submission_key));
+    });
+
+    // Second oracle vote, with its own nonce
+    let sig2 = sign_outcome(
+        &env,
+        &client.address,
         &registry_id,
-        &SignedOutcome {
-            call_id,
-            outcome: outcome_val,
-            price,
-            timestamp: ts,
-            oracle_pubkey: p2.clone(),
-            signature: sig2,
-        },
-        &0u64,
+        &s2,
+        call_id,
+        outcome_val,
+        price,
+        ts,
+        7,
+        VALID_UNTIL,
     );
-
-    let final_outcome = client.get_outcome(&call_id);
+    client.submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome: outcome_val,
+        price,
+        timestamp: ts,
+        oracle_pubkey: p2.clone(),
+        nonce: 7,
+        valid_until: VALID_UNTIL,
+        signature: sig2,
+    });
+
+    let final_outcome = client.get_outcome(&registry_id, &call_id);
     assert_eq!(final_outcome.outcome, outcome_val);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&submission_key));
+    });
 
-    let stored_votes = client.get_votes(&call_id);
+    let stored_votes = client.get_votes(&registry_id, &call_id);
     assert_eq!(stored_votes.len(), 2);
-    assert_eq!(client.get_vote_count(&call_id), 2);
+    assert_eq!(client.get_vote_count(&registry_id, &call_id), 2);
     assert_eq!(
         stored_votes.get(0).unwrap(),
         OracleVote {
@@ -352,29 +810,312 @@ fn test_quorum_reached_with_two_oracles() {
     );
 }
 
+/// Register an OutcomeManager with `count` oracles and the given quorum.
+fn setup_oracles(
+    env: &Env,
+    count: u32,
+    quorum: u32,
+) -> (
+    Address,
+    Vec<(BytesN<32>, BytesN<32>)>,
+    OutcomeManagerClient<'_>,
+) {
+    env.mock_all_auths();
+    let mut keys = Vec::new(env);
+    let mut oracles = Vec::new(env);
+    for _ in 0..count {
+        let (secret, pubkey) = gen_keypair(env);
+        oracles.push_back(pubkey.clone());
+        keys.push_back((secret, pubkey));
+    }
+    let client = OutcomeManagerClient::new(env, &env.register_contract(None, OutcomeManager));
+    let admin = Address::generate(env);
+    let fee_collector = Address::generate(env);
+    let registry_id = env.register_contract(None, MockRegistry);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &quorum,
+        &fee_collector,
+        &0u32,
+        &0u32,
+    );
+    (registry_id, keys, client)
+}
+
+/// Sign and submit a report from `oracle` for `call_id`.
+fn submit_vote(
+    env: &Env,
+    client: &OutcomeManagerClient,
+    oracle: (BytesN<32>, BytesN<32>),
+    call_id: u64,
+    outcome: u32,
+    price: i128,
+    timestamp: u64,
+) {
+    let (secret, pubkey) = oracle;
+    let registry_id = client.get_registries().get_unchecked(0);
+    let signature = sign_outcome(
+        env,
+        &client.address,
+        &registry_id,
+        &secret,
+        call_id,
+        outcome,
+        price,
+        timestamp,
+        1,
+        VALID_UNTIL,
+    );
+    client.submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome,
+        price,
+        timestamp,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        oracle_pubkey: pubkey,
+        signature,
+    });
+}
+
+#[test]
+fn test_quorum_matches_on_outcome_and_takes_median_price() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 4, 3);
+
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, 1, 100, 9000);
+    submit_vote(&env, &client, oracles.get_unchecked(1), 1, 2, 90, 9000);
+    submit_vote(&env, &client, oracles.get_unchecked(2), 1, 1, 130, 9000);
+    assert!(client.try_get_outcome(&registry_id, &1u64).is_err());
+
+    // Third UP vote reaches quorum; the DOWN vote's price is ignored
+    submit_vote(&env, &client, oracles.get_unchecked(3), 1, 1, 110, 9000);
+    let outcome = client.get_outcome(&registry_id, &1u64);
+    assert_eq!(outcome.outcome, 1);
+    assert_eq!(outcome.price, 110);
+}
+
+#[test]
+fn test_median_price_of_even_quorum_is_midpoint() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 2, 2);
+
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, 2, 131, 9000);
+    submit_vote(&env, &client, oracles.get_unchecked(1), 1, 2, 100, 9000);
+    assert_eq!(client.get_outcome(&registry_id, &1u64).price, 115);
+}
+
+#[test]
+fn test_vote_tolerance_groups_close_votes() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 4, 2);
+    let tolerance = VoteTolerance {
+        price_bps: 100,
+        window_secs: 60,
+    };
+    client.set_vote_tolerance(&Some(tolerance.clone()));
+    assert_eq!(client.get_vote_tolerance(), Some(tolerance));
+
+    // 20% apart in price, then 1% apart but observed 61s later: no quorum
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, 1, 100, 9000);
+    submit_vote(&env, &client, oracles.get_unchecked(1), 1, 1, 120, 9000);
+    submit_vote(&env, &client, oracles.get_unchecked(2), 1, 1, 101, 9061);
+    assert!(client.try_get_outcome(&registry_id, &1u64).is_err());
+
+    // Within 1% and 60s of the first and third votes: median of 100, 101, 101
+    submit_vote(&env, &client, oracles.get_unchecked(3), 1, 1, 101, 9060);
+    assert_eq!(client.get_outcome(&registry_id, &1u64).price, 101);
+}
+
+#[test]
+fn test_set_vote_tolerance_rejects_invalid_bps() {
+    let env = Env::default();
+    let (_, _, client) = setup_oracles(&env, 1, 1);
+
+    let result = client.try_set_vote_tolerance(&Some(VoteTolerance {
+        price_bps: 10_001,
+        window_secs: 60,
+    }));
+    assert_contract_error(result, OutcomeError::InvalidVoteTolerance);
+
+    client.set_vote_tolerance(&None);
+    assert_eq!(client.get_vote_tolerance(), None);
+}
+
 #[test]
 fn test_submit_unauthorized_oracle_fails() {
     let env = Env::default();
     env.mock_all_auths();
-    let (_, mock_registry, _, _, client) = setup_single_oracle(&env);
+    let (_, registry_id, _, _, client) = setup_single_oracle(&env);
 
     let (secret2, pubkey2) = gen_keypair(&env);
     let call_id = 1u64;
-    let sig = sign_outcome(&env, &secret2, call_id, 1, 100, 9000);
+    let sig = sign_outcome(
+        &env,
+        &client.address,
+        &registry_id,
+        &secret2,
+        call_id,
+        1,
+        100,
+        9000,
+        1,
+        VALID_UNTIL,
+    );
+
+    let result = client.try_submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome: 1,
+        price: 100,
+        timestamp: 9000,
+        oracle_pubkey: pubkey2,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sig,
+    });
+    assert_contract_error(result, OutcomeError::UnauthorizedOracle);
+}
+
+#[test]
+fn test_submit_signature_for_other_instance_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
+
+    // Signed for a different OutcomeManager deployment: must not replay here.
+    let other_instance = Address::generate(&env);
+    let sig = sign_outcome(
+        &env,
+        &other_instance,
+        &registry_id,
+        &oracle_secret,
+        1,
+        1,
+        100,
+        9000,
+        1,
+        VALID_UNTIL,
+    );
+
+    let result = client.try_submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id: 1,
+        outcome: 1,
+        price: 100,
+        timestamp: 9000,
+        oracle_pubkey,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sig,
+    });
+    assert!(result.is_err());
+    assert_eq!(client.get_vote_count(&registry_id, &1u64), 0);
+}
 
-    let result = client.try_submit_outcome(
-        &mock_registry,
-        &SignedOutcome {
+/// Build a report from the single test oracle with the given nonce and expiry.
+fn signed_report(
+    env: &Env,
+    client: &OutcomeManagerClient,
+    secret: &BytesN<32>,
+    pubkey: &BytesN<32>,
+    call_id: u64,
+    nonce: u64,
+    valid_until: u32,
+) -> SignedOutcome {
+    let registry_id = client.get_registries().get_unchecked(0);
+    SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome: 1,
+        price: 100,
+        timestamp: 9000,
+        nonce,
+        valid_until,
+        oracle_pubkey: pubkey.clone(),
+        signature: sign_outcome(
+            env,
+            &client.address,
+            &registry_id,
+            secret,
             call_id,
-            outcome: 1,
-            price: 100,
-            timestamp: 9000,
-            oracle_pubkey: pubkey2,
-            signature: sig,
-        },
-        &0u64,
+            1,
+            100,
+            9000,
+            nonce,
+            valid_until,
+        ),
+    }
+}
+
+#[test]
+fn test_submit_expired_report_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, _, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
+
+    let signed = signed_report(&env, &client, &oracle_secret, &oracle_pubkey, 1, 1, 50);
+    env.ledger().set_sequence_number(51);
+
+    let result = client.try_submit_outcome(&signed);
+    assert_contract_error(result, OutcomeError::ReportExpired);
+}
+
+#[test]
+fn test_submit_reused_nonce_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
+
+    let first = signed_report(
+        &env,
+        &client,
+        &oracle_secret,
+        &oracle_pubkey,
+        1,
+        5,
+        VALID_UNTIL,
     );
-    assert_contract_error(result, OutcomeError::UnauthorizedOracle);
+    client.submit_outcome(&first);
+
+    // Same nonce on another call, and a lower one, are both replays.
+    let reused = signed_report(
+        &env,
+        &client,
+        &oracle_secret,
+        &oracle_pubkey,
+        2,
+        5,
+        VALID_UNTIL,
+    );
+    let result = client.try_submit_outcome(&reused);
+    assert_contract_error(result, OutcomeError::NonceAlreadyUsed);
+    let lower = signed_report(
+        &env,
+        &client,
+        &oracle_secret,
+        &oracle_pubkey,
+        2,
+        4,
+        VALID_UNTIL,
+    );
+    let result = client.try_submit_outcome(&lower);
+    assert_contract_error(result, OutcomeError::NonceAlreadyUsed);
+
+    let next = signed_report(
+        &env,
+        &client,
+        &oracle_secret,
+        &oracle_pubkey,
+        2,
+        6,
+        VALID_UNTIL,
+    );
+    client.submit_outcome(&next);
+    assert_eq!(client.get_outcome(&registry_id, &2u64).call_id, 2);
 }
 
 #[test]
@@ -393,20 +1134,41 @@ fn test_submit_duplicate_submission_fails() {
     oracles.push_back(pubkey1.clone());
     oracles.push_back(pubkey2);
     let fee_collector = Address::generate(&env);
-    client.initialize(&admin, &oracles, &2u32, &fee_collector, &0u32, &0u64);
-
     let registry_id = env.register_contract(None, MockRegistry);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &2u32,
+        &fee_collector,
+        &0u32,
+        &0u32,
+    );
     let signed = SignedOutcome {
+        registry: registry_id.clone(),
         call_id: 7,
         outcome: 1,
         price: 100,
         timestamp: 1000,
         oracle_pubkey: pubkey1.clone(),
-        signature: sign_outcome(&env, &secret1, 7, 1, 100, 1000),
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &registry_id,
+            &secret1,
+            7,
+            1,
+            100,
+            1000,
+            1,
+            VALID_UNTIL,
+        ),
     };
 
-    client.submit_outcome(&registry_id, &signed, &0u64);
-    let result = client.try_submit_outcome(&registry_id, &signed, &0u64);
+    client.submit_outcome(&signed);
+    let result = client.try_submit_outcome(&signed);
     assert_contract_error(result, OutcomeError::DuplicateSubmission);
 }
 
@@ -415,120 +1177,1337 @@ fn test_submit_invalid_outcome_fails() {
     let env = Env::default();
     let (_admin, registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
 
-    let result = client.try_submit_outcome(
-        &registry_id,
-        &SignedOutcome {
-            call_id: 8,
-            outcome: 3,
-            price: 100,
-            timestamp: 1000,
-            oracle_pubkey: oracle_pubkey.clone(),
-            signature: sign_outcome(&env, &oracle_secret, 8, 3, 100, 1000),
-        },
-        &0u64,
-    );
+    let result = client.try_submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id: 8,
+        outcome: 4,
+        price: 100,
+        timestamp: 1000,
+        oracle_pubkey: oracle_pubkey.clone(),
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &registry_id,
+            &oracle_secret,
+            8,
+            4,
+            100,
+            1000,
+            1,
+            VALID_UNTIL,
+        ),
+    });
     assert_contract_error(result, OutcomeError::InvalidOutcome);
 }
 
 #[test]
-fn test_submit_outcome_after_settlement_fails() {
+fn test_multi_outcome_call_resolves_to_position_three() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    registry.set_outcome_count(&1u64, &3u32);
+    registry.set_outcome_count(&2u64, &3u32);
+    let winner = Address::generate(&env);
+    let loser = Address::generate(&env);
+    stake(&env, &registry_id, 1, &winner, 3, 100, 100);
+    stake(&env, &registry_id, 1, &loser, 1, 100, 100);
+
+    // DRAW is only an outcome of binary calls
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    let draw = backit_shared::OUTCOME_DRAW;
+    let result = client.try_submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id: 2,
+        outcome: draw,
+        price: 100,
+        timestamp: 1000,
+        oracle_pubkey: pubkey.clone(),
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &registry_id,
+            &secret,
+            2,
+            draw,
+            100,
+            1000,
+            1,
+            VALID_UNTIL,
+        ),
+    });
+    assert_contract_error(result, OutcomeError::InvalidOutcome);
+
+    // Position 3 takes the pool rather than being refunded as a draw
+    submit_vote(&env, &client, (secret, pubkey), 1, 3, 100, 1000);
+    assert_eq!(client.get_outcome(&registry_id, &1u64).outcome, 3);
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &loser), 0);
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &winner), 200);
+    client.claim_payout(&registry_id, &1u64, &winner);
+    assert!(client.has_claimed(&registry_id, &1u64, &winner));
+}
+
+#[test]
+fn test_submit_outcome_after_settlement_fails() {
     let env = Env::default();
     let (_admin, registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
 
     let signed = SignedOutcome {
+        registry: registry_id.clone(),
         call_id: 9,
         outcome: 1,
         price: 100,
         timestamp: 1000,
         oracle_pubkey: oracle_pubkey.clone(),
-        signature: sign_outcome(&env, &oracle_secret, 9, 1, 100, 1000),
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &registry_id,
+            &oracle_secret,
+            9,
+            1,
+            100,
+            1000,
+            1,
+            VALID_UNTIL,
+        ),
     };
 
-    client.submit_outcome(&registry_id, &signed, &0u64);
-    let result = client.try_submit_outcome(&registry_id, &signed, &0u64);
+    client.submit_outcome(&signed);
+    let result = client.try_submit_outcome(&signed);
+    assert_contract_error(result, OutcomeError::AlreadySettled);
+}
+
+// ─── Challenge Window Tests ────────────────────────────────────────────────────
+
+const DISPUTE_WINDOW: u32 = 10;
+const BOND: i128 = 500;
+
+/// One oracle with quorum 1, a dispute window and a challenge bond, plus a
+/// challenger funded with the bond.
+fn setup_challenge(
+    env: &Env,
+) -> (
+    Address,
+    Address,
+    Address,
+    (BytesN<32>, BytesN<32>),
+    token::Client<'_>,
+    OutcomeManagerClient<'_>,
+) {
+    env.mock_all_auths();
+    let (secret, pubkey) = gen_keypair(env);
+    let mut oracles = Vec::new(env);
+    oracles.push_back(pubkey.clone());
+    let client = OutcomeManagerClient::new(env, &env.register_contract(None, OutcomeManager));
+    let fee_collector = Address::generate(env);
+    let registry_id = env.register_contract(None, MockRegistry);
+    client.initialize(
+        &Address::generate(env),
+        &registry_id,
+        &oracles,
+        &1u32,
+        &fee_collector,
+        &0u32,
+        &DISPUTE_WINDOW,
+    );
+
+    let bond_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.set_challenge_bond(&bond_token, &BOND);
+    let challenger = Address::generate(env);
+    token::StellarAssetClient::new(env, &bond_token).mint(&challenger, &BOND);
+
+    (
+        registry_id,
+        fee_collector,
+        challenger,
+        (secret, pubkey),
+        token::Client::new(env, &bond_token),
+        client,
+    )
+}
+
+#[test]
+fn test_quorum_with_dispute_window_waits_before_finalizing() {
+    let env = Env::default();
+    let (registry_id, _, _, oracle, _, client) = setup_challenge(&env);
+
+    submit_vote(&env, &client, oracle.clone(), 1, 1, 100, 9000);
+    assert!(client.try_get_outcome(&registry_id, &1u64).is_err());
+    let pending = client.get_pending_outcome(&registry_id, &1u64).unwrap();
+    assert_eq!(pending.challenge_deadline, DISPUTE_WINDOW);
+    assert_eq!(pending.outcome.registry, registry_id);
+
+    let result = client.try_finalize_outcome(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeError::CallNotFinalized);
+
+    env.ledger().set_sequence_number(DISPUTE_WINDOW + 1);
+    client.finalize_outcome(&registry_id, &1u64);
+    assert_eq!(client.get_outcome(&registry_id, &1u64).price, 100);
+    assert_eq!(client.get_pending_outcome(&registry_id, &1u64), None);
+}
+
+#[test]
+fn test_upheld_challenge_replaces_outcome_and_refunds_bond() {
+    let env = Env::default();
+    let (registry_id, _, challenger, oracle, bond_token, client) = setup_challenge(&env);
+    submit_vote(&env, &client, oracle, 1, 1, 100, 9000);
+
+    client.challenge_outcome(&challenger, &registry_id, &1u64, &2u32, &90i128);
+    assert_eq!(bond_token.balance(&challenger), 0);
+    assert_eq!(
+        client.get_challenge(&registry_id, &1u64).unwrap().bond,
+        BOND
+    );
+    let result = client.try_challenge_outcome(&challenger, &registry_id, &1u64, &2u32, &90i128);
+    assert_contract_error(result, OutcomeError::AlreadyChallenged);
+
+    // An open challenge blocks permissionless finalization
+    env.ledger().set_sequence_number(DISPUTE_WINDOW + 1);
+    let result = client.try_finalize_outcome(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeError::ChallengePending);
+
+    client.resolve_challenge(&registry_id, &1u64, &true);
+    let outcome = client.get_outcome(&registry_id, &1u64);
+    assert_eq!((outcome.outcome, outcome.price), (2, 90));
+    assert_eq!(bond_token.balance(&challenger), BOND);
+    assert_eq!(client.get_challenge(&registry_id, &1u64), None);
+}
+
+#[test]
+fn test_rejected_challenge_forfeits_bond() {
+    let env = Env::default();
+    let (registry_id, fee_collector, challenger, oracle, bond_token, client) =
+        setup_challenge(&env);
+    submit_vote(&env, &client, oracle, 1, 1, 100, 9000);
+
+    client.challenge_outcome(&challenger, &registry_id, &1u64, &2u32, &90i128);
+    client.resolve_challenge(&registry_id, &1u64, &false);
+
+    assert_eq!(client.get_outcome(&registry_id, &1u64).outcome, 1);
+    assert_eq!(bond_token.balance(&fee_collector), BOND);
+}
+
+#[test]
+fn test_challenge_after_window_fails() {
+    let env = Env::default();
+    let (registry_id, _, challenger, oracle, _, client) = setup_challenge(&env);
+    submit_vote(&env, &client, oracle.clone(), 1, 1, 100, 9000);
+
+    // Oracles cannot vote again while the outcome is pending
+    let (secret, pubkey) = gen_keypair(&env);
+    client.add_oracle(&pubkey);
+    let result = client.try_submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id: 1,
+        outcome: 1,
+        price: 100,
+        timestamp: 9000,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        oracle_pubkey: pubkey,
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &registry_id,
+            &secret,
+            1,
+            1,
+            100,
+            9000,
+            1,
+            VALID_UNTIL,
+        ),
+    });
+    assert_contract_error(result, OutcomeError::OutcomePending);
+
+    env.ledger().set_sequence_number(DISPUTE_WINDOW + 1);
+    let result = client.try_challenge_outcome(&challenger, &registry_id, &1u64, &2u32, &90i128);
+    assert_contract_error(result, OutcomeError::DisputeWindowExpired);
+}
+
+#[test]
+fn test_void_outcome_has_no_payout_to_claim() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    let void = backit_shared::OUTCOME_VOID;
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, void, 0, 9000);
+    assert_eq!(client.get_outcome(&registry_id, &1u64).outcome, void);
+
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    let result = client.try_claim_payout(&registry_id, &1u64, &staker);
+    assert_contract_error(result, OutcomeError::CallVoided);
+}
+
+#[test]
+fn test_expire_unresolved_voids_after_resolution_window() {
+    let env = Env::default();
+    let (registry_id, _oracles, client) = setup_oracles(&env, 3, 2);
+    MockRegistryClient::new(&env, &registry_id).set_end_ts(&1u64, &1_000u64);
+    let window = client.get_resolution_window();
+
+    env.ledger().set_timestamp(1_000 + window);
+    let result = client.try_expire_unresolved(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeError::ResolutionWindowOpen);
+
+    env.ledger().set_timestamp(1_001 + window);
+    client.expire_unresolved(&registry_id, &1u64);
+    let outcome = client.get_outcome(&registry_id, &1u64);
+    assert_eq!(outcome.outcome, backit_shared::OUTCOME_VOID);
+    assert_eq!(outcome.price, 0);
+
+    let result = client.try_expire_unresolved(&registry_id, &1u64);
     assert_contract_error(result, OutcomeError::AlreadySettled);
 }
 
-// ─── Admin Control Tests ───────────────────────────────────────────────────────
-
 #[test]
-fn test_add_remove_oracle() {
+fn test_call_oracle_config_raises_quorum_for_one_call() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 3, 1);
+    let governance = Address::generate(&env);
+    client.set_governance(&governance, &REVERSAL_WINDOW);
+    MockRegistryClient::new(&env, &registry_id).set_end_ts(&1u64, &5_000u64);
+    let config = CallOracleConfig {
+        quorum: 2,
+        oracles: Vec::new(&env),
+    };
+    client.set_call_oracle_config(&governance, &registry_id, &1u64, &Some(config.clone()));
+    assert_eq!(
+        client.get_call_oracle_config(&registry_id, &1u64),
+        Some(config)
+    );
+
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, 1, 100, 9000);
+    assert!(client.try_get_outcome(&registry_id, &1u64).is_err());
+    submit_vote(&env, &client, oracles.get_unchecked(1), 1, 1, 100, 9000);
+    assert_eq!(client.get_outcome(&registry_id, &1u64).outcome, 1);
+
+    // Other calls keep the global quorum of 1
+    submit_vote(&env, &client, oracles.get_unchecked(2), 2, 2, 90, 9000);
+    assert_eq!(client.get_outcome(&registry_id, &2u64).outcome, 2);
+}
+
+#[test]
+fn test_call_oracle_config_restricts_voters_to_committee() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 2, 1);
+    let governance = Address::generate(&env);
+    client.set_governance(&governance, &REVERSAL_WINDOW);
+    MockRegistryClient::new(&env, &registry_id).set_end_ts(&1u64, &5_000u64);
+    let (_, member) = oracles.get_unchecked(0);
+    let (outsider_secret, outsider) = oracles.get_unchecked(1);
+    let config = CallOracleConfig {
+        quorum: 1,
+        oracles: Vec::from_array(&env, [member]),
+    };
+
+    let stranger = Address::generate(&env);
+    let result =
+        client.try_set_call_oracle_config(&stranger, &registry_id, &1u64, &Some(config.clone()));
+    assert_contract_error(result, OutcomeError::Unauthorized);
+
+    client.set_call_oracle_config(&governance, &registry_id, &1u64, &Some(config.clone()));
+    let report = signed_report(
+        &env,
+        &client,
+        &outsider_secret,
+        &outsider,
+        1,
+        1,
+        VALID_UNTIL,
+    );
+    let result = client.try_submit_outcome(&report);
+    assert_contract_error(result, OutcomeError::UnauthorizedOracle);
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, 1, 100, 9000);
+    assert_eq!(client.get_outcome(&registry_id, &1u64).outcome, 1);
+
+    env.ledger().set_timestamp(5_000);
+    let result = client.try_set_call_oracle_config(&governance, &registry_id, &1u64, &None);
+    assert_contract_error(result, OutcomeError::CallAlreadyEnded);
+}
+
+/// Sign and build a report naming `registry` for `call_id`.
+fn report_for_registry(
+    env: &Env,
+    client: &OutcomeManagerClient,
+    registry: &Address,
+    oracle: (BytesN<32>, BytesN<32>),
+    call_id: u64,
+) -> SignedOutcome {
+    let (secret, pubkey) = oracle;
+    SignedOutcome {
+        registry: registry.clone(),
+        call_id,
+        outcome: 1,
+        price: 100,
+        timestamp: 9000,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        oracle_pubkey: pubkey,
+        signature: sign_outcome(
+            env,
+            &client.address,
+            registry,
+            &secret,
+            call_id,
+            1,
+            100,
+            9000,
+            1,
+            VALID_UNTIL,
+        ),
+    }
+}
+
+#[test]
+fn test_submission_must_name_allowlisted_registry() {
+    let env = Env::default();
+    let (_, oracles, client) = setup_oracles(&env, 1, 1);
+    let other = env.register_contract(None, MockRegistry);
+
+    let report = report_for_registry(&env, &client, &other, oracles.get_unchecked(0), 1);
+    let result = client.try_submit_outcome(&report);
+    assert_contract_error(result, OutcomeError::RegistryNotAllowed);
+
+    client.add_registry(&other);
+    client.submit_outcome(&report);
+    assert_eq!(client.get_outcome(&other, &1u64).registry, other);
+
+    let staker = Address::generate(&env);
+    stake(&env, &other, 1, &staker, 1, 100, 100);
+    client.claim_payout(&other, &1u64, &staker);
+    assert!(client.has_claimed(&other, &1u64, &staker));
+
+    client.remove_registry(&other);
+    let report = report_for_registry(&env, &client, &other, oracles.get_unchecked(0), 2);
+    let result = client.try_submit_outcome(&report);
+    assert_contract_error(result, OutcomeError::RegistryNotAllowed);
+}
+
+#[test]
+fn test_registries_sharing_a_call_id_resolve_independently() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 2, 1);
+    let other = env.register_contract(None, MockRegistry);
+    client.add_registry(&other);
+
+    // Both registries number their first call 1
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, 1, 100, 9000);
+    let (secret, pubkey) = oracles.get_unchecked(1);
+    client.submit_outcome(&SignedOutcome {
+        registry: other.clone(),
+        call_id: 1,
+        outcome: 2,
+        price: 90,
+        timestamp: 9000,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        oracle_pubkey: pubkey,
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &other,
+            &secret,
+            1,
+            2,
+            90,
+            9000,
+            1,
+            VALID_UNTIL,
+        ),
+    });
+    assert_eq!(client.get_outcome(&registry_id, &1u64).outcome, 1);
+    assert_eq!(client.get_outcome(&other, &1u64).outcome, 2);
+    assert_eq!(client.get_finalized_count(), 2);
+
+    // Claims on one registry's call 1 leave the other's untouched
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake(&env, &other, 1, &staker, 2, 40, 40);
+    client.claim_payout(&registry_id, &1u64, &staker);
+    assert!(client.has_claimed(&registry_id, &1u64, &staker));
+    assert!(!client.has_claimed(&other, &1u64, &staker));
+    assert_eq!(client.get_claimable(&other, &1u64, &staker), 40);
+    client.claim_payout(&other, &1u64, &staker);
+    assert!(client.has_claimed(&other, &1u64, &staker));
+}
+
+/// Put call 1 (ending at 1_000) in price-feed mode and return the feed.
+fn setup_price_feed<'a>(
+    env: &'a Env,
+    client: &OutcomeManagerClient,
+    registry_id: &Address,
+) -> MockPriceFeedClient<'a> {
+    MockRegistryClient::new(env, registry_id).set_end_ts(&1u64, &1_000u64);
+    let feed_id = env.register_contract(None, MockPriceFeed);
+    let feed = PriceFeed {
+        oracle: feed_id.clone(),
+        asset: Asset::Other(symbol_short!("XLM")),
+    };
+    let governance = Address::generate(env);
+    client.set_governance(&governance, &REVERSAL_WINDOW);
+    client.set_call_price_feed(&governance, registry_id, &1u64, &Some(feed.clone()));
+    assert_eq!(client.get_call_price_feed(registry_id, &1u64), Some(feed));
+    MockPriceFeedClient::new(env, &feed_id)
+}
+
+#[test]
+fn test_price_feed_call_resolves_without_reports() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    let feed = setup_price_feed(&env, &client, &registry_id);
+
+    // A price observed before end_ts does not count
+    feed.set_price(&150, &999);
+    let result = client.try_resolve_from_price_feed(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeError::PriceUnavailable);
+
+    feed.set_price(&150, &1_200);
+    client.resolve_from_price_feed(&registry_id, &1u64);
+    let outcome = client.get_outcome(&registry_id, &1u64);
+    assert_eq!((outcome.outcome, outcome.price), (1, 150));
+
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    let report = signed_report(&env, &client, &secret, &pubkey, 1, 1, VALID_UNTIL);
+    let result = client.try_submit_outcome(&report);
+    assert_contract_error(result, OutcomeError::ResolvedByPriceFeed);
+}
+
+#[test]
+fn test_stale_price_feed_cannot_resolve() {
+    let env = Env::default();
+    let (registry_id, _oracles, client) = setup_oracles(&env, 1, 1);
+    let feed = setup_price_feed(&env, &client, &registry_id);
+
+    let too_late = 1_001 + client.get_max_submission_delay();
+    feed.set_price(&80, &too_late);
+    let result = client.try_resolve_from_price_feed(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeError::PriceUnavailable);
+
+    let result = client.try_resolve_from_price_feed(&registry_id, &2u64);
+    assert_contract_error(result, OutcomeError::PriceFeedNotSet);
+}
+
+#[test]
+fn test_twap_call_finalizes_at_window_average() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, registry_id, secret, pubkey, client) = setup_single_oracle(&env);
+    MockRegistryClient::new(&env, &registry_id).set_end_ts(&1u64, &1_000u64);
+    client.set_call_twap(&admin, &registry_id, &1u64, &Some(300u64));
+    assert_eq!(client.get_call_twap_window(&registry_id, &1u64), Some(300));
+
+    // Prices before end_ts or after the window do not count
+    assert!(!observe(&env, &client, &secret, &pubkey, 1, 500, 999));
+    assert!(!observe(&env, &client, &secret, &pubkey, 1, 500, 1_301));
+
+    // A spike at end_ts only weighs for its 100s: (80*100 + 120*200) / 300 = 106
+    for (price, ts) in [(80_i128, 1_000u64), (120, 1_100), (120, 1_300)] {
+        assert!(observe(&env, &client, &secret, &pubkey, 1, price, ts));
+    }
+
+    env.ledger().set_timestamp(1_300);
+    let result = client.try_finalize_twap(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeExtError::TwapWindowOpen);
+
+    env.ledger().set_timestamp(1_301);
+    client.finalize_twap(&registry_id, &1u64);
+    let outcome = client.get_outcome(&registry_id, &1u64);
+    assert_eq!((outcome.outcome, outcome.price), (1, 106));
+
+    let report = signed_report(&env, &client, &secret, &pubkey, 1, 1, 2_000);
+    let result = client.try_submit_outcome(&report);
+    assert_contract_error(result, OutcomeExtError::ResolvedByTwap);
+}
+
+#[test]
+fn test_twap_window_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, registry_id, secret, pubkey, client) = setup_single_oracle(&env);
+    MockRegistryClient::new(&env, &registry_id).set_end_ts(&1u64, &1_000u64);
+
+    let result = client.try_set_call_twap(&admin, &registry_id, &1u64, &Some(0u64));
+    assert_contract_error(result, OutcomeError::ZeroTimeWindow);
+
+    let result = client.try_finalize_twap(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeExtError::TwapNotSet);
+
+    // Outside the window an observation is rejected with a typed error
+    client.set_call_twap(&admin, &registry_id, &1u64, &Some(300u64));
+    let result = try_observe(&env, &client, &secret, &pubkey, 1, 100, 999);
+    assert_contract_error(result, OutcomeExtError::ObservationOutsideWindow);
+
+    env.ledger().set_timestamp(1_000);
+    let result = client.try_set_call_twap(&admin, &registry_id, &1u64, &None);
+    assert_contract_error(result, OutcomeError::CallAlreadyEnded);
+}
+
+#[test]
+fn test_resolution_window_cannot_undercut_submission_delay() {
+    let env = Env::default();
+    let (_registry_id, _oracles, client) = setup_oracles(&env, 1, 1);
+    let delay = client.get_max_submission_delay();
+
+    let result = client.try_set_resolution_window(&(delay - 1));
+    assert_contract_error(result, OutcomeError::InvalidResolutionWindow);
+
+    client.set_resolution_window(&delay);
+    assert_eq!(client.get_resolution_window(), delay);
+}
+
+#[test]
+fn test_vote_status_tracks_progress_towards_quorum() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 4, 3);
+    let status = client.get_vote_status(&registry_id, &1u64);
+    assert_eq!((status.quorum, status.votes_needed), (3, 3));
+    assert!(status.votes.is_empty() && status.voters.is_empty());
+
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, 1, 100, 9000);
+    submit_vote(&env, &client, oracles.get_unchecked(1), 1, 2, 90, 9000);
+    submit_vote(&env, &client, oracles.get_unchecked(2), 1, 1, 101, 9000);
+    let status = client.get_vote_status(&registry_id, &1u64);
+    assert_eq!(status.votes, Map::from_array(&env, [(1u32, 2u32), (2, 1)]));
+    assert_eq!(
+        status.voters,
+        Vec::from_array(
+            &env,
+            [
+                oracles.get_unchecked(0).1,
+                oracles.get_unchecked(1).1,
+                oracles.get_unchecked(2).1,
+            ],
+        )
+    );
+    assert_eq!(status.votes_needed, 1);
+}
+
+#[test]
+fn test_batch_submission_skips_invalid_reports() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    let report = |call_id: u64, outcome: u32, nonce: u64| SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome,
+        price: 100,
+        timestamp: 9000,
+        nonce,
+        valid_until: VALID_UNTIL,
+        oracle_pubkey: pubkey.clone(),
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &registry_id,
+            &secret,
+            call_id,
+            outcome,
+            100,
+            9000,
+            nonce,
+            VALID_UNTIL,
+        ),
+    };
+
+    let reports = Vec::from_array(
+        &env,
+        [
+            report(1, 1, 1),
+            report(2, 9, 2),
+            report(1, 2, 3),
+            report(3, 2, 4),
+        ],
+    );
+    let results = client.submit_outcomes(&reports);
+    assert_eq!(
+        results,
+        Vec::from_array(
+            &env,
+            [
+                0,
+                OutcomeError::InvalidOutcome as u32,
+                OutcomeError::AlreadySettled as u32,
+                0,
+            ],
+        )
+    );
+    assert_eq!(client.get_outcome(&registry_id, &1u64).outcome, 1);
+    assert_eq!(client.get_outcome(&registry_id, &3u64).outcome, 2);
+    assert!(client.try_get_outcome(&registry_id, &2u64).is_err());
+
+    let result = client.try_submit_outcomes(&Vec::new(&env));
+    assert_contract_error(result, OutcomeError::EmptyBatch);
+}
+
+#[test]
+fn test_price_far_from_start_price_is_rejected() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    assert_eq!(client.get_max_price_deviation(), None);
+
+    let result = client.try_set_max_price_deviation(&Some(1u32));
+    assert_contract_error(result, OutcomeExtError::InvalidPriceDeviation);
+    client.set_max_price_deviation(&Some(100u32));
+    assert_eq!(client.get_max_price_deviation(), Some(100));
+
+    // 100 * 100 + 1 off a start price of 100: a misplaced decimal point
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    for price in [10_001_i128, 0] {
+        let report = SignedOutcome {
+            registry: registry_id.clone(),
+            call_id: 1,
+            outcome: 1,
+            price,
+            timestamp: 9000,
+            nonce: 1,
+            valid_until: VALID_UNTIL,
+            oracle_pubkey: pubkey.clone(),
+            signature: sign_outcome(
+                &env,
+                &client.address,
+                &registry_id,
+                &secret,
+                1,
+                1,
+                price,
+                9000,
+                1,
+                VALID_UNTIL,
+            ),
+        };
+        let result = client.try_submit_outcome(&report);
+        assert_contract_error(result, OutcomeExtError::PriceDeviationTooLarge);
+    }
+
+    submit_vote(&env, &client, oracles.get_unchecked(0), 1, 1, 10_000, 9000);
+    assert_eq!(client.get_outcome(&registry_id, &1u64).price, 10_000);
+}
+
+// ─── Governance Reversal Tests ─────────────────────────────────────────────────
+
+const REVERSAL_WINDOW: u32 = 100;
+
+fn evidence(env: &Env) -> Bytes {
+    Bytes::from_slice(
+        env,
+        b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    )
+}
+
+#[test]
+fn test_governance_reverses_outcome_in_registry() {
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 0);
+    let result = client.try_reverse_outcome(&registry_id, &1u64, &2u32, &90i128, &evidence(&env));
+    assert_contract_error(result, OutcomeError::GovernanceNotSet);
+
+    client.set_governance(&Address::generate(&env), &REVERSAL_WINDOW);
+    client.reverse_outcome(&registry_id, &1u64, &2u32, &90i128, &evidence(&env));
+
+    let outcome = client.get_outcome(&registry_id, &1u64);
+    assert_eq!((outcome.outcome, outcome.price), (2, 90));
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    assert_eq!(registry.get_override(&1u64), Some((2, 90)));
+}
+
+#[test]
+fn test_claims_follow_reversed_outcome() {
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 0);
+    client.set_governance(&Address::generate(&env), &REVERSAL_WINDOW);
+    let old_winner = Address::generate(&env);
+    let new_winner = Address::generate(&env);
+    stake(&env, &registry_id, 1, &old_winner, 1, 100, 100);
+    stake(&env, &registry_id, 1, &new_winner, 2, 100, 100);
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &old_winner), 200);
+
+    client.reverse_outcome(&registry_id, &1u64, &2u32, &90i128, &evidence(&env));
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &old_winner), 0);
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &new_winner), 200);
+    client.claim_payout(&registry_id, &1u64, &new_winner);
+    assert!(client.has_claimed(&registry_id, &1u64, &new_winner));
+    let result = client.try_claim_payout(&registry_id, &1u64, &old_winner);
+    assert_contract_error(result, OutcomeError::NothingToClaim);
+}
+
+#[test]
+fn test_reverse_outcome_after_window_fails() {
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 0);
+    client.set_governance(&Address::generate(&env), &REVERSAL_WINDOW);
+
+    env.ledger().set_sequence_number(REVERSAL_WINDOW + 1);
+    let result = client.try_reverse_outcome(&registry_id, &1u64, &2u32, &90i128, &evidence(&env));
+    assert_contract_error(result, OutcomeError::ReversalWindowClosed);
+}
+
+#[test]
+fn test_reverse_outcome_after_claim_fails() {
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 0);
+    client.set_governance(&Address::generate(&env), &REVERSAL_WINDOW);
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    client.claim_payout(&registry_id, &1u64, &staker);
+
+    let result = client.try_reverse_outcome(&registry_id, &1u64, &2u32, &90i128, &evidence(&env));
+    assert_contract_error(result, OutcomeError::PayoutStarted);
+}
+
+// ─── Admin Control Tests ───────────────────────────────────────────────────────
+
+#[test]
+fn test_add_remove_oracle() {
+    let env = Env::default();
+    let (_, _, _, _, client) = setup_single_oracle(&env);
+    let (_, new_pubkey) = gen_keypair(&env);
+
+    client.add_oracle(&new_pubkey);
+    assert!(client.is_oracle(&new_pubkey));
+
+    client.remove_oracle(&new_pubkey);
+    assert!(!client.is_oracle(&new_pubkey));
+}
+
+#[test]
+fn test_get_oracles_tracks_add_remove() {
+    let env = Env::default();
+    let (_, _, _, original_pubkey, client) = setup_single_oracle(&env);
+    let (_, second_pubkey) = gen_keypair(&env);
+
+    let initial = client.get_oracles();
+    assert_eq!(initial.len(), 1);
+    assert_eq!(initial.get(0).unwrap(), original_pubkey.clone());
+    assert_eq!(client.get_oracle_count(), 1);
+
+    client.add_oracle(&second_pubkey);
+    let with_second = client.get_oracles();
+    assert_eq!(with_second.len(), 2);
+    assert_eq!(with_second.get(0).unwrap(), original_pubkey);
+    assert_eq!(with_second.get(1).unwrap(), second_pubkey.clone());
+    assert_eq!(client.get_oracle_count(), 2);
+
+    client.remove_oracle(&second_pubkey);
+    assert_eq!(client.get_oracles().len(), 1);
+    assert_eq!(client.get_oracle_count(), 1);
+}
+
+#[test]
+fn test_add_oracle_enforces_max_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let fee_collector = Address::generate(&env);
+    let contract_id = env.register_contract(None, OutcomeManager);
+    let client = OutcomeManagerClient::new(&env, &contract_id);
+
+    let mut oracles = Vec::new(&env);
+    for _ in 0..MAX_ORACLES {
+        let (_, pubkey) = gen_keypair(&env);
+        oracles.push_back(pubkey);
+    }
+
+    let registry_id = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &1u32,
+        &fee_collector,
+        &0u32,
+        &0u32,
+    );
+    let (_, extra_pubkey) = gen_keypair(&env);
+    let result = client.try_add_oracle(&extra_pubkey);
+    assert_contract_error(result, OutcomeError::MaxOraclesReached);
+}
+
+#[test]
+fn test_oracle_info_records_additions_and_labels() {
+    let env = Env::default();
+    env.ledger().set_timestamp(500);
+    let (_, oracles, client) = setup_oracles(&env, 3, 1);
+    let first = oracles.get_unchecked(0).1;
+
+    env.ledger().set_timestamp(800);
+    let (_, added) = gen_keypair(&env);
+    client.add_oracle(&added);
+    let label = Bytes::from_slice(&env, b"reflector-eu-1");
+    client.set_oracle_label(&added, &Some(label.clone()));
+    let info = client.get_oracle_info(&added).unwrap();
+    assert_eq!((info.added_at, info.label), (800, Some(label)));
+    assert_eq!(client.get_oracle_info(&first).unwrap().added_at, 500);
+
+    let long_label = Bytes::from_slice(&env, &[b'x'; 65]);
+    let result = client.try_set_oracle_label(&added, &Some(long_label));
+    assert_contract_error(result, OutcomeExtError::InvalidOracleLabel);
+
+    let page = client.get_oracle_infos(&0u32, &3u32);
+    assert_eq!(page.oracles.len(), 3);
+    assert_eq!(page.oracles.get_unchecked(0).pubkey, first);
+    assert_eq!(page.next_cursor, Some(3));
+    let page = client.get_oracle_infos(&3u32, &3u32);
+    assert_eq!(page.oracles.get_unchecked(0).pubkey, added);
+    assert_eq!(page.next_cursor, None);
+
+    client.remove_oracle(&added);
+    assert_eq!(client.get_oracle_info(&added), None);
+    let result = client.try_set_oracle_label(&added, &None);
+    assert_contract_error(result, OutcomeError::UnauthorizedOracle);
+}
+
+#[test]
+fn test_oracle_submission_history_lets_daemons_resume() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 2, 2);
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    let other = oracles.get_unchecked(1).1;
+    assert_eq!(client.get_oracle_nonce(&pubkey), None);
+
+    for (call_id, nonce) in [(1u64, 1u64), (2, 2), (3, 5)] {
+        client.submit_outcome(&signed_report(
+            &env,
+            &client,
+            &secret,
+            &pubkey,
+            call_id,
+            nonce,
+            VALID_UNTIL,
+        ));
+    }
+    assert!(client.has_submitted(&registry_id, &2u64, &pubkey));
+    assert!(!client.has_submitted(&registry_id, &2u64, &other));
+    assert!(!client.has_submitted(&registry_id, &4u64, &pubkey));
+    assert_eq!(client.get_oracle_nonce(&pubkey), Some(5));
+
+    let page = client.get_oracle_submissions(&pubkey, &0u32, &2u32);
+    assert_eq!(page.submissions.len(), 2);
+    assert_eq!(page.submissions.get_unchecked(1).call_id, 2);
+    assert_eq!(page.submissions.get_unchecked(0).nonce, 5);
+    assert_eq!(page.next_cursor, Some(2));
+    let page = client.get_oracle_submissions(&pubkey, &2u32, &2u32);
+    assert_eq!(page.submissions.get_unchecked(0).call_id, 1);
+    assert_eq!(page.next_cursor, None);
+    assert!(client
+        .get_oracle_submissions(&other, &0u32, &10u32)
+        .submissions
+        .is_empty());
+}
+
+#[test]
+fn test_finalized_outcomes_are_paginated_in_order() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    for (nonce, call_id) in [(1u64, 7u64), (2, 3), (3, 5)] {
+        client.submit_outcome(&signed_report(
+            &env,
+            &client,
+            &secret,
+            &pubkey,
+            call_id,
+            nonce,
+            VALID_UNTIL,
+        ));
+    }
+    assert_eq!(client.get_finalized_count(), 3);
+
+    let page = client.get_finalized_outcomes(&0u32, &2u32);
+    assert_eq!(page.outcomes.len(), 2);
+    assert_eq!(
+        page.outcomes.get_unchecked(0),
+        client.get_outcome(&registry_id, &7u64)
+    );
+    assert_eq!(page.outcomes.get_unchecked(1).call_id, 3);
+    assert_eq!(page.next_cursor, Some(2));
+    let page = client.get_finalized_outcomes(&2u32, &2u32);
+    assert_eq!(page.outcomes.get_unchecked(0).call_id, 5);
+    assert_eq!(page.next_cursor, None);
+    assert!(client
+        .get_finalized_outcomes(&9u32, &2u32)
+        .outcomes
+        .is_empty());
+}
+
+#[test]
+fn test_weakening_changes_wait_out_param_timelock() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (_, oracles, client) = setup_oracles(&env, 3, 2);
+    let removed = oracles.get_unchecked(2).1;
+    let governance = Address::generate(&env);
+    client.set_governance(&governance, &REVERSAL_WINDOW);
+
+    // Without a timelock every change is immediate
+    client.set_quorum(&1);
+    client.set_quorum(&2);
+    client.set_param_timelock(&100);
+
+    // Raises stay immediate; reductions and removals must be queued
+    client.set_quorum(&3);
+    client.set_param_timelock(&200);
+    assert_contract_error(
+        client.try_set_quorum(&2),
+        OutcomeExtError::ParamChangeTimelocked,
+    );
+    let result = client.try_remove_oracle(&removed);
+    assert_contract_error(result, OutcomeExtError::ParamChangeTimelocked);
+    let result = client.try_set_param_timelock(&0);
+    assert_contract_error(result, OutcomeExtError::ParamChangeTimelocked);
+
+    let lower = client.queue_param_change(&ParamChange::Quorum(2));
+    let remove = client.queue_param_change(&ParamChange::RemoveOracle(removed.clone()));
+    assert_eq!(
+        client.get_pending_param_changes().get(lower).unwrap().eta,
+        1_200
+    );
+    let result = client.try_execute_param_change(&lower);
+    assert_contract_error(result, OutcomeExtError::ParamTimelockActive);
+
+    // Governance can veto a queued change
+    client.cancel_param_change(&governance, &remove);
+    let result = client.try_execute_param_change(&remove);
+    assert_contract_error(result, OutcomeExtError::ParamChangeNotQueued);
+
+    env.ledger().set_timestamp(1_200);
+    client.execute_param_change(&lower);
+    assert_eq!(client.get_quorum(), 2);
+    assert_eq!(client.get_oracle_count(), 3);
+    assert!(client.get_pending_param_changes().is_empty());
+}
+
+#[test]
+fn test_preview_message_is_what_oracles_sign() {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    let msg = client.preview_message(
+        &registry_id,
+        &1u64,
+        &1u32,
+        &100i128,
+        &9000u64,
+        &1u64,
+        &VALID_UNTIL,
+    );
+
+    let mut msg_bytes = [0u8; 256];
+    let msg_len = msg.len() as usize;
+    msg.copy_into_slice(&mut msg_bytes[..msg_len]);
+    let signature = SigningKey::from_bytes(&secret.to_array()).sign(&msg_bytes[..msg_len]);
+
+    client.submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id: 1,
+        outcome: 1,
+        price: 100,
+        timestamp: 9000,
+        oracle_pubkey: pubkey,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: BytesN::from_array(&env, &signature.to_bytes()),
+    });
+    assert_eq!(client.get_outcome(&registry_id, &1u64).outcome, 1);
+}
+
+#[test]
+fn test_admin_signer_set_config() {
+    let env = Env::default();
+    let (_, _, client) = setup_oracles(&env, 3, 2);
+    let signers = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    let result = client.try_set_admin_threshold(&1);
+    assert_contract_error(result, OutcomeExtError::AdminSignersNotSet);
+    for (signers, threshold) in [
+        (signers.clone(), 3u32),
+        (signers.clone(), 0),
+        (Vec::new(&env), 1),
+        (
+            soroban_sdk::vec![&env, signers.get_unchecked(0), signers.get_unchecked(0)],
+            1,
+        ),
+    ] {
+        let result = client.try_set_admin_signers(&signers, &threshold);
+        assert_contract_error(result, OutcomeExtError::InvalidAdminSigners);
+    }
+
+    client.set_admin_signers(&signers, &2);
+    client.set_admin_threshold(&1);
+    let admin_signers = client.get_admin_signers().unwrap();
+    assert_eq!(
+        (admin_signers.signers, admin_signers.threshold),
+        (signers, 1)
+    );
+
+    // Handing admin rights to one address drops the signer set
+    client.set_admin(&Address::generate(&env));
+    assert_eq!(client.get_admin_signers(), None);
+}
+
+#[test]
+fn test_admin_calls_need_threshold_of_signers() {
+    use soroban_sdk::auth::{Context, ContractContext};
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    let (_, _, client) = setup_oracles(&env, 3, 2);
+    let (a, b, c) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    client.set_admin_signers(
+        &soroban_sdk::vec![&env, a.clone(), b.clone(), c.clone()],
+        &2,
+    );
+
+    let payload = BytesN::from_array(&env, &[7; 32]);
+    let context = |contract: &Address| {
+        soroban_sdk::vec![
+            &env,
+            Context::Contract(ContractContext {
+                contract: contract.clone(),
+                fn_name: soroban_sdk::Symbol::new(&env, "set_quorum"),
+                args: soroban_sdk::vec![&env, 1u32.into_val(&env)],
+            })
+        ]
+    };
+    let check = |signers: Vec<Address>, contexts: &Vec<Context>| {
+        env.try_invoke_contract_check_auth::<soroban_sdk::Error>(
+            &client.address,
+            &payload,
+            signers.into_val(&env),
+            contexts,
+        )
+    };
+    let own = context(&client.address);
+
+    assert_eq!(
+        check(soroban_sdk::vec![&env, a.clone(), c.clone()], &own),
+        Ok(())
+    );
+    let result = check(soroban_sdk::vec![&env, a.clone(), a.clone()], &own);
+    assert_eq!(
+        result,
+        Err(Ok(OutcomeExtError::InsufficientAdminSignatures.into()))
+    );
+    let outsider = Address::generate(&env);
+    let result = check(soroban_sdk::vec![&env, a.clone(), outsider], &own);
+    assert_eq!(result, Err(Ok(OutcomeError::Unauthorized.into())));
+
+    // The signers cannot spend the contract's own authority elsewhere
+    let elsewhere = context(&Address::generate(&env));
+    let result = check(soroban_sdk::vec![&env, a, b], &elsewhere);
+    assert_eq!(result, Err(Ok(OutcomeError::Unauthorized.into())));
+}
+
+#[test]
+fn test_oracles_earn_rewards_for_matching_votes() {
     let env = Env::default();
-    let (_, _, _, _, client) = setup_single_oracle(&env);
-    let (_, new_pubkey) = gen_keypair(&env);
+    let (registry_id, oracles, client) = setup_oracles(&env, 3, 2);
+    let vote = |oracle: u32, call_id: u64, outcome: u32, nonce: u64| {
+        let (secret, pubkey) = oracles.get_unchecked(oracle);
+        client.submit_outcome(&SignedOutcome {
+            registry: registry_id.clone(),
+            call_id,
+            outcome,
+            price: 100,
+            timestamp: 9000,
+            nonce,
+            valid_until: VALID_UNTIL,
+            oracle_pubkey: pubkey,
+            signature: sign_outcome(
+                &env,
+                &client.address,
+                &registry_id,
+                &secret,
+                call_id,
+                outcome,
+                100,
+                9000,
+                nonce,
+                VALID_UNTIL,
+            ),
+        });
+    };
 
-    client.add_oracle(&new_pubkey);
-    assert!(client.is_oracle(&new_pubkey));
+    let reward_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.set_oracle_reward(&reward_token, &10);
+    let funder = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &reward_token).mint(&funder, &25);
+    client.fund_oracle_rewards(&funder, &25);
+
+    // The dissenting oracle earns nothing
+    vote(0, 1, 1, 1);
+    vote(2, 1, 2, 1);
+    vote(1, 1, 1, 1);
+    let (first, second, dissenter) = (
+        oracles.get_unchecked(0).1,
+        oracles.get_unchecked(1).1,
+        oracles.get_unchecked(2).1,
+    );
+    assert_eq!(client.get_oracle_rewards(&first), 10);
+    assert_eq!(client.get_oracle_rewards(&second), 10);
+    assert_eq!(client.get_oracle_rewards(&dissenter), 0);
+    assert_eq!(client.get_oracle_reward_budget(), 5);
+
+    // Votes beyond the budget go unrewarded
+    vote(0, 2, 1, 2);
+    vote(1, 2, 1, 2);
+    assert_eq!(client.get_oracle_rewards(&first), 10);
+
+    let result = client.try_claim_oracle_rewards(&first);
+    assert_contract_error(result, OutcomeExtError::PayoutAddressNotSet);
+    let payout = Address::generate(&env);
+    client.set_oracle_payout_address(&first, &Some(payout.clone()));
+    assert_eq!(client.claim_oracle_rewards(&first), 10);
+    assert_eq!(token::Client::new(&env, &reward_token).balance(&payout), 10);
+    let result = client.try_claim_oracle_rewards(&first);
+    assert_contract_error(result, OutcomeError::NothingToClaim);
 
-    client.remove_oracle(&new_pubkey);
-    assert!(!client.is_oracle(&new_pubkey));
+    let other_token = Address::generate(&env);
+    let result = client.try_set_oracle_reward(&other_token, &10);
+    assert_contract_error(result, OutcomeExtError::InvalidOracleReward);
 }
 
 #[test]
-fn test_get_oracles_tracks_add_remove() {
+fn test_staged_oracle_epoch_takes_over_at_activation_ledger() {
     let env = Env::default();
-    let (_, _, _, original_pubkey, client) = setup_single_oracle(&env);
-    let (_, second_pubkey) = gen_keypair(&env);
-
-    let initial = client.get_oracles();
-    assert_eq!(initial.len(), 1);
-    assert_eq!(initial.get(0).unwrap(), original_pubkey.clone());
-    assert_eq!(client.get_oracle_count(), 1);
+    let (registry_id, oracles, client) = setup_oracles(&env, 2, 1);
+    let governance = Address::generate(&env);
+    client.set_governance(&governance, &REVERSAL_WINDOW);
+
+    let (next_secret, next_pubkey) = gen_keypair(&env);
+    let (_, other_pubkey) = gen_keypair(&env);
+    let epoch = StagedEpoch {
+        oracles: Vec::from_array(&env, [next_pubkey.clone(), other_pubkey]),
+        quorum: 2,
+        activation_ledger: 10,
+    };
+    client.stage_oracle_epoch(&governance, &Some(epoch.clone()));
+    assert_eq!(client.get_staged_epoch(), Some(epoch.clone()));
+    assert_eq!(client.get_oracle_epoch(), 0);
+    assert!(client.is_oracle(&oracles.get_unchecked(0).1));
 
-    client.add_oracle(&second_pubkey);
-    let with_second = client.get_oracles();
-    assert_eq!(with_second.len(), 2);
-    assert_eq!(with_second.get(0).unwrap(), original_pubkey);
-    assert_eq!(with_second.get(1).unwrap(), second_pubkey.clone());
-    assert_eq!(client.get_oracle_count(), 2);
+    env.ledger().set_sequence_number(10);
+    assert_eq!(client.get_oracles(), epoch.oracles);
+    assert_eq!(client.get_quorum(), 2);
+    assert_eq!(client.get_oracle_epoch(), 1);
+    assert_eq!(client.get_staged_epoch(), None);
 
-    client.remove_oracle(&second_pubkey);
-    assert_eq!(client.get_oracles().len(), 1);
-    assert_eq!(client.get_oracle_count(), 1);
+    let (old_secret, old_pubkey) = oracles.get_unchecked(0);
+    let report = signed_report(&env, &client, &old_secret, &old_pubkey, 1, 1, VALID_UNTIL);
+    let result = client.try_submit_outcome(&report);
+    assert_contract_error(result, OutcomeError::UnauthorizedOracle);
+    submit_vote(&env, &client, (next_secret, next_pubkey), 1, 1, 100, 9000);
+    assert!(client.try_get_outcome(&registry_id, &1u64).is_err());
 }
 
 #[test]
-fn test_add_oracle_enforces_max_limit() {
+fn test_stage_oracle_epoch_rejects_past_activation() {
     let env = Env::default();
-    env.mock_all_auths();
-    let admin = Address::generate(&env);
-    let fee_collector = Address::generate(&env);
-    let contract_id = env.register_contract(None, OutcomeManager);
-    let client = OutcomeManagerClient::new(&env, &contract_id);
+    let (_, oracles, client) = setup_oracles(&env, 1, 1);
+    env.ledger().set_sequence_number(5);
+    let governance = Address::generate(&env);
+    client.set_governance(&governance, &REVERSAL_WINDOW);
+    let epoch = StagedEpoch {
+        oracles: Vec::from_array(&env, [oracles.get_unchecked(0).1]),
+        quorum: 1,
+        activation_ledger: 5,
+    };
+    let result = client.try_stage_oracle_epoch(&governance, &Some(epoch));
+    assert_contract_error(result, OutcomeError::InvalidEpochActivation);
+}
 
-    let mut oracles = Vec::new(&env);
-    for _ in 0..MAX_ORACLES {
-        let (_, pubkey) = gen_keypair(&env);
-        oracles.push_back(pubkey);
-    }
+#[test]
+fn test_oracle_epoch_waits_for_param_timelock() {
+    let env = Env::default();
+    let (_, oracles, client) = setup_oracles(&env, 2, 1);
+    let governance = Address::generate(&env);
+    client.set_governance(&governance, &REVERSAL_WINDOW);
+    env.ledger().set_timestamp(1_000);
+    client.set_param_timelock(&100);
 
-    client.initialize(&admin, &oracles, &1u32, &fee_collector, &0u32, &0u64);
-    let (_, extra_pubkey) = gen_keypair(&env);
-    let result = client.try_add_oracle(&extra_pubkey);
-    assert_contract_error(result, OutcomeError::MaxOraclesReached);
+    let (_, pubkey) = gen_keypair(&env);
+    let epoch = StagedEpoch {
+        oracles: Vec::from_array(&env, [pubkey]),
+        quorum: 1,
+        activation_ledger: 10,
+    };
+    let result = client.try_stage_oracle_epoch(&governance, &Some(epoch.clone()));
+    assert_contract_error(result, OutcomeExtError::ParamChangeTimelocked);
+
+    let id = client.queue_param_change(&ParamChange::OracleEpoch(epoch.clone()));
+    let result = client.try_execute_param_change(&id);
+    assert_contract_error(result, OutcomeExtError::ParamTimelockActive);
+    assert_eq!(client.get_staged_epoch(), None);
+
+    env.ledger().set_timestamp(1_100);
+    client.execute_param_change(&id);
+    assert_eq!(client.get_staged_epoch(), Some(epoch));
+    assert!(client.is_oracle(&oracles.get_unchecked(0).1));
+
+    // Cancelling a staged epoch needs no timelock
+    client.stage_oracle_epoch(&governance, &None);
+    assert_eq!(client.get_staged_epoch(), None);
 }
 
 #[test]
 fn test_set_quorum() {
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::IntoVal;
+
     let env = Env::default();
     let (_, _, _, _, client) = setup_single_oracle(&env);
 
     // Add a second oracle so quorum=2 is valid
     let (_, pubkey2) = gen_keypair(&env);
     client.add_oracle(&pubkey2);
+    let last = env.events().all().last().unwrap();
+    let topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("oracle"), symbol_short!("added")).into_val(&env);
+    assert_eq!(last.1, topics);
+    let (oracle, version): (BytesN<32>, u32) = last.2.into_val(&env);
+    assert_eq!((oracle, version), (pubkey2, 1));
 
     client.set_quorum(&2u32);
+    let last = env.events().all().last().unwrap();
+    let topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("config"), symbol_short!("quorum")).into_val(&env);
+    assert_eq!(last.1, topics);
+    let (quorum, version): (u32, u32) = last.2.into_val(&env);
+    assert_eq!((quorum, version), (2, 2));
     assert_eq!(client.get_quorum(), 2);
+    assert_eq!(client.get_config_version(), 2);
 }
 
 #[test]
 fn test_set_admin() {
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::IntoVal;
+
     let env = Env::default();
-    let (_, _, _, _, client) = setup_single_oracle(&env);
+    let (admin, _, _, _, client) = setup_single_oracle(&env);
     let new_admin = Address::generate(&env);
 
     client.set_admin(&new_admin);
-    // If it doesn't panic, it's successful (auth handled by mock_all_auths)
+    let last = env.events().all().last().unwrap();
+    let change: (Address, Address, u32) = last.2.into_val(&env);
+    assert_eq!(change, (admin, new_admin, 1));
 }
 
 // ─── Payout Math Tests ─────────────────────────────────────────────────────────
@@ -573,8 +2552,8 @@ fn test_payout_calculation_single_winner_takes_all() {
 #[test]
 fn test_get_outcome_unsettled_panics() {
     let env = Env::default();
-    let (_, _, _, _, client) = setup_single_oracle(&env);
-    let result = client.try_get_outcome(&999u64);
+    let (_, registry_id, _, _, client) = setup_single_oracle(&env);
+    let result = client.try_get_outcome(&registry_id, &999u64);
     assert_contract_error(result, OutcomeError::CallNotSettled);
 }
 
@@ -592,25 +2571,42 @@ fn setup_with_fee(env: &Env, fee_bps: u32) -> (Address, Address, OutcomeManagerC
 
     let mut oracles = Vec::new(env);
     oracles.push_back(oracle_pubkey.clone());
-    client.initialize(&admin, &oracles, &1u32, &fee_collector, &fee_bps, &0u64);
-
     let registry_id = env.register_contract(None, MockRegistry);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &1u32,
+        &fee_collector,
+        &fee_bps,
+        &0u32,
+    );
 
     // Settle call_id=1
     let call_id = 1u64;
-    let sig = sign_outcome(env, &oracle_secret, call_id, 1, 100, 9000);
-    client.submit_outcome(
+    let sig = sign_outcome(
+        env,
+        &client.address,
         &registry_id,
-        &SignedOutcome {
-            call_id,
-            outcome: 1,
-            price: 100,
-            timestamp: 9000,
-            oracle_pubkey,
-            signature: sig,
-        },
-        &0u64,
+        &oracle_secret,
+        call_id,
+        1,
+        100,
+        9000,
+        1,
+        VALID_UNTIL,
     );
+    client.submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome: 1,
+        price: 100,
+        timestamp: 9000,
+        oracle_pubkey,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sig,
+    });
 
     (fee_collector, registry_id, client)
 }
@@ -627,11 +2623,125 @@ fn test_fee_deducted_from_payout() {
     let env = Env::default();
     let (_, registry_id, client) = setup_with_fee(&env, 500);
     let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 100);
 
-    client.claim_payout(&registry_id, &1u64, &staker, &100i128, &100i128, &100i128);
+    client.claim_payout(&registry_id, &1u64, &staker);
     // If no panic, payout was computed and released correctly
 }
 
+#[test]
+fn test_streak_bonus_is_funded_from_fee() {
+    // fee_bps = 500: fee 5, payout 195 before the bonus
+    // 2% streak bonus = 195 * 200 / 10000 = 3, leaving a fee of 2
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 500);
+    MockRegistryClient::new(&env, &registry_id).set_streak_bonus(&200);
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 100);
+
+    client.claim_payout(&registry_id, &1u64, &staker);
+    let last = env.events().all().last().unwrap();
+    let (_, _, payout): (u64, Address, i128) = last.2.into_val(&env);
+    assert_eq!(payout, 198);
+}
+
+#[test]
+fn test_streak_bonus_capped_at_fee() {
+    // A 100% bonus can only move the whole 5 fee to the staker
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 500);
+    MockRegistryClient::new(&env, &registry_id).set_streak_bonus(&10_000);
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 100);
+
+    client.claim_payout(&registry_id, &1u64, &staker);
+    let last = env.events().all().last().unwrap();
+    let (_, _, payout): (u64, Address, i128) = last.2.into_val(&env);
+    assert_eq!(payout, 200);
+}
+
+#[test]
+fn test_get_claimable_matches_claimed_payout() {
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 500);
+    MockRegistryClient::new(&env, &registry_id).set_streak_bonus(&200);
+    let staker = Address::generate(&env);
+    let loser = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake(&env, &registry_id, 1, &loser, 2, 100, 100);
+
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &staker), 198);
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &loser), 0);
+    assert_eq!(client.get_claimable(&registry_id, &2u64, &staker), 0);
+
+    client.claim_payout(&registry_id, &1u64, &staker);
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &staker), 0);
+}
+
+#[test]
+fn test_expire_claims_sweeps_unclaimed_winnings() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    client.set_claim_window(&Some(100u32));
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    stake(&env, &registry_id, 1, &early, 1, 100, 100);
+    stake(&env, &registry_id, 1, &late, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 200);
+
+    client.submit_outcome(&signed_report(
+        &env,
+        &client,
+        &secret,
+        &pubkey,
+        1,
+        1,
+        VALID_UNTIL,
+    ));
+    let deadline = client.get_claim_deadline(&registry_id, &1u64).unwrap();
+    assert_eq!(deadline, env.ledger().sequence() + 100);
+    client.claim_payout(&registry_id, &1u64, &early);
+    assert_contract_error(
+        client.try_expire_claims(&registry_id, &1u64, &0u32, &10u32),
+        OutcomeExtError::ClaimWindowOpen,
+    );
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number = deadline + 1);
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &late), 0);
+    assert_contract_error(
+        client.try_claim_payout(&registry_id, &1u64, &late),
+        OutcomeExtError::ClaimExpired,
+    );
+    assert_contract_error(
+        client.try_batch_claim_payouts(&registry_id, &1u64, &soroban_sdk::vec![&env, late.clone()]),
+        OutcomeExtError::ClaimExpired,
+    );
+    assert_eq!(
+        client.expire_claims(&registry_id, &1u64, &0u32, &1u32),
+        Some(1)
+    );
+    assert_eq!(
+        client.expire_claims(&registry_id, &1u64, &1u32, &10u32),
+        None
+    );
+    assert!(client.has_claimed(&registry_id, &1u64, &late));
+    assert_eq!(
+        MockRegistryClient::new(&env, &registry_id).get_expired(&1u64),
+        200
+    );
+}
+
 #[test]
 fn test_zero_fee_full_payout() {
     // fee_bps = 0: payout = staker_stake + staker_stake * losing / winning
@@ -639,8 +2749,11 @@ fn test_zero_fee_full_payout() {
     let env = Env::default();
     let (_, registry_id, client) = setup_with_fee(&env, 0);
     let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 50, 50);
+    stake(&env, &registry_id, 1, &Address::generate(&env), 1, 50, 50);
+    stake_loser(&env, &registry_id, 1, 100);
 
-    client.claim_payout(&registry_id, &1u64, &staker, &50i128, &100i128, &100i128);
+    client.claim_payout(&registry_id, &1u64, &staker);
 }
 
 #[test]
@@ -663,6 +2776,111 @@ fn test_fee_math_correctness() {
     assert_eq!(payout, 138);
 }
 
+#[test]
+fn test_outcome_and_claim_live_in_persistent_storage() {
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 0);
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 100);
+    client.claim_payout(&registry_id, &1u64, &staker);
+
+    env.as_contract(&client.address, || {
+        let persistent = env.storage().persistent();
+        let outcome_key = PersistentKey::FinalOutcome(registry_id.clone(), 1);
+        let claimed_key = PersistentKey::Claimed(registry_id.clone(), 1, staker.clone());
+        assert_eq!(persistent.get_ttl(&outcome_key), PERSISTENT_TTL_LEDGERS);
+        assert_eq!(persistent.get_ttl(&claimed_key), PERSISTENT_TTL_LEDGERS);
+    });
+    assert!(client.has_claimed(&registry_id, &1u64, &staker));
+}
+
+#[test]
+fn test_claim_all_aggregates_per_token() {
+    let env = Env::default();
+    let (registry_id, oracles, client) = setup_oracles(&env, 1, 1);
+    let other = env.register_contract(None, MockRegistry);
+    client.add_registry(&other);
+    let (secret, pubkey) = oracles.get_unchecked(0);
+    let staker = Address::generate(&env);
+
+    // Calls 1 and 2 settle in the default registry, call 3 in `other`
+    client.submit_outcome(&report_for_registry(
+        &env,
+        &client,
+        &other,
+        (secret.clone(), pubkey.clone()),
+        3,
+    ));
+    client.submit_outcome(&signed_report(
+        &env,
+        &client,
+        &secret,
+        &pubkey,
+        1,
+        2,
+        VALID_UNTIL,
+    ));
+    client.submit_outcome(&signed_report(
+        &env,
+        &client,
+        &secret,
+        &pubkey,
+        2,
+        3,
+        VALID_UNTIL,
+    ));
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake(&env, &registry_id, 2, &staker, 1, 50, 50);
+    stake(&env, &other, 3, &staker, 1, 30, 30);
+
+    let calls = soroban_sdk::vec![
+        &env,
+        (registry_id.clone(), 1u64),
+        (registry_id.clone(), 2),
+        (other.clone(), 3),
+    ];
+    let totals = client.claim_all(&staker, &calls);
+    assert_eq!(
+        totals,
+        soroban_sdk::map![
+            &env,
+            (registry_id.clone(), 150i128),
+            (other.clone(), 30i128)
+        ]
+    );
+    assert!(client.has_claimed(&registry_id, &2u64, &staker));
+
+    // A call already claimed reverts the whole batch
+    let result = client.try_claim_all(&staker, &soroban_sdk::vec![&env, (other.clone(), 3u64)]);
+    assert_contract_error(result, OutcomeError::AlreadyClaimed);
+}
+
+#[test]
+fn test_set_fee_config() {
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 500);
+    let treasury = Address::generate(&env);
+
+    client.set_fee_bps(&250);
+    client.set_fee_collector(&treasury);
+    assert_eq!(client.get_fee_bps(), 250);
+    assert_eq!(client.get_fee_collector(), treasury);
+    assert_contract_error(client.try_set_fee_bps(&10_001), OutcomeError::InvalidFeeBps);
+
+    // fee 2.5% of the 100 prize share: payout 100 + 98
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 100);
+    assert_eq!(client.get_claimable(&registry_id, &1u64, &staker), 198);
+
+    // Each allowlisted registry's mock reports 7 collected
+    let token = Address::generate(&env);
+    assert_eq!(client.get_accrued_fees(&token), 7);
+    client.add_registry(&env.register_contract(None, MockRegistry));
+    assert_eq!(client.get_accrued_fees(&token), 14);
+}
+
 #[test]
 fn test_fee_goes_to_correct_address() {
     // fee_bps = 1000 (10%), staker_stake = total_winning = total_losing = 100
@@ -671,13 +2889,106 @@ fn test_fee_goes_to_correct_address() {
     let env = Env::default();
     let (fee_collector, registry_id, client) = setup_with_fee(&env, 1000);
     let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 100);
 
     // Should not panic; MockRegistry records calls but we verify no panic = correct flow
-    client.claim_payout(&registry_id, &1u64, &staker, &100i128, &100i128, &100i128);
+    client.claim_payout(&registry_id, &1u64, &staker);
     // fee_collector address was set during setup_with_fee; contract uses it internally
     let _ = fee_collector; // referenced to confirm it was set
 }
 
+#[test]
+fn test_draw_refunds_stake_minus_fee() {
+    // fee_bps = 500 (5%), DRAW: payout = 100 - 100 * 500 / 10000 = 95
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let fee_collector = Address::generate(&env);
+    let (oracle_secret, oracle_pubkey) = gen_keypair(&env);
+
+    let contract_id = env.register_contract(None, OutcomeManager);
+    let client = OutcomeManagerClient::new(&env, &contract_id);
+    let mut oracles = Vec::new(&env);
+    oracles.push_back(oracle_pubkey.clone());
+    let registry_id = env.register_contract(None, MockRegistry);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &1u32,
+        &fee_collector,
+        &500u32,
+        &0u32,
+    );
+
+    let call_id = 5u64;
+    let draw = backit_shared::OUTCOME_DRAW;
+    client.submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome: draw,
+        price: 100,
+        timestamp: 9000,
+        oracle_pubkey,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &registry_id,
+            &oracle_secret,
+            call_id,
+            draw,
+            100,
+            9000,
+            1,
+            VALID_UNTIL,
+        ),
+    });
+    assert_eq!(client.get_outcome(&registry_id, &call_id).outcome, draw);
+
+    // The refund covers the staker's stake on every outcome
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, call_id, &staker, 1, 60, 60);
+    stake(&env, &registry_id, call_id, &staker, 2, 40, 40);
+    client.claim_payout(&registry_id, &call_id, &staker);
+
+    let last = env.events().all().last().unwrap();
+    let (_, _, payout): (u64, Address, i128) = last.2.into_val(&env);
+    assert_eq!(payout, 95);
+}
+
+#[test]
+fn test_weighted_payout_favours_early_staker() {
+    // fee_bps = 0, equal raw stakes of 100; early weight 150, late weight 100
+    // early payout = 100 + 150 * 250 / 250 = 250
+    // late payout  = 100 + 100 * 250 / 250 = 200
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    let (_, registry_id, client) = setup_with_fee(&env, 0);
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    stake(&env, &registry_id, 1, &early, 1, 100, 150);
+    stake(&env, &registry_id, 1, &late, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 250);
+
+    client.claim_payout(&registry_id, &1u64, &early);
+    let last = env.events().all().last().unwrap();
+    let (_, _, payout): (u64, Address, i128) = last.2.into_val(&env);
+    assert_eq!(payout, 250);
+
+    client.claim_payout(&registry_id, &1u64, &late);
+    let last = env.events().all().last().unwrap();
+    let (_, _, payout): (u64, Address, i128) = last.2.into_val(&env);
+    assert_eq!(payout, 200);
+}
+
 #[test]
 fn test_invalid_fee_bps_panics() {
     let env = Env::default();
@@ -691,7 +3002,16 @@ fn test_invalid_fee_bps_panics() {
 
     let mut oracles = Vec::new(&env);
     oracles.push_back(pubkey);
-    let result = client.try_initialize(&admin, &oracles, &1u32, &fee_collector, &10001u32, &0u64);
+    let registry_id = Address::generate(&env);
+    let result = client.try_initialize(
+        &admin,
+        &registry_id,
+        &oracles,
+        &1u32,
+        &fee_collector,
+        &10001u32,
+        &0u32,
+    );
     assert_contract_error(result, OutcomeError::InvalidFeeBps);
 }
 
@@ -711,17 +3031,17 @@ fn test_batch_claim_payouts_three_stakers() {
     stakers.push_back(staker2.clone());
     stakers.push_back(staker3.clone());
 
-    let mut stakes = Vec::new(&env);
-    stakes.push_back(50_i128);
-    stakes.push_back(30_i128);
-    stakes.push_back(20_i128);
+    stake(&env, &registry_id, 1, &staker1, 1, 50, 50);
+    stake(&env, &registry_id, 1, &staker2, 1, 30, 30);
+    stake(&env, &registry_id, 1, &staker3, 1, 20, 20);
+    stake_loser(&env, &registry_id, 1, 100);
 
     // Should not panic — all three processed in one tx
-    client.batch_claim_payouts(&registry_id, &1u64, &stakers, &stakes, &100_i128, &100_i128);
+    client.batch_claim_payouts(&registry_id, &1u64, &stakers);
 
-    assert!(client.has_claimed(&1u64, &staker1));
-    assert!(client.has_claimed(&1u64, &staker2));
-    assert!(client.has_claimed(&1u64, &staker3));
+    assert!(client.has_claimed(&registry_id, &1u64, &staker1));
+    assert!(client.has_claimed(&registry_id, &1u64, &staker2));
+    assert!(client.has_claimed(&registry_id, &1u64, &staker3));
 }
 
 #[test]
@@ -734,14 +3054,13 @@ fn test_batch_claim_panics_on_duplicate_staker() {
     // First batch — marks staker as claimed
     let mut stakers = Vec::new(&env);
     stakers.push_back(staker.clone());
-    let mut stakes = Vec::new(&env);
-    stakes.push_back(50_i128);
+    stake(&env, &registry_id, 1, &staker, 1, 50, 50);
+    stake_loser(&env, &registry_id, 1, 50);
 
-    client.batch_claim_payouts(&registry_id, &1u64, &stakers, &stakes, &50_i128, &50_i128);
+    client.batch_claim_payouts(&registry_id, &1u64, &stakers);
 
     // Second batch with same staker — must panic
-    let result =
-        client.try_batch_claim_payouts(&registry_id, &1u64, &stakers, &stakes, &50_i128, &50_i128);
+    let result = client.try_batch_claim_payouts(&registry_id, &1u64, &stakers);
     assert_contract_error(result, OutcomeError::AlreadyClaimed);
 }
 
@@ -751,36 +3070,11 @@ fn test_batch_claim_panics_on_empty_batch() {
     let (_, registry_id, client) = setup_with_fee(&env, 0);
 
     let stakers: Vec<Address> = Vec::new(&env);
-    let stakes: Vec<i128> = Vec::new(&env);
 
-    let result = client.try_batch_claim_payouts(
-        &registry_id,
-        &1u64,
-        &stakers,
-        &stakes,
-        &100_i128,
-        &100_i128,
-    );
+    let result = client.try_batch_claim_payouts(&registry_id, &1u64, &stakers);
     assert_contract_error(result, OutcomeError::EmptyBatch);
 }
 
-#[test]
-fn test_batch_claim_panics_on_length_mismatch() {
-    let env = Env::default();
-    let (_, registry_id, client) = setup_with_fee(&env, 0);
-
-    let mut stakers = Vec::new(&env);
-    stakers.push_back(Address::generate(&env));
-    stakers.push_back(Address::generate(&env));
-
-    let mut stakes = Vec::new(&env);
-    stakes.push_back(50_i128); // one fewer than stakers
-
-    let result =
-        client.try_batch_claim_payouts(&registry_id, &1u64, &stakers, &stakes, &100_i128, &50_i128);
-    assert_contract_error(result, OutcomeError::LengthMismatch);
-}
-
 #[test]
 fn test_batch_claim_panics_on_unsettled_call() {
     let env = Env::default();
@@ -788,18 +3082,9 @@ fn test_batch_claim_panics_on_unsettled_call() {
 
     let mut stakers = Vec::new(&env);
     stakers.push_back(Address::generate(&env));
-    let mut stakes = Vec::new(&env);
-    stakes.push_back(50_i128);
 
     // call_id=999 was never finalized
-    let result = client.try_batch_claim_payouts(
-        &registry_id,
-        &999u64,
-        &stakers,
-        &stakes,
-        &50_i128,
-        &50_i128,
-    );
+    let result = client.try_batch_claim_payouts(&registry_id, &999u64, &stakers);
     assert_contract_error(result, OutcomeError::CallNotSettled);
 }
 
@@ -816,15 +3101,15 @@ fn test_batch_claim_with_fee_deducted() {
     stakers.push_back(staker1.clone());
     stakers.push_back(staker2.clone());
 
-    let mut stakes = Vec::new(&env);
-    stakes.push_back(60_i128);
-    stakes.push_back(40_i128);
+    stake(&env, &registry_id, 1, &staker1, 1, 60, 60);
+    stake(&env, &registry_id, 1, &staker2, 1, 40, 40);
+    stake_loser(&env, &registry_id, 1, 100);
 
     // Should process without panic; fee math mirrors claim_payout
-    client.batch_claim_payouts(&registry_id, &1u64, &stakers, &stakes, &100_i128, &100_i128);
+    client.batch_claim_payouts(&registry_id, &1u64, &stakers);
 
-    assert!(client.has_claimed(&1u64, &staker1));
-    assert!(client.has_claimed(&1u64, &staker2));
+    assert!(client.has_claimed(&registry_id, &1u64, &staker1));
+    assert!(client.has_claimed(&registry_id, &1u64, &staker2));
     let _ = fee_collector;
 }
 
@@ -859,6 +3144,60 @@ fn test_om_upgrade_requires_admin_auth() {
     assert_contract_error(result, OutcomeError::NotInitialized);
 }
 
+#[test]
+fn test_om_upgrade_waits_for_queued_timelock() {
+    let env = Env::default();
+    let (_admin, _registry_id, _secret, _pubkey, client) = setup_single_oracle(&env);
+    env.mock_all_auths();
+    let hash = BytesN::<32>::from_array(&env, &[7u8; 32]);
+
+    let result = client.try_upgrade(&hash);
+    assert_contract_error(result, OutcomeExtError::UpgradeNotQueued);
+
+    env.ledger().set_timestamp(1_000);
+    client.queue_upgrade(&hash);
+    let pending = client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.eta, 1_000 + UPGRADE_TIMELOCK_SECS);
+    let other = BytesN::<32>::from_array(&env, &[8u8; 32]);
+    let result = client.try_upgrade(&other);
+    assert_contract_error(result, OutcomeExtError::UpgradeNotQueued);
+    env.ledger().set_timestamp(pending.eta - 1);
+    let result = client.try_upgrade(&hash);
+    assert_contract_error(result, OutcomeExtError::UpgradeTimelockActive);
+
+    client.cancel_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+#[test]
+fn test_om_storage_version_mismatch_fails_loudly() {
+    let env = Env::default();
+    let (_admin, registry_id, _secret, _pubkey, client) = setup_single_oracle(&env);
+    env.mock_all_auths();
+    assert_eq!(client.get_storage_version(), STORAGE_VERSION);
+    let set_stored = |version: u32| {
+        env.as_contract(&client.address, || {
+            env.storage()
+                .instance()
+                .set(&InstanceKey::StorageVersion, &version);
+        });
+    };
+
+    // Data written by newer code cannot be migrated down
+    set_stored(STORAGE_VERSION + 1);
+    let result = client.try_set_quorum(&1u32);
+    assert_contract_error(result, OutcomeExtError::StorageVersionMismatch);
+    let result = client.try_migrate();
+    assert_contract_error(result, OutcomeExtError::StorageVersionMismatch);
+
+    // Older data is brought up to date by migrate
+    set_stored(STORAGE_VERSION - 1);
+    let result = client.try_claim_payout(&registry_id, &1u64, &Address::generate(&env));
+    assert_contract_error(result, OutcomeExtError::StorageVersionMismatch);
+    assert_eq!(client.migrate(), STORAGE_VERSION);
+    client.set_quorum(&1u32);
+}
+
 // -- Fuzz / property tests for claim_payout arithmetic -----------------------
 
 /// Create a fresh settled env and run claim_payout with the given inputs.
@@ -867,15 +3206,30 @@ fn fuzz_claim_setup(staker_winning: i128, total_winning: i128, total_losing: i12
     let env = Env::default();
     let (_, registry_id, client) = setup_with_fee(&env, fee_bps);
     let staker = Address::generate(&env);
-    client.claim_payout(
+    stake(
+        &env,
         &registry_id,
-        &1u64,
+        1,
         &staker,
-        &staker_winning,
-        &total_winning,
-        &total_losing,
+        1,
+        staker_winning,
+        staker_winning,
     );
-    assert!(client.has_claimed(&1u64, &staker));
+    if total_winning > staker_winning {
+        let rest = total_winning - staker_winning;
+        stake(
+            &env,
+            &registry_id,
+            1,
+            &Address::generate(&env),
+            1,
+            rest,
+            rest,
+        );
+    }
+    stake_loser(&env, &registry_id, 1, total_losing);
+    client.claim_payout(&registry_id, &1u64, &staker);
+    assert!(client.has_claimed(&registry_id, &1u64, &staker));
 }
 
 #[test]
@@ -905,18 +3259,20 @@ fn test_fuzz_100_winners_batch_all_claimed() {
     // 100 equal winners via batch_claim_payouts -- all must be marked claimed
     let env = Env::default();
     let (_, registry_id, client) = setup_with_fee(&env, 0);
+    env.cost_estimate().budget().reset_unlimited();
 
     let mut stakers = Vec::new(&env);
-    let mut stakes = Vec::new(&env);
     for _ in 0..100u32 {
-        stakers.push_back(Address::generate(&env));
-        stakes.push_back(1_i128);
+        let staker = Address::generate(&env);
+        stake(&env, &registry_id, 1, &staker, 1, 1, 1);
+        stakers.push_back(staker);
     }
+    stake_loser(&env, &registry_id, 1, 100);
 
-    client.batch_claim_payouts(&registry_id, &1u64, &stakers, &stakes, &100_i128, &100_i128);
+    client.batch_claim_payouts(&registry_id, &1u64, &stakers);
 
     for i in 0..100u32 {
-        assert!(client.has_claimed(&1u64, &stakers.get(i).unwrap()));
+        assert!(client.has_claimed(&registry_id, &1u64, &stakers.get(i).unwrap()));
     }
 }
 
@@ -926,15 +3282,10 @@ fn test_fuzz_1_winner_takes_all() {
     let env = Env::default();
     let (_, registry_id, client) = setup_with_fee(&env, 0);
     let staker = Address::generate(&env);
-    client.claim_payout(
-        &registry_id,
-        &1u64,
-        &staker,
-        &1_000_000_i128,
-        &1_000_000_i128,
-        &1_000_000_i128,
-    );
-    assert!(client.has_claimed(&1u64, &staker));
+    stake(&env, &registry_id, 1, &staker, 1, 1_000_000, 1_000_000);
+    stake_loser(&env, &registry_id, 1, 1_000_000);
+    client.claim_payout(&registry_id, &1u64, &staker);
+    assert!(client.has_claimed(&registry_id, &1u64, &staker));
 }
 
 #[test]
@@ -954,17 +3305,88 @@ fn test_fuzz_zero_staker_stake_panics() {
     let env = Env::default();
     let (_, registry_id, client) = setup_with_fee(&env, 0);
     let staker = Address::generate(&env);
-    let result = client.try_claim_payout(&registry_id, &1u64, &staker, &0, &1, &1);
+    // Only a stake on the losing side: nothing on the winning outcome
+    stake(&env, &registry_id, 1, &staker, 2, 100, 100);
+    stake(&env, &registry_id, 1, &Address::generate(&env), 1, 100, 100);
+    let result = client.try_claim_payout(&registry_id, &1u64, &staker);
     assert_contract_error(result, OutcomeError::NothingToClaim);
 }
 
 #[test]
-fn test_fuzz_zero_total_winning_panics() {
+fn test_claim_payout_includes_penalty_pool() {
+    // fee_bps = 0: losing pool = 100 losing stake + 20 penalty pool
+    // payout = 100 + 100 * 120 / 100 = 220
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::IntoVal;
+
     let env = Env::default();
     let (_, registry_id, client) = setup_with_fee(&env, 0);
     let staker = Address::generate(&env);
-    let result = client.try_claim_payout(&registry_id, &1u64, &staker, &1, &0, &1);
-    assert_contract_error(result, OutcomeError::InvalidWinningStake);
+    stake(&env, &registry_id, 1, &staker, 1, 100, 100);
+    stake_loser(&env, &registry_id, 1, 100);
+    MockRegistryClient::new(&env, &registry_id).set_penalty_pool(&1, &20);
+
+    client.claim_payout(&registry_id, &1u64, &staker);
+    let last = env.events().all().last().unwrap();
+    let (_, _, payout): (u64, Address, i128) = last.2.into_val(&env);
+    assert_eq!(payout, 220);
+}
+
+#[test]
+fn test_fees_are_deposited_with_the_call_creator() {
+    use fee_distributor::{FeeDistributor, FeeDistributorClient, Recipients, SplitWeights};
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+    use soroban_sdk::IntoVal;
+
+    // fee_bps = 1000: 1_000 losing stake leaves a fee of 100
+    let env = Env::default();
+    let (fee_collector, registry_id, client) = setup_with_fee(&env, 1_000);
+    let stake_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &stake_token).mint(&registry_id, &2_000);
+    let creator = Address::generate(&env);
+    MockRegistryClient::new(&env, &registry_id).set_call_terms(&stake_token, &creator);
+
+    let distributor = FeeDistributorClient::new(&env, &env.register(FeeDistributor, ()));
+    distributor.initialize(
+        &Address::generate(&env),
+        &Recipients {
+            oracle_pool: Address::generate(&env),
+            insurance_fund: Address::generate(&env),
+            treasury: Address::generate(&env),
+        },
+        &SplitWeights {
+            creator: 2_000,
+            oracle_pool: 3_000,
+            insurance: 1_000,
+            treasury: 4_000,
+        },
+    );
+    client.set_fee_distributor(&Some(distributor.address.clone()));
+
+    let staker = Address::generate(&env);
+    stake(&env, &registry_id, 1, &staker, 1, 1_000, 1_000);
+    stake_loser(&env, &registry_id, 1, 1_000);
+    // Only the staker signs: the distributor's pull of the fee must be
+    // authorised by the OutcomeManager itself
+    env.mock_auths(&[MockAuth {
+        address: &staker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "claim_payout",
+            args: (registry_id.clone(), 1u64, staker.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.claim_payout(&registry_id, &1u64, &staker);
+
+    let token = token::Client::new(&env, &stake_token);
+    assert_eq!(token.balance(&distributor.address), 100);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(token.balance(&fee_collector), 0);
+    assert_eq!(distributor.get_creator_balance(&creator, &stake_token), 20);
+    assert_eq!(distributor.get_pending(&stake_token), 80);
 }
 
 // ─── Pause Mechanism Tests ─────────────────────────────────────────────────────
@@ -993,15 +3415,29 @@ fn test_submit_outcome_fails_when_paused() {
     client.pause();
 
     let signed = SignedOutcome {
+        registry: registry_id.clone(),
         call_id: 1,
         outcome: 1,
         price: 100,
         timestamp: 1000,
         oracle_pubkey: oracle_pubkey.clone(),
-        signature: sign_outcome(&env, &oracle_secret, 1, 1, 100, 1000),
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sign_outcome(
+            &env,
+            &client.address,
+            &registry_id,
+            &oracle_secret,
+            1,
+            1,
+            100,
+            1000,
+            1,
+            VALID_UNTIL,
+        ),
     };
 
-    let result = client.try_submit_outcome(&registry_id, &signed, &0u64);
+    let result = client.try_submit_outcome(&signed);
     assert_contract_error(result, OutcomeError::ContractPaused);
 }
 
@@ -1014,17 +3450,61 @@ fn test_claim_payout_fails_when_paused() {
     env.mock_all_auths();
     client.pause();
 
-    let result = client.try_claim_payout(
-        &registry_id,
-        &1u64,
-        &staker,
-        &100_i128,
-        &100_i128,
-        &100_i128,
-    );
+    let result = client.try_claim_payout(&registry_id, &1u64, &staker);
     assert_contract_error(result, OutcomeError::ContractPaused);
 }
 
+#[test]
+fn test_pauser_halts_submissions_and_claims_separately() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
+    let pauser = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let result = client.try_pause_submissions(&pauser);
+    assert_contract_error(result, OutcomeError::Unauthorized);
+    client.set_pauser(&Some(pauser.clone()));
+    assert_eq!(client.get_pauser(), Some(pauser.clone()));
+    let result = client.try_pause_claims(&stranger);
+    assert_contract_error(result, OutcomeError::Unauthorized);
+
+    client.pause_submissions(&pauser);
+    let report = signed_report(
+        &env,
+        &client,
+        &oracle_secret,
+        &oracle_pubkey,
+        1,
+        1,
+        VALID_UNTIL,
+    );
+    let result = client.try_submit_outcome(&report);
+    assert_contract_error(result, OutcomeExtError::SubmissionsPaused);
+    let result = client.try_finalize_outcome(&registry_id, &1u64);
+    assert_contract_error(result, OutcomeExtError::SubmissionsPaused);
+
+    // Claims stay open until paused on their own
+    let staker = Address::generate(&env);
+    let result = client.try_claim_payout(&registry_id, &1u64, &staker);
+    assert_contract_error(result, OutcomeError::CallNotSettled);
+    client.pause_claims(&pauser);
+    let result = client.try_claim_payout(&registry_id, &1u64, &staker);
+    assert_contract_error(result, OutcomeExtError::ClaimsPaused);
+    assert_eq!(
+        client.get_pause_state(),
+        PauseState {
+            paused: false,
+            submissions_paused: true,
+            claims_paused: true,
+        }
+    );
+
+    client.unpause_submissions(&pauser);
+    client.submit_outcome(&report);
+    assert!(client.get_pause_state().claims_paused);
+}
+
 // ─── Oracle Submission Deadline Tests ─────────────────────────────────────────
 
 #[test]
@@ -1033,23 +3513,33 @@ fn test_submission_within_window_succeeds() {
     let (_admin, registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
 
     let call_id = 10u64;
-    let call_end_ts = 1000u64;
+    MockRegistryClient::new(&env, &registry_id).set_end_ts(&call_id, &1000u64);
     // timestamp 1500 is well within default 86400s window
-    let sig = sign_outcome(&env, &oracle_secret, call_id, 1, 100, 1500);
-    client.submit_outcome(
+    let sig = sign_outcome(
+        &env,
+        &client.address,
         &registry_id,
-        &SignedOutcome {
-            call_id,
-            outcome: 1,
-            price: 100,
-            timestamp: 1500,
-            oracle_pubkey,
-            signature: sig,
-        },
-        &call_end_ts,
+        &oracle_secret,
+        call_id,
+        1,
+        100,
+        1500,
+        1,
+        VALID_UNTIL,
     );
-
-    let outcome = client.get_outcome(&call_id);
+    client.submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome: 1,
+        price: 100,
+        timestamp: 1500,
+        oracle_pubkey,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sig,
+    });
+
+    let outcome = client.get_outcome(&registry_id, &call_id);
     assert_eq!(outcome.outcome, 1u32);
 }
 
@@ -1062,24 +3552,56 @@ fn test_submission_outside_window_fails() {
     client.set_max_submission_delay(&50u64);
 
     let call_id = 11u64;
-    let call_end_ts = 1000u64;
+    MockRegistryClient::new(&env, &registry_id).set_end_ts(&call_id, &1000u64);
     // timestamp 1200 > call_end_ts(1000) + max_delay(50) = 1050
-    let sig = sign_outcome(&env, &oracle_secret, call_id, 1, 100, 1200);
-    let result = client.try_submit_outcome(
+    let sig = sign_outcome(
+        &env,
+        &client.address,
         &registry_id,
-        &SignedOutcome {
-            call_id,
-            outcome: 1,
-            price: 100,
-            timestamp: 1200,
-            oracle_pubkey,
-            signature: sig,
-        },
-        &call_end_ts,
+        &oracle_secret,
+        call_id,
+        1,
+        100,
+        1200,
+        1,
+        VALID_UNTIL,
     );
+    let result = client.try_submit_outcome(&SignedOutcome {
+        registry: registry_id.clone(),
+        call_id,
+        outcome: 1,
+        price: 100,
+        timestamp: 1200,
+        oracle_pubkey,
+        nonce: 1,
+        valid_until: VALID_UNTIL,
+        signature: sig,
+    });
     assert_contract_error(result, OutcomeError::SubmissionWindowExpired);
 }
 
+#[test]
+fn test_submission_before_call_end_fails() {
+    let env = Env::default();
+    let (_admin, registry_id, oracle_secret, oracle_pubkey, client) = setup_single_oracle(&env);
+
+    // The report's timestamp 9000 was observed before the call ended at 9001
+    let call_id = 12u64;
+    MockRegistryClient::new(&env, &registry_id).set_end_ts(&call_id, &9001u64);
+    let signed = signed_report(
+        &env,
+        &client,
+        &oracle_secret,
+        &oracle_pubkey,
+        call_id,
+        1,
+        50,
+    );
+
+    let result = client.try_submit_outcome(&signed);
+    assert_contract_error(result, OutcomeError::TimestampBeforeCallEnd);
+}
+
 #[test]
 fn test_admin_can_update_max_submission_delay() {
     let env = Env::default();
@@ -1,8 +1,41 @@
-use soroban_sdk::{Bytes, BytesN, Env};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
 
 // Re-export build_message from the shared crate so existing call sites are unchanged.
 pub use backit_shared::build_message;
 
+/// Prefix of the message oracles sign for a TWAP price observation.
+pub const OBSERVATION_PREFIX: &[u8] = b"twap_obs:";
+
+/// Build the message an oracle signs for a TWAP price observation.
+///
+/// Format (all big-endian):
+///   `b"twap_obs:"` | network_id(32B) | contract(XDR `ScVal::Address`) |
+///   registry(XDR `ScVal::Address`) | call_id(8B) | price(16B) |
+///   timestamp(8B) | nonce(8B)
+///
+/// As with [`build_message`], the network id, contract and registry pin the
+/// signature to one deployment and registry, and `nonce` (shared with the
+/// oracle's outcome reports) stops it being replayed there.
+pub fn build_observation_message(
+    env: &Env,
+    contract: &Address,
+    registry: &Address,
+    call_id: u64,
+    price: i128,
+    timestamp: u64,
+    nonce: u64,
+) -> Bytes {
+    let mut msg = Bytes::from_slice(env, OBSERVATION_PREFIX);
+    msg.append(&env.ledger().network_id().into());
+    msg.append(&contract.clone().to_xdr(env));
+    msg.append(&registry.clone().to_xdr(env));
+    msg.append(&Bytes::from_slice(env, &call_id.to_be_bytes()));
+    msg.append(&Bytes::from_slice(env, &price.to_be_bytes()));
+    msg.append(&Bytes::from_slice(env, &timestamp.to_be_bytes()));
+    msg.append(&Bytes::from_slice(env, &nonce.to_be_bytes()));
+    msg
+}
+
 /// Verify an ed25519 signature.
 ///
 /// `env.crypto().ed25519_verify` panics on failure, which reverts the transaction.
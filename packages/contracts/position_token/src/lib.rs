@@ -0,0 +1,174 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! SEP-41 fungible token representing one side of a BACKit call
+//! (e.g. `CALL42-UP`).
+//!
+//! The CallRegistry deploys one token per outcome of a call and is its admin:
+//! it mints tokens 1:1 with stake when a position is opened and claws them
+//! back when the position is reduced, switched or claimed. Holders can
+//! transfer tokens freely, which makes positions usable in wallets and DEXes.
+
+mod storage;
+mod test;
+
+use soroban_sdk::{contract, contractimpl, token::TokenInterface, Address, Env, String};
+
+use storage::*;
+
+fn check_nonnegative_amount(amount: i128) {
+    if amount < 0 {
+        panic!("negative amount is not allowed: {}", amount)
+    }
+}
+
+fn spend_balance(env: &Env, from: &Address, amount: i128) {
+    let balance = get_balance(env, from);
+    if balance < amount {
+        panic!("insufficient balance");
+    }
+    set_balance(env, from, balance - amount);
+}
+
+fn receive_balance(env: &Env, to: &Address, amount: i128) {
+    let balance = get_balance(env, to);
+    set_balance(env, to, balance + amount);
+}
+
+fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = get_allowance(env, from, spender);
+    if allowance.amount < amount {
+        panic!("insufficient allowance");
+    }
+    if amount > 0 {
+        set_allowance(
+            env,
+            from,
+            spender,
+            allowance.amount - amount,
+            allowance.expiration_ledger,
+        );
+    }
+}
+
+#[contract]
+pub struct PositionToken;
+
+#[contractimpl]
+impl PositionToken {
+    /// Set the admin and SEP-41 metadata at deployment.
+    pub fn __constructor(env: Env, admin: Address, decimal: u32, name: String, symbol: String) {
+        set_admin(&env, &admin);
+        set_metadata(
+            &env,
+            &TokenMetadata {
+                decimal,
+                name,
+                symbol,
+            },
+        );
+    }
+
+    /// Mint `amount` to `to` (admin only).
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        extend_instance_ttl(&env);
+        receive_balance(&env, &to, amount);
+        env.events().publish(("mint", admin, to), amount);
+    }
+
+    /// Destroy `amount` of `from`'s balance without their signature (admin only).
+    pub fn clawback(env: Env, from: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        extend_instance_ttl(&env);
+        spend_balance(&env, &from, amount);
+        env.events().publish(("clawback", admin, from), amount);
+    }
+
+    /// The admin allowed to mint and claw back.
+    pub fn admin(env: Env) -> Address {
+        get_admin(&env)
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for PositionToken {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        extend_instance_ttl(&env);
+        get_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        check_nonnegative_amount(amount);
+
+        extend_instance_ttl(&env);
+        set_allowance(&env, &from, &spender, amount, expiration_ledger);
+        env.events()
+            .publish(("approve", from, spender), (amount, expiration_ledger));
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        extend_instance_ttl(&env);
+        get_balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: soroban_sdk::MuxedAddress, amount: i128) {
+        from.require_auth();
+        check_nonnegative_amount(amount);
+
+        extend_instance_ttl(&env);
+        let to = to.address();
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+        env.events().publish(("transfer", from, to), amount);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        check_nonnegative_amount(amount);
+
+        extend_instance_ttl(&env);
+        spend_allowance(&env, &from, &spender, amount);
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+        env.events().publish(("transfer", from, to), amount);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        check_nonnegative_amount(amount);
+
+        extend_instance_ttl(&env);
+        spend_balance(&env, &from, amount);
+        env.events().publish(("burn", from), amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        check_nonnegative_amount(amount);
+
+        extend_instance_ttl(&env);
+        spend_allowance(&env, &from, &spender, amount);
+        spend_balance(&env, &from, amount);
+        env.events().publish(("burn", from), amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        get_metadata(&env).decimal
+    }
+
+    fn name(env: Env) -> String {
+        get_metadata(&env).name
+    }
+
+    fn symbol(env: Env) -> String {
+        get_metadata(&env).symbol
+    }
+}
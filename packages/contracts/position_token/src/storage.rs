@@ -0,0 +1,141 @@
+use soroban_sdk::{contracttype, Address, Env, String};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Metadata,
+    Balance(Address),
+    Allowance(Address, Address),
+}
+
+/// SEP-41 descriptive metadata, fixed at deployment.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenMetadata {
+    pub decimal: u32,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// An allowance and the ledger after which it lapses.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Get the admin (the CallRegistry that deployed the token)
+pub fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("not initialized")
+}
+
+/// Store the admin
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the token metadata
+pub fn get_metadata(env: &Env) -> TokenMetadata {
+    env.storage()
+        .instance()
+        .get(&DataKey::Metadata)
+        .expect("not initialized")
+}
+
+/// Store the token metadata
+pub fn set_metadata(env: &Env, metadata: &TokenMetadata) {
+    env.storage().instance().set(&DataKey::Metadata, metadata);
+}
+
+/// Get an address's balance
+pub fn get_balance(env: &Env, id: &Address) -> i128 {
+    let key = DataKey::Balance(id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(balance) => {
+            env.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_LIFETIME_THRESHOLD,
+                PERSISTENT_BUMP_AMOUNT,
+            );
+            balance
+        }
+        None => 0,
+    }
+}
+
+/// Store an address's balance
+pub fn set_balance(env: &Env, id: &Address, amount: i128) {
+    let key = DataKey::Balance(id.clone());
+    env.storage().persistent().set(&key, &amount);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the allowance `from` granted `spender`; lapsed allowances read as 0
+pub fn get_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    let allowance = env
+        .storage()
+        .temporary()
+        .get::<_, AllowanceValue>(&key)
+        .unwrap_or(AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        });
+    if allowance.expiration_ledger < env.ledger().sequence() {
+        AllowanceValue {
+            amount: 0,
+            expiration_ledger: allowance.expiration_ledger,
+        }
+    } else {
+        allowance
+    }
+}
+
+/// Store an allowance, keeping the entry alive until it lapses
+pub fn set_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        panic!("expiration_ledger is in the past");
+    }
+
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    let allowance = AllowanceValue {
+        amount,
+        expiration_ledger,
+    };
+    env.storage().temporary().set(&key, &allowance);
+    if amount > 0 {
+        let live_for = expiration_ledger - env.ledger().sequence();
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, live_for, live_for);
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{PositionToken, PositionTokenClient};
+
+fn setup() -> (Env, PositionTokenClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let id = env.register(
+        PositionToken,
+        (
+            admin.clone(),
+            7u32,
+            String::from_str(&env, "BACKit CALL42-UP"),
+            String::from_str(&env, "CALL42-UP"),
+        ),
+    );
+    (env.clone(), PositionTokenClient::new(&env, &id), admin)
+}
+
+#[test]
+fn test_metadata_set_at_deployment() {
+    let (env, client, admin) = setup();
+    assert_eq!(client.decimals(), 7);
+    assert_eq!(client.symbol(), String::from_str(&env, "CALL42-UP"));
+    assert_eq!(client.name(), String::from_str(&env, "BACKit CALL42-UP"));
+    assert_eq!(client.admin(), admin);
+}
+
+#[test]
+fn test_mint_transfer_and_clawback() {
+    let (env, client, _admin) = setup();
+    let holder = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    client.mint(&holder, &1_000);
+    client.transfer(&holder, &buyer, &400);
+    assert_eq!(client.balance(&holder), 600);
+    assert_eq!(client.balance(&buyer), 400);
+
+    client.clawback(&buyer, &400);
+    assert_eq!(client.balance(&buyer), 0);
+}
+
+#[test]
+fn test_transfer_from_spends_allowance() {
+    let (env, client, _admin) = setup();
+    let holder = Address::generate(&env);
+    let dex = Address::generate(&env);
+
+    client.mint(&holder, &1_000);
+    client.approve(&holder, &dex, &300, &100);
+    client.transfer_from(&dex, &holder, &dex, &200);
+    assert_eq!(client.allowance(&holder, &dex), 100);
+    assert_eq!(client.balance(&dex), 200);
+
+    client.burn_from(&dex, &holder, &100);
+    assert_eq!(client.allowance(&holder, &dex), 0);
+    assert_eq!(client.balance(&holder), 700);
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance")]
+fn test_clawback_beyond_balance_panics() {
+    let (env, client, _admin) = setup();
+    let holder = Address::generate(&env);
+    client.mint(&holder, &10);
+    client.clawback(&holder, &11);
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum PriceAdapterError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// Decimals exceed `MAX_DECIMALS`.
+    InvalidDecimals = 3,
+    /// A source list is empty or longer than `MAX_SOURCES`.
+    InvalidSources = 4,
+    /// A posted price is not positive.
+    InvalidPrice = 5,
+    /// A posted price is timestamped in the future.
+    InvalidTimestamp = 6,
+    /// A posted price is not newer than the latest one from the same source.
+    StalePrice = 7,
+    /// A signed report's key is not a registered signer.
+    UnknownSigner = 8,
+    /// No sources are configured for the asset.
+    AssetNotConfigured = 9,
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::{BytesN, Env};
+
+use crate::storage::{Asset, ReportSource};
+
+/// Emitted when the admin sets or removes an asset's sources
+pub fn emit_asset_configured(env: &Env, asset: &Asset, configured: bool) {
+    env.events().publish(
+        ("price_adapter", "asset_configured"),
+        (asset.clone(), configured),
+    );
+}
+
+/// Emitted when the admin adds or removes a report signer
+pub fn emit_signer_set(env: &Env, public_key: &BytesN<32>, allowed: bool) {
+    env.events().publish(
+        ("price_adapter", "signer_set"),
+        (public_key.clone(), allowed),
+    );
+}
+
+/// Emitted when a signed report or admin post is stored
+pub fn emit_price_posted(
+    env: &Env,
+    asset: &Asset,
+    source: ReportSource,
+    price: i128,
+    timestamp: u64,
+) {
+    env.events().publish(
+        ("price_adapter", "price_posted"),
+        (asset.clone(), source, price, timestamp),
+    );
+}
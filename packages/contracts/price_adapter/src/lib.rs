@@ -0,0 +1,352 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! One price interface over every upstream BACKit reads prices from.
+//!
+//! Each asset is configured with an ordered list of sources: SEP-40 feeds
+//! such as Reflector, reports signed by registered keys (the backend's
+//! DexScreener fetcher), and prices posted by the admin. `price(asset,
+//! timestamp)` returns the price from the first source with a fresh enough
+//! value at that time, rescaled to the adapter's decimals. The adapter
+//! itself speaks SEP-40 (`price`, `lastprice`, `decimals`), so call creation
+//! and resolution can both point at it as their price feed.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{
+    contract, contractimpl, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, TryFromVal,
+    Val, Vec,
+};
+
+use errors::PriceAdapterError;
+use events::*;
+use storage::*;
+
+pub use storage::{Asset, AssetConfig, PriceData, PriceSource, ReportSource, Sep40Feed};
+
+/// Most decimals prices can be expressed in.
+pub const MAX_DECIMALS: u32 = 18;
+/// Most sources one asset can list.
+pub const MAX_SOURCES: u32 = 5;
+/// Posted prices kept per asset and report source; older ones are dropped.
+pub const MAX_REPORTS: u32 = 64;
+
+/// Prefix of the message report signers sign.
+pub const PRICE_MESSAGE_PREFIX: &[u8] = b"BACKit:Price:";
+
+#[contract]
+pub struct PriceAdapter;
+
+/// Build the message a signer signs to report `asset` at `price` as of
+/// `timestamp`.
+///
+/// Format: `b"BACKit:Price:"` | network_id(32B) | `b":"` |
+/// adapter(XDR `ScVal::Address`) | `b":"` | asset(XDR) | `b":"` |
+/// price(16B big-endian) | `b":"` | timestamp(8B big-endian)
+///
+/// Binding the network and adapter stops a report from replaying on another
+/// deployment.
+pub fn build_price_message(
+    env: &Env,
+    adapter: &Address,
+    asset: &Asset,
+    price: i128,
+    timestamp: u64,
+) -> Bytes {
+    let mut msg = Bytes::from_slice(env, PRICE_MESSAGE_PREFIX);
+    msg.append(&env.ledger().network_id().into());
+    msg.append(&Bytes::from_slice(env, b":"));
+    msg.append(&adapter.clone().to_xdr(env));
+    msg.append(&Bytes::from_slice(env, b":"));
+    msg.append(&asset.clone().to_xdr(env));
+    msg.append(&Bytes::from_slice(env, b":"));
+    msg.append(&Bytes::from_slice(env, &price.to_be_bytes()));
+    msg.append(&Bytes::from_slice(env, b":"));
+    msg.append(&Bytes::from_slice(env, &timestamp.to_be_bytes()));
+    msg
+}
+
+fn require_admin(env: &Env) -> Result<Address, PriceAdapterError> {
+    let admin = get_admin(env).ok_or(PriceAdapterError::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+/// `price` in `from` decimals expressed in `to` decimals, rounded down;
+/// `None` on overflow.
+fn rescale(price: i128, from: u32, to: u32) -> Option<i128> {
+    if from <= to {
+        price.checked_mul(10i128.checked_pow(to - from)?)
+    } else {
+        Some(price / 10i128.checked_pow(from - to)?)
+    }
+}
+
+/// Whether `data` is usable as the price at `timestamp`.
+fn is_fresh(data: &PriceData, timestamp: u64, max_age: u64) -> bool {
+    data.price > 0 && data.timestamp <= timestamp && timestamp - data.timestamp <= max_age
+}
+
+/// Call a SEP-40 read on `feed`, treating a failing feed as having no price.
+fn feed_call<T>(env: &Env, feed: &Sep40Feed, function: &str, args: Vec<Val>) -> Option<T>
+where
+    T: TryFromVal<Env, Val>,
+{
+    env.try_invoke_contract::<T, soroban_sdk::Error>(
+        &feed.oracle,
+        &Symbol::new(env, function),
+        args,
+    )
+    .ok()
+    .and_then(|result| result.ok())
+}
+
+/// Price of `feed` at `timestamp`, in the adapter's decimals. The timestamp
+/// is rounded down to the feed's resolution, as SEP-40 feeds only store
+/// prices at multiples of it.
+fn sep40_price(
+    env: &Env,
+    feed: &Sep40Feed,
+    timestamp: u64,
+    max_age: u64,
+    decimals: u32,
+) -> Option<PriceData> {
+    let resolution: u32 = feed_call(env, feed, "resolution", Vec::new(env))?;
+    let feed_decimals: u32 = feed_call(env, feed, "decimals", Vec::new(env))?;
+    let slot = match resolution {
+        0 => timestamp,
+        resolution => timestamp - timestamp % resolution as u64,
+    };
+    let args = (feed.asset.clone(), slot).into_val(env);
+    let data: PriceData = feed_call::<Option<PriceData>>(env, feed, "price", args)??;
+    if !is_fresh(&data, timestamp, max_age) {
+        return None;
+    }
+    Some(PriceData {
+        price: rescale(data.price, feed_decimals, decimals)?,
+        timestamp: data.timestamp,
+    })
+}
+
+/// Latest price posted by `source` at or before `timestamp`.
+fn reported_price(
+    env: &Env,
+    asset: &Asset,
+    source: ReportSource,
+    timestamp: u64,
+    max_age: u64,
+) -> Option<PriceData> {
+    get_reports(env, asset, source)
+        .iter()
+        .rev()
+        .find(|data| data.timestamp <= timestamp)
+        .filter(|data| is_fresh(data, timestamp, max_age))
+}
+
+/// Append a posted price to `source`'s history for `asset`.
+fn store_report(
+    env: &Env,
+    asset: &Asset,
+    source: ReportSource,
+    price: i128,
+    timestamp: u64,
+) -> Result<(), PriceAdapterError> {
+    if price <= 0 {
+        return Err(PriceAdapterError::InvalidPrice);
+    }
+    if timestamp > env.ledger().timestamp() {
+        return Err(PriceAdapterError::InvalidTimestamp);
+    }
+    let mut reports = get_reports(env, asset, source);
+    if let Some(latest) = reports.last() {
+        if timestamp <= latest.timestamp {
+            return Err(PriceAdapterError::StalePrice);
+        }
+    }
+    reports.push_back(PriceData { price, timestamp });
+    if reports.len() > MAX_REPORTS {
+        reports.pop_front();
+    }
+    set_reports(env, asset, source, &reports);
+
+    emit_price_posted(env, asset, source, price, timestamp);
+
+    Ok(())
+}
+
+#[contractimpl]
+impl PriceAdapter {
+    /// Initialise the contract with an admin and the decimals every price
+    /// is returned in.
+    /// # Errors
+    /// * [`PriceAdapterError::AlreadyInitialized`] – called more than once.
+    /// * [`PriceAdapterError::InvalidDecimals`]    – `decimals` > [`MAX_DECIMALS`].
+    pub fn initialize(env: Env, admin: Address, decimals: u32) -> Result<(), PriceAdapterError> {
+        if get_admin(&env).is_some() {
+            return Err(PriceAdapterError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if decimals > MAX_DECIMALS {
+            return Err(PriceAdapterError::InvalidDecimals);
+        }
+
+        set_admin(&env, &admin);
+        set_decimals(&env, decimals);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Set, or with `None` remove, the sources `asset` is priced from
+    /// (admin only).
+    /// # Errors
+    /// * [`PriceAdapterError::NotInitialized`] – contract not initialised.
+    /// * [`PriceAdapterError::InvalidSources`] – no sources, or more than
+    ///   [`MAX_SOURCES`].
+    pub fn set_asset_config(
+        env: Env,
+        asset: Asset,
+        config: Option<AssetConfig>,
+    ) -> Result<(), PriceAdapterError> {
+        require_admin(&env)?;
+        if let Some(config) = &config {
+            if config.sources.is_empty() || config.sources.len() > MAX_SOURCES {
+                return Err(PriceAdapterError::InvalidSources);
+            }
+        }
+
+        set_asset_config(&env, &asset, &config);
+        extend_instance_ttl(&env);
+
+        emit_asset_configured(&env, &asset, config.is_some());
+
+        Ok(())
+    }
+
+    /// Register (`allowed = true`) or remove a key whose signed reports are
+    /// accepted (admin only).
+    /// # Errors
+    /// * [`PriceAdapterError::NotInitialized`] – contract not initialised.
+    pub fn set_signer(
+        env: Env,
+        public_key: BytesN<32>,
+        allowed: bool,
+    ) -> Result<(), PriceAdapterError> {
+        require_admin(&env)?;
+
+        set_signer(&env, &public_key, allowed);
+        extend_instance_ttl(&env);
+
+        emit_signer_set(&env, &public_key, allowed);
+
+        Ok(())
+    }
+
+    /// Post `asset`'s price as of `timestamp`, in the adapter's decimals
+    /// (admin only). Serves assets whose sources include
+    /// [`ReportSource::Manual`].
+    /// # Errors
+    /// * [`PriceAdapterError::NotInitialized`]   – contract not initialised.
+    /// * [`PriceAdapterError::InvalidPrice`]     – `price` ≤ 0.
+    /// * [`PriceAdapterError::InvalidTimestamp`] – `timestamp` is in the future.
+    /// * [`PriceAdapterError::StalePrice`]       – not newer than the last post.
+    pub fn post_price(
+        env: Env,
+        asset: Asset,
+        price: i128,
+        timestamp: u64,
+    ) -> Result<(), PriceAdapterError> {
+        require_admin(&env)?;
+        store_report(&env, &asset, ReportSource::Manual, price, timestamp)?;
+        extend_instance_ttl(&env);
+        Ok(())
+    }
+
+    /// Submit a report of `asset`'s price as of `timestamp`, in the
+    /// adapter's decimals, signed by a registered key over
+    /// [`build_price_message`]. Anyone may relay it. Serves assets whose
+    /// sources include [`ReportSource::Signed`].
+    /// # Errors
+    /// * [`PriceAdapterError::UnknownSigner`]    – `public_key` is not registered.
+    /// * [`PriceAdapterError::InvalidPrice`]     – `price` ≤ 0.
+    /// * [`PriceAdapterError::InvalidTimestamp`] – `timestamp` is in the future.
+    /// * [`PriceAdapterError::StalePrice`]       – not newer than the last report.
+    ///
+    /// # Panics
+    /// If the signature does not verify.
+    pub fn submit_signed_price(
+        env: Env,
+        asset: Asset,
+        price: i128,
+        timestamp: u64,
+        public_key: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<(), PriceAdapterError> {
+        if !is_signer(&env, &public_key) {
+            return Err(PriceAdapterError::UnknownSigner);
+        }
+        let message = build_price_message(
+            &env,
+            &env.current_contract_address(),
+            &asset,
+            price,
+            timestamp,
+        );
+        env.crypto()
+            .ed25519_verify(&public_key, &message, &signature);
+
+        store_report(&env, &asset, ReportSource::Signed, price, timestamp)?;
+        extend_instance_ttl(&env);
+        Ok(())
+    }
+
+    /// SEP-40: `asset`'s price at `timestamp`, from the first configured
+    /// source with a price no older than the asset's `max_age`, in
+    /// [`Self::decimals`]. `None` if no source has one.
+    pub fn price(env: Env, asset: Asset, timestamp: u64) -> Option<PriceData> {
+        let decimals = get_decimals(&env)?;
+        let config = get_asset_config(&env, &asset)?;
+        config.sources.iter().find_map(|source| match source {
+            PriceSource::Sep40(feed) => {
+                sep40_price(&env, &feed, timestamp, config.max_age, decimals)
+            }
+            PriceSource::Report(source) => {
+                reported_price(&env, &asset, source, timestamp, config.max_age)
+            }
+        })
+    }
+
+    /// SEP-40: `asset`'s price now.
+    pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        let now = env.ledger().timestamp();
+        Self::price(env, asset, now)
+    }
+
+    /// SEP-40: decimals prices are returned in.
+    /// # Errors
+    /// * [`PriceAdapterError::NotInitialized`] – contract not initialised.
+    pub fn decimals(env: Env) -> Result<u32, PriceAdapterError> {
+        get_decimals(&env).ok_or(PriceAdapterError::NotInitialized)
+    }
+
+    /// Get the sources `asset` is priced from.
+    /// # Errors
+    /// * [`PriceAdapterError::AssetNotConfigured`] – `asset` has no sources.
+    pub fn get_asset_config(env: Env, asset: Asset) -> Result<AssetConfig, PriceAdapterError> {
+        get_asset_config(&env, &asset).ok_or(PriceAdapterError::AssetNotConfigured)
+    }
+
+    /// Whether `public_key` may sign price reports.
+    pub fn is_signer(env: Env, public_key: BytesN<32>) -> bool {
+        is_signer(&env, &public_key)
+    }
+
+    /// Get the prices `source` posted for `asset`, oldest first, up to
+    /// [`MAX_REPORTS`].
+    pub fn get_reports(env: Env, asset: Asset, source: ReportSource) -> Vec<PriceData> {
+        get_reports(&env, &asset, source)
+    }
+}
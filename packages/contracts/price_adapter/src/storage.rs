@@ -0,0 +1,158 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol, Vec};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Decimals,
+    Signer(BytesN<32>),
+    AssetConfig(Asset),
+    /// Prices posted for an asset by one kind of reporter, oldest first
+    Reports(Asset, ReportSource),
+}
+
+/// Mirror of the SEP-40 `Asset`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// Mirror of the SEP-40 `PriceData`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Prices pushed into the adapter rather than read from another contract.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReportSource {
+    /// Reports (e.g. from DexScreener) signed by a registered signer
+    Signed,
+    /// Prices posted by the admin
+    Manual,
+}
+
+/// SEP-40 price feed an asset is read from, e.g. Reflector
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sep40Feed {
+    pub oracle: Address,
+    /// The asset as the feed names it
+    pub asset: Asset,
+}
+
+/// One upstream an asset's price can come from
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PriceSource {
+    Sep40(Sep40Feed),
+    Report(ReportSource),
+}
+
+/// Where an asset's price comes from and how old it may be.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetConfig {
+    /// Sources in order of preference; the first with a fresh price wins
+    pub sources: Vec<PriceSource>,
+    /// Oldest a price may be, in seconds, relative to the requested time
+    pub max_age: u64,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the decimals prices are returned in, if initialised
+pub fn get_decimals(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&DataKey::Decimals)
+}
+
+/// Store the decimals prices are returned in
+pub fn set_decimals(env: &Env, decimals: u32) {
+    env.storage().instance().set(&DataKey::Decimals, &decimals);
+}
+
+/// Whether `public_key` may sign price reports
+pub fn is_signer(env: &Env, public_key: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Signer(public_key.clone()))
+}
+
+/// Register or remove a report signer
+pub fn set_signer(env: &Env, public_key: &BytesN<32>, allowed: bool) {
+    let key = DataKey::Signer(public_key.clone());
+    if allowed {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Get an asset's sources, if configured
+pub fn get_asset_config(env: &Env, asset: &Asset) -> Option<AssetConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AssetConfig(asset.clone()))
+}
+
+/// Store or, with `None`, remove an asset's sources
+pub fn set_asset_config(env: &Env, asset: &Asset, config: &Option<AssetConfig>) {
+    let key = DataKey::AssetConfig(asset.clone());
+    match config {
+        Some(config) => {
+            env.storage().persistent().set(&key, config);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Get the prices posted for an asset by `source`, oldest first
+pub fn get_reports(env: &Env, asset: &Asset, source: ReportSource) -> Vec<PriceData> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Reports(asset.clone(), source))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the prices posted for an asset by `source`
+pub fn set_reports(env: &Env, asset: &Asset, source: ReportSource, reports: &Vec<PriceData>) {
+    let key = DataKey::Reports(asset.clone(), source);
+    env.storage().persistent().set(&key, reports);
+    bump_persistent(env, &key);
+}
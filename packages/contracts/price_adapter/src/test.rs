@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger as _},
+    vec, Address, BytesN, Env, Symbol,
+};
+
+use crate::errors::PriceAdapterError;
+use crate::{
+    build_price_message, Asset, AssetConfig, PriceAdapter, PriceAdapterClient, PriceData,
+    PriceSource, ReportSource, Sep40Feed,
+};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+/// SEP-40 feed stand-in with 14 decimals and a 300 s resolution.
+#[contract]
+pub struct MockFeed;
+
+#[contractimpl]
+impl MockFeed {
+    pub fn set_price(env: Env, timestamp: u64, price: i128) {
+        env.storage().instance().set(&timestamp, &price);
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+
+    pub fn resolution(_env: Env) -> u32 {
+        300
+    }
+
+    pub fn price(env: Env, _asset: Asset, timestamp: u64) -> Option<PriceData> {
+        env.storage()
+            .instance()
+            .get(&timestamp)
+            .map(|price| PriceData { price, timestamp })
+    }
+}
+
+const NOW: u64 = 10_000;
+
+fn setup() -> (Env, PriceAdapterClient<'static>, Asset) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let client = PriceAdapterClient::new(&env, &env.register(PriceAdapter, ()));
+    client.initialize(&Address::generate(&env), &7);
+    let asset = Asset::Other(Symbol::new(&env, "XLM"));
+
+    (env, client, asset)
+}
+
+fn configure(client: &PriceAdapterClient, asset: &Asset, sources: &[PriceSource]) {
+    let env = &client.env;
+    let mut list = vec![env];
+    for source in sources {
+        list.push_back(source.clone());
+    }
+    client.set_asset_config(
+        asset,
+        &Some(AssetConfig {
+            sources: list,
+            max_age: 600,
+        }),
+    );
+}
+
+/// Sign a price report with a fresh key, registering the key if `register`.
+fn signed_report(
+    client: &PriceAdapterClient,
+    asset: &Asset,
+    price: i128,
+    timestamp: u64,
+    register: bool,
+) -> (BytesN<32>, BytesN<64>) {
+    use ed25519_dalek::{Signer, SigningKey};
+    use rand::RngCore;
+
+    let env = &client.env;
+    let mut seed = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut seed);
+    let signing_key = SigningKey::from_bytes(&seed);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    if register {
+        client.set_signer(&public_key, &true);
+    }
+
+    let msg = build_price_message(env, &client.address, asset, price, timestamp);
+    let mut msg_bytes = [0u8; 256];
+    let msg_len = msg.len() as usize;
+    msg.copy_into_slice(&mut msg_bytes[..msg_len]);
+    let signature = signing_key.sign(&msg_bytes[..msg_len]);
+
+    (public_key, BytesN::from_array(env, &signature.to_bytes()))
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_price_falls_back_through_sources_and_is_normalized() {
+    let (env, client, asset) = setup();
+    let feed = MockFeedClient::new(&env, &env.register(MockFeed, ()));
+    configure(
+        &client,
+        &asset,
+        &[
+            PriceSource::Sep40(Sep40Feed {
+                oracle: feed.address.clone(),
+                asset: asset.clone(),
+            }),
+            PriceSource::Report(ReportSource::Manual),
+        ],
+    );
+    assert_eq!(client.price(&asset, &NOW), None);
+
+    // Admin post serves while the feed has nothing
+    client.post_price(&asset, &1_200_000, &(NOW - 100));
+    assert_eq!(
+        client.lastprice(&asset),
+        Some(PriceData {
+            price: 1_200_000,
+            timestamp: NOW - 100
+        })
+    );
+
+    // The feed's 14-decimal price takes over, read at its 300 s slot
+    feed.set_price(&9_900, &12_345_678_900_000);
+    assert_eq!(
+        client.price(&asset, &NOW),
+        Some(PriceData {
+            price: 1_234_567,
+            timestamp: 9_900
+        })
+    );
+
+    // Older than max_age everywhere
+    assert_eq!(client.price(&asset, &(NOW + 1_000)), None);
+    assert_eq!(client.decimals(), 7);
+}
+
+#[test]
+fn test_signed_reports_need_a_registered_signer() {
+    let (_env, client, asset) = setup();
+    configure(
+        &client,
+        &asset,
+        &[PriceSource::Report(ReportSource::Signed)],
+    );
+
+    let (public_key, signature) = signed_report(&client, &asset, 1_500_000, NOW - 50, false);
+    let result =
+        client.try_submit_signed_price(&asset, &1_500_000, &(NOW - 50), &public_key, &signature);
+    assert_eq!(result, Err(Ok(PriceAdapterError::UnknownSigner)));
+
+    client.set_signer(&public_key, &true);
+    client.submit_signed_price(&asset, &1_500_000, &(NOW - 50), &public_key, &signature);
+    assert_eq!(client.price(&asset, &NOW).unwrap().price, 1_500_000);
+    // Before the report there is no price
+    assert_eq!(client.price(&asset, &(NOW - 60)), None);
+
+    let result =
+        client.try_submit_signed_price(&asset, &1_500_000, &(NOW - 50), &public_key, &signature);
+    assert_eq!(result, Err(Ok(PriceAdapterError::StalePrice)));
+    let (public_key, signature) = signed_report(&client, &asset, 1_600_000, NOW + 1, true);
+    let result =
+        client.try_submit_signed_price(&asset, &1_600_000, &(NOW + 1), &public_key, &signature);
+    assert_eq!(result, Err(Ok(PriceAdapterError::InvalidTimestamp)));
+}
+
+#[test]
+#[should_panic]
+fn test_forged_signed_report_is_rejected() {
+    let (_env, client, asset) = setup();
+    let (public_key, signature) = signed_report(&client, &asset, 1_500_000, NOW, true);
+    client.submit_signed_price(&asset, &9_900_000, &NOW, &public_key, &signature);
+}
+
+#[test]
+fn test_asset_config_rules() {
+    let (env, client, asset) = setup();
+    let result = client.try_set_asset_config(
+        &asset,
+        &Some(AssetConfig {
+            sources: vec![&env],
+            max_age: 600,
+        }),
+    );
+    assert_eq!(result, Err(Ok(PriceAdapterError::InvalidSources)));
+
+    configure(
+        &client,
+        &asset,
+        &[PriceSource::Report(ReportSource::Manual)],
+    );
+    assert_eq!(client.get_asset_config(&asset).max_age, 600);
+    client.set_asset_config(&asset, &None);
+    let result = client.try_get_asset_config(&asset);
+    assert_eq!(result, Err(Ok(PriceAdapterError::AssetNotConfigured)));
+
+    let result = client.try_post_price(&asset, &0, &NOW);
+    assert_eq!(result, Err(Ok(PriceAdapterError::InvalidPrice)));
+}
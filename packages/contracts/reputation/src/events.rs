@@ -0,0 +1,44 @@
+use soroban_sdk::{Address, Env};
+
+/// Emitted when the admin registers or removes a reporter
+pub fn emit_reporter_set(env: &Env, reporter: &Address, allowed: bool) {
+    env.events()
+        .publish(("reputation", "reporter_set"), (reporter.clone(), allowed));
+}
+
+/// Emitted when a reporter records a call's creation
+pub fn emit_call_created(env: &Env, reporter: &Address, call_id: u64, creator: &Address) {
+    env.events().publish(
+        ("reputation", "call_created"),
+        (reporter.clone(), call_id, creator.clone()),
+    );
+}
+
+/// Emitted when a reporter records whether a call resolved in its creator's favour
+pub fn emit_call_resolved(
+    env: &Env,
+    reporter: &Address,
+    call_id: u64,
+    creator: &Address,
+    correct: bool,
+) {
+    env.events().publish(
+        ("reputation", "call_resolved"),
+        (reporter.clone(), call_id, creator.clone(), correct),
+    );
+}
+
+/// Emitted when a reporter records the result of a staker's position
+pub fn emit_position_settled(
+    env: &Env,
+    reporter: &Address,
+    call_id: u64,
+    staker: &Address,
+    volume: i128,
+    won: bool,
+) {
+    env.events().publish(
+        ("reputation", "position_settled"),
+        (reporter.clone(), call_id, staker.clone(), volume, won),
+    );
+}
@@ -0,0 +1,221 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! On-chain track record of BACKit creators and stakers.
+//!
+//! Registered reporters (CallRegistry instances) notify this contract when a
+//! call is created, when it resolves and when each staker's position settles.
+//! It keeps per-address counts of calls created, creator accuracy, positions
+//! won, win streaks and stake volume, and derives scores in basis points that
+//! other contracts (fee tiers, visibility, bond sizing) can read
+//! cross-contract.
+//! Each report is recorded at most once per reporter, so reporters may
+//! resend notifications safely.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+use errors::ReputationError;
+use events::*;
+use storage::*;
+
+pub use storage::Reputation;
+
+/// Scores and rates are expressed in basis points of this.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+#[contract]
+pub struct ReputationContract;
+
+fn require_reporter(env: &Env, reporter: &Address) -> Result<(), ReputationError> {
+    reporter.require_auth();
+    if !is_reporter(env, reporter) {
+        return Err(ReputationError::UnknownReporter);
+    }
+    Ok(())
+}
+
+/// `numerator / denominator` in basis points, 0 when there is no sample.
+fn ratio_bps(numerator: u32, denominator: u32) -> u32 {
+    if denominator == 0 {
+        return 0;
+    }
+    (numerator as u64 * BPS_DENOMINATOR as u64 / denominator as u64) as u32
+}
+
+#[contractimpl]
+impl ReputationContract {
+    /// Initialise the contract with an admin who manages reporters.
+    /// # Errors
+    /// * [`ReputationError::AlreadyInitialized`] – called more than once.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ReputationError> {
+        if get_admin(&env).is_some() {
+            return Err(ReputationError::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Register (`allowed = true`) or remove a reporter (admin only).
+    /// # Errors
+    /// * [`ReputationError::NotInitialized`] – contract not initialised.
+    pub fn set_reporter(env: Env, reporter: Address, allowed: bool) -> Result<(), ReputationError> {
+        let admin = get_admin(&env).ok_or(ReputationError::NotInitialized)?;
+        admin.require_auth();
+
+        set_reporter(&env, &reporter, allowed);
+        extend_instance_ttl(&env);
+
+        emit_reporter_set(&env, &reporter, allowed);
+
+        Ok(())
+    }
+
+    /// Record that `creator` created `call_id`. Returns `false` if the
+    /// reporter had already recorded it.
+    /// # Errors
+    /// * [`ReputationError::UnknownReporter`] – `reporter` is not registered.
+    pub fn record_call_created(
+        env: Env,
+        reporter: Address,
+        call_id: u64,
+        creator: Address,
+    ) -> Result<bool, ReputationError> {
+        require_reporter(&env, &reporter)?;
+        if !mark_recorded(&env, &DataKey::CallCreated(reporter.clone(), call_id)) {
+            return Ok(false);
+        }
+
+        let mut reputation = get_reputation(&env, &creator);
+        reputation.calls_created += 1;
+        set_reputation(&env, &creator, &reputation);
+
+        emit_call_created(&env, &reporter, call_id, &creator);
+
+        Ok(true)
+    }
+
+    /// Record that `call_id` resolved, and whether `creator` had backed the
+    /// outcome. Returns `false` if the reporter had already recorded it.
+    /// # Errors
+    /// * [`ReputationError::UnknownReporter`] – `reporter` is not registered.
+    pub fn record_call_resolved(
+        env: Env,
+        reporter: Address,
+        call_id: u64,
+        creator: Address,
+        correct: bool,
+    ) -> Result<bool, ReputationError> {
+        require_reporter(&env, &reporter)?;
+        if !mark_recorded(&env, &DataKey::CallResolved(reporter.clone(), call_id)) {
+            return Ok(false);
+        }
+
+        let mut reputation = get_reputation(&env, &creator);
+        reputation.calls_resolved += 1;
+        if correct {
+            reputation.calls_correct += 1;
+        }
+        set_reputation(&env, &creator, &reputation);
+
+        emit_call_resolved(&env, &reporter, call_id, &creator, correct);
+
+        Ok(true)
+    }
+
+    /// Record the result of `staker`'s `volume` stake on `call_id`; a loss
+    /// ends the staker's win streak. The reporter passes `in_order = false`
+    /// for a result older than one already reported, which then counts
+    /// toward the totals but leaves the streak alone. Returns `false` if the
+    /// reporter had already recorded it.
+    /// # Errors
+    /// * [`ReputationError::UnknownReporter`] – `reporter` is not registered.
+    /// * [`ReputationError::InvalidAmount`]   – `volume` < 0.
+    pub fn record_position(
+        env: Env,
+        reporter: Address,
+        call_id: u64,
+        staker: Address,
+        volume: i128,
+        won: bool,
+        in_order: bool,
+    ) -> Result<bool, ReputationError> {
+        require_reporter(&env, &reporter)?;
+        if volume < 0 {
+            return Err(ReputationError::InvalidAmount);
+        }
+        let key = DataKey::Position(reporter.clone(), call_id, staker.clone());
+        if !mark_recorded(&env, &key) {
+            return Ok(false);
+        }
+
+        let mut reputation = get_reputation(&env, &staker);
+        reputation.positions_settled += 1;
+        if won {
+            reputation.positions_won += 1;
+        }
+        if won && in_order {
+            reputation.current_win_streak += 1;
+            reputation.best_win_streak = reputation
+                .best_win_streak
+                .max(reputation.current_win_streak);
+        } else if in_order {
+            reputation.current_win_streak = 0;
+        }
+        reputation.volume = reputation.volume.saturating_add(volume);
+        set_reputation(&env, &staker, &reputation);
+
+        emit_position_settled(&env, &reporter, call_id, &staker, volume, won);
+
+        Ok(true)
+    }
+
+    /// Whether `reporter` may record reputation events.
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        is_reporter(&env, &reporter)
+    }
+
+    /// Get an address's track record.
+    pub fn get_reputation(env: Env, address: Address) -> Reputation {
+        get_reputation(&env, &address)
+    }
+
+    /// Share of an address's resolved calls whose outcome it had backed as
+    /// creator, in basis points (0 before any resolve).
+    pub fn get_accuracy_bps(env: Env, address: Address) -> u32 {
+        let reputation = get_reputation(&env, &address);
+        ratio_bps(reputation.calls_correct, reputation.calls_resolved)
+    }
+
+    /// Share of an address's settled positions that won, in basis points
+    /// (0 before any settle).
+    pub fn get_win_rate_bps(env: Env, address: Address) -> u32 {
+        let reputation = get_reputation(&env, &address);
+        ratio_bps(reputation.positions_won, reputation.positions_settled)
+    }
+
+    /// Combined score in basis points: correct calls plus won positions over
+    /// all resolved calls and settled positions, smoothed with one success
+    /// and one failure so that newcomers start at 5 000 and a short record
+    /// cannot reach either extreme.
+    pub fn get_score(env: Env, address: Address) -> u32 {
+        let reputation = get_reputation(&env, &address);
+        let successes = reputation
+            .calls_correct
+            .saturating_add(reputation.positions_won)
+            .saturating_add(1);
+        let samples = reputation
+            .calls_resolved
+            .saturating_add(reputation.positions_settled)
+            .saturating_add(2);
+        ratio_bps(successes, samples)
+    }
+}
@@ -0,0 +1,110 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Reporter(Address),
+    Reputation(Address),
+    /// Set once a reporter's call creation has been recorded: (reporter, call_id)
+    CallCreated(Address, u64),
+    /// Set once a reporter's call resolution has been recorded: (reporter, call_id)
+    CallResolved(Address, u64),
+    /// Set once a staker's position result has been recorded:
+    /// (reporter, call_id, staker)
+    Position(Address, u64, Address),
+}
+
+/// Lifetime track record of one address, as creator and as staker.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Reputation {
+    pub calls_created: u32,
+    /// Created calls that resolved to UP, DOWN or DRAW
+    pub calls_resolved: u32,
+    /// Resolved calls whose outcome the creator had backed
+    pub calls_correct: u32,
+    /// Positions whose result has been reported
+    pub positions_settled: u32,
+    pub positions_won: u32,
+    /// Consecutive winning positions since the last reported loss
+    pub current_win_streak: u32,
+    /// Longest run of consecutive winning positions
+    pub best_win_streak: u32,
+    /// Stake across settled positions, in stake-token units summed over tokens
+    pub volume: i128,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Whether `reporter` may record reputation events
+pub fn is_reporter(env: &Env, reporter: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Reporter(reporter.clone()))
+}
+
+/// Register or remove a reporter
+pub fn set_reporter(env: &Env, reporter: &Address, allowed: bool) {
+    let key = DataKey::Reporter(reporter.clone());
+    if allowed {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Get an address's track record
+pub fn get_reputation(env: &Env, address: &Address) -> Reputation {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Reputation(address.clone()))
+        .unwrap_or_default()
+}
+
+/// Store an address's track record
+pub fn set_reputation(env: &Env, address: &Address, reputation: &Reputation) {
+    let key = DataKey::Reputation(address.clone());
+    env.storage().persistent().set(&key, reputation);
+    bump_persistent(env, &key);
+}
+
+/// Mark a report as recorded; returns `false` if it already was
+pub fn mark_recorded(env: &Env, key: &DataKey) -> bool {
+    if env.storage().persistent().has(key) {
+        return false;
+    }
+    env.storage().persistent().set(key, &true);
+    bump_persistent(env, key);
+    true
+}
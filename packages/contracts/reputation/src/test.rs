@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::errors::ReputationError;
+use crate::{ReputationContract, ReputationContractClient};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+fn setup() -> (Env, ReputationContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = ReputationContractClient::new(&env, &env.register(ReputationContract, ()));
+    client.initialize(&Address::generate(&env));
+    let reporter = Address::generate(&env);
+    client.set_reporter(&reporter, &true);
+
+    (env, client, reporter)
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_creator_accuracy_counts_each_call_once() {
+    let (env, client, reporter) = setup();
+    let creator = Address::generate(&env);
+
+    assert!(client.record_call_created(&reporter, &1, &creator));
+    assert!(client.record_call_created(&reporter, &2, &creator));
+    assert!(!client.record_call_created(&reporter, &2, &creator));
+    assert!(client.record_call_resolved(&reporter, &1, &creator, &true));
+    assert!(client.record_call_resolved(&reporter, &2, &creator, &false));
+    assert!(!client.record_call_resolved(&reporter, &2, &creator, &true));
+
+    let reputation = client.get_reputation(&creator);
+    assert_eq!(reputation.calls_created, 2);
+    assert_eq!(reputation.calls_resolved, 2);
+    assert_eq!(reputation.calls_correct, 1);
+    assert_eq!(client.get_accuracy_bps(&creator), 5_000);
+}
+
+#[test]
+fn test_positions_track_win_rate_volume_and_score() {
+    let (env, client, reporter) = setup();
+    let staker = Address::generate(&env);
+    assert_eq!(client.get_score(&staker), 5_000);
+    assert_eq!(client.get_win_rate_bps(&staker), 0);
+
+    client.record_position(&reporter, &1, &staker, &300, &true, &true);
+    client.record_position(&reporter, &2, &staker, &200, &true, &true);
+    client.record_position(&reporter, &3, &staker, &500, &false, &true);
+    assert!(!client.record_position(&reporter, &3, &staker, &500, &false, &true));
+
+    let reputation = client.get_reputation(&staker);
+    assert_eq!(reputation.positions_settled, 3);
+    assert_eq!(reputation.volume, 1_000);
+    assert_eq!(reputation.current_win_streak, 0);
+    assert_eq!(reputation.best_win_streak, 2);
+    assert_eq!(client.get_win_rate_bps(&staker), 6_666);
+    // (2 wins + 1) / (3 positions + 2)
+    assert_eq!(client.get_score(&staker), 6_000);
+
+    // A late result counts toward the totals but not the streak
+    client.record_position(&reporter, &5, &staker, &100, &true, &true);
+    client.record_position(&reporter, &0, &staker, &100, &false, &false);
+    let reputation = client.get_reputation(&staker);
+    assert_eq!(reputation.positions_settled, 5);
+    assert_eq!(reputation.current_win_streak, 1);
+
+    let result = client.try_record_position(&reporter, &4, &staker, &-1, &true, &true);
+    assert_eq!(result, Err(Ok(ReputationError::InvalidAmount)));
+}
+
+#[test]
+fn test_only_registered_reporters_record() {
+    let (env, client, reporter) = setup();
+    let stranger = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    let result = client.try_record_call_created(&stranger, &1, &creator);
+    assert_eq!(result, Err(Ok(ReputationError::UnknownReporter)));
+
+    client.set_reporter(&reporter, &false);
+    assert!(!client.is_reporter(&reporter));
+    let result = client.try_record_call_created(&reporter, &1, &creator);
+    assert_eq!(result, Err(Ok(ReputationError::UnknownReporter)));
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u32)]
+pub enum RewardsError {
+    /// `initialize` was called on an already-initialised contract.
+    AlreadyInitialized = 1,
+    /// A function that requires the contract to be initialised was called before `initialize`.
+    NotInitialized = 2,
+    /// The epoch length is 0.
+    InvalidEpochLength = 3,
+    /// The caller is not a registered reporter.
+    UnknownReporter = 4,
+    /// An emission or stake-time amount is negative, or a schedule is empty.
+    InvalidAmount = 5,
+    /// Emissions can only be added to the current or a future epoch.
+    EpochInPast = 6,
+    /// The staker has no rewards in finished epochs.
+    NothingToClaim = 7,
+    /// The epoch has not finished, or stakers earned its emission.
+    EpochNotSweepable = 8,
+}
@@ -0,0 +1,41 @@
+use soroban_sdk::{Address, Env};
+
+/// Emitted when the admin registers or removes a reporter
+pub fn emit_reporter_set(env: &Env, reporter: &Address, allowed: bool) {
+    env.events()
+        .publish(("rewards", "reporter_set"), (reporter.clone(), allowed));
+}
+
+/// Emitted when reward tokens are scheduled for an epoch
+pub fn emit_emission_funded(env: &Env, funder: &Address, epoch: u32, amount: i128) {
+    env.events().publish(
+        ("rewards", "emission_funded"),
+        (funder.clone(), epoch, amount),
+    );
+}
+
+/// Emitted when a settled position's stake time is credited to an epoch
+pub fn emit_stake_time_recorded(
+    env: &Env,
+    call_id: u64,
+    staker: &Address,
+    epoch: u32,
+    stake_time: i128,
+) {
+    env.events().publish(
+        ("rewards", "stake_time_recorded"),
+        (call_id, staker.clone(), epoch, stake_time),
+    );
+}
+
+/// Emitted when a staker claims their rewards
+pub fn emit_rewards_claimed(env: &Env, staker: &Address, amount: i128) {
+    env.events()
+        .publish(("rewards", "rewards_claimed"), (staker.clone(), amount));
+}
+
+/// Emitted when the admin recovers an epoch's unearned emission
+pub fn emit_emission_swept(env: &Env, epoch: u32, to: &Address, amount: i128) {
+    env.events()
+        .publish(("rewards", "emission_swept"), (epoch, to.clone(), amount));
+}
@@ -0,0 +1,316 @@
+#![no_std]
+#![allow(deprecated)]
+
+//! Streams a platform token to stakers for the stake they keep on calls.
+//!
+//! Time is split into fixed-length epochs, each with its own emission funded
+//! ahead of time. Registered reporters (CallRegistry instances) report each
+//! position as it settles, weighted by stake × seconds held until the call
+//! ended; the report is credited to the epoch it arrives in. Once an epoch
+//! finishes, its emission is shared among the stakers credited to it in
+//! proportion to their stake time, and `claim_rewards` pays out every
+//! finished epoch a staker has not claimed yet.
+
+mod errors;
+mod events;
+mod storage;
+mod test;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec, I256};
+
+use errors::RewardsError;
+use events::*;
+use storage::*;
+
+pub use storage::{EpochInfo, RewardsConfig};
+
+/// Most epochs one [`RewardsContract::claim_rewards`] call pays out; stakers
+/// with more finished epochs claim again for the rest.
+pub const MAX_CLAIM_EPOCHS: u32 = 50;
+
+#[contract]
+pub struct RewardsContract;
+
+fn require_admin(env: &Env) -> Result<Address, RewardsError> {
+    let admin = get_admin(env).ok_or(RewardsError::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+/// Epoch the current ledger time falls in.
+fn current_epoch(env: &Env, config: &RewardsConfig) -> u32 {
+    let elapsed = env.ledger().timestamp().saturating_sub(config.start_ts);
+    (elapsed / config.epoch_length).min(u32::MAX as u64) as u32
+}
+
+/// `staker`'s share of a finished epoch's emission, rounded down.
+fn epoch_share(env: &Env, info: &EpochInfo, stake_time: i128) -> i128 {
+    if info.total_stake_time == 0 {
+        return 0;
+    }
+    I256::from_i128(env, info.emission)
+        .mul(&I256::from_i128(env, stake_time))
+        .div(&I256::from_i128(env, info.total_stake_time))
+        .to_i128()
+        .unwrap_or(0)
+}
+
+#[contractimpl]
+impl RewardsContract {
+    /// Initialise the contract with an admin who manages reporters, the
+    /// token paid out, and the epoch schedule: epoch `n` covers
+    /// `[start_ts + n × epoch_length, start_ts + (n + 1) × epoch_length)`.
+    /// # Errors
+    /// * [`RewardsError::AlreadyInitialized`] – called more than once.
+    /// * [`RewardsError::InvalidEpochLength`] – `epoch_length` is 0.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        reward_token: Address,
+        start_ts: u64,
+        epoch_length: u64,
+    ) -> Result<(), RewardsError> {
+        if get_admin(&env).is_some() {
+            return Err(RewardsError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if epoch_length == 0 {
+            return Err(RewardsError::InvalidEpochLength);
+        }
+
+        set_admin(&env, &admin);
+        set_config(
+            &env,
+            &RewardsConfig {
+                reward_token,
+                start_ts,
+                epoch_length,
+            },
+        );
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Register (`allowed = true`) or remove a reporter (admin only).
+    /// # Errors
+    /// * [`RewardsError::NotInitialized`] – contract not initialised.
+    pub fn set_reporter(env: Env, reporter: Address, allowed: bool) -> Result<(), RewardsError> {
+        require_admin(&env)?;
+
+        set_reporter(&env, &reporter, allowed);
+        extend_instance_ttl(&env);
+
+        emit_reporter_set(&env, &reporter, allowed);
+
+        Ok(())
+    }
+
+    /// Schedule emissions: `amounts[i]` reward tokens go to epoch
+    /// `first_epoch + i`. Anyone may fund; the total is transferred in from
+    /// `funder` and adds to what the epochs already hold.
+    /// # Errors
+    /// * [`RewardsError::NotInitialized`] – contract not initialised.
+    /// * [`RewardsError::InvalidAmount`]  – `amounts` is empty or has a negative entry.
+    /// * [`RewardsError::EpochInPast`]    – `first_epoch` has already finished.
+    pub fn fund_epochs(
+        env: Env,
+        funder: Address,
+        first_epoch: u32,
+        amounts: Vec<i128>,
+    ) -> Result<i128, RewardsError> {
+        funder.require_auth();
+        let config = get_config(&env).ok_or(RewardsError::NotInitialized)?;
+        if amounts.is_empty() || amounts.iter().any(|amount| amount < 0) {
+            return Err(RewardsError::InvalidAmount);
+        }
+        if first_epoch < current_epoch(&env, &config) {
+            return Err(RewardsError::EpochInPast);
+        }
+
+        let mut total: i128 = 0;
+        for (offset, amount) in amounts.iter().enumerate() {
+            let epoch = first_epoch
+                .checked_add(offset as u32)
+                .ok_or(RewardsError::InvalidAmount)?;
+            let mut info = get_epoch(&env, epoch);
+            info.emission = info
+                .emission
+                .checked_add(amount)
+                .ok_or(RewardsError::InvalidAmount)?;
+            set_epoch(&env, epoch, &info);
+            total = total
+                .checked_add(amount)
+                .ok_or(RewardsError::InvalidAmount)?;
+
+            emit_emission_funded(&env, &funder, epoch, amount);
+        }
+        token::Client::new(&env, &config.reward_token).transfer(
+            &funder,
+            env.current_contract_address(),
+            &total,
+        );
+        extend_instance_ttl(&env);
+
+        Ok(total)
+    }
+
+    /// Credit `staker`'s settled position on `call_id` with `stake_time`
+    /// (stake × seconds held) in the current epoch. Returns `false` if the
+    /// reporter had already recorded the position.
+    /// # Errors
+    /// * [`RewardsError::NotInitialized`]  – contract not initialised.
+    /// * [`RewardsError::UnknownReporter`] – `reporter` is not registered.
+    /// * [`RewardsError::InvalidAmount`]   – `stake_time` < 0.
+    pub fn record_stake_time(
+        env: Env,
+        reporter: Address,
+        call_id: u64,
+        staker: Address,
+        stake_time: i128,
+    ) -> Result<bool, RewardsError> {
+        reporter.require_auth();
+        let config = get_config(&env).ok_or(RewardsError::NotInitialized)?;
+        if !is_reporter(&env, &reporter) {
+            return Err(RewardsError::UnknownReporter);
+        }
+        if stake_time < 0 {
+            return Err(RewardsError::InvalidAmount);
+        }
+        if !mark_recorded(&env, &reporter, call_id, &staker) {
+            return Ok(false);
+        }
+        if stake_time == 0 {
+            return Ok(true);
+        }
+
+        let epoch = current_epoch(&env, &config);
+        let mut info = get_epoch(&env, epoch);
+        info.total_stake_time = info.total_stake_time.saturating_add(stake_time);
+        set_epoch(&env, epoch, &info);
+        add_stake_time(&env, epoch, &staker, stake_time);
+
+        emit_stake_time_recorded(&env, call_id, &staker, epoch, stake_time);
+
+        Ok(true)
+    }
+
+    /// Pay `staker` their share of every finished epoch they have stake
+    /// time in, up to [`MAX_CLAIM_EPOCHS`] epochs per call. Returns the
+    /// amount paid.
+    /// # Errors
+    /// * [`RewardsError::NotInitialized`] – contract not initialised.
+    /// * [`RewardsError::NothingToClaim`] – no finished epochs to claim.
+    pub fn claim_rewards(env: Env, staker: Address) -> Result<i128, RewardsError> {
+        staker.require_auth();
+        let config = get_config(&env).ok_or(RewardsError::NotInitialized)?;
+        let current = current_epoch(&env, &config);
+
+        let mut remaining = Vec::new(&env);
+        let mut claimed_epochs: u32 = 0;
+        let mut payout: i128 = 0;
+        for epoch in get_staker_epochs(&env, &staker).iter() {
+            if epoch >= current || claimed_epochs == MAX_CLAIM_EPOCHS {
+                remaining.push_back(epoch);
+                continue;
+            }
+            let mut info = get_epoch(&env, epoch);
+            let share = epoch_share(&env, &info, get_stake_time(&env, epoch, &staker));
+            info.claimed += share;
+            set_epoch(&env, epoch, &info);
+            remove_stake_time(&env, epoch, &staker);
+            payout += share;
+            claimed_epochs += 1;
+        }
+        if claimed_epochs == 0 {
+            return Err(RewardsError::NothingToClaim);
+        }
+        set_staker_epochs(&env, &staker, &remaining);
+
+        if payout > 0 {
+            token::Client::new(&env, &config.reward_token).transfer(
+                &env.current_contract_address(),
+                &staker,
+                &payout,
+            );
+        }
+        extend_instance_ttl(&env);
+
+        emit_rewards_claimed(&env, &staker, payout);
+
+        Ok(payout)
+    }
+
+    /// Send a finished epoch's emission to `to` when no stake time was
+    /// credited to it, so it would otherwise sit idle (admin only).
+    /// Returns the amount swept.
+    /// # Errors
+    /// * [`RewardsError::NotInitialized`]    – contract not initialised.
+    /// * [`RewardsError::EpochNotSweepable`] – the epoch is unfinished, has
+    ///   stake time, or holds nothing.
+    pub fn sweep_unused_emission(env: Env, epoch: u32, to: Address) -> Result<i128, RewardsError> {
+        require_admin(&env)?;
+        let config = get_config(&env).ok_or(RewardsError::NotInitialized)?;
+
+        let mut info = get_epoch(&env, epoch);
+        let unused = info.emission - info.claimed;
+        if epoch >= current_epoch(&env, &config) || info.total_stake_time != 0 || unused <= 0 {
+            return Err(RewardsError::EpochNotSweepable);
+        }
+        info.claimed = info.emission;
+        set_epoch(&env, epoch, &info);
+
+        token::Client::new(&env, &config.reward_token).transfer(
+            &env.current_contract_address(),
+            &to,
+            &unused,
+        );
+        extend_instance_ttl(&env);
+
+        emit_emission_swept(&env, epoch, &to, unused);
+
+        Ok(unused)
+    }
+
+    /// Get the epoch the current ledger time falls in.
+    /// # Errors
+    /// * [`RewardsError::NotInitialized`] – contract not initialised.
+    pub fn current_epoch(env: Env) -> Result<u32, RewardsError> {
+        let config = get_config(&env).ok_or(RewardsError::NotInitialized)?;
+        Ok(current_epoch(&env, &config))
+    }
+
+    /// Get an epoch's emission, total stake time and amount claimed.
+    pub fn get_epoch(env: Env, epoch: u32) -> EpochInfo {
+        get_epoch(&env, epoch)
+    }
+
+    /// Get `staker`'s unclaimed stake time in `epoch`.
+    pub fn get_stake_time(env: Env, epoch: u32, staker: Address) -> i128 {
+        get_stake_time(&env, epoch, &staker)
+    }
+
+    /// Get the rewards `staker` could claim now across all finished epochs.
+    /// # Errors
+    /// * [`RewardsError::NotInitialized`] – contract not initialised.
+    pub fn get_pending_rewards(env: Env, staker: Address) -> Result<i128, RewardsError> {
+        let config = get_config(&env).ok_or(RewardsError::NotInitialized)?;
+        let current = current_epoch(&env, &config);
+
+        let mut pending: i128 = 0;
+        for epoch in get_staker_epochs(&env, &staker).iter() {
+            if epoch < current {
+                let stake_time = get_stake_time(&env, epoch, &staker);
+                pending += epoch_share(&env, &get_epoch(&env, epoch), stake_time);
+            }
+        }
+        Ok(pending)
+    }
+
+    /// Get the reward token and epoch schedule.
+    /// # Errors
+    /// * [`RewardsError::NotInitialized`] – contract not initialised.
+    pub fn get_config(env: Env) -> Result<RewardsConfig, RewardsError> {
+        get_config(&env).ok_or(RewardsError::NotInitialized)
+    }
+}
@@ -0,0 +1,176 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Persistent storage TTL constants (in ledgers, ~5 s each)
+const PERSISTENT_BUMP_AMOUNT: u32 = 518_400; // ~30 days
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 172_800; // ~10 days
+const INSTANCE_BUMP_AMOUNT: u32 = 518_400;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 172_800;
+
+/// Storage keys for the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    Reporter(Address),
+    Epoch(u32),
+    /// A staker's stake time credited to an epoch: (epoch, staker)
+    StakeTime(u32, Address),
+    /// Epochs a staker has unclaimed stake time in, oldest first
+    StakerEpochs(Address),
+    /// Set once a position's stake time has been recorded:
+    /// (reporter, call_id, staker)
+    Recorded(Address, u64, Address),
+}
+
+/// Emission schedule parameters, fixed at initialisation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RewardsConfig {
+    /// Platform token paid out as rewards
+    pub reward_token: Address,
+    /// Timestamp epoch 0 starts at
+    pub start_ts: u64,
+    /// Seconds per epoch
+    pub epoch_length: u64,
+}
+
+/// One epoch's emission and the stake time competing for it.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EpochInfo {
+    /// Reward tokens shared among the epoch's stakers
+    pub emission: i128,
+    /// Stake time (stake × seconds) credited to the epoch
+    pub total_stake_time: i128,
+    /// Reward tokens paid out of the epoch so far
+    pub claimed: i128,
+}
+
+/// Extend contract storage lifetime
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Get the admin address, if initialised
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Store the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the emission schedule parameters, if initialised
+pub fn get_config(env: &Env) -> Option<RewardsConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+/// Store the emission schedule parameters
+pub fn set_config(env: &Env, config: &RewardsConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Whether `reporter` may record stake time
+pub fn is_reporter(env: &Env, reporter: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Reporter(reporter.clone()))
+}
+
+/// Register or remove a reporter
+pub fn set_reporter(env: &Env, reporter: &Address, allowed: bool) {
+    let key = DataKey::Reporter(reporter.clone());
+    if allowed {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Get an epoch's emission and stake time
+pub fn get_epoch(env: &Env, epoch: u32) -> EpochInfo {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Epoch(epoch))
+        .unwrap_or_default()
+}
+
+/// Store an epoch's emission and stake time
+pub fn set_epoch(env: &Env, epoch: u32, info: &EpochInfo) {
+    let key = DataKey::Epoch(epoch);
+    env.storage().persistent().set(&key, info);
+    bump_persistent(env, &key);
+}
+
+/// Get a staker's stake time in an epoch
+pub fn get_stake_time(env: &Env, epoch: u32, staker: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakeTime(epoch, staker.clone()))
+        .unwrap_or(0)
+}
+
+/// Add to a staker's stake time in an epoch, listing the epoch as unclaimed
+pub fn add_stake_time(env: &Env, epoch: u32, staker: &Address, stake_time: i128) {
+    let key = DataKey::StakeTime(epoch, staker.clone());
+    let previous = get_stake_time(env, epoch, staker);
+    env.storage()
+        .persistent()
+        .set(&key, &(previous.saturating_add(stake_time)));
+    bump_persistent(env, &key);
+
+    if previous == 0 {
+        let mut epochs = get_staker_epochs(env, staker);
+        epochs.push_back(epoch);
+        set_staker_epochs(env, staker, &epochs);
+    }
+}
+
+/// Drop a staker's stake time in an epoch once claimed
+pub fn remove_stake_time(env: &Env, epoch: u32, staker: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::StakeTime(epoch, staker.clone()));
+}
+
+/// Get the epochs a staker has unclaimed stake time in, oldest first
+pub fn get_staker_epochs(env: &Env, staker: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakerEpochs(staker.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the epochs a staker has unclaimed stake time in
+pub fn set_staker_epochs(env: &Env, staker: &Address, epochs: &Vec<u32>) {
+    let key = DataKey::StakerEpochs(staker.clone());
+    if epochs.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, epochs);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Mark a position as recorded; returns `false` if it already was
+pub fn mark_recorded(env: &Env, reporter: &Address, call_id: u64, staker: &Address) -> bool {
+    let key = DataKey::Recorded(reporter.clone(), call_id, staker.clone());
+    if env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+    true
+}
@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    token, vec, Address, Env,
+};
+
+use crate::errors::RewardsError;
+use crate::{RewardsContract, RewardsContractClient};
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+const START: u64 = 1_000;
+const EPOCH: u64 = 100;
+
+struct Setup {
+    env: Env,
+    client: RewardsContractClient<'static>,
+    reward_token: token::Client<'static>,
+    reporter: Address,
+    funder: Address,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START);
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let client = RewardsContractClient::new(&env, &env.register(RewardsContract, ()));
+    client.initialize(&Address::generate(&env), &token_id, &START, &EPOCH);
+
+    let reporter = Address::generate(&env);
+    client.set_reporter(&reporter, &true);
+    let funder = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_id).mint(&funder, &10_000);
+
+    Setup {
+        reward_token: token::Client::new(&env, &token_id),
+        env,
+        client,
+        reporter,
+        funder,
+    }
+}
+
+fn advance_epochs(s: &Setup, epochs: u64) {
+    let now = s.env.ledger().timestamp();
+    s.env.ledger().set_timestamp(now + epochs * EPOCH);
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_epoch_emission_is_shared_by_stake_time() {
+    let s = setup();
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    s.client
+        .fund_epochs(&s.funder, &0, &vec![&s.env, 1_000i128, 600]);
+    assert_eq!(s.reward_token.balance(&s.client.address), 1_600);
+
+    // Epoch 0: alice holds 3/4 of the stake time
+    assert!(s.client.record_stake_time(&s.reporter, &1, &alice, &300));
+    assert!(s.client.record_stake_time(&s.reporter, &1, &bob, &100));
+    assert!(!s.client.record_stake_time(&s.reporter, &1, &bob, &100));
+
+    let result = s.client.try_claim_rewards(&alice);
+    assert_eq!(result, Err(Ok(RewardsError::NothingToClaim)));
+
+    // Epoch 1: alice alone
+    advance_epochs(&s, 1);
+    s.client.record_stake_time(&s.reporter, &2, &alice, &50);
+    assert_eq!(s.client.get_pending_rewards(&alice), 750);
+
+    advance_epochs(&s, 1);
+    assert_eq!(s.client.get_pending_rewards(&alice), 1_350);
+    assert_eq!(s.client.claim_rewards(&alice), 1_350);
+    assert_eq!(s.client.claim_rewards(&bob), 250);
+    assert_eq!(s.reward_token.balance(&alice), 1_350);
+    assert_eq!(s.reward_token.balance(&bob), 250);
+    assert_eq!(s.client.get_epoch(&0).claimed, 1_000);
+
+    let result = s.client.try_claim_rewards(&alice);
+    assert_eq!(result, Err(Ok(RewardsError::NothingToClaim)));
+}
+
+#[test]
+fn test_only_reporters_record_and_past_epochs_cannot_be_funded() {
+    let s = setup();
+    let staker = Address::generate(&s.env);
+    let stranger = Address::generate(&s.env);
+
+    let result = s.client.try_record_stake_time(&stranger, &1, &staker, &10);
+    assert_eq!(result, Err(Ok(RewardsError::UnknownReporter)));
+    let result = s
+        .client
+        .try_record_stake_time(&s.reporter, &1, &staker, &-1);
+    assert_eq!(result, Err(Ok(RewardsError::InvalidAmount)));
+    s.client.set_reporter(&s.reporter, &false);
+    let result = s
+        .client
+        .try_record_stake_time(&s.reporter, &1, &staker, &10);
+    assert_eq!(result, Err(Ok(RewardsError::UnknownReporter)));
+
+    advance_epochs(&s, 2);
+    assert_eq!(s.client.current_epoch(), 2);
+    let result = s
+        .client
+        .try_fund_epochs(&s.funder, &1, &vec![&s.env, 100i128]);
+    assert_eq!(result, Err(Ok(RewardsError::EpochInPast)));
+    let result = s.client.try_fund_epochs(&s.funder, &2, &vec![&s.env]);
+    assert_eq!(result, Err(Ok(RewardsError::InvalidAmount)));
+}
+
+#[test]
+fn test_unearned_emission_is_swept_once_epoch_ends() {
+    let s = setup();
+    let treasury = Address::generate(&s.env);
+    s.client
+        .fund_epochs(&s.funder, &0, &vec![&s.env, 400i128, 400]);
+    s.client
+        .record_stake_time(&s.reporter, &1, &Address::generate(&s.env), &10);
+
+    advance_epochs(&s, 1);
+    let result = s.client.try_sweep_unused_emission(&0, &treasury);
+    assert_eq!(result, Err(Ok(RewardsError::EpochNotSweepable)));
+    let result = s.client.try_sweep_unused_emission(&1, &treasury);
+    assert_eq!(result, Err(Ok(RewardsError::EpochNotSweepable)));
+
+    advance_epochs(&s, 1);
+    assert_eq!(s.client.sweep_unused_emission(&1, &treasury), 400);
+    assert_eq!(s.reward_token.balance(&treasury), 400);
+    let result = s.client.try_sweep_unused_emission(&1, &treasury);
+    assert_eq!(result, Err(Ok(RewardsError::EpochNotSweepable)));
+}
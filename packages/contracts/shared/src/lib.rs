@@ -1,6 +1,6 @@
 #![no_std]
 
-use soroban_sdk::{Bytes, Env};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, Env};
 
 // ─── Outcome constants ────────────────────────────────────────────────────────
 
@@ -8,6 +8,14 @@ use soroban_sdk::{Bytes, Env};
 pub const OUTCOME_UP: u32 = 1;
 /// Outcome value representing a DOWN result.
 pub const OUTCOME_DOWN: u32 = 2;
+/// Outcome value representing a DRAW (price stayed within the tolerance band).
+/// Only meaningful for binary UP/DOWN calls; both sides are refunded minus fees.
+/// Kept out of the range of multi-outcome indices, like [`OUTCOME_VOID`].
+pub const OUTCOME_DRAW: u32 = u32::MAX - 1;
+/// Outcome value representing a VOID result: the market could not be measured
+/// (pair delisted, feed unavailable, ...). The call is voided and every staker
+/// is refunded in full. Kept out of the range of multi-outcome indices.
+pub const OUTCOME_VOID: u32 = u32::MAX;
 
 // ─── Fee constants ────────────────────────────────────────────────────────────
 
@@ -19,24 +27,65 @@ pub const MAX_FEE_BPS: u32 = 10_000;
 /// Prefix used in the canonical oracle message.
 pub const MESSAGE_PREFIX: &[u8] = b"BACKit:Outcome:";
 
+/// Version byte of the canonical oracle message layout. Bump whenever the
+/// field list below changes so old signatures can never verify under a new
+/// layout.
+pub const MESSAGE_VERSION: u8 = 4;
+
 /// Build the canonical message that oracles sign.
 ///
 /// Format (all big-endian):
-///   `b"BACKit:Outcome:"` | call_id(8B) | `b":"` | outcome(1B) | `b":"` | price(16B) | `b":"` | timestamp(8B)
-pub fn build_message(env: &Env, call_id: u64, outcome: u32, price: i128, timestamp: u64) -> Bytes {
+///   `b"BACKit:Outcome:"` | version(1B) | `b":"` | network_id(32B) | `b":"` |
+///   contract(XDR `ScVal::Address`) | `b":"` | registry(XDR `ScVal::Address`) | `b":"` |
+///   call_id(8B) | `b":"` | outcome(4B) | `b":"` | price(16B) | `b":"` |
+///   timestamp(8B) | `b":"` | nonce(8B) | `b":"` | valid_until(4B)
+///
+/// `network_id` is the SHA-256 of the network passphrase and `contract` is the
+/// OutcomeManager instance that verifies the signature, so a signature only
+/// replays on the deployment it was produced for. `registry` ties the report
+/// to the CallRegistry the call belongs to. `nonce` and `valid_until`
+/// (a ledger sequence) bound it in time on that deployment.
+///
+/// The outcome is encoded as its full `u32`, so every position of a
+/// multi-outcome call, DRAW and VOID sign distinct messages.
+#[allow(clippy::too_many_arguments)]
+pub fn build_message(
+    env: &Env,
+    contract: &Address,
+    registry: &Address,
+    call_id: u64,
+    outcome: u32,
+    price: i128,
+    timestamp: u64,
+    nonce: u64,
+    valid_until: u32,
+) -> Bytes {
     let mut msg = Bytes::new(env);
 
     msg.append(&Bytes::from_slice(env, MESSAGE_PREFIX));
+    msg.push_back(MESSAGE_VERSION);
+    msg.append(&Bytes::from_slice(env, b":"));
+
+    msg.append(&env.ledger().network_id().into());
+    msg.append(&Bytes::from_slice(env, b":"));
+    msg.append(&contract.clone().to_xdr(env));
+    msg.append(&Bytes::from_slice(env, b":"));
+    msg.append(&registry.clone().to_xdr(env));
+    msg.append(&Bytes::from_slice(env, b":"));
+
     msg.append(&Bytes::from_slice(env, &call_id.to_be_bytes()));
     msg.append(&Bytes::from_slice(env, b":"));
 
-    let outcome_byte: &[u8] = if outcome == OUTCOME_UP { b"1" } else { b"2" };
-    msg.append(&Bytes::from_slice(env, outcome_byte));
+    msg.append(&Bytes::from_slice(env, &outcome.to_be_bytes()));
     msg.append(&Bytes::from_slice(env, b":"));
 
     msg.append(&Bytes::from_slice(env, &price.to_be_bytes()));
     msg.append(&Bytes::from_slice(env, b":"));
     msg.append(&Bytes::from_slice(env, &timestamp.to_be_bytes()));
+    msg.append(&Bytes::from_slice(env, b":"));
+    msg.append(&Bytes::from_slice(env, &nonce.to_be_bytes()));
+    msg.append(&Bytes::from_slice(env, b":"));
+    msg.append(&Bytes::from_slice(env, &valid_until.to_be_bytes()));
 
     msg
 }
@@ -49,10 +98,46 @@ pub fn validate_call_id(call_id: u64) -> bool {
     call_id > 0
 }
 
-/// Returns `true` if `outcome` is a valid outcome value (UP or DOWN).
+/// Returns `true` if `outcome` is a valid outcome value (UP, DOWN, DRAW or VOID).
 #[inline]
 pub fn is_valid_outcome(outcome: u32) -> bool {
-    outcome == OUTCOME_UP || outcome == OUTCOME_DOWN
+    outcome == OUTCOME_UP
+        || outcome == OUTCOME_DOWN
+        || outcome == OUTCOME_DRAW
+        || outcome == OUTCOME_VOID
+}
+
+/// Returns `true` if a call with `outcome_count` positions can resolve to
+/// `outcome`: one of its positions, VOID, or DRAW on a binary call.
+#[inline]
+pub fn is_valid_outcome_for(outcome: u32, outcome_count: u32) -> bool {
+    outcome == OUTCOME_VOID
+        || (outcome == OUTCOME_DRAW && outcome_count == 2)
+        || (1..=outcome_count).contains(&outcome)
+}
+
+/// Returns `true` if `end_price` moved no more than `tolerance_bps` (relative to
+/// `start_price`) away from `start_price`, i.e. the call should resolve as a
+/// DRAW. A zero tolerance or non-positive start price never produces a draw.
+#[inline]
+pub fn is_within_draw_band(start_price: i128, end_price: i128, tolerance_bps: u32) -> bool {
+    if tolerance_bps == 0 || start_price <= 0 {
+        return false;
+    }
+    let diff = match end_price
+        .checked_sub(start_price)
+        .and_then(i128::checked_abs)
+    {
+        Some(diff) => diff,
+        None => return false,
+    };
+    match (
+        diff.checked_mul(10_000),
+        start_price.checked_mul(tolerance_bps as i128),
+    ) {
+        (Some(lhs), Some(rhs)) => lhs <= rhs,
+        _ => false,
+    }
 }
 
 /// Returns `true` if `fee_bps` does not exceed [`MAX_FEE_BPS`].
@@ -77,7 +77,7 @@ fn test_full_lifecycle_create_stake_submit_claim() {
         &1u32,  // quorum = 1
         &fee_collector,
         &100u32, // 1% fee
-        &3600u64, // 1 hour dispute window
+        &720u32, // ~1 hour dispute window, in ledgers
     );
 
     // Set registry address in outcome manager
@@ -328,6 +328,8 @@ fn test_pause_mechanism_blocks_submissions() {
         outcome: OUTCOME_UP,
         price: 100_000_000_i128,
         timestamp: 1000u64,
+        nonce: 1,
+        valid_until: 1000,
         oracle_pubkey: oracle_pubkey.clone(),
         signature: dummy_signature,
     };